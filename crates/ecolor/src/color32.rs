@@ -1,4 +1,4 @@
-use crate::{Rgba, fast_round, linear_f32_from_linear_u8};
+use crate::{Rgba, fast_round, linear_f32_from_gamma_u8, linear_f32_from_linear_u8};
 
 /// This format is used for space-efficient color representation (32 bits).
 ///
@@ -354,6 +354,30 @@ impl Color32 {
     pub fn intensity(&self) -> f32 {
         (self.r() as f32 * 0.299 + self.g() as f32 * 0.587 + self.b() as f32 * 0.114) / 255.0
     }
+
+    /// The relative luminance of the color, as defined by
+    /// [WCAG 2.1](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance).
+    ///
+    /// Returns a value in the range 0-1, where 0 is black and 1 is white.
+    /// Alpha is ignored.
+    pub fn relative_luminance(&self) -> f32 {
+        let [r, g, b, _] = self.to_opaque().to_array().map(linear_f32_from_gamma_u8);
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
+    /// The contrast ratio between two colors, as defined by
+    /// [WCAG 2.1](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio).
+    ///
+    /// Returns a value in the range 1-21, where 1 means no contrast (same luminance)
+    /// and 21 is black on white. The order of the arguments does not matter.
+    /// WCAG level AA requires at least 4.5 for normal text, and 3.0 for large text.
+    ///
+    /// Alpha is ignored, so blend translucent colors with their background first.
+    pub fn contrast_ratio(&self, other: Self) -> f32 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
 }
 
 impl std::ops::Mul for Color32 {
@@ -451,6 +475,21 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_contrast_ratio() {
+        let ratio = Color32::BLACK.contrast_ratio(Color32::WHITE);
+        assert!((ratio - 21.0).abs() < 0.01, "ratio: {ratio}");
+        assert_eq!(Color32::RED.contrast_ratio(Color32::RED), 1.0);
+        assert_eq!(
+            Color32::GRAY.contrast_ratio(Color32::WHITE),
+            Color32::WHITE.contrast_ratio(Color32::GRAY)
+        );
+
+        // #767676 on white is the classic "just barely passes AA" gray:
+        let ratio = Color32::from_gray(0x76).contrast_ratio(Color32::WHITE);
+        assert!(4.5 < ratio && ratio < 4.6, "ratio: {ratio}");
+    }
+
     #[test]
     fn color32_unmultiplied_round_trip() {
         for in_rgba in test_rgba() {
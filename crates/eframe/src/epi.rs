@@ -387,6 +387,14 @@ pub struct NativeOptions {
     /// Defaults to true.
     pub dithering: bool,
 
+    /// Snap native windows to the edges of the monitors and of each other
+    /// when the user drags them within this many points of an edge.
+    ///
+    /// This applies to all viewports of the app. Not supported on Wayland.
+    ///
+    /// Defaults to `None` (no snapping).
+    pub viewport_snap_distance: Option<f32>,
+
     /// Android application for `winit`'s event loop.
     ///
     /// This value is required on Android to correctly create the event loop. See
@@ -460,6 +468,8 @@ impl Default for NativeOptions {
 
             dithering: true,
 
+            viewport_snap_distance: None,
+
             #[cfg(target_os = "android")]
             android_app: None,
         }
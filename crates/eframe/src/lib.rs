@@ -204,6 +204,10 @@ pub use native::run::EframePumpStatus;
 #[cfg(feature = "persistence")]
 pub use native::file_storage::storage_dir;
 
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(any(feature = "glow", feature = "wgpu"))]
+pub use native::jump_list;
+
 #[cfg(not(target_arch = "wasm32"))]
 pub mod icon_data;
 
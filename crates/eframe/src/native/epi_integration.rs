@@ -100,6 +100,72 @@ pub fn apply_window_settings(
     }
 }
 
+/// The saved geometry of a viewport that uses [`ViewportBuilder::with_persisted_geometry`],
+/// ready to be applied to its builder with [`WindowSettings::initialize_viewport_builder`]
+/// and to its window with [`apply_window_settings`].
+#[cfg_attr(target_os = "ios", allow(dead_code, unused_variables))]
+pub fn persisted_viewport_window_settings(
+    egui_ctx: &egui::Context,
+    event_loop: &ActiveEventLoop,
+    viewport_id: ViewportId,
+    builder: &ViewportBuilder,
+) -> Option<WindowSettings> {
+    if viewport_id == ViewportId::ROOT || builder.persisted_geometry != Some(true) {
+        return None; // The root window is handled by `NativeOptions::persist_window`
+    }
+
+    // Always use the default window size / position on iOS, like for the root window.
+    #[cfg(not(target_os = "ios"))]
+    {
+        let mut window_settings = egui_ctx.data(|data| {
+            data.get_temp::<ViewportWindowSettings>(viewport_window_settings_id())?
+                .get(&viewport_id)
+                .copied()
+        })?;
+        if builder.clamp_size_to_monitor_size.unwrap_or(true) {
+            window_settings.clamp_size_to_sane_values(largest_monitor_point_size(
+                egui_ctx.zoom_factor(),
+                event_loop,
+            ));
+        }
+        window_settings.clamp_position_to_monitors(egui_ctx.zoom_factor(), event_loop);
+        Some(window_settings)
+    }
+    #[cfg(target_os = "ios")]
+    None
+}
+
+/// Remember the geometry of a viewport that uses [`ViewportBuilder::with_persisted_geometry`],
+/// so that it is written to storage on the next [`EpiIntegration::save`].
+///
+/// Called before each frame of the viewport, so we still know where it was after it closes.
+pub fn remember_viewport_window(
+    egui_ctx: &egui::Context,
+    viewport_id: ViewportId,
+    builder: &ViewportBuilder,
+    window: &winit::window::Window,
+) {
+    if !cfg!(feature = "persistence")
+        || viewport_id == ViewportId::ROOT
+        || builder.persisted_geometry != Some(true)
+    {
+        return;
+    }
+    let window_settings = WindowSettings::from_window(egui_ctx.zoom_factor(), window);
+    egui_ctx.data_mut(|data| {
+        data.get_temp_mut_or_default::<ViewportWindowSettings>(viewport_window_settings_id())
+            .insert(viewport_id, window_settings);
+    });
+}
+
+/// The [`WindowSettings`] of all the viewports with persisted geometry,
+/// including the ones that are currently closed.
+type ViewportWindowSettings = std::collections::HashMap<ViewportId, WindowSettings>;
+
+fn viewport_window_settings_id() -> egui::Id {
+    egui::Id::new("eframe_viewport_window_settings")
+}
+
 #[cfg(not(target_os = "ios"))]
 fn largest_monitor_point_size(egui_zoom_factor: f32, event_loop: &ActiveEventLoop) -> egui::Vec2 {
     profiling::function_scope!();
@@ -165,6 +231,7 @@ pub struct EpiIntegration {
     can_drag_window: bool,
     #[cfg(feature = "persistence")]
     persist_window: bool,
+    viewport_snap_distance: Option<f32>,
     app_icon_setter: super::app_icon::AppTitleIconSetter,
 }
 
@@ -195,12 +262,28 @@ impl EpiIntegration {
             raw_window_handle: window.window_handle().map(|h| h.as_raw()),
         };
 
+        #[cfg(feature = "persistence")]
+        if let Some(viewport_windows) = load_viewport_window_settings(frame.storage()) {
+            egui_ctx.data_mut(|data| {
+                data.insert_temp(viewport_window_settings_id(), viewport_windows);
+            });
+        }
+
         let icon = native_options
             .viewport
             .icon
             .clone()
             .unwrap_or_else(|| std::sync::Arc::new(load_default_egui_icon()));
 
+        super::jump_list::init(&egui_ctx);
+        super::taskbar::init(
+            native_options
+                .viewport
+                .app_id
+                .as_deref()
+                .unwrap_or(app_name),
+        );
+
         let app_icon_setter = super::app_icon::AppTitleIconSetter::new(
             native_options
                 .viewport
@@ -219,6 +302,7 @@ impl EpiIntegration {
             can_drag_window: false,
             #[cfg(feature = "persistence")]
             persist_window: native_options.persist_window,
+            viewport_snap_distance: native_options.viewport_snap_distance,
             app_icon_setter,
             beginning: Instant::now(),
             is_first_frame: true,
@@ -252,6 +336,23 @@ impl EpiIntegration {
         egui_winit.on_window_event(window, event)
     }
 
+    /// Call on [`winit::event::WindowEvent::Moved`] to snap the window to the edges of the
+    /// monitors and `other_viewports`, if [`crate::NativeOptions::viewport_snap_distance`] is set.
+    pub fn snap_viewport<'a>(
+        &self,
+        window: &winit::window::Window,
+        other_viewports: impl Iterator<Item = &'a egui::ViewportInfo>,
+    ) {
+        let Some(threshold) = self.viewport_snap_distance else {
+            return;
+        };
+        let other_windows: Vec<egui::Rect> = other_viewports
+            .filter(|info| info.minimized != Some(true))
+            .filter_map(|info| info.outer_rect)
+            .collect();
+        egui_winit::snap_window(&self.egui_ctx, window, &other_windows, threshold);
+    }
+
     pub fn pre_update(&mut self) {
         self.app_icon_setter.update();
     }
@@ -269,9 +370,19 @@ impl EpiIntegration {
 
         let close_requested = raw_input.viewport().close_requested();
 
+        raw_input.native_menu_bar = super::native_menu::is_supported();
+        if viewport_ui_cb.is_none() {
+            // The menu bar belongs to the root viewport:
+            raw_input.events.extend(
+                super::native_menu::take_clicks()
+                    .into_iter()
+                    .map(egui::Event::NativeMenuButton),
+            );
+        }
+
         app.raw_input_hook(&self.egui_ctx, &mut raw_input);
 
-        let full_output = self.egui_ctx.run(raw_input, |egui_ctx| {
+        let mut full_output = self.egui_ctx.run(raw_input, |egui_ctx| {
             if let Some(viewport_ui_cb) = viewport_ui_cb {
                 // Child viewport
                 profiling::scope!("viewport_callback");
@@ -295,12 +406,17 @@ impl EpiIntegration {
             }
         }
 
+        if let Some(menus) = full_output.platform_output.native_menu_bar.take() {
+            super::native_menu::set_menus(&self.egui_ctx, &menus);
+        }
+
         self.pending_full_output.append(full_output);
         std::mem::take(&mut self.pending_full_output)
     }
 
     pub fn report_frame_time(&mut self, seconds: f32) {
         self.frame.info.cpu_usage = Some(seconds);
+        self.egui_ctx.report_frame_time(seconds);
     }
 
     pub fn post_rendering(&mut self, window: &winit::window::Window) {
@@ -342,6 +458,12 @@ impl EpiIntegration {
                     );
                 }
             }
+            if let Some(viewport_windows) = self.egui_ctx.data(|data| {
+                data.get_temp::<ViewportWindowSettings>(viewport_window_settings_id())
+            }) {
+                profiling::scope!("viewport_windows");
+                epi::set_value(storage, STORAGE_VIEWPORT_WINDOWS_KEY, &viewport_windows);
+            }
             if _app.persist_egui_memory() {
                 profiling::scope!("egui_memory");
                 self.egui_ctx
@@ -369,6 +491,9 @@ const STORAGE_EGUI_MEMORY_KEY: &str = "egui";
 #[cfg(feature = "persistence")]
 const STORAGE_WINDOW_KEY: &str = "window";
 
+#[cfg(feature = "persistence")]
+const STORAGE_VIEWPORT_WINDOWS_KEY: &str = "viewport_windows";
+
 pub fn load_window_settings(_storage: Option<&dyn epi::Storage>) -> Option<WindowSettings> {
     profiling::function_scope!();
     #[cfg(feature = "persistence")]
@@ -379,6 +504,14 @@ pub fn load_window_settings(_storage: Option<&dyn epi::Storage>) -> Option<Windo
     None
 }
 
+#[cfg(feature = "persistence")]
+fn load_viewport_window_settings(
+    storage: Option<&dyn epi::Storage>,
+) -> Option<ViewportWindowSettings> {
+    profiling::function_scope!();
+    epi::get_value(storage?, STORAGE_VIEWPORT_WINDOWS_KEY)
+}
+
 pub fn load_egui_memory(_storage: Option<&dyn epi::Storage>) -> Option<egui::Memory> {
     profiling::function_scope!();
     #[cfg(feature = "persistence")]
@@ -118,6 +118,10 @@ struct Viewport {
     gl_surface: Option<glutin::surface::Surface<glutin::surface::WindowSurface>>,
     window: Option<Arc<Window>>,
     egui_winit: Option<egui_winit::State>,
+
+    /// The `pixels_per_point` and primitives of the last frame,
+    /// if it used [`egui::Painter::paint_low_latency`].
+    last_frame: Option<(f32, Vec<egui::ClippedPrimitive>)>,
 }
 
 // ----------------------------------------------------------------------------
@@ -366,6 +370,12 @@ impl WinitApp for GlowWinitApp<'_> {
             .and_then(|r| r.glutin.borrow().window_from_viewport.get(&id).copied())
     }
 
+    fn viewport_id_from_window_id(&self, id: WindowId) -> Option<ViewportId> {
+        self.running
+            .as_ref()
+            .and_then(|r| r.glutin.borrow().viewport_from_window.get(&id).copied())
+    }
+
     fn save(&mut self) {
         log::debug!("WinitApp::save called");
         if let Some(running) = self.running.as_mut() {
@@ -439,6 +449,12 @@ impl WinitApp for GlowWinitApp<'_> {
         if let winit::event::DeviceEvent::MouseMotion { delta } = event {
             if let Some(running) = &mut self.running {
                 let mut glutin = running.glutin.borrow_mut();
+                for viewport in glutin.viewports.values() {
+                    if let Some(window) = &viewport.window {
+                        egui_winit::restore_mouse_hit_test(&running.integration.egui_ctx, window);
+                    }
+                }
+
                 if let Some(viewport) = glutin
                     .focused_viewport
                     .and_then(|viewport| glutin.viewports.get_mut(&viewport))
@@ -542,6 +558,13 @@ impl GlowWinitRunning<'_> {
                 return Ok(EventResult::Wait);
             };
             egui_winit::update_viewport_info(&mut viewport.info, &egui_ctx, window, false);
+            super::workspace::update_viewport_info(&mut viewport.info, window);
+            epi_integration::remember_viewport_window(
+                &egui_ctx,
+                viewport_id,
+                &viewport.builder,
+                window,
+            );
 
             let Some(egui_winit) = viewport.egui_winit.as_mut() else {
                 return Ok(EventResult::Wait);
@@ -626,6 +649,7 @@ impl GlowWinitRunning<'_> {
             shapes,
             pixels_per_point,
             viewport_output,
+            stats: _,
         } = full_output;
 
         glutin.remove_viewports_not_in(&viewport_output);
@@ -670,6 +694,11 @@ impl GlowWinitRunning<'_> {
             &textures_delta,
         );
 
+        viewport.last_frame = integration
+            .egui_ctx
+            .has_low_latency_painters(viewport_id)
+            .then_some((pixels_per_point, clipped_primitives));
+
         {
             for action in viewport.actions_requested.drain() {
                 match action {
@@ -789,6 +818,23 @@ impl GlowWinitRunning<'_> {
                 }
             }
 
+            winit::event::WindowEvent::Moved(_) => {
+                if let Some(viewport_id) = viewport_id {
+                    if let Some(window) = glutin
+                        .viewports
+                        .get(&viewport_id)
+                        .and_then(|viewport| viewport.window.as_deref())
+                    {
+                        let others = glutin
+                            .viewports
+                            .iter()
+                            .filter(|(id, _)| **id != viewport_id)
+                            .map(|(_, viewport)| &viewport.info);
+                        self.integration.snap_viewport(window, others);
+                    }
+                }
+            }
+
             winit::event::WindowEvent::CloseRequested => {
                 if viewport_id == Some(ViewportId::ROOT) && self.integration.should_close() {
                     log::debug!(
@@ -853,6 +899,21 @@ impl GlowWinitRunning<'_> {
         }
 
         if event_response.repaint {
+            let is_pointer_event = matches!(
+                event,
+                winit::event::WindowEvent::CursorMoved { .. }
+                    | winit::event::WindowEvent::MouseInput { .. }
+                    | winit::event::WindowEvent::Touch(_)
+            );
+            if is_pointer_event {
+                if let Some(viewport_id) = viewport_id {
+                    drop(glutin);
+                    if let Err(err) = self.paint_low_latency(viewport_id) {
+                        log::warn!("Failed to paint low-latency ink: {err}");
+                    }
+                }
+            }
+
             if repaint_asap {
                 EventResult::RepaintNow(window_id)
             } else {
@@ -862,6 +923,53 @@ impl GlowWinitRunning<'_> {
             EventResult::Wait
         }
     }
+
+    /// Show the ink of [`egui::Painter::paint_low_latency`] for the pointer events so far,
+    /// on top of the last frame, without waiting for the next pass.
+    fn paint_low_latency(&mut self, viewport_id: ViewportId) -> Result {
+        profiling::function_scope!();
+
+        let mut glutin = self.glutin.borrow_mut();
+        let GlutinWindowContext {
+            viewports,
+            current_gl_context,
+            not_current_gl_context,
+            ..
+        } = &mut *glutin;
+        let Some(Viewport {
+            window: Some(window),
+            gl_surface: Some(gl_surface),
+            egui_winit: Some(egui_winit),
+            last_frame: Some((pixels_per_point, last_primitives)),
+            ..
+        }) = viewports.get(&viewport_id)
+        else {
+            return Ok(());
+        };
+
+        let egui_ctx = &self.integration.egui_ctx;
+        let ink = egui_ctx.low_latency_primitives(viewport_id, &egui_winit.egui_input().events);
+        if ink.is_empty() {
+            return Ok(());
+        }
+
+        change_gl_context(current_gl_context, not_current_gl_context, gl_surface);
+
+        let screen_size_in_pixels: [u32; 2] = window.inner_size().into();
+        let clear_color = self.app.clear_color(&egui_ctx.style().visuals);
+        let mut painter = self.painter.borrow_mut();
+        painter.clear(screen_size_in_pixels, clear_color);
+        painter.paint_primitives(screen_size_in_pixels, *pixels_per_point, last_primitives);
+        painter.paint_primitives(screen_size_in_pixels, *pixels_per_point, &ink);
+
+        let context = current_gl_context
+            .as_ref()
+            .ok_or(egui_glow::PainterError::from(
+                "failed to get current context to swap buffers".to_owned(),
+            ))?;
+        gl_surface.swap_buffers(context)?;
+        Ok(())
+    }
 }
 
 fn change_gl_context(
@@ -1053,6 +1161,7 @@ impl GlutinWindowContext {
                 gl_surface: None,
                 window: window.map(Arc::new),
                 egui_winit: None,
+                last_frame: None,
             },
         );
 
@@ -1112,10 +1221,22 @@ impl GlutinWindowContext {
             window
         } else {
             log::debug!("Creating a window for viewport {viewport_id:?}");
-            let window_attributes = egui_winit::create_winit_window_attributes(
+            let window_settings = epi_integration::persisted_viewport_window_settings(
                 &self.egui_ctx,
-                viewport.builder.clone(),
+                event_loop,
+                viewport_id,
+                &viewport.builder,
             );
+            let builder = match &window_settings {
+                Some(window_settings) => window_settings.initialize_viewport_builder(
+                    self.egui_ctx.zoom_factor(),
+                    event_loop,
+                    viewport.builder.clone(),
+                ),
+                None => viewport.builder.clone(),
+            };
+            let window_attributes =
+                egui_winit::create_winit_window_attributes(&self.egui_ctx, builder);
             if window_attributes.transparent()
                 && self.gl_config.supports_transparency() == Some(false)
             {
@@ -1128,6 +1249,7 @@ impl GlutinWindowContext {
                 &window,
                 &viewport.builder,
             );
+            epi_integration::apply_window_settings(&window, window_settings);
 
             egui_winit::update_viewport_info(&mut viewport.info, &self.egui_ctx, &window, true);
             viewport.window.insert(Arc::new(window))
@@ -1311,6 +1433,9 @@ impl GlutinWindowContext {
 
                 viewport.deferred_commands.append(&mut commands);
 
+                super::taskbar::process_viewport_commands(window, &viewport.deferred_commands);
+                super::workspace::process_viewport_commands(window, &viewport.deferred_commands);
+
                 egui_winit::process_viewport_commands(
                     egui_ctx,
                     &mut viewport.info,
@@ -1367,6 +1492,7 @@ fn initialize_or_update_viewport(
                 window: None,
                 egui_winit: None,
                 gl_surface: None,
+                last_frame: None,
             })
         }
 
@@ -1450,6 +1576,8 @@ fn render_immediate_viewport(
             return;
         };
         egui_winit::update_viewport_info(&mut viewport.info, egui_ctx, window, false);
+        super::workspace::update_viewport_info(&mut viewport.info, window);
+        epi_integration::remember_viewport_window(egui_ctx, viewport_id, &viewport.builder, window);
 
         let mut raw_input = egui_winit.take_egui_input(window);
         raw_input.viewports = glutin
@@ -1471,6 +1599,7 @@ fn render_immediate_viewport(
         shapes,
         pixels_per_point,
         viewport_output,
+        stats: _,
     } = egui_ctx.run(input, |ctx| {
         viewport_ui_cb(ctx);
     });
@@ -0,0 +1,359 @@
+//! Recent documents and tasks, shown by the OS outside of the app window.
+//!
+//! * On Windows they are shown in the jump list (right-click the taskbar icon).
+//! * On macOS recent documents are shown in the "Open Recent" menu and the dock menu.
+//!   Tasks are not supported.
+//!
+//! When the user picks one, the app receives a [`DeepLink`] from [`take_deep_links`].
+//!
+//! ```no_run
+//! # let path = std::path::Path::new("");
+//! // After opening or saving a document:
+//! eframe::jump_list::add_recent_document(path);
+//!
+//! eframe::jump_list::set_tasks(&[eframe::jump_list::Task::new("new_window", "New window")]);
+//!
+//! // Every frame:
+//! for link in eframe::jump_list::take_deep_links() {
+//!     match link {
+//!         eframe::jump_list::DeepLink::File(path) => { /* open the document */ }
+//!         eframe::jump_list::DeepLink::Task(id) => { /* run the task */ }
+//!     }
+//! }
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use egui::mutex::Mutex;
+
+/// The command line argument a [`Task`] launches the app with.
+const TASK_ARG: &str = "--eframe-task=";
+
+/// A request from the OS to open something in the app.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DeepLink {
+    /// The user picked this recent document, or asked the OS to open it with the app.
+    File(PathBuf),
+
+    /// The user picked the [`Task`] with this id.
+    Task(String),
+}
+
+/// An action in the jump list, e.g. "New window".
+///
+/// Windows only.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Task {
+    /// Reported back in [`DeepLink::Task`].
+    pub id: String,
+
+    /// Shown in the jump list.
+    pub title: String,
+
+    /// Shown as a tooltip.
+    pub description: String,
+}
+
+impl Task {
+    pub fn new(id: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            description: String::new(),
+        }
+    }
+
+    #[inline]
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+}
+
+#[derive(Default)]
+struct DeepLinks {
+    /// Asked to repaint when a link arrives.
+    ctx: Option<egui::Context>,
+
+    pending: Vec<DeepLink>,
+}
+
+static DEEP_LINKS: LazyLock<Mutex<DeepLinks>> = LazyLock::new(Default::default);
+
+/// Called once on startup.
+pub(crate) fn init(ctx: &egui::Context) {
+    {
+        let mut deep_links = DEEP_LINKS.lock();
+        if deep_links.ctx.is_some() {
+            return; // already initialized
+        }
+        deep_links.ctx = Some(ctx.clone());
+    }
+
+    // Tasks start a new instance of the app:
+    for arg in std::env::args().skip(1) {
+        if let Some(id) = arg.strip_prefix(TASK_ARG) {
+            push_deep_link(DeepLink::Task(id.to_owned()));
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    mac::install_open_documents_handler();
+}
+
+fn push_deep_link(link: DeepLink) {
+    let ctx = {
+        let mut deep_links = DEEP_LINKS.lock();
+        deep_links.pending.push(link);
+        deep_links.ctx.clone()
+    };
+    if let Some(ctx) = ctx {
+        ctx.request_repaint();
+    }
+}
+
+/// The [`DeepLink`]s that arrived since the last call.
+///
+/// Tasks always start a new instance of the app,
+/// so [`DeepLink::Task`] is only reported right after startup.
+///
+/// On Windows, picking a recent document also starts a new instance,
+/// with the path as a command line argument, just like opening the document from Explorer.
+/// Handle that with the rest of your command line arguments.
+pub fn take_deep_links() -> Vec<DeepLink> {
+    std::mem::take(&mut DEEP_LINKS.lock().pending)
+}
+
+/// Add a document to the recent documents of the app.
+///
+/// The OS only shows documents of types that the app is registered to open
+/// (in the registry on Windows, in `Info.plist` on macOS).
+pub fn add_recent_document(path: impl AsRef<Path>) {
+    let path = path.as_ref();
+    profiling::function_scope!();
+
+    let Ok(path) = path.canonicalize() else {
+        log::warn!("Can't add {} to the recent documents", path.display());
+        return;
+    };
+
+    #[cfg(target_os = "windows")]
+    win::add_recent_document(&path);
+
+    #[cfg(target_os = "macos")]
+    mac::add_recent_document(&path);
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let _ = path;
+}
+
+/// Forget all recent documents of the app.
+pub fn clear_recent_documents() {
+    profiling::function_scope!();
+
+    #[cfg(target_os = "windows")]
+    win::clear_recent_documents();
+
+    #[cfg(target_os = "macos")]
+    mac::clear_recent_documents();
+}
+
+/// Replace the tasks in the jump list.
+///
+/// Windows only.
+pub fn set_tasks(tasks: &[Task]) {
+    profiling::function_scope!();
+
+    #[cfg(target_os = "windows")]
+    if let Err(err) = win::set_tasks(tasks) {
+        log::warn!("Failed to set the jump list tasks: {err}");
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    let _ = tasks;
+}
+
+#[cfg(target_os = "windows")]
+#[expect(unsafe_code)]
+mod win {
+    use std::os::windows::ffi::OsStrExt as _;
+    use std::path::Path;
+
+    use windows::Win32::Foundation::E_FAIL;
+    use windows::Win32::Storage::EnhancedStorage::PKEY_Title;
+    use windows::Win32::System::Com::{
+        CLSCTX_INPROC_SERVER, COINIT_APARTMENTTHREADED, CoCreateInstance, CoInitializeEx,
+    };
+    use windows::Win32::UI::Shell::Common::{IObjectArray, IObjectCollection};
+    use windows::Win32::UI::Shell::PropertiesSystem::IPropertyStore;
+    use windows::Win32::UI::Shell::{
+        DestinationList, EnumerableObjectCollection, ICustomDestinationList, IShellLinkW, ShellLink,
+    };
+    use windows::core::{HSTRING, Interface as _, PROPVARIANT};
+    use windows_sys::Win32::UI::Shell::{SHARD_PATHW, SHAddToRecentDocs};
+
+    use super::{TASK_ARG, Task};
+
+    pub fn add_recent_document(path: &Path) {
+        let wide: Vec<u16> = path.as_os_str().encode_wide().chain([0]).collect();
+
+        // SAFETY: `wide` is a null-terminated wide string.
+        unsafe { SHAddToRecentDocs(SHARD_PATHW as _, wide.as_ptr().cast()) };
+    }
+
+    pub fn clear_recent_documents() {
+        // SAFETY: a null path clears the list.
+        unsafe { SHAddToRecentDocs(SHARD_PATHW as _, std::ptr::null()) };
+    }
+
+    pub fn set_tasks(tasks: &[Task]) -> windows::core::Result<()> {
+        let exe = std::env::current_exe()
+            .map_err(|err| windows::core::Error::new(E_FAIL, err.to_string()))?;
+
+        // SAFETY: we only pass valid COM objects and strings.
+        unsafe {
+            // Fails harmlessly if COM is already initialized (e.g. by winit).
+            let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+
+            let list: ICustomDestinationList =
+                CoCreateInstance(&DestinationList, None, CLSCTX_INPROC_SERVER)?;
+            let mut min_slots = 0;
+            let _removed: IObjectArray = list.BeginList(&mut min_slots)?;
+
+            let collection: IObjectCollection =
+                CoCreateInstance(&EnumerableObjectCollection, None, CLSCTX_INPROC_SERVER)?;
+            for task in tasks {
+                let link: IShellLinkW = CoCreateInstance(&ShellLink, None, CLSCTX_INPROC_SERVER)?;
+                link.SetPath(&HSTRING::from(exe.as_os_str()))?;
+                link.SetArguments(&HSTRING::from(format!("{TASK_ARG}{}", task.id)))?;
+                link.SetDescription(&HSTRING::from(task.description.as_str()))?;
+
+                let properties: IPropertyStore = link.cast()?;
+                properties.SetValue(&PKEY_Title, &PROPVARIANT::from(task.title.as_str()))?;
+                properties.Commit()?;
+
+                collection.AddObject(&link)?;
+            }
+
+            list.AddUserTasks(&collection.cast::<IObjectArray>()?)?;
+            list.CommitList()
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+#[expect(unsafe_code)]
+mod mac {
+    use std::path::{Path, PathBuf};
+
+    use objc2::rc::Retained;
+    use objc2::runtime::NSObject;
+    use objc2::{ClassType, DeclaredClass, declare_class, msg_send_id, mutability, sel};
+    use objc2_app_kit::NSDocumentController;
+    use objc2_foundation::{
+        MainThreadMarker, NSAppleEventDescriptor, NSAppleEventManager, NSString, NSURL,
+    };
+
+    use super::{DeepLink, push_deep_link};
+
+    /// `kCoreEventClass`, `kAEOpenDocuments`, `keyDirectObject` and `typeFileURL`.
+    const CORE_EVENT_CLASS: u32 = u32::from_be_bytes(*b"aevt");
+    const OPEN_DOCUMENTS: u32 = u32::from_be_bytes(*b"odoc");
+    const DIRECT_OBJECT: u32 = u32::from_be_bytes(*b"----");
+    const TYPE_FILE_URL: u32 = u32::from_be_bytes(*b"furl");
+
+    declare_class!(
+        /// Receives the "open documents" Apple Event, sent when the user picks a recent document.
+        struct OpenDocumentsHandler;
+
+        // SAFETY: `NSObject` has no subclassing requirements, and we don't implement `Drop`.
+        unsafe impl ClassType for OpenDocumentsHandler {
+            type Super = NSObject;
+            type Mutability = mutability::MainThreadOnly;
+            const NAME: &'static str = "EframeOpenDocumentsHandler";
+        }
+
+        impl DeclaredClass for OpenDocumentsHandler {}
+
+        // SAFETY: the signature matches what `NSAppleEventManager` expects of event handlers.
+        unsafe impl OpenDocumentsHandler {
+            #[method(handleOpenDocuments:withReplyEvent:)]
+            fn handle_open_documents(
+                &self,
+                event: &NSAppleEventDescriptor,
+                _reply: &NSAppleEventDescriptor,
+            ) {
+                for path in file_paths(event) {
+                    push_deep_link(DeepLink::File(path));
+                }
+            }
+        }
+    );
+
+    fn file_paths(event: &NSAppleEventDescriptor) -> Vec<PathBuf> {
+        // SAFETY: we only read from the event.
+        unsafe {
+            let Some(list) = event.paramDescriptorForKeyword(DIRECT_OBJECT) else {
+                return vec![];
+            };
+            (1..=list.numberOfItems()) // 1-based!
+                .filter_map(|i| list.descriptorAtIndex(i))
+                .filter_map(|item| item.coerceToDescriptorType(TYPE_FILE_URL))
+                .filter_map(|item| item.stringValue())
+                .filter_map(|url| NSURL::URLWithString(&url))
+                .filter_map(|url| url.path())
+                .map(|path| PathBuf::from(path.to_string()))
+                .collect()
+        }
+    }
+
+    pub fn install_open_documents_handler() {
+        let Some(mtm) = MainThreadMarker::new() else {
+            log::debug!("Not on the main thread; can't receive opened documents");
+            return;
+        };
+
+        // SAFETY: `init` is the designated initializer of `NSObject`,
+        // and the handler responds to the selector.
+        unsafe {
+            let handler: Retained<OpenDocumentsHandler> =
+                msg_send_id![mtm.alloc::<OpenDocumentsHandler>(), init];
+            NSAppleEventManager::sharedAppleEventManager()
+                .setEventHandler_andSelector_forEventClass_andEventID(
+                    &handler,
+                    sel!(handleOpenDocuments:withReplyEvent:),
+                    CORE_EVENT_CLASS,
+                    OPEN_DOCUMENTS,
+                );
+
+            // The event manager doesn't retain the handler, so keep it alive forever:
+            std::mem::forget(handler);
+        }
+    }
+
+    pub fn add_recent_document(path: &Path) {
+        let Some(mtm) = MainThreadMarker::new() else {
+            log::warn!("Recent documents can only be added from the main thread");
+            return;
+        };
+
+        // SAFETY: we are on the main thread.
+        unsafe {
+            let url = NSURL::fileURLWithPath(&NSString::from_str(&path.to_string_lossy()));
+            NSDocumentController::sharedDocumentController(mtm).noteNewRecentDocumentURL(&url);
+        }
+    }
+
+    pub fn clear_recent_documents() {
+        let Some(mtm) = MainThreadMarker::new() else {
+            log::warn!("Recent documents can only be cleared from the main thread");
+            return;
+        };
+
+        // SAFETY: we are on the main thread.
+        unsafe {
+            NSDocumentController::sharedDocumentController(mtm).clearRecentDocuments(None);
+        }
+    }
+}
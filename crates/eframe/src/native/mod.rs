@@ -1,7 +1,11 @@
 mod app_icon;
 mod epi_integration;
 mod event_loop_context;
+pub mod jump_list;
+mod native_menu;
 pub mod run;
+mod taskbar;
+mod workspace;
 
 /// File storage which can be used by native backends.
 #[cfg(feature = "persistence")]
@@ -0,0 +1,257 @@
+//! Show [`egui::PlatformOutput::native_menu_bar`] in the native menu bar.
+//!
+//! Only implemented on macOS. Elsewhere egui shows the menus itself.
+
+/// Can we show menus in a native menu bar on this platform?
+pub fn is_supported() -> bool {
+    cfg!(target_os = "macos")
+}
+
+/// Replace the menus of the native menu bar.
+///
+/// Clicks are reported with [`take_clicks`], and `ctx` is asked to repaint when they happen.
+pub fn set_menus(_ctx: &egui::Context, _menus: &[egui::containers::menu::NativeMenu]) {
+    profiling::function_scope!();
+
+    #[cfg(target_os = "macos")]
+    mac::set_menus(_ctx, _menus);
+}
+
+/// The native menu buttons clicked since the last call.
+pub fn take_clicks() -> Vec<egui::Id> {
+    #[cfg(target_os = "macos")]
+    return mac::take_clicks();
+
+    #[allow(unreachable_code, clippy::allow_attributes)]
+    Vec::new()
+}
+
+#[cfg(target_os = "macos")]
+#[expect(unsafe_code)]
+mod mac {
+    use std::cell::RefCell;
+
+    use egui::containers::menu::{NativeMenu, NativeMenuItem};
+    use objc2::rc::Retained;
+    use objc2::runtime::NSObject;
+    use objc2::{ClassType, DeclaredClass, declare_class, msg_send_id, mutability, sel};
+    use objc2_app_kit::{NSApplication, NSEventModifierFlags, NSMenu, NSMenuItem};
+    use objc2_foundation::{MainThreadMarker, NSString};
+
+    /// Everything lives on the main thread, like `AppKit` itself.
+    #[derive(Default)]
+    struct State {
+        ctx: Option<egui::Context>,
+
+        /// The menu item tags index into this.
+        ids: Vec<egui::Id>,
+
+        clicked: Vec<egui::Id>,
+
+        target: Option<Retained<MenuTarget>>,
+    }
+
+    thread_local! {
+        static STATE: RefCell<State> = RefCell::default();
+    }
+
+    declare_class!(
+        /// The target of the actions of our menu items.
+        struct MenuTarget;
+
+        // SAFETY: `NSObject` has no subclassing requirements, and we don't implement `Drop`.
+        unsafe impl ClassType for MenuTarget {
+            type Super = NSObject;
+            type Mutability = mutability::MainThreadOnly;
+            const NAME: &'static str = "EframeNativeMenuTarget";
+        }
+
+        impl DeclaredClass for MenuTarget {}
+
+        // SAFETY: the signature matches what `AppKit` sends to menu item actions.
+        unsafe impl MenuTarget {
+            #[method(menuItemClicked:)]
+            fn menu_item_clicked(&self, item: &NSMenuItem) {
+                // SAFETY: reading the tag has no preconditions.
+                let tag = unsafe { item.tag() };
+                let ctx = STATE.with_borrow_mut(|state| {
+                    let id = usize::try_from(tag).ok().and_then(|i| state.ids.get(i))?;
+                    state.clicked.push(*id);
+                    state.ctx.clone()
+                });
+                if let Some(ctx) = ctx {
+                    ctx.request_repaint();
+                }
+            }
+        }
+    );
+
+    impl MenuTarget {
+        fn new(mtm: MainThreadMarker) -> Retained<Self> {
+            // SAFETY: `init` is the designated initializer of `NSObject`.
+            unsafe { msg_send_id![mtm.alloc::<Self>(), init] }
+        }
+    }
+
+    pub fn take_clicks() -> Vec<egui::Id> {
+        STATE.with_borrow_mut(|state| std::mem::take(&mut state.clicked))
+    }
+
+    pub fn set_menus(ctx: &egui::Context, menus: &[NativeMenu]) {
+        let Some(mtm) = MainThreadMarker::new() else {
+            log::warn!("The native menu bar can only be set from the main thread");
+            return;
+        };
+
+        STATE.with_borrow_mut(|state| {
+            state.ctx = Some(ctx.clone());
+            state.ids.clear();
+            let target = state
+                .target
+                .get_or_insert_with(|| MenuTarget::new(mtm))
+                .clone();
+
+            let app = NSApplication::sharedApplication(mtm);
+
+            // SAFETY: we only touch the menus on the main thread.
+            unsafe {
+                // Keep the application menu (with "Quit" etc.) that winit created,
+                // and replace everything after it:
+                let main_menu = app.mainMenu().unwrap_or_else(|| {
+                    let menu = NSMenu::new(mtm);
+                    app.setMainMenu(Some(&menu));
+                    menu
+                });
+                while main_menu.numberOfItems() > 1 {
+                    main_menu.removeItemAtIndex(main_menu.numberOfItems() - 1);
+                }
+
+                for menu in menus {
+                    let item = NSMenuItem::new(mtm);
+                    item.setSubmenu(Some(&build_menu(mtm, menu, &target, &mut state.ids)));
+                    main_menu.addItem(&item);
+                }
+            }
+        });
+    }
+
+    fn build_menu(
+        mtm: MainThreadMarker,
+        menu: &NativeMenu,
+        target: &MenuTarget,
+        ids: &mut Vec<egui::Id>,
+    ) -> Retained<NSMenu> {
+        // SAFETY: we only touch the menus on the main thread,
+        // and `target` responds to the action we give the items.
+        unsafe {
+            let ns_menu = NSMenu::initWithTitle(mtm.alloc(), &NSString::from_str(&menu.title));
+
+            // Use our own `enabled` flags:
+            ns_menu.setAutoenablesItems(false);
+
+            for item in &menu.items {
+                let ns_item = match item {
+                    NativeMenuItem::Button(button) => {
+                        let key_equivalent = button
+                            .shortcut
+                            .and_then(|shortcut| key_equivalent(shortcut.logical_key))
+                            .unwrap_or_default();
+                        let ns_item = NSMenuItem::initWithTitle_action_keyEquivalent(
+                            mtm.alloc(),
+                            &NSString::from_str(&button.text),
+                            Some(sel!(menuItemClicked:)),
+                            &NSString::from_str(&key_equivalent),
+                        );
+                        if let Some(shortcut) = button.shortcut {
+                            ns_item
+                                .setKeyEquivalentModifierMask(modifier_flags(shortcut.modifiers));
+                        }
+                        ns_item.setTarget(Some(target));
+                        ns_item.setTag(ids.len() as isize);
+                        ns_item.setEnabled(button.enabled);
+                        if let Some(checked) = button.checked {
+                            ns_item.setState(isize::from(checked)); // NSControlStateValueOn == 1
+                        }
+                        ids.push(button.id);
+                        ns_item
+                    }
+                    NativeMenuItem::Separator => NSMenuItem::separatorItem(mtm),
+                    NativeMenuItem::SubMenu(sub_menu) => {
+                        let ns_item = NSMenuItem::initWithTitle_action_keyEquivalent(
+                            mtm.alloc(),
+                            &NSString::from_str(&sub_menu.title),
+                            None,
+                            &NSString::new(),
+                        );
+                        ns_item.setSubmenu(Some(&build_menu(mtm, sub_menu, target, ids)));
+                        ns_item
+                    }
+                };
+                ns_menu.addItem(&ns_item);
+            }
+
+            ns_menu
+        }
+    }
+
+    fn modifier_flags(modifiers: egui::Modifiers) -> NSEventModifierFlags {
+        let mut flags = NSEventModifierFlags::empty();
+        if modifiers.mac_cmd || modifiers.command {
+            flags |= NSEventModifierFlags::NSEventModifierFlagCommand;
+        }
+        if modifiers.ctrl {
+            flags |= NSEventModifierFlags::NSEventModifierFlagControl;
+        }
+        if modifiers.alt {
+            flags |= NSEventModifierFlags::NSEventModifierFlagOption;
+        }
+        if modifiers.shift {
+            flags |= NSEventModifierFlags::NSEventModifierFlagShift;
+        }
+        flags
+    }
+
+    /// The `keyEquivalent` of an `NSMenuItem` for this key, if it has one.
+    fn key_equivalent(key: egui::Key) -> Option<String> {
+        use egui::Key;
+
+        // See the `NS*FunctionKey` constants in `NSEvent.h`:
+        let function_key = |offset: u32| char::from_u32(0xF700 + offset).map(String::from);
+
+        match key {
+            Key::ArrowUp => function_key(0x00),
+            Key::ArrowDown => function_key(0x01),
+            Key::ArrowLeft => function_key(0x02),
+            Key::ArrowRight => function_key(0x03),
+            Key::F1 => function_key(0x04),
+            Key::F2 => function_key(0x05),
+            Key::F3 => function_key(0x06),
+            Key::F4 => function_key(0x07),
+            Key::F5 => function_key(0x08),
+            Key::F6 => function_key(0x09),
+            Key::F7 => function_key(0x0A),
+            Key::F8 => function_key(0x0B),
+            Key::F9 => function_key(0x0C),
+            Key::F10 => function_key(0x0D),
+            Key::F11 => function_key(0x0E),
+            Key::F12 => function_key(0x0F),
+            Key::Home => function_key(0x29),
+            Key::End => function_key(0x2B),
+            Key::PageUp => function_key(0x2C),
+            Key::PageDown => function_key(0x2D),
+            Key::Enter => Some("\r".to_owned()),
+            Key::Tab => Some("\t".to_owned()),
+            Key::Space => Some(" ".to_owned()),
+            Key::Escape => Some("\u{1b}".to_owned()),
+            Key::Backspace => Some("\u{8}".to_owned()),
+            Key::Delete => Some("\u{7f}".to_owned()),
+            Key::Minus => Some("-".to_owned()),
+            _ => {
+                // Letters, digits and punctuation.
+                // Lowercase, since the shift modifier is given separately.
+                let symbol = key.symbol_or_name();
+                (symbol.chars().count() == 1).then(|| symbol.to_lowercase())
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use winit::{
     application::ApplicationHandler,
@@ -67,6 +67,13 @@ fn with_event_loop<R>(
 /// some events, but otherwise forwards events to the [`WinitApp`].
 struct WinitAppWrapper<T: WinitApp> {
     windows_next_repaint_times: HashMap<WindowId, Instant>,
+
+    /// For [`egui::Context::set_max_repaint_rate_of`].
+    windows_last_redraw_times: HashMap<WindowId, Instant>,
+
+    /// For [`egui::ViewportCommand::MouseHitTestRegions`].
+    listening_to_all_device_events: bool,
+
     winit_app: T,
     return_result: Result<(), crate::Error>,
     run_and_return: bool,
@@ -76,6 +83,8 @@ impl<T: WinitApp> WinitAppWrapper<T> {
     fn new(winit_app: T, run_and_return: bool) -> Self {
         Self {
             windows_next_repaint_times: HashMap::default(),
+            windows_last_redraw_times: HashMap::default(),
+            listening_to_all_device_events: false,
             winit_app,
             return_result: Ok(()),
             run_and_return,
@@ -178,13 +187,26 @@ impl<T: WinitApp> WinitAppWrapper<T> {
                     return true; // not yet ready
                 };
 
+                if let (Some(min_interval), Some(last_redraw_time)) = (
+                    min_repaint_interval(&self.winit_app, *window_id),
+                    self.windows_last_redraw_times.get(window_id),
+                ) {
+                    let earliest = *last_redraw_time + min_interval;
+                    if now < earliest {
+                        *repaint_time = earliest;
+                        return true; // throttled
+                    }
+                }
+
                 event_loop.set_control_flow(ControlFlow::Poll);
 
                 if let Some(window) = self.winit_app.window(*window_id) {
                     log::trace!("request_redraw for {window_id:?}");
                     window.request_redraw();
+                    self.windows_last_redraw_times.insert(*window_id, now);
                 } else {
                     log::trace!("No window found for {window_id:?}");
+                    self.windows_last_redraw_times.remove(window_id);
                 }
                 false
             });
@@ -194,6 +216,32 @@ impl<T: WinitApp> WinitAppWrapper<T> {
             event_loop.set_control_flow(ControlFlow::WaitUntil(next_repaint_time));
         };
     }
+
+    /// Windows with click-through parts need to hear about the mouse moving even when they are
+    /// not focused, see [`egui_winit::restore_mouse_hit_test`].
+    fn listen_for_mouse_hit_test(&mut self, event_loop: &ActiveEventLoop) {
+        if !self.listening_to_all_device_events
+            && self
+                .winit_app
+                .egui_ctx()
+                .is_some_and(egui_winit::uses_mouse_hit_test)
+        {
+            event_loop.listen_device_events(winit::event_loop::DeviceEvents::Always);
+            self.listening_to_all_device_events = true;
+        }
+    }
+}
+
+/// The shortest time between two repaints of a window,
+/// as set by [`egui::Context::set_max_repaint_rate_of`].
+fn min_repaint_interval(winit_app: &impl WinitApp, window_id: WindowId) -> Option<Duration> {
+    let viewport_id = winit_app.viewport_id_from_window_id(window_id)?;
+    let hz = winit_app.egui_ctx()?.max_repaint_rate_of(viewport_id);
+    if 0.0 < hz {
+        Duration::try_from_secs_f32(1.0 / hz).ok()
+    } else {
+        None
+    }
 }
 
 impl<T: WinitApp> ApplicationHandler<UserEvent> for WinitAppWrapper<T> {
@@ -303,7 +351,9 @@ impl<T: WinitApp> ApplicationHandler<UserEvent> for WinitAppWrapper<T> {
         event_loop_context::with_event_loop_context(event_loop, move || {
             let event_result = match event {
                 winit::event::WindowEvent::RedrawRequested => {
-                    self.winit_app.run_ui_and_paint(event_loop, window_id)
+                    let event_result = self.winit_app.run_ui_and_paint(event_loop, window_id);
+                    self.listen_for_mouse_hit_test(event_loop);
+                    event_result
                 }
                 _ => self.winit_app.window_event(event_loop, window_id, event),
             };
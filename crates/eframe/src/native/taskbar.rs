@@ -0,0 +1,174 @@
+//! [`egui::ViewportCommand::SetTaskbarProgress`] and [`egui::ViewportCommand::SetBadge`].
+//!
+//! winit doesn't support these, so we talk to the OS ourselves:
+//! * Windows: `ITaskbarList3` (progress only)
+//! * macOS: the dock tile (badge only)
+//! * Linux: the Unity launcher API over D-Bus, which many docks implement
+
+use std::sync::OnceLock;
+
+use egui::ViewportCommand;
+
+/// Used to identify the app to the Unity launcher API.
+static APP_ID: OnceLock<String> = OnceLock::new();
+
+/// Called once on startup.
+pub fn init(app_id: &str) {
+    APP_ID.get_or_init(|| app_id.to_owned());
+}
+
+/// Apply the commands this module cares about, ignoring the rest.
+pub fn process_viewport_commands<'a>(
+    window: &winit::window::Window,
+    commands: impl IntoIterator<Item = &'a ViewportCommand>,
+) {
+    for command in commands {
+        match command {
+            ViewportCommand::SetTaskbarProgress(progress) => {
+                set_progress(window, progress.map(|p| p.clamp(0.0, 1.0)));
+            }
+            ViewportCommand::SetBadge(badge) => {
+                set_badge(badge.as_deref());
+            }
+            _ => {}
+        }
+    }
+}
+
+fn set_progress(_window: &winit::window::Window, _progress: Option<f32>) {
+    profiling::function_scope!();
+
+    #[cfg(target_os = "windows")]
+    if let Err(err) = win::set_progress(_window, _progress) {
+        log::warn!("Failed to set the taskbar progress: {err}");
+    }
+
+    #[cfg(target_os = "linux")]
+    unity::update(unity::Update::Progress(_progress));
+}
+
+fn set_badge(_badge: Option<&str>) {
+    profiling::function_scope!();
+
+    #[cfg(target_os = "macos")]
+    mac::set_badge(_badge);
+
+    #[cfg(target_os = "linux")]
+    match _badge.map(|badge| badge.trim().parse::<i64>()) {
+        None => unity::update(unity::Update::Count(None)),
+        Some(Ok(count)) => unity::update(unity::Update::Count(Some(count))),
+        Some(Err(_)) => log::debug!("The Unity launcher API only supports numeric badges"),
+    }
+}
+
+#[cfg(target_os = "windows")]
+#[expect(unsafe_code)]
+mod win {
+    use raw_window_handle::{HasWindowHandle as _, RawWindowHandle};
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::System::Com::{CLSCTX_INPROC_SERVER, CoCreateInstance};
+    use windows::Win32::UI::Shell::{ITaskbarList3, TBPF_NOPROGRESS, TBPF_NORMAL, TaskbarList};
+
+    pub fn set_progress(
+        window: &winit::window::Window,
+        progress: Option<f32>,
+    ) -> windows::core::Result<()> {
+        let Ok(RawWindowHandle::Win32(handle)) = window.window_handle().map(|h| h.as_raw()) else {
+            return Ok(());
+        };
+        let hwnd = HWND(handle.hwnd.get() as _);
+
+        const TOTAL: u64 = 1000;
+
+        // SAFETY: `hwnd` is a valid window handle, and winit has initialized COM on this thread.
+        unsafe {
+            let taskbar: ITaskbarList3 =
+                CoCreateInstance(&TaskbarList, None, CLSCTX_INPROC_SERVER)?;
+            taskbar.HrInit()?;
+            if let Some(progress) = progress {
+                taskbar.SetProgressState(hwnd, TBPF_NORMAL)?;
+                taskbar.SetProgressValue(hwnd, (progress * TOTAL as f32).round() as u64, TOTAL)
+            } else {
+                taskbar.SetProgressState(hwnd, TBPF_NOPROGRESS)
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+#[expect(unsafe_code)]
+mod mac {
+    use objc2_app_kit::NSApplication;
+    use objc2_foundation::{MainThreadMarker, NSString};
+
+    pub fn set_badge(badge: Option<&str>) {
+        let Some(mtm) = MainThreadMarker::new() else {
+            log::warn!("The dock badge can only be set from the main thread");
+            return;
+        };
+        let label = badge.map(NSString::from_str);
+
+        // SAFETY: we are on the main thread.
+        unsafe {
+            NSApplication::sharedApplication(mtm)
+                .dockTile()
+                .setBadgeLabel(label.as_deref());
+        }
+    }
+}
+
+/// <https://wiki.ubuntu.com/Unity/LauncherAPI>
+#[cfg(target_os = "linux")]
+mod unity {
+    use std::collections::HashMap;
+    use std::sync::OnceLock;
+
+    use zbus::zvariant::Value;
+
+    pub enum Update {
+        Progress(Option<f32>),
+        Count(Option<i64>),
+    }
+
+    fn connection() -> Option<&'static zbus::blocking::Connection> {
+        static CONNECTION: OnceLock<Option<zbus::blocking::Connection>> = OnceLock::new();
+        CONNECTION
+            .get_or_init(|| {
+                zbus::blocking::Connection::session()
+                    .inspect_err(|err| log::debug!("No D-Bus session bus: {err}"))
+                    .ok()
+            })
+            .as_ref()
+    }
+
+    pub fn update(update: Update) {
+        let Some(connection) = connection() else {
+            return;
+        };
+
+        let app_id = super::APP_ID.get().map_or("eframe", |id| id.as_str());
+        let app_uri = format!("application://{app_id}.desktop");
+
+        let mut properties: HashMap<&str, Value<'_>> = HashMap::new();
+        match update {
+            Update::Progress(progress) => {
+                properties.insert("progress", f64::from(progress.unwrap_or(0.0)).into());
+                properties.insert("progress-visible", progress.is_some().into());
+            }
+            Update::Count(count) => {
+                properties.insert("count", count.unwrap_or(0).into());
+                properties.insert("count-visible", count.is_some().into());
+            }
+        }
+
+        if let Err(err) = connection.emit_signal(
+            None::<&str>,
+            "/com/canonical/unity/launcherentry/eframe",
+            "com.canonical.Unity.LauncherEntry",
+            "Update",
+            &(app_uri, properties),
+        ) {
+            log::debug!("Failed to update the Unity launcher entry: {err}");
+        }
+    }
+}
@@ -354,6 +354,12 @@ impl WinitApp for WgpuWinitApp<'_> {
         )
     }
 
+    fn viewport_id_from_window_id(&self, id: WindowId) -> Option<ViewportId> {
+        self.running
+            .as_ref()
+            .and_then(|r| r.shared.borrow().viewport_from_window.get(&id).copied())
+    }
+
     fn save(&mut self) {
         log::debug!("WinitApp::save called");
         if let Some(running) = self.running.as_mut() {
@@ -433,6 +439,12 @@ impl WinitApp for WgpuWinitApp<'_> {
         if let winit::event::DeviceEvent::MouseMotion { delta } = event {
             if let Some(running) = &mut self.running {
                 let mut shared = running.shared.borrow_mut();
+                for viewport in shared.viewports.values() {
+                    if let Some(window) = &viewport.window {
+                        egui_winit::restore_mouse_hit_test(&running.integration.egui_ctx, window);
+                    }
+                }
+
                 if let Some(viewport) = shared
                     .focused_viewport
                     .and_then(|viewport| shared.viewports.get_mut(&viewport))
@@ -577,6 +589,7 @@ impl WgpuWinitRunning<'_> {
             };
 
             let Viewport {
+                builder,
                 viewport_ui_cb,
                 window,
                 egui_winit,
@@ -590,6 +603,13 @@ impl WgpuWinitRunning<'_> {
                 return Ok(EventResult::Wait);
             };
             egui_winit::update_viewport_info(info, &integration.egui_ctx, window, false);
+            super::workspace::update_viewport_info(info, window);
+            epi_integration::remember_viewport_window(
+                &integration.egui_ctx,
+                viewport_id,
+                builder,
+                window,
+            );
 
             {
                 profiling::scope!("set_window");
@@ -638,6 +658,7 @@ impl WgpuWinitRunning<'_> {
             shapes,
             pixels_per_point,
             viewport_output,
+            stats: _,
         } = full_output;
 
         remove_viewports_not_in(viewports, painter, viewport_from_window, &viewport_output);
@@ -795,6 +816,23 @@ impl WgpuWinitRunning<'_> {
                 }
             }
 
+            winit::event::WindowEvent::Moved(_) => {
+                if let Some(viewport_id) = viewport_id {
+                    if let Some(window) = shared
+                        .viewports
+                        .get(&viewport_id)
+                        .and_then(|viewport| viewport.window.as_deref())
+                    {
+                        let others = shared
+                            .viewports
+                            .iter()
+                            .filter(|(id, _)| **id != viewport_id)
+                            .map(|(_, viewport)| &viewport.info);
+                        integration.snap_viewport(window, others);
+                    }
+                }
+            }
+
             winit::event::WindowEvent::CloseRequested => {
                 if viewport_id == Some(ViewportId::ROOT) && integration.should_close() {
                     log::debug!(
@@ -865,8 +903,24 @@ impl Viewport {
 
         let viewport_id = self.ids.this;
 
-        match egui_winit::create_window(egui_ctx, event_loop, &self.builder) {
+        let window_settings = epi_integration::persisted_viewport_window_settings(
+            egui_ctx,
+            event_loop,
+            viewport_id,
+            &self.builder,
+        );
+        let builder = match &window_settings {
+            Some(window_settings) => window_settings.initialize_viewport_builder(
+                egui_ctx.zoom_factor(),
+                event_loop,
+                self.builder.clone(),
+            ),
+            None => self.builder.clone(),
+        };
+
+        match egui_winit::create_window(egui_ctx, event_loop, &builder) {
             Ok(window) => {
+                epi_integration::apply_window_settings(&window, window_settings);
                 windows_id.insert(window.id(), viewport_id);
 
                 let window = Arc::new(window);
@@ -958,6 +1012,8 @@ fn render_immediate_viewport(
             return;
         };
         egui_winit::update_viewport_info(&mut viewport.info, egui_ctx, window, false);
+        super::workspace::update_viewport_info(&mut viewport.info, window);
+        epi_integration::remember_viewport_window(egui_ctx, ids.this, &viewport.builder, window);
 
         let mut input = egui_winit.take_egui_input(window);
         input.viewports = viewports
@@ -980,6 +1036,7 @@ fn render_immediate_viewport(
         shapes,
         pixels_per_point,
         viewport_output,
+        stats: _,
     } = egui_ctx.run(input, |ctx| {
         viewport_ui_cb(ctx);
     });
@@ -1077,6 +1134,9 @@ fn handle_viewport_output(
 
             viewport.deferred_commands.append(&mut commands);
 
+            super::taskbar::process_viewport_commands(window, &viewport.deferred_commands);
+            super::workspace::process_viewport_commands(window, &viewport.deferred_commands);
+
             egui_winit::process_viewport_commands(
                 egui_ctx,
                 &mut viewport.info,
@@ -0,0 +1,219 @@
+//! [`egui::ViewportInfo::workspace`] and [`egui::ViewportCommand::MoveToWorkspace`].
+//!
+//! winit doesn't know about virtual desktops, so we talk to the OS ourselves:
+//! * Windows: `IVirtualDesktopManager`
+//! * Linux: `_NET_WM_DESKTOP` on X11 (with the `x11` feature)
+//!
+//! Not supported on macOS, where apps can't control which Space a window is on,
+//! nor on Wayland, which has no protocol for it.
+
+use egui::{ViewportCommand, ViewportInfo, Workspace};
+
+/// Called each frame to fill in [`ViewportInfo::workspace`].
+pub fn update_viewport_info(_info: &mut ViewportInfo, _window: &winit::window::Window) {
+    profiling::function_scope!();
+
+    #[cfg(target_os = "windows")]
+    match win::workspace(_window) {
+        Ok(workspace) => _info.workspace = workspace,
+        Err(err) => log::debug!("Failed to get the virtual desktop of the window: {err}"),
+    }
+
+    #[cfg(all(target_os = "linux", feature = "x11"))]
+    match x11::workspace(_window) {
+        Ok(workspace) => _info.workspace = workspace,
+        Err(err) => log::debug!("Failed to get the workspace of the window: {err}"),
+    }
+}
+
+/// Apply the commands this module cares about, ignoring the rest.
+pub fn process_viewport_commands<'a>(
+    window: &winit::window::Window,
+    commands: impl IntoIterator<Item = &'a ViewportCommand>,
+) {
+    for command in commands {
+        if let ViewportCommand::MoveToWorkspace(workspace) = command {
+            move_to_workspace(window, *workspace);
+        }
+    }
+}
+
+fn move_to_workspace(_window: &winit::window::Window, workspace: Workspace) {
+    profiling::function_scope!();
+
+    #[cfg(target_os = "windows")]
+    if let Err(err) = win::move_to_workspace(_window, workspace) {
+        log::warn!("Failed to move the window to {workspace:?}: {err}");
+    }
+
+    #[cfg(all(target_os = "linux", feature = "x11"))]
+    if let Err(err) = x11::move_to_workspace(_window, workspace) {
+        log::warn!("Failed to move the window to {workspace:?}: {err}");
+    }
+
+    #[cfg(not(any(target_os = "windows", all(target_os = "linux", feature = "x11"))))]
+    log::debug!("Moving windows to {workspace:?} is not supported on this platform");
+}
+
+#[cfg(target_os = "windows")]
+#[expect(unsafe_code)]
+mod win {
+    use egui::Workspace;
+    use raw_window_handle::{HasWindowHandle as _, RawWindowHandle};
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::System::Com::{CLSCTX_ALL, CoCreateInstance};
+    use windows::Win32::UI::Shell::{IVirtualDesktopManager, VirtualDesktopManager};
+    use windows::core::GUID;
+
+    thread_local! {
+        static MANAGER: Option<IVirtualDesktopManager> =
+            // SAFETY: winit has initialized COM on this thread.
+            unsafe { CoCreateInstance(&VirtualDesktopManager, None, CLSCTX_ALL) }
+                .inspect_err(|err| log::debug!("No virtual desktop manager: {err}"))
+                .ok();
+    }
+
+    fn hwnd(window: &winit::window::Window) -> Option<HWND> {
+        match window.window_handle().map(|h| h.as_raw()) {
+            Ok(RawWindowHandle::Win32(handle)) => Some(HWND(handle.hwnd.get() as _)),
+            _ => None,
+        }
+    }
+
+    pub fn workspace(window: &winit::window::Window) -> windows::core::Result<Option<Workspace>> {
+        let Some(hwnd) = hwnd(window) else {
+            return Ok(None);
+        };
+        MANAGER.with(|manager| {
+            let Some(manager) = manager else {
+                return Ok(None);
+            };
+            // SAFETY: `hwnd` is a valid window handle.
+            let guid = unsafe { manager.GetWindowDesktopId(hwnd)? };
+            // A window that is not shown yet has no desktop:
+            Ok((guid != GUID::zeroed()).then_some(Workspace::Guid(guid.to_u128())))
+        })
+    }
+
+    pub fn move_to_workspace(
+        window: &winit::window::Window,
+        workspace: Workspace,
+    ) -> windows::core::Result<()> {
+        let Workspace::Guid(guid) = workspace else {
+            log::debug!("Windows identifies virtual desktops by GUID, not {workspace:?}");
+            return Ok(());
+        };
+        let Some(hwnd) = hwnd(window) else {
+            return Ok(());
+        };
+        MANAGER.with(|manager| {
+            let Some(manager) = manager else {
+                return Ok(());
+            };
+            // SAFETY: `hwnd` is a valid window handle.
+            // Windows only lets us move windows of our own process, which this is.
+            unsafe { manager.MoveWindowToDesktop(hwnd, &GUID::from_u128(guid)) }
+        })
+    }
+}
+
+/// <https://specifications.freedesktop.org/wm-spec/latest/ar01s05.html#id-1.6.7>
+#[cfg(all(target_os = "linux", feature = "x11"))]
+mod x11 {
+    use egui::Workspace;
+    use raw_window_handle::{HasWindowHandle as _, RawWindowHandle};
+    use x11rb::connection::Connection as _;
+    use x11rb::protocol::xproto::{
+        Atom, AtomEnum, ClientMessageEvent, ConnectionExt as _, EventMask, Window,
+    };
+    use x11rb::rust_connection::RustConnection;
+
+    type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+    /// `_NET_WM_DESKTOP` uses this for windows on all desktops.
+    const ALL_DESKTOPS: u32 = 0xFFFF_FFFF;
+
+    /// Our own connection to the X server, since winit doesn't share its own.
+    struct X11 {
+        conn: RustConnection,
+        root: Window,
+        net_wm_desktop: Atom,
+    }
+
+    thread_local! {
+        static X11: Option<X11> = connect()
+            .inspect_err(|err| log::debug!("Failed to connect to the X server: {err}"))
+            .ok();
+    }
+
+    fn connect() -> Result<X11> {
+        let (conn, screen) = x11rb::connect(None)?;
+        let root = conn.setup().roots[screen].root;
+        let net_wm_desktop = conn.intern_atom(false, b"_NET_WM_DESKTOP")?.reply()?.atom;
+        Ok(X11 {
+            conn,
+            root,
+            net_wm_desktop,
+        })
+    }
+
+    /// `None` on Wayland.
+    fn x11_window(window: &winit::window::Window) -> Option<Window> {
+        match window.window_handle().map(|h| h.as_raw()) {
+            Ok(RawWindowHandle::Xlib(handle)) => Some(handle.window as Window),
+            Ok(RawWindowHandle::Xcb(handle)) => Some(handle.window.get()),
+            _ => None,
+        }
+    }
+
+    pub fn workspace(window: &winit::window::Window) -> Result<Option<Workspace>> {
+        let Some(window) = x11_window(window) else {
+            return Ok(None);
+        };
+        X11.with(|x11| {
+            let Some(x11) = x11 else {
+                return Ok(None);
+            };
+            let reply = x11
+                .conn
+                .get_property(false, window, x11.net_wm_desktop, AtomEnum::CARDINAL, 0, 1)?
+                .reply()?;
+            let desktop = reply.value32().and_then(|mut values| values.next());
+            Ok(desktop.map(|desktop| match desktop {
+                ALL_DESKTOPS => Workspace::All,
+                index => Workspace::Index(index),
+            }))
+        })
+    }
+
+    pub fn move_to_workspace(window: &winit::window::Window, workspace: Workspace) -> Result<()> {
+        let desktop = match workspace {
+            Workspace::Index(index) => index,
+            Workspace::All => ALL_DESKTOPS,
+            Workspace::Guid(_) => {
+                log::debug!("X11 identifies workspaces by index, not {workspace:?}");
+                return Ok(());
+            }
+        };
+        let Some(window) = x11_window(window) else {
+            return Ok(());
+        };
+        X11.with(|x11| {
+            let Some(x11) = x11 else {
+                return Ok(());
+            };
+            // Ask the window manager, like a pager would.
+            // The `1` says the request comes from a normal application.
+            let data = [desktop, 1, 0, 0, 0];
+            let event = ClientMessageEvent::new(32, window, x11.net_wm_desktop, data);
+            x11.conn.send_event(
+                false,
+                x11.root,
+                EventMask::SUBSTRUCTURE_REDIRECT | EventMask::SUBSTRUCTURE_NOTIFY,
+                event,
+            )?;
+            x11.conn.flush()?;
+            Ok(())
+        })
+    }
+}
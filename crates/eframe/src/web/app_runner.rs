@@ -41,6 +41,7 @@ impl AppRunner {
         text_agent: TextAgent,
     ) -> Result<Self, String> {
         let egui_ctx = egui::Context::default();
+        egui_ctx.set_task_spawner(wasm_bindgen_futures::spawn_local);
         let painter = super::ActiveWebPainter::new(egui_ctx.clone(), canvas, &web_options).await?;
 
         let info = epi::IntegrationInfo {
@@ -215,6 +216,7 @@ impl AppRunner {
 
         let canvas_size = super::canvas_size_in_points(self.canvas(), self.egui_ctx());
         let mut raw_input = self.input.new_frame(canvas_size);
+        raw_input.monitors = super::monitors(self.egui_ctx());
 
         if super::DEBUG_RESIZE {
             log::info!(
@@ -239,6 +241,7 @@ impl AppRunner {
             shapes,
             pixels_per_point,
             viewport_output,
+            stats: _,
         } = full_output;
 
         if viewport_output.len() > 1 {
@@ -301,6 +304,7 @@ impl AppRunner {
 
     pub fn report_frame_time(&mut self, cpu_usage_seconds: f32) {
         self.frame.info.cpu_usage = Some(cpu_usage_seconds);
+        self.egui_ctx.report_frame_time(cpu_usage_seconds);
     }
 
     fn handle_platform_output(&self, platform_output: egui::PlatformOutput) {
@@ -323,6 +327,7 @@ impl AppRunner {
                 accesskit_update: _, // not currently implemented
             num_completed_passes: _,    // handled by `Context::run`
             request_discard_reasons: _, // handled by `Context::run`
+            native_menu_bar: _,         // there is no native menu bar on web
         } = platform_output;
 
         for command in commands {
@@ -333,9 +338,28 @@ impl AppRunner {
                 egui::OutputCommand::CopyImage(image) => {
                     super::set_clipboard_image(&image);
                 }
+                egui::OutputCommand::CopyHtml { html, text } => {
+                    super::set_clipboard_html(&html, &text);
+                }
                 egui::OutputCommand::OpenUrl(open_url) => {
                     super::open_url(&open_url.url, open_url.new_tab);
                 }
+                egui::OutputCommand::Speak(speech) => {
+                    #[cfg(feature = "tts")]
+                    super::screen_reader::speak_speech(&speech);
+
+                    #[cfg(not(feature = "tts"))]
+                    log::warn!(
+                        "Cannot speak {:?} - feature \"tts\" not enabled.",
+                        speech.text
+                    );
+                }
+                egui::OutputCommand::HapticFeedback(kind) => {
+                    super::haptic_feedback(kind);
+                }
+                egui::OutputCommand::Beep => {
+                    log::debug!("Beeping is not supported on web");
+                }
             }
         }
 
@@ -636,6 +636,9 @@ fn install_mousemove(runner_ref: &WebRunner, target: &EventTarget) -> Result<(),
             let should_stop_propagation = (runner.web_options.should_stop_propagation)(&egui_event);
             let should_prevent_default = (runner.web_options.should_prevent_default)(&egui_event);
             runner.input.raw.events.push(egui_event);
+            // In the same time base as `super::now_sec`:
+            let time = event.time_stamp() / 1000.0;
+            runner.input.raw.pointer_samples.push((time, pos));
             runner.needs_repaint.repaint_asap();
 
             // Use web options to tell if the web event should be propagated to parent elements based on the egui event.
@@ -717,6 +720,8 @@ fn install_touchmove(runner_ref: &WebRunner, target: &EventTarget) -> Result<(),
                 let should_prevent_default =
                     (runner.web_options.should_prevent_default)(&egui_event);
                 runner.input.raw.events.push(egui_event);
+                let time = event.time_stamp() / 1000.0;
+                runner.input.raw.pointer_samples.push((time, pos));
 
                 push_touches(runner, egui::TouchPhase::Move, &event);
                 runner.needs_repaint.repaint_asap();
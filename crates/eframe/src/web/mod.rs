@@ -12,7 +12,7 @@ mod web_logger;
 mod web_runner;
 
 /// Access to the browser screen reader.
-#[cfg(feature = "web_screen_reader")]
+#[cfg(any(feature = "tts", feature = "web_screen_reader"))]
 pub mod screen_reader;
 
 /// Access to local browser storage.
@@ -110,6 +110,34 @@ pub fn native_pixels_per_point() -> f32 {
     }
 }
 
+/// The screen the browser window is on, as far as the browser tells us.
+///
+/// The browser doesn't tell us where the screen is, so it is always placed at the origin.
+pub fn monitors(ctx: &egui::Context) -> Vec<egui::MonitorInfo> {
+    let Some(screen) = web_sys::window().and_then(|window| window.screen().ok()) else {
+        return vec![];
+    };
+
+    // The screen size is in CSS pixels:
+    let zoom_factor = ctx.zoom_factor();
+    let rect = |width: Result<i32, JsValue>, height: Result<i32, JsValue>| {
+        let size = egui::vec2(width.ok()? as f32, height.ok()? as f32) / zoom_factor;
+        Some(egui::Rect::from_min_size(egui::Pos2::ZERO, size))
+    };
+
+    let Some(screen_rect) = rect(screen.width(), screen.height()) else {
+        return vec![];
+    };
+
+    vec![egui::MonitorInfo {
+        name: None,
+        rect: screen_rect,
+        work_rect: rect(screen.avail_width(), screen.avail_height()),
+        native_pixels_per_point: Some(native_pixels_per_point()),
+        video_modes: vec![],
+    }]
+}
+
 /// Ask the browser about the preferred system theme.
 ///
 /// `None` means unknown.
@@ -232,7 +260,7 @@ fn set_clipboard_image(image: &egui::ColorImage) {
 
         let mime = "image/png";
 
-        let item = match create_clipboard_item(mime, &png_bytes) {
+        let item = match create_clipboard_item(&[(mime, png_bytes.as_slice())]) {
             Ok(item) => item,
             Err(err) => {
                 log::error!("Failed to copy image: {}", string_from_js_value(&err));
@@ -254,6 +282,37 @@ fn set_clipboard_image(image: &egui::ColorImage) {
     }
 }
 
+/// Set the clipboard to `html`, with `text` for apps that can't paste HTML.
+fn set_clipboard_html(html: &str, text: &str) {
+    if let Some(window) = web_sys::window() {
+        if !window.is_secure_context() {
+            log::error!(
+                "Clipboard is not available because we are not in a secure context. \
+                See https://developer.mozilla.org/en-US/docs/Web/Security/Secure_Contexts"
+            );
+            return;
+        }
+
+        let representations = [("text/html", html.as_bytes()), ("text/plain", text.as_bytes())];
+        let item = match create_clipboard_item(&representations) {
+            Ok(item) => item,
+            Err(err) => {
+                log::error!("Failed to copy html: {}", string_from_js_value(&err));
+                return;
+            }
+        };
+        let items = js_sys::Array::of1(&item);
+        let promise = window.navigator().clipboard().write(&items);
+        let future = wasm_bindgen_futures::JsFuture::from(promise);
+        let future = async move {
+            if let Err(err) = future.await {
+                log::error!("Copy/cut action failed: {}", string_from_js_value(&err));
+            }
+        };
+        wasm_bindgen_futures::spawn_local(future);
+    }
+}
+
 fn to_image(image: &egui::ColorImage) -> Result<image::RgbaImage, String> {
     profiling::function_scope!();
     image::RgbaImage::from_raw(
@@ -276,23 +335,28 @@ fn to_png_bytes(image: &image::RgbaImage) -> Result<Vec<u8>, String> {
     Ok(png_bytes)
 }
 
-fn create_clipboard_item(mime: &str, bytes: &[u8]) -> Result<web_sys::ClipboardItem, JsValue> {
-    let array = js_sys::Uint8Array::from(bytes);
-    let blob_parts = js_sys::Array::new();
-    blob_parts.push(&array);
+/// A clipboard item with the same content in each of the given mime types.
+fn create_clipboard_item(
+    representations: &[(&str, &[u8])],
+) -> Result<web_sys::ClipboardItem, JsValue> {
+    let items = js_sys::Object::new();
 
-    let options = web_sys::BlobPropertyBag::new();
-    options.set_type(mime);
+    for &(mime, bytes) in representations {
+        let array = js_sys::Uint8Array::from(bytes);
+        let blob_parts = js_sys::Array::new();
+        blob_parts.push(&array);
 
-    let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(&blob_parts, &options)?;
+        let options = web_sys::BlobPropertyBag::new();
+        options.set_type(mime);
 
-    let items = js_sys::Object::new();
+        let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(&blob_parts, &options)?;
 
-    // SAFETY: I hope so
-    #[expect(unsafe_code, unused_unsafe)] // Weird false positive
-    unsafe {
-        js_sys::Reflect::set(&items, &JsValue::from_str(mime), &blob)?
-    };
+        // SAFETY: I hope so
+        #[expect(unsafe_code, unused_unsafe)] // Weird false positive
+        unsafe {
+            js_sys::Reflect::set(&items, &JsValue::from_str(mime), &blob)?
+        };
+    }
 
     let clipboard_item = web_sys::ClipboardItem::new_with_record_from_str_to_blob_promise(&items)?;
 
@@ -351,6 +415,26 @@ pub fn open_url(url: &str, new_tab: bool) -> Option<()> {
     Some(())
 }
 
+/// Vibrate the device, if it has a vibration motor and the browser supports it.
+fn haptic_feedback(kind: egui::HapticKind) -> Option<()> {
+    use egui::HapticKind;
+
+    // Vibration patterns, in milliseconds of alternating on and off:
+    let pattern: &[u32] = match kind {
+        HapticKind::Selection => &[5],
+        HapticKind::Light => &[10],
+        HapticKind::Medium => &[20],
+        HapticKind::Heavy => &[40],
+        HapticKind::Success => &[10, 60, 10],
+        HapticKind::Warning => &[20, 80, 20],
+        HapticKind::Error => &[30, 60, 30, 60, 30],
+    };
+    let pattern: js_sys::Array = pattern.iter().map(|&ms| JsValue::from(ms)).collect();
+
+    web_sys::window()?.navigator().vibrate_with_pattern(&pattern);
+    Some(())
+}
+
 /// e.g. "#fragment" part of "www.example.com/index.html#fragment",
 ///
 /// Percent decoded
@@ -1,5 +1,21 @@
+use wasm_bindgen::JsCast as _;
+
 /// Speak the given text out loud.
 pub fn speak(text: &str) {
+    speak_utterance(text, 1.0, None, true);
+}
+
+/// Speak the given [`egui::Speech`] out loud, with its rate and voice.
+pub fn speak_speech(speech: &egui::Speech) {
+    speak_utterance(
+        &speech.text,
+        speech.rate,
+        speech.voice.as_deref(),
+        speech.interrupt,
+    );
+}
+
+fn speak_utterance(text: &str, rate: f32, voice: Option<&str>, interrupt: bool) {
     if text.is_empty() {
         return;
     }
@@ -8,12 +24,27 @@ pub fn speak(text: &str) {
         log::debug!("Speaking {text:?}");
 
         if let Ok(speech_synthesis) = window.speech_synthesis() {
-            speech_synthesis.cancel(); // interrupt previous speech, if any
+            if interrupt {
+                speech_synthesis.cancel(); // interrupt previous speech, if any
+            }
 
             if let Ok(utterance) = web_sys::SpeechSynthesisUtterance::new_with_text(text) {
-                utterance.set_rate(1.0);
+                utterance.set_rate(rate);
                 utterance.set_pitch(1.0);
                 utterance.set_volume(1.0);
+
+                if let Some(voice_name) = voice {
+                    let voice = speech_synthesis
+                        .get_voices()
+                        .iter()
+                        .filter_map(|voice| voice.dyn_into::<web_sys::SpeechSynthesisVoice>().ok())
+                        .find(|voice| voice.name() == voice_name);
+                    if voice.is_none() {
+                        log::debug!("No text-to-speech voice named {voice_name:?}");
+                    }
+                    utterance.set_voice(voice.as_ref());
+                }
+
                 speech_synthesis.speak(&utterance);
             }
         }
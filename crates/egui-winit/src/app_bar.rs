@@ -0,0 +1,156 @@
+//! Docking windows to an edge of the monitor like a taskbar, for [`egui::ViewportCommand::AppBar`].
+//!
+//! * On Windows we register the window as an appbar with `SHAppBarMessage`,
+//!   which reserves its space so that other windows are not maximized over it.
+//! * Elsewhere we can only move the window to the edge and keep it on top.
+
+use egui::{AppBar, ScreenEdge};
+use winit::{
+    dpi::{PhysicalPosition, PhysicalSize},
+    window::{Window, WindowLevel},
+};
+
+fn app_bar_id(window: &Window) -> egui::Id {
+    egui::Id::new(("egui_winit_app_bar", window.id()))
+}
+
+/// Dock the window to an edge of its monitor, or undock it with `None`.
+pub(crate) fn set_app_bar(
+    egui_ctx: &egui::Context,
+    window: &Window,
+    app_bar: Option<AppBar>,
+    pixels_per_point: f32,
+) {
+    let id = app_bar_id(window);
+    let Some(app_bar) = app_bar else {
+        #[cfg(target_os = "windows")]
+        if egui_ctx.data(|data| data.get_temp::<AppBar>(id)).is_some() {
+            win::remove(window);
+        }
+        egui_ctx.data_mut(|data| data.remove::<AppBar>(id));
+        return;
+    };
+
+    let Some(monitor) = window.current_monitor() else {
+        log::warn!("Can't dock the window to an edge of an unknown monitor");
+        return;
+    };
+    let (position, size) = (monitor.position(), monitor.size());
+    let monitor_rect = [
+        position.x,
+        position.y,
+        position.x + size.width as i32,
+        position.y + size.height as i32,
+    ];
+    let thickness = (app_bar.thickness * pixels_per_point).round() as i32;
+    let rect = edge_rect(monitor_rect, app_bar.edge, thickness);
+
+    #[cfg(target_os = "windows")]
+    let rect = win::reserve(window, rect, app_bar.edge, thickness);
+
+    egui_ctx.data_mut(|data| data.insert_temp(id, app_bar));
+
+    let [left, top, right, bottom] = rect;
+    window.set_decorations(false);
+    window.set_window_level(WindowLevel::AlwaysOnTop);
+    window.set_outer_position(PhysicalPosition::new(left, top));
+    let size = PhysicalSize::new((right - left).max(1) as u32, (bottom - top).max(1) as u32);
+    if window.request_inner_size(size).is_some() {
+        log::debug!("Failed to set the size of the appbar");
+    }
+}
+
+/// Give back the reserved space of a docked window that is closing.
+pub(crate) fn on_destroyed(egui_ctx: &egui::Context, window: &Window) {
+    set_app_bar(egui_ctx, window, None, 1.0);
+}
+
+/// The part of the `monitor` that the appbar covers.
+///
+/// Rects are `[left, top, right, bottom]` in physical pixels.
+fn edge_rect(monitor: [i32; 4], edge: ScreenEdge, thickness: i32) -> [i32; 4] {
+    let [left, top, right, bottom] = monitor;
+    match edge {
+        ScreenEdge::Left => [left, top, left + thickness, bottom],
+        ScreenEdge::Top => [left, top, right, top + thickness],
+        ScreenEdge::Right => [right - thickness, top, right, bottom],
+        ScreenEdge::Bottom => [left, bottom - thickness, right, bottom],
+    }
+}
+
+#[cfg(target_os = "windows")]
+#[expect(unsafe_code)]
+mod win {
+    use egui::ScreenEdge;
+    use raw_window_handle::{HasWindowHandle as _, RawWindowHandle};
+    use winapi::shared::windef::{HWND, RECT};
+    use winapi::um::{shellapi, winuser};
+    use winit::window::Window;
+
+    /// Sent to the window when other appbars or the taskbar change.
+    ///
+    /// winit doesn't pass custom messages on, so we can't react to it,
+    /// but registering an appbar requires one.
+    const CALLBACK_MESSAGE: u32 = winuser::WM_USER + 0xAB;
+
+    fn app_bar_data(window: &Window) -> Option<shellapi::APPBARDATA> {
+        let Ok(RawWindowHandle::Win32(handle)) = window.window_handle().map(|h| h.as_raw()) else {
+            return None;
+        };
+        // SAFETY: `APPBARDATA` is plain data, for which all zeroes is valid.
+        let mut data: shellapi::APPBARDATA = unsafe { std::mem::zeroed() };
+        data.cbSize = std::mem::size_of::<shellapi::APPBARDATA>() as u32;
+        data.hWnd = handle.hwnd.get() as HWND;
+        Some(data)
+    }
+
+    /// Register the window as an appbar covering `rect`, and return the rect the system
+    /// gives it, which is moved out of the way of other appbars on the same edge.
+    pub fn reserve(window: &Window, rect: [i32; 4], edge: ScreenEdge, thickness: i32) -> [i32; 4] {
+        let Some(mut data) = app_bar_data(window) else {
+            return rect;
+        };
+
+        // This fails harmlessly if the window is already registered:
+        data.uCallbackMessage = CALLBACK_MESSAGE;
+        // SAFETY: `data` is valid for the duration of the call.
+        unsafe { shellapi::SHAppBarMessage(shellapi::ABM_NEW, &mut data) };
+
+        data.uEdge = match edge {
+            ScreenEdge::Left => shellapi::ABE_LEFT,
+            ScreenEdge::Top => shellapi::ABE_TOP,
+            ScreenEdge::Right => shellapi::ABE_RIGHT,
+            ScreenEdge::Bottom => shellapi::ABE_BOTTOM,
+        };
+        let [left, top, right, bottom] = rect;
+        data.rc = RECT {
+            left,
+            top,
+            right,
+            bottom,
+        };
+
+        // The system moves the rect out of the way of other appbars,
+        // after which we restore our thickness:
+        // SAFETY: `data` is valid for the duration of the call.
+        unsafe { shellapi::SHAppBarMessage(shellapi::ABM_QUERYPOS, &mut data) };
+        match edge {
+            ScreenEdge::Left => data.rc.right = data.rc.left + thickness,
+            ScreenEdge::Top => data.rc.bottom = data.rc.top + thickness,
+            ScreenEdge::Right => data.rc.left = data.rc.right - thickness,
+            ScreenEdge::Bottom => data.rc.top = data.rc.bottom - thickness,
+        }
+        // SAFETY: `data` is valid for the duration of the call.
+        unsafe { shellapi::SHAppBarMessage(shellapi::ABM_SETPOS, &mut data) };
+
+        [data.rc.left, data.rc.top, data.rc.right, data.rc.bottom]
+    }
+
+    /// Unregister the appbar, giving its space back to other windows.
+    pub fn remove(window: &Window) {
+        if let Some(mut data) = app_bar_data(window) {
+            // SAFETY: `data` is valid for the duration of the call.
+            unsafe { shellapi::SHAppBarMessage(shellapi::ABM_REMOVE, &mut data) };
+        }
+    }
+}
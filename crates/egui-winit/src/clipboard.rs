@@ -109,6 +109,22 @@ impl Clipboard {
         self.clipboard = text;
     }
 
+    /// Put `html` on the clipboard, with `text` for apps that can't paste HTML.
+    ///
+    /// Only `text` is copied where there is no support for HTML on the clipboard.
+    pub fn set_html(&mut self, html: String, text: String) {
+        #[cfg(all(feature = "arboard", not(target_os = "android")))]
+        if let Some(clipboard) = &mut self.arboard {
+            if let Err(err) = clipboard.set_html(html, Some(text)) {
+                log::error!("arboard copy/cut error: {err}");
+            }
+            return;
+        }
+
+        _ = html;
+        self.set_text(text);
+    }
+
     pub fn set_image(&mut self, image: &egui::ColorImage) {
         #[cfg(all(feature = "arboard", not(target_os = "android")))]
         if let Some(clipboard) = &mut self.arboard {
@@ -17,10 +17,18 @@ use egui::accesskit;
 use egui::{Pos2, Rect, Theme, Vec2, ViewportBuilder, ViewportCommand, ViewportId, ViewportInfo};
 pub use winit;
 
+mod app_bar;
 pub mod clipboard;
+mod mouse_hit_test;
+mod spanning_fullscreen;
+mod speech;
+mod window_animation;
 mod window_settings;
+mod window_shape;
+mod window_snapping;
 
 pub use window_settings::WindowSettings;
+pub use window_snapping::{snap_outer_rect, snap_window};
 
 use ahash::HashSet;
 use raw_window_handle::HasDisplayHandle;
@@ -87,6 +95,8 @@ pub struct State {
 
     clipboard: clipboard::Clipboard,
 
+    speaker: speech::Speaker,
+
     /// If `true`, mouse inputs will be treated as touches.
     /// Useful for debugging touch support in egui.
     ///
@@ -137,6 +147,7 @@ impl State {
             clipboard: clipboard::Clipboard::new(
                 display_target.display_handle().ok().map(|h| h.as_raw()),
             ),
+            speaker: Default::default(),
 
             simulate_touch_screen: false,
             pointer_touch_id: None,
@@ -240,8 +251,8 @@ impl State {
         // See: https://github.com/rust-windowing/winit/issues/208
         // This solves an issue where egui window positions would be changed when minimizing on Windows.
         let screen_size_in_pixels = screen_size_in_pixels(window);
-        let screen_size_in_points =
-            screen_size_in_pixels / pixels_per_point(&self.egui_ctx, window);
+        let pixels_per_point = pixels_per_point(&self.egui_ctx, window);
+        let screen_size_in_points = screen_size_in_pixels / pixels_per_point;
 
         self.egui_input.screen_rect = (screen_size_in_points.x > 0.0
             && screen_size_in_points.y > 0.0)
@@ -256,6 +267,8 @@ impl State {
             .or_default()
             .native_pixels_per_point = Some(window.scale_factor() as f32);
 
+        self.egui_input.monitors = monitors_in_points(window, pixels_per_point);
+
         self.egui_input.take()
     }
 
@@ -476,12 +489,27 @@ impl State {
                 }
             }
 
+            WindowEvent::Resized(_) => {
+                let pixels_per_point = pixels_per_point(&self.egui_ctx, window);
+                window_shape::on_resized(&self.egui_ctx, window, pixels_per_point);
+                EventResponse {
+                    repaint: true,
+                    consumed: false,
+                }
+            }
+
+            WindowEvent::Destroyed => {
+                app_bar::on_destroyed(&self.egui_ctx, window);
+                EventResponse {
+                    repaint: true,
+                    consumed: false,
+                }
+            }
+
             // Things that may require repaint:
             WindowEvent::RedrawRequested
             | WindowEvent::CursorEntered { .. }
-            | WindowEvent::Destroyed
             | WindowEvent::Occluded(_)
-            | WindowEvent::Resized(_)
             | WindowEvent::Moved(_)
             | WindowEvent::TouchpadPressure { .. }
             | WindowEvent::CloseRequested => EventResponse {
@@ -603,11 +631,21 @@ impl State {
         );
         self.pointer_pos_in_points = Some(pos_in_points);
 
+        mouse_hit_test::on_cursor_moved(
+            &self.egui_ctx,
+            window,
+            pos_in_points,
+            pixels_per_point,
+            self.any_pointer_button_down,
+        );
+
+        let time = self.start_time.elapsed().as_secs_f64();
         if self.simulate_touch_screen {
             if self.any_pointer_button_down {
                 self.egui_input
                     .events
                     .push(egui::Event::PointerMoved(pos_in_points));
+                self.egui_input.pointer_samples.push((time, pos_in_points));
 
                 self.egui_input.events.push(egui::Event::Touch {
                     device_id: egui::TouchDeviceId(0),
@@ -621,6 +659,7 @@ impl State {
             self.egui_input
                 .events
                 .push(egui::Event::PointerMoved(pos_in_points));
+            self.egui_input.pointer_samples.push((time, pos_in_points));
         }
     }
 
@@ -821,6 +860,7 @@ impl State {
     /// * update the cursor
     /// * copy text to the clipboard
     /// * open any clicked urls
+    /// * read text out loud
     /// * update the IME
     /// *
     pub fn handle_platform_output(
@@ -843,6 +883,7 @@ impl State {
             accesskit_update,
             num_completed_passes: _,    // `egui::Context::run` handles this
             request_discard_reasons: _, // `egui::Context::run` handles this
+            native_menu_bar: _,         // handled by `eframe`
         } = platform_output;
 
         for command in commands {
@@ -853,9 +894,21 @@ impl State {
                 egui::OutputCommand::CopyImage(image) => {
                     self.clipboard.set_image(&image);
                 }
+                egui::OutputCommand::CopyHtml { html, text } => {
+                    self.clipboard.set_html(html, text);
+                }
                 egui::OutputCommand::OpenUrl(open_url) => {
                     open_url_in_browser(&open_url.url);
                 }
+                egui::OutputCommand::Speak(speech) => {
+                    self.speaker.speak(&speech);
+                }
+                egui::OutputCommand::HapticFeedback(kind) => {
+                    log::trace!("Haptic feedback {kind:?} is not supported by egui-winit");
+                }
+                egui::OutputCommand::Beep => {
+                    beep();
+                }
             }
         }
 
@@ -878,7 +931,7 @@ impl State {
 
         if let Some(ime) = ime {
             let pixels_per_point = pixels_per_point(&self.egui_ctx, window);
-            let ime_rect_px = pixels_per_point * ime.rect;
+            let ime_rect_px = pixels_per_point * ime.candidate_rect;
             if self.ime_rect_px != Some(ime_rect_px)
                 || self.egui_ctx.input(|i| !i.events.is_empty())
             {
@@ -963,6 +1016,74 @@ pub fn outer_rect_in_points(window: &Window, pixels_per_point: f32) -> Option<Re
     Some(outer_rect_px / pixels_per_point)
 }
 
+/// Call this for all windows when the mouse moves anywhere, i.e. on
+/// [`winit::event::DeviceEvent::MouseMotion`].
+///
+/// Windows using [`ViewportCommand::MouseHitTestRegions`] or [`ViewportCommand::SetWindowShape`]
+/// ignore the mouse while it is outside the regions or shape, so they get no cursor events.
+/// This lets them see the mouse again, until the next cursor event decides.
+pub fn restore_mouse_hit_test(egui_ctx: &egui::Context, window: &Window) {
+    mouse_hit_test::restore(egui_ctx, window);
+}
+
+/// Does any window use [`ViewportCommand::MouseHitTestRegions`]
+/// or [`ViewportCommand::SetWindowShape`]?
+///
+/// If so, the integration should listen to device events even when not focused
+/// (see [`winit::event_loop::ActiveEventLoop::listen_device_events`]),
+/// and call [`restore_mouse_hit_test`] on mouse motion.
+pub fn uses_mouse_hit_test(egui_ctx: &egui::Context) -> bool {
+    mouse_hit_test::is_used(egui_ctx)
+}
+
+/// All monitors connected to the computer, in the same space as [`outer_rect_in_points`].
+pub fn monitors_in_points(window: &Window, pixels_per_point: f32) -> Vec<egui::MonitorInfo> {
+    profiling::function_scope!();
+    window
+        .available_monitors()
+        .map(|monitor| {
+            let pos_px = monitor.position();
+            let size_px = monitor.size();
+            let rect_px = egui::Rect::from_min_size(
+                egui::pos2(pos_px.x as f32, pos_px.y as f32),
+                egui::vec2(size_px.width as f32, size_px.height as f32),
+            );
+            egui::MonitorInfo {
+                name: monitor.name(),
+                rect: rect_px / pixels_per_point,
+                work_rect: None, // winit doesn't tell us
+                native_pixels_per_point: Some(monitor.scale_factor() as f32),
+                video_modes: monitor.video_modes().map(|mode| video_mode(&mode)).collect(),
+            }
+        })
+        .collect()
+}
+
+fn video_mode(mode: &winit::monitor::VideoModeHandle) -> egui::VideoMode {
+    egui::VideoMode {
+        size: [mode.size().width, mode.size().height],
+        bit_depth: mode.bit_depth(),
+        refresh_rate_millihertz: mode.refresh_rate_millihertz(),
+    }
+}
+
+/// The mode of the monitor with the given index in [`monitors_in_points`],
+/// or its best mode if `video_mode` is `None`.
+fn find_video_mode(
+    window: &Window,
+    monitor: usize,
+    video_mode: Option<egui::VideoMode>,
+) -> Option<winit::monitor::VideoModeHandle> {
+    let mut modes = window.available_monitors().nth(monitor)?.video_modes();
+    match video_mode {
+        Some(wanted) => modes.find(|mode| self::video_mode(mode) == wanted),
+        None => modes.max_by_key(|mode| {
+            let size = mode.size();
+            (size.width * size.height, mode.refresh_rate_millihertz(), mode.bit_depth())
+        }),
+    }
+}
+
 /// Update the given viewport info with the current state of the window.
 ///
 /// Call before [`State::take_egui_input`].
@@ -1019,7 +1140,9 @@ pub fn update_viewport_info(
         viewport_info.minimized = Some(window.is_minimized().unwrap_or(false));
     }
 
-    viewport_info.fullscreen = Some(window.fullscreen().is_some());
+    viewport_info.fullscreen = Some(
+        window.fullscreen().is_some() || spanning_fullscreen::is_active(egui_ctx, window),
+    );
     viewport_info.focused = Some(window.has_focus());
 }
 
@@ -1035,6 +1158,29 @@ fn open_url_in_browser(_url: &str) {
     }
 }
 
+/// Play the system alert sound.
+fn beep() {
+    #[cfg(target_os = "windows")]
+    {
+        // 0xFFFFFFFF is the simple beep, which works even without a sound card.
+        #[expect(unsafe_code)]
+        // SAFETY: `MessageBeep` has no preconditions.
+        unsafe {
+            winapi::um::winuser::MessageBeep(0xFFFF_FFFF);
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        use std::io::Write as _;
+
+        // Ring the terminal bell:
+        let mut stderr = std::io::stderr();
+        stderr.write_all(b"\x07").ok();
+        stderr.flush().ok();
+    }
+}
+
 /// Winit sends special keys (backspace, delete, F1, …) as characters.
 /// Ignore those.
 /// We also ignore '\r', '\n', '\t'.
@@ -1339,6 +1485,8 @@ pub fn process_viewport_commands(
     for command in commands {
         process_viewport_command(egui_ctx, window, command, info, actions_requested);
     }
+
+    window_animation::advance(egui_ctx, window, pixels_per_point(egui_ctx, window));
 }
 
 fn process_viewport_command(
@@ -1372,6 +1520,7 @@ fn process_viewport_command(
             }
         }
         ViewportCommand::InnerSize(size) => {
+            window_animation::stop_inner_size(egui_ctx, window);
             let width_px = pixels_per_point * size.x.max(1.0);
             let height_px = pixels_per_point * size.y.max(1.0);
             let requested_size = PhysicalSize::new(width_px, height_px);
@@ -1415,13 +1564,43 @@ fn process_viewport_command(
             window.set_title(&title);
         }
         ViewportCommand::Transparent(v) => window.set_transparent(v),
+        ViewportCommand::Backdrop(backdrop) => set_backdrop(window, backdrop),
         ViewportCommand::Visible(v) => window.set_visible(v),
         ViewportCommand::OuterPosition(pos) => {
+            window_animation::stop_outer_position(egui_ctx, window);
             window.set_outer_position(PhysicalPosition::new(
                 pixels_per_point * pos.x,
                 pixels_per_point * pos.y,
             ));
         }
+        ViewportCommand::AnimateOuterPosition {
+            target,
+            duration,
+            easing,
+        } => {
+            window_animation::animate_outer_position(
+                egui_ctx,
+                window,
+                pixels_per_point,
+                target,
+                duration,
+                easing,
+            );
+        }
+        ViewportCommand::AnimateInnerSize {
+            target,
+            duration,
+            easing,
+        } => {
+            window_animation::animate_inner_size(
+                egui_ctx,
+                window,
+                pixels_per_point,
+                target,
+                duration,
+                easing,
+            );
+        }
         ViewportCommand::MinInnerSize(s) => {
             window.set_min_inner_size((s.is_finite() && s != Vec2::ZERO).then_some(
                 PhysicalSize::new(pixels_per_point * s.x, pixels_per_point * s.y),
@@ -1466,8 +1645,35 @@ fn process_viewport_command(
             info.maximized = Some(v);
         }
         ViewportCommand::Fullscreen(v) => {
+            spanning_fullscreen::exit(egui_ctx, window);
             window.set_fullscreen(v.then_some(winit::window::Fullscreen::Borderless(None)));
         }
+        ViewportCommand::FullscreenOnRect(rect) => {
+            window_animation::stop_outer_position(egui_ctx, window);
+            window_animation::stop_inner_size(egui_ctx, window);
+            spanning_fullscreen::enter(egui_ctx, window, rect, pixels_per_point);
+        }
+        ViewportCommand::FullscreenOnMonitor(monitor) => {
+            spanning_fullscreen::exit(egui_ctx, window);
+            let handle = window.available_monitors().nth(monitor);
+            if handle.is_none() {
+                log::warn!("FullscreenOnMonitor: there is no monitor {monitor}");
+            }
+            window.set_fullscreen(Some(winit::window::Fullscreen::Borderless(handle)));
+        }
+        ViewportCommand::ExclusiveFullscreen {
+            monitor,
+            video_mode,
+        } => {
+            if let Some(mode) = find_video_mode(window, monitor, video_mode) {
+                spanning_fullscreen::exit(egui_ctx, window);
+                window.set_fullscreen(Some(winit::window::Fullscreen::Exclusive(mode)));
+            } else {
+                log::warn!(
+                    "ExclusiveFullscreen: monitor {monitor} has no video mode {video_mode:?}"
+                );
+            }
+        }
         ViewportCommand::Decorations(v) => window.set_decorations(v),
         ViewportCommand::WindowLevel(l) => window.set_window_level(match l {
             egui::viewport::WindowLevel::AlwaysOnBottom => WindowLevel::AlwaysOnBottom,
@@ -1478,6 +1684,13 @@ fn process_viewport_command(
             let winit_icon = icon.and_then(|icon| to_winit_icon(&icon));
             window.set_window_icon(winit_icon);
         }
+        ViewportCommand::Taskbar(_show) => {
+            #[cfg(target_os = "windows")]
+            {
+                use winit::platform::windows::WindowExtWindows as _;
+                window.set_skip_taskbar(!_show);
+            }
+        }
         ViewportCommand::IMERect(rect) => {
             window.set_ime_cursor_area(
                 PhysicalPosition::new(pixels_per_point * rect.min.x, pixels_per_point * rect.min.y),
@@ -1538,6 +1751,15 @@ fn process_viewport_command(
                 log::warn!("{command:?}: {err}");
             }
         }
+        ViewportCommand::MouseHitTestRegions(regions) => {
+            mouse_hit_test::set_regions(egui_ctx, window, regions);
+        }
+        ViewportCommand::SetWindowShape(shape) => {
+            window_shape::set_shape(egui_ctx, window, shape, pixels_per_point);
+        }
+        ViewportCommand::AppBar(app_bar) => {
+            app_bar::set_app_bar(egui_ctx, window, app_bar, pixels_per_point);
+        }
         ViewportCommand::Screenshot(user_data) => {
             actions_requested.insert(ActionRequested::Screenshot(user_data));
         }
@@ -1550,6 +1772,11 @@ fn process_viewport_command(
         ViewportCommand::RequestPaste => {
             actions_requested.insert(ActionRequested::Paste);
         }
+        ViewportCommand::SetTaskbarProgress(_)
+        | ViewportCommand::SetBadge(_)
+        | ViewportCommand::MoveToWorkspace(_) => {
+            // Not supported by winit. `eframe` handles these itself.
+        }
     }
 }
 
@@ -1588,6 +1815,7 @@ pub fn create_winit_window_attributes(
         maximized,
         resizable,
         transparent,
+        backdrop,
         decorations,
         icon,
         active,
@@ -1616,7 +1844,10 @@ pub fn create_winit_window_attributes(
         window_type: _window_type,
 
         mouse_passthrough: _, // handled in `apply_viewport_builder_to_window`
+        modal_owner: _, // handled by egui, which blocks the input to the owner
         clamp_size_to_monitor_size: _, // Handled in `viewport_builder` in `epi_integration.rs`
+        persisted_geometry: _, // Handled by `eframe`, which saves and restores the geometry
+        app_bar: _, // handled in `apply_viewport_builder_to_window`
     } = viewport_builder;
 
     let mut window_attributes = winit::window::WindowAttributes::default()
@@ -1705,6 +1936,18 @@ pub fn create_winit_window_attributes(
         window_attributes = window_attributes.with_window_icon(winit_icon);
     }
 
+    if let Some(backdrop) = backdrop {
+        #[cfg(target_os = "windows")]
+        {
+            use winit::platform::windows::WindowAttributesExtWindows as _;
+            window_attributes = window_attributes.with_system_backdrop(to_winit_backdrop(backdrop));
+        }
+        #[cfg(not(target_os = "windows"))]
+        {
+            window_attributes = window_attributes.with_blur(backdrop != egui::Backdrop::None);
+        }
+    }
+
     #[cfg(all(feature = "wayland", target_os = "linux"))]
     if let Some(app_id) = _app_id {
         use winit::platform::wayland::WindowAttributesExtWayland as _;
@@ -1761,6 +2004,29 @@ pub fn create_winit_window_attributes(
     window_attributes
 }
 
+fn set_backdrop(window: &Window, backdrop: egui::Backdrop) {
+    #[cfg(target_os = "windows")]
+    {
+        use winit::platform::windows::WindowExtWindows as _;
+        window.set_system_backdrop(to_winit_backdrop(backdrop));
+    }
+    #[cfg(not(target_os = "windows"))]
+    window.set_blur(backdrop != egui::Backdrop::None);
+}
+
+/// Windows 11 has no plain blur, so we use the acrylic material for that.
+#[cfg(target_os = "windows")]
+fn to_winit_backdrop(backdrop: egui::Backdrop) -> winit::platform::windows::BackdropType {
+    use winit::platform::windows::BackdropType;
+    match backdrop {
+        egui::Backdrop::None => BackdropType::None,
+        egui::Backdrop::Mica => BackdropType::MainWindow,
+        egui::Backdrop::Blur | egui::Backdrop::Acrylic | egui::Backdrop::Vibrancy => {
+            BackdropType::TransientWindow
+        }
+    }
+}
+
 fn to_winit_icon(icon: &egui::IconData) -> Option<winit::window::Icon> {
     if icon.is_empty() {
         None
@@ -1826,6 +2092,9 @@ pub fn apply_viewport_builder_to_window(
         if let Some(maximized) = builder.maximized {
             window.set_maximized(maximized);
         }
+        if let Some(app_bar) = builder.app_bar {
+            app_bar::set_app_bar(egui_ctx, window, Some(app_bar), pixels_per_point);
+        }
     }
 }
 
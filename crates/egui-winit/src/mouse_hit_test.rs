@@ -0,0 +1,115 @@
+//! Click-through parts of windows, for [`egui::ViewportCommand::MouseHitTestRegions`]
+//! and [`egui::ViewportCommand::SetWindowShape`].
+//!
+//! winit can only make the whole window ignore the mouse, so we turn that on and off
+//! as the cursor moves in and out of the regions and the shape.
+//! While the window ignores the mouse it gets no cursor events,
+//! so we let it see the mouse again whenever the mouse moves anywhere
+//! (see [`crate::restore_mouse_hit_test`]), and let the next cursor event decide.
+
+use egui::{Pos2, Rect, WindowShape};
+use winit::window::Window;
+
+#[derive(Clone, Debug, Default)]
+struct HitTest {
+    /// In ui points. `None` means the whole window.
+    regions: Option<Vec<Rect>>,
+
+    /// The shape of the window. `None` means a rectangle.
+    shape: Option<WindowShape>,
+
+    /// Is the window currently ignoring the mouse?
+    passthrough: bool,
+}
+
+fn hit_test_id(window: &Window) -> egui::Id {
+    egui::Id::new(("egui_winit_mouse_hit_test", window.id()))
+}
+
+fn any_window_id() -> egui::Id {
+    egui::Id::new("egui_winit_mouse_hit_test_used")
+}
+
+fn set_passthrough(window: &Window, hit_test: &mut HitTest, passthrough: bool) {
+    if hit_test.passthrough == passthrough {
+        return;
+    }
+    match window.set_cursor_hittest(!passthrough) {
+        Ok(()) => hit_test.passthrough = passthrough,
+        Err(err) => log::warn!("set_cursor_hittest failed: {err}"),
+    }
+}
+
+/// Start or stop letting clicks outside of the regions through.
+pub(crate) fn set_regions(egui_ctx: &egui::Context, window: &Window, regions: Option<Vec<Rect>>) {
+    update(egui_ctx, window, |hit_test| hit_test.regions = regions);
+}
+
+/// Start or stop letting clicks outside of the shape through.
+pub(crate) fn set_shape(egui_ctx: &egui::Context, window: &Window, shape: Option<WindowShape>) {
+    update(egui_ctx, window, |hit_test| hit_test.shape = shape);
+}
+
+fn update(egui_ctx: &egui::Context, window: &Window, update: impl FnOnce(&mut HitTest)) {
+    let id = hit_test_id(window);
+    egui_ctx.data_mut(|data| {
+        let mut hit_test: HitTest = data.get_temp(id).unwrap_or_default();
+        update(&mut hit_test);
+        if hit_test.regions.is_some() || hit_test.shape.is_some() {
+            data.insert_temp(id, hit_test);
+            data.insert_temp(any_window_id(), true);
+        } else {
+            set_passthrough(window, &mut hit_test, false);
+            data.remove::<HitTest>(id);
+        }
+    });
+}
+
+/// Let the mouse through if the cursor is outside all the regions, or outside the shape.
+///
+/// We never let go of a drag that started inside a region.
+pub(crate) fn on_cursor_moved(
+    egui_ctx: &egui::Context,
+    window: &Window,
+    pos_in_points: Pos2,
+    pixels_per_point: f32,
+    any_button_down: bool,
+) {
+    let id = hit_test_id(window);
+    let inner_size = window.inner_size();
+    let size_in_points =
+        egui::vec2(inner_size.width as f32, inner_size.height as f32) / pixels_per_point;
+    egui_ctx.data_mut(|data| {
+        if let Some(mut hit_test) = data.get_temp::<HitTest>(id) {
+            let in_regions = hit_test.regions.as_ref().is_none_or(|regions| {
+                regions.iter().any(|region| region.contains(pos_in_points))
+            });
+            let in_shape = hit_test
+                .shape
+                .as_ref()
+                .is_none_or(|shape| shape.contains(size_in_points, pos_in_points));
+            let inside = in_regions && in_shape;
+            set_passthrough(window, &mut hit_test, !inside && !any_button_down);
+            data.insert_temp(id, hit_test);
+        }
+    });
+}
+
+/// Let the window see the mouse again, until the next cursor event.
+pub(crate) fn restore(egui_ctx: &egui::Context, window: &Window) {
+    let id = hit_test_id(window);
+    egui_ctx.data_mut(|data| {
+        if let Some(mut hit_test) = data.get_temp::<HitTest>(id) {
+            if hit_test.passthrough {
+                set_passthrough(window, &mut hit_test, false);
+                data.insert_temp(id, hit_test);
+            }
+        }
+    });
+}
+
+/// Has any window ever used [`egui::ViewportCommand::MouseHitTestRegions`]
+/// or [`egui::ViewportCommand::SetWindowShape`]?
+pub(crate) fn is_used(egui_ctx: &egui::Context) -> bool {
+    egui_ctx.data(|data| data.get_temp::<bool>(any_window_id()).unwrap_or(false))
+}
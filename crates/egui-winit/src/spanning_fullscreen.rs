@@ -0,0 +1,77 @@
+//! Borderless windows covering any rectangle, e.g. several monitors,
+//! for [`egui::ViewportCommand::FullscreenOnRect`].
+//!
+//! winit can only make a window fullscreen on one monitor,
+//! so we remove the decorations and move and resize the window ourselves.
+
+use egui::Rect;
+use winit::{
+    dpi::{PhysicalPosition, PhysicalSize},
+    window::Window,
+};
+
+/// How the window was before it covered the rectangle, so we can put it back.
+///
+/// This is kept in the temporary data of the [`egui::Context`] while the window is spanning.
+#[derive(Clone, Copy, Debug)]
+struct Restore {
+    outer_position: Option<PhysicalPosition<i32>>,
+    inner_size: PhysicalSize<u32>,
+    decorations: bool,
+    maximized: bool,
+}
+
+fn restore_id(window: &Window) -> egui::Id {
+    egui::Id::new(("egui_winit_spanning_fullscreen", window.id()))
+}
+
+/// Is the window covering a rectangle, because of [`enter`]?
+pub fn is_active(egui_ctx: &egui::Context, window: &Window) -> bool {
+    let id = restore_id(window);
+    egui_ctx.data(|data| data.get_temp::<Restore>(id).is_some())
+}
+
+/// Make the window borderless and cover `rect`, given in points.
+pub fn enter(egui_ctx: &egui::Context, window: &Window, rect: Rect, pixels_per_point: f32) {
+    let id = restore_id(window);
+    if !is_active(egui_ctx, window) {
+        let restore = Restore {
+            outer_position: window.outer_position().ok(),
+            inner_size: window.inner_size(),
+            decorations: window.is_decorated(),
+            maximized: window.is_maximized(),
+        };
+        egui_ctx.data_mut(|data| data.insert_temp(id, restore));
+    }
+
+    let rect = rect * pixels_per_point;
+    window.set_fullscreen(None);
+    window.set_maximized(false);
+    window.set_decorations(false);
+    window.set_outer_position(PhysicalPosition::new(rect.min.x, rect.min.y));
+    let _ = window.request_inner_size(PhysicalSize::new(
+        rect.width().max(1.0),
+        rect.height().max(1.0),
+    ));
+}
+
+/// Put the window back to how it was before [`enter`].
+///
+/// Does nothing if the window isn't covering a rectangle.
+pub fn exit(egui_ctx: &egui::Context, window: &Window) {
+    let id = restore_id(window);
+    let Some(restore) = egui_ctx.data_mut(|data| {
+        let restore = data.get_temp::<Restore>(id);
+        data.remove::<Restore>(id);
+        restore
+    }) else {
+        return;
+    };
+
+    window.set_decorations(restore.decorations);
+    let _ = window.request_inner_size(restore.inner_size);
+    if let Some(outer_position) = restore.outer_position {
+        window.set_outer_position(outer_position);
+    }
+    window.set_maximized(restore.maximized);
+}
@@ -0,0 +1,70 @@
+/// Reads [`egui::Speech`] out loud using the text-to-speech engine of the OS
+/// (SAPI or WinRT on Windows, AVSpeechSynthesizer on Mac, Speech Dispatcher on Linux).
+///
+/// If the "tts" feature is off, or there is no text-to-speech engine,
+/// then the text is just logged instead.
+#[derive(Default)]
+pub struct Speaker {
+    /// Lazily created on first use, since connecting to the engine can be slow.
+    #[cfg(feature = "tts")]
+    tts: Option<Result<tts::Tts, tts::Error>>,
+}
+
+impl Speaker {
+    pub fn speak(&mut self, speech: &egui::Speech) {
+        #[cfg(feature = "tts")]
+        {
+            let tts = self.tts.get_or_insert_with(|| {
+                let tts = tts::Tts::default();
+                if let Err(err) = &tts {
+                    log::warn!("Failed to initialize text-to-speech: {err}");
+                }
+                tts
+            });
+
+            if let Ok(tts) = tts {
+                if let Err(err) = speak(tts, speech) {
+                    log::warn!("Failed to speak: {err}");
+                }
+            }
+        }
+
+        #[cfg(not(feature = "tts"))]
+        {
+            log::warn!(
+                "Cannot speak {:?} - feature \"tts\" not enabled.",
+                speech.text
+            );
+        }
+    }
+}
+
+#[cfg(feature = "tts")]
+fn speak(tts: &mut tts::Tts, speech: &egui::Speech) -> Result<(), tts::Error> {
+    let egui::Speech {
+        text,
+        rate,
+        voice,
+        interrupt,
+    } = speech;
+
+    let features = tts.supported_features();
+
+    if features.rate {
+        let rate = (tts.normal_rate() * rate).clamp(tts.min_rate(), tts.max_rate());
+        tts.set_rate(rate)?;
+    }
+
+    if features.voice {
+        if let Some(voice_name) = voice {
+            if let Some(voice) = tts.voices()?.into_iter().find(|v| &v.name() == voice_name) {
+                tts.set_voice(&voice)?;
+            } else {
+                log::debug!("No text-to-speech voice named {voice_name:?}");
+            }
+        }
+    }
+
+    tts.speak(text.as_str(), *interrupt)?;
+    Ok(())
+}
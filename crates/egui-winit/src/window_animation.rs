@@ -0,0 +1,179 @@
+//! Smooth window moves and resizes, for [`egui::ViewportCommand::AnimateOuterPosition`]
+//! and [`egui::ViewportCommand::AnimateInnerSize`].
+
+use egui::{Pos2, Vec2, ViewportEasing};
+use winit::{
+    dpi::{PhysicalPosition, PhysicalSize},
+    window::Window,
+};
+
+#[derive(Clone, Copy, Debug)]
+struct Tween {
+    from: Vec2,
+    to: Vec2,
+    start: web_time::Instant,
+    duration: f32,
+    easing: ViewportEasing,
+}
+
+impl Tween {
+    fn new(from: Vec2, to: Vec2, duration: f32, easing: ViewportEasing) -> Self {
+        Self {
+            from,
+            to,
+            start: web_time::Instant::now(),
+            duration,
+            easing,
+        }
+    }
+
+    /// The current value, and whether or not we're done.
+    fn current(&self) -> (Vec2, bool) {
+        let t = self.start.elapsed().as_secs_f32() / self.duration;
+        if 1.0 <= t {
+            (self.to, true)
+        } else {
+            let t = self.easing.apply(t);
+            (self.from + (self.to - self.from) * t, false)
+        }
+    }
+}
+
+/// The animations in progress for one window, in ui points.
+///
+/// This is kept in the temporary data of the [`egui::Context`],
+/// so that [`crate::process_viewport_commands`] can advance it every frame.
+#[derive(Clone, Copy, Debug, Default)]
+struct WindowAnimation {
+    outer_position: Option<Tween>,
+    inner_size: Option<Tween>,
+}
+
+fn animation_id(window: &Window) -> egui::Id {
+    egui::Id::new(("egui_winit_window_animation", window.id()))
+}
+
+fn modify(egui_ctx: &egui::Context, window: &Window, f: impl FnOnce(&mut WindowAnimation)) {
+    let id = animation_id(window);
+    egui_ctx.data_mut(|data| {
+        let mut animation: WindowAnimation = data.get_temp(id).unwrap_or_default();
+        f(&mut animation);
+        if animation.outer_position.is_some() || animation.inner_size.is_some() {
+            data.insert_temp(id, animation);
+        } else {
+            data.remove::<WindowAnimation>(id);
+        }
+    });
+}
+
+fn set_outer_position(window: &Window, pos: Vec2, pixels_per_point: f32) {
+    window.set_outer_position(PhysicalPosition::new(
+        pixels_per_point * pos.x,
+        pixels_per_point * pos.y,
+    ));
+}
+
+fn request_inner_size(window: &Window, size: Vec2, pixels_per_point: f32) {
+    let _ = window.request_inner_size(PhysicalSize::new(
+        pixels_per_point * size.x.max(1.0),
+        pixels_per_point * size.y.max(1.0),
+    ));
+}
+
+/// Start moving the window from where it is now.
+///
+/// If we don't know where the window is (e.g. on Wayland), it is moved right away.
+pub(crate) fn animate_outer_position(
+    egui_ctx: &egui::Context,
+    window: &Window,
+    pixels_per_point: f32,
+    target: Pos2,
+    duration: f32,
+    easing: ViewportEasing,
+) {
+    let from = crate::outer_rect_in_points(window, pixels_per_point).map(|rect| rect.min);
+    modify(egui_ctx, window, |animation| {
+        animation.outer_position = match from {
+            Some(from) if 0.0 < duration => Some(Tween::new(
+                from.to_vec2(),
+                target.to_vec2(),
+                duration,
+                easing,
+            )),
+            _ => {
+                set_outer_position(window, target.to_vec2(), pixels_per_point);
+                None
+            }
+        };
+    });
+}
+
+/// Start resizing the window from its current size.
+pub(crate) fn animate_inner_size(
+    egui_ctx: &egui::Context,
+    window: &Window,
+    pixels_per_point: f32,
+    target: Vec2,
+    duration: f32,
+    easing: ViewportEasing,
+) {
+    let size_px = window.inner_size();
+    let from = Vec2::new(size_px.width as f32, size_px.height as f32) / pixels_per_point;
+    modify(egui_ctx, window, |animation| {
+        animation.inner_size = if 0.0 < duration {
+            Some(Tween::new(from, target, duration, easing))
+        } else {
+            request_inner_size(window, target, pixels_per_point);
+            None
+        };
+    });
+}
+
+/// Called when the window is moved without animation, so that the animation doesn't fight it.
+pub(crate) fn stop_outer_position(egui_ctx: &egui::Context, window: &Window) {
+    modify(egui_ctx, window, |animation| animation.outer_position = None);
+}
+
+/// Called when the window is resized without animation, so that the animation doesn't fight it.
+pub(crate) fn stop_inner_size(egui_ctx: &egui::Context, window: &Window) {
+    modify(egui_ctx, window, |animation| animation.inner_size = None);
+}
+
+/// Is [`egui::ViewportCommand::AnimateOuterPosition`] moving the window?
+pub(crate) fn is_moving(egui_ctx: &egui::Context, window: &Window) -> bool {
+    egui_ctx.data(|data| {
+        data.get_temp::<WindowAnimation>(animation_id(window))
+            .is_some_and(|animation| animation.outer_position.is_some())
+    })
+}
+
+/// Move the window along, and keep repainting until the animations are done.
+pub(crate) fn advance(egui_ctx: &egui::Context, window: &Window, pixels_per_point: f32) {
+    if egui_ctx.data(|data| data.get_temp::<WindowAnimation>(animation_id(window)).is_none()) {
+        return; // Fast path
+    }
+
+    profiling::function_scope!();
+
+    modify(egui_ctx, window, |animation| {
+        if let Some(tween) = animation.outer_position {
+            let (pos, done) = tween.current();
+            set_outer_position(window, pos, pixels_per_point);
+            if done {
+                animation.outer_position = None;
+            }
+        }
+
+        if let Some(tween) = animation.inner_size {
+            let (size, done) = tween.current();
+            request_inner_size(window, size, pixels_per_point);
+            if done {
+                animation.inner_size = None;
+            }
+        }
+
+        if animation.outer_position.is_some() || animation.inner_size.is_some() {
+            window.request_redraw();
+        }
+    });
+}
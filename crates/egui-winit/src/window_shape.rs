@@ -0,0 +1,109 @@
+//! Non-rectangular windows, for [`egui::ViewportCommand::SetWindowShape`].
+//!
+//! winit can't shape windows, so:
+//! * On Windows we cut the window to the shape with `SetWindowRgn`,
+//!   which also lets clicks outside of the shape through.
+//! * Elsewhere we only let clicks outside of the shape through (see `mouse_hit_test`),
+//!   so the window must be transparent for it to look shaped.
+
+use egui::WindowShape;
+use winit::window::Window;
+
+#[cfg(target_os = "windows")]
+fn shape_id(window: &Window) -> egui::Id {
+    egui::Id::new(("egui_winit_window_shape", window.id()))
+}
+
+/// Give the window a shape, or make it a rectangle again with `None`.
+pub(crate) fn set_shape(
+    egui_ctx: &egui::Context,
+    window: &Window,
+    shape: Option<WindowShape>,
+    _pixels_per_point: f32,
+) {
+    #[cfg(target_os = "windows")]
+    {
+        let id = shape_id(window);
+        egui_ctx.data_mut(|data| match &shape {
+            Some(shape) => data.insert_temp(id, shape.clone()),
+            None => data.remove::<WindowShape>(id),
+        });
+        win::set_region(window, shape.as_ref(), _pixels_per_point);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    crate::mouse_hit_test::set_shape(egui_ctx, window, shape);
+}
+
+/// The region of a window doesn't grow with it, so we cut it again when it is resized.
+pub(crate) fn on_resized(_egui_ctx: &egui::Context, _window: &Window, _pixels_per_point: f32) {
+    #[cfg(target_os = "windows")]
+    if let Some(shape) = _egui_ctx.data(|data| data.get_temp::<WindowShape>(shape_id(_window))) {
+        win::set_region(_window, Some(&shape), _pixels_per_point);
+    }
+}
+
+#[cfg(target_os = "windows")]
+#[expect(unsafe_code)]
+mod win {
+    use egui::{Pos2, WindowShape};
+    use raw_window_handle::{HasWindowHandle as _, RawWindowHandle};
+    use winapi::shared::windef::{HRGN, HWND, POINT};
+    use winapi::um::{wingdi, winuser};
+    use winit::window::Window;
+
+    pub fn set_region(window: &Window, shape: Option<&WindowShape>, pixels_per_point: f32) {
+        let Ok(RawWindowHandle::Win32(handle)) = window.window_handle().map(|h| h.as_raw()) else {
+            return;
+        };
+        let hwnd = handle.hwnd.get() as HWND;
+
+        // The region is relative to the outer top-left corner of the window,
+        // but the shape to the inner one:
+        let (left, top) = match (window.inner_position(), window.outer_position()) {
+            (Ok(inner), Ok(outer)) => (inner.x - outer.x, inner.y - outer.y),
+            _ => (0, 0),
+        };
+        let size = window.inner_size();
+        // The right and bottom edges of regions are exclusive:
+        let right = left + size.width as i32 + 1;
+        let bottom = top + size.height as i32 + 1;
+
+        let region: HRGN = match shape {
+            None => std::ptr::null_mut(),
+            Some(WindowShape::RoundedRect(radius)) => {
+                let diameter = (2.0 * radius * pixels_per_point).round() as i32;
+                // SAFETY: no pointers involved.
+                unsafe { wingdi::CreateRoundRectRgn(left, top, right, bottom, diameter, diameter) }
+            }
+            Some(WindowShape::Ellipse) => {
+                // SAFETY: no pointers involved.
+                unsafe { wingdi::CreateEllipticRgn(left, top, right, bottom) }
+            }
+            Some(WindowShape::Polygon(points)) => {
+                let to_point = |pos: &Pos2| POINT {
+                    x: left + (pos.x * pixels_per_point).round() as i32,
+                    y: top + (pos.y * pixels_per_point).round() as i32,
+                };
+                let points: Vec<POINT> = points.iter().map(to_point).collect();
+                let count = points.len() as i32;
+                // SAFETY: `points` outlives the call, which copies them.
+                unsafe { wingdi::CreatePolygonRgn(points.as_ptr(), count, wingdi::ALTERNATE) }
+            }
+        };
+        if shape.is_some() && region.is_null() {
+            log::warn!("Failed to create a window region for {shape:?}");
+            return;
+        }
+
+        // SAFETY: `hwnd` is a valid window handle.
+        // On success the system owns the region, and frees the previous one.
+        if unsafe { winuser::SetWindowRgn(hwnd, region, 1) } == 0 {
+            log::warn!("SetWindowRgn failed for {shape:?}");
+            if !region.is_null() {
+                // SAFETY: the region is still ours, and not used by anything.
+                unsafe { wingdi::DeleteObject(region.cast()) };
+            }
+        }
+    }
+}
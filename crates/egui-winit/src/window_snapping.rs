@@ -0,0 +1,95 @@
+//! Snapping windows to the edges of monitors and other windows while they are dragged.
+
+use egui::{Rect, vec2};
+use winit::{dpi::PhysicalPosition, window::Window};
+
+/// Where to move a window with the outer rect `rect`, so that its edges line up with
+/// nearby edges of the `monitors` and the other `windows`.
+///
+/// Edges within `threshold` points are snapped to, the closest one on each axis winning.
+/// Windows snap to the inside of the monitor edges,
+/// and to the outside of other windows, like in Winamp.
+/// When next to another window, the window is also aligned with its other edges,
+/// e.g. so two windows stacked on top of each other get the same left edge.
+///
+/// All rects are in points.
+pub fn snap_outer_rect(rect: Rect, monitors: &[Rect], windows: &[Rect], threshold: f32) -> Rect {
+    // The smallest move along x and y:
+    let mut best: [Option<f32>; 2] = [None, None];
+    let mut consider = |axis: usize, from: f32, to: f32| {
+        let delta = to - from;
+        if delta.abs() <= threshold && best[axis].is_none_or(|best| delta.abs() < best.abs()) {
+            best[axis] = Some(delta);
+        }
+    };
+
+    for monitor in monitors {
+        consider(0, rect.left(), monitor.left());
+        consider(0, rect.right(), monitor.right());
+        consider(1, rect.top(), monitor.top());
+        consider(1, rect.bottom(), monitor.bottom());
+    }
+
+    for other in windows {
+        let near = other.expand(threshold);
+
+        // Side by side:
+        if near.y_range().intersects(rect.y_range()) {
+            consider(0, rect.left(), other.right());
+            consider(0, rect.right(), other.left());
+            if near.x_range().intersects(rect.x_range()) {
+                consider(1, rect.top(), other.top());
+                consider(1, rect.bottom(), other.bottom());
+            }
+        }
+
+        // Stacked:
+        if near.x_range().intersects(rect.x_range()) {
+            consider(1, rect.top(), other.bottom());
+            consider(1, rect.bottom(), other.top());
+            if near.y_range().intersects(rect.y_range()) {
+                consider(0, rect.left(), other.left());
+                consider(0, rect.right(), other.right());
+            }
+        }
+    }
+
+    let [dx, dy] = best;
+    rect.translate(vec2(dx.unwrap_or(0.0), dy.unwrap_or(0.0)))
+}
+
+/// Snap the window to the edges of the monitors and `other_windows`, see [`snap_outer_rect`].
+///
+/// Call this when the window has moved, i.e. on [`winit::event::WindowEvent::Moved`].
+/// `other_windows` are the outer rects of the other windows of the app, in points.
+///
+/// Windows that are being moved by [`egui::ViewportCommand::AnimateOuterPosition`]
+/// are left alone.
+pub fn snap_window(
+    egui_ctx: &egui::Context,
+    window: &Window,
+    other_windows: &[Rect],
+    threshold: f32,
+) {
+    if crate::window_animation::is_moving(egui_ctx, window) {
+        return;
+    }
+
+    let pixels_per_point = crate::pixels_per_point(egui_ctx, window);
+    let Some(outer_rect) = crate::outer_rect_in_points(window, pixels_per_point) else {
+        return; // e.g. Wayland, where windows can't move themselves anyway
+    };
+    let monitors: Vec<Rect> = crate::monitors_in_points(window, pixels_per_point)
+        .iter()
+        .map(|monitor| monitor.usable_rect())
+        .collect();
+
+    let snapped = snap_outer_rect(outer_rect, &monitors, other_windows, threshold);
+    if snapped.min != outer_rect.min {
+        // Setting the position triggers another `Moved` event, but that one is already snapped.
+        window.set_outer_position(PhysicalPosition::new(
+            pixels_per_point * snapped.min.x,
+            pixels_per_point * snapped.min.y,
+        ));
+    }
+}
@@ -5,8 +5,9 @@
 use emath::GuiRounding as _;
 
 use crate::{
-    Align2, Context, Id, InnerResponse, LayerId, Layout, NumExt as _, Order, Pos2, Rect, Response,
-    Sense, Ui, UiBuilder, UiKind, UiStackInfo, Vec2, WidgetRect, WidgetWithState, emath, pos2,
+    Align2, AreaSelection, Context, Id, InnerResponse, LayerId, Layout, NumExt as _, Order, Pos2,
+    Rect, Response, Sense, Snapping, StrokeKind, Ui, UiBuilder, UiKind, UiStackInfo, Vec2,
+    WidgetRect, WidgetWithState, emath, pos2,
 };
 
 /// State of an [`Area`] that is persisted between frames.
@@ -34,6 +35,10 @@ pub struct AreaState {
     /// If false, clicks goes straight through to what is behind us. Useful for tooltips etc.
     pub interactable: bool,
 
+    /// Can the area be selected with the [`crate::AreaSelection`]?
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub selectable: bool,
+
     /// At what time was this area first shown?
     ///
     /// Used to fade in the area.
@@ -48,6 +53,7 @@ impl Default for AreaState {
             pivot: Align2::LEFT_TOP,
             size: None,
             interactable: true,
+            selectable: false,
             last_became_visible_at: None,
         }
     }
@@ -122,6 +128,8 @@ pub struct Area {
     fade_in: bool,
     layout: Layout,
     sizing_pass: bool,
+    snapping: Option<Snapping>,
+    selectable: bool,
 }
 
 impl WidgetWithState for Area {
@@ -149,6 +157,8 @@ impl Area {
             fade_in: true,
             layout: Layout::default(),
             sizing_pass: false,
+            snapping: None,
+            selectable: false,
         }
     }
 
@@ -299,6 +309,31 @@ impl Area {
         self
     }
 
+    /// Snap to a grid, and to the edges of other areas and the screen, while being dragged.
+    ///
+    /// Default: no snapping.
+    #[inline]
+    pub fn snapping(mut self, snapping: Snapping) -> Self {
+        self.snapping = Some(snapping);
+        self
+    }
+
+    /// Let the user select the area together with others, with the [`crate::AreaSelection`],
+    /// to move, align and distribute them as a group.
+    ///
+    /// Command-click the area to select or unselect it.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
+    pub fn is_selectable(&self) -> bool {
+        self.selectable
+    }
+
     /// Where the "root" of the area is.
     ///
     /// For instance, if you set this to [`Align2::RIGHT_TOP`]
@@ -434,6 +469,8 @@ impl Area {
             fade_in,
             layout,
             sizing_pass: force_sizing_pass,
+            snapping,
+            selectable,
         } = self;
 
         let constrain_rect = constrain_rect.unwrap_or_else(|| ctx.screen_rect());
@@ -447,6 +484,7 @@ impl Area {
             pivot,
             size: None,
             interactable,
+            selectable,
             last_became_visible_at: None,
         });
         if force_sizing_pass {
@@ -455,6 +493,7 @@ impl Area {
         }
         state.pivot = pivot;
         state.interactable = interactable;
+        state.selectable = selectable;
         if let Some(new_pos) = new_pos {
             state.pivot_pos = Some(new_pos);
         }
@@ -503,7 +542,7 @@ impl Area {
         // interact right away to prevent frame-delay
         let mut move_response = {
             let interact_id = layer_id.id.with("move");
-            let sense = sense.unwrap_or_else(|| {
+            let mut sense = sense.unwrap_or_else(|| {
                 if movable {
                     Sense::drag()
                 } else if interactable {
@@ -512,6 +551,9 @@ impl Area {
                     Sense::hover()
                 }
             });
+            if selectable {
+                sense |= Sense::click();
+            }
 
             let move_response = ctx.create_widget(
                 WidgetRect {
@@ -526,9 +568,21 @@ impl Area {
             );
 
             if movable && move_response.dragged() {
-                if let Some(pivot_pos) = &mut state.pivot_pos {
+                let left_top = state.left_top_pos();
+                if let Some(snapping) = snapping {
+                    let targets = snap_targets(ctx, layer_id, constrain_rect);
+                    let rect = snapping.drag_rect(&move_response, state.rect(), &targets);
+                    state.set_left_top_pos(rect.min);
+                } else if let Some(pivot_pos) = &mut state.pivot_pos {
                     *pivot_pos += move_response.drag_delta();
                 }
+                if selectable {
+                    AreaSelection::move_with(ctx, id, state.left_top_pos() - left_top);
+                }
+            }
+
+            if selectable {
+                AreaSelection::toggle_on_click(ctx, id, &move_response);
             }
 
             if (move_response.dragged() || move_response.clicked())
@@ -569,6 +623,21 @@ impl Area {
     }
 }
 
+/// What a dragged area snaps to: the other areas in its [`Order`], and the `constrain_rect`.
+fn snap_targets(ctx: &Context, layer_id: LayerId, constrain_rect: Rect) -> Vec<Rect> {
+    ctx.memory(|mem| {
+        let areas = mem.areas();
+        areas
+            .visible_layer_ids()
+            .into_iter()
+            .filter(|layer| layer.order == layer_id.order && *layer != layer_id)
+            .filter(|layer| !areas.is_sublayer(layer))
+            .filter_map(|layer| Some(areas.get(layer.id)?.rect()))
+            .chain(std::iter::once(constrain_rect))
+            .collect()
+    })
+}
+
 impl Prepared {
     pub(crate) fn state(&self) -> &AreaState {
         &self.state
@@ -655,6 +724,15 @@ impl Prepared {
             response.set_close();
         }
 
+        if state.selectable && AreaSelection::load(ctx).contains(layer_id.id) {
+            ctx.layer_painter(layer_id).rect_stroke(
+                final_rect,
+                ctx.style().visuals.window_corner_radius,
+                ctx.style().visuals.selection.stroke,
+                StrokeKind::Outside,
+            );
+        }
+
         ctx.memory_mut(|m| m.areas_mut().set_state(layer_id, state));
 
         if sizing_pass {
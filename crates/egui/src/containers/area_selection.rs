@@ -0,0 +1,309 @@
+//! Selecting several floating [`crate::Area`]s and [`crate::Window`]s to move,
+//! align and distribute them together, e.g. in dashboard builders.
+
+use crate::{Align, Context, Id, Lasso, LassoResponse, Order, Rect, Response, Ui, Vec2};
+
+/// Which floating [`crate::Area`]s and [`crate::Window`]s are selected.
+///
+/// Only areas made [`crate::Area::selectable`] (or [`crate::Window::selectable`]) can be selected,
+/// by command-clicking them (on the title bar of a window),
+/// or by dragging out a marquee over the background with [`Self::marquee`].
+/// Dragging any of the selected areas moves all of them,
+/// and [`Self::toolbar`] has buttons to line them up.
+///
+/// ```
+/// # egui::__run_test_ctx(|ctx| {
+/// egui::CentralPanel::default().show(ctx, |ui| {
+///     egui::AreaSelection::toolbar(ui);
+///     let rect = ui.max_rect();
+///     let background = ui.interact(rect, ui.id().with("bg"), egui::Sense::click_and_drag());
+///     let _ = egui::AreaSelection::marquee(ui, &background);
+/// });
+/// for name in ["Sales", "Users", "Errors"] {
+///     egui::Window::new(name).selectable(true).show(ctx, |ui| {
+///         ui.label("…");
+///     });
+/// }
+/// # });
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AreaSelection {
+    /// The ids of the selected areas, in the order they were selected.
+    pub ids: Vec<Id>,
+}
+
+impl AreaSelection {
+    fn id() -> Id {
+        Id::new("area_selection")
+    }
+
+    /// The current selection.
+    pub fn load(ctx: &Context) -> Self {
+        ctx.data(|d| d.get_temp(Self::id())).unwrap_or_default()
+    }
+
+    /// Make this the current selection.
+    pub fn store(self, ctx: &Context) {
+        ctx.data_mut(|d| d.insert_temp(Self::id(), self));
+    }
+
+    /// Is the area with this id selected?
+    pub fn contains(&self, id: Id) -> bool {
+        self.ids.contains(&id)
+    }
+
+    /// Select the area if it isn't, and unselect it if it is.
+    pub fn toggle(&mut self, id: Id) {
+        if let Some(index) = self.ids.iter().position(|&selected| selected == id) {
+            self.ids.remove(index);
+        } else {
+            self.ids.push(id);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Select the selectable areas touched by a marquee dragged out on `response`,
+    /// e.g. the background of a [`crate::CentralPanel`].
+    ///
+    /// The marquee replaces the selection, or adds to it while shift or command is held.
+    /// If the `response` also senses clicks, clicking it clears the selection.
+    pub fn marquee(ui: &Ui, response: &Response) -> LassoResponse {
+        let ctx = ui.ctx();
+        let lasso = Lasso::new("area_selection_marquee").show(ui, response);
+        let add = ui.input(|i| i.modifiers.shift || i.modifiers.command);
+
+        if let Some(marquee) = lasso.finished_selection() {
+            let mut selection = if add { Self::load(ctx) } else { Self::default() };
+            for (id, rect) in selectable_areas(ctx) {
+                if marquee.intersects_rect(rect) && !selection.contains(id) {
+                    selection.ids.push(id);
+                }
+            }
+            selection.store(ctx);
+        } else if response.clicked() && !add {
+            Self::default().store(ctx);
+        }
+
+        lasso
+    }
+
+    /// Line up the left edges ([`Align::Min`]), centers or right edges of the selected areas,
+    /// with those furthest out.
+    pub fn align_x(&self, ctx: &Context, align: Align) {
+        self.align(ctx, 0, align);
+    }
+
+    /// Line up the top edges ([`Align::Min`]), centers or bottom edges of the selected areas,
+    /// with those furthest out.
+    pub fn align_y(&self, ctx: &Context, align: Align) {
+        self.align(ctx, 1, align);
+    }
+
+    /// Move the selected areas so the horizontal gaps between them are all the same.
+    ///
+    /// The left-most and right-most areas stay where they are.
+    pub fn distribute_x(&self, ctx: &Context) {
+        self.distribute(ctx, 0);
+    }
+
+    /// Move the selected areas so the vertical gaps between them are all the same.
+    ///
+    /// The top-most and bottom-most areas stay where they are.
+    pub fn distribute_y(&self, ctx: &Context) {
+        self.distribute(ctx, 1);
+    }
+
+    /// Buttons to align and distribute the current selection.
+    pub fn toolbar(ui: &mut Ui) -> Response {
+        type Action = fn(&AreaSelection, &Context);
+
+        let selection = Self::load(ui.ctx());
+        let aligns: [(&str, &str, Action); 6] = [
+            ("Left", "Align the left edges", |s, ctx| s.align_x(ctx, Align::Min)),
+            ("Center", "Align the horizontal centers", |s, ctx| {
+                s.align_x(ctx, Align::Center);
+            }),
+            ("Right", "Align the right edges", |s, ctx| s.align_x(ctx, Align::Max)),
+            ("Top", "Align the top edges", |s, ctx| s.align_y(ctx, Align::Min)),
+            ("Middle", "Align the vertical centers", |s, ctx| {
+                s.align_y(ctx, Align::Center);
+            }),
+            ("Bottom", "Align the bottom edges", |s, ctx| s.align_y(ctx, Align::Max)),
+        ];
+        let distributes: [(&str, &str, Action); 2] = [
+            ("Distribute ↔", "Make the horizontal gaps the same", Self::distribute_x),
+            ("Distribute ↕", "Make the vertical gaps the same", Self::distribute_y),
+        ];
+
+        ui.horizontal(|ui| {
+            for (buttons, min_len) in [(&aligns[..], 2), (&distributes[..], 3)] {
+                for &(text, hover_text, action) in buttons {
+                    let enabled = min_len <= selection.len();
+                    if ui
+                        .add_enabled(enabled, crate::Button::new(text))
+                        .on_hover_text(hover_text)
+                        .clicked()
+                    {
+                        action(&selection, ui.ctx());
+                    }
+                }
+                ui.separator();
+            }
+            ui.label(format!("{} selected", selection.len()));
+        })
+        .response
+    }
+
+    fn align(&self, ctx: &Context, axis: usize, align: Align) {
+        let rects = self.rects(ctx);
+        if rects.len() < 2 {
+            return;
+        }
+        let bounds = rects
+            .iter()
+            .fold(Rect::NOTHING, |bounds, (_, rect)| bounds.union(*rect));
+        let range = if axis == 0 {
+            bounds.x_range()
+        } else {
+            bounds.y_range()
+        };
+        for (id, rect) in rects {
+            let mut delta = Vec2::ZERO;
+            delta[axis] = align.align_size_within_range(rect.size()[axis], range).min
+                - rect.min[axis];
+            move_area(ctx, id, delta);
+        }
+    }
+
+    fn distribute(&self, ctx: &Context, axis: usize) {
+        let mut rects = self.rects(ctx);
+        if rects.len() < 3 {
+            return;
+        }
+        rects.sort_by(|(_, a), (_, b)| a.center()[axis].total_cmp(&b.center()[axis]));
+
+        let start = rects.iter().map(|(_, r)| r.min[axis]).fold(f32::INFINITY, f32::min);
+        let end = rects.iter().map(|(_, r)| r.max[axis]).fold(f32::NEG_INFINITY, f32::max);
+        let total_size: f32 = rects.iter().map(|(_, r)| r.size()[axis]).sum();
+        let gap = (end - start - total_size) / (rects.len() - 1) as f32;
+
+        let mut pos = start;
+        for (id, rect) in rects {
+            let mut delta = Vec2::ZERO;
+            delta[axis] = pos - rect.min[axis];
+            move_area(ctx, id, delta);
+            pos += rect.size()[axis] + gap;
+        }
+    }
+
+    /// The selected areas that are shown, with where they are.
+    fn rects(&self, ctx: &Context) -> Vec<(Id, Rect)> {
+        selectable_areas(ctx)
+            .into_iter()
+            .filter(|(id, _)| self.contains(*id))
+            .collect()
+    }
+
+    /// Command-clicking a selectable area selects or unselects it.
+    pub(crate) fn toggle_on_click(ctx: &Context, id: Id, response: &Response) {
+        if response.clicked() && ctx.input(|i| i.modifiers.command) {
+            let mut selection = Self::load(ctx);
+            selection.toggle(id);
+            selection.store(ctx);
+        }
+    }
+
+    /// When `dragged` was moved by `delta`, move the other selected areas with it.
+    pub(crate) fn move_with(ctx: &Context, dragged: Id, delta: Vec2) {
+        if delta == Vec2::ZERO {
+            return;
+        }
+        let selection = Self::load(ctx);
+        if selection.contains(dragged) {
+            for &id in selection.ids.iter().filter(|&&id| id != dragged) {
+                move_area(ctx, id, delta);
+            }
+        }
+    }
+}
+
+/// The visible selectable areas, back to front.
+fn selectable_areas(ctx: &Context) -> Vec<(Id, Rect)> {
+    ctx.memory(|mem| {
+        let areas = mem.areas();
+        areas
+            .order()
+            .iter()
+            .filter(|layer| areas.is_visible(layer) && !areas.is_sublayer(layer))
+            .filter(|layer| layer.order != Order::Tooltip)
+            .filter_map(|layer| areas.get(layer.id).map(|state| (layer.id, state)))
+            .filter(|(_, state)| state.selectable)
+            .map(|(id, state)| (id, state.rect()))
+            .collect()
+    })
+}
+
+fn move_area(ctx: &Context, id: Id, delta: Vec2) {
+    ctx.memory_mut(|mem| {
+        if let Some(state) = mem.areas_mut().get_mut(id) {
+            let pos = state.left_top_pos() + delta;
+            state.set_left_top_pos(pos);
+        }
+    });
+    ctx.request_repaint();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Area, AreaState, RawInput, pos2};
+
+    fn show_areas(ctx: &Context, positions: &[(Id, crate::Pos2)]) {
+        let _ = ctx.run(RawInput::default(), |ctx| {
+            for &(id, pos) in positions {
+                Area::new(id).selectable(true).default_pos(pos).show(ctx, |ui| {
+                    ui.allocate_space(Vec2::splat(20.0));
+                });
+            }
+        });
+    }
+
+    fn left_top(ctx: &Context, id: Id) -> crate::Pos2 {
+        AreaState::load(ctx, id).unwrap().left_top_pos()
+    }
+
+    #[test]
+    fn align_and_distribute() {
+        let ctx = Context::default();
+        let (a, b, c) = (Id::new("a"), Id::new("b"), Id::new("c"));
+        let positions = [(a, pos2(10.0, 10.0)), (b, pos2(100.0, 40.0)), (c, pos2(50.0, 70.0))];
+        show_areas(&ctx, &positions);
+        show_areas(&ctx, &positions);
+
+        let selection = AreaSelection { ids: vec![a, b, c] };
+        selection.align_y(&ctx, Align::Min);
+        assert_eq!(left_top(&ctx, b).y, 10.0);
+        assert_eq!(left_top(&ctx, c).y, 10.0);
+
+        let gap = |ctx: &Context, from: Id, to: Id| {
+            left_top(ctx, to).x - AreaState::load(ctx, from).unwrap().rect().right()
+        };
+        selection.distribute_x(&ctx);
+        assert_eq!(left_top(&ctx, a).x, 10.0, "The outermost areas stay");
+        assert_eq!(left_top(&ctx, b).x, 100.0, "The outermost areas stay");
+        assert_eq!(gap(&ctx, a, c), gap(&ctx, c, b));
+
+        // The others follow the one dragged:
+        AreaSelection::move_with(&ctx, a, Vec2::new(5.0, 0.0));
+        assert_eq!(left_top(&ctx, a).x, 10.0, "The dragged area moves itself");
+        assert_eq!(left_top(&ctx, b).x, 105.0);
+    }
+}
@@ -0,0 +1,375 @@
+use emath::{NumExt as _, Rect, pos2, remap_clamp, vec2};
+
+use crate::{
+    Area, Color32, Context, CornerRadius, Frame, Id, InnerResponse, LayerId, Order, Response,
+    Sense, Ui, UiBuilder, UiKind,
+};
+
+/// How long it takes the sheet to settle at a snap point, in seconds.
+const ANIMATION_TIME: f32 = 0.25;
+
+/// Letting go of the handle while moving faster than this (in points per second)
+/// moves the sheet to the next snap point in that direction.
+const FLING_VELOCITY: f32 = 500.0;
+
+/// Height of the draggable area at the top of the sheet.
+const HANDLE_HEIGHT: f32 = 20.0;
+
+/// How much of the content stays visible above the sheet at [`SheetDetent::Full`].
+const FULL_TOP_GAP: f32 = 24.0;
+
+/// The heights a [`BottomSheet`] can snap to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum SheetDetent {
+    /// Only the top of the sheet is visible, see [`BottomSheet::peek_height`].
+    Peek,
+
+    /// The sheet covers half the screen.
+    Half,
+
+    /// The sheet covers almost all of the screen.
+    Full,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct State {
+    detent: SheetDetent,
+
+    /// The height while the user is dragging the handle.
+    drag_height: Option<f32>,
+}
+
+/// What was shown in a [`BottomSheet`] this frame.
+pub struct BottomSheetResponse<R> {
+    /// What the contents returned.
+    pub inner: R,
+
+    /// The response of the whole sheet.
+    pub response: Response,
+
+    /// The snap point the sheet is at, or moving to.
+    pub detent: SheetDetent,
+}
+
+/// A panel that slides up from the bottom edge of the screen,
+/// the mobile-friendly counterpart to [`crate::SidePanel`].
+///
+/// The user can drag the handle at the top of the sheet to move it between the snap points
+/// ([`SheetDetent::Peek`], [`SheetDetent::Half`] and [`SheetDetent::Full`]),
+/// or fling it down to dismiss it.
+/// Above the peek height, a scrim darkens the content behind the sheet;
+/// clicking it dismisses the sheet too.
+///
+/// Use [`crate::Context::breakpoint`] to choose between a bottom sheet and a side panel.
+///
+/// ```
+/// # egui::__run_test_ctx(|ctx| {
+/// # let mut open = true;
+/// egui::BottomSheet::new("details").show(ctx, &mut open, |ui| {
+///     ui.heading("Details");
+///     egui::ScrollArea::vertical().show(ui, |ui| {
+///         ui.label("Lots of content");
+///     });
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct BottomSheet {
+    id: Id,
+    snap_points: Vec<SheetDetent>,
+    default_detent: SheetDetent,
+    peek_height: f32,
+    max_width: f32,
+    scrim_color: Color32,
+    dismissible: bool,
+    frame: Option<Frame>,
+}
+
+impl BottomSheet {
+    /// The id should be globally unique, e.g. `Id::new("my_bottom_sheet")`.
+    pub fn new(id: impl Into<Id>) -> Self {
+        Self {
+            id: id.into(),
+            snap_points: vec![SheetDetent::Peek, SheetDetent::Half, SheetDetent::Full],
+            default_detent: SheetDetent::Half,
+            peek_height: 96.0,
+            max_width: 640.0,
+            scrim_color: Color32::from_black_alpha(100),
+            dismissible: true,
+            frame: None,
+        }
+    }
+
+    /// Which heights the sheet can snap to.
+    ///
+    /// Default: all of them.
+    #[inline]
+    pub fn snap_points(mut self, snap_points: impl IntoIterator<Item = SheetDetent>) -> Self {
+        self.snap_points = snap_points.into_iter().collect();
+        self.snap_points.sort();
+        self.snap_points.dedup();
+        self
+    }
+
+    /// Where the sheet slides up to when it is opened.
+    ///
+    /// Default: [`SheetDetent::Half`].
+    #[inline]
+    pub fn default_detent(mut self, default_detent: SheetDetent) -> Self {
+        self.default_detent = default_detent;
+        self
+    }
+
+    /// The height of the sheet at [`SheetDetent::Peek`].
+    ///
+    /// Default: `96.0`.
+    #[inline]
+    pub fn peek_height(mut self, peek_height: f32) -> Self {
+        self.peek_height = peek_height;
+        self
+    }
+
+    /// On wide screens the sheet is centered, and at most this wide.
+    ///
+    /// Default: `640.0`.
+    #[inline]
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// The color of the scrim covering the content behind the sheet.
+    ///
+    /// Default: `Color32::from_black_alpha(100)`.
+    #[inline]
+    pub fn scrim_color(mut self, scrim_color: Color32) -> Self {
+        self.scrim_color = scrim_color;
+        self
+    }
+
+    /// Can the user close the sheet by flinging it down or clicking the scrim?
+    ///
+    /// If not, the sheet goes to the lowest snap point instead.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn dismissible(mut self, dismissible: bool) -> Self {
+        self.dismissible = dismissible;
+        self
+    }
+
+    /// Change the background of the sheet.
+    ///
+    /// Default: [`Frame::window`], with only the top corners rounded.
+    #[inline]
+    pub fn frame(mut self, frame: Frame) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+
+    /// Show the sheet while `open` is `true`, and animate it away when it becomes `false`.
+    ///
+    /// `open` is set to `false` when the user dismisses the sheet.
+    ///
+    /// Returns `None` when the sheet is fully closed.
+    pub fn show<R>(
+        self,
+        ctx: &Context,
+        open: &mut bool,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<BottomSheetResponse<R>> {
+        let Self {
+            id,
+            snap_points,
+            default_detent,
+            peek_height,
+            max_width,
+            scrim_color,
+            dismissible,
+            frame,
+        } = self;
+
+        let screen_rect = ctx.screen_rect();
+        let height_of = |detent: SheetDetent| {
+            let height = match detent {
+                SheetDetent::Peek => peek_height,
+                SheetDetent::Half => 0.5 * screen_rect.height(),
+                SheetDetent::Full => screen_rect.height() - FULL_TOP_GAP,
+            };
+            height.clamp(0.0, screen_rect.height())
+        };
+
+        let snap_points = if snap_points.is_empty() {
+            vec![default_detent]
+        } else {
+            snap_points
+        };
+        let nearest_snap_point = |height: f32| {
+            snap_points
+                .iter()
+                .copied()
+                .min_by(|a, b| {
+                    let distance = |detent| (height_of(detent) - height).abs();
+                    distance(*a).total_cmp(&distance(*b))
+                })
+                .unwrap_or(default_detent)
+        };
+        let lowest = snap_points[0];
+
+        let height_id = id.with("height");
+        let mut state = ctx.data(|d| d.get_temp::<State>(id));
+        if *open && state.is_none() {
+            // Slide up from the bottom edge:
+            ctx.animate_value_with_time(height_id, 0.0, 0.0);
+            state = Some(State {
+                detent: nearest_snap_point(height_of(default_detent)),
+                drag_height: None,
+            });
+        }
+        let mut state = state?;
+
+        if !*open {
+            state.drag_height = None;
+        }
+        let target_height = if *open { height_of(state.detent) } else { 0.0 };
+        let animated_height = ctx.animate_value_with_time(height_id, target_height, ANIMATION_TIME);
+        if !*open && animated_height <= 0.0 {
+            ctx.data_mut(|d| d.remove::<State>(id));
+            return None;
+        }
+        let height = state.drag_height.unwrap_or(animated_height);
+
+        let sheet_layer = LayerId::new(Order::Foreground, id);
+
+        // Fade in the scrim between the peek and half height:
+        let scrim_start = if lowest == SheetDetent::Peek {
+            height_of(SheetDetent::Peek)
+        } else {
+            0.0
+        };
+        let scrim_end = height_of(SheetDetent::Half).at_least(scrim_start + 1.0);
+        let scrim_opacity = remap_clamp(height, scrim_start..=scrim_end, 0.0..=1.0);
+        if scrim_opacity > 0.0 {
+            let scrim = Area::new(id.with("scrim"))
+                .order(Order::Foreground)
+                .fixed_pos(screen_rect.min)
+                .constrain(false)
+                .show(ctx, |ui| {
+                    let response = ui.allocate_rect(screen_rect, Sense::click());
+                    ui.painter().rect_filled(
+                        screen_rect,
+                        0.0,
+                        scrim_color.gamma_multiply(scrim_opacity),
+                    );
+                    response.clicked()
+                });
+            ctx.set_sublayer(scrim.response.layer_id, sheet_layer);
+
+            if scrim.inner {
+                if dismissible {
+                    *open = false;
+                } else {
+                    state.detent = lowest;
+                }
+            }
+        }
+
+        let width = screen_rect.width().at_most(max_width);
+        let sheet_rect = Rect::from_min_max(
+            pos2(screen_rect.center().x - 0.5 * width, screen_rect.bottom() - height),
+            pos2(screen_rect.center().x + 0.5 * width, screen_rect.bottom()),
+        );
+
+        let frame = frame.unwrap_or_else(|| {
+            let frame = Frame::window(&ctx.style());
+            let radius = frame.corner_radius.nw;
+            frame.corner_radius(CornerRadius {
+                nw: radius,
+                ne: radius,
+                sw: 0,
+                se: 0,
+            })
+        });
+
+        let InnerResponse {
+            inner: (inner, handle_response),
+            response,
+        } = Area::new(id)
+            .kind(UiKind::BottomSheet)
+            .order(Order::Foreground)
+            .fixed_pos(sheet_rect.min)
+            .constrain(false)
+            .show(ctx, |ui| {
+                let content_rect = sheet_rect - frame.total_margin();
+                ui.painter().add(frame.paint(content_rect));
+
+                let handle_rect = Rect::from_min_size(
+                    sheet_rect.min,
+                    vec2(sheet_rect.width(), HANDLE_HEIGHT),
+                );
+                let handle_response = ui.interact(handle_rect, id.with("handle"), Sense::drag());
+                let handle_color = ui.style().interact(&handle_response).fg_stroke.color;
+                ui.painter().rect_filled(
+                    Rect::from_center_size(handle_rect.center(), vec2(32.0, 4.0)),
+                    2.0,
+                    handle_color.gamma_multiply(0.5),
+                );
+
+                let mut content_rect = content_rect;
+                content_rect.min.y = content_rect.min.y.at_least(handle_rect.max.y);
+                content_rect.max.y = content_rect.max.y.at_least(content_rect.min.y);
+                let mut content_ui = ui.new_child(UiBuilder::new().max_rect(content_rect));
+                content_ui.set_clip_rect(content_rect.intersect(ui.clip_rect()));
+                let inner = add_contents(&mut content_ui);
+
+                ui.expand_to_include_rect(sheet_rect);
+                (inner, handle_response)
+            });
+
+        if handle_response.dragged() {
+            let drag_height = state.drag_height.unwrap_or(height) - handle_response.drag_delta().y;
+            let drag_height = drag_height.clamp(0.0, height_of(SheetDetent::Full));
+            state.drag_height = Some(drag_height);
+            ctx.animate_value_with_time(height_id, drag_height, 0.0);
+        } else if let Some(released_height) = state.drag_height.take() {
+            let velocity = ctx.input(|i| i.pointer.velocity().y); // positive is down
+            let next = if velocity > FLING_VELOCITY {
+                snap_points
+                    .iter()
+                    .rev()
+                    .copied()
+                    .find(|&detent| height_of(detent) < released_height)
+            } else if velocity < -FLING_VELOCITY {
+                Some(
+                    snap_points
+                        .iter()
+                        .copied()
+                        .find(|&detent| released_height < height_of(detent))
+                        .unwrap_or(snap_points[snap_points.len() - 1]),
+                )
+            } else if released_height < 0.5 * height_of(lowest) {
+                None
+            } else {
+                Some(nearest_snap_point(released_height))
+            };
+
+            match next {
+                Some(detent) => state.detent = detent,
+                None if dismissible => *open = false,
+                None => state.detent = lowest,
+            }
+
+            // Continue the animation from where the user let go:
+            ctx.animate_value_with_time(height_id, released_height, 0.0);
+        }
+
+        ctx.data_mut(|d| d.insert_temp(id, state));
+
+        Some(BottomSheetResponse {
+            inner,
+            response,
+            detent: state.detent,
+        })
+    }
+}
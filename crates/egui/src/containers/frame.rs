@@ -485,7 +485,11 @@ impl Prepared {
         let widget_rect = self.frame.widget_rect(content_rect);
 
         if ui.is_rect_visible(widget_rect) {
-            let shape = self.frame.paint(content_rect);
+            let mut frame = self.frame;
+            if ui.ctx().is_degraded() {
+                frame.shadow = Shadow::NONE;
+            }
+            let shape = frame.paint(content_rect);
             ui.painter().set(self.where_to_put_background, shape);
         }
     }
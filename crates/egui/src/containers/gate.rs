@@ -0,0 +1,248 @@
+//! Widgets for features the current user may not use, e.g. because of their role or plan.
+
+use std::sync::Arc;
+
+use emath::{Rect, Vec2, pos2, vec2};
+use epaint::{CornerRadius, PathShape, PathStroke};
+
+use crate::{Context, Id, Response, Sense, Ui};
+
+/// What the current user may do with a gated feature. See [`GatePolicy`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GateAccess {
+    /// Show and use the feature as usual.
+    Allowed,
+
+    /// Show the feature disabled and with a lock.
+    ///
+    /// The reason, e.g. "Upgrade to Pro to export", is shown on hover.
+    Locked(String),
+
+    /// Don't show the feature at all.
+    Hidden,
+}
+
+/// Decides which features the current user may use, for all [`Gate`]s.
+///
+/// Set it with [`Gate::set_policy`].
+/// A closure `Fn(&str) -> GateAccess` is also a policy.
+pub trait GatePolicy: Send + Sync {
+    /// What the current user may do with the feature.
+    fn access(&self, feature: &str) -> GateAccess;
+
+    /// The user clicked a locked feature, e.g. to open a dialog about upgrading.
+    fn on_locked_click(&self, _ctx: &Context, _feature: &str) {}
+}
+
+impl<F: Fn(&str) -> GateAccess + Send + Sync> GatePolicy for F {
+    fn access(&self, feature: &str) -> GateAccess {
+        self(feature)
+    }
+}
+
+/// Show widgets only as far as the [`GatePolicy`] allows the current user to use them.
+///
+/// Locked features are shown disabled with a lock, or replaced with [`Self::replace_with`],
+/// and hovering them shows the reason they are locked.
+/// Without a policy, everything is allowed.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::{Gate, GateAccess};
+///
+/// Gate::set_policy(ui.ctx(), |feature: &str| match feature {
+///     "export" => GateAccess::Locked("Upgrade to Pro to export".to_owned()),
+///     "admin" => GateAccess::Hidden,
+///     _ => GateAccess::Allowed,
+/// });
+///
+/// let gate = Gate::new("export").show(ui, |ui| ui.button("Export…").clicked());
+/// if gate.inner == Some(true) {
+///     // export
+/// }
+/// Gate::new("admin").show(ui, |ui| ui.button("Users"));
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Gate<'a> {
+    feature: &'a str,
+    replacement: Option<Box<dyn FnOnce(&mut Ui) + 'a>>,
+}
+
+impl<'a> Gate<'a> {
+    /// Gate the feature with this name, as it is known to the [`GatePolicy`].
+    pub fn new(feature: &'a str) -> Self {
+        Self {
+            feature,
+            replacement: None,
+        }
+    }
+
+    /// When the feature is locked, show this instead of the disabled feature,
+    /// e.g. a button to upgrade.
+    #[inline]
+    pub fn replace_with(mut self, replacement: impl FnOnce(&mut Ui) + 'a) -> Self {
+        self.replacement = Some(Box::new(replacement));
+        self
+    }
+
+    fn policy_id() -> Id {
+        Id::new("gate_policy")
+    }
+
+    fn policy(ctx: &Context) -> Option<Arc<dyn GatePolicy>> {
+        ctx.data(|d| d.get_temp(Self::policy_id()))
+    }
+
+    /// Use this policy for all gates from now on.
+    pub fn set_policy(ctx: &Context, policy: impl GatePolicy + 'static) {
+        let policy: Arc<dyn GatePolicy> = Arc::new(policy);
+        ctx.data_mut(|d| d.insert_temp(Self::policy_id(), policy));
+    }
+
+    /// What the current user may do with the feature, according to the policy.
+    pub fn access(ctx: &Context, feature: &str) -> GateAccess {
+        Self::policy(ctx).map_or(GateAccess::Allowed, |policy| policy.access(feature))
+    }
+
+    /// Show the feature with `add_contents`, unless the policy says otherwise.
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> GateResponse<R> {
+        let Self {
+            feature,
+            replacement,
+        } = self;
+        let access = Self::access(ui.ctx(), feature);
+
+        match &access {
+            GateAccess::Allowed => {
+                let response = ui.scope(add_contents);
+                GateResponse {
+                    inner: Some(response.inner),
+                    response: Some(response.response),
+                    access,
+                }
+            }
+
+            GateAccess::Hidden => GateResponse {
+                inner: None,
+                response: None,
+                access,
+            },
+
+            GateAccess::Locked(reason) => {
+                let (inner, rect) = if let Some(replacement) = replacement {
+                    (None, ui.scope(replacement).response.rect)
+                } else {
+                    let response = ui.add_enabled_ui(false, add_contents);
+                    (Some(response.inner), response.response.rect)
+                };
+
+                // On top of the disabled widgets, so it gets the hovers and clicks:
+                let mut response = ui.interact(rect, ui.auto_id_with(feature), Sense::click());
+                paint_lock(ui, rect);
+                if !reason.is_empty() {
+                    response = response.on_hover_text(reason.as_str());
+                }
+                if response.clicked() {
+                    if let Some(policy) = Self::policy(ui.ctx()) {
+                        policy.on_locked_click(ui.ctx(), feature);
+                    }
+                }
+
+                GateResponse {
+                    inner,
+                    response: Some(response),
+                    access,
+                }
+            }
+        }
+    }
+}
+
+/// What [`Gate::show`] returns.
+#[derive(Debug)]
+pub struct GateResponse<R> {
+    /// What the contents returned, unless they weren't shown.
+    ///
+    /// The contents of a locked feature are disabled, so nothing in them can be clicked.
+    pub inner: Option<R>,
+
+    /// The response of everything shown, or `None` if the feature is hidden.
+    pub response: Option<Response>,
+
+    /// What the policy allowed.
+    pub access: GateAccess,
+}
+
+impl<R> GateResponse<R> {
+    /// Did the user click the feature even though it is locked?
+    pub fn locked_clicked(&self) -> bool {
+        matches!(self.access, GateAccess::Locked(_))
+            && self.response.as_ref().is_some_and(|r| r.clicked())
+    }
+}
+
+/// A small padlock in the top right corner of `rect`.
+fn paint_lock(ui: &Ui, rect: Rect) {
+    let size = 0.5 * ui.spacing().interact_size.y;
+    let icon = Rect::from_min_size(pos2(rect.right() - size, rect.top()), Vec2::splat(size));
+    let color = ui.visuals().strong_text_color();
+
+    let body = Rect::from_min_max(pos2(icon.left(), icon.top() + 0.45 * size), icon.max);
+    ui.painter().rect_filled(body, CornerRadius::same((0.15 * size) as u8), color);
+
+    let center = pos2(icon.center().x, body.top());
+    let radius = 0.3 * size;
+    let shackle = (0..=8)
+        .map(|i| {
+            let angle = std::f32::consts::PI * (1.0 + i as f32 / 8.0);
+            center + radius * vec2(angle.cos(), angle.sin())
+        })
+        .collect();
+    ui.painter().add(PathShape::line(shackle, PathStroke::new(0.15 * size, color)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CentralPanel, RawInput};
+
+    #[test]
+    fn policy_decides() {
+        let ctx = Context::default();
+        Gate::set_policy(&ctx, |feature: &str| match feature {
+            "locked" => GateAccess::Locked("Upgrade".to_owned()),
+            "hidden" => GateAccess::Hidden,
+            _ => GateAccess::Allowed,
+        });
+
+        let _ = ctx.run(RawInput::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                let allowed = Gate::new("allowed").show(ui, |ui| ui.is_enabled());
+                assert_eq!(allowed.inner, Some(true));
+
+                let locked = Gate::new("locked").show(ui, |ui| ui.is_enabled());
+                assert_eq!(locked.inner, Some(false), "Shown, but disabled");
+                assert_eq!(locked.access, GateAccess::Locked("Upgrade".to_owned()));
+
+                let replaced = Gate::new("locked")
+                    .replace_with(|ui| {
+                        ui.label("Upgrade");
+                    })
+                    .show(ui, |_ui| ());
+                assert_eq!(replaced.inner, None);
+                assert!(replaced.response.is_some());
+
+                let hidden = Gate::new("hidden").show(ui, |ui| ui.button("Admin"));
+                assert!(hidden.inner.is_none());
+                assert!(hidden.response.is_none());
+            });
+        });
+
+        assert_eq!(Gate::access(&Context::default(), "locked"), GateAccess::Allowed);
+    }
+}
@@ -3,14 +3,14 @@
 //! Show menus via
 //! - [`Popup::menu`] and [`Popup::context_menu`]
 //! - [`Ui::menu_button`], [`MenuButton`] and [`SubMenuButton`]
-//! - [`MenuBar`]
+//! - [`MenuBar`], and [`MenuBar::show_menus`] for menus that can also go in the native menu bar
 //! - [`Response::context_menu`]
 //!
 //! See [`MenuBar`] for an example.
 
 use crate::style::StyleModifier;
 use crate::{
-    Button, Color32, Context, Frame, Id, InnerResponse, IntoAtoms, Layout, Popup,
+    Button, Color32, Context, Frame, Id, InnerResponse, IntoAtoms, KeyboardShortcut, Layout, Popup,
     PopupCloseBehavior, Response, Style, Ui, UiBuilder, UiKind, UiStack, UiStackInfo, Widget as _,
 };
 use emath::{Align, RectAlign, Vec2, vec2};
@@ -259,6 +259,231 @@ impl MenuBar {
             .inner
         })
     }
+
+    /// Show menus described by `menus`, and return the id of the button that was clicked, if any.
+    ///
+    /// The keyboard shortcuts of the buttons work even when their menu is closed.
+    ///
+    /// If the integration supports a native menu bar (see [`crate::RawInput::native_menu_bar`]),
+    /// the menus are shown there instead, and nothing is added to `ui`.
+    /// That way Mac users get the standard app menus, while other platforms get the same
+    /// menus rendered by egui, from a single definition.
+    ///
+    /// ### Example:
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::containers::menu::{NativeMenu, NativeMenuButton};
+    /// use egui::{Id, Key, KeyboardShortcut, Modifiers};
+    ///
+    /// let menus = [NativeMenu::new("File")
+    ///     .button(
+    ///         NativeMenuButton::new(Id::new("open"), "Open…")
+    ///             .shortcut(KeyboardShortcut::new(Modifiers::COMMAND, Key::O)),
+    ///     )
+    ///     .separator()
+    ///     .button(NativeMenuButton::new(Id::new("quit"), "Quit"))];
+    ///
+    /// if egui::MenuBar::new().show_menus(ui, &menus) == Some(Id::new("quit")) {
+    ///     ui.ctx().send_viewport_cmd(egui::ViewportCommand::Close);
+    /// }
+    /// # });
+    /// ```
+    pub fn show_menus(self, ui: &mut Ui, menus: &[NativeMenu]) -> Option<Id> {
+        let ctx = ui.ctx().clone();
+
+        if ctx.input(|i| i.raw.native_menu_bar) {
+            // Only send the menus to the integration when they change:
+            let sent_id = Id::new("egui_native_menu_bar");
+            let hash = crate::util::hash(menus);
+            if ctx.data(|d| d.get_temp::<u64>(sent_id)) != Some(hash) {
+                ctx.data_mut(|d| d.insert_temp(sent_id, hash));
+                ctx.output_mut(|o| o.native_menu_bar = Some(menus.to_vec()));
+            }
+
+            let native_click = ctx.input(|i| {
+                i.events.iter().find_map(|event| match event {
+                    crate::Event::NativeMenuButton(id) => Some(*id),
+                    _ => None,
+                })
+            });
+
+            // The native menu handles most shortcuts itself, but not necessarily all keys:
+            return native_click.or_else(|| shortcut_clicked(&ctx, menus));
+        }
+
+        let mut clicked = shortcut_clicked(&ctx, menus);
+        self.ui(ui, |ui| {
+            for menu in menus {
+                menu_ui(ui, menu, &mut clicked);
+            }
+        });
+        clicked
+    }
+}
+
+/// A menu described as data, for [`MenuBar::show_menus`].
+///
+/// Unlike menus built with [`Ui::menu_button`], these can be handed to the integration
+/// and shown in the native menu bar (e.g. on macOS).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NativeMenu {
+    pub title: String,
+    pub items: Vec<NativeMenuItem>,
+}
+
+impl NativeMenu {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            items: Vec::new(),
+        }
+    }
+
+    /// Add a button.
+    #[inline]
+    pub fn button(mut self, button: NativeMenuButton) -> Self {
+        self.items.push(NativeMenuItem::Button(button));
+        self
+    }
+
+    /// Add a separator line.
+    #[inline]
+    pub fn separator(mut self) -> Self {
+        self.items.push(NativeMenuItem::Separator);
+        self
+    }
+
+    /// Add a submenu.
+    #[inline]
+    pub fn sub_menu(mut self, menu: Self) -> Self {
+        self.items.push(NativeMenuItem::SubMenu(menu));
+        self
+    }
+
+    fn collect_buttons<'a>(&'a self, buttons: &mut Vec<&'a NativeMenuButton>) {
+        for item in &self.items {
+            match item {
+                NativeMenuItem::Button(button) => buttons.push(button),
+                NativeMenuItem::Separator => {}
+                NativeMenuItem::SubMenu(menu) => menu.collect_buttons(buttons),
+            }
+        }
+    }
+}
+
+/// An entry in a [`NativeMenu`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum NativeMenuItem {
+    Button(NativeMenuButton),
+    Separator,
+    SubMenu(NativeMenu),
+}
+
+/// A clickable entry in a [`NativeMenu`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NativeMenuButton {
+    /// Returned by [`MenuBar::show_menus`] when the button is clicked.
+    pub id: Id,
+
+    pub text: String,
+
+    /// Clicks the button when pressed, even if the menu is closed.
+    pub shortcut: Option<KeyboardShortcut>,
+
+    pub enabled: bool,
+
+    /// If set, a checkmark is shown when `true`.
+    pub checked: Option<bool>,
+}
+
+impl NativeMenuButton {
+    pub fn new(id: Id, text: impl Into<String>) -> Self {
+        Self {
+            id,
+            text: text.into(),
+            shortcut: None,
+            enabled: true,
+            checked: None,
+        }
+    }
+
+    #[inline]
+    pub fn shortcut(mut self, shortcut: KeyboardShortcut) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+
+    /// Default: `true`.
+    #[inline]
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Show a checkmark when `checked` is `true`.
+    #[inline]
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+}
+
+/// The button whose shortcut was pressed this frame, if any.
+fn shortcut_clicked(ctx: &Context, menus: &[NativeMenu]) -> Option<Id> {
+    let mut buttons = Vec::new();
+    for menu in menus {
+        menu.collect_buttons(&mut buttons);
+    }
+    let mut buttons: Vec<(&KeyboardShortcut, Id)> = buttons
+        .into_iter()
+        .filter(|button| button.enabled)
+        .filter_map(|button| Some((button.shortcut.as_ref()?, button.id)))
+        .collect();
+
+    // Match the most specific shortcuts first, see `InputState::consume_shortcut`:
+    buttons.sort_by_key(|(shortcut, _)| {
+        let m = shortcut.modifiers;
+        std::cmp::Reverse(
+            [m.alt, m.ctrl, m.shift, m.command]
+                .into_iter()
+                .filter(|&b| b)
+                .count(),
+        )
+    });
+
+    ctx.input_mut(|i| {
+        buttons
+            .into_iter()
+            .find_map(|(shortcut, id)| i.consume_shortcut(shortcut).then_some(id))
+    })
+}
+
+fn menu_ui(ui: &mut Ui, menu: &NativeMenu, clicked: &mut Option<Id>) {
+    ui.menu_button(menu.title.as_str(), |ui| {
+        for item in &menu.items {
+            match item {
+                NativeMenuItem::Button(button) => {
+                    let mut widget = Button::new(button.text.as_str());
+                    if let Some(shortcut) = &button.shortcut {
+                        widget = widget.shortcut_text(ui.ctx().format_shortcut(shortcut));
+                    }
+                    if let Some(checked) = button.checked {
+                        widget = widget.selected(checked);
+                    }
+                    if ui.add_enabled(button.enabled, widget).clicked() {
+                        *clicked = Some(button.id);
+                    }
+                }
+                NativeMenuItem::Separator => {
+                    ui.separator();
+                }
+                NativeMenuItem::SubMenu(sub_menu) => menu_ui(ui, sub_menu, clicked),
+            }
+        }
+    });
 }
 
 /// A thin wrapper around a [`Button`] that shows a [`Popup::menu`] when clicked.
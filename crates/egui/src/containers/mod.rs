@@ -3,36 +3,52 @@
 //! For instance, a [`Frame`] adds a frame and background to some contained UI.
 
 pub(crate) mod area;
+mod area_selection;
+mod bottom_sheet;
 mod close_tag;
 pub mod collapsing_header;
 mod combo_box;
 pub mod frame;
+mod gate;
 pub mod menu;
 pub mod modal;
 pub mod old_popup;
 pub mod panel;
 mod popup;
+mod presentation;
+mod pull_to_refresh;
 pub(crate) mod resize;
+mod rulers;
 mod scene;
 pub mod scroll_area;
 mod sides;
-mod tooltip;
+mod steps;
+mod swipe_row;
+pub(crate) mod tooltip;
 pub(crate) mod window;
 
 pub use {
     area::{Area, AreaState},
+    area_selection::AreaSelection,
+    bottom_sheet::{BottomSheet, BottomSheetResponse, SheetDetent},
     close_tag::ClosableTag,
     collapsing_header::{CollapsingHeader, CollapsingResponse},
     combo_box::*,
     frame::Frame,
+    gate::{Gate, GateAccess, GatePolicy, GateResponse},
     modal::{Modal, ModalResponse},
     old_popup::*,
     panel::{CentralPanel, SidePanel, TopBottomPanel},
     popup::*,
+    presentation::{Presentation, PresentationRole},
+    pull_to_refresh::{PullToRefresh, PullToRefreshOutput},
     resize::Resize,
+    rulers::{Guides, Rulers, RulersResponse},
     scene::{DragPanButtons, Scene},
     scroll_area::ScrollArea,
     sides::Sides,
+    steps::{Steps, StepsResponse, StepsState},
+    swipe_row::{SwipeAction, SwipeDirection, SwipeRow, SwipeRowResponse},
     tooltip::*,
     window::Window,
 };
@@ -43,8 +43,11 @@ impl PanelState {
         self.rect.size()
     }
 
-    fn store(self, ctx: &Context, bar_id: Id) {
-        ctx.data_mut(|d| d.insert_persisted(bar_id, self));
+    pub(crate) fn store(self, ctx: &Context, bar_id: Id) {
+        ctx.memory_mut(|mem| {
+            mem.data.insert_persisted(bar_id, self);
+            mem.panel_ids.insert(bar_id);
+        });
     }
 }
 
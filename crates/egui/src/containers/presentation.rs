@@ -0,0 +1,197 @@
+//! Showing an app on a second monitor, e.g. a projector or the wall of a control room.
+
+use emath::TSTransform;
+
+use crate::{
+    CentralPanel, Context, Id, LayerId, Ui, UiBuilder, ViewportBuilder, ViewportClass,
+    ViewportCommand, ViewportId, Window,
+};
+
+/// Which of the two windows of a [`Presentation`] some ui is shown in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PresentationRole {
+    /// The window of the one giving the presentation, i.e. the current viewport.
+    Presenter,
+
+    /// The window the audience sees, e.g. on a projector.
+    Audience,
+}
+
+/// Show ui in a second window for an audience, e.g. the slides of a slide show on a projector,
+/// or an overview on the wall of a control room.
+///
+/// The audience window is an immediate viewport, so it is shown in the same frame as the
+/// current one and its ui can use the same state as the presenter's: a slide changed by the
+/// presenter is shown to the audience right away.
+/// The state of the widgets (e.g. scroll positions) is shared too, as both windows use the same
+/// [`Id`]s.
+///
+/// Where multiple viewports are not supported (see [`Context::embed_viewports`]),
+/// the audience window is shown as a [`Window`] instead.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut slide = 0;
+/// // The same slides for everyone, larger for the audience:
+/// egui::Presentation::new("slides")
+///     .monitor(Some(1))
+///     .scale(2.0)
+///     .mirror(ui, |ui, role| {
+///         ui.heading(format!("Slide {slide}"));
+///         if role == egui::PresentationRole::Presenter && ui.button("Next").clicked() {
+///             slide += 1;
+///         }
+///     });
+///
+/// // Or notes for the presenter, and only the slide for the audience:
+/// ui.label("Notes for this slide…");
+/// egui::Presentation::new("slides").show_audience(ui.ctx(), |ui| {
+///     ui.heading(format!("Slide {slide}"));
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show_audience() or .mirror()"]
+pub struct Presentation {
+    viewport_id: ViewportId,
+    title: String,
+    monitor: Option<usize>,
+    scale: f32,
+}
+
+impl Presentation {
+    /// The audience window is identified by `id_salt`, which must be unique among viewports.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            viewport_id: ViewportId::from_hash_of(id_salt),
+            title: "Presentation".to_owned(),
+            monitor: None,
+            scale: 1.0,
+        }
+    }
+
+    /// The title of the audience window.
+    #[inline]
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Make the audience window fullscreen on this monitor,
+    /// an index into [`crate::RawInput::monitors`].
+    ///
+    /// With `None` (the default), it is a normal window that the user can move.
+    #[inline]
+    pub fn monitor(mut self, monitor: Option<usize>) -> Self {
+        self.monitor = monitor;
+        self
+    }
+
+    /// Scale the ui of the audience window by this, independently of the presenter's,
+    /// e.g. to make it readable from the back of the room.
+    ///
+    /// Default: `1.0`.
+    #[inline]
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// The id of the audience viewport, e.g. for sending it [`ViewportCommand`]s.
+    pub fn viewport_id(&self) -> ViewportId {
+        self.viewport_id
+    }
+
+    /// Show `add_contents` in the audience window.
+    ///
+    /// Returns `None` if the audience window is embedded and collapsed.
+    pub fn show_audience<R>(
+        self,
+        ctx: &Context,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<R> {
+        let Self {
+            viewport_id,
+            title,
+            monitor,
+            scale,
+        } = self;
+
+        let mut add_contents = Some(add_contents);
+        let builder = ViewportBuilder::default().with_title(title.clone());
+        ctx.show_viewport_immediate(viewport_id, builder, |ctx, class| {
+            let add_contents = add_contents.take()?;
+            if class == ViewportClass::Embedded {
+                return Window::new(title.clone())
+                    .id(Id::new(viewport_id))
+                    .show(ctx, |ui| show_scaled(ui, scale, add_contents))
+                    .and_then(|response| response.inner);
+            }
+
+            if let Some(monitor) = monitor {
+                // Only once, so that the user can still leave fullscreen:
+                let id = Id::new(viewport_id).with("monitor");
+                if ctx.data(|data| data.get_temp::<usize>(id)) != Some(monitor) {
+                    ctx.send_viewport_cmd(ViewportCommand::FullscreenOnMonitor(monitor));
+                    ctx.data_mut(|data| data.insert_temp(id, monitor));
+                }
+            }
+
+            let panel =
+                CentralPanel::default().show(ctx, |ui| show_scaled(ui, scale, add_contents));
+            Some(panel.inner)
+        })
+    }
+
+    /// Show the same ui both in `ui` and in the audience window.
+    ///
+    /// `add_contents` is called once for each, with the [`PresentationRole`] of its window,
+    /// e.g. to show controls only to the presenter.
+    pub fn mirror(self, ui: &mut Ui, mut add_contents: impl FnMut(&mut Ui, PresentationRole)) {
+        add_contents(ui, PresentationRole::Presenter);
+        let ctx = ui.ctx().clone();
+        self.show_audience(&ctx, |ui| add_contents(ui, PresentationRole::Audience));
+    }
+}
+
+/// Show `add_contents` in a layer scaled by `scale`, filling `ui`.
+fn show_scaled<R>(ui: &mut Ui, scale: f32, add_contents: impl FnOnce(&mut Ui) -> R) -> R {
+    if scale == 1.0 {
+        return add_contents(ui);
+    }
+
+    let layer_id = LayerId::new(ui.layer_id().order, ui.id().with("presentation_scale"));
+    ui.ctx().set_sublayer(ui.layer_id(), layer_id);
+    let to_global = TSTransform::from_scaling(scale);
+    ui.ctx().set_transform_layer(layer_id, to_global);
+
+    let max_rect = to_global.inverse() * ui.max_rect();
+    let mut scaled_ui = ui.new_child(UiBuilder::new().layer_id(layer_id).max_rect(max_rect));
+    scaled_ui.set_clip_rect(to_global.inverse() * ui.clip_rect());
+    let ret = add_contents(&mut scaled_ui);
+
+    ui.allocate_rect(to_global * scaled_ui.min_rect(), crate::Sense::hover());
+    ret
+}
+
+#[test]
+fn test_presentation_mirror() {
+    use crate::RawInput;
+
+    let ctx = Context::default();
+    let mut roles = vec![];
+    let _ = ctx.run(RawInput::default(), |ctx| {
+        CentralPanel::default().show(ctx, |ui| {
+            Presentation::new("test")
+                .scale(2.0)
+                .mirror(ui, |ui, role| {
+                    roles.push(role);
+                    ui.label("Slide");
+                });
+        });
+    });
+    assert_eq!(
+        roles,
+        [PresentationRole::Presenter, PresentationRole::Audience],
+        "The audience window is embedded by default"
+    );
+}
@@ -0,0 +1,257 @@
+use emath::{Align2, NumExt as _, Rect, vec2};
+use epaint::{Shape, Stroke};
+
+use crate::{
+    Id, ScrollArea, Spinner, TextStyle, Ui, scroll_area::ScrollAreaOutput,
+    style::OverscrollEffect,
+};
+
+/// How long the "done" check mark is shown after a refresh has completed, in seconds.
+const COMPLETION_DURATION: f64 = 0.6;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+enum Phase {
+    #[default]
+    Idle,
+
+    /// Waiting for the app to finish refreshing.
+    Refreshing,
+
+    /// Showing the completion animation, started at the given time.
+    Finished { since: f64 },
+}
+
+/// What happened in a [`PullToRefresh`] container this frame.
+pub struct PullToRefreshOutput<R> {
+    /// The output of the wrapped [`ScrollArea`], including what the contents returned.
+    pub scroll_area: ScrollAreaOutput<R>,
+
+    /// Did the user pull down far enough and let go this frame?
+    ///
+    /// If so, start refreshing and pass `true` to [`PullToRefresh::refreshing`] until you are done.
+    pub refresh_requested: bool,
+}
+
+/// A vertical [`ScrollArea`] that the user can pull down past the top to refresh the contents,
+/// as is common on touch screens.
+///
+/// While pulling, an indicator shows how far the user has to pull.
+/// When the user lets go past [`Self::threshold`], you get
+/// [`PullToRefreshOutput::refresh_requested`] (and the [`Self::on_refresh`] callback is called).
+/// A spinner is then shown for as long as you pass `true` to [`Self::refreshing`],
+/// followed by a short completion animation.
+///
+/// This uses [`crate::style::ScrollStyle::overscroll_effect`],
+/// which defaults to [`OverscrollEffect::Stretch`] within this container if it is turned off.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut loading = false;
+/// let output = egui::PullToRefresh::new("feed")
+///     .refreshing(loading)
+///     .show(ui, |ui| {
+///         ui.label("Latest news");
+///     });
+/// if output.refresh_requested {
+///     loading = true; // … and start loading
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct PullToRefresh<'a> {
+    id_salt: Id,
+    scroll_area: ScrollArea,
+    threshold: f32,
+    refreshing: bool,
+    on_refresh: Option<Box<dyn FnOnce() + 'a>>,
+}
+
+impl<'a> PullToRefresh<'a> {
+    /// The `id_salt` must be unique among sibling widgets.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        let id_salt = Id::new(id_salt);
+        Self {
+            id_salt,
+            scroll_area: ScrollArea::vertical().id_salt(id_salt),
+            threshold: 48.0,
+            refreshing: false,
+            on_refresh: None,
+        }
+    }
+
+    /// Use this [`ScrollArea`] instead of the default vertical one,
+    /// e.g. to set [`ScrollArea::auto_shrink`] or [`ScrollArea::stick_to_bottom`].
+    #[inline]
+    pub fn scroll_area(mut self, scroll_area: ScrollArea) -> Self {
+        self.scroll_area = scroll_area;
+        self
+    }
+
+    /// How far (in points) the user has to pull down before a refresh is triggered.
+    ///
+    /// This is also the height of the gap the spinner is shown in while refreshing.
+    /// It is limited to a bit less than [`crate::style::ScrollStyle::max_overscroll`].
+    ///
+    /// Default: `48.0`.
+    #[inline]
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Is the app currently refreshing?
+    ///
+    /// Set this to `true` from the frame you get [`PullToRefreshOutput::refresh_requested`]
+    /// until the refresh is done; the completion animation starts on the first frame it is `false`.
+    /// You can also set it without the user pulling, e.g. during the initial load.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn refreshing(mut self, refreshing: bool) -> Self {
+        self.refreshing = refreshing;
+        self
+    }
+
+    /// Called when the user triggers a refresh.
+    ///
+    /// The same as checking [`PullToRefreshOutput::refresh_requested`].
+    #[inline]
+    pub fn on_refresh(mut self, on_refresh: impl FnOnce() + 'a) -> Self {
+        self.on_refresh = Some(Box::new(on_refresh));
+        self
+    }
+
+    /// Show the contents in the scroll area.
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> PullToRefreshOutput<R> {
+        let Self {
+            id_salt,
+            scroll_area,
+            threshold,
+            refreshing,
+            on_refresh,
+        } = self;
+
+        let id = ui.make_persistent_id(id_salt);
+        let ctx = ui.ctx().clone();
+        let time = ui.input(|i| i.time);
+        let threshold = threshold
+            .at_most(0.9 * ui.spacing().scroll.max_overscroll)
+            .at_least(1.0);
+
+        let mut phase: Phase = ctx.data(|d| d.get_temp(id)).unwrap_or_default();
+        if refreshing {
+            phase = Phase::Refreshing;
+        } else if phase == Phase::Refreshing {
+            phase = Phase::Finished { since: time };
+        }
+        if let Phase::Finished { since } = phase {
+            if COMPLETION_DURATION <= time - since {
+                phase = Phase::Idle;
+            } else {
+                ctx.request_repaint();
+            }
+        }
+
+        // Keep a gap at the top for the spinner while refreshing,
+        // and for the check mark during the first half of the completion animation:
+        let keep_gap = match phase {
+            Phase::Idle => false,
+            Phase::Refreshing => true,
+            Phase::Finished { since } => time - since < 0.5 * COMPLETION_DURATION,
+        };
+        let gap = ctx.animate_value_with_time(
+            id.with("gap"),
+            if keep_gap { threshold } else { 0.0 },
+            ui.style().animation_time,
+        );
+
+        let scroll_area = ui
+            .scope(|ui| {
+                let scroll = &mut ui.spacing_mut().scroll;
+                if scroll.overscroll_effect == OverscrollEffect::None {
+                    scroll.overscroll_effect = OverscrollEffect::Stretch;
+                }
+                scroll_area.show(ui, |ui| {
+                    if 0.0 < gap {
+                        ui.add_space(gap);
+                    }
+                    add_contents(ui)
+                })
+            })
+            .inner;
+
+        let pulled = -scroll_area.state.overscroll().y;
+        let released = -scroll_area.state.overscroll_released().y;
+
+        let refresh_requested = phase == Phase::Idle && threshold <= released;
+        if refresh_requested {
+            phase = Phase::Refreshing;
+            if let Some(on_refresh) = on_refresh {
+                on_refresh();
+            }
+        }
+
+        // The indicator goes in the space revealed above the content:
+        let inner_rect = scroll_area.inner_rect;
+        let revealed = pulled.max(gap - scroll_area.state.offset.y);
+        if 1.0 < revealed {
+            let size = ui.spacing().interact_size.y.at_most(revealed);
+            let center = inner_rect.center_top() + vec2(0.0, revealed / 2.0);
+            let rect = Rect::from_center_size(center, vec2(size, size));
+            let painter = ui.painter().with_clip_rect(inner_rect);
+
+            match phase {
+                Phase::Idle => {
+                    let progress = (pulled / threshold).at_most(1.0);
+                    paint_progress(ui, &painter, rect, progress);
+                }
+                Phase::Refreshing => {
+                    Spinner::new().paint_at(ui, rect);
+                }
+                Phase::Finished { since } => {
+                    let opacity = 1.0 - ((time - since) / COMPLETION_DURATION) as f32;
+                    painter.text(
+                        rect.center(),
+                        Align2::CENTER_CENTER,
+                        "✔",
+                        TextStyle::Heading.resolve(ui.style()),
+                        ui.visuals().strong_text_color().gamma_multiply(opacity),
+                    );
+                }
+            }
+        }
+
+        ctx.data_mut(|d| d.insert_temp(id, phase));
+
+        PullToRefreshOutput {
+            scroll_area,
+            refresh_requested,
+        }
+    }
+}
+
+/// An arc that closes as the user pulls further, turning solid once letting go would refresh.
+fn paint_progress(ui: &Ui, painter: &crate::Painter, rect: Rect, progress: f32) {
+    let visuals = ui.visuals();
+    let color = if 1.0 <= progress {
+        visuals.strong_text_color()
+    } else {
+        visuals.weak_text_color().gamma_multiply(progress.at_least(0.2))
+    };
+
+    let radius = rect.height() / 2.0 - 2.0;
+    let n_points = (radius.round() as u32).clamp(8, 64);
+    let start_angle = -std::f32::consts::FRAC_PI_2;
+    let end_angle = start_angle + 0.9 * std::f32::consts::TAU * progress;
+    let points = (0..=n_points)
+        .map(|i| {
+            let angle = emath::lerp(start_angle..=end_angle, i as f32 / n_points as f32);
+            rect.center() + radius * vec2(angle.cos(), angle.sin())
+        })
+        .collect();
+    painter.add(Shape::line(points, Stroke::new(3.0, color)));
+}
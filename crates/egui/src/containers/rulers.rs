@@ -0,0 +1,643 @@
+use emath::{Pos2, Rect, TSTransform, Vec2, pos2, vec2};
+use epaint::{Stroke, TextShape};
+
+use crate::{Context, CursorIcon, Id, LayerId, Response, Sense, TextStyle, Ui, WidgetRect};
+
+/// Guide lines placed by dragging them out of [`Rulers`].
+///
+/// The positions are in the units shown on the rulers, i.e. relative to [`Rulers::origin`].
+/// The guides of [`Rulers`] are persisted by egui (if the `persistence` feature is on),
+/// and can be changed with [`Self::load`] and [`Self::store`].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Guides {
+    /// The x coordinate of each vertical guide.
+    pub vertical: Vec<f32>,
+
+    /// The y coordinate of each horizontal guide.
+    pub horizontal: Vec<f32>,
+}
+
+impl Guides {
+    pub fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data_mut(|d| d.get_persisted(id))
+    }
+
+    pub fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_persisted(id, self));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vertical.is_empty() && self.horizontal.is_empty()
+    }
+
+    /// The x coordinate of the vertical guide closest to `x`, if any is within `max_distance`.
+    pub fn snap_x(&self, x: f32, max_distance: f32) -> Option<f32> {
+        closest(&self.vertical, x, max_distance)
+    }
+
+    /// The y coordinate of the horizontal guide closest to `y`, if any is within `max_distance`.
+    pub fn snap_y(&self, y: f32, max_distance: f32) -> Option<f32> {
+        closest(&self.horizontal, y, max_distance)
+    }
+
+    /// Move `pos` onto the closest guides within `max_distance`, separately along x and y.
+    pub fn snap_pos(&self, pos: Pos2, max_distance: f32) -> Pos2 {
+        pos2(
+            self.snap_x(pos.x, max_distance).unwrap_or(pos.x),
+            self.snap_y(pos.y, max_distance).unwrap_or(pos.y),
+        )
+    }
+
+    /// Move `rect` so that its closest edge or center lines up with a guide within `max_distance`,
+    /// separately along x and y.
+    ///
+    /// Use this for things that are dragged, e.g. together with a [`crate::TransformGizmo`].
+    pub fn snap_rect(&self, rect: Rect, max_distance: f32) -> Rect {
+        let snap = |guides: &[f32], edges: [f32; 3]| {
+            edges
+                .into_iter()
+                .filter_map(|edge| Some(closest(guides, edge, max_distance)? - edge))
+                .min_by(|a, b| a.abs().total_cmp(&b.abs()))
+                .unwrap_or(0.0)
+        };
+        rect.translate(vec2(
+            snap(&self.vertical, [rect.left(), rect.center().x, rect.right()]),
+            snap(&self.horizontal, [rect.top(), rect.center().y, rect.bottom()]),
+        ))
+    }
+
+    /// The vertical guides for `axis == 0`, and the horizontal ones for `axis == 1`.
+    fn axis(&self, axis: usize) -> &[f32] {
+        if axis == 0 {
+            &self.vertical
+        } else {
+            &self.horizontal
+        }
+    }
+
+    fn axis_mut(&mut self, axis: usize) -> &mut Vec<f32> {
+        if axis == 0 {
+            &mut self.vertical
+        } else {
+            &mut self.horizontal
+        }
+    }
+}
+
+fn closest(guides: &[f32], value: f32, max_distance: f32) -> Option<f32> {
+    guides
+        .iter()
+        .copied()
+        .filter(|guide| (guide - value).abs() <= max_distance)
+        .min_by(|a, b| (a - value).abs().total_cmp(&(b - value).abs()))
+}
+
+/// What happened with the [`Rulers`] this frame.
+#[derive(Clone, Debug)]
+pub struct RulersResponse {
+    /// Where the [`Guides`] are stored.
+    pub id: Id,
+
+    /// The guides, after any changes made this frame.
+    pub guides: Guides,
+
+    /// Did the user add, move or remove a guide this frame?
+    pub changed: bool,
+
+    /// The pointer position, in ruler units, if it is over the canvas.
+    pub pointer: Option<Pos2>,
+}
+
+/// Horizontal and vertical rulers along the top and left edges of a canvas,
+/// with guides that can be dragged out of them.
+///
+/// Show this inside a [`crate::Scene`] or [`crate::ScrollArea`],
+/// and the rulers follow its panning and zooming.
+/// They are painted on top of the contents, in screen space, along the edges of what is visible.
+///
+/// * Drag from a ruler into the canvas to add a guide.
+/// * Drag a guide to move it. It snaps to the ruler ticks, unless shift is held.
+/// * Drag a guide back onto its ruler to remove it.
+///
+/// Other things can snap to the guides with [`Self::snap_pos`] and [`Self::snap_rect`].
+/// The guides are remembered by egui, see [`Guides`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut scene_rect = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(400.0, 300.0));
+/// # let mut node_pos = egui::pos2(50.0, 50.0);
+/// egui::Scene::new().show(ui, &mut scene_rect, |ui| {
+///     let rulers = egui::Rulers::new("rulers");
+///
+///     let node = egui::Rect::from_min_size(node_pos, egui::vec2(100.0, 60.0));
+///     let response = ui.interact(node, ui.id().with("node"), egui::Sense::drag());
+///     if response.dragged() {
+///         node_pos = rulers.snap_rect(ui, node.translate(response.drag_delta())).min;
+///     }
+///     ui.painter().rect_filled(node, 4.0, ui.visuals().widgets.inactive.bg_fill);
+///
+///     rulers.show(ui);
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Rulers {
+    id_salt: Id,
+    thickness: f32,
+    origin: Option<Pos2>,
+    snap_distance: f32,
+    editable: bool,
+    guide_stroke: Option<Stroke>,
+}
+
+impl Rulers {
+    /// The `id_salt` must be unique among sibling widgets.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            thickness: 20.0,
+            origin: None,
+            snap_distance: 6.0,
+            editable: true,
+            guide_stroke: None,
+        }
+    }
+
+    /// The width of the rulers, in points.
+    ///
+    /// Default: `20.0`.
+    #[inline]
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Where zero is on the rulers, in the coordinates of the [`Ui`] they are shown in.
+    ///
+    /// Default: the top left corner of [`Ui::max_rect`],
+    /// which is `(0, 0)` in a [`crate::Scene`],
+    /// and the top left of the contents in a [`crate::ScrollArea`].
+    #[inline]
+    pub fn origin(mut self, origin: Pos2) -> Self {
+        self.origin = Some(origin);
+        self
+    }
+
+    /// How close things must be to snap, in points on screen.
+    ///
+    /// Default: `6.0`.
+    #[inline]
+    pub fn snap_distance(mut self, snap_distance: f32) -> Self {
+        self.snap_distance = snap_distance;
+        self
+    }
+
+    /// Can the user add, move and remove guides?
+    ///
+    /// If not, the guides are still shown and snapped to.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
+
+    /// How to paint the guides.
+    ///
+    /// Default: a thin line in the selection color of the current [`crate::Visuals`].
+    #[inline]
+    pub fn guide_stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.guide_stroke = Some(stroke.into());
+        self
+    }
+
+    /// Where the [`Guides`] are stored, for [`Guides::load`] and [`Guides::store`].
+    pub fn id(&self, ui: &Ui) -> Id {
+        ui.make_persistent_id(self.id_salt)
+    }
+
+    /// The current guides, from the last time the rulers were shown.
+    pub fn guides(&self, ui: &Ui) -> Guides {
+        Guides::load(ui.ctx(), self.id(ui)).unwrap_or_default()
+    }
+
+    /// Move `pos`, in the coordinates of `ui`, onto the closest guides.
+    pub fn snap_pos(&self, ui: &Ui, pos: Pos2) -> Pos2 {
+        let space = self.space(ui);
+        let snapped = self
+            .guides(ui)
+            .snap_pos(space.ui_to_ruler(pos), space.ruler_distance(self.snap_distance));
+        space.ruler_to_ui(snapped)
+    }
+
+    /// Move `rect`, in the coordinates of `ui`, so that an edge or its center is on a guide.
+    pub fn snap_rect(&self, ui: &Ui, rect: Rect) -> Rect {
+        let space = self.space(ui);
+        let in_ruler = rect.translate(space.ui_to_ruler(Pos2::ZERO).to_vec2());
+        let snapped = self
+            .guides(ui)
+            .snap_rect(in_ruler, space.ruler_distance(self.snap_distance));
+        // Ruler units are the same size as the units of the `ui`:
+        rect.translate(snapped.min - in_ruler.min)
+    }
+
+    fn space(&self, ui: &Ui) -> RulerSpace {
+        RulerSpace {
+            to_global: ui
+                .ctx()
+                .layer_transform_to_global(ui.layer_id())
+                .unwrap_or_default(),
+            origin: self.origin.unwrap_or_else(|| ui.max_rect().min),
+        }
+    }
+
+    /// Paint the rulers and guides on top of what is visible of `ui`,
+    /// and let the user edit the guides.
+    pub fn show(self, ui: &Ui) -> RulersResponse {
+        let id = self.id(ui);
+        let space = self.space(ui);
+        let ctx = ui.ctx();
+        let mut guides = Guides::load(ctx, id).unwrap_or_default();
+        let old_guides = guides.clone();
+
+        let canvas = space.to_global * ui.clip_rect();
+        let pointer = ctx
+            .pointer_latest_pos()
+            .filter(|pos| canvas.contains(*pos))
+            .map(|pos| space.global_to_ruler(pos));
+
+        if !canvas.is_positive() || !space.to_global.is_valid() {
+            return RulersResponse {
+                id,
+                guides,
+                changed: false,
+                pointer,
+            };
+        }
+
+        // Rulers and guides are in screen space, on a layer above the contents:
+        let layer_id = LayerId::new(ui.layer_id().order, id.with("rulers_layer"));
+        let parent_layer = ctx
+            .memory(|mem| mem.areas().parent_layer(ui.layer_id()))
+            .unwrap_or(ui.layer_id());
+        ctx.set_sublayer(parent_layer, layer_id);
+
+        let thickness = self.thickness.min(canvas.width()).min(canvas.height());
+        let bars = [
+            Rect::from_x_y_ranges(
+                canvas.left() + thickness..=canvas.right(),
+                canvas.top()..=canvas.top() + thickness,
+            ),
+            Rect::from_x_y_ranges(
+                canvas.left()..=canvas.left() + thickness,
+                canvas.top() + thickness..=canvas.bottom(),
+            ),
+        ];
+        let step = TickStep::new(space.to_global.scaling);
+        let free = ui.input(|i| i.modifiers.shift);
+        let snap_to_tick = |value: f32| {
+            let tick = step.snap(value);
+            if !free && (tick - value).abs() * space.to_global.scaling <= self.snap_distance {
+                tick
+            } else {
+                value
+            }
+        };
+        let interact = |rect: Rect, id: Id, sense: Sense| -> Response {
+            ctx.create_widget(
+                WidgetRect {
+                    id,
+                    layer_id,
+                    rect,
+                    interact_rect: rect.intersect(canvas),
+                    sense,
+                    enabled: ui.is_enabled(),
+                },
+                true,
+            )
+        };
+
+        // Axis 0 is the vertical guides, dragged out of the top ruler along x;
+        // axis 1 is the horizontal guides, dragged out of the left ruler along y.
+        let mut hovered_guide = None;
+        let mut dragged_guide = None;
+        if self.editable {
+            let grab_radius = ui.style().interaction.resize_grab_radius_side;
+            let new_guide_id = id.with("new_guide");
+
+            let mut drags: Vec<(usize, usize, Response)> = vec![];
+            for axis in 0..2 {
+                for (index, &position) in guides.axis(axis).iter().enumerate() {
+                    let line = space.ruler_to_global_axis(axis, position);
+                    let grab_range = line - grab_radius..=line + grab_radius;
+                    let rect = if axis == 0 {
+                        Rect::from_x_y_ranges(grab_range, canvas.y_range())
+                    } else {
+                        Rect::from_x_y_ranges(canvas.x_range(), grab_range)
+                    };
+                    let response = interact(rect, id.with(("guide", axis, index)), Sense::drag());
+                    drags.push((axis, index, response));
+                }
+            }
+
+            // The rulers are on top of the guides, so that new guides can always be dragged out:
+            for (axis, bar) in bars.into_iter().enumerate() {
+                let response = interact(bar, id.with(("ruler", axis)), Sense::drag());
+                if response.drag_started() {
+                    let position = response
+                        .interact_pointer_pos()
+                        .map_or(0.0, |pos| space.global_to_ruler(pos)[axis]);
+                    guides.axis_mut(axis).push(snap_to_tick(position));
+                    let index = guides.axis_mut(axis).len() - 1;
+                    ctx.data_mut(|d| d.insert_temp(new_guide_id, (axis, index)));
+                }
+                if response.dragged() || response.drag_stopped() {
+                    if let Some((axis, index)) = ctx.data(|d| d.get_temp(new_guide_id)) {
+                        drags.push((axis, index, response));
+                    }
+                    if response.drag_stopped() {
+                        ctx.data_mut(|d| d.remove::<(usize, usize)>(new_guide_id));
+                    }
+                }
+            }
+
+            let mut removed = None;
+            for (axis, index, response) in drags {
+                if response.hovered() {
+                    hovered_guide = Some((axis, index));
+                }
+                if !response.dragged() && !response.drag_stopped() {
+                    continue;
+                }
+                dragged_guide = Some((axis, index));
+                let Some(pointer) = response.interact_pointer_pos() else {
+                    continue;
+                };
+                if let Some(position) = guides.axis_mut(axis).get_mut(index) {
+                    *position = snap_to_tick(space.global_to_ruler(pointer)[axis]);
+                }
+                let dropped_outside = !canvas.contains(pointer);
+                if response.drag_stopped() && (bars[axis].contains(pointer) || dropped_outside) {
+                    removed = Some((axis, index));
+                }
+            }
+            if let Some((axis, index)) = removed {
+                let positions = guides.axis_mut(axis);
+                if index < positions.len() {
+                    positions.remove(index);
+                }
+            }
+
+            if let Some((axis, _)) = dragged_guide.or(hovered_guide) {
+                ctx.set_cursor_icon(if axis == 0 {
+                    CursorIcon::ResizeHorizontal
+                } else {
+                    CursorIcon::ResizeVertical
+                });
+            }
+        }
+
+        // Paint:
+        let painter = ctx.layer_painter(layer_id).with_clip_rect(canvas);
+        let visuals = ui.visuals();
+        let guide_stroke = self
+            .guide_stroke
+            .unwrap_or_else(|| Stroke::new(1.0, visuals.selection.bg_fill));
+        for axis in 0..2 {
+            for (index, &position) in guides.axis(axis).iter().enumerate() {
+                let line = space.ruler_to_global_axis(axis, position);
+                let guide = Some((axis, index));
+                let stroke = if dragged_guide == guide || hovered_guide == guide {
+                    Stroke::new(2.0 * guide_stroke.width, guide_stroke.color)
+                } else {
+                    guide_stroke
+                };
+                if axis == 0 {
+                    painter.vline(line, canvas.y_range(), stroke);
+                } else {
+                    painter.hline(canvas.x_range(), line, stroke);
+                }
+            }
+        }
+
+        let font_id = TextStyle::Small.resolve(ui.style());
+        let border = visuals.widgets.noninteractive.bg_stroke;
+        let corner = Rect::from_min_size(canvas.min, Vec2::splat(thickness));
+        for rect in [bars[0], bars[1], corner] {
+            painter.rect_filled(rect, 0.0, visuals.extreme_bg_color);
+        }
+        painter.hline(canvas.x_range(), canvas.top() + thickness, border);
+        painter.vline(canvas.left() + thickness, canvas.y_range(), border);
+
+        for (axis, bar) in bars.into_iter().enumerate() {
+            let ruler_painter = painter.with_clip_rect(bar);
+            let screen_range = if axis == 0 { bar.x_range() } else { bar.y_range() };
+            let first = space.global_to_ruler_axis(axis, screen_range.min);
+            let last = space.global_to_ruler_axis(axis, screen_range.max);
+
+            for (value, major) in step.ticks(first..=last) {
+                let at = space.ruler_to_global_axis(axis, value);
+                let length = if major { 0.6 * thickness } else { 0.25 * thickness };
+                let color = if major {
+                    visuals.text_color()
+                } else {
+                    visuals.weak_text_color()
+                };
+                if axis == 0 {
+                    let x = at.round() + 0.5;
+                    let y_range = bar.bottom() - length..=bar.bottom();
+                    ruler_painter.vline(x, y_range, Stroke::new(1.0, color));
+                } else {
+                    let y = at.round() + 0.5;
+                    let x_range = bar.right() - length..=bar.right();
+                    ruler_painter.hline(x_range, y, Stroke::new(1.0, color));
+                }
+                if major {
+                    let galley =
+                        ruler_painter.layout_no_wrap(step.format(value), font_id.clone(), color);
+                    let shape = if axis == 0 {
+                        TextShape::new(pos2(at + 2.0, bar.top()), galley, color)
+                    } else {
+                        // Reading upwards, like most rulers do:
+                        TextShape::new(pos2(bar.left(), at - 2.0), galley, color)
+                            .with_angle(-std::f32::consts::FRAC_PI_2)
+                    };
+                    ruler_painter.add(shape);
+                }
+            }
+
+            if let Some(pointer) = pointer {
+                let at = space.ruler_to_global_axis(axis, pointer[axis]);
+                let stroke = Stroke::new(1.0, visuals.selection.stroke.color);
+                if axis == 0 {
+                    ruler_painter.vline(at, bar.y_range(), stroke);
+                } else {
+                    ruler_painter.hline(bar.x_range(), at, stroke);
+                }
+            }
+        }
+
+        let changed = guides != old_guides;
+        if changed {
+            guides.clone().store(ctx, id);
+        }
+
+        RulersResponse {
+            id,
+            guides,
+            changed,
+            pointer,
+        }
+    }
+}
+
+/// Maps between the coordinates of the [`Ui`], the units on the rulers, and the screen.
+#[derive(Clone, Copy)]
+struct RulerSpace {
+    /// From the [`Ui`] to the screen.
+    to_global: TSTransform,
+
+    /// Zero on the rulers, in the coordinates of the [`Ui`].
+    origin: Pos2,
+}
+
+impl RulerSpace {
+    fn ui_to_ruler(self, pos: Pos2) -> Pos2 {
+        (pos - self.origin).to_pos2()
+    }
+
+    fn ruler_to_ui(self, pos: Pos2) -> Pos2 {
+        self.origin + pos.to_vec2()
+    }
+
+    fn ruler_distance(self, screen_distance: f32) -> f32 {
+        screen_distance / self.to_global.scaling
+    }
+
+    fn global_to_ruler(self, pos: Pos2) -> Pos2 {
+        self.ui_to_ruler(self.to_global.inverse() * pos)
+    }
+
+    fn global_to_ruler_axis(self, axis: usize, screen: f32) -> f32 {
+        let pos = if axis == 0 { pos2(screen, 0.0) } else { pos2(0.0, screen) };
+        self.global_to_ruler(pos)[axis]
+    }
+
+    /// The screen coordinate of a ruler value.
+    fn ruler_to_global_axis(self, axis: usize, value: f32) -> f32 {
+        (self.to_global * self.ruler_to_ui(Pos2::ZERO + Vec2::splat(value)))[axis]
+    }
+}
+
+/// The distance between the ticks on the rulers, in ruler units.
+#[derive(Clone, Copy)]
+struct TickStep {
+    /// Labeled ticks.
+    major: f32,
+
+    /// Unlabeled ticks in between.
+    minor: f32,
+}
+
+impl TickStep {
+    /// Labels at least this many points apart:
+    const MIN_LABEL_SPACING: f32 = 50.0;
+
+    /// Unlabeled ticks at least this many points apart:
+    const MIN_TICK_SPACING: f32 = 5.0;
+
+    fn new(points_per_unit: f32) -> Self {
+        let min_major = Self::MIN_LABEL_SPACING / points_per_unit;
+        let mut major = emath::nice_number(f64::from(min_major)) as f32;
+        // `nice_number` rounds to the closest nice number, which may be too small,
+        // so go to the next one, e.g. from 2 to 5:
+        if major < min_major {
+            let power_of_ten = 10_f32.powf(major.log10().floor());
+            // Rounding errors can give a mantissa of 10 instead of 1:
+            let mantissa = major / power_of_ten;
+            let next = if mantissa < 1.5 {
+                2.0
+            } else if mantissa < 3.5 {
+                5.0
+            } else if mantissa < 7.5 {
+                10.0
+            } else {
+                20.0
+            };
+            major = next * power_of_ten;
+        }
+        let minor = if major / 10.0 * points_per_unit >= Self::MIN_TICK_SPACING {
+            major / 10.0
+        } else if major / 5.0 * points_per_unit >= Self::MIN_TICK_SPACING {
+            major / 5.0
+        } else {
+            major / 2.0
+        };
+        Self { major, minor }
+    }
+
+    /// The closest minor tick.
+    fn snap(self, value: f32) -> f32 {
+        (value / self.minor).round() * self.minor
+    }
+
+    /// All ticks in the range, and whether they are major ones.
+    fn ticks(self, range: std::ops::RangeInclusive<f32>) -> impl Iterator<Item = (f32, bool)> {
+        let first = (range.start() / self.minor).ceil() as i64;
+        let last = (range.end() / self.minor).floor() as i64;
+        let per_major = (self.major / self.minor).round() as i64;
+        (first..=last).map(move |i| (i as f32 * self.minor, i % per_major == 0))
+    }
+
+    fn format(self, value: f32) -> String {
+        let decimals = (-self.major.log10()).ceil().max(0.0) as usize;
+        emath::format_with_decimals_in_range(f64::from(value), 0..=decimals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use emath::{Rect, pos2, vec2};
+
+    use super::{Guides, TickStep};
+
+    #[test]
+    fn test_snap_to_guides() {
+        let guides = Guides {
+            vertical: vec![100.0, 200.0],
+            horizontal: vec![50.0],
+        };
+
+        assert_eq!(guides.snap_x(103.0, 5.0), Some(100.0));
+        assert_eq!(guides.snap_x(150.0, 5.0), None);
+        assert_eq!(guides.snap_pos(pos2(198.0, 80.0), 5.0), pos2(200.0, 80.0));
+
+        // The right edge is closest to a guide:
+        let rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(98.0, 20.0));
+        assert_eq!(guides.snap_rect(rect, 5.0).right(), 100.0);
+
+        // The center is closest to a guide:
+        let rect = Rect::from_min_size(pos2(0.0, 41.0), vec2(10.0, 20.0));
+        assert_eq!(guides.snap_rect(rect, 5.0).center().y, 50.0);
+    }
+
+    #[test]
+    fn test_tick_step() {
+        for points_per_unit in [0.01, 0.3, 1.0, 2.5, 40.0] {
+            let step = TickStep::new(points_per_unit);
+            assert!(TickStep::MIN_LABEL_SPACING <= step.major * points_per_unit);
+            assert!(step.minor < step.major);
+        }
+
+        let step = TickStep::new(1.0);
+        assert_eq!((step.major, step.minor), (50.0, 5.0));
+        let ticks: Vec<_> = step.ticks(-12.0..=55.0).collect();
+        assert_eq!(ticks.first(), Some(&(-10.0, false)));
+        assert_eq!(ticks.iter().filter(|(_, major)| *major).count(), 2);
+        assert_eq!(step.format(50.0), "50");
+        assert_eq!(TickStep::new(400.0).format(0.3), "0.3");
+    }
+}
@@ -5,6 +5,7 @@ use std::ops::{Add, AddAssign, BitOr, BitOrAssign};
 use crate::{
     Context, CursorIcon, Id, NumExt as _, Pos2, Rangef, Rect, Sense, Ui, UiBuilder, UiKind,
     UiStackInfo, Vec2, Vec2b, emath, epaint, lerp, pass_state, pos2, remap, remap_clamp,
+    style::OverscrollEffect,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -47,6 +48,18 @@ pub struct State {
 
     /// Area that can be dragged. This is the size of the content from the last frame.
     interact_rect: Option<Rect>,
+
+    /// How far the user has pulled the content past its end. See [`Self::overscroll`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    overscroll: Vec2,
+
+    /// Was the user pulling past the end of the content last frame?
+    #[cfg_attr(feature = "serde", serde(skip))]
+    overscroll_pulling: Vec2b,
+
+    /// See [`Self::overscroll_released`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    overscroll_released: Vec2,
 }
 
 impl Default for State {
@@ -61,6 +74,9 @@ impl Default for State {
             scroll_start_offset_from_top_left: [None; 2],
             scroll_stuck_to_end: Vec2b::TRUE,
             interact_rect: None,
+            overscroll: Vec2::ZERO,
+            overscroll_pulling: Vec2b::FALSE,
+            overscroll_released: Vec2::ZERO,
         }
     }
 }
@@ -78,6 +94,66 @@ impl State {
     pub fn velocity(&self) -> Vec2 {
         self.vel
     }
+
+    /// How far (in points) the user is pulling the content past its end.
+    ///
+    /// Negative when pulling past the top/left, positive when pulling past the bottom/right.
+    /// Goes back to zero once the user lets go.
+    ///
+    /// Only tracked if [`crate::style::ScrollStyle::overscroll_effect`] is enabled.
+    pub fn overscroll(&self) -> Vec2 {
+        self.overscroll
+    }
+
+    /// The [`Self::overscroll`] at the moment the user let go, or zero.
+    ///
+    /// This is non-zero for only one frame, so you can use it to implement pull-to-refresh:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let output = egui::ScrollArea::vertical().show(ui, |ui| {
+    ///     ui.label("Some content");
+    /// });
+    /// if output.state.overscroll_released().y < -50.0 {
+    ///     // refresh…
+    /// }
+    /// # });
+    /// ```
+    pub fn overscroll_released(&self) -> Vec2 {
+        self.overscroll_released
+    }
+
+    fn update_overscroll(&mut self, ctx: &Context, pull: Vec2, max_overscroll: f32) {
+        let dt = ctx.input(|i| i.stable_dt).at_most(0.1);
+        let max_overscroll = max_overscroll.at_least(1.0);
+
+        self.overscroll_released = Vec2::ZERO;
+
+        for d in 0..2 {
+            let pulling = pull[d] != 0.0;
+            if pulling {
+                // Like a rubber band: the further out, the harder it is to pull.
+                let resistance = 1.0 - (self.overscroll[d].abs() / max_overscroll).at_most(1.0);
+                self.overscroll[d] = (self.overscroll[d] + 0.5 * resistance * pull[d])
+                    .clamp(-max_overscroll, max_overscroll);
+            } else {
+                if self.overscroll_pulling[d] {
+                    self.overscroll_released[d] = self.overscroll[d];
+                }
+
+                // Bounce back:
+                self.overscroll[d] *= (1.0 - 10.0 * dt).at_least(0.0);
+                if self.overscroll[d].abs() < 0.5 {
+                    self.overscroll[d] = 0.0;
+                }
+            }
+            self.overscroll_pulling[d] = pulling;
+        }
+
+        if self.overscroll != Vec2::ZERO {
+            ctx.request_repaint();
+        }
+    }
 }
 
 pub struct ScrollAreaOutput<R> {
@@ -291,6 +367,7 @@ pub struct ScrollArea {
     on_drag_cursor: Option<CursorIcon>,
     scroll_source: ScrollSource,
     wheel_scroll_multiplier: Vec2,
+    swap_wheel_axes: bool,
 
     /// If true for vertical or horizontal the scroll wheel will stick to the
     /// end position until user manually changes position. It will become true
@@ -344,6 +421,7 @@ impl ScrollArea {
             on_drag_cursor: None,
             scroll_source: ScrollSource::default(),
             wheel_scroll_multiplier: Vec2::splat(1.0),
+            swap_wheel_axes: false,
             stick_to_end: Vec2b::FALSE,
             animated: true,
         }
@@ -565,6 +643,19 @@ impl ScrollArea {
         self
     }
 
+    /// If `true`, vertical wheel movements scroll this area horizontally, and vice versa.
+    ///
+    /// This is on top of [`crate::InputOptions::swap_scroll_axes`],
+    /// and is useful for e.g. a timeline that mostly scrolls horizontally.
+    /// Use [`Self::wheel_scroll_multiplier`] to invert the direction.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn swap_wheel_axes(mut self, swap_wheel_axes: bool) -> Self {
+        self.swap_wheel_axes = swap_wheel_axes;
+        self
+    }
+
     /// For each axis, should the containing area shrink if the content is small?
     ///
     /// * If `true`, egui will add blank space outside the scroll area.
@@ -653,6 +744,7 @@ struct Prepared {
 
     scroll_source: ScrollSource,
     wheel_scroll_multiplier: Vec2,
+    swap_wheel_axes: bool,
     stick_to_end: Vec2b,
 
     /// If there was a scroll target before the [`ScrollArea`] was added this frame, it's
@@ -678,6 +770,7 @@ impl ScrollArea {
             on_drag_cursor,
             scroll_source,
             wheel_scroll_multiplier,
+            swap_wheel_axes,
             stick_to_end,
             animated,
         } = self;
@@ -744,7 +837,13 @@ impl ScrollArea {
             }
         }
 
-        let content_max_rect = Rect::from_min_size(inner_rect.min - state.offset, content_max_size);
+        let stretch = if ui.spacing().scroll.overscroll_effect == OverscrollEffect::Stretch {
+            state.overscroll
+        } else {
+            Vec2::ZERO
+        };
+        let content_max_rect =
+            Rect::from_min_size(inner_rect.min - state.offset - stretch, content_max_size);
         let mut content_ui = ui.new_child(
             UiBuilder::new()
                 .ui_stack_info(UiStackInfo::new(UiKind::ScrollArea))
@@ -886,6 +985,7 @@ impl ScrollArea {
             viewport,
             scroll_source,
             wheel_scroll_multiplier,
+            swap_wheel_axes,
             stick_to_end,
             saved_scroll_target,
             animated,
@@ -1001,6 +1101,7 @@ impl Prepared {
             viewport: _,
             scroll_source,
             wheel_scroll_multiplier,
+            swap_wheel_axes,
             stick_to_end,
             saved_scroll_target,
             animated,
@@ -1119,17 +1220,23 @@ impl Prepared {
 
         let max_offset = content_size - inner_rect.size();
         let is_hovering_outer_rect = ui.rect_contains_pointer(outer_rect);
+
+        // How much the user tried to scroll past the end of the content:
+        let mut overscroll_pull = Vec2::ZERO;
+
         if scroll_source.mouse_wheel && ui.is_enabled() && is_hovering_outer_rect {
             let always_scroll_enabled_direction = ui.style().always_scroll_the_only_direction
                 && direction_enabled[0] != direction_enabled[1];
             for d in 0..2 {
                 if direction_enabled[d] {
+                    // Which axis of the input scrolls us along `d`:
+                    let wheel_d = if swap_wheel_axes { 1 - d } else { d };
                     let scroll_delta = ui.ctx().input(|input| {
                         if always_scroll_enabled_direction {
                             // no bidirectional scrolling; allow horizontal scrolling without pressing shift
                             input.smooth_scroll_delta[0] + input.smooth_scroll_delta[1]
                         } else {
-                            input.smooth_scroll_delta[d]
+                            input.smooth_scroll_delta[wheel_d]
                         }
                     });
                     let scroll_delta = scroll_delta * wheel_scroll_multiplier[d];
@@ -1146,12 +1253,14 @@ impl Prepared {
                                 input.smooth_scroll_delta[0] = 0.0;
                                 input.smooth_scroll_delta[1] = 0.0;
                             } else {
-                                input.smooth_scroll_delta[d] = 0.0;
+                                input.smooth_scroll_delta[wheel_d] = 0.0;
                             }
                         });
 
                         state.scroll_stuck_to_end[d] = false;
                         state.offset_target[d] = None;
+                    } else {
+                        overscroll_pull[d] -= scroll_delta;
                     }
                 }
             }
@@ -1173,6 +1282,9 @@ impl Prepared {
 
         let scroll_style = ui.spacing().scroll;
 
+        // Offset before clamping, so we know if the user dragged the content past its end:
+        let unbounded_offset = state.offset;
+
         // Paint the bars:
         let scroll_bar_rect = scroll_bar_rect.unwrap_or(inner_rect);
         for d in 0..2 {
@@ -1425,6 +1537,23 @@ impl Prepared {
         state.offset = state.offset.min(available_offset);
         state.offset = state.offset.max(Vec2::ZERO);
 
+        if scroll_style.overscroll_effect != OverscrollEffect::None {
+            // Only count dragging, not e.g. the content shrinking:
+            if ui.input(|i| i.pointer.any_down()) {
+                overscroll_pull += unbounded_offset - state.offset;
+            }
+            for d in 0..2 {
+                if !direction_enabled[d] {
+                    overscroll_pull[d] = 0.0;
+                }
+            }
+            state.update_overscroll(ui.ctx(), overscroll_pull, scroll_style.max_overscroll);
+
+            if scroll_style.overscroll_effect == OverscrollEffect::Glow {
+                paint_overscroll_glow(ui, inner_rect, state.overscroll, scroll_style.max_overscroll);
+            }
+        }
+
         // Is scroll handle at end of content, or is there no scrollbar
         // yet (not enough content), but sticking is requested? If so, enter sticky mode.
         // Only has an effect if stick_to_end is enabled but we save in
@@ -1446,3 +1575,47 @@ impl Prepared {
         (content_size, state)
     }
 }
+
+/// Paint a glow along the edges the user is pulling past.
+fn paint_overscroll_glow(ui: &Ui, inner_rect: Rect, overscroll: Vec2, max_overscroll: f32) {
+    for d in 0..2 {
+        let amount = overscroll[d];
+        if amount == 0.0 {
+            continue;
+        }
+
+        let strength = (amount.abs() / max_overscroll.at_least(1.0)).at_most(1.0);
+        let color = ui.visuals().selection.bg_fill.gamma_multiply(strength);
+        let thickness = amount.abs();
+
+        // The edge we are pulling past, and the direction towards the content:
+        let (edge, inward) = if amount < 0.0 {
+            (inner_rect.min[d], 1.0)
+        } else {
+            (inner_rect.max[d], -1.0)
+        };
+        let (cross_min, cross_max) = (inner_rect.min[1 - d], inner_rect.max[1 - d]);
+        let pos = |along: f32, cross: f32| {
+            if d == 0 {
+                pos2(along, cross)
+            } else {
+                pos2(cross, along)
+            }
+        };
+
+        let mut mesh = epaint::Mesh::default();
+        mesh.colored_vertex(pos(edge, cross_min), color);
+        mesh.colored_vertex(pos(edge, cross_max), color);
+        mesh.colored_vertex(
+            pos(edge + inward * thickness, cross_max),
+            epaint::Color32::TRANSPARENT,
+        );
+        mesh.colored_vertex(
+            pos(edge + inward * thickness, cross_min),
+            epaint::Color32::TRANSPARENT,
+        );
+        mesh.add_triangle(0, 1, 2);
+        mesh.add_triangle(0, 2, 3);
+        ui.painter().with_clip_rect(inner_rect).add(mesh);
+    }
+}
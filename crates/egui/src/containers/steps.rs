@@ -0,0 +1,368 @@
+use emath::{Align2, Rect, Vec2, vec2};
+use epaint::{Stroke, StrokeKind};
+
+use crate::{
+    Button, Context, Id, Response, Sense, TextStyle, Ui, UiBuilder, WidgetInfo, WidgetText,
+    WidgetType,
+};
+
+/// The state of a [`Steps`] container, stored in [`crate::Memory`] between frames.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct StepsState {
+    /// Index of the step currently shown.
+    pub current: usize,
+
+    /// The furthest step the user has reached so far.
+    ///
+    /// Steps up to and including this one can be jumped to by clicking the step indicator.
+    pub reached: usize,
+}
+
+impl StepsState {
+    pub fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data_mut(|d| d.get_persisted(id))
+    }
+
+    pub fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_persisted(id, self));
+    }
+
+    /// Go to the given step, e.g. to restart a wizard.
+    pub fn set_current(&mut self, step: usize) {
+        self.current = step;
+        self.reached = self.reached.max(step);
+    }
+}
+
+/// What happened in a [`Steps`] container this frame.
+pub struct StepsResponse<R> {
+    /// What the step contents returned.
+    pub inner: R,
+
+    /// The response of the whole container.
+    pub response: Response,
+
+    /// The step that will be shown next frame.
+    pub current: usize,
+
+    /// Did the user navigate to another step this frame?
+    pub step_changed: bool,
+
+    /// Did the user click "Finish" on the last step (with that step being valid)?
+    pub finished: bool,
+}
+
+/// A container for multi-step flows, such as setup wizards.
+///
+/// Shows a step indicator at the top, the contents of the current step,
+/// and "Back"/"Next" buttons at the bottom.
+/// The current step is persisted in [`crate::Memory`].
+///
+/// The contents closure acts as the validation gate for each step:
+/// return `Ok(value)` to allow moving on, or `Err(reason)` to disable the "Next" button.
+/// The reason is shown when hovering the disabled button.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut name = String::new();
+/// let response = egui::Steps::new("signup", ["Name", "Confirm"]).show(ui, |ui, step| {
+///     match step {
+///         0 => {
+///             ui.text_edit_singleline(&mut name);
+///             if name.is_empty() {
+///                 return Err("Please enter a name".to_owned());
+///             }
+///         }
+///         _ => {
+///             ui.label(format!("Hello {name}!"));
+///         }
+///     }
+///     Ok(())
+/// });
+/// if response.finished {
+///     // …
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Steps {
+    id_salt: Id,
+    titles: Vec<WidgetText>,
+    show_indicator: bool,
+    animated: bool,
+    back_text: WidgetText,
+    next_text: WidgetText,
+    finish_text: WidgetText,
+}
+
+impl Steps {
+    /// The `id_salt` must be unique among sibling widgets.
+    pub fn new<T: Into<WidgetText>>(
+        id_salt: impl std::hash::Hash,
+        titles: impl IntoIterator<Item = T>,
+    ) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            titles: titles.into_iter().map(Into::into).collect(),
+            show_indicator: true,
+            animated: true,
+            back_text: "Back".into(),
+            next_text: "Next".into(),
+            finish_text: "Finish".into(),
+        }
+    }
+
+    /// Show the step indicator header?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn show_indicator(mut self, show_indicator: bool) -> Self {
+        self.show_indicator = show_indicator;
+        self
+    }
+
+    /// Slide and fade between steps?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+
+    /// Set the texts of the "Back", "Next" and "Finish" buttons.
+    #[inline]
+    pub fn button_texts(
+        mut self,
+        back: impl Into<WidgetText>,
+        next: impl Into<WidgetText>,
+        finish: impl Into<WidgetText>,
+    ) -> Self {
+        self.back_text = back.into();
+        self.next_text = next.into();
+        self.finish_text = finish.into();
+        self
+    }
+
+    /// Show the current step.
+    ///
+    /// The closure is given the index of the step to show.
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui, usize) -> Result<R, String>,
+    ) -> StepsResponse<Result<R, String>> {
+        let Self {
+            id_salt,
+            titles,
+            show_indicator,
+            animated,
+            back_text,
+            next_text,
+            finish_text,
+        } = self;
+
+        let id = ui.make_persistent_id(id_salt);
+        let num_steps = titles.len().max(1);
+        let mut state = StepsState::load(ui.ctx(), id).unwrap_or_default();
+        state.current = state.current.min(num_steps - 1);
+        state.reached = state.reached.clamp(state.current, num_steps - 1);
+        let shown_step = state.current;
+
+        let inner_response = ui.vertical(|ui| {
+            if show_indicator {
+                if let Some(step) = step_indicator(ui, &titles, &state) {
+                    state.current = step;
+                }
+                ui.separator();
+            }
+
+            let inner = step_contents(ui, id, shown_step, animated, add_contents);
+
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(shown_step > 0, Button::new(back_text))
+                    .clicked()
+                {
+                    state.current = shown_step - 1;
+                }
+
+                let is_last = shown_step + 1 == num_steps;
+                let forward_text = if is_last { finish_text } else { next_text };
+                let mut forward = ui.add_enabled(inner.is_ok(), Button::new(forward_text));
+                if let Err(reason) = &inner {
+                    forward = forward.on_disabled_hover_text(reason.as_str());
+                }
+
+                let finished = forward.clicked() && is_last;
+                if forward.clicked() && !is_last {
+                    state.set_current(shown_step + 1);
+                }
+                (inner, finished)
+            })
+            .inner
+        });
+
+        let (inner, finished) = inner_response.inner;
+        let step_changed = state.current != shown_step;
+        if step_changed {
+            ui.ctx().request_repaint();
+        }
+        state.store(ui.ctx(), id);
+
+        StepsResponse {
+            inner,
+            response: inner_response.response,
+            current: state.current,
+            step_changed,
+            finished,
+        }
+    }
+}
+
+/// Paint the step indicator.
+///
+/// Returns the index of a clicked step, if any.
+fn step_indicator(ui: &mut Ui, titles: &[WidgetText], state: &StepsState) -> Option<usize> {
+    let mut clicked = None;
+
+    ui.horizontal(|ui| {
+        let diameter = ui.spacing().interact_size.y;
+
+        for (i, title) in titles.iter().enumerate() {
+            if i > 0 {
+                let (rect, _) = ui.allocate_exact_size(vec2(diameter, diameter), Sense::hover());
+                let stroke = if i <= state.reached {
+                    ui.visuals().selection.stroke
+                } else {
+                    ui.visuals().widgets.noninteractive.bg_stroke
+                };
+                ui.painter()
+                    .hline(rect.x_range(), rect.center().y, Stroke::new(1.0, stroke.color));
+            }
+
+            let reachable = i <= state.reached && i != state.current;
+            let sense = if reachable {
+                Sense::click()
+            } else {
+                Sense::hover()
+            };
+
+            let galley = title.clone().into_galley(
+                ui,
+                Some(crate::TextWrapMode::Extend),
+                f32::INFINITY,
+                TextStyle::Button,
+            );
+            let size = vec2(
+                diameter + ui.spacing().item_spacing.x + galley.size().x,
+                diameter,
+            );
+            let (rect, response) = ui.allocate_exact_size(size, sense);
+            response.widget_info(|| {
+                WidgetInfo::selected(
+                    WidgetType::Button,
+                    reachable,
+                    i == state.current,
+                    galley.text(),
+                )
+            });
+
+            if response.clicked() {
+                clicked = Some(i);
+            }
+
+            if ui.is_rect_visible(rect) {
+                let visuals = ui.style().interact_selectable(&response, i == state.current);
+                let circle_rect = Rect::from_min_size(rect.min, Vec2::splat(diameter));
+                let (fill, text_color) = if i <= state.current {
+                    (
+                        ui.visuals().selection.bg_fill,
+                        ui.visuals().selection.stroke.color,
+                    )
+                } else {
+                    (ui.visuals().faint_bg_color, visuals.text_color())
+                };
+
+                let painter = ui.painter();
+                painter.circle(
+                    circle_rect.center(),
+                    0.5 * diameter - 1.0,
+                    fill,
+                    visuals.fg_stroke,
+                );
+                let label = if i < state.current {
+                    "✔".to_owned()
+                } else {
+                    (i + 1).to_string()
+                };
+                painter.text(
+                    circle_rect.center(),
+                    Align2::CENTER_CENTER,
+                    label,
+                    TextStyle::Button.resolve(ui.style()),
+                    text_color,
+                );
+
+                let text_pos = Align2::LEFT_CENTER
+                    .align_size_within_rect(
+                        galley.size(),
+                        rect.with_min_x(circle_rect.max.x + ui.spacing().item_spacing.x),
+                    )
+                    .min;
+                painter.galley(text_pos, galley, visuals.text_color());
+
+                if response.has_focus() {
+                    painter.rect_stroke(
+                        rect.expand(2.0),
+                        visuals.corner_radius,
+                        ui.visuals().selection.stroke,
+                        StrokeKind::Outside,
+                    );
+                }
+            }
+        }
+    });
+
+    clicked
+}
+
+/// Show the contents of the given step, sliding it in from the side when the step changes.
+fn step_contents<R>(
+    ui: &mut Ui,
+    id: Id,
+    step: usize,
+    animated: bool,
+    add_contents: impl FnOnce(&mut Ui, usize) -> R,
+) -> R {
+    let anim_time = if animated {
+        ui.style().animation_time
+    } else {
+        0.0
+    };
+    let position = ui
+        .ctx()
+        .animate_value_with_time(id.with("transition"), step as f32, anim_time);
+
+    // How far we still have to go, in the range [-1, 1]:
+    let offset = (position - step as f32).clamp(-1.0, 1.0);
+
+    if offset == 0.0 {
+        add_contents(ui, step)
+    } else {
+        // Moving forward, the new step comes in from the right:
+        let slide = -offset * 0.25 * ui.available_width();
+        let available_rect = ui.available_rect_before_wrap();
+        let max_rect = available_rect.translate(vec2(slide, 0.0));
+        ui.scope_builder(UiBuilder::new().max_rect(max_rect), |ui| {
+            ui.multiply_opacity(1.0 - offset.abs());
+            ui.set_clip_rect(ui.clip_rect().intersect(available_rect));
+            add_contents(ui, step)
+        })
+        .inner
+    }
+}
@@ -0,0 +1,297 @@
+use emath::{Align2, NumExt as _, Rect, vec2};
+use epaint::Shape;
+
+use crate::{Color32, HapticKind, Id, Response, Sense, TextStyle, Ui, UiBuilder, WidgetText};
+
+/// How fast the row springs back after being let go (per second).
+const SPRING_BACK_SPEED: f32 = 15.0;
+
+/// Which way the user swiped a [`SwipeRow`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SwipeDirection {
+    /// The row moved left, revealing the [`SwipeRow::swipe_left`] action on the right.
+    Left,
+
+    /// The row moved right, revealing the [`SwipeRow::swipe_right`] action on the left.
+    Right,
+}
+
+/// An action revealed behind a [`SwipeRow`], e.g. "Delete" or "Archive".
+pub struct SwipeAction {
+    text: WidgetText,
+    fill: Option<Color32>,
+}
+
+impl SwipeAction {
+    pub fn new(text: impl Into<WidgetText>) -> Self {
+        Self {
+            text: text.into(),
+            fill: None,
+        }
+    }
+
+    /// The background color behind the action.
+    ///
+    /// Default: [`crate::style::Selection::bg_fill`].
+    /// Use [`crate::Visuals::error_fg_color`] for destructive actions.
+    #[inline]
+    pub fn fill(mut self, fill: Color32) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct State {
+    /// How far the contents have been moved to the right.
+    offset: f32,
+}
+
+/// What happened in a [`SwipeRow`] this frame.
+pub struct SwipeRowResponse<R> {
+    /// What the contents returned.
+    pub inner: R,
+
+    /// The response of the whole row.
+    pub response: Response,
+
+    /// Is the user currently dragging the row far enough to trigger an action?
+    pub armed: Option<SwipeDirection>,
+
+    /// Did the user let go past the threshold this frame?
+    ///
+    /// If so, perform the action, e.g. remove the row.
+    pub triggered: Option<SwipeDirection>,
+}
+
+/// A list row that the user can swipe left or right to reveal and trigger actions,
+/// as is common on touch screens.
+///
+/// Swiping works with touch and with dragging the mouse.
+/// When let go past the [`Self::threshold`], the action is reported in
+/// [`SwipeRowResponse::triggered`]. Either way, the row springs back into place.
+///
+/// If [`crate::style::Interaction::haptic_feedback`] is enabled, there is a haptic pulse
+/// when the user drags past the threshold. Use [`Self::on_threshold`] for other feedback.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut messages = vec!["Hello".to_owned()];
+/// let mut remove = None;
+/// for (i, message) in messages.iter().enumerate() {
+///     let row = egui::SwipeRow::new(i)
+///         .swipe_left(egui::SwipeAction::new("🗑 Delete").fill(ui.visuals().error_fg_color))
+///         .swipe_right(egui::SwipeAction::new("🗄 Archive"))
+///         .show(ui, |ui| {
+///             ui.label(message);
+///         });
+///     if row.triggered.is_some() {
+///         remove = Some(i);
+///     }
+/// }
+/// if let Some(i) = remove {
+///     messages.remove(i);
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct SwipeRow<'a> {
+    id_salt: Id,
+    swipe_left: Option<SwipeAction>,
+    swipe_right: Option<SwipeAction>,
+    threshold: f32,
+    on_threshold: Option<Box<dyn FnMut(SwipeDirection, bool) + 'a>>,
+}
+
+impl<'a> SwipeRow<'a> {
+    /// The `id_salt` must be unique among sibling widgets.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            swipe_left: None,
+            swipe_right: None,
+            threshold: 96.0,
+            on_threshold: None,
+        }
+    }
+
+    /// The action revealed when swiping left.
+    ///
+    /// Without one, the row can't be swiped left.
+    #[inline]
+    pub fn swipe_left(mut self, action: SwipeAction) -> Self {
+        self.swipe_left = Some(action);
+        self
+    }
+
+    /// The action revealed when swiping right.
+    ///
+    /// Without one, the row can't be swiped right.
+    #[inline]
+    pub fn swipe_right(mut self, action: SwipeAction) -> Self {
+        self.swipe_right = Some(action);
+        self
+    }
+
+    /// How far (in points) the user has to swipe to trigger an action.
+    ///
+    /// Default: `96.0`.
+    #[inline]
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Called with `true` when the user drags past the threshold,
+    /// and with `false` when they drag back again.
+    ///
+    /// This is a good place to play a sound.
+    #[inline]
+    pub fn on_threshold(mut self, on_threshold: impl FnMut(SwipeDirection, bool) + 'a) -> Self {
+        self.on_threshold = Some(Box::new(on_threshold));
+        self
+    }
+
+    /// Show the contents of the row.
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> SwipeRowResponse<R> {
+        let Self {
+            id_salt,
+            swipe_left,
+            swipe_right,
+            threshold,
+            mut on_threshold,
+        } = self;
+
+        let id = ui.make_persistent_id(id_salt);
+        let mut state: State = ui.data(|d| d.get_temp(id)).unwrap_or_default();
+        let threshold = threshold.at_least(1.0);
+
+        let armed_at = |offset: f32| {
+            if threshold <= offset && swipe_right.is_some() {
+                Some(SwipeDirection::Right)
+            } else if offset <= -threshold && swipe_left.is_some() {
+                Some(SwipeDirection::Left)
+            } else {
+                None
+            }
+        };
+
+        // The background goes behind the contents:
+        let background = ui.painter().add(Shape::Noop);
+
+        let max_rect = ui.available_rect_before_wrap();
+        let mut clip_rect = ui.clip_rect();
+        clip_rect.min.x = clip_rect.min.x.at_least(max_rect.min.x);
+        clip_rect.max.x = clip_rect.max.x.at_most(max_rect.max.x);
+
+        let mut content_ui = ui.new_child(
+            UiBuilder::new()
+                .id_salt(id.with("contents"))
+                .max_rect(max_rect.translate(vec2(state.offset, 0.0))),
+        );
+        content_ui.set_clip_rect(clip_rect);
+        let inner = add_contents(&mut content_ui);
+
+        let row_rect = Rect::from_min_size(
+            max_rect.min,
+            vec2(max_rect.width(), content_ui.min_rect().height()),
+        );
+        let response = ui.interact(row_rect, id, Sense::drag());
+        ui.advance_cursor_after_rect(row_rect);
+
+        let armed_before = armed_at(state.offset);
+        let mut triggered = None;
+        if response.dragged() {
+            let min_offset = if swipe_left.is_some() { -row_rect.width() } else { 0.0 };
+            let max_offset = if swipe_right.is_some() { row_rect.width() } else { 0.0 };
+            state.offset = (state.offset + response.drag_delta().x).clamp(min_offset, max_offset);
+        } else {
+            if response.drag_stopped() {
+                triggered = armed_before;
+            }
+
+            let dt = ui.input(|i| i.stable_dt).at_most(0.1);
+            state.offset *= (-SPRING_BACK_SPEED * dt).exp();
+            if state.offset.abs() < 0.5 {
+                state.offset = 0.0;
+            } else {
+                ui.ctx().request_repaint();
+            }
+        }
+
+        let armed = armed_at(state.offset);
+        if response.dragged() && armed != armed_before {
+            if armed.is_some() && ui.style().interaction.haptic_feedback {
+                ui.ctx().haptic_feedback(HapticKind::Light);
+            }
+            if let Some(on_threshold) = &mut on_threshold {
+                if let Some(direction) = armed {
+                    on_threshold(direction, true);
+                } else if let Some(direction) = armed_before {
+                    on_threshold(direction, false);
+                }
+            }
+        }
+
+        // Paint the revealed action, and a background under the moved contents:
+        if state.offset != 0.0 {
+            let visuals = ui.visuals();
+            let (action, revealed_rect, align) = if 0.0 < state.offset {
+                let mut rect = row_rect;
+                rect.max.x = rect.min.x + state.offset;
+                (&swipe_right, rect, Align2::LEFT_CENTER)
+            } else {
+                let mut rect = row_rect;
+                rect.min.x = rect.max.x + state.offset;
+                (&swipe_left, rect, Align2::RIGHT_CENTER)
+            };
+
+            if let Some(action) = action {
+                let fill = action.fill.unwrap_or(visuals.selection.bg_fill);
+                let fill = if armed.is_some() {
+                    fill
+                } else {
+                    fill.gamma_multiply(0.6)
+                };
+                let content_rect = row_rect.translate(vec2(state.offset, 0.0));
+                ui.painter().set(
+                    background,
+                    Shape::Vec(vec![
+                        Shape::rect_filled(revealed_rect, 0.0, fill),
+                        Shape::rect_filled(content_rect, 0.0, visuals.panel_fill),
+                    ]),
+                );
+
+                let margin = ui.spacing().item_spacing.x;
+                let galley = action.text.clone().into_galley(
+                    ui,
+                    Some(crate::TextWrapMode::Extend),
+                    f32::INFINITY,
+                    TextStyle::Button,
+                );
+                let text_rect = align.align_size_within_rect(
+                    galley.size(),
+                    revealed_rect.shrink2(vec2(margin, 0.0)),
+                );
+                ui.painter().with_clip_rect(revealed_rect).galley(
+                    text_rect.min,
+                    galley,
+                    visuals.selection.stroke.color,
+                );
+            }
+        }
+
+        ui.data_mut(|d| d.insert_temp(id, state));
+
+        SwipeRowResponse {
+            inner,
+            response,
+            armed,
+            triggered,
+        }
+    }
+}
@@ -1,7 +1,12 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
 use crate::pass_state::PerWidgetTooltipState;
 use crate::{
     AreaState, Context, Id, InnerResponse, LayerId, Layout, Order, Popup, PopupAnchor, PopupKind,
-    Response, Sense,
+    Response, Sense, mutex::Mutex,
 };
 use emath::Vec2;
 
@@ -397,3 +402,72 @@ impl Tooltip<'_> {
         })
     }
 }
+
+// ----------------------------------------------------------------------------
+
+/// The state of a [`Response::on_hover_preview`], cached in [`crate::Memory`].
+pub(crate) struct HoverPreview<T> {
+    value: Arc<Mutex<Option<Arc<T>>>>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl<T> Clone for HoverPreview<T> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            cancelled: self.cancelled.clone(),
+        }
+    }
+}
+
+impl<T: 'static + Send + Sync> HoverPreview<T> {
+    pub(crate) fn new(ctx: &Context) -> (Self, HoverPreviewSender<T>) {
+        let preview = Self {
+            value: Default::default(),
+            cancelled: Default::default(),
+        };
+        let sender = HoverPreviewSender {
+            value: preview.value.clone(),
+            cancelled: preview.cancelled.clone(),
+            ctx: ctx.clone(),
+        };
+        (preview, sender)
+    }
+
+    /// The loaded value, if it has arrived.
+    pub(crate) fn ready(&self) -> Option<Arc<T>> {
+        self.value.lock().clone()
+    }
+
+    pub(crate) fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Used to deliver the content of a [`Response::on_hover_preview`] once it has loaded.
+///
+/// This can be sent to another thread or async task.
+pub struct HoverPreviewSender<T> {
+    value: Arc<Mutex<Option<Arc<T>>>>,
+    cancelled: Arc<AtomicBool>,
+    ctx: Context,
+}
+
+impl<T> HoverPreviewSender<T> {
+    /// Deliver the loaded value and repaint, so that it is shown.
+    ///
+    /// Does nothing if the preview was cancelled.
+    pub fn send(self, value: T) {
+        if !self.is_cancelled() {
+            *self.value.lock() = Some(Arc::new(value));
+            self.ctx.request_repaint();
+        }
+    }
+
+    /// Has the user stopped hovering the widget before the value was delivered?
+    ///
+    /// Long-running loaders can check this to stop early.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
@@ -30,6 +30,11 @@ use super::{Area, Frame, Resize, ScrollArea, area, resize};
 ///
 /// The previous rectangle used by this window can be obtained through [`crate::Memory::area_rect()`].
 ///
+/// Windows can also be moved and resized with the keyboard:
+/// give the title bar keyboard focus (e.g. with tab), then use the arrow keys to move the window,
+/// and shift + arrow keys to resize it.
+/// Press enter to confirm, or escape to put the window back where it was.
+///
 /// Note that this is NOT a native OS window.
 /// To create a new native OS window, use [`crate::Context::show_viewport_deferred`].
 #[must_use = "You should call .show()"]
@@ -113,6 +118,27 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Snap to a grid, and to the edges of other windows and the screen, while being dragged.
+    ///
+    /// Default: no snapping.
+    #[inline]
+    pub fn snapping(mut self, snapping: Snapping) -> Self {
+        self.area = self.area.snapping(snapping);
+        self
+    }
+
+    /// Let the user select the window together with others, with the [`crate::AreaSelection`],
+    /// to move, align and distribute them as a group.
+    ///
+    /// Command-click the title bar to select or unselect the window.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.area = self.area.selectable(selectable);
+        self
+    }
+
     /// `order(Order::Foreground)` for a Window that should always be on top
     #[inline]
     pub fn order(mut self, order: Order) -> Self {
@@ -463,6 +489,8 @@ impl Window<'_> {
 
         let is_collapsed = with_title_bar && !collapsing.is_open();
         let possible = PossibleInteractions::new(&area, &resize, is_collapsed);
+        let movable = area.is_enabled() && area.is_movable();
+        let selectable = area.is_selectable();
 
         let resize = resize.resizable(false); // We resize it manually
         let mut resize = resize.id(resize_id);
@@ -527,6 +555,27 @@ impl Window<'_> {
                 &mut area,
                 resize_id,
             );
+
+            if with_title_bar && !resize_interaction.any_dragged() {
+                if let Some((new_rect, resized)) = keyboard_move_and_resize(
+                    ctx,
+                    title_bar_id(area_id),
+                    possible,
+                    movable,
+                    last_frame_outer_rect,
+                    window_frame,
+                ) {
+                    set_window_rect(
+                        ctx,
+                        new_rect,
+                        resized,
+                        margins,
+                        area_layer_id,
+                        &mut area,
+                        resize_id,
+                    );
+                }
+            }
         }
 
         let mut area_content_ui = area.content_ui(ctx);
@@ -632,6 +681,7 @@ impl Window<'_> {
                         open.as_deref_mut(),
                         &mut collapsing,
                         collapsible,
+                        selectable,
                     );
                 }
 
@@ -828,10 +878,32 @@ fn resize_response(
     area: &mut area::Prepared,
     resize_id: Id,
 ) {
-    let Some(mut new_rect) = move_and_resize_window(ctx, &resize_interaction) else {
+    let Some(new_rect) = move_and_resize_window(ctx, &resize_interaction) else {
         return;
     };
 
+    set_window_rect(
+        ctx,
+        new_rect,
+        resize_interaction.any_dragged(),
+        margins,
+        area_layer_id,
+        area,
+        resize_id,
+    );
+}
+
+/// Move the window to `new_rect` (in the center of the stroke),
+/// and also resize it if `resized` is set.
+fn set_window_rect(
+    ctx: &Context,
+    mut new_rect: Rect,
+    resized: bool,
+    margins: Vec2,
+    area_layer_id: LayerId,
+    area: &mut area::Prepared,
+    resize_id: Id,
+) {
     if area.constrain() {
         new_rect = Context::constrain_window_rect_to_area(new_rect, area.constrain_rect());
     }
@@ -839,7 +911,7 @@ fn resize_response(
     // TODO(emilk): add this to a Window state instead as a command "move here next frame"
     area.state_mut().set_left_top_pos(new_rect.left_top());
 
-    if resize_interaction.any_dragged() {
+    if resized {
         if let Some(mut state) = resize::State::load(ctx, resize_id) {
             state.requested_size = Some(new_rect.size() - margins);
             state.store(ctx, resize_id);
@@ -849,6 +921,92 @@ fn resize_response(
     ctx.memory_mut(|mem| mem.areas_mut().move_to_top(area_layer_id));
 }
 
+/// How many points the arrow keys move or resize a window with a focused title bar.
+const KEYBOARD_STEP: f32 = 8.0;
+
+/// Keyboard window management, for accessibility.
+///
+/// While the title bar has keyboard focus, the arrow keys move the window,
+/// and shift + arrow keys resize it.
+/// Enter confirms the new placement, and escape puts the window back where it was
+/// when the title bar got focus.
+///
+/// Acts on outer rect (outside the stroke).
+/// Returns the new rect (in the center of the stroke), and whether or not it was resized.
+fn keyboard_move_and_resize(
+    ctx: &Context,
+    title_bar_id: Id,
+    possible: PossibleInteractions,
+    movable: bool,
+    outer_rect: Rect,
+    window_frame: Frame,
+) -> Option<(Rect, bool)> {
+    let original_rect_id = title_bar_id.with("keyboard_original_rect");
+
+    if !ctx.memory(|mem| mem.has_focus(title_bar_id)) {
+        ctx.data_mut(|data| data.remove::<Rect>(original_rect_id));
+        return None;
+    }
+
+    // We want the arrow keys and escape for ourselves:
+    ctx.memory_mut(|mem| {
+        mem.set_focus_lock_filter(
+            title_bar_id,
+            EventFilter {
+                tab: false,
+                horizontal_arrows: true,
+                vertical_arrows: true,
+                escape: true,
+            },
+        );
+    });
+
+    // Put the rect in the center of the stroke, just like when dragging:
+    let rect = outer_rect.shrink(window_frame.stroke.width / 2.0);
+    let original_rect = ctx.data_mut(|data| *data.get_temp_mut_or(original_rect_id, rect));
+
+    let (delta, resize, confirm, cancel) = ctx.input(|i| {
+        let mut delta = Vec2::ZERO;
+        for (key, dir) in [
+            (Key::ArrowLeft, -Vec2::X),
+            (Key::ArrowRight, Vec2::X),
+            (Key::ArrowUp, -Vec2::Y),
+            (Key::ArrowDown, Vec2::Y),
+        ] {
+            delta += i.num_presses(key) as f32 * dir;
+        }
+        (
+            KEYBOARD_STEP * delta,
+            i.modifiers.shift,
+            i.key_pressed(Key::Enter),
+            i.key_pressed(Key::Escape),
+        )
+    });
+
+    if confirm || cancel {
+        ctx.memory_mut(|mem| mem.surrender_focus(title_bar_id));
+        ctx.data_mut(|data| data.remove::<Rect>(original_rect_id));
+        return cancel.then(|| (original_rect, original_rect.size() != rect.size()));
+    }
+
+    if delta == Vec2::ZERO {
+        return None;
+    }
+
+    if resize {
+        let mut new_rect = rect;
+        if possible.resize_left || possible.resize_right {
+            new_rect.max.x += delta.x;
+        }
+        if possible.resize_top || possible.resize_bottom {
+            new_rect.max.y += delta.y;
+        }
+        (new_rect != rect).then_some((new_rect, true))
+    } else {
+        movable.then(|| (rect.translate(delta), false))
+    }
+}
+
 /// Acts on outer rect (outside the stroke)
 fn move_and_resize_window(ctx: &Context, interaction: &ResizeInteraction) -> Option<Rect> {
     if !interaction.any_dragged() {
@@ -1108,6 +1266,11 @@ fn paint_frame_interaction(ui: &Ui, rect: Rect, interaction: ResizeInteraction)
 
 // ----------------------------------------------------------------------------
 
+/// The title bar is focusable, and used for moving and resizing the window with the keyboard.
+fn title_bar_id(area_id: Id) -> Id {
+    area_id.with("__window_title_bar")
+}
+
 struct TitleBar {
     window_frame: Frame,
 
@@ -1193,6 +1356,7 @@ impl TitleBar {
         open: Option<&mut bool>,
         collapsing: &mut CollapsingState,
         collapsible: bool,
+        selectable: bool,
     ) {
         let window_frame = self.window_frame;
         let title_inner_rect = self.inner_rect;
@@ -1260,15 +1424,25 @@ impl TitleBar {
             );
         }
 
-        let id = ui.unique_id().with("__window_title_bar");
+        let response = ui.interact(double_click_rect, title_bar_id(ui.unique_id()), Sense::click());
 
-        if ui
-            .interact(double_click_rect, id, Sense::click())
-            .double_clicked()
-            && collapsible
-        {
+        if response.double_clicked() && collapsible {
             collapsing.toggle(ui);
         }
+
+        if selectable {
+            AreaSelection::toggle_on_click(ui.ctx(), ui.layer_id().id, &response);
+        }
+
+        if response.has_focus() {
+            // Show that the arrow keys now move (and resize) the window:
+            ui.painter().rect_stroke(
+                title_inner_rect,
+                0.0,
+                ui.visuals().selection.stroke,
+                StrokeKind::Inside,
+            );
+        }
     }
 
     /// Paints the "Close" button at the right side of the title bar
@@ -16,18 +16,18 @@ use epaint::{
 
 use crate::{
     Align2, CursorIcon, DeferredViewportUiCallback, FontDefinitions, Grid, Id, ImmediateViewport,
-    ImmediateViewportRendererCallback, Key, KeyboardShortcut, Label, LayerId, Memory,
-    ModifierNames, Modifiers, NumExt as _, Order, Painter, RawInput, Response, RichText,
-    ScrollArea, Sense, Style, TextStyle, TextureHandle, TextureOptions, Ui, ViewportBuilder,
-    ViewportCommand, ViewportId, ViewportIdMap, ViewportIdPair, ViewportIdSet, ViewportOutput,
-    Widget as _, WidgetRect, WidgetText,
+    ImmediateViewportRendererCallback, Key, KeyboardShortcut, Label, LayerId, LayoutSnapshot,
+    Memory, ModifierNames, Modifiers, MouseHitTest, NumExt as _, Order, Painter, RawInput, Response,
+    RichText, ScrollArea, Sense, Style, TextStyle, TextureHandle, TextureOptions, Ui,
+    ViewportBuilder, ViewportCommand, ViewportId, ViewportIdMap, ViewportIdPair, ViewportIdSet,
+    ViewportOutput, Widget as _, WidgetRect, WidgetText,
     animation_manager::AnimationManager,
     containers::{self, area::AreaState},
     data::output::PlatformOutput,
     epaint, hit_test,
     input_state::{InputState, MultiTouchInfo, PointerEvent},
     interaction,
-    layers::GraphicLayers,
+    layers::{GraphicLayers, LayerTint},
     load::{self, Bytes, Loaders, SizedTexture},
     memory::{Options, Theme},
     os::OperatingSystem,
@@ -107,6 +107,9 @@ struct NamedContextCallback {
 struct Plugins {
     pub on_begin_pass: Vec<NamedContextCallback>,
     pub on_end_pass: Vec<NamedContextCallback>,
+    pub widget_decorators: Vec<NamedWidgetDecorator>,
+    pub on_interaction: Vec<NamedInteractionCallback>,
+    pub on_degradation_changed: Vec<NamedContextCallback>,
 }
 
 impl Plugins {
@@ -131,6 +134,45 @@ impl Plugins {
     }
 }
 
+/// Called for each [`crate::InteractionEvent`]. See [`Context::on_interaction`].
+pub type InteractionCallback = Arc<dyn Fn(&crate::InteractionEvent) + Send + Sync>;
+
+#[derive(Clone)]
+struct NamedInteractionCallback {
+    debug_name: &'static str,
+    callback: InteractionCallback,
+}
+
+/// Paints extra shapes for a widget, e.g. a focus ring or a debug badge.
+///
+/// Called with the [`Ui`] the widget was added to, its [`Response`] and its [`crate::WidgetInfo`].
+/// Return [`epaint::Shape::Noop`] to paint nothing.
+///
+/// See [`Context::add_widget_decorator`].
+pub type WidgetDecorator =
+    Arc<dyn Fn(&Ui, &Response, &crate::WidgetInfo) -> epaint::Shape + Send + Sync>;
+
+/// Where the shapes of a [`WidgetDecorator`] are painted, relative to the widget.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DecoratorPhase {
+    /// Paint below the widget, e.g. a custom hover background.
+    Pre,
+
+    /// Paint on top of the widget, e.g. a focus ring.
+    Post,
+}
+
+#[derive(Clone)]
+pub(crate) struct NamedWidgetDecorator {
+    debug_name: &'static str,
+    pub phase: DecoratorPhase,
+
+    /// `None` means all widgets.
+    widget_type: Option<crate::WidgetType>,
+
+    pub decorator: WidgetDecorator,
+}
+
 // ----------------------------------------------------------------------------
 
 /// Repaint-logic
@@ -281,6 +323,12 @@ pub struct ViewportState {
     pub output: PlatformOutput,
     pub commands: Vec<ViewportCommand>,
 
+    /// Set with [`Context::set_mouse_hit_test`].
+    pub mouse_hit_test: MouseHitTest,
+
+    /// The regions last sent in [`ViewportCommand::MouseHitTestRegions`].
+    mouse_hit_test_regions: Option<Vec<Rect>>,
+
     // ----------------------
     // Cross-frame statistics:
     pub num_multipass_in_row: usize,
@@ -404,6 +452,80 @@ impl ViewportRepaintInfo {
 
 // ----------------------------------------------------------------------------
 
+/// Tracks the frame times reported with [`Context::report_frame_time`]
+/// against [`Options::frame_time_budget`].
+#[derive(Clone, Copy, Debug, Default)]
+struct FrameBudget {
+    /// Exponential moving average of the reported frame times, in seconds.
+    smoothed_frame_time: Option<f32>,
+
+    is_degraded: bool,
+}
+
+impl FrameBudget {
+    /// Returns `true` if [`Self::is_degraded`] changed.
+    fn report(&mut self, frame_time: f32, budget: Option<f32>) -> bool {
+        let smoothed_frame_time = match self.smoothed_frame_time {
+            Some(smoothed) => emath::lerp(smoothed..=frame_time, 0.1),
+            None => frame_time,
+        };
+        self.smoothed_frame_time = Some(smoothed_frame_time);
+
+        let was_degraded = self.is_degraded;
+        self.is_degraded = match budget {
+            None => false,
+            // Some hysteresis, so we don't flip back and forth every frame:
+            Some(budget) if was_degraded => 0.8 * budget < smoothed_frame_time,
+            Some(budget) => budget < smoothed_frame_time,
+        };
+        was_degraded != self.is_degraded
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The [`crate::UserData`] of a screenshot taken with [`Context::screenshot_viewport`].
+struct ForwardedScreenshot {
+    /// The viewport that asked for the screenshot.
+    requester: ViewportId,
+
+    /// What the user passed to [`Context::screenshot_viewport`].
+    user_data: crate::UserData,
+}
+
+/// Remove all pointer, keyboard and touch input,
+/// because a modal viewport is shown on top of this one.
+///
+/// Returns `true` if the user tried clicking.
+fn block_input_for_modal(raw_input: &mut RawInput) -> bool {
+    use crate::Event;
+
+    let clicked = raw_input.events.iter().any(|event| {
+        matches!(
+            event,
+            Event::PointerButton { pressed: true, .. }
+                | Event::Touch {
+                    phase: crate::TouchPhase::Start,
+                    ..
+                }
+        )
+    });
+
+    raw_input.events.retain(|event| {
+        matches!(
+            event,
+            Event::WindowFocused(_) | Event::Screenshot { .. } | Event::MonitorsChanged(_)
+        )
+    });
+    raw_input.events.push(Event::PointerGone);
+    raw_input.hovered_files.clear();
+    raw_input.dropped_files.clear();
+
+    clicked
+}
+
+// ----------------------------------------------------------------------------
+
 #[derive(Default)]
 struct ContextImpl {
     /// Since we could have multiple viewports across multiple monitors with
@@ -419,6 +541,19 @@ struct ContextImpl {
 
     plugins: Plugins,
 
+    frame_budget: FrameBudget,
+
+    /// The emptied shape buffer from the last [`Context::tessellate`],
+    /// reused at the end of the next pass to avoid reallocating it.
+    shape_pool: Vec<ClippedShape>,
+
+    /// Allocation counts at the start of the current pass.
+    #[cfg(feature = "count_allocations")]
+    allocations_at_pass_start: epaint::util::AllocationStats,
+
+    #[cfg(feature = "count_allocations")]
+    allocations_last_pass: epaint::util::AllocationStats,
+
     /// All viewports share the same texture manager and texture namespace.
     ///
     /// In all viewports, [`TextureId::default`] is special, and points to the font atlas.
@@ -440,8 +575,19 @@ struct ContextImpl {
 
     paint_stats: PaintStats,
 
+    /// See [`crate::FrameStats::tessellation`].
+    last_tessellation: Option<crate::TessellationStats>,
+
     request_repaint_callback: Option<Box<dyn Fn(RequestRepaintInfo) + Send + Sync>>,
 
+    task_spawner: Option<Arc<crate::task::Spawner>>,
+
+    max_repaint_rates: ViewportIdMap<f32>,
+
+    /// Screenshots taken with [`Context::screenshot_viewport`],
+    /// waiting for the viewport that asked for them.
+    forwarded_screenshots: ViewportIdMap<Vec<crate::Event>>,
+
     viewport_parents: ViewportIdMap<ViewportId>,
     viewports: ViewportIdMap<ViewportState>,
 
@@ -454,6 +600,14 @@ struct ContextImpl {
 }
 
 impl ContextImpl {
+    /// See [`Context::is_degraded`].
+    fn is_degraded(&self) -> bool {
+        let options = &self.memory.options;
+        self.frame_budget.is_degraded
+            && options.frame_time_budget.is_some()
+            && !options.deterministic
+    }
+
     fn begin_pass(&mut self, mut new_raw_input: RawInput) {
         let viewport_id = new_raw_input.viewport_id;
         let parent_id = new_raw_input
@@ -466,6 +620,11 @@ impl ContextImpl {
         let is_outermost_viewport = self.viewport_stack.is_empty(); // not necessarily root, just outermost immediate viewport
         self.viewport_stack.push(ids);
 
+        #[cfg(feature = "count_allocations")]
+        if is_outermost_viewport {
+            self.allocations_at_pass_start = epaint::util::AllocationStats::now();
+        }
+
         self.begin_pass_repaint_logic(viewport_id);
 
         let viewport = self.viewports.entry(viewport_id).or_default();
@@ -491,6 +650,22 @@ impl ContextImpl {
             .unwrap_or(1.0);
         let pixels_per_point = self.memory.options.zoom_factor * native_pixels_per_point;
 
+        self.forward_screenshots(viewport_id, &mut new_raw_input);
+
+        if self.memory.options.deterministic {
+            // Advance time by the same step each pass, whatever the clock says:
+            new_raw_input.time = None;
+            new_raw_input.predicted_dt = Options::DETERMINISTIC_TIME_STEP;
+        }
+
+        if let Some(modal_id) = self.modal_viewport_of(viewport_id) {
+            let clicked = block_input_for_modal(&mut new_raw_input);
+            if clicked {
+                self.viewport_for(modal_id).commands.push(ViewportCommand::Focus);
+                self.request_repaint(modal_id, RepaintCause::new());
+            }
+        }
+
         let all_viewport_ids: ViewportIdSet = self.all_viewport_ids();
 
         let viewport = self.viewports.entry(self.viewport_id()).or_default();
@@ -504,6 +679,10 @@ impl ContextImpl {
             self.memory.options.input_options,
         );
         let repaint_after = viewport.input.wants_repaint_after();
+        let theme_transition_in_progress = self
+            .memory
+            .options
+            .update_theme_transition(viewport.input.time);
 
         let screen_rect = viewport.input.screen_rect;
 
@@ -544,6 +723,7 @@ impl ContextImpl {
                 pivot: Align2::LEFT_TOP,
                 size: Some(screen_rect.size()),
                 interactable: true,
+                selectable: false,
                 last_became_visible_at: None,
             },
         );
@@ -569,6 +749,9 @@ impl ContextImpl {
         if let Some(delay) = repaint_after {
             self.request_repaint_after(delay, viewport_id, RepaintCause::new());
         }
+        if theme_transition_in_progress {
+            self.request_repaint(viewport_id, RepaintCause::new());
+        }
     }
 
     /// Load fonts unless already loaded.
@@ -633,6 +816,7 @@ impl ContextImpl {
 
         {
             profiling::scope!("Fonts::begin_pass");
+            fonts.set_max_cached_galleys(self.memory.options.max_cached_galleys);
             fonts.begin_pass(pixels_per_point, max_texture_side, text_alpha_from_coverage);
         }
 
@@ -697,6 +881,60 @@ impl ContextImpl {
     fn viewport_for(&mut self, viewport_id: ViewportId) -> &mut ViewportState {
         self.viewports.entry(viewport_id).or_default()
     }
+
+    /// Move the screenshots taken with [`Context::screenshot_viewport`]
+    /// to the viewport that asked for them.
+    fn forward_screenshots(&mut self, viewport_id: ViewportId, raw_input: &mut RawInput) {
+        let mut forwarded = vec![];
+        raw_input.events.retain(|event| {
+            if let crate::Event::Screenshot {
+                viewport_id: captured_id,
+                user_data,
+                image,
+            } = event
+            {
+                let request = (user_data.data.as_ref())
+                    .and_then(|data| data.downcast_ref::<ForwardedScreenshot>());
+                if let Some(request) = request {
+                    let event = crate::Event::Screenshot {
+                        viewport_id: *captured_id,
+                        user_data: request.user_data.clone(),
+                        image: image.clone(),
+                    };
+                    forwarded.push((request.requester, event));
+                    return false;
+                }
+            }
+            true
+        });
+
+        for (requester, event) in forwarded {
+            self.forwarded_screenshots
+                .entry(requester)
+                .or_default()
+                .push(event);
+            if requester != viewport_id {
+                self.request_repaint(requester, RepaintCause::new());
+            }
+        }
+
+        if let Some(events) = self.forwarded_screenshots.remove(&viewport_id) {
+            raw_input.events.extend(events);
+        }
+    }
+
+    /// The modal viewport blocking the input to the given viewport, if any.
+    ///
+    /// See [`ViewportBuilder::with_modal_owner`].
+    fn modal_viewport_of(&self, owner_id: ViewportId) -> Option<ViewportId> {
+        // Viewports that weren't shown last pass have already been removed.
+        self.viewports
+            .iter()
+            .find(|(id, viewport)| {
+                **id != owner_id && viewport.builder.modal_owner == Some(owner_id)
+            })
+            .map(|(id, _)| *id)
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -778,6 +1016,7 @@ impl Default for Context {
         crate::debug_text::register(&ctx);
         crate::text_selection::LabelSelectionState::register(&ctx);
         crate::DragAndDrop::register(&ctx);
+        crate::VirtualKeyboard::register(&ctx);
 
         ctx
     }
@@ -1440,20 +1679,21 @@ impl Context {
 
     /// This is called by [`Response::widget_info`], but can also be called directly.
     ///
-    /// With some debug flags it will store the widget info in [`crate::WidgetRects`] for later display.
+    /// With some debug flags, or when there are widget decorators (see [`Self::add_widget_decorator`]),
+    /// it will store the widget info in [`crate::WidgetRects`] for later use.
     #[inline]
     pub fn register_widget_info(&self, id: Id, make_info: impl Fn() -> crate::WidgetInfo) {
-        #[cfg(debug_assertions)]
         self.write(|ctx| {
-            if ctx.memory.options.style().debug.show_interactive_widgets {
+            #[cfg(debug_assertions)]
+            let show_interactive_widgets =
+                ctx.memory.options.style().debug.show_interactive_widgets;
+            #[cfg(not(debug_assertions))]
+            let show_interactive_widgets = false;
+
+            if show_interactive_widgets || !ctx.plugins.widget_decorators.is_empty() {
                 ctx.viewport().this_pass.widgets.set_info(id, make_info());
             }
         });
-
-        #[cfg(not(debug_assertions))]
-        {
-            _ = (self, id, make_info);
-        }
     }
 
     /// Get a full-screen painter for a new or existing layer
@@ -1533,6 +1773,51 @@ impl Context {
         self.send_cmd(crate::OutputCommand::OpenUrl(open_url));
     }
 
+    /// Read the given text out loud, using the text-to-speech engine of the platform.
+    ///
+    /// Pass in a [`crate::Speech`] to control the rate and voice.
+    ///
+    /// This requires the `tts` feature of `eframe` on native.
+    /// For users with a screen reader, prefer describing your ui with [AccessKit](https://accesskit.dev/).
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// ctx.speak("Please insert your card");
+    /// ctx.speak(egui::Speech::new("Thank you").rate(0.8));
+    /// ```
+    pub fn speak(&self, speech: impl Into<crate::Speech>) {
+        self.send_cmd(crate::OutputCommand::Speak(speech.into()));
+    }
+
+    /// Give the user a short haptic pulse, e.g. a vibration on a phone.
+    ///
+    /// This is always sent, but the built-in widgets only call this if
+    /// [`crate::style::Interaction::haptic_feedback`] is enabled.
+    ///
+    /// Supported by `eframe` on web, on devices with a vibration motor.
+    /// Ignored on platforms without haptics.
+    pub fn haptic_feedback(&self, kind: crate::HapticKind) {
+        self.send_cmd(crate::OutputCommand::HapticFeedback(kind));
+    }
+
+    /// Play the system alert sound.
+    ///
+    /// On Windows this is the default system sound,
+    /// elsewhere `eframe` rings the terminal bell.
+    pub fn beep(&self) {
+        self.send_cmd(crate::OutputCommand::Beep);
+    }
+
+    /// Report a [`crate::SoundEvent`] to [`crate::style::Interaction::sound_hooks`] of the
+    /// global style, if any.
+    ///
+    /// Prefer [`crate::Ui::play_sound`] in widgets, which uses the style of the [`crate::Ui`].
+    pub fn play_sound(&self, event: crate::SoundEvent) {
+        if let Some(sound_hooks) = &self.style().interaction.sound_hooks {
+            sound_hooks.play(self, event);
+        }
+    }
+
     /// Copy the given text to the system clipboard.
     ///
     /// Note that in web applications, the clipboard is only accessible in secure contexts (e.g.,
@@ -1551,6 +1836,14 @@ impl Context {
         self.send_cmd(crate::OutputCommand::CopyImage(image));
     }
 
+    /// Copy the given rich text to the system clipboard, as HTML and as plain `text`.
+    ///
+    /// Integrations that can't put HTML on the clipboard copy the plain `text` instead.
+    /// See [`crate::OutputCommand::CopyHtml`].
+    pub fn copy_html(&self, html: String, text: String) {
+        self.send_cmd(crate::OutputCommand::CopyHtml { html, text });
+    }
+
     fn can_show_modifier_symbols(&self) -> bool {
         let ModifierNames {
             alt,
@@ -1599,6 +1892,67 @@ impl Context {
         }
     }
 
+    /// Register a keyboard shortcut that is active this pass,
+    /// so that it shows up in the shortcut cheatsheet (see [`crate::Options::shortcut_cheatsheet_key`]).
+    ///
+    /// Call this each pass the shortcut is active.
+    /// This does not consume the shortcut; for that, see [`Self::consume_registered_shortcut`].
+    ///
+    /// Registering the same shortcut for different actions is shown as a conflict in the cheatsheet.
+    pub fn register_shortcut(
+        &self,
+        category: impl Into<String>,
+        description: impl Into<String>,
+        shortcut: KeyboardShortcut,
+    ) {
+        let registered = crate::shortcuts::RegisteredShortcut {
+            shortcut,
+            category: category.into(),
+            description: description.into(),
+        };
+        self.write(|ctx| {
+            let shortcuts = &mut ctx.viewport().this_pass.shortcuts;
+            if !shortcuts.contains(&registered) {
+                shortcuts.push(registered);
+            }
+        });
+    }
+
+    /// [`Self::register_shortcut`] and [`InputState::consume_shortcut`] in one.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// let save = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::S);
+    /// if ctx.consume_registered_shortcut("File", "Save", &save) {
+    ///     // …
+    /// }
+    /// ```
+    pub fn consume_registered_shortcut(
+        &self,
+        category: impl Into<String>,
+        description: impl Into<String>,
+        shortcut: &KeyboardShortcut,
+    ) -> bool {
+        self.register_shortcut(category, description, *shortcut);
+        self.input_mut(|i| i.consume_shortcut(shortcut))
+    }
+
+    /// All keyboard shortcuts registered so far this pass, in registration order.
+    pub fn registered_shortcuts(&self) -> Vec<crate::shortcuts::RegisteredShortcut> {
+        self.write(|ctx| ctx.viewport().this_pass.shortcuts.clone())
+    }
+
+    /// Is the shortcut cheatsheet shown even without holding
+    /// [`crate::Options::shortcut_cheatsheet_key`]?
+    pub fn shortcut_cheatsheet_open(&self) -> bool {
+        self.data(|d| d.get_temp(Id::new("shortcut_cheatsheet"))).unwrap_or(false)
+    }
+
+    /// Show or hide the shortcut cheatsheet, e.g. from a "Help" menu.
+    pub fn set_shortcut_cheatsheet_open(&self, open: bool) {
+        self.data_mut(|d| d.insert_temp(Id::new("shortcut_cheatsheet"), open));
+    }
+
     /// The total number of completed frames.
     ///
     /// Starts at zero, and is incremented once at the end of each call to [`Self::run`].
@@ -1790,6 +2144,36 @@ impl Context {
         self.read(|ctx| ctx.has_requested_repaint(viewport_id))
     }
 
+    /// Limit how many times per second the given viewport is repainted.
+    ///
+    /// This is useful for e.g. a status viewport that only needs a couple of updates
+    /// per second, even while other viewports are animating.
+    /// Repaints, including those caused by input, are delayed until enough time has passed.
+    ///
+    /// Use [`f32::INFINITY`] to remove the limit, which is the default.
+    ///
+    /// This is up to the integration to implement. `eframe` does so on native.
+    /// Immediate viewports are painted together with their parent,
+    /// so this is mostly useful for deferred viewports.
+    pub fn set_max_repaint_rate_of(&self, viewport_id: ViewportId, hz: f32) {
+        self.write(|ctx| {
+            if hz.is_finite() {
+                ctx.max_repaint_rates.insert(viewport_id, hz);
+            } else {
+                ctx.max_repaint_rates.remove(&viewport_id);
+            }
+        });
+    }
+
+    /// How many times per second the given viewport may be repainted,
+    /// as set by [`Self::set_max_repaint_rate_of`].
+    ///
+    /// [`f32::INFINITY`] means there is no limit.
+    pub fn max_repaint_rate_of(&self, viewport_id: ViewportId) -> f32 {
+        self.read(|ctx| ctx.max_repaint_rates.get(&viewport_id).copied())
+            .unwrap_or(f32::INFINITY)
+    }
+
     /// Why are we repainting?
     ///
     /// This can be helpful in debugging why egui is constantly repainting.
@@ -1815,6 +2199,71 @@ impl Context {
         self.write(|ctx| ctx.request_repaint_callback = Some(callback));
     }
 
+    /// For integrations: set what runs the futures of [`Self::spawn_async`].
+    ///
+    /// By default each future is run on its own thread on native,
+    /// while on the web there is no default, so this must be set.
+    /// `eframe` uses `wasm_bindgen_futures::spawn_local` on the web.
+    ///
+    /// You can also use this to run the futures on e.g. a `tokio` runtime,
+    /// if they need one.
+    pub fn set_task_spawner(
+        &self,
+        spawner: impl Fn(crate::task::BoxFuture) + Send + Sync + 'static,
+    ) {
+        let spawner: Arc<crate::task::Spawner> = Arc::new(spawner);
+        self.write(|ctx| ctx.task_spawner = Some(spawner));
+    }
+
+    /// Run a future in the background, and get a [`crate::Task`] with its result.
+    ///
+    /// A repaint is requested when the future is done, so you can just poll the
+    /// [`crate::Task`] each frame, e.g. with [`crate::Ui::suspense`].
+    ///
+    /// See [`Self::set_task_spawner`] for where the future is run.
+    /// If the future panics, the task is [abandoned](crate::Task::is_abandoned).
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut task = None;
+    /// let task = task.get_or_insert_with(|| {
+    ///     ui.ctx().spawn_async(async { "Hello from the future!".to_owned() })
+    /// });
+    /// ui.suspense(task, |ui, text| ui.label(text.as_str()), |ui| ui.spinner());
+    /// # });
+    /// ```
+    pub fn spawn_async<T: crate::task::MaybeSend + 'static>(
+        &self,
+        future: impl Future<Output = T> + crate::task::MaybeSend + 'static,
+    ) -> crate::Task<T> {
+        let (sender, task) = crate::Task::new();
+        let ctx = self.clone();
+        let future = Box::pin(async move {
+            let value = future.await;
+            if sender.send(value).is_ok() {
+                ctx.request_repaint();
+            }
+        });
+
+        // Don't hold the lock while spawning, in case the spawner runs the future right away.
+        let spawner = self.read(|ctx| ctx.task_spawner.clone());
+        if let Some(spawner) = spawner {
+            spawner(future);
+        } else {
+            #[cfg(not(target_arch = "wasm32"))]
+            crate::task::spawn_thread(future);
+
+            #[cfg(target_arch = "wasm32")]
+            {
+                #[cfg(feature = "log")]
+                log::error!("No task spawner set. Call Context::set_task_spawner first.");
+                drop(future);
+            }
+        }
+
+        task
+    }
+
     /// Request to discard the visual output of this pass,
     /// and to immediately do another one.
     ///
@@ -1868,6 +2317,101 @@ impl Context {
     }
 }
 
+/// ## Frame time budget
+impl Context {
+    /// The integration should call this after each frame with how long it took to compute,
+    /// in seconds (e.g. the CPU time spent running the app and painting).
+    ///
+    /// This is compared against [`Options::frame_time_budget`] to decide [`Self::is_degraded`].
+    /// `eframe` calls this for you.
+    pub fn report_frame_time(&self, seconds: f32) {
+        let changed = self.write(|ctx| {
+            let budget = ctx.memory.options.frame_time_budget;
+            ctx.frame_budget.report(seconds, budget)
+        });
+
+        if changed {
+            self.request_repaint();
+            let callbacks = self.read(|ctx| ctx.plugins.on_degradation_changed.clone());
+            Plugins::call(self, "on_degradation_changed", &callbacks);
+        }
+    }
+
+    /// Recent frames have been slower than [`Options::frame_time_budget`].
+    ///
+    /// While this is `true`, egui skips shadows, turns off feathering (anti-aliasing),
+    /// and finishes all animations instantly, to keep interaction responsive on weak hardware.
+    /// Apps can check this to shed work of their own, e.g. lower the quality of a 3D view.
+    ///
+    /// See also [`Self::on_degradation_changed`].
+    pub fn is_degraded(&self) -> bool {
+        self.read(|ctx| ctx.is_degraded())
+    }
+
+    /// How many heap allocations the last pass did, from [`Self::begin_pass`] to [`Self::end_pass`].
+    ///
+    /// Use this to find allocator churn, e.g. in apps with tens of thousands of widgets.
+    /// This is all zero unless [`epaint::util::CountingAllocator`] is your global allocator.
+    #[cfg(feature = "count_allocations")]
+    pub fn allocations_last_pass(&self) -> epaint::util::AllocationStats {
+        self.read(|ctx| ctx.allocations_last_pass)
+    }
+
+    /// The smoothed frame time reported with [`Self::report_frame_time`], in seconds.
+    ///
+    /// `None` if the integration doesn't report frame times.
+    pub fn smoothed_frame_time(&self) -> Option<f32> {
+        self.read(|ctx| ctx.frame_budget.smoothed_frame_time)
+    }
+}
+
+/// ## Snapshots
+impl Context {
+    /// Copy the state that the next pass of the viewport starts from:
+    /// the [`Memory`], the input, the widgets of the previous pass, and the animations.
+    ///
+    /// Call this between passes. See [`crate::TimeTravel`].
+    pub fn snapshot(&self, viewport_id: ViewportId) -> crate::ContextSnapshot {
+        self.write(|ctx| {
+            let memory = ctx.memory.clone();
+            let animation_manager = ctx.animation_manager.clone();
+            let viewport = ctx.viewports.entry(viewport_id).or_default();
+            crate::ContextSnapshot {
+                viewport_id,
+                memory,
+                animation_manager,
+                input: viewport.input.clone(),
+                prev_pass: viewport.prev_pass.clone(),
+                interact_widgets: viewport.interact_widgets.clone(),
+            }
+        })
+    }
+
+    /// Go back to the state of a [`Self::snapshot`], so that the next pass of its viewport
+    /// starts from where it did back then.
+    ///
+    /// Textures, fonts and the pass counters are left as they are.
+    pub fn restore(&self, snapshot: &crate::ContextSnapshot) {
+        let crate::ContextSnapshot {
+            viewport_id,
+            memory,
+            animation_manager,
+            input,
+            prev_pass,
+            interact_widgets,
+        } = snapshot.clone();
+
+        self.write(|ctx| {
+            ctx.memory = memory;
+            ctx.animation_manager = animation_manager;
+            let viewport = ctx.viewports.entry(viewport_id).or_default();
+            viewport.input = input;
+            viewport.prev_pass = prev_pass;
+            viewport.interact_widgets = interact_widgets;
+        });
+    }
+}
+
 /// Callbacks
 impl Context {
     /// Call the given callback at the start of each pass of each viewport.
@@ -1893,6 +2437,153 @@ impl Context {
         };
         self.write(|ctx| ctx.plugins.on_end_pass.push(named_cb));
     }
+
+    /// Call the given callback each time [`Self::is_degraded`] changes.
+    ///
+    /// Use this to shed work when frames are too slow, and to restore it once they are fast again.
+    /// See [`Options::frame_time_budget`].
+    pub fn on_degradation_changed(&self, debug_name: &'static str, cb: ContextCallback) {
+        let named_cb = NamedContextCallback {
+            debug_name,
+            callback: cb,
+        };
+        self.write(|ctx| ctx.plugins.on_degradation_changed.push(named_cb));
+    }
+
+    /// Paint extra shapes below or on top of every widget of the given type.
+    ///
+    /// Use `None` as `widget_type` to decorate all widgets.
+    /// This lets a design system restyle interaction feedback (focus rings, hover effects, …)
+    /// without forking the widgets themselves.
+    ///
+    /// Decorators are only applied to widgets added with [`Ui::add`]
+    /// (which includes helpers like [`Ui::button`]) that report a [`crate::WidgetInfo`].
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// use egui::{DecoratorPhase, Shape, Stroke, StrokeKind, WidgetType};
+    /// use std::sync::Arc;
+    ///
+    /// ctx.add_widget_decorator(
+    ///     "focus_ring",
+    ///     DecoratorPhase::Post,
+    ///     Some(WidgetType::Button),
+    ///     Arc::new(|ui, response, _info| {
+    ///         if response.has_focus() {
+    ///             let stroke = Stroke::new(2.0, ui.visuals().selection.stroke.color);
+    ///             Shape::rect_stroke(response.rect.expand(2.0), 4.0, stroke, StrokeKind::Outside)
+    ///         } else {
+    ///             Shape::Noop
+    ///         }
+    ///     }),
+    /// );
+    /// # });
+    /// ```
+    pub fn add_widget_decorator(
+        &self,
+        debug_name: &'static str,
+        phase: DecoratorPhase,
+        widget_type: Option<crate::WidgetType>,
+        decorator: WidgetDecorator,
+    ) {
+        let named_decorator = NamedWidgetDecorator {
+            debug_name,
+            phase,
+            widget_type,
+            decorator,
+        };
+        self.write(|ctx| ctx.plugins.widget_decorators.push(named_decorator));
+    }
+
+    /// Remove all widget decorators added with the given `debug_name`.
+    ///
+    /// See [`Self::add_widget_decorator`].
+    pub fn remove_widget_decorators(&self, debug_name: &'static str) {
+        self.write(|ctx| {
+            ctx.plugins
+                .widget_decorators
+                .retain(|decorator| decorator.debug_name != debug_name);
+        });
+    }
+
+    /// Call the given callback each time the user interacts with a widget.
+    ///
+    /// This is an opt-in structured interaction log, e.g. for UX analytics or usage heatmaps.
+    /// An event is recorded for each [`crate::output::OutputEvent`] a widget emits
+    /// (clicks, focus changes, value changes, …), so no call site needs to be instrumented.
+    ///
+    /// The callback is called during the pass, so keep it cheap (e.g. push to a channel).
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// use std::sync::{Arc, mpsc};
+    ///
+    /// let (sender, receiver) = mpsc::channel();
+    /// ctx.on_interaction(
+    ///     "analytics",
+    ///     Arc::new(move |event: &egui::InteractionEvent| {
+    ///         sender.send(*event).ok();
+    ///     }),
+    /// );
+    /// # });
+    /// ```
+    pub fn on_interaction(&self, debug_name: &'static str, cb: InteractionCallback) {
+        let named_cb = NamedInteractionCallback {
+            debug_name,
+            callback: cb,
+        };
+        self.write(|ctx| ctx.plugins.on_interaction.push(named_cb));
+    }
+
+    /// Remove all interaction callbacks added with the given `debug_name`.
+    ///
+    /// See [`Self::on_interaction`].
+    pub fn remove_interaction_callbacks(&self, debug_name: &'static str) {
+        self.write(|ctx| {
+            ctx.plugins
+                .on_interaction
+                .retain(|cb| cb.debug_name != debug_name);
+        });
+    }
+
+    /// Report an interaction to the callbacks registered with [`Self::on_interaction`].
+    pub(crate) fn record_interaction(&self, make_event: impl FnOnce() -> crate::InteractionEvent) {
+        let callbacks = self.read(|ctx| ctx.plugins.on_interaction.clone());
+        if callbacks.is_empty() {
+            return;
+        }
+
+        profiling::function_scope!();
+        let event = make_event();
+        for NamedInteractionCallback {
+            debug_name: _name,
+            callback,
+        } in &callbacks
+        {
+            profiling::scope!("interaction callback", _name);
+            (callback)(&event);
+        }
+    }
+
+    /// Are there any widget decorators registered?
+    pub(crate) fn has_widget_decorators(&self) -> bool {
+        self.read(|ctx| !ctx.plugins.widget_decorators.is_empty())
+    }
+
+    /// The decorators that apply to a widget of the given type, in registration order.
+    pub(crate) fn widget_decorators(
+        &self,
+        widget_type: crate::WidgetType,
+    ) -> Vec<NamedWidgetDecorator> {
+        self.read(|ctx| {
+            ctx.plugins
+                .widget_decorators
+                .iter()
+                .filter(|decorator| decorator.widget_type.is_none_or(|typ| typ == widget_type))
+                .cloned()
+                .collect()
+        })
+    }
 }
 
 impl Context {
@@ -1981,7 +2672,30 @@ impl Context {
         self.options_mut(|opt| opt.theme_preference = theme_preference.into());
     }
 
-    /// The currently active [`Style`] used by all subsequent windows, panels etc.
+    /// Like [`Self::set_theme`], but cross-fade from the current style to the new one
+    /// over the given number of seconds, instead of switching instantly.
+    ///
+    /// The colors, sizes etc of the two styles are interpolated with [`Style::lerp`].
+    ///
+    /// Example:
+    /// ```
+    /// # let mut ctx = egui::Context::default();
+    /// ctx.set_theme_animated(egui::Theme::Light, 0.3);
+    /// ```
+    pub fn set_theme_animated(
+        &self,
+        theme_preference: impl Into<crate::ThemePreference>,
+        duration: f32,
+    ) {
+        let time = self.input(|i| i.time);
+        self.options_mut(|opt| {
+            opt.start_theme_transition(time, duration);
+            opt.theme_preference = theme_preference.into();
+        });
+        self.request_repaint();
+    }
+
+    /// The currently active [`Style`] used by all subsequent windows, panels etc.
     pub fn style(&self) -> Arc<Style> {
         self.options(|opt| opt.style().clone())
     }
@@ -2259,6 +2973,8 @@ impl Context {
         // Plugins run just before the pass ends.
         self.read(|ctx| ctx.plugins.clone()).on_end_pass(self);
 
+        crate::shortcuts::show_cheatsheet(self);
+
         #[cfg(debug_assertions)]
         self.debug_painting();
 
@@ -2396,6 +3112,50 @@ impl Context {
             }
         }
 
+        if self.style().debug.show_contrast_warnings {
+            let visuals = self.style().visuals.clone();
+            let widget_rects = self.write(|ctx| ctx.viewport().this_pass.widgets.clone());
+            let interact_widgets = self.write(|ctx| ctx.viewport().interact_widgets.clone());
+
+            for widget in widget_rects.layers().flat_map(|(_, widgets)| widgets) {
+                let has_text = widget_rects
+                    .info(widget.id)
+                    .is_some_and(|info| info.label.is_some() || info.current_text_value.is_some());
+                if !has_text {
+                    continue;
+                }
+
+                let id = widget.id;
+                let widget_visuals = if !widget.sense.interactive() {
+                    &visuals.widgets.noninteractive
+                } else if interact_widgets.clicked == Some(id)
+                    || interact_widgets.dragged == Some(id)
+                {
+                    &visuals.widgets.active
+                } else if interact_widgets.hovered.contains(&id) {
+                    &visuals.widgets.hovered
+                } else {
+                    &visuals.widgets.inactive
+                };
+
+                let (_, _, ratio) = visuals.contrast_on_panel(
+                    widget_visuals.fg_stroke.color,
+                    widget_visuals.weak_bg_fill,
+                );
+                if ratio < crate::style::ContrastWarning::TEXT {
+                    paint_widget(widget, &format!("contrast {ratio:.1}"), Color32::ORANGE);
+                }
+            }
+        }
+
+        if self.style().debug.show_overflow {
+            let overflows = self.pass_state(|fs| fs.overflows.clone());
+            for overflow in overflows {
+                let painter = Painter::new(self.clone(), overflow.layer_id, Rect::EVERYTHING);
+                painter.debug_rect(overflow.rect, Color32::RED, overflow.reason);
+            }
+        }
+
         if let Some(debug_rect) = self.pass_state_mut(|fs| fs.debug_rect.take()) {
             debug_rect.paint(&self.debug_painter());
         }
@@ -2488,9 +3248,33 @@ impl ContextImpl {
             }
         }
 
-        let shapes = viewport
-            .graphics
-            .drain(self.memory.areas().order(), &self.memory.to_global);
+        let stats = crate::FrameStats {
+            layers: viewport.graphics.layer_stats(),
+            tessellation: self.last_tessellation,
+            ..crate::FrameStats::from_textures_delta(&textures_delta)
+        };
+
+        let mut shapes = std::mem::take(&mut self.shape_pool);
+        viewport.graphics.drain_into(
+            self.memory.areas().order(),
+            &self.memory.to_global,
+            &self.memory.layer_tints,
+            &mut shapes,
+        );
+
+        let mouse_hit_test_regions = match &viewport.mouse_hit_test {
+            MouseHitTest::Everywhere => None,
+            MouseHitTest::Regions(regions) => Some(regions.clone()),
+            MouseHitTest::Painted => Some(painted_regions(&shapes)),
+            MouseHitTest::Interactive => Some(interactive_regions(
+                &viewport.this_pass.widgets,
+                &self.memory.to_global,
+            )),
+        };
+        if mouse_hit_test_regions != viewport.mouse_hit_test_regions {
+            viewport.mouse_hit_test_regions.clone_from(&mouse_hit_test_regions);
+            viewport.commands.push(ViewportCommand::MouseHitTestRegions(mouse_hit_test_regions));
+        }
 
         let mut repaint_needed = false;
 
@@ -2552,6 +3336,12 @@ impl ContextImpl {
         // just the top _immediate_ viewport.
         let is_last = self.viewport_stack.is_empty();
 
+        #[cfg(feature = "count_allocations")]
+        if is_last {
+            self.allocations_last_pass =
+                epaint::util::AllocationStats::now() - self.allocations_at_pass_start;
+        }
+
         let viewport_output = self
             .viewports
             .iter_mut()
@@ -2616,7 +3406,127 @@ impl ContextImpl {
             shapes,
             pixels_per_point,
             viewport_output,
+            stats,
+        }
+    }
+}
+
+impl ContextImpl {
+    fn tessellator(&self, pixels_per_point: f32) -> tessellator::Tessellator {
+        let mut tessellation_options = self.memory.options.tessellation_options;
+        if self.is_degraded() {
+            tessellation_options.feathering = false;
+        }
+        let texture_atlas = if let Some(fonts) = self.fonts.get(&pixels_per_point.into()) {
+            fonts.texture_atlas()
+        } else {
+            #[cfg(feature = "log")]
+            log::warn!("No font size matching {pixels_per_point} pixels per point found.");
+            self.fonts
+                .iter()
+                .next()
+                .expect("No fonts loaded")
+                .1
+                .texture_atlas()
+        };
+        let (font_tex_size, prepared_discs) = {
+            let atlas = texture_atlas.lock();
+            (atlas.size(), atlas.prepared_discs())
+        };
+        tessellator::Tessellator::new(
+            pixels_per_point,
+            tessellation_options,
+            font_tex_size,
+            prepared_discs,
+        )
+    }
+}
+
+/// Where something was painted, for [`MouseHitTest::Painted`].
+fn painted_regions(shapes: &[ClippedShape]) -> Vec<Rect> {
+    shapes
+        .iter()
+        .filter_map(|clipped| {
+            let rect = clipped.shape.visual_bounding_rect().intersect(clipped.clip_rect);
+            rect.is_positive().then_some(rect)
+        })
+        .collect()
+}
+
+/// Where the widgets that can be clicked or dragged are, for [`MouseHitTest::Interactive`].
+fn interactive_regions(
+    widgets: &crate::WidgetRects,
+    to_global: &ahash::HashMap<LayerId, TSTransform>,
+) -> Vec<Rect> {
+    widgets
+        .layers()
+        .flat_map(|(layer_id, widgets)| {
+            let to_global = to_global.get(layer_id).copied().unwrap_or_default();
+            widgets
+                .iter()
+                .filter(|widget| widget.enabled && widget.sense.interactive())
+                .map(move |widget| to_global * widget.interact_rect)
+        })
+        .filter(|rect| rect.is_positive())
+        .collect()
+}
+
+/// ## Low latency painting
+impl Context {
+    pub(crate) fn register_low_latency_painter(&self, painter: crate::painter::LowLatencyPainter) {
+        self.write(|ctx| ctx.viewport().this_pass.low_latency_painters.push(painter));
+    }
+
+    /// Did the last pass of this viewport use [`crate::Painter::paint_low_latency`]?
+    pub fn has_low_latency_painters(&self, viewport_id: ViewportId) -> bool {
+        self.read(|ctx| {
+            ctx.viewports
+                .get(&viewport_id)
+                .is_some_and(|viewport| !viewport.prev_pass.low_latency_painters.is_empty())
+        })
+    }
+
+    /// For integrations: paint the pointer events that arrived since the last pass,
+    /// with the [`crate::Painter::paint_low_latency`] of that pass.
+    ///
+    /// `events` are the events collected for the next pass so far.
+    /// Paint the returned primitives on top of the primitives of the last frame,
+    /// with the `pixels_per_point` of that frame, and present the result right away.
+    /// Keep the events for the next pass; the ui will then paint the strokes itself.
+    pub fn low_latency_primitives(
+        &self,
+        viewport_id: ViewportId,
+        events: &[crate::Event],
+    ) -> Vec<ClippedPrimitive> {
+        let (painters, pixels_per_point) = self.read(|ctx| {
+            ctx.viewports.get(&viewport_id).map_or_else(Default::default, |viewport| {
+                (
+                    viewport.prev_pass.low_latency_painters.clone(),
+                    viewport.input.pixels_per_point,
+                )
+            })
+        });
+        if painters.is_empty() {
+            return vec![];
         }
+
+        // Paint without holding any locks, in case the painters use the context:
+        let shapes: Vec<ClippedShape> = painters
+            .iter()
+            .flat_map(|painter| {
+                let to_global = self.layer_transform_to_global(painter.layer_id);
+                (painter.paint)(events).into_iter().map(move |mut shape| {
+                    let mut clip_rect = painter.clip_rect;
+                    if let Some(to_global) = to_global {
+                        shape.transform(to_global);
+                        clip_rect = to_global * clip_rect;
+                    }
+                    ClippedShape { clip_rect, shape }
+                })
+            })
+            .collect();
+
+        self.read(|ctx| ctx.tessellator(pixels_per_point).tessellate_shapes(shapes))
     }
 }
 
@@ -2637,41 +3547,97 @@ impl Context {
         // shapes are the same, but just comparing the shapes takes about 50% of the time
         // it takes to tessellate them, so it is not a worth optimization.
 
-        self.write(|ctx| {
-            let tessellation_options = ctx.memory.options.tessellation_options;
-            let texture_atlas = if let Some(fonts) = ctx.fonts.get(&pixels_per_point.into()) {
-                fonts.texture_atlas()
-            } else {
-                #[cfg(feature = "log")]
-                log::warn!("No font size matching {pixels_per_point} pixels per point found.");
-                ctx.fonts
-                    .iter()
-                    .next()
-                    .expect("No fonts loaded")
-                    .1
-                    .texture_atlas()
-            };
-            let (font_tex_size, prepared_discs) = {
-                let atlas = texture_atlas.lock();
-                (atlas.size(), atlas.prepared_discs())
-            };
+        let mut shapes = shapes;
 
+        self.write(|ctx| {
             let paint_stats = PaintStats::from_shapes(&shapes);
+            #[cfg(not(target_arch = "wasm32"))]
+            let start = std::time::Instant::now();
             let clipped_primitives = {
                 profiling::scope!("tessellator::tessellate_shapes");
-                tessellator::Tessellator::new(
-                    pixels_per_point,
-                    tessellation_options,
-                    font_tex_size,
-                    prepared_discs,
-                )
-                .tessellate_shapes(shapes)
+                ctx.tessellator(pixels_per_point)
+                    .tessellate_shapes_drain(&mut shapes)
             };
+            #[cfg(not(target_arch = "wasm32"))]
+            let seconds = Some(start.elapsed().as_secs_f32());
+            #[cfg(target_arch = "wasm32")]
+            let seconds = None;
+            ctx.last_tessellation =
+                Some(crate::TessellationStats::new(&clipped_primitives, seconds));
             ctx.paint_stats = paint_stats.with_clipped_primitives(&clipped_primitives);
+            if ctx.shape_pool.capacity() < shapes.capacity() {
+                ctx.shape_pool = shapes;
+            }
             clipped_primitives
         })
     }
 
+    /// A new [`Context`] with the fonts and style of this one,
+    /// for laying out something in a pass of its own, away from the screen.
+    pub(crate) fn new_isolated(&self) -> Self {
+        let ctx = Self::default();
+        ctx.set_fonts(self.fonts(|f| f.lock().fonts.definitions().clone()));
+        ctx.set_style(self.style());
+        ctx
+    }
+
+    /// Paint some ui into an image, e.g. to export a chart or a card as a PNG.
+    ///
+    /// The ui is laid out in a pass of its own, in a [`Context`] with the fonts and style
+    /// of this one, so it doesn't show up on screen.
+    /// `scale` is the number of pixels per point of the image, whatever the display is,
+    /// so use e.g. `4.0` for a crisp image to print.
+    ///
+    /// The background is transparent, so paint one (e.g. with a [`crate::Frame`]) if you want it.
+    /// The image is painted on the CPU with [`epaint::Rasterizer`],
+    /// which leaves out paint callbacks and images with textures of your own.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// let image = ctx.render_to_image(egui::vec2(200.0, 100.0), 4.0, |ui| {
+    ///     egui::Frame::canvas(ui.style()).show(ui, |ui| {
+    ///         ui.heading("Revenue");
+    ///         ui.label("€ 1.2M");
+    ///     });
+    /// });
+    /// assert_eq!(image.size, [800, 400]);
+    /// # });
+    /// ```
+    pub fn render_to_image(
+        &self,
+        size_in_points: Vec2,
+        scale: f32,
+        mut add_contents: impl FnMut(&mut Ui),
+    ) -> epaint::ColorImage {
+        let ctx = self.new_isolated();
+
+        let rect = Rect::from_min_size(Pos2::ZERO, size_in_points);
+        let info = crate::ViewportInfo {
+            native_pixels_per_point: Some(scale),
+            ..Default::default()
+        };
+        let raw_input = RawInput {
+            screen_rect: Some(rect),
+            viewports: std::iter::once((ViewportId::ROOT, info)).collect(),
+            ..Default::default()
+        };
+        let output = ctx.run(raw_input, |ctx| {
+            let builder = crate::UiBuilder::new().max_rect(rect);
+            add_contents(&mut Ui::new(ctx.clone(), Id::new("render_to_image"), builder));
+        });
+
+        let mut rasterizer = epaint::Rasterizer::default();
+        rasterizer.set_textures(&output.textures_delta);
+        let primitives = ctx.tessellate(output.shapes, output.pixels_per_point);
+        let size = (scale * size_in_points).round();
+        rasterizer.paint(
+            [size.x as usize, size.y as usize],
+            output.pixels_per_point,
+            Color32::TRANSPARENT,
+            &primitives,
+        )
+    }
+
     // ---------------------------------------------------------------------
 
     /// Position and size of the egui area.
@@ -2679,6 +3645,50 @@ impl Context {
         self.input(|i| i.screen_rect()).round_ui()
     }
 
+    /// The [`crate::SizeClass`] of the [`Self::screen_rect`] width,
+    /// according to [`crate::style::Spacing::breakpoints`].
+    ///
+    /// Use this to swap between layouts, e.g. a side panel on desktop and a bottom panel on a phone.
+    /// The result only changes once the width has moved past the
+    /// [`crate::style::Breakpoints::hysteresis`], so the layout doesn't flicker while resizing.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// # let _ = ctx.run(Default::default(), |ctx| {
+    /// if ctx.breakpoint().is_compact() {
+    ///     egui::TopBottomPanel::bottom("navigation").show(ctx, |ui| {
+    ///         ui.label("Navigation");
+    ///     });
+    /// } else {
+    ///     egui::SidePanel::left("navigation").show(ctx, |ui| {
+    ///         ui.label("Navigation");
+    ///     });
+    /// }
+    /// # });
+    /// ```
+    pub fn breakpoint(&self) -> crate::SizeClass {
+        let id = Id::new("breakpoint").with(self.viewport_id());
+        let breakpoints = self.style().spacing.breakpoints;
+        self.size_class_for(id, &breakpoints, self.screen_rect().width())
+    }
+
+    /// The [`crate::SizeClass`] of `width`, with hysteresis based on what it was for `id` last time.
+    pub(crate) fn size_class_for(
+        &self,
+        id: Id,
+        breakpoints: &crate::style::Breakpoints,
+        width: f32,
+    ) -> crate::SizeClass {
+        self.data_mut(|data| {
+            let size_class = match data.get_temp(id) {
+                Some(previous) => breakpoints.size_class_with_hysteresis(width, previous),
+                None => breakpoints.size_class(width),
+            };
+            data.insert_temp(id, size_class);
+            size_class
+        })
+    }
+
     /// How much space is still available after panels have been added.
     pub fn available_rect(&self) -> Rect {
         self.pass_state(|s| s.available_rect()).round_ui()
@@ -2833,6 +3843,54 @@ impl Context {
         });
     }
 
+    /// Multiply the alpha of everything painted in the given layer with this, in the range [0, 1].
+    ///
+    /// This is applied when the layer is composited at the end of the frame,
+    /// so it can be used to fade whole windows or areas in and out.
+    /// The layer is still interactive, even at zero opacity.
+    ///
+    /// This is a sticky setting, remembered from one frame to the next.
+    pub fn set_layer_opacity(&self, layer_id: LayerId, opacity: f32) {
+        let opacity = opacity.clamp(0.0, 1.0);
+        self.update_layer_tint(layer_id, |layer_tint| layer_tint.opacity = opacity);
+    }
+
+    /// The opacity of the given layer, set with [`Self::set_layer_opacity`].
+    pub fn layer_opacity(&self, layer_id: LayerId) -> f32 {
+        self.memory(|m| m.layer_tints.get(&layer_id).map_or(1.0, |t| t.opacity))
+    }
+
+    /// Multiply the color of everything painted in the given layer with this.
+    ///
+    /// Use e.g. [`Color32::GRAY`] to dim everything behind a modal dialog.
+    /// Use [`Color32::WHITE`] for no tint.
+    ///
+    /// This is a sticky setting, remembered from one frame to the next.
+    pub fn set_layer_tint(&self, layer_id: LayerId, tint: Color32) {
+        self.update_layer_tint(layer_id, |layer_tint| layer_tint.tint = tint);
+    }
+
+    /// The tint of the given layer, set with [`Self::set_layer_tint`].
+    pub fn layer_tint(&self, layer_id: LayerId) -> Color32 {
+        self.memory(|m| {
+            m.layer_tints
+                .get(&layer_id)
+                .map_or(Color32::WHITE, |t| t.tint)
+        })
+    }
+
+    fn update_layer_tint(&self, layer_id: LayerId, update: impl FnOnce(&mut LayerTint)) {
+        self.memory_mut(|m| {
+            let mut layer_tint = m.layer_tints.get(&layer_id).copied().unwrap_or_default();
+            update(&mut layer_tint);
+            if layer_tint.is_identity() {
+                m.layer_tints.remove(&layer_id);
+            } else {
+                m.layer_tints.insert(layer_id, layer_tint);
+            }
+        });
+    }
+
     /// Return how to transform the graphics of the given layer into the global coordinate system.
     ///
     /// Set this with [`Self::layer_transform_to_global`].
@@ -2884,6 +3942,25 @@ impl Context {
         self.memory_mut(|mem| mem.areas_mut().set_sublayer(parent, child));
     }
 
+    /// Capture the position, size and collapse state of all windows and panels,
+    /// as well as the placement of all viewports.
+    ///
+    /// Windows are captured for the current viewport only.
+    /// Use [`Self::restore_layout`] to go back to this layout later,
+    /// e.g. to let the user switch between named workspaces.
+    pub fn save_layout(&self) -> LayoutSnapshot {
+        LayoutSnapshot::save(self)
+    }
+
+    /// Put windows, panels and viewports back to how they were in a [`LayoutSnapshot`]
+    /// from [`Self::save_layout`].
+    ///
+    /// The changes take effect the next time the windows and panels are shown.
+    /// Windows and panels that are not in the snapshot are left as they are.
+    pub fn restore_layout(&self, snapshot: &LayoutSnapshot) {
+        snapshot.restore(self);
+    }
+
     /// Retrieve the [`LayerId`] of the top level windows.
     pub fn top_layer_id(&self) -> Option<LayerId> {
         self.memory(|mem| mem.areas().top_layer_id(Order::Middle))
@@ -2997,6 +4074,11 @@ impl Context {
         easing: fn(f32) -> f32,
     ) -> f32 {
         let animated_value = self.write(|ctx| {
+            let animation_time = if ctx.is_degraded() {
+                0.0
+            } else {
+                animation_time
+            };
             ctx.animation_manager.animate_bool(
                 &ctx.viewports.entry(ctx.viewport_id()).or_default().input,
                 animation_time,
@@ -3024,6 +4106,11 @@ impl Context {
     #[track_caller] // To track repaint cause
     pub fn animate_value_with_time(&self, id: Id, target_value: f32, animation_time: f32) -> f32 {
         let animated_value = self.write(|ctx| {
+            let animation_time = if ctx.is_degraded() {
+                0.0
+            } else {
+                animation_time
+            };
             ctx.animation_manager.animate_value(
                 &ctx.viewports.entry(ctx.viewport_id()).or_default().input,
                 animation_time,
@@ -3136,13 +4223,28 @@ impl Context {
 
         ui.add_space(16.0);
 
+        let galley_cache_stats = self.fonts(|f| f.galley_cache_stats());
         ui.label(format!(
             "There are {} text galleys in the layout cache",
-            self.fonts(|f| f.num_galleys_in_cache())
+            galley_cache_stats.num_galleys
         ))
         .on_hover_text("This is approximately the number of text strings on screen");
+        ui.label(format!(
+            "Last pass: {} cache hits, {} misses, {} evicted",
+            galley_cache_stats.hits, galley_cache_stats.misses, galley_cache_stats.evictions
+        ));
         ui.add_space(16.0);
 
+        #[cfg(feature = "count_allocations")]
+        {
+            let stats = self.allocations_last_pass();
+            ui.label(format!(
+                "The last pass did {} allocations ({} bytes) and {} deallocations",
+                stats.num_allocations, stats.num_bytes_allocated, stats.num_deallocations
+            ));
+            ui.add_space(16.0);
+        }
+
         CollapsingHeader::new("🔃 Repaint Causes")
             .default_open(false)
             .show(ui, |ui| {
@@ -3155,6 +4257,15 @@ impl Context {
                 }
             });
 
+        #[cfg(debug_assertions)]
+        {
+            if self.style().debug.show_overflow {
+                CollapsingHeader::new("⚠ Overflowing widgets")
+                    .default_open(false)
+                    .show(ui, |ui| self.overflow_ui(ui));
+            }
+        }
+
         CollapsingHeader::new("📥 Input")
             .default_open(false)
             .show(ui, |ui| {
@@ -3205,6 +4316,39 @@ impl Context {
             });
     }
 
+    /// List the widgets that didn't fit last pass, see [`crate::style::DebugOptions::show_overflow`].
+    #[cfg(debug_assertions)]
+    fn overflow_ui(&self, ui: &mut Ui) {
+        let overflows = self.prev_pass_state(|fs| fs.overflows.clone());
+        if overflows.is_empty() {
+            ui.label("Everything fits 👍");
+            return;
+        }
+
+        for (i, overflow) in overflows.into_iter().enumerate() {
+            let text = format!(
+                "{} at {:?} in {}",
+                overflow.reason,
+                overflow.rect,
+                overflow.layer_id.short_debug_format()
+            );
+            if overflow.callstack.is_empty() {
+                ui.label(text)
+                    .on_hover_text("Enable the `callstack` feature to see where this was added");
+            } else {
+                crate::CollapsingHeader::new(text)
+                    .id_salt(("overflow", i))
+                    .show(ui, |ui| {
+                        let callstack = RichText::new(&overflow.callstack).monospace();
+                        let response = ui.add(Label::new(callstack).sense(Sense::click()));
+                        if response.on_hover_text("Click to copy").clicked() {
+                            ui.ctx().copy_text(overflow.callstack.clone());
+                        }
+                    });
+            }
+        }
+    }
+
     /// Show stats about the allocated textures.
     pub fn texture_ui(&self, ui: &mut crate::Ui) {
         let tex_mngr = self.tex_manager();
@@ -3836,6 +4980,42 @@ impl Context {
         self.write(|ctx| ctx.viewport_for(id).commands.push(command));
     }
 
+    /// Make parts of the current viewport click-through, e.g. for an overlay.
+    ///
+    /// The mouse only interacts with the viewport where the [`MouseHitTest`] says so,
+    /// and clicks elsewhere go to whatever is below the window.
+    /// This stays in effect until you change it.
+    ///
+    /// At the end of each pass, egui sends [`ViewportCommand::MouseHitTestRegions`]
+    /// to the integration if the regions changed.
+    pub fn set_mouse_hit_test(&self, hit_test: MouseHitTest) {
+        self.write(|ctx| ctx.viewport().mouse_hit_test = hit_test);
+    }
+
+    /// Where the mouse can interact with the current viewport.
+    ///
+    /// See [`Self::set_mouse_hit_test`].
+    pub fn mouse_hit_test(&self) -> MouseHitTest {
+        self.write(|ctx| ctx.viewport().mouse_hit_test.clone())
+    }
+
+    /// Take a screenshot of the given viewport, e.g. a child window,
+    /// and get it back in the current viewport.
+    ///
+    /// The result is a [`crate::Event::Screenshot`] in the input of the current viewport,
+    /// with the `viewport_id` of the captured viewport and the given `user_data`.
+    /// The captured viewport is repainted for this, but doesn't need to be focused.
+    ///
+    /// Sending [`ViewportCommand::Screenshot`] to a viewport gives the result to
+    /// that viewport instead.
+    pub fn screenshot_viewport(&self, viewport_id: ViewportId, user_data: crate::UserData) {
+        let user_data = crate::UserData::new(ForwardedScreenshot {
+            requester: self.viewport_id(),
+            user_data,
+        });
+        self.send_viewport_cmd_to(viewport_id, ViewportCommand::Screenshot(user_data));
+    }
+
     /// Show a deferred viewport, creating a new native window, if possible.
     ///
     /// The given id must be unique for each viewport.
@@ -3970,6 +5150,64 @@ impl Context {
             )
         })
     }
+
+    /// Show an immediate viewport as a modal dialog of the current viewport,
+    /// and get its result once the user is done with it.
+    ///
+    /// This is [`Self::show_viewport_immediate`] with [`ViewportBuilder::with_modal_owner`],
+    /// so the current viewport gets no input while the dialog is shown.
+    ///
+    /// Keep calling this each pass until `viewport_ui_cb` returns `Some`,
+    /// which is then returned from here, and stop calling it to close the dialog.
+    /// If the user closes the window, [`crate::ViewportInfo::close_requested`] is set,
+    /// and you probably want to return some "cancelled" result.
+    ///
+    /// If the viewport is [`ViewportClass::Embedded`], nothing is blocked,
+    /// so consider showing the contents in a [`crate::Modal`].
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// # let mut confirm_quit = true;
+    /// if confirm_quit {
+    ///     let answer = ctx.show_modal_viewport_immediate(
+    ///         egui::ViewportId::from_hash_of("confirm_quit"),
+    ///         egui::ViewportBuilder::default().with_title("Quit?"),
+    ///         |ctx, _class| {
+    ///             if ctx.input(|i| i.viewport().close_requested()) {
+    ///                 return Some(false);
+    ///             }
+    ///             egui::CentralPanel::default()
+    ///                 .show(ctx, |ui| {
+    ///                     ui.label("Do you really want to quit?");
+    ///                     if ui.button("Quit").clicked() {
+    ///                         Some(true)
+    ///                     } else if ui.button("Cancel").clicked() {
+    ///                         Some(false)
+    ///                     } else {
+    ///                         None
+    ///                     }
+    ///                 })
+    ///                 .inner
+    ///         },
+    ///     );
+    ///     if let Some(quit) = answer {
+    ///         confirm_quit = false;
+    ///         if quit {
+    ///             ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+    ///         }
+    ///     }
+    /// }
+    /// # });
+    /// ```
+    pub fn show_modal_viewport_immediate<R>(
+        &self,
+        new_viewport_id: ViewportId,
+        builder: ViewportBuilder,
+        viewport_ui_cb: impl FnMut(&Self, ViewportClass) -> Option<R>,
+    ) -> Option<R> {
+        let builder = builder.with_modal_owner(self.viewport_id());
+        self.show_viewport_immediate(new_viewport_id, builder, viewport_ui_cb)
+    }
 }
 
 /// ## Interaction
@@ -4199,4 +5437,1087 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn test_widget_decorators() {
+        use std::sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        };
+
+        use crate::{DecoratorPhase, WidgetType};
+
+        let ctx = Context::default();
+        ctx.options_mut(|o| o.max_passes = 1.try_into().unwrap());
+
+        let num_buttons = Arc::new(AtomicUsize::new(0));
+        let num_widgets = Arc::new(AtomicUsize::new(0));
+        ctx.add_widget_decorator("buttons", DecoratorPhase::Post, Some(WidgetType::Button), {
+            let num_buttons = num_buttons.clone();
+            Arc::new(move |_ui, _response, _info| {
+                num_buttons.fetch_add(1, Ordering::Relaxed);
+                epaint::Shape::Noop
+            })
+        });
+        ctx.add_widget_decorator("all", DecoratorPhase::Pre, None, {
+            let num_widgets = num_widgets.clone();
+            Arc::new(move |_ui, _response, _info| {
+                num_widgets.fetch_add(1, Ordering::Relaxed);
+                epaint::Shape::Noop
+            })
+        });
+
+        let run = || {
+            let _ = ctx.run(Default::default(), |ctx| {
+                crate::CentralPanel::default().show(ctx, |ui| {
+                    ui.label("Label");
+                    _ = ui.button("Button");
+                });
+            });
+        };
+
+        run();
+        assert_eq!(num_buttons.load(Ordering::Relaxed), 1);
+        assert_eq!(num_widgets.load(Ordering::Relaxed), 2);
+
+        ctx.remove_widget_decorators("all");
+        run();
+        assert_eq!(num_buttons.load(Ordering::Relaxed), 2);
+        assert_eq!(num_widgets.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn test_interaction_callbacks() {
+        use std::sync::{Arc, Mutex};
+
+        use crate::{InteractionKind, Sense, WidgetInfo, WidgetType, vec2};
+
+        let ctx = Context::default();
+        ctx.options_mut(|o| o.max_passes = 1.try_into().unwrap());
+
+        let events = Arc::new(Mutex::new(vec![]));
+        ctx.on_interaction("test", {
+            let events = events.clone();
+            Arc::new(move |event| events.lock().unwrap().push(*event))
+        });
+
+        let _ = ctx.run(Default::default(), |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                let mut response = ui.allocate_response(vec2(10.0, 10.0), Sense::click());
+                response.mark_changed();
+                response.widget_info(|| WidgetInfo::labeled(WidgetType::Checkbox, true, "Check"));
+            });
+        });
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, InteractionKind::ValueChanged);
+        assert_eq!(events[0].widget_type, WidgetType::Checkbox);
+        assert_eq!(events[0].label_hash, Some(crate::util::hash("Check")));
+    }
+
+    #[test]
+    fn test_frame_time_budget() {
+        use std::sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        };
+
+        let ctx = Context::default();
+        let num_changes = Arc::new(AtomicUsize::new(0));
+        ctx.on_degradation_changed("test", {
+            let num_changes = num_changes.clone();
+            Arc::new(move |_ctx| {
+                num_changes.fetch_add(1, Ordering::Relaxed);
+            })
+        });
+
+        // No budget, no degradation:
+        ctx.report_frame_time(1.0);
+        assert!(!ctx.is_degraded());
+
+        ctx.options_mut(|o| o.frame_time_budget = Some(0.010));
+        ctx.report_frame_time(1.0);
+        assert!(ctx.is_degraded());
+        assert_eq!(num_changes.load(Ordering::Relaxed), 1);
+
+        for _ in 0..100 {
+            ctx.report_frame_time(0.001);
+        }
+        assert!(!ctx.is_degraded());
+        assert_eq!(num_changes.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn test_deterministic() {
+        use crate::{RawInput, memory::Options};
+
+        let ctx = Context::default();
+        ctx.options_mut(|o| {
+            o.deterministic = true;
+            o.frame_time_budget = Some(0.010);
+        });
+        ctx.report_frame_time(1.0);
+        assert!(!ctx.is_degraded(), "The wall clock doesn't matter");
+
+        for time in [123.0, 0.5] {
+            let raw_input = RawInput {
+                time: Some(time),
+                predicted_dt: 1.0 / 144.0,
+                ..Default::default()
+            };
+            let _ = ctx.run(raw_input, |_| {});
+        }
+        let step = Options::DETERMINISTIC_TIME_STEP;
+        ctx.input(|i| {
+            assert!((i.time - 2.0 * step as f64).abs() < 1e-6);
+            assert_eq!(i.predicted_dt, step);
+        });
+    }
+
+    #[test]
+    fn test_low_latency_painting() {
+        use crate::{CentralPanel, Color32, Event, Rect, Shape, ViewportId, pos2, vec2};
+
+        let ctx = Context::default();
+        let run = |ink: bool| {
+            let _ = ctx.run(Default::default(), |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    if ink {
+                        ui.painter().paint_low_latency(|events| {
+                            events
+                                .iter()
+                                .filter_map(|event| match event {
+                                    Event::PointerMoved(pos) => Some(Shape::rect_filled(
+                                        Rect::from_center_size(*pos, vec2(2.0, 2.0)),
+                                        0.0,
+                                        Color32::WHITE,
+                                    )),
+                                    _ => None,
+                                })
+                                .collect()
+                        });
+                    }
+                });
+            });
+        };
+        let events = [
+            Event::PointerMoved(pos2(10.0, 10.0)),
+            Event::PointerMoved(pos2(20.0, 20.0)),
+        ];
+
+        run(true);
+        assert!(ctx.has_low_latency_painters(ViewportId::ROOT));
+        let primitives = ctx.low_latency_primitives(ViewportId::ROOT, &events);
+        assert!(!primitives.is_empty());
+
+        run(false);
+        assert!(!ctx.has_low_latency_painters(ViewportId::ROOT));
+        assert!(ctx.low_latency_primitives(ViewportId::ROOT, &events).is_empty());
+    }
+
+    #[test]
+    fn test_pointer_samples() {
+        use crate::{Event, RawInput, pos2};
+
+        let ctx = Context::default();
+        let run = |time: f64, events: Vec<Event>| {
+            let input = RawInput {
+                time: Some(time),
+                events,
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |_| {});
+        };
+
+        run(1.0, vec![]);
+        run(
+            1.5,
+            vec![
+                Event::PointerMoved(pos2(10.0, 10.0)),
+                Event::PointerMoved(pos2(20.0, 20.0)),
+            ],
+        );
+        assert_eq!(
+            ctx.input(|i| i.pointer.samples().to_vec()),
+            [(1.25, pos2(10.0, 10.0)), (1.5, pos2(20.0, 20.0))]
+        );
+
+        run(2.0, vec![]);
+        assert!(ctx.input(|i| i.pointer.samples().is_empty()));
+
+        let samples = vec![(2.1, pos2(30.0, 30.0)), (2.2, pos2(40.0, 40.0))];
+        let input = RawInput {
+            time: Some(2.5),
+            events: vec![
+                Event::PointerMoved(pos2(30.0, 30.0)),
+                Event::PointerMoved(pos2(40.0, 40.0)),
+            ],
+            pointer_samples: samples.clone(),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |_| {});
+        assert_eq!(
+            ctx.input(|i| i.pointer.samples().to_vec()),
+            samples,
+            "The times from the integration are used"
+        );
+    }
+
+    #[test]
+    fn test_frame_stats() {
+        use crate::CentralPanel;
+
+        let ctx = Context::default();
+        let run = || {
+            ctx.run(Default::default(), |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    ui.label("Hello");
+                });
+            })
+        };
+
+        let output = run();
+        assert!(0 < output.stats.num_shapes());
+        assert!(0 < output.stats.textures_created, "The font atlas");
+        assert!(output.stats.tessellation.is_none(), "Not tessellated yet");
+
+        let primitives = ctx.tessellate(output.shapes, output.pixels_per_point);
+        let output = run();
+        let tessellation = output.stats.tessellation.unwrap();
+        assert_eq!(tessellation.num_primitives, primitives.len());
+        assert!(0 < tessellation.num_indices);
+    }
+
+    #[test]
+    fn test_cull_offscreen_widgets() {
+        use crate::{Rect, vec2};
+
+        let ctx = Context::default();
+        ctx.options_mut(|o| o.max_passes = 1.try_into().unwrap());
+        ctx.style_mut(|style| style.cull_offscreen_widgets = true);
+
+        let mut num_interactive = vec![];
+        for _ in 0..2 {
+            let _ = ctx.run(Default::default(), |ctx| {
+                crate::CentralPanel::default().show(ctx, |ui| {
+                    ui.set_clip_rect(Rect::from_min_size(ui.cursor().min, vec2(100.0, 100.0)));
+                    for i in 0..100 {
+                        _ = ui.button(format!("Button {i}"));
+                    }
+                    num_interactive.push(ctx.pass_state(|fs| {
+                        fs.widgets
+                            .get_layer(ui.layer_id())
+                            .filter(|w| w.sense.senses_click())
+                            .count()
+                    }));
+                });
+            });
+        }
+
+        assert_eq!(num_interactive[0], 100, "Sizes are unknown the first pass");
+        assert!(
+            0 < num_interactive[1] && num_interactive[1] < 10,
+            "Only the visible buttons should be shown: {num_interactive:?}"
+        );
+    }
+
+    #[test]
+    fn test_window_keyboard_move_and_resize() {
+        use crate::{Event, Id, Key, Modifiers, RawInput};
+
+        let ctx = Context::default();
+        ctx.style_mut(|style| style.animation_time = 0.0);
+
+        let window_id = Id::new("test_window");
+        let run = |keys: &[(Key, Modifiers)]| {
+            let events = keys
+                .iter()
+                .map(|&(key, modifiers)| Event::Key {
+                    key,
+                    physical_key: None,
+                    pressed: true,
+                    repeat: false,
+                    modifiers,
+                })
+                .collect();
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                crate::Window::new("Window").id(window_id).show(ctx, |ui| {
+                    ui.label("Hello");
+                });
+            });
+            ctx.memory(|mem| mem.area_rect(window_id)).unwrap()
+        };
+
+        run(&[]);
+        run(&[]);
+        ctx.memory_mut(|mem| mem.request_focus(window_id.with("__window_title_bar")));
+        let original = run(&[]);
+        run(&[]); // The focus lock filter is only applied once we had focus for a frame
+
+        let moved = run(&[(Key::ArrowRight, Modifiers::NONE)]);
+        assert_eq!(moved, original.translate(crate::vec2(8.0, 0.0)));
+
+        let resized = run(&[(Key::ArrowDown, Modifiers::SHIFT)]);
+        assert_eq!(resized.min, moved.min);
+        assert!(resized.height() > moved.height(), "{resized:?} vs {moved:?}");
+
+        let restored = run(&[(Key::Escape, Modifiers::NONE)]);
+        assert_eq!(restored, original);
+        assert!(!ctx.memory(|mem| mem.has_focus(window_id.with("__window_title_bar"))));
+    }
+
+    #[test]
+    fn test_save_and_restore_layout() {
+        use crate::{Id, pos2};
+
+        let ctx = Context::default();
+        let window_id = Id::new("test_window");
+        let run = || {
+            let _ = ctx.run(Default::default(), |ctx| {
+                crate::SidePanel::left("test_panel").show(ctx, |ui| {
+                    ui.label("Panel");
+                });
+                crate::Window::new("Window").id(window_id).show(ctx, |ui| {
+                    ui.label("Hello");
+                });
+            });
+        };
+
+        run();
+        run();
+        let snapshot = ctx.save_layout();
+        let original = ctx.memory(|mem| mem.area_rect(window_id)).unwrap();
+        assert_eq!(snapshot.windows.len(), 1);
+        assert_eq!(snapshot.panels.len(), 1);
+
+        ctx.memory_mut(|mem| {
+            let layer_id = crate::LayerId::new(crate::Order::Middle, window_id);
+            let mut state = *mem.areas().get(window_id).unwrap();
+            state.pivot_pos = Some(pos2(300.0, 200.0));
+            mem.areas_mut().set_state(layer_id, state);
+        });
+        run();
+        assert_ne!(ctx.memory(|mem| mem.area_rect(window_id)).unwrap(), original);
+
+        ctx.restore_layout(&snapshot);
+        run();
+        assert_eq!(ctx.memory(|mem| mem.area_rect(window_id)).unwrap(), original);
+        assert_eq!(ctx.save_layout(), snapshot);
+    }
+
+    #[test]
+    fn test_layer_opacity_and_tint() {
+        use crate::{Color32, LayerId, Rect, Shape};
+
+        let ctx = Context::default();
+        let layer_id = LayerId::background();
+        ctx.set_layer_opacity(layer_id, 0.5);
+        ctx.set_layer_tint(layer_id, Color32::RED);
+        assert_eq!(ctx.layer_opacity(layer_id), 0.5);
+
+        let output = ctx.run(Default::default(), |ctx| {
+            ctx.layer_painter(layer_id)
+                .rect_filled(Rect::EVERYTHING, 0.0, Color32::WHITE);
+            ctx.layer_painter(LayerId::debug())
+                .rect_filled(Rect::EVERYTHING, 0.0, Color32::WHITE);
+        });
+        let fills: Vec<Color32> = output
+            .shapes
+            .iter()
+            .filter_map(|clipped| match &clipped.shape {
+                Shape::Rect(rect) => Some(rect.fill),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(fills, [Color32::RED.gamma_multiply(0.5), Color32::WHITE]);
+
+        ctx.set_layer_opacity(layer_id, 1.0);
+        ctx.set_layer_tint(layer_id, Color32::WHITE);
+        assert!(ctx.memory(|m| m.layer_tints.is_empty()));
+    }
+
+    #[test]
+    fn test_scroll_input_mapping() {
+        use crate::{Event, Modifiers, MouseWheelUnit, RawInput, Vec2b, vec2};
+
+        let ctx = Context::default();
+        ctx.options_mut(|o| {
+            o.input_options.swap_scroll_axes = true;
+            o.input_options.invert_scroll = Vec2b::new(true, false);
+        });
+
+        let input = RawInput {
+            events: vec![Event::MouseWheel {
+                unit: MouseWheelUnit::Point,
+                delta: vec2(0.0, 10.0),
+                modifiers: Modifiers::NONE,
+            }],
+            ..Default::default()
+        };
+        let mut raw_scroll_delta = None;
+        let _ = ctx.run(input, |ctx| {
+            raw_scroll_delta = Some(ctx.input(|i| i.raw_scroll_delta));
+        });
+        assert_eq!(raw_scroll_delta, Some(vec2(-10.0, 0.0)));
+    }
+
+    #[test]
+    fn test_scroll_area_overscroll() {
+        use crate::{
+            CentralPanel, Event, Modifiers, MouseWheelUnit, RawInput, ScrollArea, pos2,
+            style::OverscrollEffect, vec2,
+        };
+
+        let ctx = Context::default();
+        ctx.style_mut(|style| style.spacing.scroll.overscroll_effect = OverscrollEffect::Stretch);
+
+        let mut max_overscroll = 0.0_f32;
+        for frame in 0..5 {
+            let mut events = vec![Event::PointerMoved(pos2(50.0, 50.0))];
+            if frame == 1 {
+                // Scroll up, when we are already at the top:
+                events.push(Event::MouseWheel {
+                    unit: MouseWheelUnit::Point,
+                    delta: vec2(0.0, 40.0),
+                    modifiers: Modifiers::NONE,
+                });
+            }
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    let output = ScrollArea::vertical().show(ui, |ui| {
+                        ui.label("Short content");
+                    });
+                    max_overscroll = max_overscroll.max(-output.state.overscroll().y);
+                });
+            });
+        }
+        assert!(0.0 < max_overscroll, "Scrolling past the top should overscroll");
+    }
+
+    #[test]
+    fn test_pull_to_refresh() {
+        use crate::{
+            CentralPanel, Event, Modifiers, MouseWheelUnit, PullToRefresh, RawInput, pos2, vec2,
+        };
+
+        let ctx = Context::default();
+
+        let mut num_requests = 0;
+        let mut num_callbacks = 0;
+        for frame in 0..60 {
+            let mut events = vec![Event::PointerMoved(pos2(50.0, 50.0))];
+            if frame == 1 {
+                // Pull down, when we are already at the top:
+                events.push(Event::MouseWheel {
+                    unit: MouseWheelUnit::Point,
+                    delta: vec2(0.0, 200.0),
+                    modifiers: Modifiers::NONE,
+                });
+            }
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    let output = PullToRefresh::new("feed")
+                        .threshold(5.0)
+                        .on_refresh(|| num_callbacks += 1)
+                        .show(ui, |ui| {
+                            ui.label("Short content");
+                        });
+                    if output.refresh_requested {
+                        num_requests += 1;
+                    }
+                });
+            });
+        }
+        assert_eq!(num_requests, 1);
+        assert_eq!(num_callbacks, 1);
+    }
+
+    #[test]
+    fn test_registered_shortcuts() {
+        use crate::{Key, KeyboardShortcut, Modifiers, RawInput};
+
+        let ctx = Context::default();
+        let save = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+        let search = KeyboardShortcut::new(Modifiers::COMMAND, Key::F);
+
+        let _ = ctx.run(RawInput::default(), |ctx| {
+            ctx.register_shortcut("File", "Save", save);
+            ctx.register_shortcut("File", "Save", save); // duplicates are ignored
+            ctx.register_shortcut("Edit", "Find", search);
+            ctx.register_shortcut("Edit", "Store", save); // conflict
+
+            let shortcuts = ctx.registered_shortcuts();
+            assert_eq!(shortcuts.len(), 3);
+            assert_eq!(shortcuts[0].description, "Save");
+            assert_eq!(shortcuts[2].shortcut, save);
+        });
+
+        // The registry is cleared each pass:
+        let _ = ctx.run(RawInput::default(), |ctx| {
+            assert!(ctx.registered_shortcuts().is_empty());
+        });
+    }
+
+    #[test]
+    fn test_monitors() {
+        use crate::{MonitorInfo, RawInput, Rect, pos2, vec2};
+
+        let ctx = Context::default();
+        let left = Rect::from_min_size(pos2(-1920.0, 0.0), vec2(1920.0, 1080.0));
+        let right = Rect::from_min_size(pos2(0.0, 0.0), vec2(2560.0, 1440.0));
+        let input = RawInput {
+            monitors: vec![
+                MonitorInfo {
+                    name: Some("Left".to_owned()),
+                    rect: left,
+                    ..Default::default()
+                },
+                MonitorInfo {
+                    name: Some("Right".to_owned()),
+                    rect: right,
+                    work_rect: Some(right.shrink2(vec2(0.0, 20.0))),
+                    native_pixels_per_point: Some(2.0),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        let _ = ctx.run(input, |ctx| {
+            ctx.input(|i| {
+                assert_eq!(i.monitors().len(), 2);
+                let monitor = i.monitor_at(pos2(-100.0, 100.0)).unwrap();
+                assert_eq!(monitor.name.as_deref(), Some("Left"));
+                assert_eq!(monitor.usable_rect(), left);
+                let monitor = i.monitor_at(pos2(100.0, 1400.0)).unwrap();
+                assert_eq!(monitor.usable_rect(), right.shrink2(vec2(0.0, 20.0)));
+                assert!(i.monitor_at(pos2(0.0, -100.0)).is_none());
+            });
+        });
+    }
+
+    #[test]
+    fn test_breakpoint_hysteresis() {
+        use crate::{RawInput, Rect, SizeClass, pos2};
+
+        let ctx = Context::default();
+        let size_class_at = |width: f32| {
+            let input = RawInput {
+                screen_rect: Some(Rect::from_min_max(pos2(0.0, 0.0), pos2(width, 400.0))),
+                ..Default::default()
+            };
+            let mut size_class = SizeClass::Compact;
+            let _ = ctx.run(input, |ctx| size_class = ctx.breakpoint());
+            size_class
+        };
+
+        // Default breakpoints are at 600 and 840, with a hysteresis of 16:
+        assert_eq!(size_class_at(500.0), SizeClass::Compact);
+        assert_eq!(size_class_at(605.0), SizeClass::Compact);
+        assert_eq!(size_class_at(610.0), SizeClass::Medium);
+        assert_eq!(size_class_at(595.0), SizeClass::Medium);
+        assert_eq!(size_class_at(590.0), SizeClass::Compact);
+        assert_eq!(size_class_at(1000.0), SizeClass::Expanded);
+        assert_eq!(size_class_at(300.0), SizeClass::Compact);
+    }
+
+    #[test]
+    fn test_bottom_sheet() {
+        use crate::{BottomSheet, RawInput, SheetDetent};
+
+        let ctx = Context::default();
+        let mut open = true;
+        let mut shown = None;
+        for _ in 0..30 {
+            let _ = ctx.run(RawInput::default(), |ctx| {
+                shown = BottomSheet::new("sheet")
+                    .show(ctx, &mut open, |ui| {
+                        ui.label("Contents");
+                    })
+                    .map(|sheet| (sheet.detent, sheet.response.rect));
+            });
+        }
+        let (detent, rect) = shown.unwrap();
+        assert_eq!(detent, SheetDetent::Half);
+        assert!((rect.bottom() - ctx.screen_rect().bottom()).abs() < 1.0);
+        assert!((rect.height() - 0.5 * ctx.screen_rect().height()).abs() < 1.0);
+
+        open = false;
+        for _ in 0..30 {
+            let _ = ctx.run(RawInput::default(), |ctx| {
+                shown = BottomSheet::new("sheet")
+                    .show(ctx, &mut open, |ui| {
+                        ui.label("Contents");
+                    })
+                    .map(|sheet| (sheet.detent, sheet.response.rect));
+            });
+        }
+        assert!(shown.is_none(), "The sheet should be closed");
+    }
+
+    #[test]
+    fn test_swipe_row() {
+        use crate::{
+            CentralPanel, Event, Modifiers, PointerButton, RawInput, SwipeAction, SwipeDirection,
+            SwipeRow, pos2,
+        };
+
+        let ctx = Context::default();
+
+        let mut triggered = None;
+        let mut num_threshold_calls = 0;
+        for frame in 0..20 {
+            let x = 300.0 - 20.0 * frame as f32;
+            let mut events = vec![Event::PointerMoved(pos2(x, 15.0))];
+            if frame == 1 || frame == 15 {
+                events.push(Event::PointerButton {
+                    pos: pos2(x, 15.0),
+                    button: PointerButton::Primary,
+                    pressed: frame == 1,
+                    modifiers: Modifiers::NONE,
+                });
+            }
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    let row = SwipeRow::new("row")
+                        .swipe_left(SwipeAction::new("Delete"))
+                        .on_threshold(|_, _| num_threshold_calls += 1)
+                        .show(ui, |ui| {
+                            ui.label("Swipe me");
+                        });
+                    if row.triggered.is_some() {
+                        triggered = row.triggered;
+                    }
+                });
+            });
+        }
+        assert_eq!(triggered, Some(SwipeDirection::Left));
+        assert_eq!(num_threshold_calls, 1);
+    }
+
+    #[test]
+    fn test_sound_hooks() {
+        use std::sync::{Arc, Mutex};
+
+        use crate::{
+            CentralPanel, Event, Modifiers, OutputCommand, PointerButton, RawInput, SoundEvent,
+            pos2, style::SoundHooks,
+        };
+
+        let ctx = Context::default();
+
+        let played = Arc::new(Mutex::new(vec![]));
+        ctx.all_styles_mut(|style| {
+            let played = played.clone();
+            style.interaction.sound_hooks = Some(SoundHooks::new(move |_ctx, event| {
+                played.lock().unwrap().push(event);
+            }));
+        });
+
+        let mut checked = false;
+        for frame in 0..4 {
+            let mut events = vec![Event::PointerMoved(pos2(15.0, 15.0))];
+            if frame == 1 || frame == 2 {
+                events.push(Event::PointerButton {
+                    pos: pos2(15.0, 15.0),
+                    button: PointerButton::Primary,
+                    pressed: frame == 1,
+                    modifiers: Modifiers::NONE,
+                });
+            }
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    ui.checkbox(&mut checked, "Check me");
+                    let _ = ui.button("Click me");
+                });
+            });
+        }
+        assert!(checked);
+        assert_eq!(*played.lock().unwrap(), vec![SoundEvent::Toggle]);
+
+        ctx.all_styles_mut(|style| style.interaction.sound_hooks = Some(SoundHooks::system_beep()));
+        let output = ctx.run(RawInput::default(), |ctx| {
+            ctx.play_sound(SoundEvent::Click);
+            ctx.play_sound(SoundEvent::Error);
+        });
+        assert_eq!(output.platform_output.commands, vec![OutputCommand::Beep]);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn test_overflow_warnings() {
+        use crate::{CentralPanel, Label, RawInput, ScrollArea, TextWrapMode};
+
+        let ctx = Context::default();
+        ctx.all_styles_mut(|style| style.debug.show_overflow = true);
+
+        let _ = ctx.run(RawInput::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                let long_text = "This text is much too long to fit ".repeat(10);
+
+                // Fits:
+                ui.label("Short");
+
+                // Truncated:
+                ui.scope(|ui| {
+                    ui.set_max_width(50.0);
+                    ui.add(Label::new(&long_text).wrap_mode(TextWrapMode::Truncate));
+                });
+
+                // Scrolled, not clipped:
+                ScrollArea::horizontal().show(ui, |ui| {
+                    ui.add(Label::new(&long_text).extend());
+                });
+            });
+        });
+
+        let reasons: Vec<&str> =
+            ctx.prev_pass_state(|fs| fs.overflows.iter().map(|o| o.reason).collect());
+        assert_eq!(reasons, vec!["truncated"]);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_spawn_async() {
+        let ctx = Context::default();
+        let mut task = ctx.spawn_async(async { 42 });
+
+        let start = std::time::Instant::now();
+        while task.ready().is_none() {
+            assert!(start.elapsed().as_secs() < 10, "The task never finished");
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        assert_eq!(task.ready(), Some(&42));
+
+        let mut panicking: crate::Task<()> = ctx.spawn_async(async { panic!("Oops") });
+        while !panicking.is_abandoned() {
+            assert!(start.elapsed().as_secs() < 10, "The task was never abandoned");
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn test_modal_viewport_blocks_owner() {
+        use crate::{
+            Event, Modifiers, PointerButton, RawInput, ViewportBuilder, ViewportCommand,
+            ViewportId, pos2,
+        };
+
+        let ctx = Context::default();
+        let modal_id = ViewportId::from_hash_of("modal");
+        let show_modal = |ctx: &Context| {
+            let builder = ViewportBuilder::default().with_modal_owner(ViewportId::ROOT);
+            ctx.show_viewport_deferred(modal_id, builder, |_ctx, _class| {});
+        };
+
+        let _ = ctx.run(RawInput::default(), show_modal);
+
+        let click = RawInput {
+            events: vec![Event::PointerButton {
+                pos: pos2(10.0, 10.0),
+                button: PointerButton::Primary,
+                pressed: true,
+                modifiers: Modifiers::NONE,
+            }],
+            ..Default::default()
+        };
+        let output = ctx.run(click.clone(), |ctx| {
+            assert!(!ctx.input(|i| i.pointer.any_pressed()));
+            show_modal(ctx);
+        });
+        let modal_commands = &output.viewport_output[&modal_id].commands;
+        assert!(modal_commands.contains(&ViewportCommand::Focus));
+
+        // Once the modal viewport is gone, the input is back:
+        let _ = ctx.run(RawInput::default(), |_ctx| {});
+        let _ = ctx.run(click, |ctx| {
+            assert!(ctx.input(|i| i.pointer.any_pressed()));
+        });
+    }
+
+    #[test]
+    fn test_shared_values() {
+        use std::sync::{
+            Arc, Mutex, RwLock,
+            atomic::{AtomicBool, AtomicU32, Ordering},
+        };
+
+        use crate::{CentralPanel, DragValue, RawInput, SharedValue as _, Slider};
+
+        let ctx = Context::default();
+        let volume = Arc::new(RwLock::new(0.5_f32));
+        let count = AtomicU32::new(3);
+        let muted = Arc::new(AtomicBool::new(false));
+        let name = Mutex::new(String::from("egui"));
+
+        let _ = ctx.run(RawInput::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                ui.add(Slider::from_shared(&volume, 0.0..=1.0));
+                ui.add(DragValue::from_shared(&count));
+                ui.edit_shared(&muted, |ui, muted| {
+                    *muted = true;
+                    ui.checkbox(muted, "Muted")
+                });
+                ui.edit_shared(&name, |_ui, name| name.push('!'));
+            });
+        });
+
+        assert_eq!(volume.get(), 0.5);
+        assert_eq!(count.load(Ordering::Relaxed), 3);
+        assert!(muted.get());
+        assert_eq!(name.get(), "egui!");
+    }
+
+    #[test]
+    fn test_max_repaint_rate() {
+        use crate::ViewportId;
+
+        let ctx = Context::default();
+        let status_id = ViewportId::from_hash_of("status");
+        assert_eq!(ctx.max_repaint_rate_of(status_id), f32::INFINITY);
+
+        ctx.set_max_repaint_rate_of(status_id, 2.0);
+        assert_eq!(ctx.max_repaint_rate_of(status_id), 2.0);
+        assert_eq!(ctx.max_repaint_rate_of(ViewportId::ROOT), f32::INFINITY);
+
+        ctx.set_max_repaint_rate_of(status_id, f32::INFINITY);
+        assert_eq!(ctx.max_repaint_rate_of(status_id), f32::INFINITY);
+    }
+
+    #[test]
+    fn test_screenshot_viewport() {
+        use std::sync::Arc;
+
+        use crate::{
+            ColorImage, Event, RawInput, UserData, ViewportBuilder, ViewportCommand, ViewportId,
+        };
+
+        let ctx = Context::default();
+        let child_id = ViewportId::from_hash_of("child");
+        let show_child = |ctx: &Context| {
+            ctx.show_viewport_deferred(child_id, ViewportBuilder::default(), |_ctx, _class| {});
+        };
+        let user_data = UserData::new("my screenshot");
+
+        let output = ctx.run(RawInput::default(), |ctx| {
+            show_child(ctx);
+            ctx.screenshot_viewport(child_id, user_data.clone());
+        });
+        let Some(ViewportCommand::Screenshot(forwarded_user_data)) =
+            output.viewport_output[&child_id].commands.first().cloned()
+        else {
+            panic!("Expected a screenshot command for the child viewport");
+        };
+
+        // The integration paints the child viewport, and gives it the screenshot:
+        let screenshot = Event::Screenshot {
+            viewport_id: child_id,
+            user_data: forwarded_user_data,
+            image: Arc::new(ColorImage::example()),
+        };
+        let child_input = RawInput {
+            viewport_id: child_id,
+            events: vec![screenshot],
+            ..Default::default()
+        };
+        let _ = ctx.run(child_input, |ctx| {
+            assert!(ctx.input(|i| i.events.is_empty()));
+        });
+        assert!(ctx.has_requested_repaint_for(&ViewportId::ROOT));
+
+        let _ = ctx.run(RawInput::default(), |ctx| {
+            show_child(ctx);
+            let events = ctx.input(|i| i.events.clone());
+            assert!(matches!(
+                events.as_slice(),
+                [Event::Screenshot { viewport_id, user_data: data, .. }]
+                    if *viewport_id == child_id && *data == user_data
+            ));
+        });
+    }
+
+    #[test]
+    fn test_mouse_hit_test() {
+        use crate::{
+            Color32, FullOutput, Id, Label, LayerId, MouseHitTest, RawInput, Rect, Ui, UiBuilder,
+            ViewportCommand, ViewportId, pos2, vec2,
+        };
+
+        let sent_regions = |output: &FullOutput| {
+            output.viewport_output[&ViewportId::ROOT]
+                .commands
+                .iter()
+                .find_map(|command| match command {
+                    ViewportCommand::MouseHitTestRegions(regions) => Some(regions.clone()),
+                    _ => None,
+                })
+        };
+
+        let ctx = Context::default();
+        let output = ctx.run(RawInput::default(), |_ctx| {});
+        assert_eq!(sent_regions(&output), None);
+
+        let button = Rect::from_min_size(pos2(10.0, 10.0), vec2(20.0, 20.0));
+        let output = ctx.run(RawInput::default(), |ctx| {
+            ctx.set_mouse_hit_test(MouseHitTest::Regions(vec![button]));
+        });
+        assert_eq!(sent_regions(&output), Some(Some(vec![button])));
+
+        // Only sent again when the regions change:
+        let output = ctx.run(RawInput::default(), |_ctx| {});
+        assert_eq!(sent_regions(&output), None);
+
+        let output = ctx.run(RawInput::default(), |ctx| {
+            ctx.set_mouse_hit_test(MouseHitTest::Painted);
+            ctx.layer_painter(LayerId::background()).rect_filled(button, 0.0, Color32::RED);
+        });
+        assert_eq!(sent_regions(&output), None, "The same region was painted");
+
+        let mut button_rect = Rect::NOTHING;
+        let output = ctx.run(RawInput::default(), |ctx| {
+            ctx.set_mouse_hit_test(MouseHitTest::Interactive);
+            let mut ui = Ui::new(ctx.clone(), Id::new("hud"), UiBuilder::new());
+            ui.add(Label::new("Not clickable").selectable(false));
+            button_rect = ui.button("Clickable").interact_rect;
+        });
+        assert_eq!(sent_regions(&output), Some(Some(vec![button_rect])));
+
+        let output = ctx.run(RawInput::default(), |ctx| {
+            ctx.set_mouse_hit_test(MouseHitTest::Everywhere);
+        });
+        assert_eq!(sent_regions(&output), Some(None));
+    }
+
+    #[test]
+    fn test_viewport_change_events() {
+        use crate::{RawInput, Rect, ViewportEvent, ViewportId, ViewportInfo, pos2, vec2};
+
+        let input = |inner_rect: Rect, focused: bool| {
+            let info = ViewportInfo {
+                inner_rect: Some(inner_rect),
+                outer_rect: Some(inner_rect.expand(4.0)),
+                focused: Some(focused),
+                ..Default::default()
+            };
+            RawInput {
+                viewports: std::iter::once((ViewportId::ROOT, info)).collect(),
+                ..Default::default()
+            }
+        };
+        let events = |ctx: &Context, raw_input: RawInput| {
+            let mut events = vec![];
+            let _ = ctx.run(raw_input, |ctx| {
+                events = ctx.input(|i| i.viewport().events.clone());
+            });
+            events
+        };
+
+        let ctx = Context::default();
+        let rect = Rect::from_min_size(pos2(100.0, 100.0), vec2(300.0, 200.0));
+        assert_eq!(events(&ctx, input(rect, true)), vec![], "Nothing to compare with");
+        assert_eq!(events(&ctx, input(rect, true)), vec![]);
+
+        let moved = rect.translate(vec2(10.0, 0.0));
+        assert_eq!(
+            events(&ctx, input(moved, false)),
+            vec![ViewportEvent::Moved(pos2(106.0, 96.0)), ViewportEvent::FocusLost]
+        );
+
+        let resized = Rect::from_min_size(moved.min, vec2(400.0, 200.0));
+        assert_eq!(
+            events(&ctx, input(resized, true)),
+            vec![ViewportEvent::Resized(vec2(400.0, 200.0)), ViewportEvent::FocusGained]
+        );
+
+        let with_fullscreen = |fullscreen: bool| {
+            let mut raw_input = input(resized, true);
+            raw_input.viewports.get_mut(&ViewportId::ROOT).unwrap().fullscreen = Some(fullscreen);
+            raw_input
+        };
+        assert_eq!(
+            events(&ctx, with_fullscreen(false)),
+            vec![],
+            "Unknown before, so no event"
+        );
+        assert_eq!(
+            events(&ctx, with_fullscreen(true)),
+            vec![ViewportEvent::FullscreenChanged(true)]
+        );
+    }
+
+    #[test]
+    fn test_monitors_changed() {
+        use crate::{Event, MonitorInfo, RawInput, Rect, pos2, vec2};
+
+        let monitor = |x: f32, width: f32| MonitorInfo {
+            rect: Rect::from_min_size(pos2(x, 0.0), vec2(width, 1080.0)),
+            ..Default::default()
+        };
+        let changes = |ctx: &Context, monitors: Vec<MonitorInfo>| {
+            let mut changes = vec![];
+            let raw_input = RawInput {
+                monitors,
+                ..Default::default()
+            };
+            let _ = ctx.run(raw_input, |ctx| {
+                ctx.input(|i| {
+                    for event in &i.events {
+                        if let Event::MonitorsChanged(monitors) = event {
+                            changes.push(monitors.clone());
+                        }
+                    }
+                });
+            });
+            changes
+        };
+
+        let ctx = Context::default();
+        let one = vec![monitor(0.0, 1920.0)];
+        assert!(changes(&ctx, one.clone()).is_empty(), "Nothing to compare with");
+        assert!(changes(&ctx, one.clone()).is_empty());
+
+        let two = vec![monitor(0.0, 1920.0), monitor(1920.0, 2560.0)];
+        assert_eq!(changes(&ctx, two.clone()), vec![two], "Connected");
+
+        let resized = vec![monitor(0.0, 1280.0), monitor(1280.0, 2560.0)];
+        assert_eq!(changes(&ctx, resized.clone()), vec![resized], "New resolution");
+
+        assert_eq!(changes(&ctx, one.clone()), vec![one], "Disconnected");
+        assert!(changes(&ctx, vec![]).is_empty(), "Unknown is not a change");
+    }
+
+    #[test]
+    fn test_render_to_image() {
+        use crate::{Color32, vec2};
+
+        let ctx = Context::default();
+        let _ = ctx.run(Default::default(), |ctx| {
+            let image = ctx.render_to_image(vec2(10.0, 5.0), 3.0, |ui| {
+                ui.painter().rect_filled(ui.max_rect(), 0.0, Color32::RED);
+            });
+            assert_eq!(image.size, [30, 15]);
+            assert_eq!(image.pixels[7 * 30 + 15], Color32::RED);
+        });
+    }
 }
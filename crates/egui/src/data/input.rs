@@ -27,6 +27,11 @@ pub struct RawInput {
     /// Information about all egui viewports.
     pub viewports: ViewportIdMap<ViewportInfo>,
 
+    /// All monitors connected to the computer, as far as the integration knows.
+    ///
+    /// Empty means "unknown".
+    pub monitors: Vec<MonitorInfo>,
+
     /// Position and size of the area that egui should use, in points.
     /// Usually you would set this to
     ///
@@ -63,6 +68,14 @@ pub struct RawInput {
     /// and/or the pointer (mouse/touch) with [`crate::Context::is_using_pointer`].
     pub events: Vec<Event>,
 
+    /// When each [`Event::PointerMoved`] in [`Self::events`] happened, and where to,
+    /// in the same time base as [`Self::time`].
+    ///
+    /// Integrations often get several pointer moves per frame, and can record when each one
+    /// arrived. Leave it empty if you don't know, and egui will estimate the times.
+    /// See [`crate::PointerState::samples`].
+    pub pointer_samples: Vec<(f64, Pos2)>,
+
     /// Dragged files hovering over egui.
     pub hovered_files: Vec<HoveredFile>,
 
@@ -81,6 +94,14 @@ pub struct RawInput {
     ///
     /// `None` means "don't know".
     pub system_theme: Option<Theme>,
+
+    /// Does the integration show [`crate::PlatformOutput::native_menu_bar`] in a native menu bar?
+    ///
+    /// If so, [`crate::MenuBar::show_menus`] hands its menus to the integration
+    /// instead of showing them with egui.
+    ///
+    /// `eframe` sets this on macOS.
+    pub native_menu_bar: bool,
 }
 
 impl Default for RawInput {
@@ -88,16 +109,19 @@ impl Default for RawInput {
         Self {
             viewport_id: ViewportId::ROOT,
             viewports: std::iter::once((ViewportId::ROOT, Default::default())).collect(),
+            monitors: Vec::new(),
             screen_rect: None,
             max_texture_side: None,
             time: None,
             predicted_dt: 1.0 / 60.0,
             modifiers: Modifiers::default(),
             events: vec![],
+            pointer_samples: vec![],
             hovered_files: Default::default(),
             dropped_files: Default::default(),
             focused: true, // integrations opt into global focus tracking
             system_theme: None,
+            native_menu_bar: false,
         }
     }
 }
@@ -111,6 +135,7 @@ impl RawInput {
 
     /// Helper: move volatile (deltas and events), clone the rest.
     ///
+    /// * [`Self::monitors`] is cloned.
     /// * [`Self::hovered_files`] is cloned.
     /// * [`Self::dropped_files`] is moved.
     pub fn take(&mut self) -> Self {
@@ -121,16 +146,19 @@ impl RawInput {
                 .iter_mut()
                 .map(|(id, info)| (*id, info.take()))
                 .collect(),
+            monitors: self.monitors.clone(),
             screen_rect: self.screen_rect.take(),
             max_texture_side: self.max_texture_side.take(),
             time: self.time,
             predicted_dt: self.predicted_dt,
             modifiers: self.modifiers,
             events: std::mem::take(&mut self.events),
+            pointer_samples: std::mem::take(&mut self.pointer_samples),
             hovered_files: self.hovered_files.clone(),
             dropped_files: std::mem::take(&mut self.dropped_files),
             focused: self.focused,
             system_theme: self.system_theme,
+            native_menu_bar: self.native_menu_bar,
         }
     }
 
@@ -139,34 +167,44 @@ impl RawInput {
         let Self {
             viewport_id: viewport_ids,
             viewports,
+            monitors,
             screen_rect,
             max_texture_side,
             time,
             predicted_dt,
             modifiers,
             mut events,
+            mut pointer_samples,
             mut hovered_files,
             mut dropped_files,
             focused,
             system_theme,
+            native_menu_bar,
         } = newer;
 
         self.viewport_id = viewport_ids;
         self.viewports = viewports;
+        self.monitors = monitors;
         self.screen_rect = screen_rect.or(self.screen_rect);
         self.max_texture_side = max_texture_side.or(self.max_texture_side);
         self.time = time; // use latest time
         self.predicted_dt = predicted_dt; // use latest dt
         self.modifiers = modifiers; // use latest
         self.events.append(&mut events);
+        self.pointer_samples.append(&mut pointer_samples);
         self.hovered_files.append(&mut hovered_files);
         self.dropped_files.append(&mut dropped_files);
         self.focused = focused;
         self.system_theme = system_theme;
+        self.native_menu_bar = native_menu_bar;
     }
 }
 
 /// An input event from the backend into egui, about a specific [viewport](crate::viewport).
+///
+/// The backend only needs to send [`Self::Close`].
+/// egui adds the others at the start of each pass, by comparing the [`ViewportInfo`]
+/// with the one from the previous pass.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum ViewportEvent {
@@ -181,6 +219,59 @@ pub enum ViewportEvent {
     ///
     /// This even will wake up both the child and parent viewport.
     Close,
+
+    /// The window was moved to this outer position, in ui points.
+    ///
+    /// See [`ViewportInfo::outer_rect`].
+    Moved(Pos2),
+
+    /// The window was resized to this inner size, in ui points.
+    ///
+    /// See [`ViewportInfo::inner_rect`].
+    Resized(Vec2),
+
+    /// The window is now on a monitor with a different size or scale factor.
+    ///
+    /// See [`ViewportInfo::monitor_size`] and [`ViewportInfo::native_pixels_per_point`].
+    MonitorChanged,
+
+    /// The window got keyboard focus.
+    FocusGained,
+
+    /// The window lost keyboard focus.
+    FocusLost,
+
+    /// The window was moved to another virtual desktop (workspace).
+    ///
+    /// See [`ViewportInfo::workspace`].
+    WorkspaceChanged,
+
+    /// The window entered (`true`) or left (`false`) fullscreen.
+    ///
+    /// See [`ViewportInfo::fullscreen`].
+    FullscreenChanged(bool),
+}
+
+/// A virtual desktop, also called a workspace.
+///
+/// How these are identified depends on the platform.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Workspace {
+    /// The workspace with this index, starting at zero.
+    ///
+    /// Used on X11 (`_NET_WM_DESKTOP`).
+    Index(u32),
+
+    /// The virtual desktop with this GUID.
+    ///
+    /// Used on Windows (`IVirtualDesktopManager`).
+    Guid(u128),
+
+    /// On all workspaces, following the user when they switch.
+    ///
+    /// Supported on X11.
+    All,
 }
 
 /// Information about the current viewport, given as input each frame.
@@ -198,6 +289,7 @@ pub struct ViewportInfo {
     /// Name of the viewport, if known.
     pub title: Option<String>,
 
+    /// What happened to the viewport since the last pass, e.g. it was moved or asked to close.
     pub events: Vec<ViewportEvent>,
 
     /// The OS native pixels-per-point.
@@ -244,6 +336,13 @@ pub struct ViewportInfo {
     ///
     /// This should be the same as [`RawInput::focused`].
     pub focused: Option<bool>,
+
+    /// The virtual desktop (workspace) the window is on.
+    ///
+    /// Move it with [`crate::ViewportCommand::MoveToWorkspace`].
+    ///
+    /// **`eframe` notes**: known on Windows and on X11.
+    pub workspace: Option<Workspace>,
 }
 
 impl ViewportInfo {
@@ -259,6 +358,53 @@ impl ViewportInfo {
         self.events.contains(&ViewportEvent::Close)
     }
 
+    /// Add the [`ViewportEvent`]s for what changed since the `previous` info.
+    ///
+    /// Nothing is added for things that are unknown before or after.
+    pub(crate) fn add_change_events(&mut self, previous: &Self) {
+        if let (Some(old), Some(new)) = (previous.outer_rect, self.outer_rect) {
+            if old.min != new.min {
+                self.events.push(ViewportEvent::Moved(new.min));
+            }
+        }
+
+        if let (Some(old), Some(new)) = (previous.inner_rect, self.inner_rect) {
+            if old.size() != new.size() {
+                self.events.push(ViewportEvent::Resized(new.size()));
+            }
+        }
+
+        let monitor_size_changed = matches!(
+            (previous.monitor_size, self.monitor_size),
+            (Some(old), Some(new)) if old != new
+        );
+        let scale_changed = matches!(
+            (previous.native_pixels_per_point, self.native_pixels_per_point),
+            (Some(old), Some(new)) if old != new
+        );
+        if monitor_size_changed || scale_changed {
+            self.events.push(ViewportEvent::MonitorChanged);
+        }
+
+        if let (Some(old), Some(new)) = (previous.workspace, self.workspace) {
+            if old != new {
+                self.events.push(ViewportEvent::WorkspaceChanged);
+            }
+        }
+
+        if let (Some(old), Some(new)) = (previous.fullscreen, self.fullscreen) {
+            if old != new {
+                self.events.push(ViewportEvent::FullscreenChanged(new));
+            }
+        }
+
+        match (previous.focused, self.focused) {
+            (Some(false), Some(true)) => self.events.push(ViewportEvent::FocusGained),
+            (Some(true), Some(false)) => self.events.push(ViewportEvent::FocusLost),
+            _ => {}
+        }
+    }
+
     /// Helper: move [`Self::events`], clone the other fields.
     pub fn take(&mut self) -> Self {
         Self {
@@ -273,6 +419,7 @@ impl ViewportInfo {
             maximized: self.maximized,
             fullscreen: self.fullscreen,
             focused: self.focused,
+            workspace: self.workspace,
         }
     }
 
@@ -289,6 +436,7 @@ impl ViewportInfo {
             maximized,
             fullscreen,
             focused,
+            workspace,
         } = self;
 
         crate::Grid::new("viewport_info").show(ui, |ui| {
@@ -336,6 +484,10 @@ impl ViewportInfo {
             ui.label(opt_as_str(focused));
             ui.end_row();
 
+            ui.label("Workspace:");
+            ui.label(opt_as_str(workspace));
+            ui.end_row();
+
             fn opt_rect_as_string(v: &Option<Rect>) -> String {
                 v.as_ref().map_or(String::new(), |r| {
                     format!("Pos: {:?}, size: {:?}", r.min, r.size())
@@ -349,6 +501,99 @@ impl ViewportInfo {
     }
 }
 
+/// Information about a monitor connected to the computer, given as input each frame.
+///
+/// The rectangles are in the same space as [`ViewportInfo::outer_rect`],
+/// i.e. in monitor space and ui points scale.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct MonitorInfo {
+    /// Name of the monitor, if known.
+    pub name: Option<String>,
+
+    /// The whole area of the monitor.
+    pub rect: Rect,
+
+    /// The part of the monitor that is not covered by task bars, docks, menu bars etc.
+    ///
+    /// `None` means "unknown", which is always the case with `egui-winit`.
+    pub work_rect: Option<Rect>,
+
+    /// The OS native pixels-per-point of this monitor, if known.
+    pub native_pixels_per_point: Option<f32>,
+
+    /// The video modes this monitor supports in exclusive fullscreen.
+    ///
+    /// Empty if unknown, which is always the case on web.
+    /// See [`crate::ViewportCommand::ExclusiveFullscreen`].
+    pub video_modes: Vec<VideoMode>,
+}
+
+impl MonitorInfo {
+    /// [`Self::work_rect`] if known, else [`Self::rect`].
+    #[inline]
+    pub fn usable_rect(&self) -> Rect {
+        self.work_rect.unwrap_or(self.rect)
+    }
+
+    pub fn ui(&self, ui: &mut crate::Ui) {
+        let Self {
+            name,
+            rect,
+            work_rect,
+            native_pixels_per_point,
+            video_modes,
+        } = self;
+
+        crate::Grid::new("monitor_info").show(ui, |ui| {
+            ui.label("Name:");
+            ui.label(name.as_deref().unwrap_or_default());
+            ui.end_row();
+
+            ui.label("Rect:");
+            ui.label(format!("Pos: {:?}, size: {:?}", rect.min, rect.size()));
+            ui.end_row();
+
+            ui.label("Work rect:");
+            ui.label(work_rect.map_or(String::new(), |r| {
+                format!("Pos: {:?}, size: {:?}", r.min, r.size())
+            }));
+            ui.end_row();
+
+            ui.label("Native pixels-per-point:");
+            ui.label(native_pixels_per_point.map_or(String::new(), |v| format!("{v:?}")));
+            ui.end_row();
+
+            ui.label("Video modes:");
+            ui.label(video_modes.len().to_string());
+            ui.end_row();
+        });
+    }
+}
+
+/// A resolution, color depth and refresh rate that a monitor supports.
+///
+/// See [`MonitorInfo::video_modes`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct VideoMode {
+    /// Width and height, in physical pixels.
+    pub size: [u32; 2],
+
+    /// Bits per pixel.
+    pub bit_depth: u16,
+
+    /// E.g. `60_000` for 60 Hz.
+    pub refresh_rate_millihertz: u32,
+}
+
+impl VideoMode {
+    #[inline]
+    pub fn refresh_rate_hz(&self) -> f32 {
+        self.refresh_rate_millihertz as f32 / 1000.0
+    }
+}
+
 /// A file about to be dropped into egui.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -543,6 +788,19 @@ pub enum Event {
 
         image: std::sync::Arc<ColorImage>,
     },
+
+    /// A button in the native menu bar was clicked.
+    ///
+    /// See [`crate::PlatformOutput::native_menu_bar`].
+    NativeMenuButton(crate::Id),
+
+    /// A monitor was connected or disconnected,
+    /// or changed its resolution, scale or place in the arrangement.
+    ///
+    /// Contains the new [`RawInput::monitors`].
+    /// egui sends this itself when the monitors given by the integration change,
+    /// so recompute anything derived from the monitor geometry when you see it.
+    MonitorsChanged(Vec<MonitorInfo>),
 }
 
 /// IME event.
@@ -1119,16 +1377,19 @@ impl RawInput {
         let Self {
             viewport_id,
             viewports,
+            monitors,
             screen_rect,
             max_texture_side,
             time,
             predicted_dt,
             modifiers,
             events,
+            pointer_samples,
             hovered_files,
             dropped_files,
             focused,
             system_theme,
+            native_menu_bar,
         } = self;
 
         ui.label(format!("Active viewport: {viewport_id:?}"));
@@ -1140,6 +1401,14 @@ impl RawInput {
                 });
             });
         }
+        for (i, monitor) in monitors.iter().enumerate() {
+            ui.group(|ui| {
+                ui.label(format!("Monitor {i}"));
+                ui.push_id(i, |ui| {
+                    monitor.ui(ui);
+                });
+            });
+        }
         ui.label(format!("screen_rect: {screen_rect:?} points"));
 
         ui.label(format!("max_texture_side: {max_texture_side:?}"));
@@ -1150,10 +1419,12 @@ impl RawInput {
         }
         ui.label(format!("predicted_dt: {:.1} ms", 1e3 * predicted_dt));
         ui.label(format!("modifiers: {modifiers:#?}"));
+        ui.label(format!("pointer_samples: {}", pointer_samples.len()));
         ui.label(format!("hovered_files: {}", hovered_files.len()));
         ui.label(format!("dropped_files: {}", dropped_files.len()));
         ui.label(format!("focused: {focused}"));
         ui.label(format!("system_theme: {system_theme:?}"));
+        ui.label(format!("native_menu_bar: {native_menu_bar}"));
         ui.scope(|ui| {
             ui.set_min_height(150.0);
             ui.label(format!("events: {events:#?}"))
@@ -3,7 +3,10 @@
 pub mod input;
 mod key;
 pub mod output;
+mod shared;
 mod user_data;
 
 pub use key::Key;
+pub use shared::SharedValue;
+pub(crate) use shared::get_set_numeric;
 pub use user_data::UserData;
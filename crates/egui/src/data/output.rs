@@ -1,6 +1,6 @@
 //! All the data egui returns to the backend at the end of each frame.
 
-use crate::{RepaintCause, ViewportIdMap, ViewportOutput, WidgetType};
+use crate::{LayerId, RepaintCause, ViewportIdMap, ViewportOutput, WidgetType};
 
 /// What egui emits each frame from [`crate::Context::run`].
 ///
@@ -33,6 +33,9 @@ pub struct FullOutput {
     /// It is up to the integration to spawn a native window for each viewport,
     /// and to close any window that no longer has a viewport in this map.
     pub viewport_output: ViewportIdMap<ViewportOutput>,
+
+    /// Statistics about this frame, e.g. for a performance overlay.
+    pub stats: FrameStats,
 }
 
 impl FullOutput {
@@ -44,10 +47,12 @@ impl FullOutput {
             shapes,
             pixels_per_point,
             viewport_output,
+            stats,
         } = newer;
 
         self.platform_output.append(platform_output);
         self.textures_delta.append(textures_delta);
+        self.stats.append(stats);
         self.shapes = shapes; // Only paint the latest
         self.pixels_per_point = pixels_per_point; // Use latest
 
@@ -64,6 +69,125 @@ impl FullOutput {
     }
 }
 
+/// Statistics about a frame, from [`FullOutput::stats`].
+///
+/// Integrations can show these in an overlay, or send them to a metrics system.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FrameStats {
+    /// How many shapes each layer painted, in no particular order.
+    pub layers: Vec<LayerStats>,
+
+    /// How many textures were created or replaced whole in [`FullOutput::textures_delta`].
+    pub textures_created: usize,
+
+    /// How many textures were partially updated, e.g. the font atlas with new glyphs.
+    pub textures_updated: usize,
+
+    /// How many bytes of image data there are to upload for the new and updated textures.
+    pub texture_upload_bytes: usize,
+
+    /// How many textures are to be freed.
+    pub textures_freed: usize,
+
+    /// The stats of the latest [`crate::Context::tessellate`].
+    ///
+    /// Integrations tessellate after [`crate::Context::run`] returns,
+    /// so this is usually of the previous frame.
+    pub tessellation: Option<TessellationStats>,
+}
+
+impl FrameStats {
+    /// The texture stats of these changes.
+    pub(crate) fn from_textures_delta(textures_delta: &epaint::textures::TexturesDelta) -> Self {
+        let mut stats = Self {
+            textures_freed: textures_delta.free.len(),
+            ..Default::default()
+        };
+        for (_, delta) in &textures_delta.set {
+            if delta.is_whole() {
+                stats.textures_created += 1;
+            } else {
+                stats.textures_updated += 1;
+            }
+            let [width, height] = delta.image.size();
+            stats.texture_upload_bytes += width * height * delta.image.bytes_per_pixel();
+        }
+        stats
+    }
+
+    /// Add on the stats of a newer pass.
+    pub fn append(&mut self, newer: Self) {
+        let Self {
+            layers,
+            textures_created,
+            textures_updated,
+            texture_upload_bytes,
+            textures_freed,
+            tessellation,
+        } = newer;
+
+        self.layers = layers; // Use latest
+        self.textures_created += textures_created;
+        self.textures_updated += textures_updated;
+        self.texture_upload_bytes += texture_upload_bytes;
+        self.textures_freed += textures_freed;
+        self.tessellation = tessellation.or(self.tessellation);
+    }
+
+    /// The total number of shapes painted.
+    pub fn num_shapes(&self) -> usize {
+        self.layers.iter().map(|layer| layer.num_shapes).sum()
+    }
+}
+
+/// What a layer painted. See [`FrameStats::layers`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LayerStats {
+    pub layer_id: LayerId,
+
+    /// The number of shapes, not counting those inside [`epaint::Shape::Vec`]s.
+    pub num_shapes: usize,
+}
+
+/// What [`crate::Context::tessellate`] produced. See [`FrameStats::tessellation`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TessellationStats {
+    /// The number of meshes and callbacks, i.e. roughly the number of draw calls.
+    pub num_primitives: usize,
+
+    /// The number of [`epaint::Primitive::Callback`]s among them.
+    pub num_callbacks: usize,
+
+    pub num_vertices: usize,
+
+    pub num_indices: usize,
+
+    /// How long tessellating took.
+    ///
+    /// `None` on the web, where there is no clock to measure it with.
+    pub seconds: Option<f32>,
+}
+
+impl TessellationStats {
+    pub(crate) fn new(primitives: &[epaint::ClippedPrimitive], seconds: Option<f32>) -> Self {
+        let mut stats = Self {
+            num_primitives: primitives.len(),
+            seconds,
+            ..Default::default()
+        };
+        for primitive in primitives {
+            match &primitive.primitive {
+                epaint::Primitive::Mesh(mesh) => {
+                    stats.num_vertices += mesh.vertices.len();
+                    stats.num_indices += mesh.indices.len();
+                }
+                epaint::Primitive::Callback(_) => stats.num_callbacks += 1,
+            }
+        }
+        stats
+    }
+}
+
 /// Information about text being edited.
 ///
 /// Useful for IME.
@@ -77,6 +201,11 @@ pub struct IMEOutput {
     ///
     /// This is a very thin rectangle.
     pub cursor_rect: crate::Rect,
+
+    /// The IME should show its candidate window next to this, usually below it.
+    ///
+    /// This is [`Self::rect`] unless changed with [`crate::TextEdit::ime_candidate_rect`].
+    pub candidate_rect: crate::Rect,
 }
 
 /// Commands that the egui integration should execute at the end of a frame.
@@ -93,8 +222,34 @@ pub enum OutputCommand {
     /// Put this image to the system clipboard.
     CopyImage(crate::ColorImage),
 
+    /// Put this rich text to the system clipboard, as HTML and as plain text.
+    ///
+    /// Apps that can paste HTML, like word processors and email clients, keep the formatting.
+    /// Others get the plain text.
+    CopyHtml {
+        /// The text with formatting, e.g. from [`epaint::text::LayoutJob::to_html`].
+        html: String,
+
+        /// The same text without formatting.
+        text: String,
+    },
+
     /// Open this url in a browser.
     OpenUrl(OpenUrl),
+
+    /// Read this text out loud using the text-to-speech engine of the platform.
+    ///
+    /// This is meant for kiosks and other setups where there is no screen reader.
+    /// If there is one, prefer describing your ui with AccessKit instead.
+    Speak(Speech),
+
+    /// Give the user a short haptic pulse, e.g. a vibration on a phone or gamepad.
+    ///
+    /// Ignored on platforms without haptics.
+    HapticFeedback(HapticKind),
+
+    /// Play the system alert sound.
+    Beep,
 }
 
 /// The non-rendering part of what egui emits each frame.
@@ -162,6 +317,14 @@ pub struct PlatformOutput {
     /// If empty, there was never any calls.
     #[cfg_attr(feature = "serde", serde(skip))]
     pub request_discard_reasons: Vec<RepaintCause>,
+
+    /// Show these menus in the native menu bar, replacing what was there before.
+    ///
+    /// Only set when the menus change, and only if [`crate::RawInput::native_menu_bar`] is set.
+    /// Clicks on the buttons are reported back with [`crate::Event::NativeMenuButton`].
+    ///
+    /// See [`crate::MenuBar::show_menus`].
+    pub native_menu_bar: Option<Vec<crate::containers::menu::NativeMenu>>,
 }
 
 impl PlatformOutput {
@@ -199,6 +362,7 @@ impl PlatformOutput {
             accesskit_update,
             num_completed_passes,
             mut request_discard_reasons,
+            native_menu_bar,
         } = newer;
 
         self.commands.append(&mut commands);
@@ -215,6 +379,9 @@ impl PlatformOutput {
         self.num_completed_passes += num_completed_passes;
         self.request_discard_reasons
             .append(&mut request_discard_reasons);
+        if native_menu_bar.is_some() {
+            self.native_menu_bar = native_menu_bar;
+        }
 
         #[cfg(feature = "accesskit")]
         {
@@ -269,6 +436,106 @@ impl OpenUrl {
     }
 }
 
+/// What text to read out loud, and how.
+///
+/// Use with [`crate::Context::speak`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Speech {
+    pub text: String,
+
+    /// How fast to speak, relative to the normal rate of the voice.
+    ///
+    /// `1.0` is the normal rate, `2.0` is twice as fast.
+    pub rate: f32,
+
+    /// Name of the voice to use, if available.
+    ///
+    /// If `None`, or if the voice is not available, the default voice is used.
+    pub voice: Option<String>,
+
+    /// If `true`, stop whatever is currently being spoken.
+    /// If `false`, the text is queued after it.
+    pub interrupt: bool,
+}
+
+// Like `Pos2`, we assume the rate is never NaN.
+impl Eq for Speech {}
+
+impl From<&str> for Speech {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<String> for Speech {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+impl Speech {
+    #[expect(clippy::needless_pass_by_value)]
+    pub fn new(text: impl ToString) -> Self {
+        Self {
+            text: text.to_string(),
+            rate: 1.0,
+            voice: None,
+            interrupt: true,
+        }
+    }
+
+    /// How fast to speak, relative to the normal rate. Default: `1.0`.
+    #[inline]
+    pub fn rate(mut self, rate: f32) -> Self {
+        self.rate = rate;
+        self
+    }
+
+    /// Name of the voice to use, if available.
+    #[inline]
+    pub fn voice(mut self, voice: impl Into<String>) -> Self {
+        self.voice = Some(voice.into());
+        self
+    }
+
+    /// Stop whatever is currently being spoken? Default: `true`.
+    #[inline]
+    pub fn interrupt(mut self, interrupt: bool) -> Self {
+        self.interrupt = interrupt;
+        self
+    }
+}
+
+/// What kind of haptic pulse to give, see [`crate::Context::haptic_feedback`].
+///
+/// The integration maps these to what the platform offers,
+/// so they may all feel the same on simple vibration motors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum HapticKind {
+    /// A very light tick, e.g. when a slider snaps to the next step.
+    Selection,
+
+    /// A light impact, e.g. when a dragged item passes a threshold.
+    Light,
+
+    /// A medium impact.
+    Medium,
+
+    /// A heavy impact, e.g. when something is deleted.
+    Heavy,
+
+    /// Something succeeded.
+    Success,
+
+    /// Something needs the attention of the user.
+    Warning,
+
+    /// Something failed.
+    Error,
+}
+
 /// Types of attention to request from a user when a native window is not in focus.
 ///
 /// See [winit's documentation][user_attention_type] for platform-specific meaning of the attention types.
@@ -497,6 +764,17 @@ impl OutputEvent {
             | Self::ValueChanged(info) => info,
         }
     }
+
+    pub fn interaction_kind(&self) -> InteractionKind {
+        match self {
+            Self::Clicked(_) => InteractionKind::Clicked,
+            Self::DoubleClicked(_) => InteractionKind::DoubleClicked,
+            Self::TripleClicked(_) => InteractionKind::TripleClicked,
+            Self::FocusGained(_) => InteractionKind::FocusGained,
+            Self::TextSelectionChanged(_) => InteractionKind::TextSelectionChanged,
+            Self::ValueChanged(_) => InteractionKind::ValueChanged,
+        }
+    }
 }
 
 impl std::fmt::Debug for OutputEvent {
@@ -512,6 +790,60 @@ impl std::fmt::Debug for OutputEvent {
     }
 }
 
+/// The kind of an [`InteractionEvent`]. Mirrors [`OutputEvent`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum InteractionKind {
+    /// A widget was clicked.
+    Clicked,
+
+    /// A widget was double-clicked.
+    DoubleClicked,
+
+    /// A widget was triple-clicked.
+    TripleClicked,
+
+    /// A widget gained keyboard focus.
+    FocusGained,
+
+    /// Text selection was updated.
+    TextSelectionChanged,
+
+    /// A widget's value changed.
+    ValueChanged,
+}
+
+/// A structured record of a user interacting with a widget.
+///
+/// Meant for UX analytics and usage heatmaps.
+/// It deliberately contains no widget text, only a hash of the label.
+///
+/// See [`crate::Context::on_interaction`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct InteractionEvent {
+    /// The [`crate::Id`] of the widget.
+    pub id: crate::Id,
+
+    /// The type of widget.
+    pub widget_type: WidgetType,
+
+    /// A hash of [`WidgetInfo::label`], if the widget has a label.
+    ///
+    /// Identical labels give identical hashes,
+    /// so this can be used to tell widgets apart in aggregated logs.
+    pub label_hash: Option<u64>,
+
+    /// What happened.
+    pub kind: InteractionKind,
+
+    /// Where the widget was, in screen coordinates.
+    pub rect: crate::Rect,
+
+    /// When it happened, in seconds. See [`crate::InputState::time`].
+    pub time: f64,
+}
+
 /// Describes a widget such as a [`crate::Button`] or a [`crate::TextEdit`].
 #[derive(Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -0,0 +1,148 @@
+//! Letting widgets edit values that are shared with other threads.
+
+use std::sync::{
+    Arc, Mutex, PoisonError, RwLock,
+    atomic::{self, Ordering},
+};
+
+/// A value shared with other threads, which widgets can read and write directly.
+///
+/// The lock is only held while copying the value in or out,
+/// never while the widget is shown.
+///
+/// This is implemented for [`Mutex`] and [`RwLock`] (both the `std` ones and those in
+/// [`crate::mutex`]), for the atomics, and for any [`Arc`] of those.
+/// You can implement it for your own types, e.g. a `tokio::sync::watch::Sender`.
+///
+/// Use it with [`crate::Slider::from_shared`], [`crate::DragValue::from_shared`],
+/// or [`crate::Ui::edit_shared`] for any other widget.
+/// Note that egui is not repainted when another thread changes the value,
+/// so call [`crate::Context::request_repaint`] when you do.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use std::sync::{Arc, RwLock, atomic::AtomicBool};
+///
+/// let volume = Arc::new(RwLock::new(0.5_f32));
+/// let muted = Arc::new(AtomicBool::new(false));
+///
+/// ui.add(egui::Slider::from_shared(&volume, 0.0..=1.0).text("Volume"));
+/// ui.edit_shared(&muted, |ui, muted| ui.checkbox(muted, "Muted"));
+/// # });
+/// ```
+pub trait SharedValue<T> {
+    /// A copy of the current value.
+    fn get(&self) -> T;
+
+    /// Replace the value.
+    fn set(&self, value: T);
+}
+
+impl<T, S: SharedValue<T> + ?Sized> SharedValue<T> for Arc<S> {
+    #[inline]
+    fn get(&self) -> T {
+        (**self).get()
+    }
+
+    #[inline]
+    fn set(&self, value: T) {
+        (**self).set(value);
+    }
+}
+
+impl<T: Clone> SharedValue<T> for Mutex<T> {
+    fn get(&self) -> T {
+        self.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    fn set(&self, value: T) {
+        *self.lock().unwrap_or_else(PoisonError::into_inner) = value;
+    }
+}
+
+impl<T: Clone> SharedValue<T> for RwLock<T> {
+    fn get(&self) -> T {
+        self.read().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    fn set(&self, value: T) {
+        *self.write().unwrap_or_else(PoisonError::into_inner) = value;
+    }
+}
+
+impl<T: Clone> SharedValue<T> for crate::mutex::Mutex<T> {
+    fn get(&self) -> T {
+        self.lock().clone()
+    }
+
+    fn set(&self, value: T) {
+        *self.lock() = value;
+    }
+}
+
+impl<T: Clone> SharedValue<T> for crate::mutex::RwLock<T> {
+    fn get(&self) -> T {
+        self.read().clone()
+    }
+
+    fn set(&self, value: T) {
+        *self.write() = value;
+    }
+}
+
+macro_rules! impl_shared_value_for_atomic {
+    ($($atomic:ty => $value:ty),* $(,)?) => {
+        $(
+            impl SharedValue<$value> for $atomic {
+                #[inline]
+                fn get(&self) -> $value {
+                    self.load(Ordering::Relaxed)
+                }
+
+                #[inline]
+                fn set(&self, value: $value) {
+                    self.store(value, Ordering::Relaxed);
+                }
+            }
+        )*
+    };
+}
+
+impl_shared_value_for_atomic!(
+    atomic::AtomicBool => bool,
+    atomic::AtomicI8 => i8,
+    atomic::AtomicI16 => i16,
+    atomic::AtomicI32 => i32,
+    atomic::AtomicIsize => isize,
+    atomic::AtomicU8 => u8,
+    atomic::AtomicU16 => u16,
+    atomic::AtomicU32 => u32,
+    atomic::AtomicUsize => usize,
+);
+
+#[cfg(target_has_atomic = "64")]
+impl_shared_value_for_atomic!(
+    atomic::AtomicI64 => i64,
+    atomic::AtomicU64 => u64,
+);
+
+/// For [`crate::Slider::from_shared`] and [`crate::DragValue::from_shared`].
+///
+/// Only writes to the shared value if it actually changed.
+pub(crate) fn get_set_numeric<Num: emath::Numeric>(
+    shared: &impl SharedValue<Num>,
+) -> impl FnMut(Option<f64>) -> f64 + '_ {
+    move |new_value: Option<f64>| {
+        let current = shared.get();
+        match new_value {
+            Some(new_value) => {
+                let new_value = Num::from_f64(new_value);
+                if new_value != current {
+                    shared.set(new_value);
+                }
+                new_value.to_f64()
+            }
+            None => current.to_f64(),
+        }
+    }
+}
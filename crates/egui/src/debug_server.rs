@@ -0,0 +1,346 @@
+//! Inspect an egui app from another machine, e.g. an app running on a phone or an embedded device.
+//!
+//! [`start`] listens on a TCP port.
+//! Each viewer that connects is sent the [`Style`], followed by a [`PassReport`]
+//! with the widgets and frame timings after each pass.
+//! Viewers can send [`DebugCommand`]s to change the style live.
+//!
+//! Each message is one line of [RON](https://github.com/ron-rs/ron),
+//! so you can try it with e.g. `nc localhost 4321`.
+//!
+//! This is a plugin (see [`crate::debug_text`]),
+//! so it only does anything while the app runs passes.
+//! There is no authentication, so only use it on a network you trust.
+
+use std::{
+    io::{ErrorKind, Read as _, Write as _},
+    net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    sync::Arc,
+};
+
+use epaint::mutex::Mutex;
+
+use crate::{Context, Id, LayerId, Rect, Style, ViewportId, WidgetInfo};
+
+/// Don't queue up more than this for a viewer that can't keep up.
+const MAX_QUEUED_BYTES: usize = 16 * 1024 * 1024;
+
+/// Start the debug server, listening on `addr`, e.g. `"0.0.0.0:4321"`.
+///
+/// Returns the address it listens on, which is useful if you pass port zero.
+/// Starting it again replaces the previous server, and disconnects its viewers.
+///
+/// ```no_run
+/// # let ctx = egui::Context::default();
+/// let addr = egui::debug_server::start(&ctx, "0.0.0.0:4321").expect("Failed to start");
+/// println!("Inspect me at {addr}");
+/// ```
+pub fn start(ctx: &Context, addr: impl ToSocketAddrs) -> std::io::Result<SocketAddr> {
+    let listener = TcpListener::bind(addr)?;
+    listener.set_nonblocking(true)?;
+    let local_addr = listener.local_addr()?;
+
+    let server = Server {
+        listener,
+        viewers: vec![],
+    };
+    if let Some(slot) = Server::slot(ctx) {
+        *slot.lock() = Some(server);
+    } else {
+        let slot = Arc::new(Mutex::new(Some(server)));
+        ctx.data_mut(|data| data.insert_temp(Server::id(), slot));
+        ctx.on_end_pass("debug_server", Arc::new(Server::end_pass));
+    }
+
+    Ok(local_addr)
+}
+
+/// Stop the debug server, and disconnect all viewers.
+pub fn stop(ctx: &Context) {
+    if let Some(slot) = Server::slot(ctx) {
+        *slot.lock() = None;
+    }
+}
+
+/// What the server sends to the viewers.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub enum DebugMessage {
+    /// The current style.
+    ///
+    /// Sent when a viewer connects, and after each [`DebugCommand::SetStyle`].
+    Style(Box<Style>),
+
+    /// Sent after each pass of each viewport.
+    ///
+    /// If a viewer can't keep up, it is sent only some of them.
+    Pass(PassReport),
+
+    /// A [`DebugCommand`] could not be read.
+    Error(String),
+}
+
+/// What viewers can send to the server.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub enum DebugCommand {
+    /// Use this style from now on.
+    SetStyle(Box<Style>),
+}
+
+/// What happened in a pass.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PassReport {
+    pub viewport_id: ViewportId,
+
+    /// See [`Context::cumulative_pass_nr`].
+    pub pass_nr: u64,
+
+    /// See [`crate::InputState::time`].
+    pub time: f64,
+
+    /// See [`Context::smoothed_frame_time`].
+    pub frame_time: Option<f32>,
+
+    /// The layers with widgets, in no particular order.
+    pub layers: Vec<LayerReport>,
+}
+
+/// The widgets of a layer.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct LayerReport {
+    pub layer_id: LayerId,
+
+    /// The widgets, in painting order.
+    pub widgets: Vec<WidgetReport>,
+}
+
+/// One widget of a [`LayerReport`].
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct WidgetReport {
+    pub id: Id,
+
+    /// Where the widget is, in layer coordinates.
+    pub rect: Rect,
+
+    pub enabled: bool,
+
+    /// Does the widget sense clicks, drags or focus?
+    pub interactive: bool,
+
+    /// What kind of widget it is, and its label.
+    ///
+    /// Only known for widgets that were interacted with, unless the app is a debug build.
+    pub info: Option<WidgetInfo>,
+}
+
+impl PassReport {
+    fn new(ctx: &Context) -> Self {
+        let layers = ctx.viewport(|viewport| {
+            let widgets = &viewport.this_pass.widgets;
+            widgets
+                .layers()
+                .map(|(&layer_id, rects)| LayerReport {
+                    layer_id,
+                    widgets: rects
+                        .iter()
+                        .map(|w| WidgetReport {
+                            id: w.id,
+                            rect: w.rect,
+                            enabled: w.enabled,
+                            interactive: w.sense.interactive(),
+                            info: widgets.info(w.id).cloned(),
+                        })
+                        .collect(),
+                })
+                .collect()
+        });
+
+        Self {
+            viewport_id: ctx.viewport_id(),
+            pass_nr: ctx.cumulative_pass_nr(),
+            time: ctx.input(|i| i.time),
+            frame_time: ctx.smoothed_frame_time(),
+            layers,
+        }
+    }
+}
+
+struct Server {
+    listener: TcpListener,
+    viewers: Vec<Viewer>,
+}
+
+struct Viewer {
+    stream: TcpStream,
+
+    /// What we have read, up to the end of the last full line.
+    incoming: Vec<u8>,
+
+    /// What we have yet to write.
+    outgoing: Vec<u8>,
+}
+
+impl Viewer {
+    fn send(&mut self, message: &DebugMessage) {
+        if let Ok(line) = ron::to_string(message) {
+            self.outgoing.extend_from_slice(line.as_bytes());
+            self.outgoing.push(b'\n');
+        }
+    }
+
+    /// Read the commands sent so far.
+    ///
+    /// Returns `Err` if the viewer disconnected.
+    fn receive(&mut self) -> std::io::Result<Vec<Result<DebugCommand, String>>> {
+        let mut buf = [0; 4096];
+        loop {
+            match self.stream.read(&mut buf) {
+                Ok(0) => return Err(ErrorKind::UnexpectedEof.into()),
+                Ok(n) => self.incoming.extend_from_slice(&buf[..n]),
+                Err(err) if err.kind() == ErrorKind::WouldBlock => break,
+                Err(err) if err.kind() == ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        let Some(end) = self.incoming.iter().rposition(|&b| b == b'\n') else {
+            return Ok(vec![]);
+        };
+        let lines: Vec<u8> = self.incoming.drain(..=end).collect();
+        Ok(String::from_utf8_lossy(&lines)
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| ron::from_str(line).map_err(|err| err.to_string()))
+            .collect())
+    }
+
+    /// Write as much as we can without blocking.
+    ///
+    /// Returns `Err` if the viewer disconnected.
+    fn flush(&mut self) -> std::io::Result<()> {
+        while !self.outgoing.is_empty() {
+            match self.stream.write(&self.outgoing) {
+                Ok(0) => return Err(ErrorKind::WriteZero.into()),
+                Ok(n) => {
+                    self.outgoing.drain(..n);
+                }
+                Err(err) if err.kind() == ErrorKind::WouldBlock => break,
+                Err(err) if err.kind() == ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+        if MAX_QUEUED_BYTES < self.outgoing.len() {
+            return Err(ErrorKind::OutOfMemory.into());
+        }
+        Ok(())
+    }
+}
+
+impl Server {
+    fn id() -> Id {
+        Id::new("debug_server")
+    }
+
+    /// Where the running server is kept, once one has been started.
+    fn slot(ctx: &Context) -> Option<Arc<Mutex<Option<Self>>>> {
+        ctx.data(|data| data.get_temp(Self::id()))
+    }
+
+    fn end_pass(ctx: &Context) {
+        if let Some(slot) = Self::slot(ctx) {
+            if let Some(server) = &mut *slot.lock() {
+                server.update(ctx);
+            }
+        }
+    }
+
+    fn update(&mut self, ctx: &Context) {
+        while let Ok((stream, _)) = self.listener.accept() {
+            if stream.set_nonblocking(true).is_err() {
+                continue;
+            }
+            stream.set_nodelay(true).ok();
+            let mut viewer = Viewer {
+                stream,
+                incoming: vec![],
+                outgoing: vec![],
+            };
+            viewer.send(&DebugMessage::Style(Box::new((*ctx.style()).clone())));
+            self.viewers.push(viewer);
+        }
+        if self.viewers.is_empty() {
+            return;
+        }
+
+        let mut new_style = None;
+        self.viewers.retain_mut(|viewer| {
+            let Ok(commands) = viewer.receive() else {
+                return false;
+            };
+            for command in commands {
+                match command {
+                    Ok(DebugCommand::SetStyle(style)) => new_style = Some(style),
+                    Err(err) => viewer.send(&DebugMessage::Error(err)),
+                }
+            }
+            true
+        });
+        if let Some(style) = new_style {
+            ctx.set_style(*style.clone());
+            ctx.request_repaint();
+            for viewer in &mut self.viewers {
+                viewer.send(&DebugMessage::Style(style.clone()));
+            }
+        }
+
+        let report = DebugMessage::Pass(PassReport::new(ctx));
+        self.viewers.retain_mut(|viewer| {
+            // Skip passes rather than falling further and further behind:
+            if viewer.outgoing.is_empty() {
+                viewer.send(&report);
+            }
+            viewer.flush().is_ok()
+        });
+    }
+}
+
+#[test]
+fn test_debug_server() {
+    use std::io::BufRead as _;
+
+    let ctx = Context::default();
+    let addr = start(&ctx, "127.0.0.1:0").unwrap();
+    let stream = TcpStream::connect(addr).unwrap();
+    stream.set_read_timeout(Some(std::time::Duration::from_secs(5))).unwrap();
+    let mut reader = std::io::BufReader::new(stream.try_clone().unwrap());
+    let mut read_message = || {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        ron::from_str::<DebugMessage>(&line).unwrap()
+    };
+
+    let _ = ctx.run(Default::default(), |ctx| {
+        crate::CentralPanel::default().show(ctx, |ui| {
+            ui.button("Click me");
+        });
+    });
+    assert!(matches!(read_message(), DebugMessage::Style(_)));
+    let DebugMessage::Pass(report) = read_message() else {
+        panic!("Expected a pass report");
+    };
+    assert!(report.layers.iter().any(|layer| !layer.widgets.is_empty()));
+
+    let mut style = (*ctx.style()).clone();
+    style.spacing.item_spacing.x = 42.0;
+    let command = ron::to_string(&DebugCommand::SetStyle(Box::new(style))).unwrap();
+    (&stream).write_all(format!("{command}\n").as_bytes()).unwrap();
+
+    // Wait for the command to arrive:
+    for _ in 0..100 {
+        let _ = ctx.run(Default::default(), |_| {});
+        if ctx.style().spacing.item_spacing.x == 42.0 {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    assert_eq!(ctx.style().spacing.item_spacing.x, 42.0);
+}
@@ -31,6 +31,10 @@ pub mod kb_shortcuts {
 /// By default, [`crate::Context`] calls this function at the end of each frame,
 /// controllable by [`crate::Options::zoom_with_keyboard`].
 pub(crate) fn zoom_with_keyboard(ctx: &Context) {
+    ctx.register_shortcut("View", "Zoom in", kb_shortcuts::ZOOM_IN);
+    ctx.register_shortcut("View", "Zoom out", kb_shortcuts::ZOOM_OUT);
+    ctx.register_shortcut("View", "Reset zoom", kb_shortcuts::ZOOM_RESET);
+
     if ctx.input_mut(|i| i.consume_shortcut(&kb_shortcuts::ZOOM_RESET)) {
         ctx.set_zoom_factor(1.0);
     } else {
@@ -5,7 +5,7 @@ use crate::data::input::{
     PointerButton, RawInput, TouchDeviceId, ViewportInfo,
 };
 use crate::{
-    emath::{NumExt as _, Pos2, Rect, Vec2, vec2},
+    emath::{NumExt as _, Pos2, Rect, Vec2, Vec2b, lerp, vec2},
     util::History,
 };
 use std::{
@@ -20,6 +20,7 @@ use touch_state::TouchState;
 /// Options for input state handling.
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct InputOptions {
     /// Multiplier for the scroll speed when reported in [`crate::MouseWheelUnit::Line`]s.
     pub line_scroll_speed: f32,
@@ -58,6 +59,25 @@ pub struct InputOptions {
     /// and when combined with [`Self::zoom_modifier`] it will result in zooming
     /// on only the vertical axis.
     pub vertical_scroll_modifier: Modifiers,
+
+    /// Invert the scroll direction of the mouse wheel and trackpad, per axis.
+    ///
+    /// Does not affect zooming.
+    pub invert_scroll: Vec2b,
+
+    /// If `true`, vertical wheel movements scroll horizontally, and vice versa.
+    ///
+    /// This is applied before [`Self::horizontal_scroll_modifier`] and
+    /// [`Self::vertical_scroll_modifier`].
+    /// Does not affect zooming.
+    pub swap_scroll_axes: bool,
+
+    /// Makes fast wheel or trackpad movements scroll further than slow ones.
+    ///
+    /// Each scroll delta is multiplied by `1.0 + scroll_acceleration * delta.length() / 100.0`,
+    /// with the delta in points.
+    /// Zero means no acceleration, i.e. a linear mapping.
+    pub scroll_acceleration: f32,
 }
 
 impl Default for InputOptions {
@@ -79,11 +99,31 @@ impl Default for InputOptions {
             zoom_modifier: Modifiers::COMMAND,
             horizontal_scroll_modifier: Modifiers::SHIFT,
             vertical_scroll_modifier: Modifiers::ALT,
+            invert_scroll: Vec2b::FALSE,
+            swap_scroll_axes: false,
+            scroll_acceleration: 0.0,
         }
     }
 }
 
 impl InputOptions {
+    /// Apply [`Self::scroll_acceleration`], [`Self::swap_scroll_axes`] and [`Self::invert_scroll`]
+    /// to a scroll delta (in points).
+    pub fn map_scroll_delta(&self, mut delta: Vec2) -> Vec2 {
+        if 0.0 < self.scroll_acceleration {
+            delta *= 1.0 + self.scroll_acceleration * delta.length() / 100.0;
+        }
+        if self.swap_scroll_axes {
+            delta = vec2(delta.y, delta.x);
+        }
+        for d in 0..2 {
+            if self.invert_scroll[d] {
+                delta[d] = -delta[d];
+            }
+        }
+        delta
+    }
+
     /// Show the options in the ui.
     pub fn ui(&mut self, ui: &mut crate::Ui) {
         let Self {
@@ -95,6 +135,9 @@ impl InputOptions {
             zoom_modifier,
             horizontal_scroll_modifier,
             vertical_scroll_modifier,
+            invert_scroll,
+            swap_scroll_axes,
+            scroll_acceleration,
         } = self;
         crate::Grid::new("InputOptions")
             .num_columns(2)
@@ -155,6 +198,27 @@ impl InputOptions {
                 vertical_scroll_modifier.ui(ui);
                 ui.end_row();
 
+                ui.label("Invert scroll");
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut invert_scroll.x, "x");
+                    ui.checkbox(&mut invert_scroll.y, "y");
+                });
+                ui.end_row();
+
+                ui.label("Swap scroll axes");
+                ui.checkbox(swap_scroll_axes, "")
+                    .on_hover_text("Scroll horizontally with the mouse wheel, and vice versa");
+                ui.end_row();
+
+                ui.label("Scroll acceleration");
+                ui.add(
+                    crate::DragValue::new(scroll_acceleration)
+                        .range(0.0..=f32::INFINITY)
+                        .speed(0.01),
+                )
+                .on_hover_text("Make fast scrolling go further than slow scrolling");
+                ui.end_row();
+
             });
     }
 }
@@ -342,6 +406,14 @@ impl InputState {
     ) -> Self {
         profiling::function_scope!();
 
+        // An empty list means the integration doesn't know about monitors (this frame):
+        if !new.monitors.is_empty()
+            && !self.raw.monitors.is_empty()
+            && new.monitors != self.raw.monitors
+        {
+            new.events.push(Event::MonitorsChanged(new.monitors.clone()));
+        }
+
         let time = new.time.unwrap_or(self.time + new.predicted_dt as f64);
         let unstable_dt = (time - self.time) as f32;
 
@@ -395,6 +467,12 @@ impl InputState {
                         MouseWheelUnit::Page => screen_rect.height() * *delta,
                     };
 
+                    let is_zoom = modifiers.matches_any(options.zoom_modifier);
+
+                    if !is_zoom {
+                        delta = options.map_scroll_delta(delta);
+                    }
+
                     let is_horizontal = modifiers.matches_any(options.horizontal_scroll_modifier);
                     let is_vertical = modifiers.matches_any(options.vertical_scroll_modifier);
 
@@ -420,8 +498,6 @@ impl InputState {
                         MouseWheelUnit::Line | MouseWheelUnit::Page => false,
                     };
 
-                    let is_zoom = modifiers.matches_any(options.zoom_modifier);
-
                     #[expect(clippy::collapsible_else_if)]
                     if is_zoom {
                         if is_smooth {
@@ -492,6 +568,12 @@ impl InputState {
             self.last_scroll_time
         };
 
+        for (viewport_id, info) in &mut new.viewports {
+            if let Some(previous) = self.raw.viewports.get(viewport_id) {
+                info.add_change_events(previous);
+            }
+        }
+
         Self {
             pointer,
             touch_states: self.touch_states,
@@ -525,6 +607,20 @@ impl InputState {
         self.raw.viewport()
     }
 
+    /// All monitors connected to the computer, as reported by the integration.
+    ///
+    /// Empty if the integration doesn't know.
+    /// See [`crate::RawInput::monitors`].
+    #[inline]
+    pub fn monitors(&self) -> &[crate::MonitorInfo] {
+        &self.raw.monitors
+    }
+
+    /// The monitor containing the given position, in the same space as [`ViewportInfo::outer_rect`].
+    pub fn monitor_at(&self, pos: Pos2) -> Option<&crate::MonitorInfo> {
+        self.monitors().iter().find(|monitor| monitor.rect.contains(pos))
+    }
+
     #[inline(always)]
     pub fn screen_rect(&self) -> Rect {
         self.screen_rect
@@ -962,6 +1058,9 @@ pub struct PointerState {
     /// Used for calculating velocity of pointer.
     pos_history: History<Pos2>,
 
+    /// See [`Self::samples`].
+    samples: Vec<(f64, Pos2)>,
+
     down: [bool; NUM_POINTER_BUTTONS],
 
     /// Where did the current click/drag originate?
@@ -1013,6 +1112,7 @@ impl Default for PointerState {
             velocity: Vec2::ZERO,
             direction: Vec2::ZERO,
             pos_history: History::new(2..1000, 0.1),
+            samples: vec![],
             down: Default::default(),
             press_origin: None,
             press_start_time: None,
@@ -1032,10 +1132,12 @@ impl PointerState {
     pub(crate) fn begin_pass(mut self, time: f64, new: &RawInput, options: InputOptions) -> Self {
         let was_decidedly_dragging = self.is_decidedly_dragging();
 
+        let prev_time = self.time;
         self.time = time;
         self.options = options;
 
         self.pointer_events.clear();
+        self.samples.clear();
 
         let old_pos = self.latest_pos;
         self.interact_pos = self.latest_pos;
@@ -1059,6 +1161,7 @@ impl PointerState {
 
                     self.last_move_time = time;
                     self.pointer_events.push(PointerEvent::Moved(pos));
+                    self.samples.push((time, pos));
                 }
                 Event::PointerButton {
                     pos,
@@ -1141,6 +1244,16 @@ impl PointerState {
             }
         }
 
+        if !new.pointer_samples.is_empty() {
+            self.samples.clone_from(&new.pointer_samples);
+        } else if prev_time.is_finite() {
+            // We don't know when the moves happened, so spread them out since the last pass:
+            let num_samples = self.samples.len();
+            for (i, (sample_time, _)) in self.samples.iter_mut().enumerate() {
+                *sample_time = lerp(prev_time..=time, (i + 1) as f64 / num_samples as f64);
+            }
+        }
+
         self.delta = if let (Some(old_pos), Some(new_pos)) = (old_pos, self.latest_pos) {
             new_pos - old_pos
         } else {
@@ -1203,6 +1316,19 @@ impl PointerState {
         self.velocity
     }
 
+    /// Every position the pointer moved to since the last pass, with the time of each.
+    ///
+    /// Integrations often get several moves per frame, e.g. from a pen or a fast mouse.
+    /// Use these instead of [`Self::latest_pos`] for freehand drawing,
+    /// together with [`crate::emath::smoothing`] to smooth them.
+    ///
+    /// The times are from [`crate::RawInput::pointer_samples`] if the integration
+    /// provides them, otherwise they are spread out evenly between the last pass and this one.
+    #[inline(always)]
+    pub fn samples(&self) -> &[(f64, Pos2)] {
+        &self.samples
+    }
+
     /// Current direction of the pointer.
     ///
     /// This is less sensitive to bad framerate than [`Self::velocity`].
@@ -1576,6 +1702,7 @@ impl PointerState {
             velocity,
             direction,
             pos_history: _,
+            samples,
             down,
             press_origin,
             press_start_time,
@@ -1597,6 +1724,7 @@ impl PointerState {
             velocity.x, velocity.y
         ));
         ui.label(format!("direction: {direction:?}"));
+        ui.label(format!("samples: {}", samples.len()));
         ui.label(format!("down: {down:#?}"));
         ui.label(format!("press_origin: {press_origin:?}"));
         ui.label(format!("press_start_time: {press_start_time:?} s"));
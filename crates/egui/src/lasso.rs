@@ -0,0 +1,333 @@
+use emath::{
+    Pos2, Rect,
+    geometry::{polygon_contains, segment_intersects_rect},
+};
+use epaint::{PathShape, Shape, Stroke, StrokeKind};
+
+use crate::{Color32, Id, Key, LayerId, Order, PointerButton, Response, Ui};
+
+/// How a [`Lasso`] selects.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum LassoMode {
+    /// A rectangle ("marquee" or "rubber band") spanned by the drag start and the pointer.
+    #[default]
+    Rect,
+
+    /// A freeform polygon following the pointer.
+    Freeform,
+}
+
+/// The area selected by a [`Lasso`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum LassoSelection {
+    /// See [`LassoMode::Rect`].
+    Rect(Rect),
+
+    /// See [`LassoMode::Freeform`]. The polygon is implicitly closed.
+    Polygon(Vec<Pos2>),
+}
+
+impl LassoSelection {
+    /// The smallest rectangle containing the whole selection.
+    pub fn bounding_rect(&self) -> Rect {
+        match self {
+            Self::Rect(rect) => *rect,
+            Self::Polygon(points) => Rect::from_points(points),
+        }
+    }
+
+    /// Is the point inside the selection?
+    pub fn contains(&self, pos: Pos2) -> bool {
+        match self {
+            Self::Rect(rect) => rect.contains(pos),
+            Self::Polygon(points) => polygon_contains(points, pos),
+        }
+    }
+
+    /// Is the whole rectangle inside the selection?
+    pub fn contains_rect(&self, rect: Rect) -> bool {
+        match self {
+            Self::Rect(selection) => selection.contains_rect(rect),
+            Self::Polygon(points) => {
+                rect_corners(rect)
+                    .iter()
+                    .all(|&p| polygon_contains(points, p))
+                    && !polygon_edges(points).any(|(a, b)| segment_intersects_rect(a, b, rect))
+            }
+        }
+    }
+
+    /// Does the rectangle overlap the selection at all?
+    pub fn intersects_rect(&self, rect: Rect) -> bool {
+        match self {
+            Self::Rect(selection) => selection.intersects(rect),
+            Self::Polygon(points) => {
+                rect_corners(rect)
+                    .iter()
+                    .any(|&p| polygon_contains(points, p))
+                    || polygon_edges(points).any(|(a, b)| segment_intersects_rect(a, b, rect))
+            }
+        }
+    }
+
+    /// Is the visual bounding rectangle of the shape fully inside the selection?
+    ///
+    /// Shapes that paint nothing are never contained.
+    pub fn contains_shape(&self, shape: &Shape) -> bool {
+        let rect = shape.visual_bounding_rect();
+        rect.is_finite() && self.contains_rect(rect)
+    }
+}
+
+/// What happened with a [`Lasso`] this frame.
+#[derive(Clone, Debug, Default)]
+pub struct LassoResponse {
+    /// The current selection.
+    ///
+    /// Set while the user is dragging (for live highlighting),
+    /// and on the frame the drag ends.
+    pub selection: Option<LassoSelection>,
+
+    /// The user released the pointer this frame, so [`Self::selection`] is final.
+    pub finished: bool,
+}
+
+impl LassoResponse {
+    /// Is a selection currently being dragged out?
+    #[inline]
+    pub fn in_progress(&self) -> bool {
+        self.selection.is_some() && !self.finished
+    }
+
+    /// The final selection, only on the frame the user released the pointer.
+    #[inline]
+    pub fn finished_selection(&self) -> Option<&LassoSelection> {
+        self.selection.as_ref().filter(|_| self.finished)
+    }
+}
+
+#[derive(Clone, Default)]
+struct LassoState {
+    points: Vec<Pos2>,
+
+    /// Escape was pressed during this drag.
+    cancelled: bool,
+}
+
+/// A rubber-band (marquee) or freeform lasso selection interaction.
+///
+/// Tracks a primary-button drag on a [`Response`], paints the selection on a foreground layer
+/// so it shows above the content, and reports the selected area.
+/// Pressing Escape cancels the selection.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let item_rects: Vec<egui::Rect> = vec![];
+/// let response = ui.allocate_rect(ui.max_rect(), egui::Sense::drag());
+/// let lasso = egui::Lasso::new("lasso")
+///     .mode(egui::LassoMode::Freeform)
+///     .show(ui, &response);
+/// if let Some(selection) = lasso.finished_selection() {
+///     let selected: Vec<usize> = (0..item_rects.len())
+///         .filter(|&i| selection.intersects_rect(item_rects[i]))
+///         .collect();
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Lasso {
+    id_salt: Id,
+    mode: LassoMode,
+    button: PointerButton,
+    stroke: Option<Stroke>,
+    fill: Option<Color32>,
+}
+
+impl Lasso {
+    /// The `id_salt` must be unique among sibling widgets.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            mode: LassoMode::default(),
+            button: PointerButton::Primary,
+            stroke: None,
+            fill: None,
+        }
+    }
+
+    /// Rectangle or freeform selection.
+    ///
+    /// Default: [`LassoMode::Rect`].
+    #[inline]
+    pub fn mode(mut self, mode: LassoMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Which pointer button drags out the selection.
+    ///
+    /// Default: [`PointerButton::Primary`].
+    #[inline]
+    pub fn button(mut self, button: PointerButton) -> Self {
+        self.button = button;
+        self
+    }
+
+    /// The outline of the selection.
+    ///
+    /// Default: the selection stroke of the current [`crate::Visuals`].
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = Some(stroke.into());
+        self
+    }
+
+    /// The fill of the selection.
+    ///
+    /// Default: a translucent version of the selection background color.
+    #[inline]
+    pub fn fill(mut self, fill: impl Into<Color32>) -> Self {
+        self.fill = Some(fill.into());
+        self
+    }
+
+    /// Track the drag on `response` and paint the selection.
+    ///
+    /// The `response` should sense drags, e.g. the background of a canvas.
+    pub fn show(self, ui: &Ui, response: &Response) -> LassoResponse {
+        let Self {
+            id_salt,
+            mode,
+            button,
+            stroke,
+            fill,
+        } = self;
+
+        let id = ui.make_persistent_id(id_salt);
+
+        let mut state: LassoState = if response.drag_started_by(button) {
+            // Start where the pointer was pressed, not where the drag was detected:
+            LassoState {
+                points: ui.input(|i| i.pointer.press_origin()).into_iter().collect(),
+                cancelled: false,
+            }
+        } else {
+            ui.data_mut(|d| d.get_temp(id)).unwrap_or_default()
+        };
+
+        let dragging = response.dragged_by(button);
+        let finished = response.drag_stopped_by(button);
+
+        if !dragging && !finished {
+            ui.data_mut(|d| d.remove::<LassoState>(id));
+            return LassoResponse::default();
+        }
+
+        if ui.input(|i| i.key_pressed(Key::Escape)) {
+            state.cancelled = true;
+        }
+        if state.cancelled {
+            ui.data_mut(|d| d.insert_temp(id, state));
+            return LassoResponse::default();
+        }
+
+        if let Some(pos) = response.interact_pointer_pos() {
+            match mode {
+                LassoMode::Rect => {
+                    state.points.truncate(1);
+                    state.points.push(pos);
+                }
+                LassoMode::Freeform => {
+                    if state
+                        .points
+                        .last()
+                        .is_none_or(|last| last.distance(pos) >= 2.0)
+                    {
+                        state.points.push(pos);
+                    }
+                }
+            }
+        }
+
+        let selection = match mode {
+            LassoMode::Rect => match state.points.as_slice() {
+                [start, .., end] => Some(LassoSelection::Rect(Rect::from_two_pos(*start, *end))),
+                _ => None,
+            },
+            LassoMode::Freeform => {
+                (state.points.len() >= 3).then(|| LassoSelection::Polygon(state.points.clone()))
+            }
+        };
+
+        if finished {
+            ui.data_mut(|d| d.remove::<LassoState>(id));
+        } else {
+            ui.data_mut(|d| d.insert_temp(id, state));
+
+            if let Some(selection) = &selection {
+                let visuals = &ui.visuals().selection;
+                let stroke = stroke.unwrap_or(visuals.stroke);
+                let fill = fill.unwrap_or_else(|| visuals.bg_fill.gamma_multiply(0.25));
+                let painter = ui
+                    .ctx()
+                    .layer_painter(LayerId::new(Order::Foreground, id))
+                    .with_clip_rect(response.rect);
+                match selection {
+                    LassoSelection::Rect(rect) => {
+                        painter.rect(*rect, 0.0, fill, stroke, StrokeKind::Inside);
+                    }
+                    LassoSelection::Polygon(points) => {
+                        // A lasso is rarely convex, so we can't fill it without artifacts:
+                        painter.add(PathShape::closed_line(points.clone(), stroke));
+                    }
+                }
+            }
+        }
+
+        LassoResponse {
+            selection,
+            finished,
+        }
+    }
+}
+
+fn rect_corners(rect: Rect) -> [Pos2; 4] {
+    [
+        rect.left_top(),
+        rect.right_top(),
+        rect.right_bottom(),
+        rect.left_bottom(),
+    ]
+}
+
+/// All edges of the implicitly closed polygon.
+fn polygon_edges(points: &[Pos2]) -> impl Iterator<Item = (Pos2, Pos2)> + '_ {
+    points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(&a, &b)| (a, b))
+}
+
+#[test]
+fn lasso_selection() {
+    use emath::pos2;
+
+    let triangle =
+        LassoSelection::Polygon(vec![pos2(0.0, 0.0), pos2(100.0, 0.0), pos2(0.0, 100.0)]);
+    assert!(triangle.contains(pos2(10.0, 10.0)));
+    assert!(!triangle.contains(pos2(90.0, 90.0)));
+
+    let inside = Rect::from_min_max(pos2(5.0, 5.0), pos2(20.0, 20.0));
+    let straddling = Rect::from_min_max(pos2(40.0, 40.0), pos2(60.0, 60.0));
+    let outside = Rect::from_min_max(pos2(80.0, 80.0), pos2(90.0, 90.0));
+    assert!(triangle.contains_rect(inside));
+    assert!(!triangle.contains_rect(straddling));
+    assert!(triangle.intersects_rect(straddling));
+    assert!(!triangle.intersects_rect(outside));
+
+    let marquee = LassoSelection::Rect(Rect::from_min_max(pos2(0.0, 0.0), pos2(50.0, 50.0)));
+    assert!(marquee.contains_rect(inside));
+    assert!(marquee.intersects_rect(straddling));
+    assert!(!marquee.contains_rect(straddling));
+}
@@ -1,7 +1,7 @@
 //! Handles paint layers, i.e. how things
 //! are sometimes painted behind or in front of other things.
 
-use crate::{Id, IdMap, Rect, ahash, epaint};
+use crate::{Color32, Id, IdMap, Rect, ahash, epaint};
 use epaint::{ClippedShape, Shape, emath::TSTransform};
 
 /// Different layer categories
@@ -109,6 +109,39 @@ impl std::fmt::Debug for LayerId {
     }
 }
 
+/// Opacity and tint of a whole layer, applied when the layer is composited at the end of the frame.
+///
+/// Set with [`crate::Context::set_layer_opacity`] and [`crate::Context::set_layer_tint`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LayerTint {
+    /// Multiplied with the alpha of everything in the layer, in the range [0, 1].
+    pub opacity: f32,
+
+    /// Multiplied with the color of everything in the layer.
+    pub tint: Color32,
+}
+
+impl Default for LayerTint {
+    fn default() -> Self {
+        Self {
+            opacity: 1.0,
+            tint: Color32::WHITE,
+        }
+    }
+}
+
+impl LayerTint {
+    /// Does this leave the layer unchanged?
+    pub fn is_identity(&self) -> bool {
+        self.color_multiplier() == Color32::WHITE
+    }
+
+    /// The opacity and tint combined into one (premultiplied) color multiplier.
+    pub fn color_multiplier(&self) -> Color32 {
+        self.tint.gamma_multiply(self.opacity)
+    }
+}
+
 /// A unique identifier of a specific [`Shape`] in a [`PaintList`].
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -189,6 +222,19 @@ impl PaintList {
         }
     }
 
+    /// Multiply the color of each [`Shape`] with this, in-place.
+    ///
+    /// [`Shape::Callback`] can not be tinted.
+    pub fn tint(&mut self, color_multiplier: Color32) {
+        for ClippedShape { shape, .. } in &mut self.0 {
+            epaint::shape_transform::adjust_colors(shape, move |color| {
+                if *color != Color32::PLACEHOLDER {
+                    *color = *color * color_multiplier;
+                }
+            });
+        }
+    }
+
     /// Read-only access to all held shapes.
     pub fn all_entries(&self) -> impl ExactSizeIterator<Item = &ClippedShape> {
         self.0.iter()
@@ -217,22 +263,59 @@ impl GraphicLayers {
         self.0[layer_id.order as usize].get_mut(&layer_id.id)
     }
 
+    /// How many shapes each non-empty layer has, in no particular order.
+    pub fn layer_stats(&self) -> Vec<crate::LayerStats> {
+        Order::ALL
+            .iter()
+            .zip(&self.0)
+            .flat_map(|(&order, lists)| {
+                lists
+                    .iter()
+                    .filter(|(_, list)| !list.is_empty())
+                    .map(move |(&id, list)| crate::LayerStats {
+                        layer_id: LayerId::new(order, id),
+                        num_shapes: list.0.len(),
+                    })
+            })
+            .collect()
+    }
+
     pub fn drain(
         &mut self,
         area_order: &[LayerId],
         to_global: &ahash::HashMap<LayerId, TSTransform>,
+        layer_tints: &ahash::HashMap<LayerId, LayerTint>,
     ) -> Vec<ClippedShape> {
-        profiling::function_scope!();
-
-        let mut all_shapes: Vec<_> = Default::default();
+        let mut all_shapes = Vec::new();
+        self.drain_into(area_order, to_global, layer_tints, &mut all_shapes);
+        all_shapes
+    }
 
-        for &order in &Order::ALL {
-            let order_map = &mut self.0[order as usize];
+    /// Like [`Self::drain`], but appends to an existing buffer.
+    ///
+    /// Pass in an empty buffer from a previous frame to avoid reallocating it.
+    pub fn drain_into(
+        &mut self,
+        area_order: &[LayerId],
+        to_global: &ahash::HashMap<LayerId, TSTransform>,
+        layer_tints: &ahash::HashMap<LayerId, LayerTint>,
+        all_shapes: &mut Vec<ClippedShape>,
+    ) {
+        profiling::function_scope!();
 
+        for order_map in &mut self.0 {
             // If a layer is empty at the start of the frame
             // then nobody has added to it, and it is old and defunct.
             // Free it to save memory:
             order_map.retain(|_, list| !list.is_empty());
+        }
+
+        // Reserve it all up-front, instead of growing it bit by bit:
+        let num_shapes = self.0.iter().flat_map(|map| map.values()).map(|list| list.0.len());
+        all_shapes.reserve(num_shapes.sum());
+
+        for &order in &Order::ALL {
+            let order_map = &mut self.0[order as usize];
 
             // First do the layers part of area_order:
             for layer_id in area_order {
@@ -244,6 +327,9 @@ impl GraphicLayers {
                                 clipped_shape.shape.transform(*to_global);
                             }
                         }
+                        if let Some(layer_tint) = layer_tints.get(layer_id) {
+                            list.tint(layer_tint.color_multiplier());
+                        }
                         all_shapes.append(&mut list.0);
                     }
                 }
@@ -260,10 +346,12 @@ impl GraphicLayers {
                     }
                 }
 
+                if let Some(layer_tint) = layer_tints.get(&layer_id) {
+                    list.tint(layer_tint.color_multiplier());
+                }
+
                 all_shapes.append(&mut list.0);
             }
         }
-
-        all_shapes
     }
 }
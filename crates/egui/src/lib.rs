@@ -410,6 +410,8 @@ pub mod cache;
 pub mod containers;
 mod context;
 mod data;
+#[cfg(feature = "debug_server")]
+pub mod debug_server;
 pub mod debug_text;
 mod drag_and_drop;
 pub(crate) mod grid;
@@ -419,6 +421,7 @@ mod id;
 mod input_state;
 mod interaction;
 pub mod introspection;
+mod lasso;
 pub mod layers;
 mod layout;
 pub mod load;
@@ -428,11 +431,18 @@ pub mod menu;
 pub mod os;
 mod painter;
 mod pass_state;
+mod print;
 pub(crate) mod placer;
 pub mod response;
+mod screen_recorder;
 mod sense;
+pub mod shortcuts;
+mod snapping;
 pub mod style;
+pub mod task;
 pub mod text_selection;
+mod time_travel;
+mod transform_gizmo;
 mod ui;
 mod ui_builder;
 mod ui_stack;
@@ -459,9 +469,11 @@ pub use epaint::emath;
 
 #[cfg(feature = "color-hex")]
 pub use ecolor::hex_color;
+#[cfg(feature = "derive")]
+pub use egui_derive::EguiInspect;
 pub use ecolor::{Color32, Rgba};
 pub use emath::{
-    Align, Align2, NumExt, Pos2, Rangef, Rect, RectAlign, Vec2, Vec2b, lerp, pos2, remap,
+    Affine2, Align, Align2, NumExt, Pos2, Rangef, Rect, RectAlign, Vec2, Vec2b, lerp, pos2, remap,
     remap_clamp, vec2,
 };
 pub use epaint::{
@@ -482,13 +494,17 @@ pub mod text {
 pub use self::{
     atomics::*,
     containers::{menu::MenuBar, *},
-    context::{Context, RepaintCause, RequestRepaintInfo},
+    context::{
+        Context, DecoratorPhase, InteractionCallback, RepaintCause, RequestRepaintInfo,
+        WidgetDecorator,
+    },
     data::{
-        Key, UserData,
+        Key, SharedValue, UserData,
         input::*,
         output::{
-            self, CursorIcon, FullOutput, OpenUrl, OutputCommand, PlatformOutput,
-            UserAttentionType, WidgetInfo,
+            self, CursorIcon, FrameStats, FullOutput, HapticKind, InteractionEvent,
+            InteractionKind, LayerStats, OpenUrl, OutputCommand, PlatformOutput, Speech,
+            TessellationStats, UserAttentionType, WidgetInfo,
         },
     },
     drag_and_drop::DragAndDrop,
@@ -496,15 +512,26 @@ pub use self::{
     grid::Grid,
     id::{Id, IdMap},
     input_state::{InputOptions, InputState, MultiTouchInfo, PointerState},
-    layers::{LayerId, Order},
+    lasso::{Lasso, LassoMode, LassoResponse, LassoSelection},
+    layers::{LayerId, LayerTint, Order},
     layout::*,
     load::SizeHint,
-    memory::{Memory, Options, Theme, ThemePreference},
+    memory::{
+        LayoutSnapshot, Memory, Options, PanelLayout, Theme, ThemePreference, ViewportLayout,
+        WindowLayout,
+    },
     painter::Painter,
+    print::{Print, PrintPage, PrintedPages},
     response::{InnerResponse, Response},
+    screen_recorder::{RecordedFrame, ScreenRecorder},
     sense::Sense,
-    style::{FontSelection, Spacing, Style, TextStyle, Visuals},
+    shortcuts::RegisteredShortcut,
+    snapping::{Snapped, Snapping},
+    style::{FontSelection, SizeClass, SoundEvent, Spacing, Style, TextStyle, Visuals},
+    task::Task,
     text::{Galley, TextFormat},
+    time_travel::{ContextSnapshot, TimeTravel},
+    transform_gizmo::{GizmoHandle, TransformGizmo, TransformGizmoResponse},
     ui::Ui,
     ui_builder::UiBuilder,
     ui_stack::*,
@@ -0,0 +1,189 @@
+use crate::{
+    Align2, Context, Id, LayerId, Order, Pos2, Rect, Vec2, ViewportCommand, ViewportId, area,
+    collapsing_header::CollapsingState, panel::PanelState, resize,
+};
+
+/// The placement of all windows and panels (and optionally viewports), as captured by
+/// [`Context::save_layout`].
+///
+/// Give it back to [`Context::restore_layout`] to put everything back where it was.
+/// With the `serde` feature you can serialize it,
+/// e.g. to let the user pick between named workspace layouts.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct LayoutSnapshot {
+    /// All [`crate::Window`]s and other [`crate::Area`]s in [`Order::Middle`],
+    /// ordered from bottom to top.
+    pub windows: Vec<WindowLayout>,
+
+    /// All panels that have been shown so far.
+    pub panels: Vec<PanelLayout>,
+
+    /// The native viewports.
+    ///
+    /// Clear this if you only want to restore the layout within your viewports,
+    /// and not move or resize the viewports themselves.
+    pub viewports: Vec<ViewportLayout>,
+}
+
+/// Placement of a [`crate::Window`], part of a [`LayoutSnapshot`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct WindowLayout {
+    /// The [`Id`] of the window area.
+    pub id: Id,
+
+    /// Position of the [`Self::pivot`] of the window.
+    pub pivot_pos: Pos2,
+
+    /// Where on the window [`Self::pivot_pos`] refers to.
+    pub pivot: Align2,
+
+    /// The size of the window contents that the user picked by resizing the window.
+    ///
+    /// `None` for windows that have never been resizable.
+    pub size: Option<Vec2>,
+
+    /// Was the window collapsed to just its title bar?
+    pub collapsed: bool,
+}
+
+/// Placement of a panel, part of a [`LayoutSnapshot`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PanelLayout {
+    /// The [`Id`] of the panel.
+    pub id: Id,
+
+    /// The rectangle the panel covered.
+    pub rect: Rect,
+}
+
+/// Placement of a native viewport, part of a [`LayoutSnapshot`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ViewportLayout {
+    /// Which viewport.
+    pub id: ViewportId,
+
+    /// Position of the top-left corner of the viewport, including the window decorations.
+    pub outer_pos: Option<Pos2>,
+
+    /// Size of the viewport, excluding the window decorations.
+    pub inner_size: Option<Vec2>,
+
+    /// Was the viewport maximized?
+    pub maximized: Option<bool>,
+}
+
+impl LayoutSnapshot {
+    pub(crate) fn save(ctx: &Context) -> Self {
+        let areas: Vec<(LayerId, area::AreaState)> = ctx.memory(|mem| {
+            let areas = mem.areas();
+            areas
+                .order()
+                .iter()
+                .filter(|layer| layer.order == Order::Middle && !areas.is_sublayer(layer))
+                .filter_map(|&layer| Some((layer, *areas.get(layer.id)?)))
+                .collect()
+        });
+
+        let windows = areas
+            .into_iter()
+            .filter_map(|(layer, state)| {
+                Some(WindowLayout {
+                    id: layer.id,
+                    pivot_pos: state.pivot_pos?,
+                    pivot: state.pivot,
+                    size: resize::State::load(ctx, layer.id.with("resize"))
+                        .map(|state| state.desired_size),
+                    collapsed: CollapsingState::load(ctx, layer.id.with("collapsing"))
+                        .is_some_and(|state| !state.is_open()),
+                })
+            })
+            .collect();
+
+        let panel_ids: Vec<Id> = ctx.memory(|mem| mem.panel_ids.iter().copied().collect());
+        let mut panels: Vec<PanelLayout> = panel_ids
+            .into_iter()
+            .filter_map(|id| {
+                let state = PanelState::load(ctx, id)?;
+                Some(PanelLayout {
+                    id,
+                    rect: state.rect,
+                })
+            })
+            .collect();
+        panels.sort_by_key(|panel| panel.id.value()); // Deterministic output
+
+        let mut viewports: Vec<ViewportLayout> = ctx.input(|i| {
+            i.raw
+                .viewports
+                .iter()
+                .map(|(&id, info)| ViewportLayout {
+                    id,
+                    outer_pos: info.outer_rect.map(|rect| rect.min),
+                    inner_size: info.inner_rect.map(|rect| rect.size()),
+                    maximized: info.maximized,
+                })
+                .collect()
+        });
+        viewports.sort_by_key(|viewport| viewport.id.0.value()); // Deterministic output
+
+        Self {
+            windows,
+            panels,
+            viewports,
+        }
+    }
+
+    pub(crate) fn restore(&self, ctx: &Context) {
+        let Self {
+            windows,
+            panels,
+            viewports,
+        } = self;
+
+        for window in windows {
+            let layer_id = LayerId::new(Order::Middle, window.id);
+            ctx.memory_mut(|mem| {
+                let areas = mem.areas_mut();
+                let mut state = areas.get(window.id).copied().unwrap_or_default();
+                state.pivot_pos = Some(window.pivot_pos);
+                state.pivot = window.pivot;
+                areas.set_state(layer_id, state);
+                areas.move_to_top(layer_id); // in the saved order
+            });
+
+            if let Some(size) = window.size {
+                resize::State::request_size(ctx, window.id.with("resize"), size);
+            }
+
+            let mut collapsing =
+                CollapsingState::load_with_default_open(ctx, window.id.with("collapsing"), true);
+            collapsing.set_open(!window.collapsed);
+            collapsing.store(ctx);
+        }
+
+        for panel in panels {
+            PanelState { rect: panel.rect }.store(ctx, panel.id);
+        }
+
+        for viewport in viewports {
+            if let Some(maximized) = viewport.maximized {
+                ctx.send_viewport_cmd_to(viewport.id, ViewportCommand::Maximized(maximized));
+            }
+            if viewport.maximized != Some(true) {
+                if let Some(pos) = viewport.outer_pos {
+                    ctx.send_viewport_cmd_to(viewport.id, ViewportCommand::OuterPosition(pos));
+                }
+                if let Some(size) = viewport.inner_size {
+                    ctx.send_viewport_cmd_to(viewport.id, ViewportCommand::InnerSize(size));
+                }
+            }
+        }
+
+        ctx.request_repaint();
+    }
+}
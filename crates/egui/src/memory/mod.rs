@@ -10,7 +10,9 @@ use crate::{
     ViewportIdMap, ViewportIdSet, area, vec2,
 };
 
+mod layout_snapshot;
 mod theme;
+pub use layout_snapshot::{LayoutSnapshot, PanelLayout, ViewportLayout, WindowLayout};
 pub use theme::{Theme, ThemePreference};
 
 // ----------------------------------------------------------------------------
@@ -98,10 +100,22 @@ pub struct Memory {
     /// * [`crate::Context::layer_transform_from_global`]
     pub to_global: HashMap<LayerId, TSTransform>,
 
+    /// Opacity and tint per layer.
+    ///
+    /// Instead of using this directly, use:
+    /// * [`crate::Context::set_layer_opacity`]
+    /// * [`crate::Context::set_layer_tint`]
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub layer_tints: HashMap<LayerId, crate::layers::LayerTint>,
+
     // -------------------------------------------------
     // Per-viewport:
     areas: ViewportIdMap<Areas>,
 
+    /// The panels that have been shown so far, so we can include them in a [`LayoutSnapshot`].
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) panel_ids: crate::id::IdSet,
+
     #[cfg_attr(feature = "persistence", serde(skip))]
     pub(crate) interactions: ViewportIdMap<InteractionState>,
 
@@ -129,7 +143,9 @@ impl Default for Memory {
             focus: Default::default(),
             viewport_id: Default::default(),
             areas: Default::default(),
+            panel_ids: Default::default(),
             to_global: Default::default(),
+            layer_tints: Default::default(),
             popups: Default::default(),
             everything_is_visible: Default::default(),
             add_fonts: Default::default(),
@@ -233,6 +249,14 @@ pub struct Options {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub zoom_with_keyboard: bool,
 
+    /// While this key is held down, show a cheatsheet of all keyboard shortcuts
+    /// registered this pass with [`crate::Context::register_shortcut`].
+    ///
+    /// You can also show the cheatsheet with [`crate::Context::set_shortcut_cheatsheet_open`].
+    ///
+    /// Default: `Some(Key::F1)`.
+    pub shortcut_cheatsheet_key: Option<crate::Key>,
+
     /// Controls the tessellator.
     pub tessellation_options: epaint::TessellationOptions,
 
@@ -299,6 +323,60 @@ pub struct Options {
     ///
     /// Default is `false`.
     pub reduce_texture_memory: bool,
+
+    /// Keep up to this many laid out texts cached, even if they were not used in the last pass.
+    ///
+    /// This helps with large tables of repeated labels that scroll in and out of view.
+    /// Texts used in the current pass are always cached, regardless of this limit.
+    ///
+    /// See [`epaint::Fonts::set_max_cached_galleys`] and [`epaint::Fonts::galley_cache_stats`].
+    ///
+    /// Default is `0`.
+    pub max_cached_galleys: usize,
+
+    /// How long a frame may take to compute, in seconds, before egui degrades gracefully.
+    ///
+    /// The frame time is reported by the integration with [`crate::Context::report_frame_time`].
+    /// When the smoothed frame time exceeds this budget,
+    /// [`crate::Context::is_degraded`] becomes `true`.
+    /// egui then skips shadows, turns off feathering and stops animating,
+    /// and apps can shed work of their own (see [`crate::Context::on_degradation_changed`]).
+    ///
+    /// Default is `None`, meaning there is no budget and egui never degrades.
+    pub frame_time_budget: Option<f32>,
+
+    /// Make each pass depend only on its input, and not on the wall clock,
+    /// so that snapshot tests and replays of recorded input give the same result every time.
+    ///
+    /// Time then advances by exactly [`Self::DETERMINISTIC_TIME_STEP`] each pass,
+    /// ignoring [`crate::RawInput::time`] and [`crate::RawInput::predicted_dt`],
+    /// and [`crate::Context::is_degraded`] is always `false`.
+    /// [`crate::Id`]s and auto-ids never depend on anything random.
+    ///
+    /// Float math is still done by the platform, so results can differ in the last bits
+    /// between platforms with different implementations of e.g. `sin`.
+    ///
+    /// Default is `false`.
+    pub deterministic: bool,
+
+    /// An ongoing animated switch of style, started by [`crate::Context::set_theme_animated`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) theme_transition: Option<ThemeTransition>,
+}
+
+/// Cross-fading from one style to the current one.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ThemeTransition {
+    /// The style we are fading away from.
+    pub from: std::sync::Arc<Style>,
+
+    pub start_time: f64,
+
+    /// In seconds.
+    pub duration: f32,
+
+    /// The interpolated style of the current pass.
+    pub current: std::sync::Arc<Style>,
 }
 
 impl Default for Options {
@@ -311,6 +389,7 @@ impl Default for Options {
             system_theme: None,
             zoom_factor: 1.0,
             zoom_with_keyboard: true,
+            shortcut_cheatsheet_key: Some(crate::Key::F1),
             tessellation_options: Default::default(),
             repaint_on_widget_change: false,
             max_passes: NonZeroUsize::new(2).unwrap(),
@@ -321,11 +400,18 @@ impl Default for Options {
             // Input:
             input_options: Default::default(),
             reduce_texture_memory: false,
+            max_cached_galleys: 0,
+            frame_time_budget: None,
+            deterministic: false,
+            theme_transition: None,
         }
     }
 }
 
 impl Options {
+    /// How much time passes each pass with [`Self::deterministic`], in seconds.
+    pub const DETERMINISTIC_TIME_STEP: f32 = 1.0 / 60.0;
+
     pub(crate) fn begin_pass(&mut self, new_raw_input: &RawInput) {
         self.system_theme = new_raw_input.system_theme;
     }
@@ -339,13 +425,61 @@ impl Options {
         }
     }
 
+    /// The style used for new [`Ui`](crate::Ui):s, taking any ongoing theme transition into account.
     pub(crate) fn style(&self) -> &std::sync::Arc<Style> {
+        if let Some(transition) = &self.theme_transition {
+            &transition.current
+        } else {
+            self.target_style()
+        }
+    }
+
+    /// The style of the current theme, ignoring any ongoing theme transition.
+    pub(crate) fn target_style(&self) -> &std::sync::Arc<Style> {
         match self.theme() {
             Theme::Dark => &self.dark_style,
             Theme::Light => &self.light_style,
         }
     }
 
+    /// Start cross-fading from the current style to whatever style is active next.
+    pub(crate) fn start_theme_transition(&mut self, time: f64, duration: f32) {
+        let from = self.style().clone();
+        self.theme_transition = Some(ThemeTransition {
+            current: from.clone(),
+            from,
+            start_time: time,
+            duration,
+        });
+    }
+
+    /// Update the interpolated style of an ongoing theme transition.
+    ///
+    /// Returns `true` if the transition is still in progress.
+    pub(crate) fn update_theme_transition(&mut self, time: f64) -> bool {
+        let Some(transition) = &self.theme_transition else {
+            return false;
+        };
+
+        let t = if transition.duration <= 0.0 {
+            1.0
+        } else {
+            ((time - transition.start_time) / transition.duration as f64) as f32
+        };
+
+        if t >= 1.0 {
+            self.theme_transition = None;
+            false
+        } else {
+            let t = epaint::emath::easing::cubic_in_out(t.max(0.0));
+            let current = Style::lerp(&transition.from, self.target_style(), t);
+            if let Some(transition) = &mut self.theme_transition {
+                transition.current = std::sync::Arc::new(current);
+            }
+            true
+        }
+    }
+
     pub(crate) fn style_mut(&mut self) -> &mut std::sync::Arc<Style> {
         match self.theme() {
             Theme::Dark => &mut self.dark_style,
@@ -367,6 +501,7 @@ impl Options {
             system_theme: _,
             zoom_factor: _, // TODO(emilk)
             zoom_with_keyboard,
+            shortcut_cheatsheet_key,
             tessellation_options,
             repaint_on_widget_change,
             max_passes,
@@ -375,6 +510,10 @@ impl Options {
             warn_on_id_clash,
             input_options,
             reduce_texture_memory,
+            max_cached_galleys,
+            frame_time_budget,
+            deterministic,
+            theme_transition: _,
         } = self;
 
         use crate::Widget as _;
@@ -398,9 +537,42 @@ impl Options {
                     "Zoom with keyboard (Cmd +, Cmd -, Cmd 0)",
                 );
 
+                let mut show_cheatsheet = shortcut_cheatsheet_key.is_some();
+                if ui
+                    .checkbox(&mut show_cheatsheet, "Show shortcut cheatsheet while holding F1")
+                    .changed()
+                {
+                    *shortcut_cheatsheet_key = show_cheatsheet.then_some(crate::Key::F1);
+                }
+
                 ui.checkbox(warn_on_id_clash, "Warn if two widgets have the same Id");
 
                 ui.checkbox(reduce_texture_memory, "Reduce texture memory");
+
+                ui.horizontal(|ui| {
+                    ui.label("Max cached texts:");
+                    ui.add(crate::DragValue::new(max_cached_galleys).range(0..=100_000));
+                });
+
+                ui.checkbox(deterministic, "Deterministic (fixed time step)");
+
+                ui.horizontal(|ui| {
+                    let mut has_budget = frame_time_budget.is_some();
+                    if ui.checkbox(&mut has_budget, "Frame time budget").changed() {
+                        *frame_time_budget = has_budget.then_some(1.0 / 60.0);
+                    }
+                    if let Some(budget) = frame_time_budget {
+                        let mut budget_ms = *budget * 1e3;
+                        let response = ui.add(
+                            crate::DragValue::new(&mut budget_ms)
+                                .range(1.0..=1000.0)
+                                .suffix(" ms"),
+                        );
+                        if response.changed() {
+                            *budget = budget_ms * 1e-3;
+                        }
+                    }
+                });
             });
 
         CollapsingHeader::new("🎑 Style")
@@ -1167,6 +1339,10 @@ impl Areas {
         self.areas.get(&id)
     }
 
+    pub(crate) fn get_mut(&mut self, id: Id) -> Option<&mut area::AreaState> {
+        self.areas.get_mut(&id)
+    }
+
     /// All layers back-to-front, top is last.
     pub(crate) fn order(&self) -> &[LayerId] {
         &self.order
@@ -291,6 +291,72 @@ impl Painter {
             }
         });
     }
+
+    /// Find the topmost shape added to this painter's layer this frame that is under `pos`.
+    ///
+    /// A shape is hit if `pos` is within `tolerance` points of its fill or stroke
+    /// (see [`Shape::hit_test`]) and inside its clip rectangle.
+    /// Note that this includes shapes added by other painters on the same layer.
+    ///
+    /// The returned index can be used with [`Self::set`].
+    pub fn shape_at(&self, pos: Pos2, tolerance: f32) -> Option<ShapeIdx> {
+        self.ctx.graphics(|g| {
+            let list = g.get(self.layer_id)?;
+            let entries: Vec<_> = list.all_entries().collect();
+            entries
+                .iter()
+                .rposition(|c| c.clip_rect.contains(pos) && c.shape.hit_test(pos, tolerance))
+                .map(ShapeIdx)
+        })
+    }
+
+    /// Paint pointer input as soon as it arrives, instead of waiting for the next pass.
+    ///
+    /// This is for drawing apps, to reduce the latency between the pen and the ink.
+    /// Call this each pass with a function that paints the strokes of the given pointer events,
+    /// the same way your ui will paint them once it gets them the next pass.
+    /// Integrations that support it (currently `eframe` with the `glow` renderer) call it
+    /// whenever pointer events arrive between passes, with all the events since the last pass,
+    /// and show the result on top of the last frame right away.
+    /// Elsewhere it is never called, and the strokes show up the next pass as usual.
+    ///
+    /// The events and the returned shapes are in screen coordinates,
+    /// and the shapes are clipped to [`Self::clip_rect`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let stroke = egui::Stroke::new(2.0, egui::Color32::WHITE);
+    /// ui.painter().paint_low_latency(move |events| {
+    ///     let points: Vec<egui::Pos2> = events
+    ///         .iter()
+    ///         .filter_map(|event| match event {
+    ///             egui::Event::PointerMoved(pos) => Some(*pos),
+    ///             _ => None,
+    ///         })
+    ///         .collect();
+    ///     vec![egui::Shape::line(points, stroke)]
+    /// });
+    /// # });
+    /// ```
+    pub fn paint_low_latency(
+        &self,
+        paint: impl Fn(&[crate::Event]) -> Vec<Shape> + Send + Sync + 'static,
+    ) {
+        let painter = LowLatencyPainter {
+            layer_id: self.layer_id,
+            clip_rect: self.clip_rect,
+            paint: Arc::new(paint),
+        };
+        self.ctx.register_low_latency_painter(painter);
+    }
+}
+
+/// Registered with [`Painter::paint_low_latency`].
+#[derive(Clone)]
+pub(crate) struct LowLatencyPainter {
+    pub layer_id: LayerId,
+    pub clip_rect: Rect,
+    pub paint: Arc<dyn Fn(&[crate::Event]) -> Vec<Shape> + Send + Sync>,
 }
 
 /// ## Debug painting
@@ -486,6 +552,42 @@ impl Painter {
     ) -> ShapeIdx {
         self.add(Shape::image(texture_id, rect, uv, tint))
     }
+
+    /// Paint a whole texture as a rotated and/or flipped sprite.
+    ///
+    /// The unrotated image covers a rectangle of the given `size` centered at `center`.
+    /// It is then rotated by `angle` radians (positive is clockwise) about `pivot`,
+    /// which is given in normalized coordinates of that rectangle
+    /// ((0,0) is top-left, (1,1) is bottom-right, `Vec2::splat(0.5)` is the center).
+    ///
+    /// `flip` mirrors the image horizontally (`flip[0]`) and/or vertically (`flip[1]`)
+    /// before the rotation is applied.
+    pub fn image_rotated(
+        &self,
+        texture_id: epaint::TextureId,
+        center: Pos2,
+        size: Vec2,
+        angle: f32,
+        pivot: Vec2,
+        flip: [bool; 2],
+    ) -> ShapeIdx {
+        let rect = Rect::from_center_size(center, size);
+
+        let mut uv = Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0));
+        if flip[0] {
+            std::mem::swap(&mut uv.min.x, &mut uv.max.x);
+        }
+        if flip[1] {
+            std::mem::swap(&mut uv.min.y, &mut uv.max.y);
+        }
+
+        let mut mesh = epaint::Mesh::with_texture(texture_id);
+        mesh.add_rect_with_uv(rect, uv, Color32::WHITE);
+        if angle != 0.0 {
+            mesh.rotate(emath::Rot2::from_angle(angle), rect.min + pivot * size);
+        }
+        self.add(Shape::mesh(mesh))
+    }
 }
 
 /// ## Text
@@ -513,6 +615,101 @@ impl Painter {
         rect
     }
 
+    /// Lay out and paint text along a path, e.g. for circular labels, gauges or map annotations.
+    ///
+    /// The `path` is a polyline, e.g. from [`epaint::CubicBezierShape::flatten`] or points along an arc.
+    /// The text starts `start_offset` points along the path.
+    /// Each glyph is rotated to follow the path, while keeping the spacing of a straight layout.
+    ///
+    /// `valign` decides where the text is relative to the path:
+    /// [`Align::Max`](emath::Align::Max) puts the text on top of the path (its bottom touches the path),
+    /// [`Align::Center`](emath::Align::Center) centers it on the path,
+    /// and [`Align::Min`](emath::Align::Min) hangs it below the path.
+    ///
+    /// Glyphs that do not fit on the path are not painted.
+    ///
+    /// Returns the bounding rectangle of each painted glyph, in text order,
+    /// which can be used for hit-testing.
+    #[expect(clippy::needless_pass_by_value)]
+    pub fn text_on_path(
+        &self,
+        path: &[Pos2],
+        start_offset: f32,
+        valign: emath::Align,
+        text: impl ToString,
+        font_id: FontId,
+        text_color: Color32,
+    ) -> Vec<Rect> {
+        let galley = self.layout_no_wrap(text.to_string(), font_id.clone(), text_color);
+
+        // Cumulative length along the path at each point:
+        let mut distances = Vec::with_capacity(path.len());
+        let mut total = 0.0;
+        for (i, point) in path.iter().enumerate() {
+            if i > 0 {
+                total += path[i - 1].distance(*point);
+            }
+            distances.push(total);
+        }
+
+        let point_at = |distance: f32| -> Option<(Pos2, f32)> {
+            if path.len() < 2 || distance < 0.0 || total < distance {
+                return None;
+            }
+            let i = distances
+                .partition_point(|&d| d <= distance)
+                .clamp(1, path.len() - 1);
+            let (a, b) = (path[i - 1], path[i]);
+            let segment_length = distances[i] - distances[i - 1];
+            let t = if segment_length > 0.0 {
+                (distance - distances[i - 1]) / segment_length
+            } else {
+                0.0
+            };
+            Some((a.lerp(b, t), (b - a).angle()))
+        };
+
+        let mut glyph_rects = Vec::new();
+        for row in &galley.rows {
+            for glyph in &row.glyphs {
+                if glyph.chr.is_whitespace() {
+                    continue;
+                }
+
+                let center = start_offset + row.pos.x + glyph.pos.x + 0.5 * glyph.advance_width;
+                let Some((point, angle)) = point_at(center) else {
+                    continue;
+                };
+
+                let glyph_galley =
+                    self.layout_no_wrap(glyph.chr.to_string(), font_id.clone(), text_color);
+                let size = glyph_galley.size();
+                let anchor = Vec2::new(
+                    0.5 * size.x,
+                    match valign {
+                        emath::Align::Min => 0.0,
+                        emath::Align::Center => 0.5 * size.y,
+                        emath::Align::Max => size.y,
+                    },
+                );
+
+                let rot = emath::Rot2::from_angle(angle);
+                let pos = point - rot * anchor;
+                let corners = [
+                    Vec2::ZERO,
+                    Vec2::new(size.x, 0.0),
+                    Vec2::new(0.0, size.y),
+                    size,
+                ];
+                glyph_rects.push(Rect::from_points(&corners.map(|corner| pos + rot * corner)));
+
+                self.add(epaint::TextShape::new(pos, glyph_galley, text_color).with_angle(angle));
+            }
+        }
+
+        glyph_rects
+    }
+
     /// Will wrap text at the given width and line break at `\n`.
     ///
     /// Paint the results with [`Self::galley`].
@@ -82,6 +82,21 @@ pub struct DebugRect {
     pub is_clicking: bool,
 }
 
+/// A widget or [`crate::Ui`] that didn't fit in the space it was given,
+/// see [`crate::style::DebugOptions::show_overflow`].
+#[cfg(debug_assertions)]
+#[derive(Clone)]
+pub struct OverflowWarning {
+    pub layer_id: LayerId,
+    pub rect: Rect,
+
+    /// E.g. "clipped" or "truncated".
+    pub reason: &'static str,
+
+    /// Where the widget was added, if the `callstack` feature is enabled.
+    pub callstack: String,
+}
+
 #[cfg(debug_assertions)]
 impl DebugRect {
     pub fn paint(self, painter: &Painter) {
@@ -231,8 +246,17 @@ pub struct PassState {
     /// Highlight these widgets the next pass.
     pub highlight_next_pass: IdSet,
 
+    /// Keyboard shortcuts registered this pass, for the shortcut cheatsheet.
+    pub shortcuts: Vec<crate::shortcuts::RegisteredShortcut>,
+
+    /// See [`crate::Painter::paint_low_latency`].
+    pub(crate) low_latency_painters: Vec<crate::painter::LowLatencyPainter>,
+
     #[cfg(debug_assertions)]
     pub debug_rect: Option<DebugRect>,
+
+    #[cfg(debug_assertions)]
+    pub overflows: Vec<OverflowWarning>,
 }
 
 impl Default for PassState {
@@ -250,9 +274,14 @@ impl Default for PassState {
             #[cfg(feature = "accesskit")]
             accesskit_state: None,
             highlight_next_pass: Default::default(),
+            shortcuts: Default::default(),
+            low_latency_painters: Default::default(),
 
             #[cfg(debug_assertions)]
             debug_rect: None,
+
+            #[cfg(debug_assertions)]
+            overflows: Vec::new(),
         }
     }
 }
@@ -273,9 +302,14 @@ impl PassState {
             #[cfg(feature = "accesskit")]
             accesskit_state,
             highlight_next_pass,
+            shortcuts,
+            low_latency_painters,
 
             #[cfg(debug_assertions)]
             debug_rect,
+
+            #[cfg(debug_assertions)]
+            overflows,
         } = self;
 
         used_ids.clear();
@@ -287,10 +321,13 @@ impl PassState {
         *used_by_panels = Rect::NOTHING;
         *scroll_target = [None, None];
         *scroll_delta = Default::default();
+        shortcuts.clear();
+        low_latency_painters.clear();
 
         #[cfg(debug_assertions)]
         {
             *debug_rect = None;
+            overflows.clear();
         }
 
         #[cfg(feature = "accesskit")]
@@ -0,0 +1,264 @@
+//! Laying out content on paper-sized pages, for printing or saving as PDF.
+//!
+//! [`Print::show`] lays out your content at the width of a page,
+//! then cuts it into pages, preferably between widgets rather than through them.
+//! The pages are shapes, which you tessellate and paint like a screenshot,
+//! and then hand to the printer or PDF writer of your platform.
+
+use emath::{Pos2, Rangef, Rect, Vec2, pos2, vec2};
+use epaint::{ClippedPrimitive, ClippedShape, Margin, textures::TexturesDelta};
+
+use crate::{Context, Id, RawInput, Style, Ui, UiBuilder, ViewportId, ViewportInfo, Visuals};
+
+/// Lay out content on pages.
+///
+/// All sizes are in points, and a page has 72 points per inch, like in PDF.
+///
+/// ```
+/// # egui::__run_test_ctx(|ctx| {
+/// let printed = egui::Print::new(egui::Print::A4).show(ctx, |ui| {
+///     ui.heading("Report");
+///     for i in 0..200 {
+///         ui.label(format!("Row {i}"));
+///     }
+/// });
+/// assert!(1 < printed.pages.len());
+/// for page in &printed.pages {
+///     let _primitives = printed.tessellate(page);
+///     // Paint the primitives with your renderer, and print the image.
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Clone, Debug)]
+pub struct Print {
+    page_size: Vec2,
+    margin: Margin,
+    pixels_per_point: f32,
+    style: Option<Style>,
+}
+
+impl Print {
+    /// The size of an A4 page.
+    pub const A4: Vec2 = vec2(595.0, 842.0);
+
+    /// The size of a US Letter page.
+    pub const LETTER: Vec2 = vec2(612.0, 792.0);
+
+    /// Pages of this size, in points.
+    pub fn new(page_size: Vec2) -> Self {
+        Self {
+            page_size,
+            margin: Margin::same(36),
+            pixels_per_point: 2.0,
+            style: None,
+        }
+    }
+
+    /// The empty space around the content of each page.
+    ///
+    /// Default: half an inch on each side.
+    #[inline]
+    pub fn margin(mut self, margin: impl Into<Margin>) -> Self {
+        self.margin = margin.into();
+        self
+    }
+
+    /// How many pixels the pages will be rendered with per point.
+    ///
+    /// Text is rasterized at this scale, so use e.g. `300.0 / 72.0` for a 300 DPI printer.
+    ///
+    /// Default: `2.0`.
+    #[inline]
+    pub fn pixels_per_point(mut self, pixels_per_point: f32) -> Self {
+        self.pixels_per_point = pixels_per_point;
+        self
+    }
+
+    /// The style of the printed content.
+    ///
+    /// Default: the style of the [`Context`], with light visuals for the white paper.
+    #[inline]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Lay out the content, and cut it into pages.
+    ///
+    /// The content is laid out with a [`Context`] of its own, so it doesn't show up on screen,
+    /// using the fonts of `ctx`.
+    /// It may be called more than once, like the contents of [`Context::run`].
+    pub fn show(self, ctx: &Context, mut add_contents: impl FnMut(&mut Ui)) -> PrintedPages {
+        let Self {
+            page_size,
+            margin,
+            pixels_per_point,
+            style,
+        } = self;
+
+        let print_ctx = ctx.new_isolated();
+        print_ctx.set_style(style.unwrap_or_else(|| Style {
+            visuals: Visuals::light(),
+            ..(*ctx.style()).clone()
+        }));
+
+        let content_size = page_size - margin.sum();
+        let max_rect = Rect::from_min_size(
+            pos2(margin.leftf(), 0.0),
+            vec2(content_size.x, f32::INFINITY),
+        );
+        let info = ViewportInfo {
+            native_pixels_per_point: Some(pixels_per_point),
+            ..Default::default()
+        };
+        let raw_input = RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, page_size)),
+            viewports: std::iter::once((ViewportId::ROOT, info)).collect(),
+            ..Default::default()
+        };
+
+        let mut content_rect = Rect::NOTHING;
+        let mut widget_rects = vec![];
+        let output = print_ctx.run(raw_input, |ctx| {
+            let builder = UiBuilder::new().max_rect(max_rect);
+            let mut ui = Ui::new(ctx.clone(), Id::new("print"), builder);
+            add_contents(&mut ui);
+            content_rect = ui.min_rect();
+            widget_rects = ctx.viewport(|viewport| {
+                let widgets = viewport.this_pass.widgets.get_layer(ui.layer_id());
+                widgets.map(|widget| widget.rect).collect()
+            });
+        });
+
+        let pages = if content_rect.is_positive() {
+            page_ranges(content_rect.y_range(), content_size.y, &widget_rects)
+                .into_iter()
+                .map(|range| PrintPage::cut(&output.shapes, range, margin.topf()))
+                .collect()
+        } else {
+            vec![]
+        };
+
+        PrintedPages {
+            pages,
+            page_size,
+            pixels_per_point: output.pixels_per_point,
+            textures_delta: output.textures_delta,
+            ctx: print_ctx,
+        }
+    }
+}
+
+/// What [`Print::show`] returns.
+pub struct PrintedPages {
+    /// The pages, in order.
+    pub pages: Vec<PrintPage>,
+
+    /// The size of each page, in points.
+    pub page_size: Vec2,
+
+    /// What to render the pages with.
+    pub pixels_per_point: f32,
+
+    /// The textures used by the pages, like the font atlas.
+    ///
+    /// These belong to the pages alone, and clash with those of your app,
+    /// so render the pages with a renderer of their own.
+    pub textures_delta: TexturesDelta,
+
+    ctx: Context,
+}
+
+impl PrintedPages {
+    /// Tessellate a page, to render it like a screenshot.
+    pub fn tessellate(&self, page: &PrintPage) -> Vec<ClippedPrimitive> {
+        self.ctx.tessellate(page.shapes.clone(), self.pixels_per_point)
+    }
+}
+
+/// One page of [`PrintedPages`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrintPage {
+    /// What is on the page, with the top left corner of the page at zero.
+    pub shapes: Vec<ClippedShape>,
+}
+
+impl PrintPage {
+    /// The part of `shapes` between the heights in `range`, moved onto a page.
+    fn cut(shapes: &[ClippedShape], range: Rangef, margin_top: f32) -> Self {
+        let band = Rect::from_x_y_ranges(Rangef::EVERYTHING, range);
+        let offset = vec2(0.0, margin_top - range.min);
+
+        let shapes = shapes
+            .iter()
+            .filter_map(|clipped| {
+                let clip_rect = clipped.clip_rect.intersect(band);
+                if !clip_rect.is_positive()
+                    || !clipped.shape.visual_bounding_rect().intersects(clip_rect)
+                {
+                    return None;
+                }
+                let mut shape = clipped.shape.clone();
+                shape.translate(offset);
+                Some(ClippedShape {
+                    clip_rect: clip_rect.translate(offset),
+                    shape,
+                })
+            })
+            .collect();
+
+        Self { shapes }
+    }
+}
+
+/// Cut `content` into pages at most `page_height` high.
+///
+/// Pages end above widgets that would otherwise be cut in half,
+/// unless they are too high to fit on a page anyway.
+fn page_ranges(content: Rangef, page_height: f32, widget_rects: &[Rect]) -> Vec<Rangef> {
+    let mut ranges = vec![];
+    let mut top = content.min;
+
+    while top + page_height < content.max {
+        let limit = top + page_height;
+        let mut bottom = limit;
+        while let Some(widget) = widget_rects.iter().find(|rect| {
+            rect.height() <= page_height && rect.min.y < bottom && bottom < rect.max.y
+        }) {
+            bottom = widget.min.y;
+        }
+        if bottom <= top {
+            bottom = limit;
+        }
+        ranges.push(Rangef::new(top, bottom));
+        top = bottom;
+    }
+
+    ranges.push(Rangef::new(top, content.max));
+    ranges
+}
+
+#[test]
+fn test_page_ranges() {
+    let widget = |top: f32, height: f32| Rect::from_min_size(pos2(0.0, top), vec2(100.0, height));
+
+    let pages = page_ranges(Rangef::new(0.0, 250.0), 100.0, &[]);
+    assert_eq!(
+        pages,
+        vec![Rangef::new(0.0, 100.0), Rangef::new(100.0, 200.0), Rangef::new(200.0, 250.0)]
+    );
+
+    // The widget across the first break moves to the second page:
+    let pages = page_ranges(Rangef::new(0.0, 150.0), 100.0, &[widget(90.0, 20.0)]);
+    assert_eq!(pages, vec![Rangef::new(0.0, 90.0), Rangef::new(90.0, 150.0)]);
+
+    // Widgets stay together with the group they are in:
+    let widgets = [widget(60.0, 50.0), widget(90.0, 20.0)];
+    let pages = page_ranges(Rangef::new(0.0, 150.0), 100.0, &widgets);
+    assert_eq!(pages[0], Rangef::new(0.0, 60.0));
+
+    // Widgets higher than a page are cut anyway:
+    let pages = page_ranges(Rangef::new(0.0, 250.0), 100.0, &[widget(0.0, 250.0)]);
+    assert_eq!(pages.len(), 3);
+}
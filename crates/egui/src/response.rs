@@ -1,8 +1,9 @@
 use std::{any::Any, sync::Arc};
 
 use crate::{
-    Context, CursorIcon, Id, LayerId, PointerButton, Popup, PopupKind, Sense, Tooltip, Ui,
-    WidgetRect, WidgetText,
+    Context, CursorIcon, HoverPreviewSender, Id, KeyboardShortcut, LayerId, PointerButton, Popup,
+    PopupKind, Sense, Tooltip, Ui, WidgetRect, WidgetText,
+    containers::tooltip::HoverPreview,
     emath::{Align, Pos2, Rect, Vec2},
     pass_state,
 };
@@ -575,6 +576,86 @@ impl Response {
         self
     }
 
+    /// Show a tooltip whose contents are loaded asynchronously, e.g. a link preview or a thumbnail.
+    ///
+    /// The first time the tooltip is shown, `start_loading` is called with a [`HoverPreviewSender`].
+    /// Use it to start the loading (on a background thread, an async task, …)
+    /// and call [`HoverPreviewSender::send`] when done.
+    /// Until then a spinner is shown; afterwards `add_contents` is called with the loaded value.
+    ///
+    /// If the user stops hovering before the value arrives, the load is cancelled
+    /// (see [`HoverPreviewSender::is_cancelled`]) and restarted on the next hover.
+    /// Loaded values are cached, so each preview is only loaded once.
+    /// Use [`Self::forget_hover_preview`] to load it again.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.hyperlink("https://www.egui.rs").on_hover_preview(
+    ///     |sender| {
+    ///         std::thread::spawn(move || {
+    ///             let title: String = "egui – an immediate mode GUI library".to_owned(); // fetch…
+    ///             sender.send(title);
+    ///         });
+    ///     },
+    ///     |ui, title| {
+    ///         ui.label(title);
+    ///     },
+    /// );
+    /// # });
+    /// ```
+    pub fn on_hover_preview<T: 'static + Send + Sync>(
+        self,
+        start_loading: impl FnOnce(HoverPreviewSender<T>),
+        add_contents: impl FnOnce(&mut Ui, &T),
+    ) -> Self {
+        let id = Self::hover_preview_id(self.id);
+
+        let shown = Tooltip::for_enabled(&self).show(|ui| {
+            let preview = ui.data_mut(|d| d.get_temp::<HoverPreview<T>>(id));
+            let preview = preview.unwrap_or_else(|| {
+                let (preview, sender) = HoverPreview::new(ui.ctx());
+                ui.data_mut(|d| d.insert_temp(id, preview.clone()));
+                start_loading(sender);
+                preview
+            });
+
+            if let Some(value) = preview.ready() {
+                add_contents(ui, &value);
+            } else {
+                ui.spinner();
+            }
+        });
+
+        if shown.is_none() {
+            // Cancel a pending load if the user stopped hovering:
+            self.ctx.data_mut(|d| {
+                if let Some(preview) = d.get_temp::<HoverPreview<T>>(id) {
+                    if preview.ready().is_none() {
+                        preview.cancel();
+                        d.remove::<HoverPreview<T>>(id);
+                    }
+                }
+            });
+        }
+
+        self
+    }
+
+    /// Forget the cached value of [`Self::on_hover_preview`], so that it is loaded again on next hover.
+    pub fn forget_hover_preview<T: 'static + Send + Sync>(&self) {
+        let id = Self::hover_preview_id(self.id);
+        self.ctx.data_mut(|d| {
+            if let Some(preview) = d.get_temp::<HoverPreview<T>>(id) {
+                preview.cancel();
+            }
+            d.remove::<HoverPreview<T>>(id);
+        });
+    }
+
+    fn hover_preview_id(widget_id: Id) -> Id {
+        widget_id.with("hover_preview")
+    }
+
     /// Show this UI when hovering if the widget is disabled.
     pub fn on_disabled_hover_ui(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
         Tooltip::for_disabled(&self).show(add_contents);
@@ -811,6 +892,18 @@ impl Response {
         self.ctx
             .register_widget_info(self.id, || event.widget_info().clone());
 
+        self.ctx.record_interaction(|| {
+            let info = event.widget_info();
+            crate::InteractionEvent {
+                id: self.id,
+                widget_type: info.typ,
+                label_hash: info.label.as_ref().map(crate::util::hash),
+                kind: event.interaction_kind(),
+                rect: self.rect,
+                time: self.ctx.input(|i| i.time),
+            }
+        });
+
         self.ctx.output_mut(|o| o.events.push(event));
     }
 
@@ -920,6 +1013,57 @@ impl Response {
         self
     }
 
+    /// Describe what this widget does, both to screen readers and in a tooltip.
+    ///
+    /// This way the accessible description and the visible hint always match.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.button("🗑")
+    ///     .accessibility_description("Delete the selected files");
+    /// # });
+    /// ```
+    pub fn accessibility_description(self, text: impl Into<String>) -> Self {
+        let text = text.into();
+
+        #[cfg(feature = "accesskit")]
+        self.ctx.accesskit_node_builder(self.id, |builder| {
+            builder.set_description(text.clone());
+        });
+
+        self.on_hover_text(text)
+    }
+
+    /// Tell the user which keyboard shortcut does the same thing as this widget.
+    ///
+    /// The shortcut is exposed to screen readers, and shown in a tooltip.
+    ///
+    /// This does NOT make the shortcut trigger the widget: you still need to check for it,
+    /// e.g. with [`crate::InputState::consume_shortcut`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let save = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::S);
+    /// if ui.button("Save").keyboard_hint(save).clicked()
+    ///     || ui.input_mut(|i| i.consume_shortcut(&save))
+    /// {
+    ///     // …
+    /// }
+    /// # });
+    /// ```
+    pub fn keyboard_hint(self, shortcut: KeyboardShortcut) -> Self {
+        let shortcut_text = self.ctx.format_shortcut(&shortcut);
+
+        #[cfg(feature = "accesskit")]
+        self.ctx.accesskit_node_builder(self.id, |builder| {
+            builder.set_keyboard_shortcut(shortcut_text.clone());
+        });
+
+        self.on_hover_ui(|ui| {
+            ui.weak(format!("Shortcut: {shortcut_text}"));
+        })
+    }
+
     /// Response to secondary clicks (right-clicks) by showing the given menu.
     ///
     /// Make sure the widget senses clicks (e.g. [`crate::Button`] does, [`crate::Label`] does not).
@@ -0,0 +1,241 @@
+use std::{sync::Arc, time::Duration};
+
+use epaint::ColorImage;
+
+use crate::{Context, Event, Id, Pos2, Rect, UserData, Vec2, ViewportId};
+
+/// One image recorded by a [`ScreenRecorder`].
+#[derive(Clone, Debug)]
+pub struct RecordedFrame {
+    /// Seconds since the recording started.
+    pub time: f64,
+
+    /// What the viewport (or the recorded region of it) looked like.
+    pub image: Arc<ColorImage>,
+}
+
+/// What a [`ScreenRecorder`] puts in the [`UserData`] of its screenshots,
+/// to tell them apart from other screenshots.
+struct RecordingRequest {
+    recorder: Id,
+    time: f64,
+}
+
+/// Records a viewport as a sequence of images, e.g. to make a GIF of a bug.
+///
+/// It takes screenshots (see [`crate::ViewportCommand::Screenshot`]) at a steady rate,
+/// so it needs an integration that supports them, like `eframe`.
+/// Turning the images into a GIF or video is up to you.
+///
+/// Keep the recorder in your app state, and call [`Self::update`] each pass.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut recorder = egui::ScreenRecorder::new("bug_recorder");
+/// // In your app state:
+/// // let mut recorder = egui::ScreenRecorder::new("bug_recorder").fps(15.0);
+///
+/// recorder.update(ui.ctx());
+/// if recorder.is_recording() {
+///     if ui.button("⏹ Stop").clicked() {
+///         recorder.stop();
+///         let _frames = recorder.take_frames();
+///         // Encode `frames` as a GIF…
+///     }
+/// } else if ui.button("⏺ Record a GIF of this bug").clicked() {
+///     recorder.start(ui.ctx());
+/// }
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+pub struct ScreenRecorder {
+    id: Id,
+    viewport_id: Option<ViewportId>,
+    fps: f32,
+    region: Option<Rect>,
+    max_frames: usize,
+
+    /// When the recording started, if it is going on.
+    start_time: Option<f64>,
+    next_capture_time: f64,
+    frames: Vec<RecordedFrame>,
+}
+
+impl ScreenRecorder {
+    /// The `id_salt` must be unique among recorders.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            viewport_id: None,
+            fps: 10.0,
+            region: None,
+            max_frames: 300,
+            start_time: None,
+            next_capture_time: 0.0,
+            frames: vec![],
+        }
+    }
+
+    /// Record this viewport.
+    ///
+    /// Default: the viewport that [`Self::update`] is called in.
+    #[inline]
+    pub fn viewport(mut self, viewport_id: ViewportId) -> Self {
+        self.viewport_id = Some(viewport_id);
+        self
+    }
+
+    /// How many images to record per second.
+    ///
+    /// Each one is a screenshot, which may slow down the app, so keep this low.
+    ///
+    /// Default: `10.0`.
+    #[inline]
+    pub fn fps(mut self, fps: f32) -> Self {
+        self.fps = fps;
+        self
+    }
+
+    /// Only record this part of the viewport, in points.
+    ///
+    /// Default: the whole viewport.
+    #[inline]
+    pub fn region(mut self, region: Rect) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// Keep at most this many images, dropping the oldest ones,
+    /// so that a long recording keeps the last part, which usually shows the bug.
+    ///
+    /// Default: `300`.
+    #[inline]
+    pub fn max_frames(mut self, max_frames: usize) -> Self {
+        self.max_frames = max_frames;
+        self
+    }
+
+    /// Start a new recording, dropping the images of the previous one.
+    pub fn start(&mut self, ctx: &Context) {
+        let now = ctx.input(|i| i.time);
+        self.start_time = Some(now);
+        self.next_capture_time = now;
+        self.frames.clear();
+        ctx.request_repaint();
+    }
+
+    /// Stop recording.
+    ///
+    /// Screenshots that were already asked for are still added by [`Self::update`].
+    pub fn stop(&mut self) {
+        self.start_time = None;
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.start_time.is_some()
+    }
+
+    /// The images recorded so far.
+    pub fn frames(&self) -> &[RecordedFrame] {
+        &self.frames
+    }
+
+    /// Take the images recorded so far.
+    pub fn take_frames(&mut self) -> Vec<RecordedFrame> {
+        std::mem::take(&mut self.frames)
+    }
+
+    /// Collect the screenshots taken since the last call, and take the next one when it is time.
+    ///
+    /// Call this each pass, in the viewport that started the recording.
+    pub fn update(&mut self, ctx: &Context) {
+        let viewport_id = self.viewport_id.unwrap_or_else(|| ctx.viewport_id());
+
+        let screenshots: Vec<(f64, Arc<ColorImage>)> = ctx.input(|i| {
+            i.events
+                .iter()
+                .filter_map(|event| {
+                    let Event::Screenshot {
+                        user_data, image, ..
+                    } = event
+                    else {
+                        return None;
+                    };
+                    let request = (user_data.data.as_ref())
+                        .and_then(|data| data.downcast_ref::<RecordingRequest>())?;
+                    (request.recorder == self.id).then(|| (request.time, image.clone()))
+                })
+                .collect()
+        });
+        for (time, image) in screenshots {
+            let image = match self.region {
+                Some(region) => {
+                    let pixels_per_point = ctx.input_for(viewport_id, |i| i.pixels_per_point());
+                    let size = Vec2::new(image.width() as f32, image.height() as f32);
+                    let whole = Rect::from_min_size(Pos2::ZERO, size / pixels_per_point);
+                    let region = region.intersect(whole);
+                    if !region.is_positive() {
+                        continue;
+                    }
+                    Arc::new(image.region(&region, Some(pixels_per_point)))
+                }
+                None => image,
+            };
+            self.frames.push(RecordedFrame { time, image });
+        }
+        if self.max_frames < self.frames.len() {
+            let excess = self.frames.len() - self.max_frames;
+            self.frames.drain(..excess);
+        }
+
+        let Some(start_time) = self.start_time else {
+            return;
+        };
+        let now = ctx.input(|i| i.time);
+        if self.next_capture_time <= now {
+            let request = RecordingRequest {
+                recorder: self.id,
+                time: now - start_time,
+            };
+            ctx.screenshot_viewport(viewport_id, UserData::new(request));
+            let interval = 1.0 / self.fps.max(0.1) as f64;
+            // Don't try to catch up on captures missed while the app was slow:
+            self.next_capture_time = (self.next_capture_time + interval).max(now);
+        }
+        ctx.request_repaint_after(Duration::from_secs_f64(self.next_capture_time - now));
+    }
+}
+
+#[test]
+fn test_screen_recorder() {
+    use crate::{RawInput, ViewportCommand};
+
+    let ctx = Context::default();
+    let mut recorder = ScreenRecorder::new("recorder").region(Rect::from_min_size(
+        Pos2::new(1.0, 1.0),
+        Vec2::splat(2.0),
+    ));
+
+    let output = ctx.run(RawInput::default(), |ctx| {
+        recorder.start(ctx);
+        recorder.update(ctx);
+    });
+    let commands = &output.viewport_output[&ViewportId::ROOT].commands;
+    let Some(ViewportCommand::Screenshot(user_data)) = commands.first() else {
+        panic!("Expected a screenshot command, got {commands:?}");
+    };
+
+    let screenshot = Event::Screenshot {
+        viewport_id: ViewportId::ROOT,
+        user_data: user_data.clone(),
+        image: Arc::new(ColorImage::filled([4, 4], crate::Color32::RED)),
+    };
+    let raw_input = RawInput {
+        events: vec![screenshot],
+        ..Default::default()
+    };
+    let _ = ctx.run(raw_input, |ctx| recorder.update(ctx));
+
+    assert_eq!(recorder.frames().len(), 1);
+    assert_eq!(recorder.frames()[0].image.size, [2, 2], "Only the region");
+}
@@ -0,0 +1,99 @@
+//! A registry of the keyboard shortcuts that are active this pass,
+//! and a cheatsheet overlay that lists them.
+//!
+//! Register your shortcuts with [`Context::register_shortcut`] or
+//! [`Context::consume_registered_shortcut`] each pass they are active.
+//! The user can then hold [`crate::Options::shortcut_cheatsheet_key`] (F1 by default)
+//! to see all of them, grouped by category.
+
+use crate::{Align2, Area, Context, Frame, Grid, Id, KeyboardShortcut, Order, Vec2};
+
+/// A keyboard shortcut registered with [`Context::register_shortcut`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegisteredShortcut {
+    pub shortcut: KeyboardShortcut,
+
+    /// Shortcuts are grouped by this in the cheatsheet, e.g. "File" or "View".
+    pub category: String,
+
+    /// What the shortcut does, e.g. "Save".
+    pub description: String,
+}
+
+impl RegisteredShortcut {
+    /// Do the two registrations describe the same action?
+    fn same_action(&self, other: &Self) -> bool {
+        self.category == other.category && self.description == other.description
+    }
+}
+
+/// Show the shortcut cheatsheet, if it is open or its key is held.
+///
+/// By default, [`crate::Context`] calls this function at the end of each pass.
+pub(crate) fn show_cheatsheet(ctx: &Context) {
+    let key_held = ctx
+        .options(|o| o.shortcut_cheatsheet_key)
+        .is_some_and(|key| ctx.input(|i| i.key_down(key)));
+    if !key_held && !ctx.shortcut_cheatsheet_open() {
+        return;
+    }
+
+    let shortcuts = ctx.registered_shortcuts();
+    if shortcuts.is_empty() {
+        return;
+    }
+
+    // Compare the formatted text, so that e.g. `Ctrl+S` and `Cmd+S` conflict on Windows.
+    let texts: Vec<String> = shortcuts
+        .iter()
+        .map(|s| ctx.format_shortcut(&s.shortcut))
+        .collect();
+    let is_conflict = |index: usize| {
+        texts.iter().enumerate().any(|(other, text)| {
+            text == &texts[index] && !shortcuts[other].same_action(&shortcuts[index])
+        })
+    };
+
+    // Categories in the order they were first registered:
+    let mut categories: Vec<&str> = vec![];
+    for shortcut in &shortcuts {
+        if !categories.contains(&shortcut.category.as_str()) {
+            categories.push(&shortcut.category);
+        }
+    }
+
+    Area::new(Id::new("shortcut_cheatsheet"))
+        .order(Order::Tooltip)
+        .anchor(Align2::CENTER_CENTER, Vec2::ZERO)
+        .interactable(false)
+        .show(ctx, |ui| {
+            Frame::popup(ui.style()).show(ui, |ui| {
+                ui.heading("Keyboard shortcuts");
+
+                for category in categories {
+                    ui.add_space(ui.spacing().item_spacing.y);
+                    ui.strong(category);
+
+                    Grid::new(("shortcut_cheatsheet", category))
+                        .num_columns(2)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for (index, shortcut) in shortcuts.iter().enumerate() {
+                                if shortcut.category != category {
+                                    continue;
+                                }
+                                if is_conflict(index) {
+                                    let color = ui.visuals().error_fg_color;
+                                    ui.colored_label(color, format!("⚠ {}", texts[index]));
+                                    ui.colored_label(color, &shortcut.description);
+                                } else {
+                                    ui.monospace(&texts[index]);
+                                    ui.label(&shortcut.description);
+                                }
+                                ui.end_row();
+                            }
+                        });
+                }
+            });
+        });
+}
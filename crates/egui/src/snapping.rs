@@ -0,0 +1,280 @@
+use emath::{Pos2, Rect, Vec2, pos2};
+use epaint::Stroke;
+
+use crate::{Context, Id, LayerId, Order, Response};
+
+/// How dragged things snap to a grid, to angles, and to the edges of other things.
+///
+/// Used by [`crate::Area::snapping`], [`crate::Window::snapping`]
+/// and [`crate::TransformGizmo::snapping`],
+/// and by your own draggable things with [`Self::drag_rect`].
+///
+/// Snapping is suspended while the alt key is held.
+///
+/// ```
+/// # egui::__run_test_ctx(|ctx| {
+/// egui::Window::new("Snappy")
+///     .snapping(egui::Snapping::grid(16.0))
+///     .show(ctx, |ui| {
+///         ui.label("Drag me");
+///     });
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Snapping {
+    /// Snap positions to a grid with cells of this size.
+    ///
+    /// Things snap to the edges of other things first,
+    /// and only to the grid if there are none close by.
+    pub grid: Option<Vec2>,
+
+    /// Where the lines of the grid cross.
+    pub grid_origin: Pos2,
+
+    /// Snap rotations to multiples of this many radians.
+    pub angle: Option<f32>,
+
+    /// Snap to the edges and centers of other things within this many points.
+    ///
+    /// Set to zero to only snap to the grid.
+    pub tolerance: f32,
+
+    /// Show lines where things snapped to the edges of other things.
+    pub show_lines: bool,
+}
+
+impl Default for Snapping {
+    fn default() -> Self {
+        Self {
+            grid: None,
+            grid_origin: Pos2::ZERO,
+            angle: None,
+            tolerance: 8.0,
+            show_lines: true,
+        }
+    }
+}
+
+impl Snapping {
+    /// Snap to a grid of square cells, and to the edges of other things.
+    pub fn grid(size: f32) -> Self {
+        Self::default().with_grid(Vec2::splat(size))
+    }
+
+    /// Snap positions to a grid with cells of this size.
+    #[inline]
+    pub fn with_grid(mut self, size: impl Into<Vec2>) -> Self {
+        self.grid = Some(size.into());
+        self
+    }
+
+    /// Where the lines of the grid cross.
+    ///
+    /// Default: `(0, 0)`.
+    #[inline]
+    pub fn with_grid_origin(mut self, origin: Pos2) -> Self {
+        self.grid_origin = origin;
+        self
+    }
+
+    /// Snap rotations to multiples of this many radians, e.g. `15_f32.to_radians()`.
+    #[inline]
+    pub fn with_angle(mut self, step: f32) -> Self {
+        self.angle = Some(step);
+        self
+    }
+
+    /// Snap to the edges and centers of other things within this many points.
+    ///
+    /// Default: `8.0`.
+    #[inline]
+    pub fn with_tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Show lines where things snapped to the edges of other things.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn with_lines(mut self, show_lines: bool) -> Self {
+        self.show_lines = show_lines;
+        self
+    }
+
+    /// Is snapping suspended, because the user is holding alt?
+    pub fn is_suspended(ctx: &Context) -> bool {
+        ctx.input(|i| i.modifiers.alt)
+    }
+
+    /// Snap `angle`, in radians, to a multiple of [`Self::angle`].
+    pub fn snap_angle(&self, angle: f32) -> f32 {
+        match self.angle {
+            Some(step) if 0.0 < step => (angle / step).round() * step,
+            _ => angle,
+        }
+    }
+
+    /// Snap a point to the edges and centers of the `targets`, or else to the grid.
+    pub fn snap_pos(&self, pos: Pos2, targets: &[Rect]) -> Snapped<Pos2> {
+        let snapped = self.snap_rect(Rect::from_min_max(pos, pos), targets);
+        Snapped {
+            value: snapped.value.min,
+            lines: snapped.lines,
+        }
+    }
+
+    /// Move `rect` so that an edge or its center lines up with
+    /// an edge or the center of one of the `targets`, or else snap its corner to the grid.
+    ///
+    /// Each axis snaps separately.
+    pub fn snap_rect(&self, rect: Rect, targets: &[Rect]) -> Snapped<Rect> {
+        let mut offset = Vec2::ZERO;
+        let mut lines = vec![];
+
+        for axis in 0..2 {
+            let along = |r: &Rect| [r.min[axis], r.center()[axis], r.max[axis]];
+
+            let closest = targets
+                .iter()
+                .flat_map(|target| along(target).map(|line| (target, line)))
+                .flat_map(|(target, line)| {
+                    along(&rect).map(move |edge| (target, line, line - edge))
+                })
+                .filter(|(_, _, delta)| delta.abs() <= self.tolerance)
+                .min_by(|a, b| a.2.abs().total_cmp(&b.2.abs()));
+
+            if let Some((target, line, delta)) = closest {
+                offset[axis] = delta;
+                if self.show_lines {
+                    // Along the other axis, from one to the other:
+                    let other = 1 - axis;
+                    let moved = rect.translate(offset);
+                    let from = moved.min[other].min(target.min[other]);
+                    let to = moved.max[other].max(target.max[other]);
+                    lines.push(if axis == 0 {
+                        [pos2(line, from), pos2(line, to)]
+                    } else {
+                        [pos2(from, line), pos2(to, line)]
+                    });
+                }
+            } else if let Some(grid) = self.grid {
+                if 0.0 < grid[axis] {
+                    let origin = self.grid_origin[axis];
+                    let min = rect.min[axis] - origin;
+                    offset[axis] = (min / grid[axis]).round() * grid[axis] - min;
+                }
+            }
+        }
+
+        Snapped {
+            value: rect.translate(offset),
+            lines,
+        }
+    }
+
+    /// Move `rect` along with the drag of `response`, snapping it.
+    ///
+    /// Call this each frame with the current `rect`, and use the returned one from then on.
+    /// The unsnapped position is remembered while dragging, so things can be dragged away
+    /// from where they snapped, and the feedback lines are painted on top of everything.
+    ///
+    /// [`Self::tolerance`] is in points on screen, also in a zoomed [`crate::Scene`].
+    pub fn drag_rect(&self, response: &Response, rect: Rect, targets: &[Rect]) -> Rect {
+        let ctx = &response.ctx;
+        let id = response.id.with("snapping");
+
+        if !response.dragged() {
+            ctx.data_mut(|d| d.remove::<Rect>(id));
+            return rect;
+        }
+
+        let unsnapped = if response.drag_started() {
+            rect
+        } else {
+            ctx.data(|d| d.get_temp(id)).unwrap_or(rect)
+        }
+        .translate(response.drag_delta());
+        ctx.data_mut(|d| d.insert_temp(id, unsnapped));
+
+        if Self::is_suspended(ctx) {
+            return unsnapped;
+        }
+
+        let snapped = self
+            .in_layer(ctx, response.layer_id)
+            .snap_rect(unsnapped, targets);
+        snapped.paint_lines(ctx, response.layer_id);
+        snapped.value
+    }
+
+    /// With the [`Self::tolerance`] in the coordinates of the given layer,
+    /// so that it stays the same on screen in a zoomed [`crate::Scene`].
+    pub(crate) fn in_layer(&self, ctx: &Context, layer_id: LayerId) -> Self {
+        let to_global = ctx.layer_transform_to_global(layer_id).unwrap_or_default();
+        Self {
+            tolerance: self.tolerance / to_global.scaling,
+            ..*self
+        }
+    }
+}
+
+/// The result of snapping something with [`Snapping`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Snapped<T> {
+    /// The snapped position, rectangle or the like.
+    pub value: T,
+
+    /// Lines showing what was snapped to, in the same coordinates as the value.
+    pub lines: Vec<[Pos2; 2]>,
+}
+
+impl<T> Snapped<T> {
+    /// Did it snap to the edges of other things?
+    pub fn snapped_to_edges(&self) -> bool {
+        !self.lines.is_empty()
+    }
+
+    /// Paint [`Self::lines`] on top of everything, for the one frame.
+    ///
+    /// `layer_id` is the layer the lines are in the coordinates of,
+    /// e.g. of a [`crate::Scene`].
+    pub fn paint_lines(&self, ctx: &Context, layer_id: LayerId) {
+        if self.lines.is_empty() {
+            return;
+        }
+        let to_global = ctx.layer_transform_to_global(layer_id).unwrap_or_default();
+        let painter = ctx.layer_painter(LayerId::new(Order::Foreground, Id::new("snapping_lines")));
+        let stroke = Stroke::new(1.0, ctx.style().visuals.selection.stroke.color);
+        for [a, b] in &self.lines {
+            painter.line_segment([to_global * *a, to_global * *b], stroke);
+        }
+    }
+}
+
+#[test]
+fn test_snapping() {
+    use emath::vec2;
+
+    let snapping = Snapping::grid(10.0).with_tolerance(5.0);
+    let target = Rect::from_min_size(pos2(100.0, 100.0), vec2(50.0, 50.0));
+
+    // Edges come before the grid:
+    let rect = Rect::from_min_size(pos2(153.0, 12.0), vec2(20.0, 20.0));
+    let snapped = snapping.snap_rect(rect, &[target]);
+    assert_eq!(snapped.value.min, pos2(150.0, 10.0), "Right of the target, on the grid");
+    assert_eq!(snapped.lines, vec![[pos2(150.0, 10.0), pos2(150.0, 150.0)]]);
+
+    // Centers line up:
+    let rect = Rect::from_min_size(pos2(0.0, 118.0), vec2(10.0, 10.0));
+    let snapped = snapping.snap_rect(rect, &[target]);
+    assert_eq!(snapped.value.center().y, 125.0);
+    assert!(snapped.snapped_to_edges());
+
+    assert_eq!(snapping.snap_pos(pos2(14.0, 16.0), &[]).value, pos2(10.0, 20.0));
+    assert_eq!(Snapping::default().snap_pos(pos2(14.0, 16.0), &[]).value, pos2(14.0, 16.0));
+
+    let snapping = snapping.with_angle(15_f32.to_radians());
+    assert!((snapping.snap_angle(0.3) - 15_f32.to_radians()).abs() < 1e-6);
+}
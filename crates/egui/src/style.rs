@@ -7,7 +7,7 @@ use epaint::{AlphaFromCoverage, CornerRadius, Shadow, Stroke, text::FontTweak};
 use std::{collections::BTreeMap, ops::RangeInclusive, sync::Arc};
 
 use crate::{
-    ComboBox, CursorIcon, FontFamily, FontId, Grid, Margin, Response, RichText, TextWrapMode,
+    ComboBox, Context, CursorIcon, FontFamily, FontId, Grid, Margin, Response, RichText, TextWrapMode,
     WidgetText,
     ecolor::Color32,
     emath::{Rangef, Rect, Vec2, pos2, vec2},
@@ -61,6 +61,67 @@ impl PartialEq for NumberFormatter {
 
 // ----------------------------------------------------------------------------
 
+/// Something happened in the ui that an app may want to play a sound for.
+///
+/// See [`Interaction::sound_hooks`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum SoundEvent {
+    /// A button was clicked.
+    Click,
+
+    /// A checkbox or radio button changed.
+    Toggle,
+
+    /// Something went wrong, e.g. invalid input.
+    Error,
+
+    /// Something needs the attention of the user.
+    Notification,
+}
+
+/// Plays sounds for [`SoundEvent`]s, see [`Interaction::sound_hooks`].
+#[derive(Clone)]
+pub struct SoundHooks(Arc<dyn 'static + Sync + Send + Fn(&Context, SoundEvent)>);
+
+impl SoundHooks {
+    #[inline]
+    pub fn new(hooks: impl 'static + Sync + Send + Fn(&Context, SoundEvent)) -> Self {
+        Self(Arc::new(hooks))
+    }
+
+    /// Play the system alert sound for [`SoundEvent::Error`] and [`SoundEvent::Notification`],
+    /// and nothing for the other events.
+    ///
+    /// See [`Context::beep`].
+    pub fn system_beep() -> Self {
+        Self::new(|ctx, event| match event {
+            SoundEvent::Error | SoundEvent::Notification => ctx.beep(),
+            SoundEvent::Click | SoundEvent::Toggle => {}
+        })
+    }
+
+    #[inline]
+    pub fn play(&self, ctx: &Context, event: SoundEvent) {
+        (self.0)(ctx, event);
+    }
+}
+
+impl std::fmt::Debug for SoundHooks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SoundHooks")
+    }
+}
+
+impl PartialEq for SoundHooks {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// Alias for a [`FontId`] (font of a certain size).
 ///
 /// The font is found via look-up in [`Style::text_styles`].
@@ -336,6 +397,16 @@ pub struct Style {
 
     /// Use a more compact style for menus.
     pub compact_menu_style: bool,
+
+    /// Skip widgets added with [`Ui::add`] that are outside the clip rect.
+    ///
+    /// This speeds up long lists that aren't virtualized
+    /// (i.e. that don't use [`crate::ScrollArea::show_rows`]).
+    /// A skipped widget is assumed to have the same size as the last time it was shown,
+    /// and is neither painted nor registered for interaction.
+    ///
+    /// Widgets can opt out with [`crate::Widget::allow_culling`].
+    pub cull_offscreen_widgets: bool,
 }
 
 #[test]
@@ -344,6 +415,18 @@ fn style_impl_send_sync() {
     assert_send_sync::<Style>();
 }
 
+#[test]
+fn style_lerp_end_points() {
+    let dark = crate::Theme::Dark.default_style();
+    let light = crate::Theme::Light.default_style();
+    assert_eq!(Style::lerp(&dark, &light, 0.0), dark);
+    assert_eq!(Style::lerp(&dark, &light, 1.0), light);
+
+    let halfway = Visuals::lerp(&dark.visuals, &light.visuals, 0.5);
+    assert_ne!(halfway.panel_fill, dark.visuals.panel_fill);
+    assert_ne!(halfway.panel_fill, light.visuals.panel_fill);
+}
+
 impl Style {
     // TODO(emilk): rename style.interact() to maybe… `style.interactive` ?
     /// Use this style for interactive things.
@@ -457,6 +540,10 @@ pub struct Spacing {
 
     /// Controls the spacing of a [`crate::ScrollArea`].
     pub scroll: ScrollStyle,
+
+    /// The widths at which [`Ui::breakpoint`] and [`crate::Context::breakpoint`]
+    /// switch between [`SizeClass`]es.
+    pub breakpoints: Breakpoints,
 }
 
 impl Spacing {
@@ -566,6 +653,30 @@ pub struct ScrollStyle {
     /// This is only for floating scroll bars.
     /// Solid scroll bars are always opaque.
     pub interact_handle_opacity: f32,
+
+    /// What to show when the user tries to scroll past the end of the content,
+    /// e.g. by dragging it on a touch screen or with a trackpad.
+    ///
+    /// See [`crate::scroll_area::State::overscroll`] for implementing pull-to-refresh.
+    pub overscroll_effect: OverscrollEffect,
+
+    /// How far (in points) the content can be pulled past its end.
+    pub max_overscroll: f32,
+}
+
+/// Feedback when scrolling past the end of a [`crate::ScrollArea`], see [`ScrollStyle::overscroll_effect`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum OverscrollEffect {
+    /// No feedback: the content just stops at the end.
+    #[default]
+    None,
+
+    /// Paint a glow at the edge the user is pulling on.
+    Glow,
+
+    /// Stretch the content past the edge, and bounce back when the user lets go.
+    Stretch,
 }
 
 impl Default for ScrollStyle {
@@ -574,6 +685,95 @@ impl Default for ScrollStyle {
     }
 }
 
+// ----------------------------------------------------------------------------
+
+/// How much horizontal space there is, as a coarse class.
+///
+/// Use this to adapt your layout to the window size,
+/// e.g. show a side panel on a desktop, but a bottom panel on a phone.
+///
+/// See [`Ui::breakpoint`], [`crate::Context::breakpoint`] and [`Breakpoints`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum SizeClass {
+    /// Phone-sized, e.g. a single column of content.
+    #[default]
+    Compact,
+
+    /// Tablet-sized, or a narrow desktop window.
+    Medium,
+
+    /// Desktop-sized, with room for side panels.
+    Expanded,
+}
+
+impl SizeClass {
+    #[inline]
+    pub fn is_compact(self) -> bool {
+        self == Self::Compact
+    }
+
+    #[inline]
+    pub fn is_expanded(self) -> bool {
+        self == Self::Expanded
+    }
+}
+
+/// The widths (in points) at which the [`SizeClass`] changes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Breakpoints {
+    /// Narrower than this is [`SizeClass::Compact`].
+    pub medium: f32,
+
+    /// At least this wide is [`SizeClass::Expanded`].
+    pub expanded: f32,
+
+    /// The width has to move this far past a breakpoint before the [`SizeClass`] changes.
+    ///
+    /// This prevents the layout from flickering back and forth
+    /// when the user resizes the window around a breakpoint.
+    pub hysteresis: f32,
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self {
+            medium: 600.0,
+            expanded: 840.0,
+            hysteresis: 16.0,
+        }
+    }
+}
+
+impl Breakpoints {
+    /// The [`SizeClass`] of the given width, ignoring [`Self::hysteresis`].
+    pub fn size_class(&self, width: f32) -> SizeClass {
+        if width < self.medium {
+            SizeClass::Compact
+        } else if width < self.expanded {
+            SizeClass::Medium
+        } else {
+            SizeClass::Expanded
+        }
+    }
+
+    /// The [`SizeClass`] of the given width, sticking to `previous`
+    /// until the width has moved half the [`Self::hysteresis`] past a breakpoint.
+    pub fn size_class_with_hysteresis(&self, width: f32, previous: SizeClass) -> SizeClass {
+        let margin = 0.5 * self.hysteresis;
+        let size_class = self.size_class(width);
+        if size_class > previous {
+            self.size_class(width - margin).max(previous)
+        } else if size_class < previous {
+            self.size_class(width + margin).min(previous)
+        } else {
+            size_class
+        }
+    }
+}
+
 impl ScrollStyle {
     /// Solid scroll bars that always use up space
     pub fn solid() -> Self {
@@ -595,6 +795,9 @@ impl ScrollStyle {
             dormant_handle_opacity: 0.0,
             active_handle_opacity: 0.6,
             interact_handle_opacity: 1.0,
+
+            overscroll_effect: OverscrollEffect::None,
+            max_overscroll: 64.0,
         }
     }
 
@@ -675,6 +878,9 @@ impl ScrollStyle {
             dormant_handle_opacity,
             active_handle_opacity,
             interact_handle_opacity,
+
+            overscroll_effect,
+            max_overscroll,
         } = self;
 
         ui.horizontal(|ui| {
@@ -713,6 +919,19 @@ impl ScrollStyle {
             ui.selectable_value(foreground_color, true, "Foreground");
         });
 
+        ui.horizontal(|ui| {
+            ui.label("Overscroll:");
+            ui.selectable_value(overscroll_effect, OverscrollEffect::None, "None");
+            ui.selectable_value(overscroll_effect, OverscrollEffect::Glow, "Glow");
+            ui.selectable_value(overscroll_effect, OverscrollEffect::Stretch, "Stretch");
+        });
+        if *overscroll_effect != OverscrollEffect::None {
+            ui.horizontal(|ui| {
+                ui.add(DragValue::new(max_overscroll).range(0.0..=256.0));
+                ui.label("Max overscroll");
+            });
+        }
+
         if *floating {
             crate::Grid::new("opacity").show(ui, |ui| {
                 fn opacity_ui(ui: &mut Ui, opacity: &mut f32) {
@@ -869,6 +1088,18 @@ pub struct Interaction {
     /// The default is `true`, but text selection can be slightly glitchy,
     /// so you may want to disable it.
     pub multi_widget_text_select: bool,
+
+    /// Should widgets give haptic feedback, e.g. when a [`crate::Slider`] snaps to the next step?
+    ///
+    /// Useful for touch and gamepad-centric apps. See [`crate::Context::haptic_feedback`].
+    pub haptic_feedback: bool,
+
+    /// Called when e.g. a button is clicked or a checkbox toggled, so you can play ui sounds.
+    ///
+    /// Use [`SoundHooks::system_beep`] to beep on errors and notifications.
+    /// Use [`crate::Ui::play_sound`] to report events from your own widgets.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub sound_hooks: Option<SoundHooks>,
 }
 
 /// Look and feel of the text cursor.
@@ -1110,6 +1341,329 @@ impl Visuals {
     pub fn gray_out(&self, color: Color32) -> Color32 {
         crate::ecolor::tint_color_towards(color, self.widgets.noninteractive.weak_bg_fill)
     }
+
+    /// Check the foreground/background color pairs of these visuals against the
+    /// [WCAG 2.1](https://www.w3.org/TR/WCAG21/#contrast-minimum) contrast requirements.
+    ///
+    /// Returns one warning per pair with too little contrast,
+    /// so an empty result means the visuals pass.
+    ///
+    /// See also [`DebugOptions::show_contrast_warnings`] for highlighting the offending widgets.
+    pub fn validate_contrast(&self) -> Vec<ContrastWarning> {
+        let mut warnings = Vec::new();
+
+        let mut check = |description: &str, fg: Color32, bg: Color32, required_ratio: f32| {
+            let (fg, bg, ratio) = self.contrast_on_panel(fg, bg);
+            if ratio < required_ratio {
+                warnings.push(ContrastWarning {
+                    description: description.to_owned(),
+                    foreground: fg,
+                    background: bg,
+                    ratio,
+                    required_ratio,
+                });
+            }
+        };
+
+        for (state, widget) in self.widgets.named() {
+            check(
+                &format!("{state} widget text"),
+                widget.fg_stroke.color,
+                widget.weak_bg_fill,
+                ContrastWarning::TEXT,
+            );
+        }
+
+        check(
+            "text on panel",
+            self.text_color(),
+            self.panel_fill,
+            ContrastWarning::TEXT,
+        );
+        check(
+            "text on window",
+            self.text_color(),
+            self.window_fill,
+            ContrastWarning::TEXT,
+        );
+        check(
+            "text edit text",
+            self.text_color(),
+            self.text_edit_bg_color(),
+            ContrastWarning::TEXT,
+        );
+        check(
+            "weak text on panel",
+            self.weak_text_color(),
+            self.panel_fill,
+            ContrastWarning::LARGE_TEXT,
+        );
+        check(
+            "selected text",
+            self.selection.stroke.color,
+            self.selection.bg_fill,
+            ContrastWarning::TEXT,
+        );
+        check(
+            "hyperlink",
+            self.hyperlink_color,
+            self.panel_fill,
+            ContrastWarning::TEXT,
+        );
+        check(
+            "warning text",
+            self.warn_fg_color,
+            self.panel_fill,
+            ContrastWarning::TEXT,
+        );
+        check(
+            "error text",
+            self.error_fg_color,
+            self.panel_fill,
+            ContrastWarning::TEXT,
+        );
+
+        warnings
+    }
+
+    /// Blend the colors onto the panel background (as they would be shown)
+    /// and return them together with their contrast ratio.
+    pub(crate) fn contrast_on_panel(&self, fg: Color32, bg: Color32) -> (Color32, Color32, f32) {
+        let bg = self.panel_fill.blend(bg);
+        let fg = bg.blend(fg);
+        (fg, bg, fg.contrast_ratio(bg))
+    }
+}
+
+/// A foreground/background color pair with too little contrast.
+///
+/// See [`Visuals::validate_contrast`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContrastWarning {
+    /// What the colors are used for, e.g. `"hovered widget text"`.
+    pub description: String,
+
+    /// The foreground color, blended onto the background.
+    pub foreground: Color32,
+
+    /// The background color, blended onto [`Visuals::panel_fill`].
+    pub background: Color32,
+
+    /// The actual contrast ratio, in the range 1-21.
+    pub ratio: f32,
+
+    /// The contrast ratio required for this pair.
+    pub required_ratio: f32,
+}
+
+impl ContrastWarning {
+    /// Minimum contrast ratio for normal text (WCAG 2.1 level AA).
+    pub const TEXT: f32 = 4.5;
+
+    /// Minimum contrast ratio for large text and de-emphasized text (WCAG 2.1 level AA).
+    pub const LARGE_TEXT: f32 = 3.0;
+}
+
+impl std::fmt::Display for ContrastWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: contrast ratio {:.2} is below {:.1} ({} on {})",
+            self.description,
+            self.ratio,
+            self.required_ratio,
+            self.foreground.to_hex(),
+            self.background.to_hex(),
+        )
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Interpolation, used for animated theme transitions.
+
+fn lerp_f32(a: f32, b: f32, t: f32) -> f32 {
+    emath::lerp(a..=b, t)
+}
+
+fn lerp_vec2(a: Vec2, b: Vec2, t: f32) -> Vec2 {
+    vec2(lerp_f32(a.x, b.x, t), lerp_f32(a.y, b.y, t))
+}
+
+fn lerp_u8(a: u8, b: u8, t: f32) -> u8 {
+    lerp_f32(a as f32, b as f32, t).round() as u8
+}
+
+fn lerp_i8(a: i8, b: i8, t: f32) -> i8 {
+    lerp_f32(a as f32, b as f32, t).round() as i8
+}
+
+fn lerp_color(a: Color32, b: Color32, t: f32) -> Color32 {
+    a.lerp_to_gamma(b, t)
+}
+
+fn lerp_opt_color(a: Option<Color32>, b: Option<Color32>, t: f32) -> Option<Color32> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(lerp_color(a, b, t)),
+        _ => pick(&a, &b, t),
+    }
+}
+
+fn lerp_stroke(a: Stroke, b: Stroke, t: f32) -> Stroke {
+    Stroke::new(lerp_f32(a.width, b.width, t), lerp_color(a.color, b.color, t))
+}
+
+fn lerp_corner_radius(a: CornerRadius, b: CornerRadius, t: f32) -> CornerRadius {
+    CornerRadius {
+        nw: lerp_u8(a.nw, b.nw, t),
+        ne: lerp_u8(a.ne, b.ne, t),
+        sw: lerp_u8(a.sw, b.sw, t),
+        se: lerp_u8(a.se, b.se, t),
+    }
+}
+
+fn lerp_shadow(a: Shadow, b: Shadow, t: f32) -> Shadow {
+    Shadow {
+        offset: [
+            lerp_i8(a.offset[0], b.offset[0], t),
+            lerp_i8(a.offset[1], b.offset[1], t),
+        ],
+        blur: lerp_u8(a.blur, b.blur, t),
+        spread: lerp_u8(a.spread, b.spread, t),
+        color: lerp_color(a.color, b.color, t),
+    }
+}
+
+fn lerp_margin(a: Margin, b: Margin, t: f32) -> Margin {
+    Margin {
+        left: lerp_i8(a.left, b.left, t),
+        right: lerp_i8(a.right, b.right, t),
+        top: lerp_i8(a.top, b.top, t),
+        bottom: lerp_i8(a.bottom, b.bottom, t),
+    }
+}
+
+/// For things that cannot be interpolated: switch half-way.
+fn pick<T: Clone>(a: &T, b: &T, t: f32) -> T {
+    if t < 0.5 { a.clone() } else { b.clone() }
+}
+
+impl WidgetVisuals {
+    /// Interpolate between two widget visuals. `t = 0` gives `a`, `t = 1` gives `b`.
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            bg_fill: lerp_color(a.bg_fill, b.bg_fill, t),
+            weak_bg_fill: lerp_color(a.weak_bg_fill, b.weak_bg_fill, t),
+            bg_stroke: lerp_stroke(a.bg_stroke, b.bg_stroke, t),
+            corner_radius: lerp_corner_radius(a.corner_radius, b.corner_radius, t),
+            fg_stroke: lerp_stroke(a.fg_stroke, b.fg_stroke, t),
+            expansion: lerp_f32(a.expansion, b.expansion, t),
+        }
+    }
+}
+
+impl Widgets {
+    /// Interpolate between two sets of widget visuals. `t = 0` gives `a`, `t = 1` gives `b`.
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            noninteractive: WidgetVisuals::lerp(&a.noninteractive, &b.noninteractive, t),
+            inactive: WidgetVisuals::lerp(&a.inactive, &b.inactive, t),
+            hovered: WidgetVisuals::lerp(&a.hovered, &b.hovered, t),
+            active: WidgetVisuals::lerp(&a.active, &b.active, t),
+            open: WidgetVisuals::lerp(&a.open, &b.open, t),
+        }
+    }
+}
+
+impl Visuals {
+    /// Interpolate between two visuals. `t = 0` gives `a`, `t = 1` gives `b`.
+    ///
+    /// Colors, strokes, corner radii, shadows and sizes are interpolated.
+    /// Settings that cannot be interpolated (like [`Self::dark_mode`]) switch at `t = 0.5`.
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        Self {
+            override_text_color: lerp_opt_color(a.override_text_color, b.override_text_color, t),
+            weak_text_alpha: lerp_f32(a.weak_text_alpha, b.weak_text_alpha, t),
+            weak_text_color: lerp_opt_color(a.weak_text_color, b.weak_text_color, t),
+            widgets: Widgets::lerp(&a.widgets, &b.widgets, t),
+            selection: Selection {
+                bg_fill: lerp_color(a.selection.bg_fill, b.selection.bg_fill, t),
+                stroke: lerp_stroke(a.selection.stroke, b.selection.stroke, t),
+            },
+            hyperlink_color: lerp_color(a.hyperlink_color, b.hyperlink_color, t),
+            faint_bg_color: lerp_color(a.faint_bg_color, b.faint_bg_color, t),
+            extreme_bg_color: lerp_color(a.extreme_bg_color, b.extreme_bg_color, t),
+            text_edit_bg_color: lerp_opt_color(a.text_edit_bg_color, b.text_edit_bg_color, t),
+            code_bg_color: lerp_color(a.code_bg_color, b.code_bg_color, t),
+            warn_fg_color: lerp_color(a.warn_fg_color, b.warn_fg_color, t),
+            error_fg_color: lerp_color(a.error_fg_color, b.error_fg_color, t),
+            window_corner_radius: lerp_corner_radius(
+                a.window_corner_radius,
+                b.window_corner_radius,
+                t,
+            ),
+            window_shadow: lerp_shadow(a.window_shadow, b.window_shadow, t),
+            window_fill: lerp_color(a.window_fill, b.window_fill, t),
+            window_stroke: lerp_stroke(a.window_stroke, b.window_stroke, t),
+            menu_corner_radius: lerp_corner_radius(a.menu_corner_radius, b.menu_corner_radius, t),
+            panel_fill: lerp_color(a.panel_fill, b.panel_fill, t),
+            popup_shadow: lerp_shadow(a.popup_shadow, b.popup_shadow, t),
+            resize_corner_size: lerp_f32(a.resize_corner_size, b.resize_corner_size, t),
+            text_cursor: TextCursorStyle {
+                stroke: lerp_stroke(a.text_cursor.stroke, b.text_cursor.stroke, t),
+                ..pick(&a.text_cursor, &b.text_cursor, t)
+            },
+            clip_rect_margin: lerp_f32(a.clip_rect_margin, b.clip_rect_margin, t),
+            disabled_alpha: lerp_f32(a.disabled_alpha, b.disabled_alpha, t),
+            ..pick(a, b, t)
+        }
+    }
+}
+
+impl Spacing {
+    /// Interpolate between two spacings. `t = 0` gives `a`, `t = 1` gives `b`.
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        Self {
+            item_spacing: lerp_vec2(a.item_spacing, b.item_spacing, t),
+            window_margin: lerp_margin(a.window_margin, b.window_margin, t),
+            button_padding: lerp_vec2(a.button_padding, b.button_padding, t),
+            menu_margin: lerp_margin(a.menu_margin, b.menu_margin, t),
+            indent: lerp_f32(a.indent, b.indent, t),
+            interact_size: lerp_vec2(a.interact_size, b.interact_size, t),
+            slider_width: lerp_f32(a.slider_width, b.slider_width, t),
+            slider_rail_height: lerp_f32(a.slider_rail_height, b.slider_rail_height, t),
+            combo_width: lerp_f32(a.combo_width, b.combo_width, t),
+            text_edit_width: lerp_f32(a.text_edit_width, b.text_edit_width, t),
+            icon_width: lerp_f32(a.icon_width, b.icon_width, t),
+            icon_width_inner: lerp_f32(a.icon_width_inner, b.icon_width_inner, t),
+            icon_spacing: lerp_f32(a.icon_spacing, b.icon_spacing, t),
+            default_area_size: lerp_vec2(a.default_area_size, b.default_area_size, t),
+            tooltip_width: lerp_f32(a.tooltip_width, b.tooltip_width, t),
+            menu_width: lerp_f32(a.menu_width, b.menu_width, t),
+            menu_spacing: lerp_f32(a.menu_spacing, b.menu_spacing, t),
+            combo_height: lerp_f32(a.combo_height, b.combo_height, t),
+            ..pick(a, b, t)
+        }
+    }
+}
+
+impl Style {
+    /// Interpolate between two styles. `t = 0` gives `a`, `t = 1` gives `b`.
+    ///
+    /// [`Self::visuals`], [`Self::spacing`] and [`Self::animation_time`] are interpolated.
+    /// Everything else (fonts, interaction settings, …) switches at `t = 0.5`.
+    ///
+    /// See also [`crate::Context::set_theme_animated`].
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        Self {
+            spacing: Spacing::lerp(&a.spacing, &b.spacing, t),
+            visuals: Visuals::lerp(&a.visuals, &b.visuals, t),
+            animation_time: lerp_f32(a.animation_time, b.animation_time, t),
+            ..pick(a, b, t)
+        }
+    }
 }
 
 /// Selected text, selected elements etc
@@ -1162,6 +1716,17 @@ pub struct Widgets {
 }
 
 impl Widgets {
+    /// All the widget states, with a human-readable name each.
+    pub fn named(&self) -> [(&'static str, &WidgetVisuals); 5] {
+        [
+            ("noninteractive", &self.noninteractive),
+            ("inactive", &self.inactive),
+            ("hovered", &self.hovered),
+            ("active", &self.active),
+            ("open", &self.open),
+        ]
+    }
+
     pub fn style(&self, response: &Response) -> &WidgetVisuals {
         if !response.sense.interactive() {
             &self.noninteractive
@@ -1267,6 +1832,19 @@ pub struct DebugOptions {
     ///
     /// See [`emath::GuiRounding`] for more.
     pub show_unaligned: bool,
+
+    /// Highlight widgets whose text has too little contrast against their background.
+    ///
+    /// See [`Visuals::validate_contrast`].
+    pub show_contrast_warnings: bool,
+
+    /// Highlight widgets that didn't fit in the space they were given,
+    /// i.e. that are clipped or have truncated text.
+    ///
+    /// Contents of scroll areas are not considered clipped.
+    /// The offending widgets are also listed in [`crate::Context::inspection_ui`],
+    /// with where they were added if the `callstack` feature is enabled.
+    pub show_overflow: bool,
 }
 
 #[cfg(debug_assertions)]
@@ -1283,6 +1861,8 @@ impl Default for DebugOptions {
             show_interactive_widgets: false,
             show_widget_hits: false,
             show_unaligned: cfg!(debug_assertions),
+            show_contrast_warnings: false,
+            show_overflow: false,
         }
     }
 }
@@ -1326,6 +1906,7 @@ impl Default for Style {
             always_scroll_the_only_direction: false,
             scroll_animation: ScrollAnimation::default(),
             compact_menu_style: true,
+            cull_offscreen_widgets: false,
         }
     }
 }
@@ -1352,6 +1933,7 @@ impl Default for Spacing {
             menu_spacing: 2.0,
             combo_height: 200.0,
             scroll: Default::default(),
+            breakpoints: Default::default(),
             indent_ends_with_horizontal_line: false,
         }
     }
@@ -1368,6 +1950,8 @@ impl Default for Interaction {
             tooltip_grace_time: 0.2,
             selectable_labels: true,
             multi_widget_text_select: true,
+            haptic_feedback: false,
+            sound_hooks: None,
         }
     }
 }
@@ -1634,6 +2218,7 @@ impl Style {
             always_scroll_the_only_direction,
             scroll_animation,
             compact_menu_style,
+            cull_offscreen_widgets,
         } = self;
 
         crate::Grid::new("_options").show(ui, |ui| {
@@ -1753,6 +2338,9 @@ impl Style {
                 "If scrolling is enabled for only one direction, allow horizontal scrolling without pressing shift",
             );
 
+        ui.checkbox(cull_offscreen_widgets, "Skip widgets outside the clip rect")
+            .on_hover_text("Speeds up long lists that don't use ScrollArea::show_rows");
+
         ui.vertical_centered(|ui| reset_button(ui, self, "Reset style"));
     }
 }
@@ -1794,6 +2382,7 @@ impl Spacing {
             indent_ends_with_horizontal_line,
             combo_height,
             scroll,
+            breakpoints,
         } = self;
 
         Grid::new("spacing")
@@ -1859,6 +2448,19 @@ impl Spacing {
                 ui.add(DragValue::new(menu_spacing).range(0.0..=10.0));
                 ui.end_row();
 
+                ui.label("Breakpoints")
+                    .on_hover_text("Widths for medium and expanded layouts");
+                ui.horizontal(|ui| {
+                    ui.add(DragValue::new(&mut breakpoints.medium).range(0.0..=4000.0));
+                    ui.add(DragValue::new(&mut breakpoints.expanded).range(0.0..=4000.0));
+                    ui.add(
+                        DragValue::new(&mut breakpoints.hysteresis)
+                            .prefix("hysteresis: ")
+                            .range(0.0..=100.0),
+                    );
+                });
+                ui.end_row();
+
                 ui.label("Checkboxes etc");
                 ui.vertical(|ui| {
                     ui.add(
@@ -1909,6 +2511,8 @@ impl Interaction {
             tooltip_grace_time,
             selectable_labels,
             multi_widget_text_select,
+            haptic_feedback,
+            sound_hooks: _, // can't change callbacks in the UI
         } = self;
 
         ui.spacing_mut().item_spacing = vec2(12.0, 8.0);
@@ -1965,6 +2569,9 @@ impl Interaction {
             }
         });
 
+        ui.checkbox(haptic_feedback, "Haptic feedback")
+            .on_hover_text("Give haptic feedback, e.g. when a slider snaps to the next step");
+
         ui.vertical_centered(|ui| reset_button(ui, self, "Reset interaction settings"));
     }
 }
@@ -2426,6 +3033,8 @@ impl DebugOptions {
             show_interactive_widgets,
             show_widget_hits,
             show_unaligned,
+            show_contrast_warnings,
+            show_overflow,
         } = self;
 
         {
@@ -2460,6 +3069,13 @@ impl DebugOptions {
             "Show rectangles not aligned to integer point coordinates",
         );
 
+        ui.checkbox(
+            show_contrast_warnings,
+            "Show widgets with too little text contrast",
+        );
+
+        ui.checkbox(show_overflow, "Show clipped widgets and truncated text");
+
         ui.vertical_centered(|ui| reset_button(ui, self, "Reset debug options"));
     }
 }
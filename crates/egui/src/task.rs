@@ -0,0 +1,161 @@
+//! Running async code in the background, and showing its result when it is done.
+//!
+//! See [`crate::Context::spawn_async`] and [`crate::Ui::suspense`].
+
+use std::{pin::Pin, sync::mpsc};
+
+/// [`Send`] on native, where tasks run on other threads,
+/// but nothing on the web, where they run on the main thread.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSend: Send {}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send> MaybeSend for T {}
+
+/// [`Send`] on native, where tasks run on other threads,
+/// but nothing on the web, where they run on the main thread.
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSend {}
+
+#[cfg(target_arch = "wasm32")]
+impl<T> MaybeSend for T {}
+
+/// A future given to a spawner set with [`crate::Context::set_task_spawner`].
+#[cfg(not(target_arch = "wasm32"))]
+pub type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+/// A future given to a spawner set with [`crate::Context::set_task_spawner`].
+#[cfg(target_arch = "wasm32")]
+pub type BoxFuture = Pin<Box<dyn Future<Output = ()> + 'static>>;
+
+/// Runs the futures of [`crate::Context::spawn_async`].
+pub(crate) type Spawner = dyn Fn(BoxFuture) + Send + Sync;
+
+/// The result of a [`crate::Context::spawn_async`], which will be ready some time later.
+///
+/// Poll it each frame with [`Self::ready`], or show it with [`crate::Ui::suspense`].
+/// The [`crate::Context`] is repainted when the task is done.
+///
+/// Dropping the [`Task`] does not stop the future, but its result is thrown away.
+#[must_use = "The result of the task is thrown away when the Task is dropped"]
+pub struct Task<T> {
+    state: TaskState<T>,
+}
+
+enum TaskState<T> {
+    Pending(mpsc::Receiver<T>),
+    Ready(T),
+
+    /// The future panicked, or was dropped by the spawner.
+    Abandoned,
+}
+
+impl<T> Task<T> {
+    /// Create the [`Task`], and the sender to send its result with.
+    pub(crate) fn new() -> (mpsc::Sender<T>, Self) {
+        let (sender, receiver) = mpsc::channel();
+        let task = Self {
+            state: TaskState::Pending(receiver),
+        };
+        (sender, task)
+    }
+
+    /// A [`Task`] that is already done.
+    pub fn from_ready(value: T) -> Self {
+        Self {
+            state: TaskState::Ready(value),
+        }
+    }
+
+    fn poll(&mut self) {
+        if let TaskState::Pending(receiver) = &self.state {
+            match receiver.try_recv() {
+                Ok(value) => self.state = TaskState::Ready(value),
+                Err(mpsc::TryRecvError::Empty) => {}
+                Err(mpsc::TryRecvError::Disconnected) => self.state = TaskState::Abandoned,
+            }
+        }
+    }
+
+    /// The result, if the task is done.
+    pub fn ready(&mut self) -> Option<&T> {
+        self.ready_mut().map(|value| &*value)
+    }
+
+    /// The result, if the task is done.
+    pub fn ready_mut(&mut self) -> Option<&mut T> {
+        self.poll();
+        match &mut self.state {
+            TaskState::Ready(value) => Some(value),
+            TaskState::Pending(_) | TaskState::Abandoned => None,
+        }
+    }
+
+    /// Take the result if the task is done, otherwise get the [`Task`] back.
+    pub fn try_take(mut self) -> Result<T, Self> {
+        self.poll();
+        match self.state {
+            TaskState::Ready(value) => Ok(value),
+            state => Err(Self { state }),
+        }
+    }
+
+    /// Did the future panic, so that the task will never be done?
+    pub fn is_abandoned(&mut self) -> bool {
+        self.poll();
+        matches!(self.state, TaskState::Abandoned)
+    }
+}
+
+impl<T> std::fmt::Debug for Task<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = match self.state {
+            TaskState::Pending(_) => "Pending",
+            TaskState::Ready(_) => "Ready",
+            TaskState::Abandoned => "Abandoned",
+        };
+        f.debug_struct("Task").field("state", &state).finish()
+    }
+}
+
+/// The default spawner: run each future on its own thread.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn spawn_thread(future: BoxFuture) {
+    let result = std::thread::Builder::new()
+        .name("egui_task".to_owned())
+        .spawn(move || block_on(future));
+    if let Err(err) = result {
+        // The `Task` is abandoned when the future is dropped.
+        #[cfg(feature = "log")]
+        log::error!("Failed to spawn a thread for an egui task: {err}");
+        #[cfg(not(feature = "log"))]
+        let _ = err;
+    }
+}
+
+/// Poll the future on the current thread until it is done, sleeping while it is pending.
+#[cfg(not(target_arch = "wasm32"))]
+fn block_on<F: Future>(future: F) -> F::Output {
+    use std::{
+        sync::Arc,
+        task::{Poll, Wake, Waker},
+    };
+
+    struct ThreadWaker(std::thread::Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let mut future = std::pin::pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut cx = std::task::Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
@@ -1,10 +1,10 @@
-use std::sync::Arc;
+use std::{ops::Range, sync::Arc};
 
 use emath::TSTransform;
 
 use crate::{
-    Context, CursorIcon, Event, Galley, Id, LayerId, Pos2, Rect, Response, Ui, layers::ShapeIdx,
-    text::CCursor, text_selection::CCursorRange,
+    Context, CursorIcon, Event, Galley, Id, LayerId, Painter, Pos2, Rect, Response, Ui,
+    layers::ShapeIdx, text::CCursor, text_selection::CCursorRange,
 };
 
 use super::{
@@ -73,9 +73,11 @@ struct CurrentSelection {
     pub secondary: WidgetTextCursor,
 }
 
-/// Handles text selection in labels (NOT in [`crate::TextEdit`])s.
+/// Handles text selection in labels, and in [`crate::TextEdit`]s that can't be edited.
 ///
-/// One state for all labels, because we only support text selection in one label at a time.
+/// One state for all of them, because there is only one selection at a time,
+/// which may go on from one label or uneditable text edit into the next
+/// (see [`crate::style::Interaction::multi_widget_text_select`]).
 #[derive(Clone, Debug)]
 pub struct LabelSelectionState {
     /// The current selection, if any.
@@ -96,10 +98,22 @@ pub struct LabelSelectionState {
     /// Have we reached the widget containing the secondary selection?
     has_reached_secondary: bool,
 
-    /// Accumulated text to copy.
+    /// Accumulated selected text, copied on [`Event::Copy`].
     text_to_copy: String,
+
+    /// [`Self::text_to_copy`] with its formatting, as HTML.
+    html_to_copy: String,
     last_copied_galley_rect: Option<Rect>,
 
+    /// The widgets with selected text this pass, and which characters of them are selected.
+    selected_ranges: Vec<(Id, Range<usize>)>,
+
+    /// The selected text of the previous pass.
+    selected_text_last_pass: String,
+
+    /// The [`Self::selected_ranges`] of the previous pass.
+    selected_ranges_last_pass: Vec<(Id, Range<usize>)>,
+
     /// Painted selections this frame.
     ///
     /// Kept so we can undo a bad selection visualization if we don't see both ends of the selection this frame.
@@ -117,7 +131,11 @@ impl Default for LabelSelectionState {
             has_reached_primary: Default::default(),
             has_reached_secondary: Default::default(),
             text_to_copy: Default::default(),
+            html_to_copy: Default::default(),
             last_copied_galley_rect: Default::default(),
+            selected_ranges: Default::default(),
+            selected_text_last_pass: Default::default(),
+            selected_ranges_last_pass: Default::default(),
             painted_selections: Default::default(),
         }
     }
@@ -157,7 +175,9 @@ impl LabelSelectionState {
         state.has_reached_primary = false;
         state.has_reached_secondary = false;
         state.text_to_copy.clear();
+        state.html_to_copy.clear();
         state.last_copied_galley_rect = None;
+        state.selected_ranges.clear();
         state.painted_selections.clear();
 
         state.store(ctx);
@@ -223,13 +243,44 @@ impl LabelSelectionState {
         }
 
         let text_to_copy = std::mem::take(&mut state.text_to_copy);
-        if !text_to_copy.is_empty() {
-            ctx.copy_text(text_to_copy);
+        let html_to_copy = std::mem::take(&mut state.html_to_copy);
+        if !text_to_copy.is_empty() && got_copy_event(ctx) {
+            ctx.copy_html(html_to_copy, text_to_copy.clone());
+        }
+
+        if state.selection.is_some() {
+            state.selected_text_last_pass = text_to_copy;
+            state.selected_ranges_last_pass = std::mem::take(&mut state.selected_ranges);
+        } else {
+            state.selected_text_last_pass.clear();
+            state.selected_ranges_last_pass.clear();
         }
 
         state.store(ctx);
     }
 
+    /// The text selected in labels, as it would be copied,
+    /// or `None` if no text is selected.
+    ///
+    /// This is the selection as of the end of the previous pass,
+    /// e.g. for a "Search for …" item in a context menu.
+    pub fn selected_text(ctx: &Context) -> Option<String> {
+        let state = Self::load(ctx);
+        let has_selected_text = state.selected_ranges_last_pass.iter().any(|(_, r)| !r.is_empty());
+        has_selected_text.then_some(state.selected_text_last_pass)
+    }
+
+    /// The widgets with selected text, in the order they were shown,
+    /// with the range of characters selected in each of them.
+    ///
+    /// This is the selection as of the end of the previous pass,
+    /// e.g. to annotate the selected text.
+    pub fn selected_ranges(ctx: &Context) -> Vec<(Id, Range<usize>)> {
+        let mut ranges = Self::load(ctx).selected_ranges_last_pass;
+        ranges.retain(|(_, range)| !range.is_empty());
+        ranges
+    }
+
     pub fn has_selection(&self) -> bool {
         self.selection.is_some()
     }
@@ -243,15 +294,18 @@ impl LabelSelectionState {
         if new_text.is_empty() {
             return;
         }
+        let new_html = selected_html(galley, cursor_range);
 
         if self.text_to_copy.is_empty() {
             self.text_to_copy = new_text;
+            self.html_to_copy = new_html;
             self.last_copied_galley_rect = Some(new_galley_rect);
             return;
         }
 
         let Some(last_copied_galley_rect) = self.last_copied_galley_rect else {
             self.text_to_copy = new_text;
+            self.html_to_copy = new_html;
             self.last_copied_galley_rect = Some(new_galley_rect);
             return;
         };
@@ -261,9 +315,11 @@ impl LabelSelectionState {
 
         if last_copied_galley_rect.bottom() <= new_galley_rect.top() {
             self.text_to_copy.push('\n');
+            self.html_to_copy += "<br>";
             let vertical_distance = new_galley_rect.top() - last_copied_galley_rect.bottom();
             if estimate_row_height(galley) * 0.5 < vertical_distance {
                 self.text_to_copy.push('\n');
+                self.html_to_copy += "<br>";
             }
         } else {
             let existing_ends_with_space =
@@ -277,10 +333,12 @@ impl LabelSelectionState {
             if existing_ends_with_space == Some(false) && !new_text_starts_with_space_or_punctuation
             {
                 self.text_to_copy.push(' ');
+                self.html_to_copy.push(' ');
             }
         }
 
         self.text_to_copy.push_str(&new_text);
+        self.html_to_copy += &new_html;
         self.last_copied_galley_rect = Some(new_galley_rect);
     }
 
@@ -298,7 +356,7 @@ impl LabelSelectionState {
         underline: epaint::Stroke,
     ) {
         let mut state = Self::load(ui.ctx());
-        let new_vertex_indices = state.on_label(ui, response, galley_pos, &mut galley);
+        let (new_vertex_indices, _) = state.on_label(ui, response, galley_pos, &mut galley);
 
         let shape_idx = ui.painter().add(
             epaint::TextShape::new(galley_pos, galley, fallback_color).with_underline(underline),
@@ -313,6 +371,33 @@ impl LabelSelectionState {
         state.store(ui.ctx());
     }
 
+    /// Like [`Self::label_text_selection`], for the text of a [`crate::TextEdit`]
+    /// that can't be edited, so that a selection can go on from it into labels and back.
+    ///
+    /// Returns the selected part of the galley, if any.
+    pub(crate) fn text_edit_selection(
+        ui: &Ui,
+        painter: &Painter,
+        response: &Response,
+        galley_pos: Pos2,
+        galley: &mut Arc<Galley>,
+        text_color: epaint::Color32,
+    ) -> Option<CCursorRange> {
+        let mut state = Self::load(ui.ctx());
+        let (new_vertex_indices, cursor_range) = state.on_label(ui, response, galley_pos, galley);
+
+        let shape_idx = painter.add(epaint::TextShape::new(galley_pos, galley.clone(), text_color));
+
+        if !new_vertex_indices.is_empty() {
+            state
+                .painted_selections
+                .push((shape_idx, new_vertex_indices));
+        }
+
+        state.store(ui.ctx());
+        cursor_range
+    }
+
     fn cursor_for(
         &mut self,
         ui: &Ui,
@@ -499,14 +584,14 @@ impl LabelSelectionState {
         }
     }
 
-    /// Returns the painted selections, if any.
+    /// Returns the painted selections, if any, and the selected range of the galley.
     fn on_label(
         &mut self,
         ui: &Ui,
         response: &Response,
         galley_pos_in_layer: Pos2,
         galley: &mut Arc<Galley>,
-    ) -> Vec<RowVertexIndices> {
+    ) -> (Vec<RowVertexIndices>, Option<CCursorRange>) {
         let widget_id = response.id;
 
         let global_from_layer = ui
@@ -554,9 +639,8 @@ impl LabelSelectionState {
                 }
             }
 
-            if got_copy_event(ui.ctx()) {
-                self.copy_text(galley_rect, galley, &cursor_range);
-            }
+            self.copy_text(galley_rect, galley, &cursor_range);
+            self.selected_ranges.push((response.id, cursor_range.as_sorted_char_range()));
 
             cursor_state.set_char_range(Some(cursor_range));
         }
@@ -655,7 +739,7 @@ impl LabelSelectionState {
             galley,
         );
 
-        new_vertex_indices
+        (new_vertex_indices, cursor_range)
     }
 }
 
@@ -689,20 +773,32 @@ fn process_selection_key_events(
     changed
 }
 
-fn selected_text(galley: &Galley, cursor_range: &CCursorRange) -> String {
+/// Should we copy all of the galley?
+fn copies_everything(galley: &Galley, cursor_range: &CCursorRange) -> bool {
     // This logic means we can select everything in an elided label (including the `…`)
     // and still copy the entire un-elided text!
     let everything_is_selected = cursor_range.contains(CCursorRange::select_all(galley));
 
-    let copy_everything = cursor_range.is_empty() || everything_is_selected;
+    cursor_range.is_empty() || everything_is_selected
+}
 
-    if copy_everything {
+fn selected_text(galley: &Galley, cursor_range: &CCursorRange) -> String {
+    if copies_everything(galley, cursor_range) {
         galley.text().to_owned()
     } else {
         cursor_range.slice_str(galley).to_owned()
     }
 }
 
+/// [`selected_text`] with its formatting, as HTML.
+fn selected_html(galley: &Galley, cursor_range: &CCursorRange) -> String {
+    if copies_everything(galley, cursor_range) {
+        galley.job.to_html(0..galley.text().chars().count())
+    } else {
+        galley.job.to_html(cursor_range.as_sorted_char_range())
+    }
+}
+
 fn estimate_row_height(galley: &Galley) -> f32 {
     if let Some(placed_row) = galley.rows.first() {
         placed_row.height()
@@ -5,6 +5,7 @@ pub mod accesskit_text;
 
 mod cursor_range;
 mod label_text_selection;
+pub mod multi_cursor;
 pub mod text_cursor_state;
 pub mod visuals;
 
@@ -0,0 +1,177 @@
+//! Helpers for editing text at several cursors at once, as [`crate::TextEdit`] does.
+//!
+//! A set of cursors is a slice of [`CCursorRange`]s where the first one is the primary cursor,
+//! i.e. the one that is scrolled to and used for IME.
+
+use epaint::text::{Galley, cursor::CCursor};
+
+use crate::{Vec2, vec2};
+
+use super::{CCursorRange, text_cursor_state::byte_index_from_char_index};
+
+/// Merge the cursors that overlap or touch, so that each character is selected at most once.
+///
+/// The first cursor of the result is the primary one, possibly merged with others.
+/// The rest are sorted by their position in the text.
+pub fn merge_overlapping(cursors: &[CCursorRange]) -> Vec<CCursorRange> {
+    let Some(primary) = cursors.first() else {
+        return vec![];
+    };
+
+    let mut sorted: Vec<(bool, CCursorRange)> = cursors
+        .iter()
+        .enumerate()
+        .map(|(i, range)| (i == 0, *range))
+        .collect();
+    sorted.sort_by_key(|(_, range)| range.as_sorted_char_range().start);
+
+    let mut merged: Vec<(bool, CCursorRange)> = Vec::with_capacity(sorted.len());
+    for (is_primary, range) in sorted {
+        if let Some((last_is_primary, last)) = merged.last_mut() {
+            let last_range = last.as_sorted_char_range();
+            let new_range = range.as_sorted_char_range();
+            if new_range.start <= last_range.end {
+                if last_range.end < new_range.end {
+                    *last = CCursorRange::two(
+                        CCursor::new(last_range.start),
+                        CCursor::new(new_range.end),
+                    );
+                }
+                *last_is_primary |= is_primary;
+                continue;
+            }
+        }
+        merged.push((is_primary, range));
+    }
+
+    let primary = merged
+        .iter()
+        .position(|(is_primary, _)| *is_primary)
+        .map_or(*primary, |i| merged.remove(i).1);
+    std::iter::once(primary)
+        .chain(merged.into_iter().map(|(_, range)| range))
+        .collect()
+}
+
+/// The next occurrence of the text selected by `after`, which is not yet selected by any of
+/// the `cursors`.
+///
+/// The search wraps around to the start of the text.
+/// Returns `None` if nothing is selected, or there are no more occurrences.
+pub fn next_occurrence(
+    text: &str,
+    after: &CCursorRange,
+    cursors: &[CCursorRange],
+) -> Option<CCursorRange> {
+    let needle = after.slice_str(text);
+    if needle.is_empty() {
+        return None;
+    }
+
+    let is_selected = |start: usize, end: usize| {
+        cursors
+            .iter()
+            .chain(std::iter::once(after))
+            .any(|range| range.as_sorted_char_range() == (start..end))
+    };
+
+    let search_start = byte_index_from_char_index(text, after.as_sorted_char_range().end);
+    let matches = text[search_start..]
+        .match_indices(needle)
+        .map(|(i, _)| search_start + i)
+        .chain(text[..search_start].match_indices(needle).map(|(i, _)| i));
+    for byte_index in matches {
+        let start = text[..byte_index].chars().count();
+        let end = start + needle.chars().count();
+        if !is_selected(start, end) {
+            return Some(CCursorRange::two(CCursor::new(start), CCursor::new(end)));
+        }
+    }
+    None
+}
+
+/// Select a column of the text, with one cursor on each row between `anchor` and `pointer`,
+/// both in galley coordinates.
+///
+/// The cursors go from the row of `anchor` to the row of `pointer`,
+/// so the last one is where the pointer is.
+/// Rows shorter than the column get an empty cursor at their end.
+pub fn column_selection(galley: &Galley, anchor: Vec2, pointer: Vec2) -> Vec<CCursorRange> {
+    let (min_y, max_y) = (anchor.y.min(pointer.y), anchor.y.max(pointer.y));
+    let mut cursors: Vec<CCursorRange> = galley
+        .rows
+        .iter()
+        .map(|row| row.rect())
+        .filter(|rect| min_y < rect.max.y && rect.min.y <= max_y)
+        .map(|rect| {
+            let y = rect.center().y;
+            CCursorRange {
+                primary: galley.cursor_from_pos(vec2(pointer.x, y)),
+                secondary: galley.cursor_from_pos(vec2(anchor.x, y)),
+                h_pos: None,
+            }
+        })
+        .collect();
+    if pointer.y < anchor.y {
+        cursors.reverse();
+    }
+    cursors
+}
+
+#[cfg(test)]
+mod test {
+    use epaint::text::cursor::CCursor;
+
+    use super::{merge_overlapping, next_occurrence};
+    use crate::text_selection::CCursorRange;
+
+    fn range(start: usize, end: usize) -> CCursorRange {
+        CCursorRange::two(CCursor::new(start), CCursor::new(end))
+    }
+
+    #[test]
+    fn test_merge_overlapping() {
+        let char_ranges = |cursors: &[CCursorRange]| {
+            merge_overlapping(cursors)
+                .iter()
+                .map(|range| range.as_sorted_char_range())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(char_ranges(&[]), vec![]);
+        assert_eq!(
+            char_ranges(&[range(5, 5), range(9, 9), range(1, 1)]),
+            vec![5..5, 1..1, 9..9],
+            "The primary cursor stays first"
+        );
+        assert_eq!(
+            char_ranges(&[range(5, 8), range(2, 6), range(9, 9)]),
+            vec![2..8, 9..9]
+        );
+        assert_eq!(char_ranges(&[range(2, 2), range(2, 2)]), vec![2..2]);
+        assert_eq!(
+            char_ranges(&[range(0, 3), range(3, 3)]),
+            vec![0..3],
+            "Touching"
+        );
+    }
+
+    #[test]
+    fn test_next_occurrence() {
+        let text = "foo bar foo baz foo";
+        let next = |after: CCursorRange, cursors: &[CCursorRange]| {
+            next_occurrence(text, &after, cursors).map(|range| range.as_sorted_char_range())
+        };
+
+        assert_eq!(next(range(0, 3), &[]), Some(8..11));
+        assert_eq!(next(range(8, 11), &[range(0, 3)]), Some(16..19));
+        assert_eq!(
+            next(range(16, 19), &[]),
+            Some(0..3),
+            "Wraps around to the start"
+        );
+        assert_eq!(next(range(16, 19), &[range(0, 3), range(8, 11)]), None);
+        assert_eq!(next(range(4, 4), &[]), None, "Nothing selected");
+        assert_eq!(next(range(4, 7), &[]), None, "No other occurrence");
+    }
+}
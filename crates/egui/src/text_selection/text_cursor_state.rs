@@ -54,6 +54,8 @@ impl TextCursorState {
 impl TextCursorState {
     /// Handle clicking and/or dragging text.
     ///
+    /// Double-clicking selects a word, and triple-clicking selects a paragraph.
+    ///
     /// Returns `true` if there was interaction.
     pub fn pointer_interaction(
         &mut self,
@@ -71,8 +73,8 @@ impl TextCursorState {
             self.set_char_range(Some(ccursor_range));
             true
         } else if response.triple_clicked() {
-            // Select line:
-            let ccursor_range = select_line_at(text, cursor_at_pointer);
+            // Select paragraph:
+            let ccursor_range = select_paragraph_at(text, cursor_at_pointer);
             self.set_char_range(Some(ccursor_range));
             true
         } else if response.sense.senses_drag() {
@@ -105,7 +107,7 @@ impl TextCursorState {
     }
 }
 
-fn select_word_at(text: &str, ccursor: CCursor) -> CCursorRange {
+pub(crate) fn select_word_at(text: &str, ccursor: CCursor) -> CCursorRange {
     if ccursor.index == 0 {
         CCursorRange::two(ccursor, ccursor_next_word(text, ccursor))
     } else {
@@ -140,39 +142,28 @@ fn select_word_at(text: &str, ccursor: CCursor) -> CCursorRange {
     }
 }
 
-fn select_line_at(text: &str, ccursor: CCursor) -> CCursorRange {
-    if ccursor.index == 0 {
-        CCursorRange::two(ccursor, ccursor_next_line(text, ccursor))
-    } else {
-        let it = text.chars();
-        let mut it = it.skip(ccursor.index - 1);
-        if let Some(char_before_cursor) = it.next() {
-            if let Some(char_after_cursor) = it.next() {
-                if (!is_linebreak(char_before_cursor)) && (!is_linebreak(char_after_cursor)) {
-                    let min = ccursor_previous_line(text, ccursor + 1);
-                    let max = ccursor_next_line(text, min);
-                    CCursorRange::two(min, max)
-                } else if !is_linebreak(char_before_cursor) {
-                    let min = ccursor_previous_line(text, ccursor);
-                    let max = ccursor_next_line(text, min);
-                    CCursorRange::two(min, max)
-                } else if !is_linebreak(char_after_cursor) {
-                    let max = ccursor_next_line(text, ccursor);
-                    CCursorRange::two(ccursor, max)
-                } else {
-                    let min = ccursor_previous_line(text, ccursor);
-                    let max = ccursor_next_line(text, ccursor);
-                    CCursorRange::two(min, max)
-                }
-            } else {
-                let min = ccursor_previous_line(text, ccursor);
-                CCursorRange::two(min, ccursor)
-            }
-        } else {
-            let max = ccursor_next_line(text, ccursor);
-            CCursorRange::two(ccursor, max)
-        }
-    }
+/// The paragraph around the cursor: everything between the line breaks before and after it.
+///
+/// In text without line breaks, like most labels, this is all of it.
+fn select_paragraph_at(text: &str, ccursor: CCursor) -> CCursorRange {
+    let num_chars = text.chars().count();
+    let index = ccursor.index.min(num_chars);
+
+    let start = text
+        .chars()
+        .take(index)
+        .enumerate()
+        .filter(|&(_, c)| is_linebreak(c))
+        .last()
+        .map_or(0, |(i, _)| i + 1);
+    let end = text
+        .chars()
+        .enumerate()
+        .skip(index)
+        .find(|&(_, c)| is_linebreak(c))
+        .map_or(num_chars, |(i, _)| i);
+
+    CCursorRange::two(CCursor::new(start), CCursor::new(end))
 }
 
 pub fn ccursor_next_word(text: &str, ccursor: CCursor) -> CCursor {
@@ -182,13 +173,6 @@ pub fn ccursor_next_word(text: &str, ccursor: CCursor) -> CCursor {
     }
 }
 
-fn ccursor_next_line(text: &str, ccursor: CCursor) -> CCursor {
-    CCursor {
-        index: next_line_boundary_char_index(text.chars(), ccursor.index),
-        prefer_next_row: false,
-    }
-}
-
 pub fn ccursor_previous_word(text: &str, ccursor: CCursor) -> CCursor {
     let num_chars = text.chars().count();
     let reversed: String = text.graphemes(true).rev().collect();
@@ -199,15 +183,6 @@ pub fn ccursor_previous_word(text: &str, ccursor: CCursor) -> CCursor {
     }
 }
 
-fn ccursor_previous_line(text: &str, ccursor: CCursor) -> CCursor {
-    let num_chars = text.chars().count();
-    CCursor {
-        index: num_chars
-            - next_line_boundary_char_index(text.chars().rev(), num_chars - ccursor.index),
-        prefer_next_row: true,
-    }
-}
-
 fn next_word_boundary_char_index(text: &str, index: usize) -> usize {
     for word in text.split_word_bound_indices() {
         // Splitting considers contiguous whitespace as one word, such words must be skipped,
@@ -229,24 +204,6 @@ fn skip_word(text: &str) -> bool {
     !text.chars().any(|c| !is_word_char(c))
 }
 
-fn next_line_boundary_char_index(it: impl Iterator<Item = char>, mut index: usize) -> usize {
-    let mut it = it.skip(index);
-    if let Some(_first) = it.next() {
-        index += 1;
-
-        if let Some(second) = it.next() {
-            index += 1;
-            for next in it {
-                if is_linebreak(next) != is_linebreak(second) {
-                    break;
-                }
-                index += 1;
-            }
-        }
-    }
-    index
-}
-
 pub fn is_word_char(c: char) -> bool {
     c.is_alphanumeric() || c == '_'
 }
@@ -321,7 +278,25 @@ pub fn cursor_rect(galley: &Galley, cursor: &CCursor, row_height: f32) -> Rect {
 
 #[cfg(test)]
 mod test {
-    use crate::text_selection::text_cursor_state::next_word_boundary_char_index;
+    use crate::text_selection::text_cursor_state::{
+        next_word_boundary_char_index, select_paragraph_at,
+    };
+    use epaint::text::cursor::CCursor;
+
+    #[test]
+    fn test_select_paragraph_at() {
+        let paragraph_at = |text: &str, index: usize| {
+            select_paragraph_at(text, CCursor::new(index)).as_sorted_char_range()
+        };
+
+        assert_eq!(paragraph_at("one two", 2), 0..7);
+        assert_eq!(paragraph_at("one\ntwo\r\nthree", 5), 4..7);
+        assert_eq!(paragraph_at("one\ntwo\r\nthree", 4), 4..7, "At the start");
+        assert_eq!(paragraph_at("one\ntwo\r\nthree", 3), 0..3, "At the end");
+        assert_eq!(paragraph_at("one\ntwo\r\nthree", 9), 9..14);
+        assert_eq!(paragraph_at("one\n\ntwo", 4), 4..4, "An empty line");
+        assert_eq!(paragraph_at("", 0), 0..0);
+    }
 
     #[test]
     fn test_next_word_boundary_char_index() {
@@ -95,6 +95,36 @@ pub fn paint_text_selection(
     }
 }
 
+/// The rectangles covering a range of the text, one per row, relative to the galley.
+pub fn char_range_rects(galley: &Galley, cursor_range: &CCursorRange) -> Vec<Rect> {
+    if cursor_range.is_empty() {
+        return vec![];
+    }
+
+    let [min, max] = cursor_range.sorted_cursors();
+    let min = galley.layout_from_cursor(min);
+    let max = galley.layout_from_cursor(max);
+
+    (min.row..=max.row)
+        .map(|ri| {
+            let placed_row = &galley.rows[ri];
+            let row = &placed_row.row;
+            let left = if ri == min.row {
+                row.x_offset(min.column)
+            } else {
+                0.0
+            };
+            let right = if ri == max.row {
+                row.x_offset(max.column)
+            } else {
+                row.size.x
+            };
+            Rect::from_min_max(pos2(left, 0.0), pos2(right, row.size.y))
+                .translate(placed_row.pos.to_vec2())
+        })
+        .collect()
+}
+
 /// Paint one end of the selection, e.g. the primary cursor.
 ///
 /// This will never blink.
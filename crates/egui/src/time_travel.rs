@@ -0,0 +1,223 @@
+use std::collections::VecDeque;
+
+use crate::{
+    Context, FullOutput, Memory, RawInput, ViewportId, animation_manager::AnimationManager,
+    input_state::InputState, interaction::InteractionSnapshot, pass_state::PassState,
+};
+
+/// The state of a [`Context`] between two passes of a viewport.
+///
+/// See [`Context::snapshot`] and [`Context::restore`].
+#[derive(Clone)]
+pub struct ContextSnapshot {
+    pub(crate) viewport_id: ViewportId,
+    pub(crate) memory: Memory,
+    pub(crate) animation_manager: AnimationManager,
+    pub(crate) input: InputState,
+    pub(crate) prev_pass: PassState,
+    pub(crate) interact_widgets: InteractionSnapshot,
+}
+
+impl ContextSnapshot {
+    /// The viewport this is a snapshot of.
+    pub fn viewport_id(&self) -> ViewportId {
+        self.viewport_id
+    }
+
+    /// The [`Memory`] as it was.
+    pub fn memory(&self) -> &Memory {
+        &self.memory
+    }
+
+    /// The input of the previous pass.
+    pub fn input(&self) -> &InputState {
+        &self.input
+    }
+}
+
+/// A pass recorded by [`TimeTravel`].
+struct RecordedPass {
+    /// The state the pass started from.
+    before: ContextSnapshot,
+    raw_input: RawInput,
+}
+
+/// Record the last passes of a viewport, to step back and forth through them.
+///
+/// This is for finding bugs that only show for a frame or two,
+/// e.g. flickering while dragging.
+/// Run your ui through [`Self::run`] instead of [`Context::run`] to record it,
+/// and call [`Self::step_back`] and [`Self::step_forward`] to replay the passes one by one,
+/// e.g. from a debugger or from keyboard shortcuts in your integration.
+///
+/// Replaying restores the [`Context`] with [`Context::restore`]
+/// and runs the ui again with the same input, so the ui should only depend on the [`Context`].
+/// State kept outside of it, e.g. in your app struct, is not rewound.
+///
+/// A snapshot clones the whole [`Memory`] each pass, so only record in debug builds.
+///
+/// ```
+/// # let ctx = egui::Context::default();
+/// # let run_ui = |_ctx: &egui::Context| {};
+/// let mut time_travel = egui::TimeTravel::new(100);
+/// for _ in 0..3 {
+///     let _output = time_travel.run(&ctx, egui::RawInput::default(), run_ui);
+/// }
+///
+/// // "It flickered just before the end!"
+/// let _output = time_travel.step_back(&ctx, run_ui);
+/// let _output = time_travel.step_back(&ctx, run_ui);
+/// assert_eq!(time_travel.position(), Some(1));
+///
+/// time_travel.resume(&ctx);
+/// ```
+pub struct TimeTravel {
+    max_passes: usize,
+    passes: VecDeque<RecordedPass>,
+
+    /// The state after the last recorded pass.
+    latest: Option<ContextSnapshot>,
+
+    /// Which pass was replayed last, or `None` while running live.
+    position: Option<usize>,
+}
+
+impl TimeTravel {
+    /// Remember at most this many passes, dropping the oldest ones.
+    pub fn new(max_passes: usize) -> Self {
+        Self {
+            max_passes,
+            passes: Default::default(),
+            latest: None,
+            position: None,
+        }
+    }
+
+    /// How many passes are recorded.
+    pub fn len(&self) -> usize {
+        self.passes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.passes.is_empty()
+    }
+
+    /// Which recorded pass was replayed last, counting from the oldest one,
+    /// or `None` while running live.
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
+
+    /// The input of a recorded pass.
+    pub fn raw_input(&self, index: usize) -> Option<&RawInput> {
+        self.passes.get(index).map(|pass| &pass.raw_input)
+    }
+
+    /// The state a recorded pass started from.
+    pub fn snapshot(&self, index: usize) -> Option<&ContextSnapshot> {
+        self.passes.get(index).map(|pass| &pass.before)
+    }
+
+    /// Record a pass, like [`Context::run`].
+    ///
+    /// While replaying, this first [`Self::resume`]s.
+    pub fn run(
+        &mut self,
+        ctx: &Context,
+        raw_input: RawInput,
+        run_ui: impl FnMut(&Context),
+    ) -> FullOutput {
+        self.resume(ctx);
+
+        let viewport_id = raw_input.viewport_id;
+        let before = ctx.snapshot(viewport_id);
+        let output = ctx.run(raw_input.clone(), run_ui);
+
+        self.passes.push_back(RecordedPass { before, raw_input });
+        while self.max_passes < self.passes.len() {
+            self.passes.pop_front();
+        }
+        self.latest = Some(ctx.snapshot(viewport_id));
+        output
+    }
+
+    /// Replay the recorded pass at `index`, counting from the oldest one.
+    ///
+    /// Returns `None` if there is no such pass.
+    pub fn replay(
+        &mut self,
+        ctx: &Context,
+        index: usize,
+        run_ui: impl FnMut(&Context),
+    ) -> Option<FullOutput> {
+        let pass = self.passes.get(index)?;
+        ctx.restore(&pass.before);
+        let output = ctx.run(pass.raw_input.clone(), run_ui);
+        self.position = Some(index);
+        Some(output)
+    }
+
+    /// Replay the pass before the one replayed last, or the last recorded pass while live.
+    pub fn step_back(
+        &mut self,
+        ctx: &Context,
+        run_ui: impl FnMut(&Context),
+    ) -> Option<FullOutput> {
+        let index = match self.position {
+            Some(position) => position.checked_sub(1)?,
+            None => self.passes.len().checked_sub(1)?,
+        };
+        self.replay(ctx, index, run_ui)
+    }
+
+    /// Replay the pass after the one replayed last.
+    pub fn step_forward(
+        &mut self,
+        ctx: &Context,
+        run_ui: impl FnMut(&Context),
+    ) -> Option<FullOutput> {
+        let index = self.position? + 1;
+        self.replay(ctx, index, run_ui)
+    }
+
+    /// Stop replaying, and go back to the state after the last recorded pass.
+    pub fn resume(&mut self, ctx: &Context) {
+        if self.position.take().is_some() {
+            if let Some(latest) = &self.latest {
+                ctx.restore(latest);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_time_travel() {
+    use crate::Id;
+
+    let id = Id::new("counter");
+    let count_passes = |ctx: &Context| {
+        ctx.data_mut(|data| *data.get_temp_mut_or_default::<u32>(id) += 1);
+    };
+    let count = |ctx: &Context| ctx.data(|data| data.get_temp::<u32>(id));
+
+    let ctx = Context::default();
+    let mut time_travel = TimeTravel::new(2);
+    for _ in 0..3 {
+        let _ = time_travel.run(&ctx, RawInput::default(), count_passes);
+    }
+    assert_eq!(count(&ctx), Some(3));
+    assert_eq!(time_travel.len(), 2, "The oldest pass is dropped");
+
+    let _ = time_travel.step_back(&ctx, count_passes);
+    assert_eq!(count(&ctx), Some(3), "The third pass, again");
+    let _ = time_travel.step_back(&ctx, count_passes);
+    assert_eq!(count(&ctx), Some(2));
+    assert!(time_travel.step_back(&ctx, count_passes).is_none());
+    let _ = time_travel.step_forward(&ctx, count_passes);
+    assert_eq!(time_travel.position(), Some(1));
+    assert_eq!(count(&ctx), Some(3));
+
+    let _ = time_travel.run(&ctx, RawInput::default(), count_passes);
+    assert_eq!(time_travel.position(), None);
+    assert_eq!(count(&ctx), Some(4));
+}
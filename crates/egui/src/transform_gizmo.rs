@@ -0,0 +1,405 @@
+use emath::{Affine2, Align, Align2, Pos2, Rect, Vec2, vec2};
+use epaint::{Stroke, StrokeKind};
+
+use crate::{CursorIcon, Id, Response, Sense, Snapping, Ui};
+
+/// A part of a [`TransformGizmo`] that the user can drag.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GizmoHandle {
+    /// The inside of the rectangle, for moving it.
+    Move,
+
+    /// The knob above the top edge, for rotating around the center.
+    Rotate,
+
+    /// A corner or edge handle, for scaling.
+    ///
+    /// E.g. [`Align2::RIGHT_BOTTOM`] for the bottom right corner,
+    /// or [`Align2::CENTER_TOP`] for the top edge.
+    /// The opposite corner or edge stays where it is.
+    Scale(Align2),
+}
+
+/// The scale handles, corners first.
+const SCALE_HANDLES: [Align2; 8] = [
+    Align2::LEFT_TOP,
+    Align2::RIGHT_TOP,
+    Align2::RIGHT_BOTTOM,
+    Align2::LEFT_BOTTOM,
+    Align2::CENTER_TOP,
+    Align2::RIGHT_CENTER,
+    Align2::CENTER_BOTTOM,
+    Align2::LEFT_CENTER,
+];
+
+impl GizmoHandle {
+    fn cursor(self) -> CursorIcon {
+        match self {
+            Self::Move => CursorIcon::Move,
+            Self::Rotate => CursorIcon::Grab,
+            Self::Scale(align) => match (align.x(), align.y()) {
+                (Align::Center, _) => CursorIcon::ResizeVertical,
+                (_, Align::Center) => CursorIcon::ResizeHorizontal,
+                (x, y) if x == y => CursorIcon::ResizeNwSe,
+                _ => CursorIcon::ResizeNeSw,
+            },
+        }
+    }
+}
+
+/// What happened with a [`TransformGizmo`] this frame.
+#[derive(Clone, Debug)]
+pub struct TransformGizmoResponse {
+    /// How the user moved, rotated or scaled things this frame.
+    ///
+    /// Apply it to your points (`delta * point`) or your own transform (`delta * transform`).
+    /// This is [`Affine2::IDENTITY`] when nothing is being dragged.
+    pub delta: Affine2,
+
+    /// The handle being dragged, if any.
+    pub active: Option<GizmoHandle>,
+
+    /// The handle under the pointer, if any.
+    pub hovered: Option<GizmoHandle>,
+
+    /// The response of the inside of the rectangle, e.g. to check if it was clicked.
+    pub response: Response,
+}
+
+impl TransformGizmoResponse {
+    /// Did the user move, rotate or scale anything this frame?
+    #[inline]
+    pub fn changed(&self) -> bool {
+        self.delta != Affine2::IDENTITY
+    }
+}
+
+#[derive(Clone, Copy)]
+struct GizmoState {
+    /// The center of the rectangle when the rotation started.
+    pivot: Pos2,
+
+    /// The angle of the pointer around the pivot last frame.
+    last_angle: f32,
+
+    /// From the pointer to the handle it grabbed, so the handle does not jump to the pointer.
+    grab_offset: Vec2,
+
+    /// How far the pointer has turned around the pivot since the rotation started.
+    turned: f32,
+
+    /// How much of [`Self::turned`] has been applied, after snapping.
+    applied_turn: f32,
+}
+
+/// Handles for moving, rotating and scaling a rectangle, or a set of points, on a canvas.
+///
+/// Each frame, pass the current bounding rectangle of whatever is selected to [`Self::show`],
+/// and apply the returned [`TransformGizmoResponse::delta`] to it.
+///
+/// * Drag the inside to move.
+/// * Drag a corner or edge handle to scale. Hold shift to keep the proportions.
+/// * Drag the knob above the top edge to rotate around the center.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut points = vec![egui::pos2(10.0, 10.0), egui::pos2(60.0, 40.0)];
+/// let gizmo = egui::TransformGizmo::new("gizmo").show(ui, egui::Rect::from_points(&points));
+/// for point in &mut points {
+///     *point = gizmo.delta * *point;
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct TransformGizmo {
+    id_salt: Id,
+    handle_size: f32,
+    rotation: bool,
+    scaling: bool,
+    stroke: Option<Stroke>,
+    snapping: Option<Snapping>,
+    snap_targets: Vec<Rect>,
+}
+
+impl TransformGizmo {
+    /// The `id_salt` must be unique among sibling widgets.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            handle_size: 8.0,
+            rotation: true,
+            scaling: true,
+            stroke: None,
+            snapping: None,
+            snap_targets: Vec::new(),
+        }
+    }
+
+    /// The size of the painted handles, in points.
+    ///
+    /// The area that reacts to the pointer is a bit larger.
+    ///
+    /// Default: `8.0`.
+    #[inline]
+    pub fn handle_size(mut self, handle_size: f32) -> Self {
+        self.handle_size = handle_size;
+        self
+    }
+
+    /// Show the rotation knob.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn rotation(mut self, rotation: bool) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Show the corner and edge handles for scaling.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn scaling(mut self, scaling: bool) -> Self {
+        self.scaling = scaling;
+        self
+    }
+
+    /// The outline and the handle borders.
+    ///
+    /// Default: the selection stroke of the current [`crate::Visuals`].
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = Some(stroke.into());
+        self
+    }
+
+    /// Snap while moving, rotating and scaling.
+    ///
+    /// Moving snaps the rectangle, and scaling snaps the dragged handle,
+    /// to the grid and to the [`Self::snap_targets`].
+    /// Rotations snap in steps of [`Snapping::angle`] from where the drag started.
+    ///
+    /// Default: no snapping.
+    #[inline]
+    pub fn snapping(mut self, snapping: Snapping) -> Self {
+        self.snapping = Some(snapping);
+        self
+    }
+
+    /// The bounding rectangles of other things on the canvas, for [`Self::snapping`] to.
+    #[inline]
+    pub fn snap_targets(mut self, targets: impl IntoIterator<Item = Rect>) -> Self {
+        self.snap_targets = targets.into_iter().collect();
+        self
+    }
+
+    /// Show the handles around `rect` and track dragging them.
+    pub fn show(self, ui: &Ui, rect: Rect) -> TransformGizmoResponse {
+        let Self {
+            id_salt,
+            handle_size,
+            rotation,
+            scaling,
+            stroke,
+            snapping,
+            snap_targets,
+        } = self;
+
+        let id = ui.make_persistent_id(id_salt);
+        let grab_radius = ui.style().interaction.resize_grab_radius_side;
+        let grab_size = Vec2::splat(handle_size + 2.0 * grab_radius);
+        let rotate_knob = rect.center_top() - vec2(0.0, 3.0 * handle_size);
+
+        // Later widgets are on top, so add the inside first:
+        let response = ui.interact(rect, id.with(GizmoHandle::Move), Sense::click_and_drag());
+        let mut handles = vec![(GizmoHandle::Move, response.clone())];
+        if scaling {
+            for align in SCALE_HANDLES {
+                let grab_rect = Rect::from_center_size(align.pos_in_rect(&rect), grab_size);
+                let handle = GizmoHandle::Scale(align);
+                handles.push((handle, ui.interact(grab_rect, id.with(handle), Sense::drag())));
+            }
+        }
+        if rotation {
+            let grab_rect = Rect::from_center_size(rotate_knob, grab_size);
+            let handle = GizmoHandle::Rotate;
+            handles.push((handle, ui.interact(grab_rect, id.with(handle), Sense::drag())));
+        }
+
+        let hovered = handles
+            .iter()
+            .rev()
+            .find(|(_, response)| response.hovered())
+            .map(|(handle, _)| *handle);
+        let active = handles
+            .iter()
+            .find(|(_, response)| response.dragged() || response.drag_stopped())
+            .map(|(handle, _)| *handle);
+        let mut delta = Affine2::IDENTITY;
+        let snapping = snapping
+            .filter(|_| !Snapping::is_suspended(ui.ctx()))
+            .map(|snapping| snapping.in_layer(ui.ctx(), ui.layer_id()));
+
+        if let Some(handle) = active {
+            ui.ctx().set_cursor_icon(match handle {
+                GizmoHandle::Rotate => CursorIcon::Grabbing,
+                _ => handle.cursor(),
+            });
+        } else if let Some(handle) = hovered {
+            ui.ctx().set_cursor_icon(handle.cursor());
+        }
+
+        if let Some((handle, handle_response)) = handles
+            .iter()
+            .find(|(handle, _)| Some(*handle) == active)
+        {
+            if let Some(pointer) = handle_response.interact_pointer_pos() {
+                let handle_pos = match handle {
+                    GizmoHandle::Move => rect.center(),
+                    GizmoHandle::Rotate => rotate_knob,
+                    GizmoHandle::Scale(align) => align.pos_in_rect(&rect),
+                };
+                let fresh_state = GizmoState {
+                    pivot: rect.center(),
+                    last_angle: (pointer - rect.center()).angle(),
+                    grab_offset: handle_pos - pointer,
+                    turned: 0.0,
+                    applied_turn: 0.0,
+                };
+                let mut state = if handle_response.drag_started() {
+                    fresh_state
+                } else {
+                    ui.data(|d| d.get_temp(id)).unwrap_or(fresh_state)
+                };
+
+                delta = match handle {
+                    GizmoHandle::Move => {
+                        if let Some(snapping) = snapping {
+                            let center = pointer + state.grab_offset;
+                            let moved = rect.translate(center - rect.center());
+                            let snapped = snapping.snap_rect(moved, &snap_targets);
+                            snapped.paint_lines(ui.ctx(), ui.layer_id());
+                            Affine2::from_translation(snapped.value.min - rect.min)
+                        } else {
+                            Affine2::from_translation(handle_response.drag_delta())
+                        }
+                    }
+                    GizmoHandle::Rotate => {
+                        let angle = (pointer - state.pivot).angle();
+                        state.turned += emath::normalized_angle(angle - state.last_angle);
+                        state.last_angle = angle;
+                        let turned = snapping.map_or(state.turned, |s| s.snap_angle(state.turned));
+                        let turn = turned - state.applied_turn;
+                        state.applied_turn = turned;
+                        Affine2::from_rotation_about(turn, state.pivot)
+                    }
+                    GizmoHandle::Scale(align) => {
+                        let proportional = ui.input(|i| i.modifiers.shift);
+                        let mut target = pointer + state.grab_offset;
+                        if let Some(snapping) = snapping {
+                            let snapped = snapping.snap_pos(target, &snap_targets);
+                            snapped.paint_lines(ui.ctx(), ui.layer_id());
+                            target = snapped.value;
+                        }
+                        scale_delta(rect, *align, target, proportional)
+                    }
+                };
+
+                if handle_response.drag_stopped() {
+                    ui.data_mut(|d| d.remove::<GizmoState>(id));
+                } else {
+                    ui.data_mut(|d| d.insert_temp(id, state));
+                }
+            }
+        }
+
+        if ui.is_rect_visible(rect.expand(4.0 * handle_size)) {
+            let visuals = ui.visuals();
+            let stroke = stroke.unwrap_or(visuals.selection.stroke);
+            let fill = visuals.window_fill;
+            let painter = ui.painter();
+
+            painter.rect_stroke(rect, 0.0, stroke, StrokeKind::Middle);
+            if rotation {
+                painter.line_segment([rect.center_top(), rotate_knob], stroke);
+                painter.circle(rotate_knob, 0.5 * handle_size, fill, stroke);
+            }
+            if scaling {
+                for align in SCALE_HANDLES {
+                    let handle_rect =
+                        Rect::from_center_size(align.pos_in_rect(&rect), Vec2::splat(handle_size));
+                    painter.rect(handle_rect, 0.0, fill, stroke, StrokeKind::Middle);
+                }
+            }
+        }
+
+        TransformGizmoResponse {
+            delta,
+            active,
+            hovered,
+            response,
+        }
+    }
+}
+
+/// Scale `rect` so that the handle at `align` ends up at `target`,
+/// keeping the opposite corner or edge where it is.
+///
+/// Never scales below one point, and never mirrors.
+fn scale_delta(rect: Rect, align: Align2, target: Pos2, proportional: bool) -> Affine2 {
+    let handle = align.pos_in_rect(&rect);
+    let anchor = align.flip().pos_in_rect(&rect);
+    let from = handle - anchor;
+    let to = target - anchor;
+
+    // Only scale along the axes the handle moves:
+    let ratio = |axis: usize| {
+        if from[axis] == 0.0 {
+            1.0
+        } else {
+            (to[axis] / from[axis]).max(1.0 / from[axis].abs())
+        }
+    };
+    let scale = if proportional {
+        // Project onto the line from the anchor through the handle:
+        let factor = if align.x() == Align::Center {
+            ratio(1)
+        } else if align.y() == Align::Center {
+            ratio(0)
+        } else {
+            (to.dot(from) / from.length_sq()).max(1.0 / from.x.abs().min(from.y.abs()))
+        };
+        Vec2::splat(factor)
+    } else {
+        vec2(ratio(0), ratio(1))
+    };
+    Affine2::from_scale_about(scale, anchor)
+}
+
+#[test]
+fn transform_gizmo_scale() {
+    use emath::pos2;
+
+    let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 50.0));
+
+    // Dragging the right bottom corner keeps the left top corner:
+    let delta = scale_delta(rect, Align2::RIGHT_BOTTOM, pos2(200.0, 75.0), false);
+    assert_eq!(delta * rect.left_top(), rect.left_top());
+    assert_eq!(delta * rect.right_bottom(), pos2(200.0, 75.0));
+
+    // Dragging an edge only scales across it:
+    let delta = scale_delta(rect, Align2::LEFT_CENTER, pos2(-100.0, 10.0), false);
+    assert_eq!(delta * rect.right_bottom(), rect.right_bottom());
+    assert_eq!(delta * rect.left_top(), pos2(-100.0, 0.0));
+
+    // Proportional scaling keeps the aspect ratio:
+    let delta = scale_delta(rect, Align2::RIGHT_BOTTOM, pos2(200.0, 60.0), true);
+    let scaled = Rect::from_two_pos(delta * rect.min, delta * rect.max);
+    assert!((scaled.aspect_ratio() - rect.aspect_ratio()).abs() < 1e-5);
+    assert_eq!(scaled.min, rect.min);
+
+    // Never flips:
+    let delta = scale_delta(rect, Align2::RIGHT_BOTTOM, pos2(-50.0, -50.0), false);
+    let squashed = delta * rect.right_bottom();
+    assert!((squashed - pos2(1.0, 1.0)).length() < 1e-4, "{squashed:?}");
+}
@@ -93,6 +93,9 @@ pub struct Ui {
     /// and all widgets will assume a gray style.
     enabled: bool,
 
+    /// If true, widgets that edit values show them, but don't let the user change them.
+    read_only: bool,
+
     /// Set to true in special cases where we do one frame
     /// where we size up the contents of the Ui, without actually showing it.
     sizing_pass: bool,
@@ -129,6 +132,7 @@ impl Ui {
             max_rect,
             layout,
             disabled,
+            read_only,
             invisible,
             sizing_pass,
             style,
@@ -166,6 +170,7 @@ impl Ui {
             style,
             placer,
             enabled: true,
+            read_only,
             sizing_pass,
             menu_state: None,
             stack: Arc::new(ui_stack),
@@ -255,6 +260,7 @@ impl Ui {
             max_rect,
             layout,
             disabled,
+            read_only,
             invisible,
             sizing_pass,
             style,
@@ -310,6 +316,7 @@ impl Ui {
             style,
             placer,
             enabled,
+            read_only: self.read_only || read_only,
             sizing_pass,
             menu_state: self.menu_state.clone(),
             stack: Arc::new(ui_stack),
@@ -423,6 +430,16 @@ impl Ui {
         self.style = self.ctx().style();
     }
 
+    /// Report a [`crate::SoundEvent`] to [`crate::style::Interaction::sound_hooks`], if any.
+    ///
+    /// The built-in widgets call this, e.g. when a button is clicked.
+    /// Call it from your own widgets too, so they sound like the rest of the ui.
+    pub fn play_sound(&self, event: crate::SoundEvent) {
+        if let Some(sound_hooks) = &self.style.interaction.sound_hooks {
+            sound_hooks.play(self.ctx(), event);
+        }
+    }
+
     /// The current spacing options for this [`Ui`].
     /// Short for `ui.style().spacing`.
     #[inline]
@@ -553,6 +570,47 @@ impl Ui {
         }
     }
 
+    /// If `true`, widgets that edit values, like checkboxes, sliders and text edits,
+    /// show their values but don't let the user change them.
+    ///
+    /// Unlike a disabled [`Ui`], everything is shown in the usual colors,
+    /// and the user can still scroll, select and copy text, and click buttons.
+    ///
+    /// See [`Self::read_only`].
+    #[inline]
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Calling `set_read_only()` will stop the user from changing the values of all widgets
+    /// added to the [`Ui`] after it.
+    ///
+    /// Usually it is more convenient to use [`Self::read_only`].
+    ///
+    /// Note that once read-only, there is no way to make the [`Ui`] editable again.
+    pub fn set_read_only(&mut self) {
+        self.read_only = true;
+    }
+
+    /// The sense for a widget that edits a value: `sense`, or only hover if [`Self::is_read_only`].
+    pub(crate) fn editing_sense(&self, sense: Sense) -> Sense {
+        if self.read_only {
+            Sense::hover()
+        } else {
+            sense
+        }
+    }
+
+    /// The visuals for a widget that edits a value, which look inactive if [`Self::is_read_only`]
+    /// rather than non-interactive.
+    pub(crate) fn editing_visuals(&self, response: &Response) -> &style::WidgetVisuals {
+        if self.read_only {
+            &self.visuals().widgets.inactive
+        } else {
+            self.style().interact(response)
+        }
+    }
+
     /// If `false`, any widgets added to the [`Ui`] will be invisible and non-interactive.
     ///
     /// This is `false` if any parent had [`UiBuilder::invisible`]
@@ -1016,6 +1074,33 @@ impl Ui {
         self.available_size().y
     }
 
+    /// The [`crate::SizeClass`] of [`Self::available_width`],
+    /// according to [`crate::style::Spacing::breakpoints`].
+    ///
+    /// The result is remembered for [`Self::id`], so that it only changes
+    /// once the width has moved past the [`crate::style::Breakpoints::hysteresis`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// if ui.breakpoint().is_compact() {
+    ///     ui.vertical(|ui| {
+    ///         ui.label("Name");
+    ///         ui.label("Description");
+    ///     });
+    /// } else {
+    ///     ui.horizontal(|ui| {
+    ///         ui.label("Name");
+    ///         ui.label("Description");
+    ///     });
+    /// }
+    /// # });
+    /// ```
+    pub fn breakpoint(&self) -> crate::SizeClass {
+        let breakpoints = &self.spacing().breakpoints;
+        self.ctx()
+            .size_class_for(self.id.with("breakpoint"), breakpoints, self.available_width())
+    }
+
     /// In case of a wrapping layout, how much space is left on this row/column?
     ///
     /// If the layout does not wrap, this will return the same value as [`Self::available_size`].
@@ -1704,6 +1789,9 @@ impl Ui {
     ///
     /// See also [`Self::add_sized`] and [`Self::put`].
     ///
+    /// If [`crate::Style::cull_offscreen_widgets`] is set,
+    /// widgets that are outside the clip rect may be skipped (see [`Widget::allow_culling`]).
+    ///
     /// ```
     /// # egui::__run_test_ui(|ui| {
     /// # let mut my_value = 42;
@@ -1713,7 +1801,81 @@ impl Ui {
     /// ```
     #[inline]
     pub fn add(&mut self, widget: impl Widget) -> Response {
-        widget.ui(self)
+        if self.style().cull_offscreen_widgets && widget.allow_culling() {
+            self.add_or_cull(widget)
+        } else {
+            self.add_unculled(widget)
+        }
+    }
+
+    fn add_unculled(&mut self, widget: impl Widget) -> Response {
+        if self.ctx().has_widget_decorators() {
+            self.add_decorated(widget)
+        } else {
+            widget.ui(self)
+        }
+    }
+
+    /// Skip the widget if it would end up outside the clip rect,
+    /// assuming it has the same size as last pass.
+    ///
+    /// A skipped widget only allocates space: it is not painted, and not registered for interaction.
+    fn add_or_cull(&mut self, widget: impl Widget) -> Response {
+        // The auto-id is the same each pass, as long as the layout is the same:
+        let size_id = self.next_auto_id().with("culling_size");
+
+        if let Some(size) = self.data(|d| d.get_temp::<Vec2>(size_id)) {
+            let item_spacing = self.spacing().item_spacing;
+            let frame_rect = self.placer.next_space(size, item_spacing);
+            let predicted_rect = self.placer.justify_and_align(frame_rect, size);
+
+            if !self.is_rect_visible(predicted_rect) {
+                let (id, rect) = self.allocate_space(size);
+                return Response {
+                    ctx: self.ctx().clone(),
+                    layer_id: self.layer_id(),
+                    id,
+                    rect,
+                    interact_rect: Rect::NOTHING,
+                    sense: Sense::hover(),
+                    interact_pointer_pos: None,
+                    intrinsic_size: None,
+                    flags: crate::response::Flags::empty(),
+                };
+            }
+        }
+
+        let response = self.add_unculled(widget);
+        self.data_mut(|d| d.insert_temp(size_id, response.rect.size()));
+        response
+    }
+
+    /// Add a widget and paint the decorators registered with [`Context::add_widget_decorator`].
+    fn add_decorated(&mut self, widget: impl Widget) -> Response {
+        let where_to_put_background = self.painter().add(epaint::Shape::Noop);
+
+        let response = widget.ui(self);
+
+        let Some(info) = self
+            .ctx()
+            .pass_state(|fs| fs.widgets.info(response.id).cloned())
+        else {
+            return response;
+        };
+
+        let mut below = vec![];
+        for decorator in self.ctx().widget_decorators(info.typ) {
+            let shape = (decorator.decorator)(self, &response, &info);
+            match decorator.phase {
+                crate::DecoratorPhase::Pre => below.push(shape),
+                crate::DecoratorPhase::Post => {
+                    self.painter().add(shape);
+                }
+            }
+        }
+        self.painter().set(where_to_put_background, below);
+
+        response
     }
 
     /// Add a [`Widget`] to this [`Ui`] with a given size.
@@ -1810,6 +1972,29 @@ impl Ui {
         })
     }
 
+    /// Add a section where the user can see, but not change, the values of widgets,
+    /// e.g. for a "view mode" or for users without permission to edit.
+    ///
+    /// Unlike [`Self::add_enabled_ui`], nothing is grayed out,
+    /// and the user can still scroll, select and copy text, and click buttons.
+    ///
+    /// See also [`Self::is_read_only`].
+    ///
+    /// ### Example
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut name = String::from("Ferris");
+    /// # let mut admin = false;
+    /// ui.read_only(|ui| {
+    ///     ui.text_edit_singleline(&mut name);
+    ///     ui.checkbox(&mut admin, "Admin");
+    /// });
+    /// # });
+    /// ```
+    pub fn read_only<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        self.scope_builder(UiBuilder::new().read_only(), add_contents)
+    }
+
     /// Add a single [`Widget`] that is possibly invisible.
     ///
     /// An invisible widget still takes up the same space as if it were visible.
@@ -1904,7 +2089,7 @@ impl Ui {
     /// ```
     #[inline]
     pub fn label(&mut self, text: impl Into<WidgetText>) -> Response {
-        Label::new(text).ui(self)
+        self.add(Label::new(text))
     }
 
     /// Show colored text.
@@ -1915,49 +2100,49 @@ impl Ui {
         color: impl Into<Color32>,
         text: impl Into<RichText>,
     ) -> Response {
-        Label::new(text.into().color(color)).ui(self)
+        self.add(Label::new(text.into().color(color)))
     }
 
     /// Show large text.
     ///
     /// Shortcut for `ui.label(RichText::new(text).heading())`
     pub fn heading(&mut self, text: impl Into<RichText>) -> Response {
-        Label::new(text.into().heading()).ui(self)
+        self.add(Label::new(text.into().heading()))
     }
 
     /// Show monospace (fixed width) text.
     ///
     /// Shortcut for `ui.label(RichText::new(text).monospace())`
     pub fn monospace(&mut self, text: impl Into<RichText>) -> Response {
-        Label::new(text.into().monospace()).ui(self)
+        self.add(Label::new(text.into().monospace()))
     }
 
     /// Show text as monospace with a gray background.
     ///
     /// Shortcut for `ui.label(RichText::new(text).code())`
     pub fn code(&mut self, text: impl Into<RichText>) -> Response {
-        Label::new(text.into().code()).ui(self)
+        self.add(Label::new(text.into().code()))
     }
 
     /// Show small text.
     ///
     /// Shortcut for `ui.label(RichText::new(text).small())`
     pub fn small(&mut self, text: impl Into<RichText>) -> Response {
-        Label::new(text.into().small()).ui(self)
+        self.add(Label::new(text.into().small()))
     }
 
     /// Show text that stand out a bit (e.g. slightly brighter).
     ///
     /// Shortcut for `ui.label(RichText::new(text).strong())`
     pub fn strong(&mut self, text: impl Into<RichText>) -> Response {
-        Label::new(text.into().strong()).ui(self)
+        self.add(Label::new(text.into().strong()))
     }
 
     /// Show text that is weaker (fainter color).
     ///
     /// Shortcut for `ui.label(RichText::new(text).weak())`
     pub fn weak(&mut self, text: impl Into<RichText>) -> Response {
-        Label::new(text.into().weak()).ui(self)
+        self.add(Label::new(text.into().weak()))
     }
 
     /// Looks like a hyperlink.
@@ -1975,7 +2160,7 @@ impl Ui {
     /// See also [`Link`].
     #[must_use = "You should check if the user clicked this with `if ui.link(…).clicked() { … } "]
     pub fn link(&mut self, text: impl Into<WidgetText>) -> Response {
-        Link::new(text).ui(self)
+        self.add(Link::new(text))
     }
 
     /// Link to a web page.
@@ -1990,7 +2175,7 @@ impl Ui {
     ///
     /// See also [`Hyperlink`].
     pub fn hyperlink(&mut self, url: impl ToString) -> Response {
-        Hyperlink::new(url).ui(self)
+        self.add(Hyperlink::new(url))
     }
 
     /// Shortcut for `add(Hyperlink::from_label_and_url(label, url))`.
@@ -2003,7 +2188,7 @@ impl Ui {
     ///
     /// See also [`Hyperlink`].
     pub fn hyperlink_to(&mut self, label: impl Into<WidgetText>, url: impl ToString) -> Response {
-        Hyperlink::from_label_and_url(label, url).ui(self)
+        self.add(Hyperlink::from_label_and_url(label, url))
     }
 
     /// No newlines (`\n`) allowed. Pressing enter key will result in the [`TextEdit`] losing focus (`response.lost_focus`).
@@ -2013,7 +2198,7 @@ impl Ui {
         &mut self,
         text: &mut S,
     ) -> Response {
-        TextEdit::singleline(text).ui(self)
+        self.add(TextEdit::singleline(text))
     }
 
     /// A [`TextEdit`] for multiple lines. Pressing enter key will create a new line.
@@ -2023,7 +2208,7 @@ impl Ui {
         &mut self,
         text: &mut S,
     ) -> Response {
-        TextEdit::multiline(text).ui(self)
+        self.add(TextEdit::multiline(text))
     }
 
     /// A [`TextEdit`] for code editing.
@@ -2056,7 +2241,7 @@ impl Ui {
     #[must_use = "You should check if the user clicked this with `if ui.button(…).clicked() { … } "]
     #[inline]
     pub fn button<'a>(&mut self, atoms: impl IntoAtoms<'a>) -> Response {
-        Button::new(atoms).ui(self)
+        self.add(Button::new(atoms))
     }
 
     /// A button as small as normal body text.
@@ -2066,7 +2251,7 @@ impl Ui {
     /// Shortcut for `add(Button::new(text).small())`
     #[must_use = "You should check if the user clicked this with `if ui.small_button(…).clicked() { … } "]
     pub fn small_button(&mut self, text: impl Into<WidgetText>) -> Response {
-        Button::new(text).small().ui(self)
+        self.add(Button::new(text).small())
     }
 
     /// Show a checkbox.
@@ -2074,7 +2259,7 @@ impl Ui {
     /// See also [`Self::toggle_value`].
     #[inline]
     pub fn checkbox<'a>(&mut self, checked: &'a mut bool, atoms: impl IntoAtoms<'a>) -> Response {
-        Checkbox::new(checked, atoms).ui(self)
+        self.add(Checkbox::new(checked, atoms))
     }
 
     /// Acts like a checkbox, but looks like a [`Button::selectable`].
@@ -2084,7 +2269,7 @@ impl Ui {
     /// See also [`Self::checkbox`].
     pub fn toggle_value<'a>(&mut self, selected: &mut bool, atoms: impl IntoAtoms<'a>) -> Response {
         let mut response = self.selectable_label(*selected, atoms);
-        if response.clicked() {
+        if response.clicked() && !self.read_only {
             *selected = !*selected;
             response.mark_changed();
         }
@@ -2096,7 +2281,7 @@ impl Ui {
     #[must_use = "You should check if the user clicked this with `if ui.radio(…).clicked() { … } "]
     #[inline]
     pub fn radio<'a>(&mut self, selected: bool, atoms: impl IntoAtoms<'a>) -> Response {
-        RadioButton::new(selected, atoms).ui(self)
+        self.add(RadioButton::new(selected, atoms))
     }
 
     /// Show a [`RadioButton`]. It is selected if `*current_value == selected_value`.
@@ -2137,7 +2322,7 @@ impl Ui {
     /// See also [`Button::selectable`] and [`Self::toggle_value`].
     #[must_use = "You should check if the user clicked this with `if ui.selectable_label(…).clicked() { … } "]
     pub fn selectable_label<'a>(&mut self, checked: bool, text: impl IntoAtoms<'a>) -> Response {
-        Button::selectable(checked, text).ui(self)
+        self.add(Button::selectable(checked, text))
     }
 
     /// Show selectable text. It is selected if `*current_value == selected_value`.
@@ -2153,7 +2338,7 @@ impl Ui {
         text: impl IntoAtoms<'a>,
     ) -> Response {
         let mut response = self.selectable_label(*current_value == selected_value, text);
-        if response.clicked() && *current_value != selected_value {
+        if response.clicked() && !self.read_only && *current_value != selected_value {
             *current_value = selected_value;
             response.mark_changed();
         }
@@ -2165,7 +2350,7 @@ impl Ui {
     /// See also [`Separator`].
     #[inline]
     pub fn separator(&mut self) -> Response {
-        Separator::default().ui(self)
+        self.add(Separator::default())
     }
 
     /// Shortcut for `add(Spinner::new())`
@@ -2173,7 +2358,65 @@ impl Ui {
     /// See also [`Spinner`].
     #[inline]
     pub fn spinner(&mut self) -> Response {
-        Spinner::new().ui(self)
+        self.add(Spinner::new())
+    }
+
+    /// Show the result of a [`crate::Task`] if it is done, otherwise show something else,
+    /// e.g. a spinner.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut task = egui::Task::from_ready(vec![1, 2, 3]);
+    /// ui.suspense(
+    ///     &mut task,
+    ///     |ui, numbers| {
+    ///         for number in numbers.iter() {
+    ///             ui.label(number.to_string());
+    ///         }
+    ///     },
+    ///     |ui| {
+    ///         ui.spinner();
+    ///     },
+    /// );
+    /// # });
+    /// ```
+    pub fn suspense<T, R>(
+        &mut self,
+        task: &mut crate::Task<T>,
+        add_ready: impl FnOnce(&mut Self, &mut T) -> R,
+        add_pending: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        match task.ready_mut() {
+            Some(value) => add_ready(self, value),
+            None => add_pending(self),
+        }
+    }
+
+    /// Edit a value shared with other threads, e.g. an `Arc<RwLock<T>>` or an atomic,
+    /// using any widget.
+    ///
+    /// The value is copied out, edited by `add_contents`, and written back if it changed.
+    /// The lock is not held while `add_contents` runs.
+    /// See [`crate::SharedValue`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let name = std::sync::Arc::new(std::sync::Mutex::new(String::new()));
+    /// ui.edit_shared(&name, |ui, name| ui.text_edit_singleline(name));
+    /// # });
+    /// ```
+    pub fn edit_shared<T: Clone + PartialEq, R>(
+        &mut self,
+        shared: &impl crate::SharedValue<T>,
+        add_contents: impl FnOnce(&mut Self, &mut T) -> R,
+    ) -> R {
+        let before = shared.get();
+        let mut value = before.clone();
+        let ret = add_contents(self, &mut value);
+        if value != before {
+            shared.set(value);
+        }
+        ret
     }
 
     /// Modify an angle. The given angle should be in radians, but is shown to the user in degrees.
@@ -2242,7 +2485,7 @@ impl Ui {
     /// See also [`crate::Image`], [`crate::ImageSource`].
     #[inline]
     pub fn image<'a>(&mut self, source: impl Into<ImageSource<'a>>) -> Response {
-        Image::new(source).ui(self)
+        self.add(Image::new(source))
     }
 }
 
@@ -3183,6 +3426,13 @@ fn register_rect(ui: &Ui, rect: Rect) {
         }
     }
 
+    if debug.show_overflow
+        && !ui.clip_rect().expand(0.5).contains_rect(rect)
+        && !ui.stack().contained_in(UiKind::ScrollArea)
+    {
+        register_overflow(ui, rect, "clipped");
+    }
+
     let show_callstacks = debug.debug_on_hover
         || debug.debug_on_hover_with_all_modifiers && ui.input(|i| i.modifiers.all());
 
@@ -3240,6 +3490,37 @@ fn register_rect(ui: &Ui, rect: Rect) {
 #[cfg(not(debug_assertions))]
 fn register_rect(_ui: &Ui, _rect: Rect) {}
 
+/// Remember that this widget didn't fit, if [`crate::style::DebugOptions::show_overflow`] is set.
+#[cfg(debug_assertions)]
+pub(crate) fn register_overflow(ui: &Ui, rect: Rect, reason: &'static str) {
+    /// Capturing callstacks is slow, so stop after this many.
+    const MAX_OVERFLOWS: usize = 100;
+
+    if !ui.style().debug.show_overflow {
+        return;
+    }
+    if ui.ctx().pass_state(|fs| fs.overflows.len()) >= MAX_OVERFLOWS {
+        return;
+    }
+
+    #[cfg(feature = "callstack")]
+    let callstack = crate::callstack::capture();
+
+    #[cfg(not(feature = "callstack"))]
+    let callstack = String::default();
+
+    let overflow = pass_state::OverflowWarning {
+        layer_id: ui.layer_id(),
+        rect,
+        reason,
+        callstack,
+    };
+    ui.ctx().pass_state_mut(|fs| fs.overflows.push(overflow));
+}
+
+#[cfg(not(debug_assertions))]
+pub(crate) fn register_overflow(_ui: &Ui, _rect: Rect, _reason: &'static str) {}
+
 #[test]
 fn ui_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
@@ -19,6 +19,7 @@ pub struct UiBuilder {
     pub max_rect: Option<Rect>,
     pub layout: Option<Layout>,
     pub disabled: bool,
+    pub read_only: bool,
     pub invisible: bool,
     pub sizing_pass: bool,
     pub style: Option<Arc<Style>>,
@@ -92,6 +93,18 @@ impl UiBuilder {
         self
     }
 
+    /// Make the new `Ui` read-only: widgets show their values in the usual colors,
+    /// but the user can't change them.
+    ///
+    /// Note that if the parent `Ui` is read-only, the child will always be read-only.
+    ///
+    /// See [`Ui::read_only`].
+    #[inline]
+    pub fn read_only(mut self) -> Self {
+        self.read_only = true;
+        self
+    }
+
     /// Make the contents invisible.
     ///
     /// Will also disable the `Ui` (see [`Self::disabled`]).
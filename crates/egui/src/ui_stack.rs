@@ -27,6 +27,9 @@ pub enum UiKind {
     /// A modal [`crate::Modal`].
     Modal,
 
+    /// A [`crate::BottomSheet`].
+    BottomSheet,
+
     /// A [`crate::Frame`].
     Frame,
 
@@ -90,6 +93,7 @@ impl UiKind {
             Self::Window
             | Self::Menu
             | Self::Modal
+            | Self::BottomSheet
             | Self::Popup
             | Self::Tooltip
             | Self::Picker
@@ -112,6 +112,25 @@ where
         }
     }
 
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Change the settings, dropping the oldest undo points if there are now too many.
+    pub fn set_settings(&mut self, settings: Settings) {
+        self.settings = settings;
+        while self.undos.len() > self.settings.max_undos {
+            self.undos.pop_front();
+        }
+    }
+
+    /// Forget all undo and redo points, but keep the settings.
+    pub fn clear(&mut self) {
+        self.undos.clear();
+        self.redos.clear();
+        self.flux = None;
+    }
+
     /// Do we have an undo point different from the given state?
     pub fn has_undo(&self, current_state: &State) -> bool {
         match self.undos.len() {
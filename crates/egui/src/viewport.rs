@@ -69,7 +69,7 @@
 
 use std::sync::Arc;
 
-use epaint::{Pos2, Vec2};
+use epaint::{Pos2, Rect, Vec2};
 
 use crate::{Context, Id};
 
@@ -198,6 +198,28 @@ impl From<IconData> for epaint::ColorImage {
     }
 }
 
+impl From<&epaint::ColorImage> for IconData {
+    fn from(image: &epaint::ColorImage) -> Self {
+        profiling::function_scope!();
+        Self {
+            rgba: image
+                .pixels
+                .iter()
+                .flat_map(|color| color.to_srgba_unmultiplied())
+                .collect(),
+            width: image.size[0] as u32,
+            height: image.size[1] as u32,
+        }
+    }
+}
+
+impl From<epaint::ColorImage> for IconData {
+    #[inline]
+    fn from(image: epaint::ColorImage) -> Self {
+        Self::from(&image)
+    }
+}
+
 impl From<&IconData> for epaint::ColorImage {
     fn from(icon: &IconData) -> Self {
         profiling::function_scope!();
@@ -283,6 +305,7 @@ pub struct ViewportBuilder {
     pub maximized: Option<bool>,
     pub resizable: Option<bool>,
     pub transparent: Option<bool>,
+    pub backdrop: Option<Backdrop>,
     pub decorations: Option<bool>,
     pub icon: Option<Arc<IconData>>,
     pub active: Option<bool>,
@@ -310,6 +333,19 @@ pub struct ViewportBuilder {
 
     // X11
     pub window_type: Option<X11WindowType>,
+
+    /// If set, this viewport is a modal dialog of the given viewport.
+    ///
+    /// See [`Self::with_modal_owner`].
+    pub modal_owner: Option<ViewportId>,
+
+    /// Remember the position and size of this viewport across runs.
+    ///
+    /// See [`Self::with_persisted_geometry`].
+    pub persisted_geometry: Option<bool>,
+
+    /// See [`Self::with_app_bar`].
+    pub app_bar: Option<AppBar>,
 }
 
 impl ViewportBuilder {
@@ -391,10 +427,26 @@ impl ViewportBuilder {
         self
     }
 
+    /// Let the OS blur what is behind the window, or fill it with a material like Mica.
+    ///
+    /// Only shows through where egui paints transparent colors,
+    /// so combine it with [`Self::with_transparent`] and a transparent `clear_color` in `eframe`.
+    ///
+    /// The default is [`Backdrop::None`].
+    /// See [`Backdrop`] for what is supported where.
+    #[inline]
+    pub fn with_backdrop(mut self, backdrop: Backdrop) -> Self {
+        self.backdrop = Some(backdrop);
+        self
+    }
+
     /// The application icon, e.g. in the Windows task bar or the alt-tab menu.
     ///
     /// The default icon is a white `e` on a black background (for "egui" or "eframe").
     /// If you prefer the OS default, set this to `IconData::default()`.
+    ///
+    /// Each viewport can have its own icon, e.g. from a [`epaint::ColorImage`].
+    /// Viewports without one use the icon of their parent.
     #[inline]
     pub fn with_icon(mut self, icon: impl Into<Arc<IconData>>) -> Self {
         self.icon = Some(icon.into());
@@ -480,12 +532,26 @@ impl ViewportBuilder {
     }
 
     /// windows: Whether show or hide the window icon in the taskbar.
+    ///
+    /// See also [`Self::with_skip_taskbar`].
     #[inline]
     pub fn with_taskbar(mut self, show: bool) -> Self {
         self.taskbar = Some(show);
         self
     }
 
+    /// Keep the window out of the taskbar, e.g. for palettes and tool windows,
+    /// so that only the main window shows up there.
+    ///
+    /// The same as `with_taskbar(!skip)`.
+    ///
+    /// Supported on Windows.
+    /// On macOS the dock only ever shows the app, so there is nothing to skip.
+    #[inline]
+    pub fn with_skip_taskbar(self, skip: bool) -> Self {
+        self.with_taskbar(!skip)
+    }
+
     /// Requests the window to be of specific dimensions.
     ///
     /// If this is not set, some platform-specific dimensions will be used.
@@ -625,6 +691,34 @@ impl ViewportBuilder {
         self.with_window_level(WindowLevel::AlwaysOnTop)
     }
 
+    /// Make this an overlay over other apps, e.g. for a HUD or annotations:
+    /// transparent, always on top, without decorations, shadow or taskbar entry,
+    /// and not taking the keyboard focus when it opens.
+    ///
+    /// To let clicks through to the apps below, call [`crate::Context::set_mouse_hit_test`]
+    /// from the ui of the overlay, e.g. with [`crate::MouseHitTest::Interactive`]
+    /// to only keep the clicks on its buttons.
+    /// Use [`crate::Frame::NONE`] for the [`crate::CentralPanel`] of the overlay,
+    /// and in `eframe` a transparent `clear_color`, so that the other apps show through.
+    ///
+    /// Use [`Self::with_fullscreen`] or [`Self::with_position`] and [`Self::with_inner_size`]
+    /// to choose what the overlay covers.
+    ///
+    /// ## Platform-specific
+    ///
+    /// Supported on Windows, macOS, and X11.
+    /// On Wayland the compositor decides whether the window stays on top,
+    /// and transparency depends on the graphics backend.
+    #[inline]
+    pub fn with_overlay(self) -> Self {
+        self.with_transparent(true)
+            .with_always_on_top()
+            .with_decorations(false)
+            .with_has_shadow(false)
+            .with_taskbar(false)
+            .with_active(false)
+    }
+
     /// On desktop: mouse clicks pass through the window, used for non-interactable overlays.
     ///
     /// Generally you would use this in conjunction with [`Self::with_transparent`]
@@ -644,6 +738,45 @@ impl ViewportBuilder {
         self
     }
 
+    /// Make this viewport a modal dialog of the `owner` viewport,
+    /// usually the parent.
+    ///
+    /// While the viewport is shown, the owner gets no pointer or keyboard input.
+    /// Clicking the owner brings the modal viewport to the front instead.
+    ///
+    /// See also [`crate::Context::show_modal_viewport_immediate`].
+    #[inline]
+    pub fn with_modal_owner(mut self, owner: ViewportId) -> Self {
+        self.modal_owner = Some(owner);
+        self
+    }
+
+    /// Remember the outer position, size, maximized state and monitor of this viewport
+    /// across runs, the same way `eframe` remembers the root window.
+    ///
+    /// The geometry is saved per [`ViewportId`], so give the viewport a stable id,
+    /// e.g. `ViewportId::from_hash_of("settings")`.
+    /// When restoring, the saved geometry takes precedence over the position and size
+    /// set in the builder.
+    ///
+    /// Requires the `persistence` feature of `eframe`.
+    /// Has no effect on the root viewport, which is controlled by `NativeOptions::persist_window`.
+    #[inline]
+    pub fn with_persisted_geometry(mut self, value: bool) -> Self {
+        self.persisted_geometry = Some(value);
+        self
+    }
+
+    /// Dock the window to an edge of its monitor like a taskbar, e.g. for a utility strip.
+    ///
+    /// See [`AppBar`] for what is supported where.
+    /// Undock it again with [`ViewportCommand::AppBar`]`(None)`.
+    #[inline]
+    pub fn with_app_bar(mut self, app_bar: AppBar) -> Self {
+        self.app_bar = Some(app_bar);
+        self
+    }
+
     /// Update this `ViewportBuilder` with a delta,
     /// returning a list of commands and a bool indicating if the window needs to be recreated.
     #[must_use]
@@ -660,6 +793,7 @@ impl ViewportBuilder {
             maximized: new_maximized,
             resizable: new_resizable,
             transparent: new_transparent,
+            backdrop: new_backdrop,
             decorations: new_decorations,
             icon: new_icon,
             active: new_active,
@@ -678,6 +812,9 @@ impl ViewportBuilder {
             mouse_passthrough: new_mouse_passthrough,
             taskbar: new_taskbar,
             window_type: new_window_type,
+            modal_owner: new_modal_owner,
+            persisted_geometry: new_persisted_geometry,
+            app_bar: new_app_bar,
         } = new_vp_builder;
 
         let mut commands = Vec::new();
@@ -745,6 +882,13 @@ impl ViewportBuilder {
             }
         }
 
+        if let Some(new_backdrop) = new_backdrop {
+            if Some(new_backdrop) != self.backdrop {
+                self.backdrop = Some(new_backdrop);
+                commands.push(ViewportCommand::Backdrop(new_backdrop));
+            }
+        }
+
         if let Some(new_decorations) = new_decorations {
             if Some(new_decorations) != self.decorations {
                 self.decorations = Some(new_decorations);
@@ -771,6 +915,13 @@ impl ViewportBuilder {
             }
         }
 
+        if let Some(new_taskbar) = new_taskbar {
+            if Some(new_taskbar) != self.taskbar {
+                self.taskbar = Some(new_taskbar);
+                commands.push(ViewportCommand::Taskbar(new_taskbar));
+            }
+        }
+
         if let Some(new_mouse_passthrough) = new_mouse_passthrough {
             if Some(new_mouse_passthrough) != self.mouse_passthrough {
                 self.mouse_passthrough = Some(new_mouse_passthrough);
@@ -846,11 +997,6 @@ impl ViewportBuilder {
             recreate_window = true;
         }
 
-        if new_taskbar.is_some() && self.taskbar != new_taskbar {
-            self.taskbar = new_taskbar;
-            recreate_window = true;
-        }
-
         if new_fullsize_content_view.is_some()
             && self.fullsize_content_view != new_fullsize_content_view
         {
@@ -875,6 +1021,21 @@ impl ViewportBuilder {
             recreate_window = true;
         }
 
+        if new_modal_owner.is_some() {
+            // Handled by egui, so nothing for the integration to do.
+            self.modal_owner = new_modal_owner;
+        }
+
+        if new_persisted_geometry.is_some() {
+            // Handled by the integration when saving and creating windows.
+            self.persisted_geometry = new_persisted_geometry;
+        }
+
+        if new_app_bar.is_some() && self.app_bar != new_app_bar {
+            self.app_bar = new_app_bar;
+            commands.push(ViewportCommand::AppBar(new_app_bar));
+        }
+
         (commands, recreate_window)
     }
 }
@@ -888,6 +1049,76 @@ pub enum WindowLevel {
     AlwaysOnTop,
 }
 
+/// What the OS shows behind a transparent window, set with [`ViewportBuilder::with_backdrop`].
+///
+/// Where an effect isn't available, the closest one that is is used,
+/// and where none are, the window is just transparent.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Backdrop {
+    /// No effect; what is behind a transparent window shows through as it is.
+    #[default]
+    None,
+
+    /// Blur what is behind the window.
+    ///
+    /// Supported on macOS and on Wayland compositors with the KDE blur protocol.
+    /// Acrylic on Windows 11.
+    Blur,
+
+    /// The blurred and tinted acrylic material of Windows 11, for transient windows
+    /// like menus and popups.
+    ///
+    /// [`Self::Blur`] elsewhere.
+    Acrylic,
+
+    /// The Mica material of Windows 11, tinted by the desktop wallpaper, for main windows.
+    ///
+    /// [`Self::Blur`] elsewhere.
+    Mica,
+
+    /// The vibrancy of macOS, which is what [`Self::Blur`] gives there.
+    ///
+    /// Acrylic on Windows 11.
+    Vibrancy,
+}
+
+/// An edge of a monitor, for [`AppBar`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ScreenEdge {
+    Left,
+    Top,
+    Right,
+    Bottom,
+}
+
+/// Dock a window to an edge of its monitor like a taskbar,
+/// with [`ViewportBuilder::with_app_bar`] or [`ViewportCommand::AppBar`].
+///
+/// The window is moved to cover the whole edge, `thickness` points deep,
+/// and loses its decorations and stays on top of other windows.
+///
+/// ## Platform-specific
+///
+/// On Windows the window is registered as an appbar, like the taskbar:
+/// the space is reserved, so that maximized windows leave it free,
+/// and several appbars on the same edge are placed next to each other.
+///
+/// Elsewhere the space is not reserved, so other windows may cover it when they are maximized.
+/// On X11, [`X11WindowType::Dock`] makes most window managers keep the window above the others.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AppBar {
+    pub edge: ScreenEdge,
+
+    /// How far the window reaches into the monitor from the edge, in points.
+    pub thickness: f32,
+}
+
+// Like `Pos2`, we assume the thickness is never NaN.
+impl Eq for AppBar {}
+
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum X11WindowType {
@@ -983,6 +1214,103 @@ pub enum ResizeDirection {
     SouthWest,
 }
 
+/// The easing curve of [`ViewportCommand::AnimateOuterPosition`] and
+/// [`ViewportCommand::AnimateInnerSize`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ViewportEasing {
+    /// Constant speed.
+    Linear,
+
+    /// Start slow, end fast.
+    EaseIn,
+
+    /// Start fast, end slow.
+    EaseOut,
+
+    /// Start and end slow.
+    #[default]
+    EaseInOut,
+}
+
+impl ViewportEasing {
+    /// Map the linear progress `t` in `0.0..=1.0` to the eased progress.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => emath::easing::linear(t),
+            Self::EaseIn => emath::easing::cubic_in(t),
+            Self::EaseOut => emath::easing::cubic_out(t),
+            Self::EaseInOut => emath::easing::cubic_in_out(t),
+        }
+    }
+}
+
+/// Where the mouse can interact with a viewport, set with [`crate::Context::set_mouse_hit_test`].
+///
+/// Elsewhere, clicks pass through the window to whatever is below it,
+/// which is useful for overlays and HUDs.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum MouseHitTest {
+    /// The whole window, as usual.
+    #[default]
+    Everywhere,
+
+    /// Only inside these rectangles, in ui points.
+    Regions(Vec<Rect>),
+
+    /// Only where egui painted something.
+    ///
+    /// Make sure the background is not painted, e.g. by using [`crate::Frame::NONE`]
+    /// for your [`crate::CentralPanel`], or else it covers the whole window.
+    Painted,
+
+    /// Only on the enabled widgets that sense clicks or drags, e.g. the buttons of a HUD,
+    /// and on [`crate::Window`]s.
+    ///
+    /// Everything else that is painted, e.g. backgrounds and drawings, lets clicks through.
+    /// Labels with selectable text sense clicks too,
+    /// so turn off [`crate::style::Interaction::selectable_labels`] to let clicks through them.
+    Interactive,
+}
+
+/// The shape of a window, for [`ViewportCommand::SetWindowShape`].
+///
+/// In ui points, relative to the top-left corner of the inside of the window.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum WindowShape {
+    /// A rectangle with corners rounded by this radius.
+    RoundedRect(f32),
+
+    /// An ellipse filling the window, or a circle if the window is square.
+    Ellipse,
+
+    /// Any polygon.
+    ///
+    /// Self-intersecting polygons have holes, like with the even-odd rule.
+    Polygon(Vec<Pos2>),
+}
+
+impl WindowShape {
+    /// Is `pos` inside the shape of a window of the given inner `size`?
+    pub fn contains(&self, size: Vec2, pos: Pos2) -> bool {
+        let rect = Rect::from_min_size(Pos2::ZERO, size);
+        match self {
+            Self::RoundedRect(radius) => {
+                let radius = radius.clamp(0.0, 0.5 * size.min_elem());
+                rect.contains(pos) && rect.shrink(radius).distance_sq_to_pos(pos) <= radius * radius
+            }
+            Self::Ellipse => {
+                let d = (pos - rect.center()) / (0.5 * size);
+                d.length_sq() <= 1.0
+            }
+            Self::Polygon(points) => emath::geometry::polygon_contains(points, pos),
+        }
+    }
+}
+
 /// An output [viewport](crate::viewport)-command from egui to the backend, e.g. to change the window title or size.
 ///
 /// You can send a [`ViewportCommand`] to the viewport with [`Context::send_viewport_cmd`].
@@ -995,7 +1323,7 @@ pub enum ResizeDirection {
 ///
 /// Only commands specific to a viewport are part of [`ViewportCommand`].
 /// Other commands should be put in [`crate::OutputCommand`].
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum ViewportCommand {
     /// Request this viewport to be closed.
@@ -1013,6 +1341,11 @@ pub enum ViewportCommand {
     /// Turn the window transparent or not.
     Transparent(bool),
 
+    /// Change the effect behind the window.
+    ///
+    /// See [`ViewportBuilder::with_backdrop`].
+    Backdrop(Backdrop),
+
     /// Set the visibility of the window.
     Visible(bool),
 
@@ -1058,8 +1391,51 @@ pub enum ViewportCommand {
     Maximized(bool),
 
     /// Turn borderless fullscreen on/off.
+    ///
+    /// Turning it off also ends [`Self::FullscreenOnRect`].
     Fullscreen(bool),
 
+    /// Make the window borderless and cover this rectangle, e.g. to span several monitors
+    /// for a video wall or a kiosk.
+    ///
+    /// The rectangle is in points, in the same space as [`crate::MonitorInfo::rect`],
+    /// so to cover a set of monitors, use the union of their rectangles.
+    /// [`crate::ViewportInfo::fullscreen`] is `true` until [`Self::Fullscreen`]`(false)`
+    /// restores the window to where it was.
+    ///
+    /// Task bars and docks may still be drawn on top of the window.
+    /// Use [`WindowLevel::AlwaysOnTop`] if that is a problem.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// let all_monitors = ctx.input(|i| {
+    ///     i.raw.monitors.iter().fold(egui::Rect::NOTHING, |rect, m| rect.union(m.rect))
+    /// });
+    /// if all_monitors.is_positive() {
+    ///     ctx.send_viewport_cmd(egui::ViewportCommand::FullscreenOnRect(all_monitors));
+    /// }
+    /// ```
+    FullscreenOnRect(Rect),
+
+    /// Make the window borderless fullscreen on one of [`crate::RawInput::monitors`],
+    /// given by its index.
+    ///
+    /// [`Self::Fullscreen`]`(false)` leaves fullscreen again.
+    FullscreenOnMonitor(usize),
+
+    /// Make the window fullscreen on one of [`crate::RawInput::monitors`], given by its index,
+    /// switching the monitor to one of its [`crate::MonitorInfo::video_modes`].
+    ///
+    /// With no `video_mode`, the one with the highest resolution and refresh rate is used.
+    ///
+    /// Exclusive fullscreen can have less latency than borderless fullscreen,
+    /// but switching to and from it is slow, and it is not supported everywhere, e.g. on Wayland
+    /// and web. [`Self::Fullscreen`]`(false)` leaves it again.
+    ExclusiveFullscreen {
+        monitor: usize,
+        video_mode: Option<crate::VideoMode>,
+    },
+
     /// Show window decorations, i.e. the chrome around the content
     /// with the title bar, close buttons, resize handles, etc.
     Decorations(bool),
@@ -1068,8 +1444,23 @@ pub enum ViewportCommand {
     WindowLevel(WindowLevel),
 
     /// The window icon.
+    ///
+    /// See [`ViewportBuilder::with_icon`].
     Icon(Option<Arc<IconData>>),
 
+    /// Show or hide the window in the taskbar.
+    ///
+    /// See [`ViewportBuilder::with_skip_taskbar`].
+    Taskbar(bool),
+
+    /// Move the window to another virtual desktop (workspace).
+    ///
+    /// Supported by `eframe` on Windows ([`crate::Workspace::Guid`])
+    /// and X11 ([`crate::Workspace::Index`] and [`crate::Workspace::All`]).
+    /// To keep a tool window with the user on X11, move it to [`crate::Workspace::All`],
+    /// or follow the workspace of the main window in [`crate::ViewportInfo::workspace`].
+    MoveToWorkspace(crate::Workspace),
+
     /// Set the IME cursor editing area.
     IMERect(crate::Rect),
     IMEAllowed(bool),
@@ -1108,9 +1499,37 @@ pub enum ViewportCommand {
     /// Enable mouse pass-through: mouse clicks pass through the window, used for non-interactable overlays.
     MousePassthrough(bool),
 
+    /// Only let the mouse interact with these parts of the window, in ui points.
+    ///
+    /// Clicks elsewhere pass through the window. `None` means the whole window.
+    ///
+    /// egui sends this whenever the regions change,
+    /// so use [`crate::Context::set_mouse_hit_test`] instead of sending it yourself.
+    /// Don't combine it with [`Self::MousePassthrough`].
+    MouseHitTestRegions(Option<Vec<Rect>>),
+
+    /// Give the window a shape other than a rectangle, e.g. a circle.
+    ///
+    /// `None` makes it a rectangle again.
+    ///
+    ///
+    /// Outside of the shape clicks pass through the window.
+    /// Use it together with [`ViewportBuilder::with_decorations`]`(false)`
+    /// and [`ViewportBuilder::with_transparent`]`(true)`, and paint the background of
+    /// your ui in the same shape, because not all platforms cut the window to the shape.
+    SetWindowShape(Option<WindowShape>),
+
+    /// Dock the window to an edge of its monitor like a taskbar, or undock it with `None`.
+    ///
+    /// See [`AppBar`] for what is supported where.
+    /// Undocking leaves the window where it is, without decorations.
+    AppBar(Option<AppBar>),
+
     /// Take a screenshot of the next frame after this.
     ///
-    /// The results are returned in [`crate::Event::Screenshot`].
+    /// The results are returned in [`crate::Event::Screenshot`], to the viewport
+    /// the screenshot was taken of.
+    /// Use [`crate::Context::screenshot_viewport`] to get screenshots of other viewports.
     Screenshot(crate::UserData),
 
     /// Request cut of the current selection
@@ -1127,8 +1546,55 @@ pub enum ViewportCommand {
     ///
     /// This is equivalent to the system keyboard shortcut for paste (e.g. CTRL + V).
     RequestPaste,
+
+    /// Show the progress of a long operation in the taskbar or dock, in the range `0.0..=1.0`.
+    ///
+    /// This stays visible when the window is minimized. `None` hides it.
+    ///
+    /// Supported by `eframe` on Windows (taskbar button of this viewport),
+    /// and on Linux desktops implementing the Unity launcher API (e.g. KDE, Ubuntu Dock).
+    SetTaskbarProgress(Option<f32>),
+
+    /// Show a short text, such as an unread count, on the app icon in the dock or launcher.
+    ///
+    /// `None` removes it.
+    ///
+    /// Supported by `eframe` on macOS (dock badge),
+    /// and on Linux desktops implementing the Unity launcher API, if the text is a number.
+    SetBadge(Option<String>),
+
+    /// Smoothly move the window to this position over `duration` seconds.
+    ///
+    /// Like [`Self::OuterPosition`], but the integration moves the window a little each frame,
+    /// so you don't need to send a new command every frame yourself.
+    /// A later [`Self::OuterPosition`] or [`Self::AnimateOuterPosition`] replaces the animation.
+    ///
+    /// If the current position is unknown (e.g. on Wayland) or `duration` is zero,
+    /// the window is moved immediately.
+    AnimateOuterPosition {
+        target: Pos2,
+
+        /// In seconds.
+        duration: f32,
+        easing: ViewportEasing,
+    },
+
+    /// Smoothly resize the window to this inner size over `duration` seconds.
+    ///
+    /// Like [`Self::InnerSize`], but the integration resizes the window a little each frame.
+    /// A later [`Self::InnerSize`] or [`Self::AnimateInnerSize`] replaces the animation.
+    AnimateInnerSize {
+        target: Vec2,
+
+        /// In seconds.
+        duration: f32,
+        easing: ViewportEasing,
+    },
 }
 
+// Like `Pos2`, we assume the progress and durations are never NaN.
+impl Eq for ViewportCommand {}
+
 impl ViewportCommand {
     /// Construct a command to center the viewport on the monitor, if possible.
     pub fn center_on_screen(ctx: &crate::Context) -> Option<Self> {
@@ -0,0 +1,286 @@
+use std::f32::consts::TAU;
+
+use crate::{
+    DragValue, EventFilter, Key, NumExt as _, Response, Sense, Stroke, Ui, ValueFormat, Vec2,
+    Widget, WidgetInfo, emath,
+};
+
+/// Edit an angle by turning a dial, e.g. a rotation or a direction.
+///
+/// The angle is in radians. Zero points right, and positive angles turn clockwise on screen,
+/// the same as [`Vec2::angled`].
+///
+/// Dragging snaps to 15° steps. Hold shift to turn the dial freely.
+/// The value is shown next to the dial, and can be edited as text.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut angle: f32 = 0.0;
+/// ui.add(egui::AngleDial::new(&mut angle));
+/// ui.add(egui::AngleDial::new(&mut angle).value_format(egui::ValueFormat::Radians));
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct AngleDial<'a> {
+    angle: &'a mut f32,
+    diameter: Option<f32>,
+    snap_step: f32,
+    wrap: bool,
+    show_value: bool,
+    value_format: ValueFormat,
+}
+
+impl<'a> AngleDial<'a> {
+    pub fn new(angle: &'a mut f32) -> Self {
+        Self {
+            angle,
+            diameter: None,
+            snap_step: 15_f32.to_radians(),
+            wrap: true,
+            show_value: true,
+            value_format: ValueFormat::Degrees,
+        }
+    }
+
+    /// The size of the dial.
+    ///
+    /// Default: twice the height of [`crate::style::Spacing::interact_size`].
+    #[inline]
+    pub fn diameter(mut self, diameter: f32) -> Self {
+        self.diameter = Some(diameter);
+        self
+    }
+
+    /// Snap to multiples of this many radians while dragging, unless shift is held.
+    ///
+    /// This is also how much the arrow keys turn the dial.
+    /// Set to zero to never snap.
+    ///
+    /// Default: 15°.
+    #[inline]
+    pub fn snap_step(mut self, step: f32) -> Self {
+        self.snap_step = step.at_least(0.0);
+        self
+    }
+
+    /// Keep the angle in `[0, τ)`.
+    ///
+    /// If `false`, each full turn of the dial adds or removes τ (360°),
+    /// e.g. for rotations that should be animated several turns.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Show the value next to the dial, where it can be dragged or edited as text.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn show_value(mut self, show_value: bool) -> Self {
+        self.show_value = show_value;
+        self
+    }
+
+    /// How to show the value, e.g. [`ValueFormat::Degrees`] (the default)
+    /// or [`ValueFormat::Radians`].
+    ///
+    /// Both formats can parse the other's unit, so the user can type e.g. "1.5 rad" or "90°".
+    #[inline]
+    pub fn value_format(mut self, value_format: ValueFormat) -> Self {
+        self.value_format = value_format;
+        self
+    }
+
+    fn dial_ui(&mut self, ui: &Ui, response: &Response) {
+        let rect = response.rect;
+        let free = ui.input(|i| i.modifiers.shift);
+        let snap_step = if free { 0.0 } else { self.snap_step };
+
+        if let Some(pointer_pos) = response.interact_pointer_pos() {
+            let offset = pointer_pos - rect.center();
+            // Near the center the direction is too jittery to be useful:
+            if 2.0 < offset.length() {
+                *self.angle = turned_angle(*self.angle, offset.angle(), snap_step, self.wrap);
+            }
+        }
+
+        if response.has_focus() {
+            ui.ctx().memory_mut(|m| {
+                m.set_focus_lock_filter(
+                    response.id,
+                    EventFilter {
+                        horizontal_arrows: true,
+                        vertical_arrows: true,
+                        ..Default::default()
+                    },
+                );
+            });
+
+            let steps = ui.input(|i| {
+                i.num_presses(Key::ArrowRight) as i32 + i.num_presses(Key::ArrowDown) as i32
+                    - i.num_presses(Key::ArrowLeft) as i32
+                    - i.num_presses(Key::ArrowUp) as i32
+            });
+            if steps != 0 {
+                let step = if snap_step == 0.0 {
+                    1_f32.to_radians()
+                } else {
+                    snap_step
+                };
+                let target = *self.angle + steps as f32 * step;
+                *self.angle = snapped(target, snap_step, self.wrap);
+            }
+        }
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.editing_visuals(response);
+            let radius = rect.width() / 2.0 - 1.0;
+            let center = rect.center();
+            let painter = ui.painter();
+
+            painter.circle(center, radius, visuals.bg_fill, visuals.bg_stroke);
+
+            // A tick every 45°:
+            let tick_stroke = Stroke::new(1.0, ui.visuals().weak_text_color());
+            for i in 0..8 {
+                let dir = Vec2::angled(i as f32 * TAU / 8.0);
+                painter.line_segment(
+                    [center + 0.8 * radius * dir, center + radius * dir],
+                    tick_stroke,
+                );
+            }
+
+            let dir = Vec2::angled(*self.angle);
+            let tip = center + (radius - 3.0).at_least(0.0) * dir;
+            painter.line_segment([center, tip], visuals.fg_stroke);
+            painter.circle_filled(tip, 2.5 + visuals.expansion, ui.visuals().selection.bg_fill);
+            painter.circle_filled(center, 1.5, visuals.fg_stroke.color);
+        }
+    }
+
+    fn value_ui(&mut self, ui: &mut Ui) -> Response {
+        let mut value = *self.angle;
+        let response = ui.add(
+            DragValue::new(&mut value)
+                .speed(1_f32.to_radians())
+                .value_format(self.value_format.clone()),
+        );
+        if value != *self.angle {
+            *self.angle = if self.wrap { wrapped(value) } else { value };
+        }
+        response
+    }
+}
+
+impl Widget for AngleDial<'_> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let old_angle = *self.angle;
+
+        let diameter = self
+            .diameter
+            .unwrap_or_else(|| 2.0 * ui.spacing().interact_size.y);
+
+        ui.horizontal(|ui| {
+            let sense = ui.editing_sense(Sense::click_and_drag());
+            let (_, mut response) = ui.allocate_exact_size(Vec2::splat(diameter), sense);
+            self.dial_ui(ui, &response);
+
+            if *self.angle != old_angle {
+                response.mark_changed();
+            }
+            response.widget_info(|| {
+                WidgetInfo::slider(ui.is_enabled(), self.angle.to_degrees() as f64, "Angle")
+            });
+
+            if self.show_value {
+                let value_response = self.value_ui(ui);
+                if value_response.gained_focus()
+                    || value_response.has_focus()
+                    || value_response.lost_focus()
+                {
+                    response = value_response.union(response);
+                } else {
+                    response = response.union(value_response);
+                }
+            }
+            response
+        })
+        .inner
+    }
+}
+
+/// Turn `angle` towards the pointer at `pointer_angle`, the short way around.
+///
+/// Going the short way around means the angle never jumps by a whole turn
+/// when the pointer crosses the left side of the dial (±180°).
+fn turned_angle(angle: f32, pointer_angle: f32, snap_step: f32, wrap: bool) -> f32 {
+    let target = angle + emath::normalized_angle(pointer_angle - angle);
+    snapped(target, snap_step, wrap)
+}
+
+fn snapped(angle: f32, snap_step: f32, wrap: bool) -> f32 {
+    let angle = if 0.0 < snap_step {
+        (angle / snap_step).round() * snap_step
+    } else {
+        angle
+    };
+    if wrap { wrapped(angle) } else { angle }
+}
+
+/// Wrap to `[0, τ)`.
+fn wrapped(angle: f32) -> f32 {
+    let angle = angle.rem_euclid(TAU);
+    // `rem_euclid` can round up to `TAU` for tiny negative angles:
+    if angle < TAU { angle } else { 0.0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::{PI, TAU};
+
+    use super::{turned_angle, wrapped};
+
+    fn degrees(angle: f32) -> f32 {
+        (angle.to_degrees() * 1000.0).round() / 1000.0
+    }
+
+    #[test]
+    fn test_turned_angle() {
+        let snap = 15_f32.to_radians();
+
+        assert_eq!(degrees(turned_angle(0.0, 50_f32.to_radians(), snap, true)), 45.0);
+        assert_eq!(degrees(turned_angle(0.0, 50_f32.to_radians(), 0.0, true)), 50.0);
+
+        // Crossing the left side of the dial:
+        let just_below = PI - 0.01;
+        let just_above = -PI + 0.01;
+        let turned = turned_angle(just_below, just_above, 0.0, false);
+        assert!((turned - (PI + 0.01)).abs() < 1e-5, "{turned}");
+        assert_eq!(degrees(turned_angle(just_below, just_above, snap, false)), 180.0);
+
+        // Several turns without wrapping:
+        let mut angle = 0.0;
+        for i in 1..=16 {
+            angle = turned_angle(angle, i as f32 * TAU / 8.0, 0.0, false);
+        }
+        assert!((angle - 2.0 * TAU).abs() < 1e-4, "{angle}");
+
+        // ...and with:
+        let mut angle = 0.0;
+        for i in 1..=15 {
+            angle = turned_angle(angle, i as f32 * TAU / 8.0, snap, true);
+        }
+        assert_eq!(degrees(angle), 315.0);
+    }
+
+    #[test]
+    fn test_wrapped() {
+        assert_eq!(wrapped(0.0), 0.0);
+        assert_eq!(wrapped(TAU), 0.0);
+        assert_eq!(wrapped(-1e-9), 0.0);
+        assert!((wrapped(-PI / 2.0) - 1.5 * PI).abs() < 1e-6);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    Atom, AtomLayout, Atoms, Id, IntoAtoms, NumExt as _, Response, Sense, Shape, Ui, Vec2, Widget,
-    WidgetInfo, WidgetType, epaint, pos2,
+    Atom, AtomLayout, Atoms, Id, IntoAtoms, NumExt as _, Response, Sense, Shape, SoundEvent, Ui,
+    Vec2, Widget, WidgetInfo, WidgetType, epaint, pos2,
 };
 
 // TODO(emilk): allow checkbox without a text label
@@ -70,13 +70,14 @@ impl Widget for Checkbox<'_> {
         let text = atoms.text().map(String::from);
 
         let mut prepared = AtomLayout::new(atoms)
-            .sense(Sense::click())
+            .sense(ui.editing_sense(Sense::click()))
             .min_size(min_size)
             .allocate(ui);
 
         if prepared.response.clicked() {
             *checked = !*checked;
             prepared.response.mark_changed();
+            ui.play_sound(SoundEvent::Toggle);
         }
         prepared.response.widget_info(|| {
             if indeterminate {
@@ -97,7 +98,7 @@ impl Widget for Checkbox<'_> {
 
         if ui.is_rect_visible(prepared.response.rect) {
             // let visuals = ui.style().interact_selectable(&response, *checked); // too colorful
-            let visuals = *ui.style().interact(&prepared.response);
+            let visuals = *ui.editing_visuals(&prepared.response);
             prepared.fallback_text_color = visuals.text_color();
             let response = prepared.paint(ui);
 
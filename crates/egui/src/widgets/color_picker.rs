@@ -87,14 +87,14 @@ pub fn show_color_at(painter: &Painter, color: Color32, rect: Rect) {
 
 fn color_button(ui: &mut Ui, color: Color32, open: bool) -> Response {
     let size = ui.spacing().interact_size;
-    let (rect, response) = ui.allocate_exact_size(size, Sense::click());
+    let (rect, response) = ui.allocate_exact_size(size, ui.editing_sense(Sense::click()));
     response.widget_info(|| WidgetInfo::new(WidgetType::ColorButton));
 
     if ui.is_rect_visible(rect) {
         let visuals = if open {
             &ui.visuals().widgets.open
         } else {
-            ui.style().interact(&response)
+            ui.editing_visuals(&response)
         };
         let rect = rect.expand(visuals.expansion);
 
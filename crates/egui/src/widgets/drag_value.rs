@@ -65,6 +65,21 @@ impl<'a> DragValue<'a> {
         }
     }
 
+    /// Edit a value shared with other threads, e.g. an `Arc<Mutex<i32>>` or an atomic.
+    ///
+    /// The value is only locked while reading and writing it,
+    /// and only written to when the user changes it.
+    /// See [`crate::SharedValue`].
+    pub fn from_shared<Num: emath::Numeric>(value: &'a impl crate::SharedValue<Num>) -> Self {
+        let slf = Self::from_get_set(crate::data::get_set_numeric(value));
+
+        if Num::INTEGRAL {
+            slf.max_decimals(0).range(Num::MIN..=Num::MAX).speed(0.25)
+        } else {
+            slf
+        }
+    }
+
     pub fn from_get_set(get_set_value: impl 'a + FnMut(Option<f64>) -> f64) -> Self {
         Self {
             get_set_value: Box::new(get_set_value),
@@ -415,6 +430,22 @@ impl<'a> DragValue<'a> {
         .custom_parser(|s| i64::from_str_radix(s, 16).map(|n| n as f64).ok())
     }
 
+    /// Set `custom_formatter` and `custom_parser` to show the value with a unit, e.g. "1.5 GiB" or "2h 30m".
+    ///
+    /// See [`crate::ValueFormat`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut bytes: u64 = 0;
+    /// ui.add(egui::DragValue::new(&mut bytes).value_format(egui::ValueFormat::Bytes));
+    /// # });
+    /// ```
+    pub fn value_format(self, format: crate::ValueFormat) -> Self {
+        let parse_format = format.clone();
+        self.custom_formatter(move |n, decimals| format.format(n, decimals))
+            .custom_parser(move |s| parse_format.parse(s))
+    }
+
     /// Update the value on each key press when text-editing the value.
     ///
     /// Default: `true`.
@@ -597,7 +628,7 @@ impl Widget for DragValue<'_> {
                     .text_style(text_style),
             )
             .wrap_mode(TextWrapMode::Extend)
-            .sense(Sense::click_and_drag())
+            .sense(ui.editing_sense(Sense::click_and_drag()))
             .min_size(ui.spacing().interact_size); // TODO(emilk): find some more generic solution to `min_size`
 
             let cursor_icon = if value <= *range.start() {
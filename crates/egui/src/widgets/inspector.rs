@@ -0,0 +1,526 @@
+use std::ops::RangeInclusive;
+
+use emath::{Numeric, Pos2, Vec2, vec2};
+use epaint::{Color32, CornerRadius, Margin, Rgba, Shadow, Stroke, ecolor::Hsva};
+
+use crate::{
+    Align, Button, Checkbox, CollapsingHeader, DragValue, Id, Label, Layout, NumExt as _, Response,
+    Slider, TextEdit, Ui, WidgetText, util::undoer::Undoer,
+};
+
+/// A value that can be edited in an [`Inspector`].
+///
+/// Implemented for numbers, `bool`, `String`, colors, vectors and some style types.
+/// Implement it for your own types to use them with [`Property`],
+/// or use [`InspectorUi::custom`] for one-off editors.
+pub trait InspectorValue {
+    /// Show an editor for the value, and mark the response as changed when it is edited.
+    ///
+    /// `range` comes from [`Property::range`], and is only used by numbers.
+    fn inspector_ui(&mut self, ui: &mut Ui, range: Option<RangeInclusive<f64>>) -> Response;
+}
+
+macro_rules! impl_inspector_value_for_numeric {
+    ($($t:ty),*) => {
+        $(
+            impl InspectorValue for $t {
+                fn inspector_ui(
+                    &mut self,
+                    ui: &mut Ui,
+                    range: Option<RangeInclusive<f64>>,
+                ) -> Response {
+                    if let Some(range) = range {
+                        let range =
+                            <$t>::from_f64(*range.start())..=<$t>::from_f64(*range.end());
+                        ui.add(Slider::new(self, range))
+                    } else {
+                        ui.add(DragValue::new(self))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_inspector_value_for_numeric!(
+    f32, f64, i8, u8, i16, u16, i32, u32, i64, u64, isize, usize
+);
+
+impl InspectorValue for bool {
+    fn inspector_ui(&mut self, ui: &mut Ui, _range: Option<RangeInclusive<f64>>) -> Response {
+        ui.add(Checkbox::without_text(self))
+    }
+}
+
+impl InspectorValue for String {
+    fn inspector_ui(&mut self, ui: &mut Ui, _range: Option<RangeInclusive<f64>>) -> Response {
+        ui.add(TextEdit::singleline(self))
+    }
+}
+
+impl InspectorValue for Color32 {
+    fn inspector_ui(&mut self, ui: &mut Ui, _range: Option<RangeInclusive<f64>>) -> Response {
+        ui.color_edit_button_srgba(self)
+    }
+}
+
+impl InspectorValue for Rgba {
+    fn inspector_ui(&mut self, ui: &mut Ui, _range: Option<RangeInclusive<f64>>) -> Response {
+        let mut rgba = self.to_rgba_unmultiplied();
+        let response = ui.color_edit_button_rgba_unmultiplied(&mut rgba);
+        if response.changed() {
+            *self = Self::from_rgba_unmultiplied(rgba[0], rgba[1], rgba[2], rgba[3]);
+        }
+        response
+    }
+}
+
+impl InspectorValue for Hsva {
+    fn inspector_ui(&mut self, ui: &mut Ui, _range: Option<RangeInclusive<f64>>) -> Response {
+        ui.color_edit_button_hsva(self)
+    }
+}
+
+impl InspectorValue for Vec2 {
+    fn inspector_ui(&mut self, ui: &mut Ui, range: Option<RangeInclusive<f64>>) -> Response {
+        xy_ui(ui, &mut self.x, &mut self.y, range)
+    }
+}
+
+impl InspectorValue for Pos2 {
+    fn inspector_ui(&mut self, ui: &mut Ui, range: Option<RangeInclusive<f64>>) -> Response {
+        xy_ui(ui, &mut self.x, &mut self.y, range)
+    }
+}
+
+impl InspectorValue for Stroke {
+    fn inspector_ui(&mut self, ui: &mut Ui, _range: Option<RangeInclusive<f64>>) -> Response {
+        ui.add(self)
+    }
+}
+
+impl InspectorValue for Margin {
+    fn inspector_ui(&mut self, ui: &mut Ui, _range: Option<RangeInclusive<f64>>) -> Response {
+        ui.add(self)
+    }
+}
+
+impl InspectorValue for CornerRadius {
+    fn inspector_ui(&mut self, ui: &mut Ui, _range: Option<RangeInclusive<f64>>) -> Response {
+        ui.add(self)
+    }
+}
+
+impl InspectorValue for Shadow {
+    fn inspector_ui(&mut self, ui: &mut Ui, _range: Option<RangeInclusive<f64>>) -> Response {
+        ui.add(self)
+    }
+}
+
+fn xy_ui(ui: &mut Ui, x: &mut f32, y: &mut f32, range: Option<RangeInclusive<f64>>) -> Response {
+    let add_drag_value = |ui: &mut Ui, value: &mut f32, prefix: &str| {
+        let mut drag_value = DragValue::new(value).prefix(prefix);
+        if let Some(range) = &range {
+            drag_value = drag_value.range(range.clone());
+        }
+        ui.add(drag_value)
+    };
+    ui.horizontal(|ui| {
+        let x = add_drag_value(ui, x, "x: ");
+        let y = add_drag_value(ui, y, "y: ");
+        x | y
+    })
+    .inner
+}
+
+// ----------------------------------------------------------------------------
+
+/// Something that can show its fields in an [`Inspector`].
+///
+/// With the `derive` feature you can implement this with `#[derive(egui::EguiInspect)]`,
+/// with attributes for ranges, labels, tooltips, defaults and custom editors
+/// (see the [`egui_derive`](https://docs.rs/egui_derive) docs).
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::widgets::inspector::{EguiInspect, InspectorUi, Property};
+///
+/// struct Player {
+///     name: String,
+///     max_speed: f32,
+/// }
+///
+/// impl EguiInspect for Player {
+///     fn inspect(&mut self, inspector: &mut InspectorUi<'_>) {
+///         inspector.add(Property::new("Name", &mut self.name));
+///         inspector.add(Property::new("Max speed", &mut self.max_speed).range(0.0..=10.0));
+///     }
+/// }
+///
+/// let mut player = Player { name: "Ferris".to_owned(), max_speed: 1.0 };
+/// player.ui(ui);
+/// # });
+/// ```
+pub trait EguiInspect {
+    /// Add a property (or group) for each field.
+    fn inspect(&mut self, inspector: &mut InspectorUi<'_>);
+
+    /// Show all fields in an [`Inspector`].
+    fn ui(&mut self, ui: &mut Ui) -> InspectorResponse<()> {
+        Inspector::new(std::any::type_name::<Self>()).show(ui, |inspector| self.inspect(inspector))
+    }
+
+    /// Like [`Self::ui`], but with "Undo" and "Redo" buttons,
+    /// recording the edits in the given [`Undoer`].
+    fn ui_with_undo(&mut self, ui: &mut Ui, undoer: &mut Undoer<Self>) -> InspectorResponse<()>
+    where
+        Self: Clone + PartialEq,
+    {
+        ui.horizontal(|ui| {
+            let undo = ui.add_enabled(undoer.has_undo(self), Button::new("⟲ Undo"));
+            if undo.clicked() {
+                if let Some(state) = undoer.undo(self) {
+                    *self = state.clone();
+                }
+            }
+            let redo = ui.add_enabled(undoer.has_redo(self), Button::new("⟳ Redo"));
+            if redo.clicked() {
+                if let Some(state) = undoer.redo(self) {
+                    *self = state.clone();
+                }
+            }
+        });
+
+        let response = self.ui(ui);
+        undoer.feed_state(ui.input(|i| i.time), self);
+        response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// One row in an [`Inspector`]: a named value with an editor.
+///
+/// Add it with [`InspectorUi::add`].
+#[must_use = "You should add the property with `inspector.add(property);`"]
+pub struct Property<'a, T> {
+    name: String,
+    value: &'a mut T,
+    default: Option<T>,
+    range: Option<RangeInclusive<f64>>,
+    hover_text: Option<WidgetText>,
+}
+
+impl<'a, T: InspectorValue + PartialEq> Property<'a, T> {
+    /// The `name` is shown next to the editor, and is what the search filter matches.
+    pub fn new(name: impl Into<String>, value: &'a mut T) -> Self {
+        Self {
+            name: name.into(),
+            value,
+            default: None,
+            range: None,
+            hover_text: None,
+        }
+    }
+
+    /// Show a button to reset the value to this default, enabled whenever the value differs from it.
+    #[inline]
+    pub fn default(mut self, default: T) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Edit numbers with a [`Slider`] over this range, instead of a [`DragValue`].
+    #[inline]
+    pub fn range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Show this text when hovering the name of the property.
+    #[inline]
+    pub fn hover_text(mut self, hover_text: impl Into<WidgetText>) -> Self {
+        self.hover_text = Some(hover_text.into());
+        self
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// What happened in an [`Inspector`] this frame.
+pub struct InspectorResponse<R> {
+    /// What the contents closure returned.
+    pub inner: R,
+
+    /// The response of the whole inspector.
+    pub response: Response,
+
+    /// The properties that were edited (or reset) this frame.
+    ///
+    /// Each is the names of its enclosing groups and its own name, joined by `/`,
+    /// e.g. `"Transform/Position"`.
+    pub changed_properties: Vec<String>,
+}
+
+impl<R> InspectorResponse<R> {
+    /// Was any property edited this frame?
+    pub fn changed(&self) -> bool {
+        !self.changed_properties.is_empty()
+    }
+}
+
+/// A property editor, as found in every editor-style tool.
+///
+/// Shows named values in rows, each with an editor fitting its type
+/// (see [`InspectorValue`]), optionally in collapsible groups.
+/// A search field at the top filters the rows by name.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let (mut name, mut speed, mut visible, mut color) =
+/// #     (String::new(), 1.0_f32, true, egui::Color32::RED);
+/// use egui::widgets::inspector::Property;
+///
+/// let response = egui::Inspector::new("player").show(ui, |inspector| {
+///     inspector.add(Property::new("Name", &mut name));
+///     inspector.group("Movement", |inspector| {
+///         inspector.add(Property::new("Speed", &mut speed).range(0.0..=10.0).default(1.0));
+///         inspector.add(Property::new("Visible", &mut visible).default(true));
+///     });
+///     inspector.add(Property::new("Color", &mut color));
+/// });
+/// for property in &response.changed_properties {
+///     println!("{property} changed");
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Inspector {
+    id_salt: Id,
+    searchable: bool,
+}
+
+impl Inspector {
+    /// The `id_salt` must be unique among sibling widgets.
+    pub fn new(id_salt: impl std::hash::Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            searchable: true,
+        }
+    }
+
+    /// Show a search field for filtering the properties by name?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn searchable(mut self, searchable: bool) -> Self {
+        self.searchable = searchable;
+        self
+    }
+
+    /// Show the properties.
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut InspectorUi<'_>) -> R,
+    ) -> InspectorResponse<R> {
+        let Self {
+            id_salt,
+            searchable,
+        } = self;
+
+        let mut changed_properties = Vec::new();
+        let inner_response = ui.push_id(id_salt, |ui| {
+            let filter_id = ui.id().with("filter");
+            let mut filter = String::new();
+            if searchable {
+                filter = ui.data_mut(|d| d.get_temp(filter_id)).unwrap_or_default();
+                ui.add(
+                    TextEdit::singleline(&mut filter)
+                        .hint_text("🔍 Search")
+                        .desired_width(f32::INFINITY),
+                );
+                ui.data_mut(|d| d.insert_temp(filter_id, filter.clone()));
+            }
+
+            let mut inspector = InspectorUi {
+                ui,
+                filter: filter.trim().to_lowercase(),
+                group_path: Vec::new(),
+                group_matches: false,
+                changed_properties: &mut changed_properties,
+            };
+            add_contents(&mut inspector)
+        });
+
+        InspectorResponse {
+            inner: inner_response.inner,
+            response: inner_response.response,
+            changed_properties,
+        }
+    }
+}
+
+/// Adds properties and groups to an [`Inspector`]. See [`Inspector::show`].
+pub struct InspectorUi<'a> {
+    ui: &'a mut Ui,
+
+    /// Lowercase search filter, or empty.
+    filter: String,
+
+    /// The names of the enclosing groups.
+    group_path: Vec<String>,
+
+    /// Does the name of an enclosing group match the filter?
+    group_matches: bool,
+
+    changed_properties: &'a mut Vec<String>,
+}
+
+impl InspectorUi<'_> {
+    /// The [`Ui`] the properties are added to, e.g. for adding headings or separators.
+    pub fn ui(&mut self) -> &mut Ui {
+        self.ui
+    }
+
+    /// Is the user searching for a property?
+    pub fn is_filtering(&self) -> bool {
+        !self.filter.is_empty()
+    }
+
+    /// Add a property row.
+    ///
+    /// Returns the response of the editor, or `None` if the property is hidden by the search filter.
+    pub fn add<T: InspectorValue + PartialEq>(
+        &mut self,
+        property: Property<'_, T>,
+    ) -> Option<Response> {
+        let Property {
+            name,
+            value,
+            default,
+            range,
+            hover_text,
+        } = property;
+
+        self.row(name, hover_text, |ui| {
+            let mut response = value.inspector_ui(ui, range);
+            if let Some(default) = default {
+                let reset = ui
+                    .add_enabled(*value != default, Button::new("↺").small())
+                    .on_hover_text("Reset to default");
+                if reset.clicked() {
+                    *value = default;
+                    response.mark_changed();
+                }
+            }
+            response
+        })
+    }
+
+    /// Add a property row with your own editor, e.g. a [`crate::ComboBox`] for an enum.
+    ///
+    /// Mark the returned [`Response`] as changed when the value is edited.
+    /// Returns `None` if the property is hidden by the search filter.
+    pub fn custom(
+        &mut self,
+        name: impl Into<String>,
+        add_editor: impl FnOnce(&mut Ui) -> Response,
+    ) -> Option<Response> {
+        self.row(name.into(), None, add_editor)
+    }
+
+    /// Add a collapsible group of properties.
+    ///
+    /// While searching, groups are flattened and matching properties are shown with their group names.
+    ///
+    /// Returns `None` if the group is collapsed.
+    pub fn group<R>(
+        &mut self,
+        name: impl Into<String>,
+        add_contents: impl FnOnce(&mut InspectorUi<'_>) -> R,
+    ) -> Option<R> {
+        let name = name.into();
+        let group_matches =
+            self.group_matches || (self.is_filtering() && name_matches(&name, &self.filter));
+
+        let mut group_path = self.group_path.clone();
+        group_path.push(name.clone());
+
+        if self.is_filtering() {
+            let mut inspector = InspectorUi {
+                ui: self.ui,
+                filter: self.filter.clone(),
+                group_path,
+                group_matches,
+                changed_properties: self.changed_properties,
+            };
+            Some(add_contents(&mut inspector))
+        } else {
+            CollapsingHeader::new(name)
+                .id_salt(&group_path)
+                .default_open(true)
+                .show(self.ui, |ui| {
+                    let mut inspector = InspectorUi {
+                        ui,
+                        filter: String::new(),
+                        group_path,
+                        group_matches,
+                        changed_properties: self.changed_properties,
+                    };
+                    add_contents(&mut inspector)
+                })
+                .body_returned
+        }
+    }
+
+    fn row(
+        &mut self,
+        name: String,
+        hover_text: Option<WidgetText>,
+        add_editor: impl FnOnce(&mut Ui) -> Response,
+    ) -> Option<Response> {
+        if !self.group_matches && self.is_filtering() && !name_matches(&name, &self.filter) {
+            return None;
+        }
+
+        let mut path = self.group_path.clone();
+        path.push(name);
+
+        let label = if self.is_filtering() {
+            path.join(" › ")
+        } else {
+            path.last().cloned().unwrap_or_default()
+        };
+
+        let response = self
+            .ui
+            .horizontal(|ui| {
+                let label_width = (0.4 * ui.available_width()).at_least(0.0);
+                let label_response = ui
+                    .allocate_ui_with_layout(
+                        vec2(label_width, ui.spacing().interact_size.y),
+                        Layout::left_to_right(Align::Center),
+                        |ui| {
+                            ui.set_min_width(label_width);
+                            ui.add(Label::new(label).truncate())
+                        },
+                    )
+                    .inner;
+                if let Some(hover_text) = hover_text {
+                    label_response.on_hover_text(hover_text);
+                }
+                add_editor(ui)
+            })
+            .inner;
+
+        if response.changed() {
+            self.changed_properties.push(path.join("/"));
+        }
+        Some(response)
+    }
+}
+
+fn name_matches(name: &str, lowercase_filter: &str) -> bool {
+    name.to_lowercase().contains(lowercase_filter)
+}
@@ -28,6 +28,7 @@ pub struct Label {
     sense: Option<Sense>,
     selectable: Option<bool>,
     halign: Option<Align>,
+    justify: Option<bool>,
     show_tooltip_when_elided: bool,
 }
 
@@ -39,6 +40,7 @@ impl Label {
             sense: None,
             selectable: None,
             halign: None,
+            justify: None,
             show_tooltip_when_elided: true,
         }
     }
@@ -88,6 +90,19 @@ impl Label {
         self
     }
 
+    /// Stretch the wrapped rows of text so that they fill the whole width, like in a book.
+    ///
+    /// By default, the text is justified if the [`crate::Layout`] of the ui is
+    /// horizontally justified.
+    ///
+    /// To avoid large gaps between the words, add [`epaint::text::SOFT_HYPHEN`]s to the text,
+    /// e.g. with [`epaint::text::insert_soft_hyphens`] and a hyphenation dictionary.
+    #[inline]
+    pub fn justify(mut self, justify: bool) -> Self {
+        self.justify = Some(justify);
+        self
+    }
+
     /// Can the user select the text with the mouse?
     ///
     /// Overrides [`crate::style::Interaction::selectable_labels`].
@@ -249,7 +264,9 @@ impl Label {
                 layout_job.justify = false;
             } else {
                 layout_job.halign = self.halign.unwrap_or(ui.layout().horizontal_placement());
-                layout_job.justify = ui.layout().horizontal_justify();
+                layout_job.justify = self
+                    .justify
+                    .unwrap_or_else(|| ui.layout().horizontal_justify());
             };
 
             let galley = ui.fonts(|fonts| fonts.layout_job(layout_job));
@@ -279,6 +296,10 @@ impl Widget for Label {
         response
             .widget_info(|| WidgetInfo::labeled(WidgetType::Label, ui.is_enabled(), galley.text()));
 
+        if galley.elided {
+            crate::ui::register_overflow(ui, response.rect, "truncated");
+        }
+
         if ui.is_rect_visible(response.rect) {
             if show_tooltip_when_elided && galley.elided {
                 // Show the full (non-elided) text on hover:
@@ -6,6 +6,7 @@
 
 use crate::{Response, Ui, epaint};
 
+mod angle_dial;
 mod button;
 mod checkbox;
 pub mod color_picker;
@@ -13,6 +14,7 @@ pub(crate) mod drag_value;
 mod hyperlink;
 mod image;
 mod image_button;
+pub mod inspector;
 mod label;
 mod progress_bar;
 mod radio_button;
@@ -20,11 +22,15 @@ mod selected_label;
 mod separator;
 mod slider;
 mod spinner;
+mod tag_edit;
 pub mod text_edit;
+mod value_format;
+pub mod virtual_keyboard;
 
 #[expect(deprecated)]
 pub use self::selected_label::SelectableLabel;
 pub use self::{
+    angle_dial::AngleDial,
     button::Button,
     checkbox::Checkbox,
     drag_value::DragValue,
@@ -34,13 +40,17 @@ pub use self::{
         decode_animated_image_uri, has_gif_magic_header, has_webp_header, paint_texture_at,
     },
     image_button::ImageButton,
+    inspector::{EguiInspect, Inspector},
     label::Label,
     progress_bar::ProgressBar,
     radio_button::RadioButton,
     separator::Separator,
     slider::{Slider, SliderClamping, SliderOrientation},
     spinner::Spinner,
+    tag_edit::TagEdit,
     text_edit::{TextBuffer, TextEdit},
+    value_format::ValueFormat,
+    virtual_keyboard::{KeyboardLayout, VirtualKeyboard},
 };
 
 // ----------------------------------------------------------------------------
@@ -68,6 +78,15 @@ pub trait Widget {
     ///
     /// Tip: you can `impl Widget for &mut YourObject { }`.
     fn ui(self, ui: &mut Ui) -> Response;
+
+    /// May [`Ui::add`] skip this widget when it is outside the clip rect?
+    ///
+    /// This only matters if [`crate::Style::cull_offscreen_widgets`] is set.
+    /// Return `false` if the widget needs to run even when it's not visible,
+    /// e.g. because it has side effects or handles keyboard input.
+    fn allow_culling(&self) -> bool {
+        true
+    }
 }
 
 /// This enables functions that return `impl Widget`, so that you can
@@ -1,6 +1,6 @@
 use crate::{
-    Atom, AtomLayout, Atoms, Id, IntoAtoms, NumExt as _, Response, Sense, Ui, Vec2, Widget,
-    WidgetInfo, WidgetType, epaint,
+    Atom, AtomLayout, Atoms, Id, IntoAtoms, NumExt as _, Response, Sense, SoundEvent, Ui, Vec2,
+    Widget, WidgetInfo, WidgetType, epaint,
 };
 
 /// One out of several alternatives, either selected or not.
@@ -56,10 +56,14 @@ impl Widget for RadioButton<'_> {
         let text = atoms.text().map(String::from);
 
         let mut prepared = AtomLayout::new(atoms)
-            .sense(Sense::click())
+            .sense(ui.editing_sense(Sense::click()))
             .min_size(min_size)
             .allocate(ui);
 
+        if prepared.response.clicked() && !checked {
+            ui.play_sound(SoundEvent::Toggle);
+        }
+
         prepared.response.widget_info(|| {
             WidgetInfo::selected(
                 WidgetType::RadioButton,
@@ -71,7 +75,7 @@ impl Widget for RadioButton<'_> {
 
         if ui.is_rect_visible(prepared.response.rect) {
             // let visuals = ui.style().interact_selectable(&response, checked); // too colorful
-            let visuals = *ui.style().interact(&prepared.response);
+            let visuals = *ui.editing_visuals(&prepared.response);
 
             prepared.fallback_text_color = visuals.text_color();
             let response = prepared.paint(ui);
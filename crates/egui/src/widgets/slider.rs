@@ -3,9 +3,9 @@
 use std::ops::RangeInclusive;
 
 use crate::{
-    Color32, DragValue, EventFilter, Key, Label, MINUS_CHAR_STR, NumExt as _, Pos2, Rangef, Rect,
-    Response, Sense, TextStyle, TextWrapMode, Ui, Vec2, Widget, WidgetInfo, WidgetText, emath,
-    epaint, lerp, pos2, remap, remap_clamp, style, style::HandleShape, vec2,
+    Color32, DragValue, EventFilter, HapticKind, Key, Label, MINUS_CHAR_STR, NumExt as _, Pos2,
+    Rangef, Rect, Response, Sense, TextStyle, TextWrapMode, Ui, Vec2, Widget, WidgetInfo,
+    WidgetText, emath, epaint, lerp, pos2, remap, remap_clamp, style, style::HandleShape, vec2,
 };
 
 use super::drag_value::clamp_value_to_range;
@@ -137,6 +137,22 @@ impl<'a> Slider<'a> {
         if Num::INTEGRAL { slf.integer() } else { slf }
     }
 
+    /// Creates a new horizontal slider for a value shared with other threads,
+    /// e.g. an `Arc<RwLock<f32>>` or an atomic.
+    ///
+    /// The value is only locked while reading and writing it,
+    /// and only written to when the user changes it.
+    /// See [`crate::SharedValue`].
+    pub fn from_shared<Num: emath::Numeric>(
+        value: &'a impl crate::SharedValue<Num>,
+        range: RangeInclusive<Num>,
+    ) -> Self {
+        let range_f64 = range.start().to_f64()..=range.end().to_f64();
+        let slf = Self::from_get_set(range_f64, crate::data::get_set_numeric(value));
+
+        if Num::INTEGRAL { slf.integer() } else { slf }
+    }
+
     pub fn from_get_set(
         range: RangeInclusive<f64>,
         get_set_value: impl 'a + FnMut(Option<f64>) -> f64,
@@ -594,6 +610,24 @@ impl<'a> Slider<'a> {
         .custom_parser(|s| i64::from_str_radix(s, 16).map(|n| n as f64).ok())
     }
 
+    /// Set `custom_formatter` and `custom_parser` to show the value with a unit, e.g. "1.5 GiB" or "2h 30m".
+    ///
+    /// See [`crate::ValueFormat`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut seconds: f64 = 0.0;
+    /// ui.add(
+    ///     egui::Slider::new(&mut seconds, 0.0..=86_400.0).value_format(egui::ValueFormat::Duration),
+    /// );
+    /// # });
+    /// ```
+    pub fn value_format(self, format: crate::ValueFormat) -> Self {
+        let parse_format = format.clone();
+        self.custom_formatter(move |n, decimals| format.format(n, decimals))
+            .custom_parser(move |s| parse_format.parse(s))
+    }
+
     /// Helper: equivalent to `self.precision(0).smallest_positive(1.0)`.
     /// If you use one of the integer constructors (e.g. `Slider::i32`) this is called for you,
     /// but if you want to have a slider for picking integer values in an `Slider::f64`, use this.
@@ -658,7 +692,7 @@ impl Slider<'_> {
             SliderOrientation::Horizontal => vec2(ui.spacing().slider_width, thickness),
             SliderOrientation::Vertical => vec2(thickness, ui.spacing().slider_width),
         };
-        ui.allocate_response(desired_size, Sense::drag())
+        ui.allocate_response(desired_size, ui.editing_sense(Sense::drag()))
     }
 
     /// Just the slider, no text
@@ -775,7 +809,7 @@ impl Slider<'_> {
         if ui.is_rect_visible(response.rect) {
             let value = self.get_value();
 
-            let visuals = ui.style().interact(response);
+            let visuals = ui.editing_visuals(response);
             let widget_visuals = &ui.visuals().widgets;
             let spacing = &ui.style().spacing;
 
@@ -975,6 +1009,12 @@ impl Slider<'_> {
         let value = self.get_value();
         if value != old_value {
             response.mark_changed();
+
+            // Snapped to the next step:
+            let haptic_feedback = ui.style().interaction.haptic_feedback;
+            if haptic_feedback && self.step.is_some() && response.dragged() {
+                ui.ctx().haptic_feedback(HapticKind::Selection);
+            }
         }
         response.widget_info(|| WidgetInfo::slider(ui.is_enabled(), value, self.text.text()));
 
@@ -0,0 +1,255 @@
+use crate::{
+    Button, Frame, Id, Key, Margin, NumExt as _, Popup, PopupCloseBehavior, Response,
+    TextEdit, Ui, Widget, WidgetInfo, WidgetText, WidgetType,
+};
+
+/// Produces autocomplete suggestions for the text currently being typed into a [`TagEdit`].
+type SuggestionProvider<'a> = Box<dyn FnMut(&str) -> Vec<String> + 'a>;
+
+/// A text field that turns typed entries into removable chips ("tags").
+///
+/// A tag is committed when the user presses Enter, or types one of the separator characters
+/// (`,` by default). Pressing backspace in an empty field removes the last tag,
+/// and each chip has a small button to remove it.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut tags: Vec<String> = vec!["rust".to_owned()];
+/// ui.add(egui::TagEdit::new(&mut tags).hint_text("Add tag…"));
+/// # });
+/// ```
+///
+/// The response is marked as changed whenever a tag is added or removed.
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct TagEdit<'a> {
+    tags: &'a mut Vec<String>,
+    id_salt: Option<Id>,
+    hint_text: WidgetText,
+    separators: Vec<char>,
+    allow_duplicates: bool,
+    desired_width: Option<f32>,
+    suggestions: Option<SuggestionProvider<'a>>,
+}
+
+impl<'a> TagEdit<'a> {
+    pub fn new(tags: &'a mut Vec<String>) -> Self {
+        Self {
+            tags,
+            id_salt: None,
+            hint_text: WidgetText::default(),
+            separators: vec![','],
+            allow_duplicates: false,
+            desired_width: None,
+            suggestions: None,
+        }
+    }
+
+    /// Assign a source for the unique id of the tag editor.
+    ///
+    /// This is needed if you have several tag editors in the same [`Ui`].
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
+    /// Show a faint hint text in the input field when it is empty.
+    #[inline]
+    pub fn hint_text(mut self, hint_text: impl Into<WidgetText>) -> Self {
+        self.hint_text = hint_text.into();
+        self
+    }
+
+    /// Characters which commit the current entry as a tag when typed.
+    ///
+    /// Default: `[',']`. Enter always commits.
+    #[inline]
+    pub fn separators(mut self, separators: impl Into<Vec<char>>) -> Self {
+        self.separators = separators.into();
+        self
+    }
+
+    /// Whether the same tag may be added more than once.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn allow_duplicates(mut self, allow_duplicates: bool) -> Self {
+        self.allow_duplicates = allow_duplicates;
+        self
+    }
+
+    /// The desired width of the whole widget. Will use all horizontal space if not set.
+    #[inline]
+    pub fn desired_width(mut self, desired_width: f32) -> Self {
+        self.desired_width = Some(desired_width);
+        self
+    }
+
+    /// Provide autocomplete suggestions for the text being typed.
+    ///
+    /// The closure is called with the current (non-empty) input,
+    /// and the returned suggestions are shown in a popup below the field.
+    /// Suggestions that are already present as tags are hidden unless
+    /// [`Self::allow_duplicates`] is set.
+    #[inline]
+    pub fn suggestions(mut self, provider: impl FnMut(&str) -> Vec<String> + 'a) -> Self {
+        self.suggestions = Some(Box::new(provider));
+        self
+    }
+}
+
+impl TagEdit<'_> {
+    /// Add a tag, unless it is empty or a disallowed duplicate.
+    ///
+    /// Returns `true` if the tag was added.
+    fn commit(&mut self, text: &str) -> bool {
+        let text = text.trim();
+        if text.is_empty() || (!self.allow_duplicates && self.tags.iter().any(|t| t == text)) {
+            return false;
+        }
+        self.tags.push(text.to_owned());
+        true
+    }
+}
+
+impl Widget for TagEdit<'_> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let id = ui.make_persistent_id(self.id_salt.unwrap_or_else(|| Id::new("tag_edit")));
+        let text_id = id.with("input");
+
+        // The text that has been typed but not yet turned into a tag:
+        let mut pending: String = ui
+            .ctx()
+            .data_mut(|d| d.get_temp(id))
+            .unwrap_or_default();
+        let was_empty = pending.is_empty();
+
+        let desired_width = self
+            .desired_width
+            .unwrap_or_else(|| ui.available_width())
+            .at_least(ui.spacing().interact_size.x);
+
+        let read_only = ui.is_read_only();
+        let mut changed = false;
+        let mut removed = None;
+
+        let visuals = ui.visuals();
+        let frame = Frame::new()
+            .fill(visuals.text_edit_bg_color())
+            .stroke(visuals.widgets.inactive.bg_stroke)
+            .corner_radius(visuals.widgets.inactive.corner_radius)
+            .inner_margin(Margin::same(2));
+
+        let frame_response = frame.show(ui, |ui| {
+            ui.set_width(desired_width - 4.0);
+            ui.horizontal_wrapped(|ui| {
+                for (i, tag) in self.tags.iter().enumerate() {
+                    let chip = Button::new(tag.as_str())
+                        .small()
+                        .corner_radius(ui.visuals().widgets.inactive.corner_radius);
+                    if read_only {
+                        ui.add(chip);
+                    } else if ui.add(chip.right_text("🗙")).on_hover_text("Remove").clicked() {
+                        removed = Some(i);
+                    }
+                }
+
+                let input_width = ui
+                    .available_width()
+                    .at_least(4.0 * ui.spacing().interact_size.y);
+                TextEdit::singleline(&mut pending)
+                    .id(text_id)
+                    .frame(false)
+                    .hint_text(self.hint_text.clone())
+                    .desired_width(input_width)
+                    .return_key(None)
+                    .show(ui)
+                    .response
+            })
+            .inner
+        });
+        let text_response = frame_response.inner;
+
+        if let Some(i) = removed {
+            self.tags.remove(i);
+            changed = true;
+        }
+
+        if text_response.has_focus() && !read_only {
+            let (enter, backspace) = ui.input(|i| {
+                (
+                    i.key_pressed(Key::Enter),
+                    i.key_pressed(Key::Backspace) && !i.modifiers.any(),
+                )
+            });
+
+            if enter {
+                changed |= self.commit(&pending);
+                pending.clear();
+            } else if backspace && was_empty && pending.is_empty() {
+                changed |= self.tags.pop().is_some();
+            }
+        }
+
+        if let Some(last_separator) = pending.rfind(|c| self.separators.contains(&c)) {
+            let (entries, rest) = pending.split_at(last_separator);
+            let rest = rest[rest.chars().next().map_or(0, char::len_utf8)..].to_owned();
+            for entry in entries
+                .split(|c| self.separators.contains(&c))
+                .map(str::to_owned)
+                .collect::<Vec<_>>()
+            {
+                changed |= self.commit(&entry);
+            }
+            pending = rest;
+        }
+
+        if let Some(provider) = &mut self.suggestions {
+            let query = pending.trim();
+            // Also check `lost_focus`, so that clicking a suggestion registers:
+            let active = text_response.has_focus() || text_response.lost_focus();
+            let candidates: Vec<String> = if query.is_empty() || !active {
+                Vec::new()
+            } else {
+                provider(query)
+                    .into_iter()
+                    .filter(|s| self.allow_duplicates || !self.tags.contains(s))
+                    .collect()
+            };
+
+            if !candidates.is_empty() {
+                let popup = Popup::from_response(&text_response)
+                    .id(id.with("suggestions"))
+                    .open(true)
+                    .close_behavior(PopupCloseBehavior::IgnoreClicks)
+                    .width(text_response.rect.width())
+                    .show(|ui| {
+                        let mut chosen = None;
+                        for candidate in candidates {
+                            if ui.selectable_label(false, candidate.as_str()).clicked() {
+                                chosen = Some(candidate);
+                            }
+                        }
+                        chosen
+                    });
+
+                if let Some(chosen) = popup.and_then(|popup| popup.inner) {
+                    changed |= self.commit(&chosen);
+                    pending.clear();
+                    text_response.request_focus();
+                }
+            }
+        }
+
+        ui.ctx().data_mut(|d| d.insert_temp(id, pending));
+
+        let mut response = frame_response.response.union(text_response);
+        if changed {
+            response.mark_changed();
+        }
+        response.widget_info(|| {
+            WidgetInfo::labeled(WidgetType::Other, ui.is_enabled(), self.tags.join(", "))
+        });
+        response
+    }
+}
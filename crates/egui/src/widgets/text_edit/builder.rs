@@ -8,16 +8,21 @@ use epaint::{
 
 use crate::{
     Align, Align2, Color32, Context, CursorIcon, Event, EventFilter, FontSelection, Id, ImeEvent,
-    Key, KeyboardShortcut, Margin, Modifiers, NumExt as _, Response, Sense, Shape, TextBuffer,
-    TextStyle, TextWrapMode, Ui, Vec2, Widget, WidgetInfo, WidgetText, WidgetWithState, epaint,
+    Key, KeyboardShortcut, Margin, Modifiers, NumExt as _, Response, Sense, Shape, Stroke,
+    TextBuffer, TextStyle, TextWrapMode, Ui, Vec2, Visuals, Widget, WidgetInfo, WidgetText,
+    WidgetWithState, epaint,
     os::OperatingSystem,
-    output::OutputEvent,
+    output::{IMEOutput, OutputEvent},
     response, text_selection,
-    text_selection::{CCursorRange, text_cursor_state::cursor_rect, visuals::paint_text_selection},
+    text_selection::{
+        CCursorRange, multi_cursor,
+        text_cursor_state::{cursor_rect, is_word_char, select_word_at},
+        visuals::{char_range_rects, paint_text_selection},
+    },
     vec2,
 };
 
-use super::{TextEditOutput, TextEditState};
+use super::{TextEditOutput, TextEditState, input_filter::InputFilter};
 
 type LayouterFn<'t> = &'t mut dyn FnMut(&Ui, &dyn TextBuffer, f32) -> Arc<Galley>;
 
@@ -59,6 +64,12 @@ type LayouterFn<'t> = &'t mut dyn FnMut(&Ui, &dyn TextBuffer, f32) -> Arc<Galley
 /// }
 /// ```
 ///
+/// ## Multiple cursors
+/// Alt-click adds a cursor, alt-drag selects a column, and ctrl-D (cmd-D on Mac)
+/// selects the next occurrence of the selected text.
+/// Typing, pasting and deleting then happens at all the cursors at once.
+/// See [`TextEditState::extra_cursors`].
+///
 /// ## Advanced usage
 /// See [`TextEdit::show`].
 ///
@@ -87,6 +98,10 @@ pub struct TextEdit<'t> {
     align: Align2,
     clip_text: bool,
     char_limit: usize,
+    input_filter: InputFilter<'t>,
+    validator: Option<Box<dyn Fn(&str) -> bool + 't>>,
+    ime_composition_style: Option<ImeCompositionStyle>,
+    ime_candidate_rect: Option<Box<dyn Fn(&IMEOutput) -> Rect + 't>>,
     return_key: Option<KeyboardShortcut>,
     background_color: Option<Color32>,
 }
@@ -146,6 +161,10 @@ impl<'t> TextEdit<'t> {
             align: Align2::LEFT_TOP,
             clip_text: false,
             char_limit: usize::MAX,
+            input_filter: Default::default(),
+            validator: None,
+            ime_composition_style: None,
+            ime_candidate_rect: None,
             return_key: Some(KeyboardShortcut::new(Modifiers::NONE, Key::Enter)),
             background_color: None,
         }
@@ -255,6 +274,8 @@ impl<'t> TextEdit<'t> {
     /// This function will be called at least once per frame,
     /// so it is strongly suggested that you cache the results of any syntax highlighter
     /// so as not to waste CPU highlighting the same string every frame.
+    /// For large texts, use [`crate::text_edit::IncrementalLayouter`] to only highlight
+    /// the lines that changed.
     ///
     /// The arguments is the enclosing [`Ui`] (so you can access e.g. [`Ui::fonts`]),
     /// the text and the wrap width.
@@ -364,6 +385,86 @@ impl<'t> TextEdit<'t> {
         self
     }
 
+    /// Only accept typed and pasted characters for which this returns `true`.
+    ///
+    /// The other characters are dropped before they reach the text.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut my_string = String::new();
+    /// ui.add(egui::TextEdit::singleline(&mut my_string).char_filter(|c| c.is_ascii_digit()));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn char_filter(mut self, char_filter: impl Fn(char) -> bool + 't) -> Self {
+        self.input_filter.char_filter = Some(Box::new(char_filter));
+        self
+    }
+
+    /// Don't let typing or pasting make the text longer than this many graphemes,
+    /// i.e. letters as the user sees them, which may be several `char`s each, like an emoji.
+    ///
+    /// Text that doesn't fit is cut off.
+    /// Unlike [`Self::char_limit`], this works for multiline [`TextEdit`]s too.
+    #[inline]
+    pub fn grapheme_limit(mut self, limit: usize) -> Self {
+        self.input_filter.grapheme_limit = Some(limit);
+        self
+    }
+
+    /// Check if the text is valid, e.g. with a regular expression.
+    ///
+    /// Invalid text is still accepted, but the frame is painted with
+    /// [`crate::Visuals::error_fg_color`], and [`TextEditOutput::is_valid`] is `false`.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut my_string = String::new();
+    /// let output = egui::TextEdit::singleline(&mut my_string)
+    ///     .validator(|text| text.parse::<f32>().is_ok())
+    ///     .show(ui);
+    /// if !output.is_valid {
+    ///     ui.colored_label(ui.visuals().error_fg_color, "Not a number");
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn validator(mut self, validator: impl Fn(&str) -> bool + 't) -> Self {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    /// How to show the text being composed with an IME, before it is committed.
+    ///
+    /// Default: [`ImeCompositionStyle::from_visuals`].
+    /// See also [`TextEditState::ime_composition`].
+    #[inline]
+    pub fn ime_composition_style(mut self, style: ImeCompositionStyle) -> Self {
+        self.ime_composition_style = Some(style);
+        self
+    }
+
+    /// Where the IME should show its candidate window, in screen coordinates.
+    ///
+    /// The function gets the default [`IMEOutput`], where [`IMEOutput::candidate_rect`]
+    /// is the whole [`TextEdit`]. E.g. return [`IMEOutput::cursor_rect`]
+    /// to show the candidates right below the cursor, which is nicer for a large code editor.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut my_code = String::new();
+    /// ui.add(egui::TextEdit::multiline(&mut my_code).ime_candidate_rect(|ime| ime.cursor_rect));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn ime_candidate_rect(
+        mut self,
+        candidate_rect: impl Fn(&IMEOutput) -> Rect + 't,
+    ) -> Self {
+        self.ime_candidate_rect = Some(Box::new(candidate_rect));
+        self
+    }
+
     /// Set the horizontal align of the inner text.
     #[inline]
     pub fn horizontal_align(mut self, align: Align) -> Self {
@@ -398,12 +499,38 @@ impl<'t> TextEdit<'t> {
     }
 }
 
+/// How a [`TextEdit`] shows the text being composed with an IME, before it is committed.
+///
+/// See [`TextEdit::ime_composition_style`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ImeCompositionStyle {
+    /// The line under the composed text.
+    pub underline: Stroke,
+
+    /// Painted behind the composed text.
+    pub background: Color32,
+}
+
+impl ImeCompositionStyle {
+    /// Underline the composed text with the text color, like most platforms do.
+    pub fn from_visuals(visuals: &Visuals) -> Self {
+        Self {
+            underline: Stroke::new(1.0, visuals.text_color()),
+            background: Color32::TRANSPARENT,
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 impl Widget for TextEdit<'_> {
     fn ui(self, ui: &mut Ui) -> Response {
         self.show(ui).response
     }
+
+    fn allow_culling(&self) -> bool {
+        false // We may have keyboard focus
+    }
 }
 
 impl TextEdit<'_> {
@@ -423,7 +550,7 @@ impl TextEdit<'_> {
     /// # });
     /// ```
     pub fn show(self, ui: &mut Ui) -> TextEditOutput {
-        let is_mutable = self.text.is_mutable();
+        let is_mutable = self.text.is_mutable() && !ui.is_read_only();
         let frame = self.frame;
         let where_to_put_background = ui.painter().add(Shape::Noop);
         let background_color = self
@@ -434,7 +561,7 @@ impl TextEdit<'_> {
         if frame {
             let visuals = ui.style().interact(&output.response);
             let frame_rect = output.response.rect.expand(visuals.expansion);
-            let shape = if is_mutable {
+            let mut shape = if is_mutable {
                 if output.response.has_focus() {
                     epaint::RectShape::new(
                         frame_rect,
@@ -461,6 +588,10 @@ impl TextEdit<'_> {
                     StrokeKind::Inside,
                 )
             };
+            if !output.is_valid {
+                shape.stroke.color = ui.visuals().error_fg_color;
+                shape.stroke.width = shape.stroke.width.at_least(1.0);
+            }
 
             ui.painter().set(where_to_put_background, shape);
         }
@@ -491,6 +622,10 @@ impl TextEdit<'_> {
             align,
             clip_text,
             char_limit,
+            input_filter,
+            validator,
+            ime_composition_style,
+            ime_candidate_rect,
             return_key,
             background_color: _,
         } = self;
@@ -549,6 +684,12 @@ impl TextEdit<'_> {
         });
         let mut state = TextEditState::load(ui.ctx(), id).unwrap_or_default();
 
+        // Text that can't be edited is selected together with the labels around it:
+        let shares_label_selection = interactive
+            && !password
+            && (!text.is_mutable() || ui.is_read_only())
+            && ui.style().interaction.multi_widget_text_select;
+
         // On touch screens (e.g. mobile in `eframe` web), should
         // dragging select text, or scroll the enclosing [`ScrollArea`] (if any)?
         // Since currently copying selected text in not supported on `eframe` web,
@@ -573,17 +714,17 @@ impl TextEdit<'_> {
         let text_clip_rect = rect;
         let painter = ui.painter_at(text_clip_rect.expand(1.0)); // expand to avoid clipping cursor
 
-        if interactive {
+        if interactive && !shares_label_selection {
             if let Some(pointer_pos) = response.interact_pointer_pos() {
-                if response.hovered() && text.is_mutable() {
+                if response.hovered() && text.is_mutable() && !ui.is_read_only() {
                     ui.output_mut(|o| o.mutable_text_under_cursor = true);
                 }
 
                 // TODO(emilk): drag selected text to either move or clone (ctrl on windows, alt on mac)
 
                 let singleline_offset = vec2(state.singleline_offset, 0.0);
-                let cursor_at_pointer =
-                    galley.cursor_from_pos(pointer_pos - rect.min + singleline_offset);
+                let pointer_in_galley = pointer_pos - rect.min + singleline_offset;
+                let cursor_at_pointer = galley.cursor_from_pos(pointer_in_galley);
 
                 if ui.visuals().text_cursor.preview
                     && response.hovered()
@@ -596,13 +737,33 @@ impl TextEdit<'_> {
                 }
 
                 let is_being_dragged = ui.ctx().is_being_dragged(response.id);
-                let did_interact = state.cursor.pointer_interaction(
-                    ui,
-                    &response,
-                    cursor_at_pointer,
-                    &galley,
-                    is_being_dragged,
-                );
+                let is_pressed = response.hovered() && ui.input(|i| i.pointer.any_pressed());
+                let did_interact = if !password
+                    && ui.input(|i| i.modifiers.alt)
+                    && (is_pressed || state.column_anchor.is_some())
+                {
+                    multi_cursor_pointer_interaction(
+                        &mut state,
+                        &galley,
+                        pointer_in_galley,
+                        cursor_at_pointer,
+                        is_pressed,
+                        is_being_dragged,
+                    )
+                } else {
+                    let did_interact = state.cursor.pointer_interaction(
+                        ui,
+                        &response,
+                        cursor_at_pointer,
+                        &galley,
+                        is_being_dragged,
+                    );
+                    if did_interact {
+                        state.extra_cursors.clear();
+                        state.column_anchor = None;
+                    }
+                    did_interact
+                };
 
                 if did_interact || response.clicked() {
                     ui.memory_mut(|mem| mem.request_focus(response.id));
@@ -618,7 +779,7 @@ impl TextEdit<'_> {
 
         let mut cursor_range = None;
         let prev_cursor_range = state.cursor.range(&galley);
-        if interactive && ui.memory(|mem| mem.has_focus(id)) {
+        if interactive && !shares_label_selection && ui.memory(|mem| mem.has_focus(id)) {
             ui.memory_mut(|mem| mem.set_focus_lock_filter(id, event_filter));
 
             let default_cursor_range = if cursor_at_end {
@@ -627,10 +788,16 @@ impl TextEdit<'_> {
                 CCursorRange::default()
             };
 
+            // In a read-only `Ui` the user can still move the cursor, select and copy:
+            let read_only_text = ui.is_read_only().then(|| text.as_str().to_owned());
+            let mut read_only_str = read_only_text.as_deref();
             let (changed, new_cursor_range) = events(
                 ui,
                 &mut state,
-                text,
+                match &mut read_only_str {
+                    Some(read_only_str) => read_only_str,
+                    None => &mut *text,
+                },
                 &mut galley,
                 layouter,
                 id,
@@ -639,11 +806,12 @@ impl TextEdit<'_> {
                 password,
                 default_cursor_range,
                 char_limit,
+                &input_filter,
                 event_filter,
                 return_key,
             );
 
-            if changed {
+            if changed && read_only_text.is_none() {
                 response.mark_changed();
             }
             cursor_range = Some(new_cursor_range);
@@ -717,11 +885,27 @@ impl TextEdit<'_> {
                 painter.galley(galley_pos, galley, hint_text_color);
             }
 
-            let has_focus = ui.memory(|mem| mem.has_focus(id));
+            let has_focus = !shares_label_selection && ui.memory(|mem| mem.has_focus(id));
+
+            let ime_composition_rects = match state.ime_composition {
+                Some(composition) if has_focus => char_range_rects(&galley, &composition),
+                _ => vec![],
+            };
+            let ime_composition_style = ime_composition_style
+                .unwrap_or_else(|| ImeCompositionStyle::from_visuals(ui.visuals()));
 
             if has_focus {
                 if let Some(cursor_range) = state.cursor.range(&galley) {
-                    // Add text selection rectangles to the galley:
+                    // The text being composed is selected, but shown with its own style:
+                    let is_composition = state.ime_composition.is_some_and(|composition| {
+                        composition.as_sorted_char_range() == cursor_range.as_sorted_char_range()
+                    });
+                    if !is_composition {
+                        // Add text selection rectangles to the galley:
+                        paint_text_selection(&mut galley, ui.visuals(), &cursor_range, None);
+                    }
+                }
+                for cursor_range in state.extra_cursor_ranges(&galley) {
                     paint_text_selection(&mut galley, ui.visuals(), &cursor_range, None);
                 }
             }
@@ -739,7 +923,26 @@ impl TextEdit<'_> {
                 }
             }
 
-            painter.galley(galley_pos, galley.clone(), text_color);
+            if shares_label_selection {
+                cursor_range = text_selection::LabelSelectionState::text_edit_selection(
+                    ui,
+                    &painter,
+                    &response,
+                    galley_pos,
+                    &mut galley,
+                    text_color,
+                );
+            } else {
+                for rect in &ime_composition_rects {
+                    let rect = rect.translate(galley_pos.to_vec2());
+                    painter.rect_filled(rect, 0.0, ime_composition_style.background);
+                }
+                painter.galley(galley_pos, galley.clone(), text_color);
+                for rect in &ime_composition_rects {
+                    let rect = rect.translate(galley_pos.to_vec2());
+                    painter.hline(rect.x_range(), rect.bottom(), ime_composition_style.underline);
+                }
+            }
 
             if has_focus {
                 if let Some(cursor_range) = state.cursor.range(&galley) {
@@ -770,6 +973,17 @@ impl TextEdit<'_> {
                                 primary_cursor_rect,
                                 now - state.last_interaction_time,
                             );
+                            for cursor_range in state.extra_cursor_ranges(&galley) {
+                                let cursor_rect =
+                                    cursor_rect(&galley, &cursor_range.primary, row_height)
+                                        .translate(galley_pos.to_vec2());
+                                text_selection::visuals::paint_text_cursor(
+                                    ui,
+                                    &painter,
+                                    cursor_rect,
+                                    now - state.last_interaction_time,
+                                );
+                            }
                         }
 
                         // Set IME output (in screen coords) when text is editable and visible
@@ -778,12 +992,15 @@ impl TextEdit<'_> {
                             .layer_transform_to_global(ui.layer_id())
                             .unwrap_or_default();
 
-                        ui.ctx().output_mut(|o| {
-                            o.ime = Some(crate::output::IMEOutput {
-                                rect: to_global * rect,
-                                cursor_rect: to_global * primary_cursor_rect,
-                            });
-                        });
+                        let mut ime = IMEOutput {
+                            rect: to_global * rect,
+                            cursor_rect: to_global * primary_cursor_rect,
+                            candidate_rect: to_global * rect,
+                        };
+                        if let Some(ime_candidate_rect) = &ime_candidate_rect {
+                            ime.candidate_rect = ime_candidate_rect(&ime);
+                        }
+                        ui.ctx().output_mut(|o| o.ime = Some(ime));
                     }
                 }
             }
@@ -792,6 +1009,7 @@ impl TextEdit<'_> {
         // Ensures correct IME behavior when the text input area gains or loses focus.
         if state.ime_enabled && (response.gained_focus() || response.lost_focus()) {
             state.ime_enabled = false;
+            state.ime_composition = None;
             if let Some(mut ccursor_range) = state.cursor.char_range() {
                 ccursor_range.secondary.index = ccursor_range.primary.index;
                 state.cursor.set_char_range(Some(ccursor_range));
@@ -850,6 +1068,8 @@ impl TextEdit<'_> {
             );
         }
 
+        let is_valid = validator.is_none_or(|validator| validator(text.as_str()));
+
         TextEditOutput {
             response,
             galley,
@@ -857,6 +1077,7 @@ impl TextEdit<'_> {
             text_clip_rect,
             state,
             cursor_range,
+            is_valid,
         }
     }
 }
@@ -893,6 +1114,7 @@ fn events(
     password: bool,
     default_cursor_range: CCursorRange,
     char_limit: usize,
+    input_filter: &InputFilter<'_>,
     event_filter: EventFilter,
     return_key: Option<KeyboardShortcut>,
 ) -> (bool, CCursorRange) {
@@ -907,12 +1129,16 @@ fn events(
         &(cursor_range, text.as_str().to_owned()),
     );
 
-    let copy_if_not_password = |ui: &Ui, text: String| {
+    let copy_if_not_password = |ui: &Ui, galley: &Galley, cursor_range: &CCursorRange| {
         if !password {
-            ui.ctx().copy_text(text);
+            let html = galley.job.to_html(cursor_range.as_sorted_char_range());
+            let text = cursor_range.slice_str(galley.text()).to_owned();
+            ui.ctx().copy_html(html, text);
         }
     };
 
+    let mut extra_cursors = state.extra_cursor_ranges(galley);
+
     let mut any_change = false;
 
     let mut events = ui.input(|i| i.filtered_events(&event_filter));
@@ -924,15 +1150,65 @@ fn events(
     }
 
     for event in &events {
+        let mut cursors = vec![cursor_range];
+        cursors.extend_from_slice(&extra_cursors);
+        let Some(event) = input_filter.filter_event(event, text.as_str(), &cursors) else {
+            continue;
+        };
+        let event = &event;
+
+        if !extra_cursors.is_empty() {
+            let mut cursors = vec![cursor_range];
+            cursors.append(&mut extra_cursors);
+            let handled = multi_cursor_event(
+                ui,
+                event,
+                &mut cursors,
+                text,
+                galley,
+                layouter,
+                wrap_width,
+                multiline,
+                char_limit,
+                return_key,
+            );
+            let mut cursors = multi_cursor::merge_overlapping(&cursors).into_iter();
+            cursor_range = cursors.next().unwrap_or(cursor_range);
+            extra_cursors = cursors.collect();
+
+            if let Some(changed) = handled {
+                any_change |= changed;
+                continue;
+            }
+        }
+
         let did_mutate_text = match event {
             // First handle events that only changes the selection cursor, not the text:
             event if cursor_range.on_event(os, event, galley, id) => None,
 
+            Event::Key {
+                key: Key::D,
+                pressed: true,
+                modifiers,
+                ..
+            } if !password && modifiers.matches_logically(Modifiers::COMMAND) => {
+                // Select the word at the cursor, or else the next occurrence of the selection:
+                if cursor_range.is_empty() {
+                    cursor_range = select_word_at(text.as_str(), cursor_range.primary);
+                } else if let Some(next) =
+                    multi_cursor::next_occurrence(text.as_str(), &cursor_range, &extra_cursors)
+                {
+                    extra_cursors.push(cursor_range);
+                    cursor_range = next;
+                }
+                None
+            }
+
             Event::Copy => {
                 if cursor_range.is_empty() {
                     None
                 } else {
-                    copy_if_not_password(ui, cursor_range.slice_str(text.as_str()).to_owned());
+                    copy_if_not_password(ui, galley, &cursor_range);
                     None
                 }
             }
@@ -940,7 +1216,7 @@ fn events(
                 if cursor_range.is_empty() {
                     None
                 } else {
-                    copy_if_not_password(ui, cursor_range.slice_str(text.as_str()).to_owned());
+                    copy_if_not_password(ui, galley, &cursor_range);
                     Some(CCursorRange::one(text.delete_selected(&cursor_range)))
                 }
             }
@@ -958,6 +1234,15 @@ fn events(
             Event::Text(text_to_insert) => {
                 // Newlines are handled by `Key::Enter`.
                 if !text_to_insert.is_empty() && text_to_insert != "\n" && text_to_insert != "\r" {
+                    if state.undo_at_word_boundaries
+                        && ends_word(text.as_str(), &cursor_range, text_to_insert)
+                    {
+                        state
+                            .undoer
+                            .lock()
+                            .add_undo(&(cursor_range, text.as_str().to_owned()));
+                    }
+
                     let mut ccursor = text.delete_selected(&cursor_range);
 
                     text.insert_text_at(&mut ccursor, text_to_insert, char_limit);
@@ -992,6 +1277,13 @@ fn events(
             }) =>
             {
                 if multiline {
+                    if state.undo_at_word_boundaries {
+                        state
+                            .undoer
+                            .lock()
+                            .add_undo(&(cursor_range, text.as_str().to_owned()));
+                    }
+
                     let mut ccursor = text.delete_selected(&cursor_range);
                     text.insert_text_at(&mut ccursor, "\n", char_limit);
                     // TODO(emilk): if code editor, auto-indent by same leading tabs, + one if the lines end on an opening bracket
@@ -1066,7 +1358,9 @@ fn events(
                             text.insert_text_at(&mut ccursor, text_mark, char_limit);
                         }
                         state.ime_cursor_range = cursor_range;
-                        Some(CCursorRange::two(start_cursor, ccursor))
+                        let composition = CCursorRange::two(start_cursor, ccursor);
+                        state.ime_composition = (!text_mark.is_empty()).then_some(composition);
+                        Some(composition)
                     }
                 }
                 ImeEvent::Commit(prediction) => {
@@ -1074,6 +1368,7 @@ fn events(
                         None
                     } else {
                         state.ime_enabled = false;
+                        state.ime_composition = None;
 
                         if !prediction.is_empty()
                             && cursor_range.secondary.index
@@ -1090,6 +1385,7 @@ fn events(
                 }
                 ImeEvent::Disabled => {
                     state.ime_enabled = false;
+                    state.ime_composition = None;
                     None
                 }
             },
@@ -1105,10 +1401,14 @@ fn events(
 
             // Set cursor_range using new galley:
             cursor_range = new_ccursor_range;
+
+            // Only the primary cursor takes part in e.g. undo and IME:
+            extra_cursors.clear();
         }
     }
 
     state.cursor.set_char_range(Some(cursor_range));
+    state.extra_cursors = extra_cursors;
 
     state.undoer.lock().feed_state(
         ui.input(|i| i.time),
@@ -1118,6 +1418,225 @@ fn events(
     (any_change, cursor_range)
 }
 
+/// Does typing `text_to_insert` at the cursor end the word before it,
+/// e.g. typing a space or a comma after a word?
+fn ends_word(text: &str, cursor_range: &CCursorRange, text_to_insert: &str) -> bool {
+    let starts_with_word_char = text_to_insert.chars().next().is_some_and(is_word_char);
+    let [min, _] = cursor_range.sorted_cursors();
+    let follows_word_char = min
+        .index
+        .checked_sub(1)
+        .and_then(|index| text.chars().nth(index))
+        .is_some_and(is_word_char);
+    follows_word_char && !starts_with_word_char
+}
+
+// ----------------------------------------------------------------------------
+
+/// Alt-click to add a cursor, and alt-drag to select a column.
+///
+/// Returns `true` if there was interaction.
+fn multi_cursor_pointer_interaction(
+    state: &mut TextEditState,
+    galley: &Galley,
+    pointer_in_galley: Vec2,
+    cursor_at_pointer: CCursor,
+    is_pressed: bool,
+    is_being_dragged: bool,
+) -> bool {
+    if is_pressed {
+        let mut cursors = vec![CCursorRange::one(cursor_at_pointer)];
+        cursors.extend(state.cursor.range(galley));
+        cursors.extend(state.extra_cursor_ranges(galley));
+        let mut cursors = multi_cursor::merge_overlapping(&cursors).into_iter();
+        state.cursor.set_char_range(cursors.next());
+        state.extra_cursors = cursors.collect();
+        state.column_anchor = Some(pointer_in_galley);
+        true
+    } else if is_being_dragged {
+        if let Some(anchor) = state.column_anchor {
+            if anchor != pointer_in_galley {
+                // The column replaces all other cursors:
+                let mut cursors = multi_cursor::column_selection(galley, anchor, pointer_in_galley);
+                if let Some(primary) = cursors.pop() {
+                    state.cursor.set_char_range(Some(primary));
+                    state.extra_cursors = cursors;
+                }
+            }
+        }
+        true
+    } else {
+        state.column_anchor = None;
+        false
+    }
+}
+
+/// An edit of the text at one cursor, returning the new cursor if the text changed.
+type CursorEdit<'a> =
+    Box<dyn FnMut(&mut dyn TextBuffer, &Galley, &CCursorRange) -> Option<CCursorRange> + 'a>;
+
+/// Handle an event at each of several cursors, the first of which is the primary one.
+///
+/// Returns whether the text changed,
+/// or `None` for events that only apply to the primary cursor, like undo and IME.
+#[expect(clippy::too_many_arguments)]
+fn multi_cursor_event(
+    ui: &Ui,
+    event: &Event,
+    cursors: &mut [CCursorRange],
+    text: &mut dyn TextBuffer,
+    galley: &mut Arc<Galley>,
+    layouter: &mut dyn FnMut(&Ui, &dyn TextBuffer, f32) -> Arc<Galley>,
+    wrap_width: f32,
+    multiline: bool,
+    char_limit: usize,
+    return_key: Option<KeyboardShortcut>,
+) -> Option<bool> {
+    let os = ui.ctx().os();
+
+    // Key presses we don't know are left for the primary cursor, unless they change the text:
+    let mut only_if_changed = false;
+
+    let mut edit: CursorEdit<'_> = match event {
+        Event::Copy | Event::Cut => {
+            let mut selected: Vec<CCursorRange> =
+                cursors.iter().filter(|r| !r.is_empty()).copied().collect();
+            if selected.is_empty() {
+                return Some(false);
+            }
+            selected.sort_by_key(|r| r.as_sorted_char_range().start);
+            let copied: Vec<&str> = selected
+                .iter()
+                .map(|r| r.slice_str(galley.text()))
+                .collect();
+            ui.ctx().copy_text(copied.join("\n"));
+
+            if matches!(event, Event::Copy) {
+                return Some(false);
+            }
+            Box::new(|text: &mut dyn TextBuffer, _: &Galley, cursor_range: &CCursorRange| {
+                Some(CCursorRange::one(text.delete_selected(cursor_range)))
+            })
+        }
+        Event::Paste(text_to_insert) => insert_at_each_cursor(text_to_insert, char_limit),
+        Event::Text(text_to_insert) => {
+            // Newlines are handled by `Key::Enter`.
+            if text_to_insert == "\n" || text_to_insert == "\r" {
+                return Some(false);
+            }
+            insert_at_each_cursor(text_to_insert, char_limit)
+        }
+        Event::Key {
+            key: Key::Tab,
+            pressed: true,
+            modifiers,
+            ..
+        } if multiline => Box::new(
+            |text: &mut dyn TextBuffer, _: &Galley, cursor_range: &CCursorRange| {
+                let mut ccursor = text.delete_selected(cursor_range);
+                if modifiers.shift {
+                    text.decrease_indentation(&mut ccursor);
+                } else {
+                    text.insert_text_at(&mut ccursor, "\t", char_limit);
+                }
+                Some(CCursorRange::one(ccursor))
+            },
+        ),
+        Event::Key {
+            key,
+            pressed: true,
+            modifiers,
+            ..
+        } if multiline
+            && return_key.is_some_and(|return_key| {
+                *key == return_key.logical_key
+                    && modifiers.matches_logically(return_key.modifiers)
+            }) =>
+        {
+            insert_at_each_cursor("\n", char_limit)
+        }
+        Event::Key {
+            key,
+            pressed: true,
+            modifiers,
+            ..
+        } => {
+            let mut moved = false;
+            for cursor_range in cursors.iter_mut() {
+                moved |= cursor_range.on_key_press(os, galley, modifiers, *key);
+            }
+            if moved {
+                return Some(false);
+            }
+            only_if_changed = true;
+            Box::new(
+                move |text: &mut dyn TextBuffer, galley: &Galley, cursor_range: &CCursorRange| {
+                    check_for_mutating_key_press(os, cursor_range, text, galley, modifiers, *key)
+                },
+            )
+        }
+        _ => return None,
+    };
+
+    let changed = edit_each_cursor(ui, cursors, text, galley, layouter, wrap_width, &mut *edit);
+    if only_if_changed && !changed {
+        None
+    } else {
+        Some(changed)
+    }
+}
+
+/// Replace the selection with `text_to_insert`.
+fn insert_at_each_cursor(text_to_insert: &str, char_limit: usize) -> CursorEdit<'_> {
+    Box::new(move |text: &mut dyn TextBuffer, _: &Galley, cursor_range: &CCursorRange| {
+        let mut ccursor = text.delete_selected(cursor_range);
+        text.insert_text_at(&mut ccursor, text_to_insert, char_limit);
+        Some(CCursorRange::one(ccursor))
+    })
+}
+
+/// Make the same edit at each cursor, from the first one in the text to the last one.
+///
+/// Returns `true` if the text changed.
+fn edit_each_cursor(
+    ui: &Ui,
+    cursors: &mut [CCursorRange],
+    text: &mut dyn TextBuffer,
+    galley: &mut Arc<Galley>,
+    layouter: &mut dyn FnMut(&Ui, &dyn TextBuffer, f32) -> Arc<Galley>,
+    wrap_width: f32,
+    edit: &mut dyn FnMut(&mut dyn TextBuffer, &Galley, &CCursorRange) -> Option<CCursorRange>,
+) -> bool {
+    let mut order: Vec<usize> = (0..cursors.len()).collect();
+    order.sort_by_key(|&i| cursors[i].as_sorted_char_range().start);
+
+    // How many characters were added (or removed) before the next cursor:
+    let mut shift = 0_isize;
+    let mut changed = false;
+    for i in order {
+        let shift_ccursor = |ccursor: CCursor| CCursor {
+            index: ccursor.index.saturating_add_signed(shift),
+            ..ccursor
+        };
+        let cursor_range = CCursorRange {
+            primary: shift_ccursor(cursors[i].primary),
+            secondary: shift_ccursor(cursors[i].secondary),
+            h_pos: cursors[i].h_pos,
+        };
+
+        let num_chars_before = text.as_str().chars().count();
+        if let Some(new_cursor_range) = edit(text, galley, &cursor_range) {
+            *galley = layouter(ui, text, wrap_width);
+            shift += text.as_str().chars().count() as isize - num_chars_before as isize;
+            cursors[i] = new_cursor_range;
+            changed = true;
+        } else {
+            cursors[i] = cursor_range;
+        }
+    }
+    changed
+}
+
 // ----------------------------------------------------------------------------
 
 fn remove_ime_incompatible_events(events: &mut Vec<Event>) {
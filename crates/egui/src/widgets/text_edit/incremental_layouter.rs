@@ -0,0 +1,172 @@
+use std::{ops::Range, sync::Arc};
+
+use epaint::text::{Galley, LayoutJob};
+
+use crate::Ui;
+
+/// Lays out text one paragraph at a time, only calling your highlighter for the paragraphs
+/// that changed since the last layout.
+///
+/// Use this from [`crate::TextEdit::layouter`] for syntax highlighting large texts:
+/// typing then only re-lexes the edited lines instead of the whole text.
+/// The galleys of the unchanged paragraphs are cached by [`crate::text::Fonts`] as usual.
+///
+/// The highlighter only sees one paragraph at a time (including its trailing `\n`),
+/// so state spanning several lines (e.g. block comments) is not supported.
+/// If the highlighting depends on something else than the text, e.g. the theme,
+/// call [`Self::clear`] when that changes.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut my_code = String::new();
+/// # fn my_highlighter(ui: &egui::Ui, line: &str) -> egui::text::LayoutJob { Default::default() }
+/// // Keep this between frames, e.g. in your app struct:
+/// let mut incremental = egui::text_edit::IncrementalLayouter::default();
+///
+/// let mut layouter = |ui: &egui::Ui, buf: &dyn egui::TextBuffer, wrap_width: f32| {
+///     incremental.layout(ui, buf.as_str(), wrap_width, my_highlighter)
+/// };
+/// ui.add(egui::TextEdit::multiline(&mut my_code).layouter(&mut layouter));
+/// # });
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct IncrementalLayouter {
+    /// The paragraphs of the last layout, in order.
+    paragraphs: Vec<LayoutJob>,
+
+    /// See [`Self::last_changed_range`].
+    last_changed_range: Option<Range<usize>>,
+}
+
+impl IncrementalLayouter {
+    /// Lay out `text`, calling `layout_paragraph` for each paragraph that changed since last
+    /// time.
+    ///
+    /// `layout_paragraph` is given one paragraph of the text, including its trailing `\n`,
+    /// and must return a [`LayoutJob`] with exactly that text.
+    pub fn layout(
+        &mut self,
+        ui: &Ui,
+        text: &str,
+        wrap_width: f32,
+        mut layout_paragraph: impl FnMut(&Ui, &str) -> LayoutJob,
+    ) -> Arc<Galley> {
+        let new_paragraphs: Vec<&str> = text.split_inclusive('\n').collect();
+
+        let num_same_before = self
+            .paragraphs
+            .iter()
+            .zip(&new_paragraphs)
+            .take_while(|(old, new)| old.text == **new)
+            .count();
+        let num_same_after = self.paragraphs[num_same_before..]
+            .iter()
+            .rev()
+            .zip(new_paragraphs[num_same_before..].iter().rev())
+            .take_while(|(old, new)| old.text == **new)
+            .count();
+
+        let changed = num_same_before..new_paragraphs.len() - num_same_after;
+        let changed_start: usize = new_paragraphs[..changed.start]
+            .iter()
+            .copied()
+            .map(str::len)
+            .sum();
+        let changed_len: usize = new_paragraphs[changed.clone()]
+            .iter()
+            .copied()
+            .map(str::len)
+            .sum();
+        self.last_changed_range = Some(changed_start..changed_start + changed_len);
+
+        let old_changed = num_same_before..self.paragraphs.len() - num_same_after;
+        let relaid_out: Vec<LayoutJob> = new_paragraphs[changed]
+            .iter()
+            .map(|paragraph| {
+                let job = layout_paragraph(ui, paragraph);
+                debug_assert_eq!(
+                    job.text, *paragraph,
+                    "The layout of a paragraph must have the same text"
+                );
+                job
+            })
+            .collect();
+        self.paragraphs.splice(old_changed, relaid_out);
+
+        let mut job = LayoutJob {
+            text: String::with_capacity(text.len()),
+            ..Default::default()
+        };
+        for paragraph in &self.paragraphs {
+            let offset = job.text.len();
+            job.text += &paragraph.text;
+            job.sections.extend(paragraph.sections.iter().cloned().map(|mut section| {
+                section.byte_range =
+                    offset + section.byte_range.start..offset + section.byte_range.end;
+                section
+            }));
+        }
+        job.wrap.max_width = wrap_width;
+        ui.fonts(|f| f.layout_job(job))
+    }
+
+    /// The byte range of the text that was laid out anew by the last [`Self::layout`],
+    /// or `None` if nothing has been laid out yet.
+    ///
+    /// The range is empty if no paragraph changed.
+    pub fn last_changed_range(&self) -> Option<Range<usize>> {
+        self.last_changed_range.clone()
+    }
+
+    /// Forget all paragraphs, so that the next [`Self::layout`] lays out all of the text again.
+    pub fn clear(&mut self) {
+        self.paragraphs.clear();
+    }
+}
+
+#[test]
+fn test_incremental_layouter() {
+    use crate::{CentralPanel, Color32, Context, FontId, text::TextFormat};
+
+    fn layout(
+        ctx: &Context,
+        incremental: &mut IncrementalLayouter,
+        text: &str,
+    ) -> (String, Vec<String>) {
+        let mut galley_text = String::new();
+        let mut laid_out = vec![];
+        let _ = ctx.run(Default::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                let galley = incremental.layout(ui, text, f32::INFINITY, |_ui, paragraph| {
+                    laid_out.push(paragraph.to_owned());
+                    LayoutJob::single_section(
+                        paragraph.to_owned(),
+                        TextFormat::simple(FontId::monospace(12.0), Color32::WHITE),
+                    )
+                });
+                galley_text = galley.text().to_owned();
+            });
+        });
+        (galley_text, laid_out)
+    }
+
+    let ctx = Context::default();
+    let mut incremental = IncrementalLayouter::default();
+
+    let (text, laid_out) = layout(&ctx, &mut incremental, "fn a() {}\nfn b() {}\n");
+    assert_eq!(text, "fn a() {}\nfn b() {}\n");
+    assert_eq!(laid_out, ["fn a() {}\n", "fn b() {}\n"]);
+
+    let (text, laid_out) = layout(&ctx, &mut incremental, "fn a() {}\nfn bc() {}\n");
+    assert_eq!(text, "fn a() {}\nfn bc() {}\n");
+    assert_eq!(laid_out, ["fn bc() {}\n"], "Only the edited line is laid out again");
+    assert_eq!(incremental.last_changed_range(), Some(10..21));
+
+    let (_, laid_out) = layout(&ctx, &mut incremental, "fn a() {}\nfn bc() {}\n");
+    assert!(laid_out.is_empty());
+    assert_eq!(incremental.last_changed_range(), Some(0..0));
+
+    let (text, laid_out) = layout(&ctx, &mut incremental, "fn bc() {}\n");
+    assert_eq!(text, "fn bc() {}\n");
+    assert!(laid_out.is_empty(), "Removing a line lays out nothing");
+}
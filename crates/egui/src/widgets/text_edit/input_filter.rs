@@ -0,0 +1,95 @@
+use unicode_segmentation::UnicodeSegmentation as _;
+
+use crate::{Event, text::CCursorRange};
+
+/// What typed and pasted text a [`crate::TextEdit`] accepts.
+///
+/// See [`crate::TextEdit::char_filter`] and [`crate::TextEdit::grapheme_limit`].
+#[derive(Default)]
+pub(crate) struct InputFilter<'t> {
+    pub char_filter: Option<Box<dyn Fn(char) -> bool + 't>>,
+    pub grapheme_limit: Option<usize>,
+}
+
+impl InputFilter<'_> {
+    /// The event with the rejected characters removed from its text,
+    /// or `None` if nothing of it is left.
+    ///
+    /// `cursors` are where the text will be inserted, each replacing its selection.
+    pub fn filter_event(
+        &self,
+        event: &Event,
+        text: &str,
+        cursors: &[CCursorRange],
+    ) -> Option<Event> {
+        match event {
+            Event::Text(text_to_insert) => {
+                Some(Event::Text(self.filter_text(text_to_insert, text, cursors)?))
+            }
+            Event::Paste(text_to_insert) => {
+                Some(Event::Paste(self.filter_text(text_to_insert, text, cursors)?))
+            }
+            _ => Some(event.clone()),
+        }
+    }
+
+    fn filter_text(
+        &self,
+        text_to_insert: &str,
+        text: &str,
+        cursors: &[CCursorRange],
+    ) -> Option<String> {
+        let mut filtered: String = match &self.char_filter {
+            Some(char_filter) => text_to_insert.chars().filter(|c| char_filter(*c)).collect(),
+            None => text_to_insert.to_owned(),
+        };
+
+        if let Some(grapheme_limit) = self.grapheme_limit {
+            let num_selected: usize = cursors
+                .iter()
+                .map(|range| range.slice_str(text).graphemes(true).count())
+                .sum();
+            let num_kept = text.graphemes(true).count().saturating_sub(num_selected);
+            let num_per_cursor = grapheme_limit.saturating_sub(num_kept) / cursors.len().max(1);
+            if let Some((end, _)) = filtered.grapheme_indices(true).nth(num_per_cursor) {
+                filtered.truncate(end);
+            }
+        }
+
+        (!filtered.is_empty() || text_to_insert.is_empty()).then_some(filtered)
+    }
+}
+
+#[test]
+fn test_input_filter() {
+    use epaint::text::cursor::CCursor;
+
+    let filter = InputFilter {
+        char_filter: Some(Box::new(|c| c.is_ascii_digit())),
+        grapheme_limit: Some(4),
+    };
+    let at = |index| [CCursorRange::one(CCursor::new(index))];
+    let filter_text = |text_to_insert: &str, text: &str, cursors: &[CCursorRange]| {
+        filter.filter_event(&Event::Paste(String::from(text_to_insert)), text, cursors)
+    };
+
+    assert_eq!(filter_text("1a2", "", &at(0)), Some(Event::Paste("12".to_owned())));
+    assert_eq!(filter_text("abc", "", &at(0)), None, "Nothing left to insert");
+    assert_eq!(filter_text("123", "45", &at(2)), Some(Event::Paste("12".to_owned())));
+    assert_eq!(filter_text("1", "4567", &at(4)), None, "Full");
+    assert_eq!(
+        filter_text("123", "4567", &[CCursorRange::two(CCursor::new(1), CCursor::new(3))]),
+        Some(Event::Paste("12".to_owned())),
+        "The selection is replaced"
+    );
+
+    let filter = InputFilter {
+        char_filter: None,
+        grapheme_limit: Some(2),
+    };
+    assert_eq!(
+        filter.filter_event(&Event::Text("e\u{301}e\u{301}e".to_owned()), "", &at(0)),
+        Some(Event::Text("e\u{301}e\u{301}".to_owned())),
+        "An accented letter is one grapheme"
+    );
+}
@@ -1,9 +1,13 @@
 mod builder;
+mod incremental_layouter;
+mod input_filter;
 mod output;
 mod state;
 mod text_buffer;
 
 pub use {
-    crate::text_selection::TextCursorState, builder::TextEdit, output::TextEditOutput,
+    crate::text_selection::TextCursorState,
+    builder::{ImeCompositionStyle, TextEdit},
+    incremental_layouter::IncrementalLayouter, output::TextEditOutput,
     state::TextEditState, text_buffer::TextBuffer,
 };
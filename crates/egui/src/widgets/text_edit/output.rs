@@ -21,6 +21,11 @@ pub struct TextEditOutput {
 
     /// Where the text cursor is.
     pub cursor_range: Option<CCursorRange>,
+
+    /// Does the text pass the [`crate::TextEdit::validator`]?
+    ///
+    /// Always `true` if there is none.
+    pub is_valid: bool,
 }
 
 // TODO(emilk): add `output.paint` and `output.store` and split out that code from `TextEdit::show`.
@@ -3,8 +3,9 @@ use std::sync::Arc;
 use crate::mutex::Mutex;
 
 use crate::{
-    Context, Id,
+    Context, Galley, Id, TextBuffer, Vec2,
     text_selection::{CCursorRange, TextCursorState},
+    util::undoer,
 };
 
 pub type TextEditUndoer = crate::util::undoer::Undoer<(CCursorRange, String)>;
@@ -37,10 +38,29 @@ pub struct TextEditState {
     /// Controls the text selection.
     pub cursor: TextCursorState,
 
+    /// More cursors besides [`Self::cursor`], for editing the text in several places at once.
+    ///
+    /// The user adds these with alt-click, alt-drag (a column selection),
+    /// and ctrl-D (cmd-D on Mac) to select the next occurrence of the selected text.
+    /// A click without alt removes them.
+    pub extra_cursors: Vec<CCursorRange>,
+
+    /// Where an alt-drag column selection started, in galley coordinates.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) column_anchor: Option<Vec2>,
+
     /// Wrapped in Arc for cheaper clones.
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) undoer: Arc<Mutex<TextEditUndoer>>,
 
+    /// Add an undo point at the end of each word as it is typed,
+    /// so that undo removes one word at a time.
+    ///
+    /// Otherwise edits are only grouped by time, see [`Self::set_undo_settings`].
+    ///
+    /// Default: `false`.
+    pub undo_at_word_boundaries: bool,
+
     // If IME candidate window is shown on this text edit.
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) ime_enabled: bool,
@@ -49,6 +69,10 @@ pub struct TextEditState {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) ime_cursor_range: CCursorRange,
 
+    /// See [`Self::ime_composition`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) ime_composition: Option<CCursorRange>,
+
     // Visual offset when editing singleline text bigger than the width.
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) singleline_offset: f32,
@@ -68,6 +92,22 @@ impl TextEditState {
         ctx.data_mut(|d| d.insert_persisted(id, self));
     }
 
+    /// The [`Self::extra_cursors`], clamped within the character range of the given [`Galley`].
+    pub fn extra_cursor_ranges(&self, galley: &Galley) -> Vec<CCursorRange> {
+        self.extra_cursors
+            .iter()
+            .filter_map(|range| TextCursorState::from(*range).range(galley))
+            .collect()
+    }
+
+    /// The text being composed with an IME, which is not yet committed.
+    ///
+    /// It is already part of the text, and is shown with the
+    /// [`crate::text_edit::ImeCompositionStyle`].
+    pub fn ime_composition(&self) -> Option<CCursorRange> {
+        self.ime_composition
+    }
+
     pub fn undoer(&self) -> TextEditUndoer {
         self.undoer.lock().clone()
     }
@@ -77,7 +117,115 @@ impl TextEditState {
         *self.undoer.lock() = undoer;
     }
 
+    /// Forget the undo history, but keep the [`Self::undo_settings`].
     pub fn clear_undoer(&mut self) {
-        self.set_undoer(TextEditUndoer::default());
+        let settings = self.undo_settings();
+        self.set_undoer(TextEditUndoer::with_settings(settings));
+    }
+
+    /// How edits are grouped into undo points over time, and how many are kept.
+    pub fn undo_settings(&self) -> undoer::Settings {
+        self.undoer.lock().settings().clone()
     }
+
+    #[expect(clippy::needless_pass_by_ref_mut)] // Intentionally hide interiority of mutability
+    pub fn set_undo_settings(&mut self, settings: undoer::Settings) {
+        self.undoer.lock().set_settings(settings);
+    }
+
+    /// Is there an edit of `text` to undo?
+    pub fn has_undo(&self, text: &str) -> bool {
+        self.undoer.lock().has_undo(&self.undo_state(text))
+    }
+
+    /// Is there an undone edit of `text` to redo?
+    pub fn has_redo(&self, text: &str) -> bool {
+        self.undoer.lock().has_redo(&self.undo_state(text))
+    }
+
+    /// Undo the last edit of `text`, like pressing ctrl-Z (cmd-Z on Mac) would.
+    ///
+    /// Returns `false` if there was nothing to undo.
+    /// Remember to [`Self::store`] the state afterwards.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut text = String::new();
+    /// let output = egui::TextEdit::multiline(&mut text).show(ui);
+    /// if ui.button("Undo").clicked() {
+    ///     let mut state = output.state;
+    ///     state.undo(&mut text);
+    ///     state.store(ui.ctx(), output.response.id);
+    /// }
+    /// # });
+    /// ```
+    pub fn undo(&mut self, text: &mut dyn TextBuffer) -> bool {
+        let current_state = self.undo_state(text.as_str());
+        let undo_state = self.undoer.lock().undo(&current_state).cloned();
+        self.apply_undo_state(text, undo_state)
+    }
+
+    /// Redo the last undone edit of `text`, like pressing ctrl-Y (cmd-shift-Z on Mac) would.
+    ///
+    /// Returns `false` if there was nothing to redo.
+    /// Remember to [`Self::store`] the state afterwards.
+    pub fn redo(&mut self, text: &mut dyn TextBuffer) -> bool {
+        let current_state = self.undo_state(text.as_str());
+        let redo_state = self.undoer.lock().redo(&current_state).cloned();
+        self.apply_undo_state(text, redo_state)
+    }
+
+    fn undo_state(&self, text: &str) -> (CCursorRange, String) {
+        (self.cursor.char_range().unwrap_or_default(), text.to_owned())
+    }
+
+    fn apply_undo_state(
+        &mut self,
+        text: &mut dyn TextBuffer,
+        state: Option<(CCursorRange, String)>,
+    ) -> bool {
+        let Some((cursor_range, new_text)) = state else {
+            return false;
+        };
+        text.replace_with(&new_text);
+        self.cursor.set_char_range(Some(cursor_range));
+        self.extra_cursors.clear();
+        true
+    }
+}
+
+#[test]
+fn test_text_edit_state_undo_redo() {
+    use epaint::text::cursor::CCursor;
+
+    let mut state = TextEditState::default();
+    state.set_undo_settings(undoer::Settings {
+        max_undos: 2,
+        ..Default::default()
+    });
+
+    let mut text = String::new();
+    for new_text in ["a", "ab", "abc"] {
+        text = new_text.to_owned();
+        let cursor_range = CCursorRange::one(CCursor::new(text.len()));
+        state.cursor.set_char_range(Some(cursor_range));
+        state.undoer.lock().add_undo(&(cursor_range, text.clone()));
+    }
+    text.push('d');
+    state.cursor.set_char_range(Some(CCursorRange::one(CCursor::new(4))));
+
+    assert!(state.has_undo(&text));
+    assert!(state.undo(&mut text));
+    assert_eq!(text, "abc");
+    assert!(state.undo(&mut text));
+    assert_eq!(text, "ab");
+    assert!(!state.undo(&mut text), "Only two undo points are kept");
+
+    assert!(state.has_redo(&text));
+    assert!(state.redo(&mut text));
+    assert_eq!(text, "abc");
+
+    state.clear_undoer();
+    assert!(!state.has_undo(&text));
+    assert_eq!(state.undo_settings().max_undos, 2);
 }
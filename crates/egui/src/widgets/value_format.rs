@@ -0,0 +1,358 @@
+use std::{borrow::Cow, ops::RangeInclusive};
+
+/// How to show a number with its unit, e.g. "1.5 GiB", "2h 30m", "25%" or "90°".
+///
+/// Each format can also parse the text back, so it can be used for editing:
+/// use [`crate::DragValue::value_format`] or [`crate::Slider::value_format`].
+/// For showing numbers elsewhere, e.g. in table cells, use [`Self::format`].
+///
+/// ```
+/// use egui::ValueFormat;
+///
+/// assert_eq!(ValueFormat::Bytes.format(1.5 * 1024.0 * 1024.0 * 1024.0, 0..=6), "1.5 GiB");
+/// assert_eq!(ValueFormat::Duration.parse("2h 30m"), Some(9000.0));
+/// assert_eq!(ValueFormat::si("Hz").format(1500.0, 0..=6), "1.5 kHz");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ValueFormat {
+    /// With an SI prefix before the given unit, e.g. "1.5 kHz" or "20 µs".
+    ///
+    /// The unit can be empty, e.g. "1.5 k".
+    /// Shown with three significant digits.
+    Si(Cow<'static, str>),
+
+    /// A number of bytes, with binary prefixes, e.g. "1.5 GiB".
+    ///
+    /// Shown with three significant digits.
+    /// When parsing, decimal prefixes (e.g. "GB") are also understood.
+    Bytes,
+
+    /// A number of bytes, with decimal prefixes, e.g. "1.5 GB".
+    ///
+    /// Shown with three significant digits.
+    /// When parsing, binary prefixes (e.g. "GiB") are also understood.
+    BytesDecimal,
+
+    /// A number of seconds, e.g. "2h 30m", "1.5 s" or "250 ms".
+    ///
+    /// Durations of a minute or more are shown in whole seconds.
+    /// When parsing, the units `d`, `h`, `m` (or `min`), `s`, `ms`, `µs` (or `us`) and `ns`
+    /// can be combined, e.g. "1h 30m" or "1.5h".
+    Duration,
+
+    /// A fraction shown as a percentage, e.g. `0.25` as "25%".
+    Percent,
+
+    /// An angle in radians shown in degrees, e.g. [`std::f64::consts::FRAC_PI_2`] as "90°".
+    ///
+    /// When parsing, angles ending with "rad" are read as radians.
+    Degrees,
+
+    /// An angle in radians, e.g. "1.57 rad".
+    ///
+    /// When parsing, angles ending with "°" or "deg" are read as degrees.
+    Radians,
+}
+
+/// SI prefixes, from pico to peta.
+const SI_PREFIXES: [&str; 10] = ["p", "n", "µ", "m", "", "k", "M", "G", "T", "P"];
+
+/// The index of the empty prefix in [`SI_PREFIXES`].
+const SI_UNIT_INDEX: usize = 4;
+
+const BINARY_BYTE_UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+const DECIMAL_BYTE_UNITS: [&str; 7] = ["B", "kB", "MB", "GB", "TB", "PB", "EB"];
+
+/// Longer units first, so that e.g. "ms" is not read as "m".
+const DURATION_UNITS: [(&str, f64); 10] = [
+    ("d", 24.0 * 60.0 * 60.0),
+    ("h", 60.0 * 60.0),
+    ("min", 60.0),
+    ("ms", 1e-3),
+    ("m", 60.0),
+    ("sec", 1.0),
+    ("s", 1.0),
+    ("µs", 1e-6),
+    ("us", 1e-6),
+    ("ns", 1e-9),
+];
+
+impl ValueFormat {
+    /// With an SI prefix before the given unit, e.g. `ValueFormat::si("Hz")` for "1.5 kHz".
+    pub fn si(unit: impl Into<Cow<'static, str>>) -> Self {
+        Self::Si(unit.into())
+    }
+
+    /// Format the value.
+    ///
+    /// `decimals` is the range of decimals to show, as for [`crate::Style::number_formatter`].
+    /// It is used by [`Self::Percent`], [`Self::Degrees`] and [`Self::Radians`],
+    /// while the other formats show a fixed number of significant digits.
+    pub fn format(&self, value: f64, decimals: RangeInclusive<usize>) -> String {
+        if !value.is_finite() {
+            return value.to_string();
+        }
+        let sign = if value < 0.0 { "-" } else { "" };
+        let abs = value.abs();
+
+        match self {
+            Self::Si(unit) => {
+                let (number, prefix) = with_prefix(abs, 1000.0, &SI_PREFIXES, SI_UNIT_INDEX);
+                format!("{sign}{number} {prefix}{unit}").trim_end().to_owned()
+            }
+            Self::Bytes => {
+                let (number, unit) = with_prefix(abs, 1024.0, &BINARY_BYTE_UNITS, 0);
+                format!("{sign}{number} {unit}")
+            }
+            Self::BytesDecimal => {
+                let (number, unit) = with_prefix(abs, 1000.0, &DECIMAL_BYTE_UNITS, 0);
+                format!("{sign}{number} {unit}")
+            }
+            Self::Duration => format!("{sign}{}", format_duration(abs)),
+            Self::Percent => {
+                // Two fewer decimals are needed, since the number is 100 times larger:
+                let (min_decimals, max_decimals) = decimals.into_inner();
+                let decimals = min_decimals.saturating_sub(2)..=max_decimals.saturating_sub(2);
+                let number = emath::format_with_decimals_in_range(100.0 * value, decimals);
+                format!("{number}%")
+            }
+            Self::Degrees => {
+                let number = emath::format_with_decimals_in_range(value.to_degrees(), decimals);
+                format!("{number}°")
+            }
+            Self::Radians => {
+                let number = emath::format_with_decimals_in_range(value, decimals);
+                format!("{number} rad")
+            }
+        }
+    }
+
+    /// Parse text written in this format, or a plain number.
+    ///
+    /// Whitespace is ignored, and the unit can be left out.
+    pub fn parse(&self, text: &str) -> Option<f64> {
+        let text: String = text
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| if c == '−' { '-' } else { c })
+            .collect();
+        if let Ok(value) = text.parse::<f64>() {
+            return Some(match self {
+                Self::Percent => value / 100.0,
+                Self::Degrees => value.to_radians(),
+                Self::Si(_) | Self::Bytes | Self::BytesDecimal | Self::Duration | Self::Radians => {
+                    value
+                }
+            });
+        }
+
+        let (sign, text) = match text.strip_prefix('-') {
+            Some(rest) => (-1.0, rest),
+            None => (1.0, text.strip_prefix('+').unwrap_or(&text)),
+        };
+
+        let abs = match self {
+            Self::Si(unit) => {
+                let unit: String = unit.chars().filter(|c| !c.is_whitespace()).collect();
+                let text = text.strip_suffix(unit.as_str()).unwrap_or(text);
+                parse_with_prefix(text, 1000.0, &SI_PREFIXES, SI_UNIT_INDEX)?
+            }
+            Self::Bytes | Self::BytesDecimal => {
+                let text = text.strip_suffix(['B', 'b']).unwrap_or(text);
+                if let Some(text) = text.strip_suffix('i') {
+                    parse_with_prefix(text, 1024.0, &["", "K", "M", "G", "T", "P", "E"], 0)?
+                } else {
+                    parse_with_prefix(text, 1000.0, &["", "k", "M", "G", "T", "P", "E"], 0)
+                        .or_else(|| parse_with_prefix(text, 1000.0, &["", "K"], 0))?
+                }
+            }
+            Self::Duration => parse_duration(text)?,
+            Self::Percent => text.strip_suffix('%')?.parse::<f64>().ok()? / 100.0,
+            Self::Degrees | Self::Radians => {
+                if let Some(radians) = text.strip_suffix("rad") {
+                    radians.parse::<f64>().ok()?
+                } else {
+                    let degrees = text.strip_suffix('°').or_else(|| text.strip_suffix("deg"))?;
+                    degrees.parse::<f64>().ok()?.to_radians()
+                }
+            }
+        };
+        Some(sign * abs)
+    }
+}
+
+/// Scale the positive `value` to the prefix that gives it one to three digits before the point,
+/// and round it to three significant digits.
+///
+/// `units[unit_index]` is the unit of `value`, and each one after it is `base` times larger.
+fn with_prefix(
+    value: f64,
+    base: f64,
+    units: &[&'static str],
+    unit_index: usize,
+) -> (String, &'static str) {
+    let scale = |index: usize| base.powi(index as i32 - unit_index as i32);
+
+    let mut index = unit_index;
+    if 0.0 < value {
+        while index + 1 < units.len() && base <= value / scale(index) {
+            index += 1;
+        }
+        while 0 < index && value / scale(index) < 1.0 {
+            index -= 1;
+        }
+    }
+
+    loop {
+        let scaled = value / scale(index);
+        let decimals = if index == 0 && unit_index == 0 {
+            0 // Whole bytes
+        } else if scaled < 10.0 {
+            2
+        } else if scaled < 100.0 {
+            1
+        } else {
+            0
+        };
+        let text = format!("{scaled:.decimals$}");
+
+        // Rounding can give e.g. "1000 k", which should be "1 M":
+        if index + 1 < units.len() && base <= text.parse::<f64>().unwrap_or(0.0) {
+            index += 1;
+            continue;
+        }
+        return (trim_zeros(text), units[index]);
+    }
+}
+
+/// "1.50" -> "1.5" and "2.00" -> "2".
+fn trim_zeros(text: String) -> String {
+    if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.').to_owned()
+    } else {
+        text
+    }
+}
+
+fn parse_with_prefix(text: &str, base: f64, units: &[&str], unit_index: usize) -> Option<f64> {
+    for (index, prefix) in units.iter().enumerate().rev() {
+        if let Some(number) = text.strip_suffix(prefix) {
+            if let Ok(number) = number.parse::<f64>() {
+                // Dividing rounds better than multiplying with e.g. 0.001:
+                return Some(if unit_index <= index {
+                    number * base.powi((index - unit_index) as i32)
+                } else {
+                    number / base.powi((unit_index - index) as i32)
+                });
+            }
+        }
+    }
+    None
+}
+
+fn format_duration(seconds: f64) -> String {
+    if seconds < 59.5 {
+        let (number, unit) = with_prefix(seconds, 1000.0, &["ns", "µs", "ms", "s"], 3);
+        return format!("{number} {unit}");
+    }
+
+    let mut remaining = seconds.round() as u64;
+    let mut parts = vec![];
+    for (unit, unit_seconds) in [("d", 24 * 60 * 60), ("h", 60 * 60), ("m", 60), ("s", 1)] {
+        let count = remaining / unit_seconds;
+        remaining %= unit_seconds;
+        if 0 < count {
+            parts.push(format!("{count}{unit}"));
+        }
+    }
+    parts.join(" ")
+}
+
+/// Parse e.g. "1h30m" or "1.5s" (without whitespace).
+fn parse_duration(mut text: &str) -> Option<f64> {
+    let mut seconds = 0.0;
+    while !text.is_empty() {
+        let number_len = text
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(text.len());
+        let number = text[..number_len].parse::<f64>().ok()?;
+        text = &text[number_len..];
+
+        let (unit, unit_seconds) = DURATION_UNITS
+            .iter()
+            .find(|(unit, _)| text.starts_with(unit))?;
+        seconds += number * unit_seconds;
+        text = &text[unit.len()..];
+    }
+    Some(seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ValueFormat;
+
+    #[test]
+    fn test_format() {
+        let si = ValueFormat::si("Hz");
+        assert_eq!(si.format(0.0, 0..=6), "0 Hz");
+        assert_eq!(si.format(1500.0, 0..=6), "1.5 kHz");
+        assert_eq!(si.format(-0.02, 0..=6), "-20 mHz");
+        assert_eq!(si.format(999_960.0, 0..=6), "1 MHz", "Rounds up to the next prefix");
+        assert_eq!(ValueFormat::si("").format(12_345.0, 0..=6), "12.3 k");
+
+        let gib = 1024.0 * 1024.0 * 1024.0;
+        assert_eq!(ValueFormat::Bytes.format(512.0, 0..=6), "512 B");
+        assert_eq!(ValueFormat::Bytes.format(1.5 * gib, 0..=6), "1.5 GiB");
+        assert_eq!(ValueFormat::BytesDecimal.format(1.5e9, 0..=6), "1.5 GB");
+
+        assert_eq!(ValueFormat::Duration.format(0.25, 0..=6), "250 ms");
+        assert_eq!(ValueFormat::Duration.format(1.25, 0..=6), "1.25 s");
+        assert_eq!(ValueFormat::Duration.format(9000.0, 0..=6), "2h 30m");
+        assert_eq!(ValueFormat::Duration.format(90_061.0, 0..=6), "1d 1h 1m 1s");
+
+        assert_eq!(ValueFormat::Percent.format(0.25, 0..=6), "25%");
+        assert_eq!(ValueFormat::Percent.format(0.125, 0..=3), "12.5%");
+        assert_eq!(
+            ValueFormat::Degrees.format(std::f64::consts::FRAC_PI_2, 0..=3),
+            "90°"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let si = ValueFormat::si("Hz");
+        assert_eq!(si.parse("1.5 kHz"), Some(1500.0));
+        assert_eq!(si.parse("1.5k"), Some(1500.0));
+        assert_eq!(si.parse("−20 mHz"), Some(-0.02));
+        assert_eq!(si.parse("42"), Some(42.0));
+        assert_eq!(si.parse("1.5 qHz"), None);
+
+        let gib = 1024.0 * 1024.0 * 1024.0;
+        assert_eq!(ValueFormat::Bytes.parse("1.5 GiB"), Some(1.5 * gib));
+        assert_eq!(ValueFormat::Bytes.parse("2 KB"), Some(2000.0));
+        assert_eq!(ValueFormat::BytesDecimal.parse("1.5GB"), Some(1.5e9));
+        assert_eq!(ValueFormat::Bytes.parse("512 B"), Some(512.0));
+
+        assert_eq!(ValueFormat::Duration.parse("2h 30m"), Some(9000.0));
+        assert_eq!(ValueFormat::Duration.parse("1.5h"), Some(5400.0));
+        assert_eq!(ValueFormat::Duration.parse("250ms"), Some(0.25));
+        assert_eq!(ValueFormat::Duration.parse("1d 1h 1m 1s"), Some(90_061.0));
+        assert_eq!(ValueFormat::Duration.parse("2 fortnights"), None);
+
+        assert_eq!(ValueFormat::Percent.parse("25%"), Some(0.25));
+        assert_eq!(ValueFormat::Percent.parse("25"), Some(0.25));
+        let half_turn = ValueFormat::Degrees.parse("180°").unwrap();
+        assert!((half_turn - std::f64::consts::PI).abs() < 1e-12, "{half_turn}");
+        assert_eq!(ValueFormat::Degrees.parse("1 rad"), Some(1.0));
+        assert_eq!(ValueFormat::Radians.parse("1.5"), Some(1.5));
+        assert_eq!(ValueFormat::Radians.format(1.5, 0..=3), "1.5 rad");
+
+        // Round trips:
+        for format in [ValueFormat::Bytes, ValueFormat::Duration, ValueFormat::Percent] {
+            for value in [3.0, 120.0, 4096.0] {
+                let text = format.format(value, 0..=6);
+                assert_eq!(format.parse(&text), Some(value), "{format:?} {text:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,532 @@
+//! An on-screen keyboard for touch screens and kiosk devices.
+//!
+//! See [`VirtualKeyboard`].
+
+use std::sync::Arc;
+
+use crate::{
+    Button, Context, Event, Id, Key, Modifiers, Popup, PopupCloseBehavior, Rect, Response,
+    RichText, Sense, Ui, Vec2, ViewportId, Widget, pos2, vec2,
+};
+
+/// What pressing a [`VirtualKey`] does.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VirtualKeyKind {
+    /// Type a character. Letters are upper-cased while shift is active.
+    Char(char),
+
+    Space,
+
+    Backspace,
+
+    Enter,
+
+    Tab,
+
+    /// Upper-case the next typed letter.
+    Shift,
+
+    /// Switch between the text layout and the numeric layout.
+    ToggleNumeric,
+}
+
+/// A single key on a [`KeyboardLayout`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct VirtualKey {
+    pub kind: VirtualKeyKind,
+
+    /// Characters offered when the key is long-pressed, e.g. accented variants of a letter.
+    pub alternates: Vec<char>,
+
+    /// Width of the key, in units of a regular key width.
+    pub width: f32,
+}
+
+impl VirtualKey {
+    pub fn new(kind: VirtualKeyKind) -> Self {
+        let alternates = match kind {
+            VirtualKeyKind::Char(c) => default_alternates(c).to_vec(),
+            _ => Vec::new(),
+        };
+        Self {
+            kind,
+            alternates,
+            width: 1.0,
+        }
+    }
+
+    /// Set the characters offered when the key is long-pressed.
+    #[inline]
+    pub fn alternates(mut self, alternates: impl Into<Vec<char>>) -> Self {
+        self.alternates = alternates.into();
+        self
+    }
+
+    /// Width of the key, in units of a regular key width.
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    fn label(&self, shift: bool) -> String {
+        match self.kind {
+            VirtualKeyKind::Char(c) => apply_shift(c, shift).to_string(),
+            VirtualKeyKind::Space => "␣".to_owned(),
+            VirtualKeyKind::Backspace => "⌫".to_owned(),
+            VirtualKeyKind::Enter => "⏎".to_owned(),
+            VirtualKeyKind::Tab => "⇥".to_owned(),
+            VirtualKeyKind::Shift => "⇧".to_owned(),
+            VirtualKeyKind::ToggleNumeric => "123".to_owned(),
+        }
+    }
+}
+
+/// The rows of keys shown by a [`VirtualKeyboard`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyboardLayout {
+    pub rows: Vec<Vec<VirtualKey>>,
+}
+
+impl KeyboardLayout {
+    /// Build a layout from rows of characters, e.g. `["qwertyuiop", "asdfghjkl"]`.
+    ///
+    /// Shift and backspace are added around the last row,
+    /// and a bottom row with a numeric toggle, space and enter is appended.
+    pub fn from_char_rows(rows: &[&str]) -> Self {
+        let mut rows: Vec<Vec<VirtualKey>> = rows
+            .iter()
+            .map(|row| {
+                row.chars()
+                    .map(|c| VirtualKey::new(VirtualKeyKind::Char(c)))
+                    .collect()
+            })
+            .collect();
+
+        if let Some(last) = rows.last_mut() {
+            last.insert(0, VirtualKey::new(VirtualKeyKind::Shift).width(1.5));
+            last.push(VirtualKey::new(VirtualKeyKind::Backspace).width(1.5));
+        }
+
+        rows.push(vec![
+            VirtualKey::new(VirtualKeyKind::ToggleNumeric).width(1.5),
+            VirtualKey::new(VirtualKeyKind::Char(',')),
+            VirtualKey::new(VirtualKeyKind::Space).width(5.0),
+            VirtualKey::new(VirtualKeyKind::Char('.')),
+            VirtualKey::new(VirtualKeyKind::Enter).width(1.5),
+        ]);
+
+        Self { rows }
+    }
+
+    /// The US/UK English layout.
+    pub fn qwerty() -> Self {
+        Self::from_char_rows(&["qwertyuiop", "asdfghjkl", "zxcvbnm"])
+    }
+
+    /// The German layout.
+    pub fn qwertz() -> Self {
+        Self::from_char_rows(&["qwertzuiopü", "asdfghjklöä", "yxcvbnm"])
+    }
+
+    /// The French layout.
+    pub fn azerty() -> Self {
+        Self::from_char_rows(&["azertyuiop", "qsdfghjklm", "wxcvbn"])
+    }
+
+    /// Digits and common symbols.
+    pub fn numeric() -> Self {
+        let char_row = |chars: &str| -> Vec<VirtualKey> {
+            chars
+                .chars()
+                .map(|c| VirtualKey::new(VirtualKeyKind::Char(c)))
+                .collect()
+        };
+
+        let mut last = char_row("-/:;()@\"");
+        last.push(VirtualKey::new(VirtualKeyKind::Backspace).width(1.5));
+
+        Self {
+            rows: vec![
+                char_row("1234567890"),
+                char_row("+=*%&#$!?'"),
+                last,
+                vec![
+                    VirtualKey::new(VirtualKeyKind::ToggleNumeric).width(1.5),
+                    VirtualKey::new(VirtualKeyKind::Char(',')),
+                    VirtualKey::new(VirtualKeyKind::Space).width(5.0),
+                    VirtualKey::new(VirtualKeyKind::Char('.')),
+                    VirtualKey::new(VirtualKeyKind::Enter).width(1.5),
+                ],
+            ],
+        }
+    }
+
+    /// Pick a text layout suitable for the given locale, e.g. `"de-DE"` or `"fr"`.
+    ///
+    /// Falls back to [`Self::qwerty`] for unknown locales.
+    pub fn for_locale(locale: &str) -> Self {
+        let language = locale
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match language.as_str() {
+            "de" | "cs" | "hu" | "sk" | "sl" | "hr" => Self::qwertz(),
+            "fr" | "be" => Self::azerty(),
+            _ => Self::qwerty(),
+        }
+    }
+}
+
+impl Default for KeyboardLayout {
+    fn default() -> Self {
+        Self::qwerty()
+    }
+}
+
+/// Accented variants offered on long-press by default.
+fn default_alternates(c: char) -> &'static [char] {
+    match c {
+        'a' => &['à', 'á', 'â', 'ä', 'ã', 'å', 'æ'],
+        'c' => &['ç', 'ć', 'č'],
+        'e' => &['è', 'é', 'ê', 'ë', 'ē'],
+        'i' => &['ì', 'í', 'î', 'ï'],
+        'n' => &['ñ', 'ń'],
+        'o' => &['ò', 'ó', 'ô', 'ö', 'õ', 'ø', 'œ'],
+        's' => &['ß', 'ś', 'š'],
+        'u' => &['ù', 'ú', 'û', 'ü'],
+        'y' => &['ý', 'ÿ'],
+        'z' => &['ź', 'ż', 'ž'],
+        '-' => &['–', '—'],
+        '$' => &['€', '£', '¥'],
+        _ => &[],
+    }
+}
+
+fn apply_shift(c: char, shift: bool) -> char {
+    if shift {
+        c.to_uppercase().next().unwrap_or(c)
+    } else {
+        c
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Clone, Debug, Default)]
+struct KeyboardState {
+    /// The widget we type into.
+    ///
+    /// Pressing a key takes focus away from the widget,
+    /// so we remember it here and give it back focus when the input is delivered.
+    target: Option<Id>,
+    shift: bool,
+    numeric: bool,
+
+    /// The long-pressed key whose alternates are being shown: `(row, column)`.
+    alternates_for: Option<(usize, usize)>,
+}
+
+/// Input produced by a [`VirtualKeyboard`], waiting to be delivered at the start of the next pass.
+#[derive(Clone)]
+struct PendingInput {
+    viewport_id: ViewportId,
+    target: Id,
+    events: Vec<Event>,
+}
+
+#[derive(Clone, Default)]
+struct PendingInputs(Vec<PendingInput>);
+
+/// An on-screen keyboard, for devices without a physical keyboard.
+///
+/// The keyboard types into whichever widget had keyboard focus most recently
+/// (usually a [`crate::TextEdit`]), by injecting [`Event::Text`] and [`Event::Key`] events
+/// at the start of the next pass.
+///
+/// Long-pressing a key shows its alternates (e.g. accented letters).
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut text = String::new();
+/// ui.text_edit_singleline(&mut text);
+/// ui.add(egui::VirtualKeyboard::new().layout(egui::KeyboardLayout::for_locale("de-DE")));
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct VirtualKeyboard {
+    id_salt: Option<Id>,
+    layout: KeyboardLayout,
+    numeric_layout: KeyboardLayout,
+    key_size: Option<Vec2>,
+    long_press_time: f64,
+}
+
+impl Default for VirtualKeyboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VirtualKeyboard {
+    pub fn new() -> Self {
+        Self {
+            id_salt: None,
+            layout: KeyboardLayout::qwerty(),
+            numeric_layout: KeyboardLayout::numeric(),
+            key_size: None,
+            long_press_time: 0.5,
+        }
+    }
+
+    /// Assign a source for the unique id of the keyboard.
+    ///
+    /// This is needed if you show several keyboards in the same [`Ui`].
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
+    /// The layout for text input.
+    ///
+    /// Default: [`KeyboardLayout::qwerty`].
+    /// See also [`KeyboardLayout::for_locale`].
+    #[inline]
+    pub fn layout(mut self, layout: KeyboardLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// The layout shown after pressing the numeric toggle.
+    ///
+    /// Default: [`KeyboardLayout::numeric`].
+    #[inline]
+    pub fn numeric_layout(mut self, numeric_layout: KeyboardLayout) -> Self {
+        self.numeric_layout = numeric_layout;
+        self
+    }
+
+    /// The size of a regular key.
+    ///
+    /// Default: fit ten keys into the available width.
+    #[inline]
+    pub fn key_size(mut self, key_size: Vec2) -> Self {
+        self.key_size = Some(key_size);
+        self
+    }
+
+    /// How long a key must be held down to show its alternates, in seconds.
+    ///
+    /// Default: `0.5`.
+    #[inline]
+    pub fn long_press_time(mut self, long_press_time: f64) -> Self {
+        self.long_press_time = long_press_time;
+        self
+    }
+
+    pub(crate) fn register(ctx: &Context) {
+        ctx.on_begin_pass("virtual_keyboard", Arc::new(Self::begin_pass));
+    }
+
+    /// Deliver the input queued by keyboards during the previous pass.
+    fn begin_pass(ctx: &Context) {
+        let viewport_id = ctx.viewport_id();
+        let pending = ctx.data_mut(|d| {
+            let queue = d.get_temp_mut_or_default::<PendingInputs>(Id::NULL);
+            let (ours, rest) = std::mem::take(&mut queue.0)
+                .into_iter()
+                .partition(|input| input.viewport_id == viewport_id);
+            queue.0 = rest;
+            ours
+        });
+
+        for PendingInput { target, events, .. } in pending {
+            ctx.memory_mut(|mem| mem.request_focus(target));
+            ctx.input_mut(|i| i.events.extend(events));
+        }
+    }
+}
+
+impl Widget for VirtualKeyboard {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            id_salt,
+            layout,
+            numeric_layout,
+            key_size,
+            long_press_time,
+        } = self;
+
+        let id = ui.make_persistent_id(id_salt.unwrap_or_else(|| Id::new("virtual_keyboard")));
+        let mut state: KeyboardState = ui.data_mut(|d| d.get_temp(id)).unwrap_or_default();
+
+        let layout = if state.numeric {
+            &numeric_layout
+        } else {
+            &layout
+        };
+
+        let spacing = ui.spacing().item_spacing;
+        let key_size = key_size.unwrap_or_else(|| {
+            let width = ((ui.available_width() - 9.0 * spacing.x) / 10.0).max(16.0);
+            vec2(width, (1.2 * width).min(2.0 * ui.spacing().interact_size.y))
+        });
+
+        let mut events = Vec::new();
+        let mut key_ids = Vec::new();
+
+        let inner = ui.vertical_centered(|ui| {
+            for (row_idx, row) in layout.rows.iter().enumerate() {
+                let row_width = row.iter().map(|key| key.width * key_size.x).sum::<f32>()
+                    + spacing.x * row.len().saturating_sub(1) as f32;
+                let (row_rect, _) =
+                    ui.allocate_exact_size(vec2(row_width, key_size.y), Sense::hover());
+
+                let mut x = row_rect.left();
+                for (col_idx, key) in row.iter().enumerate() {
+                    let rect = Rect::from_min_size(
+                        pos2(x, row_rect.top()),
+                        vec2(key.width * key_size.x, key_size.y),
+                    );
+                    x = rect.right() + spacing.x;
+
+                    let selected = match key.kind {
+                        VirtualKeyKind::Shift => state.shift,
+                        VirtualKeyKind::ToggleNumeric => state.numeric,
+                        _ => false,
+                    };
+                    let label = key.label(state.shift);
+                    let text = RichText::new(label).size(0.5 * key_size.y);
+                    let response = ui.put(rect, Button::selectable(selected, text));
+                    key_ids.push(response.id);
+
+                    let held_for = ui.input(|i| {
+                        i.pointer
+                            .press_start_time()
+                            .map_or(0.0, |start| i.time - start)
+                    });
+                    if response.is_pointer_button_down_on()
+                        && !key.alternates.is_empty()
+                        && held_for >= long_press_time
+                    {
+                        state.alternates_for = Some((row_idx, col_idx));
+                    } else if response.is_pointer_button_down_on() && !key.alternates.is_empty() {
+                        // Wake up in time to show the alternates:
+                        ui.ctx().request_repaint_after_secs(
+                            (long_press_time - held_for).max(0.0) as f32,
+                        );
+                    }
+
+                    let showing_alternates = state.alternates_for == Some((row_idx, col_idx));
+                    if response.clicked() && !showing_alternates {
+                        press_key(&mut state, key.kind, &mut events);
+                    }
+
+                    if showing_alternates {
+                        let popup = Popup::from_response(&response)
+                            .id(id.with("alternates"))
+                            .open(true)
+                            .close_behavior(PopupCloseBehavior::CloseOnClickOutside)
+                            .show(|ui| {
+                                ui.horizontal(|ui| {
+                                    let mut chosen = None;
+                                    for &alternate in &key.alternates {
+                                        let text = apply_shift(alternate, state.shift).to_string();
+                                        let button = Button::new(
+                                            RichText::new(text).size(0.5 * key_size.y),
+                                        )
+                                        .min_size(key_size);
+                                        if ui.add(button).clicked() {
+                                            chosen = Some(alternate);
+                                        }
+                                    }
+                                    chosen
+                                })
+                                .inner
+                            });
+
+                        let clicked_elsewhere = ui.input(|i| i.pointer.any_pressed())
+                            && !response.contains_pointer()
+                            && popup
+                                .as_ref()
+                                .is_none_or(|popup| !popup.response.contains_pointer());
+
+                        if let Some(alternate) = popup.and_then(|popup| popup.inner) {
+                            press_key(&mut state, VirtualKeyKind::Char(alternate), &mut events);
+                            state.alternates_for = None;
+                        } else if clicked_elsewhere {
+                            state.alternates_for = None;
+                        }
+                    }
+                }
+            }
+        });
+
+        let response = inner.response;
+
+        // Figure out which widget we are typing into:
+        let focused = ui.memory(|mem| mem.focused());
+        if let Some(focused) = focused.filter(|focused| !key_ids.contains(focused)) {
+            state.target = Some(focused);
+        } else if focused.is_none() {
+            let pressed_elsewhere = ui.input(|i| {
+                i.pointer.any_pressed()
+                    && !i
+                        .pointer
+                        .press_origin()
+                        .is_some_and(|pos| response.rect.contains(pos))
+            });
+            if pressed_elsewhere && state.alternates_for.is_none() {
+                state.target = None;
+            }
+        }
+
+        if let (Some(target), false) = (state.target, events.is_empty()) {
+            let viewport_id = ui.ctx().viewport_id();
+            ui.data_mut(|d| {
+                d.get_temp_mut_or_default::<PendingInputs>(Id::NULL)
+                    .0
+                    .push(PendingInput {
+                        viewport_id,
+                        target,
+                        events,
+                    });
+            });
+            ui.ctx().request_repaint();
+        }
+
+        ui.data_mut(|d| d.insert_temp(id, state));
+
+        response
+    }
+}
+
+fn press_key(state: &mut KeyboardState, kind: VirtualKeyKind, events: &mut Vec<Event>) {
+    let key_events = |key: Key| {
+        [true, false].map(|pressed| Event::Key {
+            key,
+            physical_key: None,
+            pressed,
+            repeat: false,
+            modifiers: Modifiers::NONE,
+        })
+    };
+
+    match kind {
+        VirtualKeyKind::Char(c) => {
+            events.push(Event::Text(apply_shift(c, state.shift).to_string()));
+            state.shift = false;
+        }
+        VirtualKeyKind::Space => events.push(Event::Text(" ".to_owned())),
+        VirtualKeyKind::Backspace => events.extend(key_events(Key::Backspace)),
+        VirtualKeyKind::Enter => events.extend(key_events(Key::Enter)),
+        VirtualKeyKind::Tab => events.extend(key_events(Key::Tab)),
+        VirtualKeyKind::Shift => state.shift = !state.shift,
+        VirtualKeyKind::ToggleNumeric => {
+            state.numeric = !state.numeric;
+            state.alternates_for = None;
+        }
+    }
+}
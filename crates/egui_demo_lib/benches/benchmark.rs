@@ -9,7 +9,8 @@ use egui_demo_lib::LOREM_IPSUM_LONG;
 use rand::Rng as _;
 
 #[global_allocator]
-static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc; // Much faster allocator
+static GLOBAL: egui::epaint::util::CountingAllocator<mimalloc::MiMalloc> =
+    egui::epaint::util::CountingAllocator::new(mimalloc::MiMalloc); // Much faster allocator
 
 /// Each iteration should be called in their own `Ui` with an intentional id clash,
 /// to prevent the Context from building a massive map of `WidgetRects` (which would slow the test,
@@ -51,6 +52,38 @@ pub fn criterion_benchmark(c: &mut Criterion) {
         });
     }
 
+    {
+        // Apps with tens of thousands of widgets should not churn the allocator.
+        let ctx = egui::Context::default();
+        let many_buttons = |ctx: &egui::Context| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                for i in 0..10_000 {
+                    ui.push_id(i, |ui| {
+                        _ = ui.button("Hello World");
+                    });
+                }
+            });
+        };
+
+        for _ in 0..3 {
+            // Warm up caches:
+            let full_output = ctx.run(RawInput::default(), many_buttons);
+            ctx.tessellate(full_output.shapes, full_output.pixels_per_point);
+        }
+        let allocations = ctx.allocations_last_pass();
+        println!(
+            "10k buttons: {} allocations ({} bytes) per pass",
+            allocations.num_allocations, allocations.num_bytes_allocated
+        );
+
+        c.bench_function("10k_buttons_with_tessellate", |b| {
+            b.iter(|| {
+                let full_output = ctx.run(RawInput::default(), many_buttons);
+                ctx.tessellate(full_output.shapes, full_output.pixels_per_point)
+            });
+        });
+    }
+
     if false {
         let ctx = egui::Context::default();
         ctx.memory_mut(|m| m.set_everything_is_visible(true)); // give us everything
@@ -0,0 +1,236 @@
+//! Derive macros for [`egui`](https://docs.rs/egui).
+//!
+//! Don't depend on this crate directly; enable the `derive` feature of `egui` instead,
+//! and use `egui::EguiInspect`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Expr, Fields, LitStr, Path, parse_macro_input, spanned::Spanned as _};
+
+/// Implement `egui::EguiInspect` for a struct, showing each field as a row in an `egui::Inspector`.
+///
+/// Each field needs to implement `egui::widgets::inspector::InspectorValue`,
+/// unless it is marked `nested` or `with`.
+///
+/// Field attributes:
+/// * `#[inspect(skip)]`: don't show this field.
+/// * `#[inspect(label = "Max speed")]`: the name to show. Defaults to the field name in sentence case.
+/// * `#[inspect(tooltip = "In meters per second")]`: shown when hovering the name.
+/// * `#[inspect(range = 0.0..=10.0)]`: edit a number with a slider.
+/// * `#[inspect(default = 1.0)]`: show a button to reset the field to this value.
+/// * `#[inspect(nested)]`: show a field that itself implements `EguiInspect` as a collapsible group.
+/// * `#[inspect(with = my_editor)]`: edit the field with `fn my_editor(&mut T, &mut Ui) -> Response`.
+///
+/// Use `#[inspect(crate = eframe::egui)]` on the struct if you access egui through another crate.
+///
+/// ```ignore
+/// #[derive(egui::EguiInspect)]
+/// struct Player {
+///     name: String,
+///
+///     #[inspect(range = 0.0..=10.0, default = 1.0, tooltip = "In meters per second")]
+///     max_speed: f32,
+///
+///     #[inspect(skip)]
+///     id: u64,
+/// }
+/// ```
+#[proc_macro_derive(EguiInspect, attributes(inspect))]
+pub fn derive_egui_inspect(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_egui_inspect_impl(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn derive_egui_inspect_impl(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let krate = crate_path(input)?;
+
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "EguiInspect can only be derived for structs",
+        ));
+    };
+
+    let rows = match &data.fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|field| {
+                #[expect(clippy::unwrap_used)] // Named fields have names
+                let ident = field.ident.as_ref().unwrap();
+                let member = quote!(#ident);
+                let name = ident.to_string();
+                field_row(&krate, field, member, label_from_field_name(&name))
+            })
+            .collect::<syn::Result<Vec<_>>>()?,
+        Fields::Unnamed(fields) => fields
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let member = syn::Index::from(index);
+                field_row(&krate, field, quote!(#member), index.to_string())
+            })
+            .collect::<syn::Result<Vec<_>>>()?,
+        Fields::Unit => Vec::new(),
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #krate::EguiInspect for #name #ty_generics #where_clause {
+            fn inspect(&mut self, inspector: &mut #krate::widgets::inspector::InspectorUi<'_>) {
+                #(#rows)*
+            }
+        }
+    })
+}
+
+/// The path to `egui`, from `#[inspect(crate = …)]` on the struct.
+fn crate_path(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let mut krate = None;
+    for attr in &input.attrs {
+        if attr.path().is_ident("inspect") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("crate") {
+                    krate = Some(meta.value()?.parse::<Path>()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown struct attribute, expected `crate`"))
+                }
+            })?;
+        }
+    }
+    Ok(krate.map_or_else(|| quote!(::egui), |krate| quote!(#krate)))
+}
+
+#[derive(Default)]
+struct FieldAttributes {
+    skip: bool,
+    label: Option<LitStr>,
+    tooltip: Option<LitStr>,
+    range: Option<Expr>,
+    default: Option<Expr>,
+    nested: bool,
+    with: Option<Path>,
+}
+
+impl FieldAttributes {
+    fn parse(field: &syn::Field) -> syn::Result<Self> {
+        let mut attributes = Self::default();
+        for attr in &field.attrs {
+            if !attr.path().is_ident("inspect") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    attributes.skip = true;
+                } else if meta.path.is_ident("nested") {
+                    attributes.nested = true;
+                } else if meta.path.is_ident("label") {
+                    attributes.label = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("tooltip") {
+                    attributes.tooltip = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("range") {
+                    attributes.range = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("default") {
+                    attributes.default = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("with") {
+                    attributes.with = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error(
+                        "unknown field attribute, expected one of \
+                         `skip`, `label`, `tooltip`, `range`, `default`, `nested`, `with`",
+                    ));
+                }
+                Ok(())
+            })?;
+        }
+
+        if attributes.nested && attributes.with.is_some() {
+            return Err(syn::Error::new(
+                field.span(),
+                "`nested` and `with` can't be used together",
+            ));
+        }
+
+        Ok(attributes)
+    }
+}
+
+fn field_row(
+    krate: &TokenStream,
+    field: &syn::Field,
+    member: TokenStream,
+    default_label: String,
+) -> syn::Result<TokenStream> {
+    let FieldAttributes {
+        skip,
+        label,
+        tooltip,
+        range,
+        default,
+        nested,
+        with,
+    } = FieldAttributes::parse(field)?;
+
+    if skip {
+        return Ok(TokenStream::new());
+    }
+
+    let label = label.unwrap_or_else(|| LitStr::new(&default_label, field.span()));
+
+    if nested {
+        return Ok(quote! {
+            inspector.group(#label, |inspector| {
+                #krate::EguiInspect::inspect(&mut self.#member, inspector);
+            });
+        });
+    }
+
+    if let Some(with) = with {
+        let custom = quote!(inspector.custom(#label, |ui| #with(&mut self.#member, ui)));
+        return Ok(if let Some(tooltip) = tooltip {
+            quote! {
+                if let Some(response) = #custom {
+                    response.on_hover_text(#tooltip);
+                }
+            }
+        } else {
+            quote!(#custom;)
+        });
+    }
+
+    let range = range.map(|range| {
+        quote! {
+            .range({
+                let range = #range;
+                (*range.start() as f64)..=(*range.end() as f64)
+            })
+        }
+    });
+    let default = default.map(|default| quote!(.default(#default)));
+    let hover_text = tooltip.map(|tooltip| quote!(.hover_text(#tooltip)));
+
+    Ok(quote! {
+        inspector.add(
+            #krate::widgets::inspector::Property::new(#label, &mut self.#member)
+                #range
+                #default
+                #hover_text
+        );
+    })
+}
+
+/// `max_speed` -> `Max speed`
+fn label_from_field_name(name: &str) -> String {
+    let name = name.trim_start_matches("r#").replace('_', " ");
+    let mut chars = name.trim().chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
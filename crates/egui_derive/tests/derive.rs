@@ -0,0 +1,74 @@
+use egui::{Context, EguiInspect, RawInput, Response, Ui};
+
+#[derive(Clone, PartialEq, EguiInspect)]
+struct Transform {
+    #[inspect(range = -100.0..=100.0)]
+    x: f32,
+
+    #[inspect(default = 1.0, tooltip = "Uniform scale")]
+    scale: f32,
+}
+
+#[derive(Clone, PartialEq, EguiInspect)]
+struct Player {
+    #[inspect(label = "Player name")]
+    name: String,
+
+    visible: bool,
+
+    #[inspect(nested)]
+    transform: Transform,
+
+    #[inspect(with = level_ui)]
+    level: u8,
+
+    #[inspect(skip)]
+    id: u64,
+}
+
+#[derive(EguiInspect)]
+struct Tuple(f32, #[inspect(skip)] String);
+
+fn level_ui(level: &mut u8, ui: &mut Ui) -> Response {
+    ui.add(egui::Slider::new(level, 1..=10))
+}
+
+fn player() -> Player {
+    Player {
+        name: "Ferris".to_owned(),
+        visible: true,
+        transform: Transform { x: 0.0, scale: 1.0 },
+        level: 1,
+        id: 42,
+    }
+}
+
+#[test]
+fn derived_inspector_shows_all_fields() {
+    let ctx = Context::default();
+    let mut player = player();
+    let mut tuple = Tuple(1.0, String::new());
+
+    let _ = ctx.run(RawInput::default(), |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let response = player.ui(ui);
+            assert!(!response.changed());
+            tuple.ui(ui);
+        });
+    });
+    assert_eq!(player.id, 42);
+}
+
+#[test]
+fn derived_inspector_with_undo() {
+    let ctx = Context::default();
+    let mut player = player();
+    let mut undoer = egui::util::undoer::Undoer::default();
+
+    let _ = ctx.run(RawInput::default(), |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            player.ui_with_undo(ui, &mut undoer);
+        });
+    });
+    assert!(!undoer.has_undo(&player));
+}
@@ -0,0 +1,504 @@
+//! Widgets for audio tools: level meters and waveform displays.
+
+use std::ops::Range;
+
+use egui::{
+    Color32, Id, Mesh, Pos2, Rect, Response, Sense, Shape, Stroke, StrokeKind, Ui, Vec2, Widget,
+    WidgetInfo, WidgetType, emath::remap_clamp, pos2, vec2,
+};
+
+/// Convert a linear amplitude to decibels.
+fn amplitude_to_db(amplitude: f32) -> f32 {
+    20.0 * amplitude.max(1e-10).log10()
+}
+
+/// The state of the meter ballistics, stored in [`egui::Memory`].
+#[derive(Clone, Copy, Debug)]
+struct MeterState {
+    peak_db: f32,
+    rms_db: f32,
+    hold_db: f32,
+    hold_time: f64,
+    clipped: bool,
+}
+
+/// A peak/RMS level meter, as found on mixing consoles.
+///
+/// The peak level rises instantly and falls slowly ("ballistics"), and the highest peak
+/// is held for a while. Once the signal clips (reaches 1.0), the clip indicator lights up
+/// until the meter is clicked.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let samples = [0.0_f32; 512];
+/// ui.add(egui_extras::LevelMeter::from_samples(&samples).id_salt("left"));
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct LevelMeter {
+    peak: f32,
+    rms: f32,
+    id_salt: Option<Id>,
+    vertical: bool,
+    desired_size: Option<Vec2>,
+    min_db: f32,
+    decay_db_per_second: f32,
+    peak_hold_seconds: f32,
+}
+
+impl LevelMeter {
+    /// Show the given peak and RMS levels, as linear amplitudes (where 1.0 is full scale).
+    pub fn new(peak: f32, rms: f32) -> Self {
+        Self {
+            peak: peak.abs(),
+            rms: rms.abs(),
+            id_salt: None,
+            vertical: true,
+            desired_size: None,
+            min_db: -60.0,
+            decay_db_per_second: 20.0,
+            peak_hold_seconds: 1.5,
+        }
+    }
+
+    /// Measure the peak and RMS level of a block of samples.
+    pub fn from_samples(samples: &[f32]) -> Self {
+        let peak = samples.iter().fold(0.0_f32, |peak, s| peak.max(s.abs()));
+        let rms = if samples.is_empty() {
+            0.0
+        } else {
+            (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+        };
+        Self::new(peak, rms)
+    }
+
+    /// Needed if you show several meters in the same [`Ui`].
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
+    /// Fill upwards (`true`) or to the right (`false`).
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn vertical(mut self, vertical: bool) -> Self {
+        self.vertical = vertical;
+        self
+    }
+
+    #[inline]
+    pub fn desired_size(mut self, desired_size: Vec2) -> Self {
+        self.desired_size = Some(desired_size);
+        self
+    }
+
+    /// The level shown as an empty meter, in dB.
+    ///
+    /// Default: `-60.0`.
+    #[inline]
+    pub fn min_db(mut self, min_db: f32) -> Self {
+        self.min_db = min_db.min(-1.0);
+        self
+    }
+
+    /// How fast the meter falls after a peak.
+    ///
+    /// Default: `20.0` dB per second.
+    #[inline]
+    pub fn decay(mut self, db_per_second: f32) -> Self {
+        self.decay_db_per_second = db_per_second.max(0.0);
+        self
+    }
+
+    /// How long the highest peak stays marked.
+    ///
+    /// Default: `1.5` seconds.
+    #[inline]
+    pub fn peak_hold(mut self, seconds: f32) -> Self {
+        self.peak_hold_seconds = seconds.max(0.0);
+        self
+    }
+}
+
+impl Widget for LevelMeter {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            peak,
+            rms,
+            id_salt,
+            vertical,
+            desired_size,
+            min_db,
+            decay_db_per_second,
+            peak_hold_seconds,
+        } = self;
+
+        let thickness = ui.spacing().interact_size.y * 0.5;
+        let length = ui.spacing().slider_width;
+        let desired_size = desired_size.unwrap_or(if vertical {
+            vec2(thickness, length)
+        } else {
+            vec2(length, thickness)
+        });
+        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::click());
+        let id = id_salt.map_or(response.id, |salt| ui.make_persistent_id(salt));
+
+        let (time, dt) = ui.input(|i| (i.time, i.stable_dt.min(0.1)));
+        let peak_db = amplitude_to_db(peak).max(min_db);
+        let rms_db = amplitude_to_db(rms).max(min_db);
+
+        let mut state = ui
+            .data_mut(|d| d.get_temp::<MeterState>(id))
+            .unwrap_or(MeterState {
+                peak_db,
+                rms_db,
+                hold_db: peak_db,
+                hold_time: time,
+                clipped: false,
+            });
+
+        let falloff = decay_db_per_second * dt;
+        state.peak_db = peak_db.max(state.peak_db - falloff);
+        state.rms_db = rms_db.max(state.rms_db - falloff);
+        if peak_db >= state.hold_db || time - state.hold_time > f64::from(peak_hold_seconds) {
+            state.hold_db = state.peak_db;
+            state.hold_time = time;
+        }
+        if peak >= 1.0 {
+            state.clipped = true;
+        }
+        if response.clicked() {
+            state.clipped = false;
+        }
+        ui.data_mut(|d| d.insert_temp(id, state));
+
+        if state.peak_db > peak_db || state.rms_db > rms_db {
+            ui.ctx().request_repaint(); // still falling
+        }
+
+        response.widget_info(|| {
+            WidgetInfo::labeled(
+                WidgetType::ProgressIndicator,
+                ui.is_enabled(),
+                format!("Level {:.1} dB", state.peak_db),
+            )
+        });
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.visuals();
+            let painter = ui.painter_at(rect);
+            painter.rect_filled(rect, 0.0, visuals.extreme_bg_color);
+
+            // The part of the meter from `from` to `to` (normalized, 0 = empty, 1 = full):
+            let segment = |from: f32, to: f32| {
+                if vertical {
+                    Rect::from_x_y_ranges(
+                        rect.x_range(),
+                        rect.bottom() - to * rect.height()..=rect.bottom() - from * rect.height(),
+                    )
+                } else {
+                    Rect::from_x_y_ranges(
+                        rect.left() + from * rect.width()..=rect.left() + to * rect.width(),
+                        rect.y_range(),
+                    )
+                }
+            };
+            let normalized = |db: f32| remap_clamp(db, min_db..=0.0, 0.0..=1.0);
+
+            // Color zones. The peak is dimmed, the RMS level is solid:
+            let zones = [
+                (min_db, -12.0, Color32::from_rgb(60, 200, 80)),
+                (-12.0, -3.0, Color32::from_rgb(230, 200, 50)),
+                (-3.0, 0.0, Color32::from_rgb(230, 60, 50)),
+            ];
+            for (zone_min, zone_max, color) in zones {
+                let from = normalized(zone_min);
+                let peak_to = normalized(state.peak_db.min(zone_max));
+                let rms_to = normalized(state.rms_db.min(zone_max));
+                if peak_to > from {
+                    painter.rect_filled(segment(from, peak_to), 0.0, color.gamma_multiply(0.5));
+                }
+                if rms_to > from {
+                    painter.rect_filled(segment(from, rms_to), 0.0, color);
+                }
+            }
+
+            // Peak hold marker:
+            let hold = normalized(state.hold_db);
+            if hold > 0.0 {
+                let hold_line = if vertical {
+                    let y = rect.bottom() - hold * rect.height();
+                    [pos2(rect.left(), y), pos2(rect.right(), y)]
+                } else {
+                    let x = rect.left() + hold * rect.width();
+                    [pos2(x, rect.top()), pos2(x, rect.bottom())]
+                };
+                painter.line_segment(hold_line, Stroke::new(2.0, visuals.strong_text_color()));
+            }
+
+            // Clip indicator at the top (or right) end:
+            let clip_size = thickness.min(if vertical {
+                rect.height()
+            } else {
+                rect.width()
+            }) * 0.5;
+            let clip_rect = if vertical {
+                Rect::from_min_size(rect.min, vec2(rect.width(), clip_size))
+            } else {
+                Rect::from_min_size(
+                    pos2(rect.right() - clip_size, rect.top()),
+                    vec2(clip_size, rect.height()),
+                )
+            };
+            let clip_color = if state.clipped {
+                Color32::RED
+            } else {
+                visuals.faint_bg_color
+            };
+            painter.rect_filled(clip_rect, 0.0, clip_color);
+
+            painter.rect_stroke(
+                rect,
+                0.0,
+                visuals.widgets.noninteractive.bg_stroke,
+                StrokeKind::Inside,
+            );
+        }
+
+        if state.clipped {
+            response.on_hover_text("Clipped! Click to reset.")
+        } else {
+            response
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The zoom and selection of a [`Waveform`], stored in [`egui::Memory`].
+#[derive(Clone, Debug, Default)]
+struct WaveformState {
+    /// Visible range of samples. Empty means everything.
+    visible: Range<usize>,
+    selection: Option<Range<usize>>,
+    drag_anchor: Option<usize>,
+}
+
+/// What happened in a [`Waveform`] this frame.
+pub struct WaveformResponse {
+    pub response: Response,
+
+    /// The samples currently in view.
+    pub visible: Range<usize>,
+
+    /// The samples selected by dragging, if any.
+    pub selection: Option<Range<usize>>,
+}
+
+/// Shows a (possibly very long) buffer of audio samples.
+///
+/// Each pixel column shows the minimum and maximum sample it covers, so peaks are never lost
+/// no matter how far out you zoom.
+///
+/// Zoom with Ctrl + scroll (or pinch), pan by scrolling, select by dragging,
+/// and double-click to show everything again.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let samples: Vec<f32> = (0..48_000).map(|i| (i as f32 * 0.05).sin()).collect();
+/// let waveform = egui_extras::Waveform::new(&samples).show(ui);
+/// if let Some(selection) = waveform.selection {
+///     ui.label(format!("Selected {} samples", selection.len()));
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Waveform<'a> {
+    samples: &'a [f32],
+    id_salt: Option<Id>,
+    desired_size: Option<Vec2>,
+    color: Option<Color32>,
+}
+
+impl<'a> Waveform<'a> {
+    /// Samples are expected to be in the range `-1.0..=1.0`.
+    pub fn new(samples: &'a [f32]) -> Self {
+        Self {
+            samples,
+            id_salt: None,
+            desired_size: None,
+            color: None,
+        }
+    }
+
+    /// Needed if you show several waveforms in the same [`Ui`].
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
+    /// Default: all available width, and a height of four interact sizes.
+    #[inline]
+    pub fn desired_size(mut self, desired_size: Vec2) -> Self {
+        self.desired_size = Some(desired_size);
+        self
+    }
+
+    /// Default: the selection stroke color.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    pub fn show(self, ui: &mut Ui) -> WaveformResponse {
+        let Self {
+            samples,
+            id_salt,
+            desired_size,
+            color,
+        } = self;
+
+        let desired_size = desired_size
+            .unwrap_or_else(|| vec2(ui.available_width(), 4.0 * ui.spacing().interact_size.y));
+        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::click_and_drag());
+        let id = id_salt.map_or(response.id, |salt| ui.make_persistent_id(salt));
+
+        let mut state: WaveformState = ui.data_mut(|d| d.get_temp(id)).unwrap_or_default();
+        let len = samples.len();
+        if state.visible.is_empty() || state.visible.end > len {
+            state.visible = 0..len;
+        }
+
+        let sample_at = |x: f32, visible: &Range<usize>| {
+            let t = remap_clamp(x, rect.x_range(), 0.0..=1.0);
+            visible.start + (t * visible.len() as f32).round() as usize
+        };
+
+        // Zoom and pan:
+        let (zoom, scroll) = ui.input(|i| (i.zoom_delta(), i.smooth_scroll_delta));
+        if response.hovered() && len > 0 && (zoom != 1.0 || scroll != Vec2::ZERO) {
+            let pointer_x = response.hover_pos().map_or(rect.center().x, |p| p.x);
+            let visible = state.visible.clone();
+            let mut new_len = visible.len() as f32;
+            if zoom != 1.0 {
+                new_len = (new_len / zoom).clamp(rect.width().min(len as f32).max(1.0), len as f32);
+            }
+            let anchor = sample_at(pointer_x, &visible) as f32;
+            let anchor_t = remap_clamp(pointer_x, rect.x_range(), 0.0..=1.0);
+            let pan = -(scroll.x + scroll.y) / rect.width() * new_len;
+            let start = (anchor - anchor_t * new_len + pan).clamp(0.0, len as f32 - new_len);
+            let new_visible = start.round() as usize..(start + new_len).round() as usize;
+            if new_visible != visible && !new_visible.is_empty() {
+                state.visible = new_visible.start..new_visible.end.min(len);
+            }
+        }
+        if response.double_clicked() {
+            state.visible = 0..len;
+            state.selection = None;
+        }
+
+        // Selection:
+        if response.drag_started() {
+            state.drag_anchor = ui
+                .input(|i| i.pointer.press_origin())
+                .map(|pos| sample_at(pos.x, &state.visible));
+        }
+        if let (true, Some(anchor), Some(pos)) = (
+            response.dragged(),
+            state.drag_anchor,
+            response.interact_pointer_pos(),
+        ) {
+            let current = sample_at(pos.x, &state.visible);
+            state.selection = Some(anchor.min(current)..anchor.max(current));
+        }
+        if response.clicked() {
+            state.selection = None;
+        }
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.visuals();
+            let color = color.unwrap_or(visuals.selection.stroke.color);
+            let painter = ui.painter_at(rect);
+            painter.rect_filled(rect, 0.0, visuals.extreme_bg_color);
+
+            if let Some(selection) = &state.selection {
+                let x_of = |sample: usize| {
+                    remap_clamp(
+                        sample as f32,
+                        state.visible.start as f32..=state.visible.end as f32,
+                        rect.x_range(),
+                    )
+                };
+                let selection_rect = Rect::from_x_y_ranges(
+                    x_of(selection.start)..=x_of(selection.end).max(x_of(selection.start) + 1.0),
+                    rect.y_range(),
+                );
+                painter.rect_filled(
+                    selection_rect,
+                    0.0,
+                    visuals.selection.bg_fill.gamma_multiply(0.5),
+                );
+            }
+
+            painter.hline(
+                rect.x_range(),
+                rect.center().y,
+                Stroke::new(1.0, visuals.weak_text_color()),
+            );
+            painter.add(Shape::mesh(waveform_mesh(
+                &samples[state.visible.clone()],
+                rect,
+                ui.pixels_per_point(),
+                color,
+            )));
+        }
+
+        let visible = state.visible.clone();
+        let selection = state.selection.clone();
+        ui.data_mut(|d| d.insert_temp(id, state));
+
+        WaveformResponse {
+            response,
+            visible,
+            selection,
+        }
+    }
+}
+
+/// One rectangle per physical pixel column, spanning the min and max sample of that column.
+fn waveform_mesh(samples: &[f32], rect: Rect, pixels_per_point: f32, color: Color32) -> Mesh {
+    let mut mesh = Mesh::default();
+    if samples.is_empty() {
+        return mesh;
+    }
+
+    let columns = ((rect.width() * pixels_per_point).round() as usize).max(1);
+    let column_width = rect.width() / columns as f32;
+    let y_of = |sample: f32| rect.center().y - sample.clamp(-1.0, 1.0) * 0.5 * rect.height();
+    let min_height = 1.0 / pixels_per_point;
+
+    for column in 0..columns {
+        let from = column * samples.len() / columns;
+        let to = ((column + 1) * samples.len() / columns)
+            .max(from + 1)
+            .min(samples.len());
+        let (min, max) = samples[from..to]
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), &s| {
+                (min.min(s), max.max(s))
+            });
+
+        let x = rect.left() + column as f32 * column_width;
+        let top = y_of(max);
+        let bottom = y_of(min).max(top + min_height);
+        mesh.add_colored_rect(
+            Rect::from_min_max(Pos2::new(x, top), pos2(x + column_width, bottom)),
+            color,
+        );
+    }
+    mesh
+}
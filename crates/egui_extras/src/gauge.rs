@@ -0,0 +1,525 @@
+//! Circular gauges and radial progress indicators.
+
+use std::ops::RangeInclusive;
+
+use egui::{
+    Align2, Color32, Id, Mesh, NumExt as _, Pos2, Response, Sense, Shape, Stroke, TextStyle, Ui,
+    Vec2, Widget, WidgetInfo, WidgetText, WidgetType, emath::remap_clamp, epaint::PathStroke, vec2,
+};
+
+/// A point on a circle. Angles are in radians, clockwise from the positive x axis.
+fn point_on_circle(center: Pos2, radius: f32, angle: f32) -> Pos2 {
+    center + radius * Vec2::angled(angle)
+}
+
+/// Enough points along an arc for it to look smooth.
+fn arc_points(center: Pos2, radius: f32, start_angle: f32, end_angle: f32) -> Vec<Pos2> {
+    let segments = ((end_angle - start_angle).abs() * radius.sqrt() * 2.0)
+        .ceil()
+        .max(1.0) as usize;
+    (0..=segments)
+        .map(|i| {
+            let t = i as f32 / segments as f32;
+            point_on_circle(center, radius, egui::lerp(start_angle..=end_angle, t))
+        })
+        .collect()
+}
+
+/// A filled band between two radii, e.g. a thick arc or a colored zone.
+fn arc_band(
+    mesh: &mut Mesh,
+    center: Pos2,
+    radii: RangeInclusive<f32>,
+    start_angle: f32,
+    end_angle: f32,
+    color: Color32,
+) {
+    let outer = arc_points(center, *radii.end(), start_angle, end_angle);
+    let inner = arc_points(center, *radii.start(), start_angle, end_angle);
+    let first = mesh.vertices.len() as u32;
+    for (o, i) in outer.iter().zip(&inner) {
+        mesh.colored_vertex(*o, color);
+        mesh.colored_vertex(*i, color);
+    }
+    for segment in 0..outer.len().saturating_sub(1) as u32 {
+        let idx = first + 2 * segment;
+        mesh.add_triangle(idx, idx + 1, idx + 2);
+        mesh.add_triangle(idx + 1, idx + 3, idx + 2);
+    }
+}
+
+/// How a [`Gauge`] shows its value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GaugeStyle {
+    /// A needle pointing at the value, like an analog instrument.
+    #[default]
+    Needle,
+
+    /// The arc is filled up to the value.
+    FilledArc,
+}
+
+/// A circular gauge for dashboards and monitoring.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let cpu_temperature = 64.0;
+/// ui.add(
+///     egui_extras::Gauge::new(cpu_temperature, 0.0..=100.0)
+///         .text("CPU")
+///         .suffix(" °C")
+///         .zone(70.0..=85.0, egui::Color32::YELLOW)
+///         .zone(85.0..=100.0, egui::Color32::RED),
+/// );
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct Gauge {
+    value: f32,
+    range: RangeInclusive<f32>,
+    id_salt: Option<Id>,
+    style: GaugeStyle,
+    start_angle: f32,
+    sweep: f32,
+    diameter: Option<f32>,
+    major_ticks: usize,
+    minor_ticks: usize,
+    zones: Vec<(RangeInclusive<f32>, Color32)>,
+    text: Option<WidgetText>,
+    suffix: String,
+    show_value: bool,
+    animated: bool,
+}
+
+impl Gauge {
+    pub fn new(value: f32, range: RangeInclusive<f32>) -> Self {
+        Self {
+            value,
+            range,
+            id_salt: None,
+            style: GaugeStyle::default(),
+            start_angle: 135_f32.to_radians(),
+            sweep: 270_f32.to_radians(),
+            diameter: None,
+            major_ticks: 5,
+            minor_ticks: 4,
+            zones: Vec::new(),
+            text: None,
+            suffix: String::new(),
+            show_value: true,
+            animated: true,
+        }
+    }
+
+    /// Needed if you show several animated gauges in the same [`Ui`].
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
+    #[inline]
+    pub fn style(mut self, style: GaugeStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Where the arc starts, and how far it goes, in radians.
+    ///
+    /// Angles are measured clockwise from "3 o'clock".
+    /// Default: starting at the bottom left (135°) and sweeping 270°.
+    #[inline]
+    pub fn arc(mut self, start_angle: f32, sweep: f32) -> Self {
+        self.start_angle = start_angle;
+        self.sweep = sweep;
+        self
+    }
+
+    /// Default: four interact heights.
+    #[inline]
+    pub fn diameter(mut self, diameter: f32) -> Self {
+        self.diameter = Some(diameter);
+        self
+    }
+
+    /// The number of labeled intervals, and unlabeled ticks within each of them.
+    ///
+    /// Default: `5` major intervals with `4` minor ticks each.
+    /// Use `0` major intervals to hide the ticks.
+    #[inline]
+    pub fn ticks(mut self, major: usize, minor: usize) -> Self {
+        self.major_ticks = major;
+        self.minor_ticks = minor;
+        self
+    }
+
+    /// Color part of the scale, e.g. a warning range. Can be called several times.
+    ///
+    /// With [`GaugeStyle::FilledArc`], the fill takes the color of the zone the value is in.
+    #[inline]
+    pub fn zone(mut self, range: RangeInclusive<f32>, color: impl Into<Color32>) -> Self {
+        self.zones.push((range, color.into()));
+        self
+    }
+
+    /// A label shown below the value.
+    #[inline]
+    pub fn text(mut self, text: impl Into<WidgetText>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Add a suffix to the value, e.g. a unit.
+    #[inline]
+    pub fn suffix(mut self, suffix: impl ToString) -> Self {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    /// Show the value as text in the middle?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn show_value(mut self, show_value: bool) -> Self {
+        self.show_value = show_value;
+        self
+    }
+
+    /// Animate value changes?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+}
+
+impl Widget for Gauge {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            value,
+            range,
+            id_salt,
+            style,
+            start_angle,
+            sweep,
+            diameter,
+            major_ticks,
+            minor_ticks,
+            zones,
+            text,
+            suffix,
+            show_value,
+            animated,
+        } = self;
+
+        let diameter = diameter.unwrap_or(4.0 * ui.spacing().interact_size.y);
+        let (rect, response) = ui.allocate_exact_size(Vec2::splat(diameter), Sense::hover());
+        let id = id_salt.map_or(response.id, |salt| ui.make_persistent_id(salt));
+
+        let shown_value = if animated {
+            ui.ctx()
+                .animate_value_with_time(id, value, ui.style().animation_time)
+        } else {
+            value
+        };
+
+        response.widget_info(|| {
+            WidgetInfo::labeled(
+                WidgetType::ProgressIndicator,
+                ui.is_enabled(),
+                format!(
+                    "{}{value}{suffix}",
+                    text.as_ref()
+                        .map_or(String::new(), |t| format!("{}: ", t.text()))
+                ),
+            )
+        });
+
+        if !ui.is_rect_visible(rect) {
+            return response;
+        }
+
+        let visuals = ui.visuals();
+        let painter = ui.painter();
+        let center = rect.center();
+        let radius = 0.5 * diameter;
+        let band_width = (0.1 * diameter).max(2.0);
+        let band = radius - band_width..=radius;
+        let angle_of = |v: f32| start_angle + sweep * remap_clamp(v, range.clone(), 0.0..=1.0);
+
+        // The scale and its colored zones:
+        let mut mesh = Mesh::default();
+        arc_band(
+            &mut mesh,
+            center,
+            band.clone(),
+            start_angle,
+            start_angle + sweep,
+            visuals.extreme_bg_color,
+        );
+        for (zone, color) in &zones {
+            let zone_band = if style == GaugeStyle::Needle {
+                band.clone()
+            } else {
+                radius - 0.3 * band_width..=radius
+            };
+            arc_band(
+                &mut mesh,
+                center,
+                zone_band,
+                angle_of(*zone.start()),
+                angle_of(*zone.end()),
+                *color,
+            );
+        }
+        if style == GaugeStyle::FilledArc {
+            let fill = zones
+                .iter()
+                .rev()
+                .find(|(zone, _)| zone.contains(&shown_value))
+                .map_or(visuals.selection.bg_fill, |(_, color)| *color);
+            arc_band(
+                &mut mesh,
+                center,
+                radius - band_width..=radius - 0.3 * band_width,
+                start_angle,
+                angle_of(shown_value),
+                fill,
+            );
+        }
+        painter.add(Shape::mesh(mesh));
+
+        // Ticks and labels:
+        if major_ticks > 0 {
+            let tick_stroke = Stroke::new(1.0, visuals.text_color());
+            let font_id = TextStyle::Small.resolve(ui.style());
+            let num_ticks = major_ticks * (minor_ticks + 1);
+            for i in 0..=num_ticks {
+                let t = i as f32 / num_ticks as f32;
+                let angle = start_angle + t * sweep;
+                let is_major = i % (minor_ticks + 1) == 0;
+                let inner = radius - band_width * if is_major { 1.6 } else { 1.3 };
+                painter.line_segment(
+                    [
+                        point_on_circle(center, inner, angle),
+                        point_on_circle(center, radius - band_width, angle),
+                    ],
+                    tick_stroke,
+                );
+                if is_major && diameter >= 80.0 {
+                    let tick_value = egui::lerp(range.clone(), t);
+                    painter.text(
+                        point_on_circle(center, inner - 0.6 * font_id.size, angle),
+                        Align2::CENTER_CENTER,
+                        egui::emath::format_with_decimals_in_range(f64::from(tick_value), 0..=1),
+                        font_id.clone(),
+                        visuals.weak_text_color(),
+                    );
+                }
+            }
+        }
+
+        // Needle:
+        if style == GaugeStyle::Needle {
+            let angle = angle_of(shown_value);
+            let direction = Vec2::angled(angle);
+            let side = direction.rot90() * (0.03 * diameter).max(1.5);
+            let tip = center + direction * (radius - 0.5 * band_width);
+            let needle_color = visuals.strong_text_color();
+            painter.add(Shape::convex_polygon(
+                vec![center + side, tip, center - side],
+                needle_color,
+                PathStroke::NONE,
+            ));
+            painter.circle_filled(center, (0.06 * diameter).max(2.0), needle_color);
+        }
+
+        // Value and label:
+        let text_pos = center + vec2(0.0, 0.35 * radius);
+        if show_value {
+            painter.text(
+                text_pos,
+                Align2::CENTER_CENTER,
+                format!(
+                    "{}{suffix}",
+                    egui::emath::format_with_decimals_in_range(f64::from(value), 0..=2)
+                ),
+                TextStyle::Body.resolve(ui.style()),
+                visuals.strong_text_color(),
+            );
+        }
+        if let Some(text) = text {
+            let galley = text.into_galley(
+                ui,
+                Some(egui::TextWrapMode::Extend),
+                diameter,
+                TextStyle::Small,
+            );
+            let pos = text_pos
+                + vec2(
+                    -0.5 * galley.size().x,
+                    0.4 * ui.text_style_height(&TextStyle::Body),
+                );
+            painter.galley(pos, galley, visuals.text_color());
+        }
+
+        response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A circular progress indicator, i.e. a round version of [`egui::ProgressBar`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// ui.add(egui_extras::RadialProgress::new(0.75).show_percentage());
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct RadialProgress {
+    progress: f32,
+    diameter: Option<f32>,
+    thickness: Option<f32>,
+    color: Option<Color32>,
+    text: Option<WidgetText>,
+    show_percentage: bool,
+    animated: bool,
+}
+
+impl RadialProgress {
+    /// Progress in the range `[0, 1]`.
+    pub fn new(progress: f32) -> Self {
+        Self {
+            progress: progress.clamp(0.0, 1.0),
+            diameter: None,
+            thickness: None,
+            color: None,
+            text: None,
+            show_percentage: false,
+            animated: true,
+        }
+    }
+
+    /// Default: two interact heights.
+    #[inline]
+    pub fn diameter(mut self, diameter: f32) -> Self {
+        self.diameter = Some(diameter);
+        self
+    }
+
+    /// The width of the ring. Default: a tenth of the diameter.
+    #[inline]
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = Some(thickness);
+        self
+    }
+
+    /// The color of the filled part. Default: the selection color.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// A text to show in the middle.
+    #[inline]
+    pub fn text(mut self, text: impl Into<WidgetText>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Show the progress as a percentage in the middle.
+    #[inline]
+    pub fn show_percentage(mut self) -> Self {
+        self.show_percentage = true;
+        self
+    }
+
+    /// Animate progress changes?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+}
+
+impl Widget for RadialProgress {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            progress,
+            diameter,
+            thickness,
+            color,
+            text,
+            show_percentage,
+            animated,
+        } = self;
+
+        let diameter = diameter.unwrap_or(2.0 * ui.spacing().interact_size.y);
+        let thickness = thickness.unwrap_or(0.1 * diameter).at_most(0.5 * diameter);
+        let (rect, response) = ui.allocate_exact_size(Vec2::splat(diameter), Sense::hover());
+
+        let shown = if animated {
+            ui.ctx()
+                .animate_value_with_time(response.id, progress, ui.style().animation_time)
+        } else {
+            progress
+        };
+
+        response.widget_info(|| {
+            let mut info = WidgetInfo::new(WidgetType::ProgressIndicator);
+            info.value = Some(f64::from(progress) * 100.0);
+            info
+        });
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.visuals();
+            let center = rect.center();
+            let radius = 0.5 * diameter;
+            let start = -std::f32::consts::FRAC_PI_2; // 12 o'clock
+            let full = std::f32::consts::TAU;
+
+            let mut mesh = Mesh::default();
+            arc_band(
+                &mut mesh,
+                center,
+                radius - thickness..=radius,
+                start,
+                start + full,
+                visuals.extreme_bg_color,
+            );
+            if shown > 0.0 {
+                arc_band(
+                    &mut mesh,
+                    center,
+                    radius - thickness..=radius,
+                    start,
+                    start + shown * full,
+                    color.unwrap_or(visuals.selection.bg_fill),
+                );
+            }
+            ui.painter().add(Shape::mesh(mesh));
+
+            let text = text.or_else(|| {
+                show_percentage.then(|| format!("{}%", (progress * 100.0).round() as usize).into())
+            });
+            if let Some(text) = text {
+                let galley = text.into_galley(
+                    ui,
+                    Some(egui::TextWrapMode::Truncate),
+                    diameter - 2.0 * thickness,
+                    TextStyle::Small,
+                );
+                let pos = center - 0.5 * galley.size();
+                ui.painter().galley(pos, galley, visuals.text_color());
+            }
+        }
+
+        response
+    }
+}
@@ -0,0 +1,405 @@
+//! An expandable tree view of JSON-like data.
+
+use egui::{
+    Color32, Id, Label, Response, Sense, TextFormat, TextStyle, Ui, Widget,
+    collapsing_header::CollapsingState, text::LayoutJob,
+};
+
+/// A JSON value, as shown by [`JsonTree`].
+///
+/// With the `serde_json` feature you can convert a `serde_json::Value` into this.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum JsonValue {
+    #[default]
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+
+    /// Key-value pairs, in order.
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    /// Number of children of an array or object, otherwise zero.
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Array(array) => array.len(),
+            Self::Object(object) => object.len(),
+            Self::Null | Self::Bool(_) | Self::Number(_) | Self::String(_) => 0,
+        }
+    }
+
+    /// Is this an empty array or object, or not a container at all?
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Does this value, or anything inside of it, contain the (lowercase) query,
+    /// either in a key or in a value?
+    fn contains(&self, lowercase_query: &str) -> bool {
+        match self {
+            Self::Array(array) => array.iter().any(|value| value.contains(lowercase_query)),
+            Self::Object(object) => object.iter().any(|(key, value)| {
+                key.to_lowercase().contains(lowercase_query) || value.contains(lowercase_query)
+            }),
+            Self::Null | Self::Bool(_) | Self::Number(_) | Self::String(_) => {
+                self.to_string().to_lowercase().contains(lowercase_query)
+            }
+        }
+    }
+}
+
+/// Writes the value as compact JSON.
+impl std::fmt::Display for JsonValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Null => f.write_str("null"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Number(value) => write_number(f, *value),
+            Self::String(value) => write_string(f, value),
+            Self::Array(array) => {
+                f.write_str("[")?;
+                for (i, value) in array.iter().enumerate() {
+                    if 0 < i {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                f.write_str("]")
+            }
+            Self::Object(object) => {
+                f.write_str("{")?;
+                for (i, (key, value)) in object.iter().enumerate() {
+                    if 0 < i {
+                        f.write_str(",")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                f.write_str("}")
+            }
+        }
+    }
+}
+
+fn write_number(f: &mut std::fmt::Formatter<'_>, value: f64) -> std::fmt::Result {
+    if !value.is_finite() {
+        f.write_str("null") // Like JSON.stringify
+    } else if value.fract() == 0.0 && value.abs() < 1e15 {
+        write!(f, "{}", value as i64)
+    } else {
+        write!(f, "{value}")
+    }
+}
+
+fn write_string(f: &mut std::fmt::Formatter<'_>, value: &str) -> std::fmt::Result {
+    f.write_str("\"")?;
+    for c in value.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{c}")?,
+        }
+    }
+    f.write_str("\"")
+}
+
+#[cfg(feature = "serde_json")]
+impl From<&serde_json::Value> for JsonValue {
+    fn from(value: &serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::Null => Self::Null,
+            serde_json::Value::Bool(value) => Self::Bool(*value),
+            serde_json::Value::Number(value) => Self::Number(value.as_f64().unwrap_or(f64::NAN)),
+            serde_json::Value::String(value) => Self::String(value.clone()),
+            serde_json::Value::Array(array) => Self::Array(array.iter().map(Self::from).collect()),
+            serde_json::Value::Object(object) => Self::Object(
+                object
+                    .iter()
+                    .map(|(key, value)| (key.clone(), Self::from(value)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl From<serde_json::Value> for JsonValue {
+    fn from(value: serde_json::Value) -> Self {
+        Self::from(&value)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Shows a [`JsonValue`] as an expandable tree with syntax coloring.
+///
+/// Right-click a row to copy its path (like `$.users[3].name`) or its value as JSON.
+/// Arrays and objects with many children are split into ranges that are only shown when expanded,
+/// so even huge documents stay fast.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_extras::{JsonTree, JsonValue};
+///
+/// let value = JsonValue::Object(vec![
+///     ("name".to_owned(), JsonValue::String("egui".to_owned())),
+///     ("stars".to_owned(), JsonValue::Number(25_000.0)),
+/// ]);
+/// # let query = "";
+/// ui.add(JsonTree::new("response", &value).search(query));
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct JsonTree<'a> {
+    id_salt: Id,
+    value: &'a JsonValue,
+    search: String,
+    default_expand_depth: usize,
+    chunk_size: usize,
+}
+
+impl<'a> JsonTree<'a> {
+    /// The `id_salt` must be unique among sibling widgets.
+    pub fn new(id_salt: impl std::hash::Hash, value: &'a JsonValue) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            value,
+            search: String::new(),
+            default_expand_depth: 1,
+            chunk_size: 100,
+        }
+    }
+
+    /// Highlight keys and values containing this text (ignoring case),
+    /// and expand everything leading to them.
+    #[inline]
+    pub fn search(mut self, query: &str) -> Self {
+        self.search = query.trim().to_lowercase();
+        self
+    }
+
+    /// How many levels are expanded the first time the tree is shown.
+    ///
+    /// Default: `1` (only the root).
+    #[inline]
+    pub fn default_expand_depth(mut self, depth: usize) -> Self {
+        self.default_expand_depth = depth;
+        self
+    }
+
+    /// Arrays and objects with more children than this are split into ranges of this size.
+    ///
+    /// Default: `100`.
+    #[inline]
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    fn node_ui(&self, ui: &mut Ui, key: Option<&str>, value: &JsonValue, path: &str, depth: usize) {
+        let row = |ui: &mut Ui| {
+            let job = self.row_layout_job(ui, key, value);
+            let response = ui.add(Label::new(job).sense(Sense::click()));
+            self.context_menu(&response, value, path);
+        };
+
+        if value.is_empty() {
+            // A leaf: line it up with the headers of the expandable nodes.
+            ui.horizontal(|ui| {
+                ui.add_space(ui.spacing().indent - ui.spacing().item_spacing.x);
+                row(ui);
+            });
+            return;
+        }
+
+        let mut state = CollapsingState::load_with_default_open(
+            ui.ctx(),
+            self.id_salt.with(path),
+            depth < self.default_expand_depth,
+        );
+        if !self.search.is_empty() && value.contains(&self.search) {
+            state.set_open(true);
+        }
+        state
+            .show_header(ui, row)
+            .body(|ui| self.children_ui(ui, value, path, depth + 1));
+    }
+
+    fn children_ui(&self, ui: &mut Ui, value: &JsonValue, path: &str, depth: usize) {
+        let len = value.len();
+        if len <= self.chunk_size {
+            self.children_range_ui(ui, value, path, depth, 0..len);
+            return;
+        }
+
+        for start in (0..len).step_by(self.chunk_size) {
+            let range = start..(start + self.chunk_size).min(len);
+
+            let mut state = CollapsingState::load_with_default_open(
+                ui.ctx(),
+                self.id_salt.with((path, start)),
+                false,
+            );
+            if !self.search.is_empty() && self.range_contains_search(value, range.clone()) {
+                state.set_open(true);
+            }
+            state
+                .show_header(ui, |ui| {
+                    ui.weak(format!("[{} … {}]", range.start, range.end - 1));
+                })
+                .body(|ui| self.children_range_ui(ui, value, path, depth, range));
+        }
+    }
+
+    fn children_range_ui(
+        &self,
+        ui: &mut Ui,
+        value: &JsonValue,
+        path: &str,
+        depth: usize,
+        range: std::ops::Range<usize>,
+    ) {
+        match value {
+            JsonValue::Array(array) => {
+                for (i, child) in array[range.clone()].iter().enumerate() {
+                    let index = range.start + i;
+                    let key = index.to_string();
+                    let path = format!("{path}[{index}]");
+                    self.node_ui(ui, Some(&key), child, &path, depth);
+                }
+            }
+            JsonValue::Object(object) => {
+                for (key, child) in &object[range] {
+                    let path = child_path(path, key);
+                    self.node_ui(ui, Some(key), child, &path, depth);
+                }
+            }
+            JsonValue::Null | JsonValue::Bool(_) | JsonValue::Number(_) | JsonValue::String(_) => {}
+        }
+    }
+
+    fn range_contains_search(&self, value: &JsonValue, range: std::ops::Range<usize>) -> bool {
+        match value {
+            JsonValue::Array(array) => array[range].iter().any(|v| v.contains(&self.search)),
+            JsonValue::Object(object) => object[range].iter().any(|(key, v)| {
+                key.to_lowercase().contains(&self.search) || v.contains(&self.search)
+            }),
+            JsonValue::Null | JsonValue::Bool(_) | JsonValue::Number(_) | JsonValue::String(_) => {
+                false
+            }
+        }
+    }
+
+    fn row_layout_job(&self, ui: &Ui, key: Option<&str>, value: &JsonValue) -> LayoutJob {
+        let visuals = ui.visuals();
+        let font_id = TextStyle::Monospace.resolve(ui.style());
+        let dark_mode = visuals.dark_mode;
+
+        let key_color = visuals.strong_text_color();
+        let punctuation_color = visuals.weak_text_color();
+        let string_color = if dark_mode {
+            Color32::from_rgb(152, 195, 121)
+        } else {
+            Color32::from_rgb(80, 161, 79)
+        };
+        let number_color = if dark_mode {
+            Color32::from_rgb(209, 154, 102)
+        } else {
+            Color32::from_rgb(152, 104, 1)
+        };
+        let keyword_color = if dark_mode {
+            Color32::from_rgb(198, 120, 221)
+        } else {
+            Color32::from_rgb(166, 38, 164)
+        };
+
+        let mut job = LayoutJob::default();
+        let mut append = |text: &str, color: Color32, searchable: bool| {
+            let is_match = searchable
+                && !self.search.is_empty()
+                && text.to_lowercase().contains(&self.search);
+            let background = if is_match {
+                visuals.selection.bg_fill
+            } else {
+                Color32::TRANSPARENT
+            };
+            job.append(
+                text,
+                0.0,
+                TextFormat {
+                    font_id: font_id.clone(),
+                    color,
+                    background,
+                    ..Default::default()
+                },
+            );
+        };
+
+        if let Some(key) = key {
+            append(key, key_color, true);
+            append(": ", punctuation_color, false);
+        }
+
+        match value {
+            JsonValue::Null | JsonValue::Bool(_) => append(&value.to_string(), keyword_color, true),
+            JsonValue::Number(_) => append(&value.to_string(), number_color, true),
+            JsonValue::String(_) => append(&value.to_string(), string_color, true),
+            JsonValue::Array(array) if array.is_empty() => append("[]", punctuation_color, false),
+            JsonValue::Object(object) if object.is_empty() => {
+                append("{}", punctuation_color, false);
+            }
+            JsonValue::Array(array) => {
+                append(&format!("[…] {} items", array.len()), punctuation_color, false);
+            }
+            JsonValue::Object(object) => {
+                append(&format!("{{…}} {} keys", object.len()), punctuation_color, false);
+            }
+        }
+
+        job
+    }
+
+    fn context_menu(&self, response: &Response, value: &JsonValue, path: &str) {
+        response.context_menu(|ui| {
+            if ui.button("Copy path").clicked() {
+                ui.ctx().copy_text(path.to_owned());
+            }
+            if ui.button("Copy value").clicked() {
+                ui.ctx().copy_text(value.to_string());
+            }
+        });
+    }
+}
+
+impl Widget for JsonTree<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        ui.vertical(|ui| {
+            self.node_ui(ui, None, self.value, "$", 0);
+        })
+        .response
+    }
+}
+
+/// The path to a key in an object, like `$.name` or `$["odd key"]`.
+fn child_path(path: &str, key: &str) -> String {
+    let is_identifier = key
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_identifier {
+        format!("{path}.{key}")
+    } else {
+        format!("{path}[{}]", JsonValue::String(key.to_owned()))
+    }
+}
+
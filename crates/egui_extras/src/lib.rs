@@ -9,26 +9,44 @@
 #![allow(clippy::float_cmp)]
 #![allow(clippy::manual_range_contains)]
 
+mod audio;
 #[cfg(feature = "chrono")]
 mod datepicker;
+mod gauge;
 
 pub mod syntax_highlighting;
 
 #[doc(hidden)]
 pub mod image;
+mod json_tree;
 mod layout;
 mod loaders;
+mod log_viewer;
+mod magnifier;
 mod sizing;
+mod sparkline;
 mod strip;
 mod table;
+mod terminal;
+mod video_texture;
 
 #[cfg(feature = "chrono")]
 pub use crate::datepicker::DatePickerButton;
 
+pub use crate::audio::{LevelMeter, Waveform, WaveformResponse};
+pub use crate::gauge::{Gauge, GaugeStyle, RadialProgress};
+pub use crate::json_tree::{JsonTree, JsonValue};
 pub(crate) use crate::layout::StripLayout;
+pub use crate::log_viewer::{LogBuffer, LogEntry, LogLevel, LogViewer};
+pub use crate::magnifier::Magnifier;
 pub use crate::sizing::Size;
+pub use crate::sparkline::{MiniBar, Sparkline};
 pub use crate::strip::*;
 pub use crate::table::*;
+pub use crate::terminal::{CellAttributes, GridPos, TerminalCell, TerminalGrid, TerminalView};
+pub use crate::video_texture::{
+    VideoFrame, VideoFrameFormat, VideoFrameSender, VideoTexture, YuvMatrix,
+};
 
 pub use loaders::install_image_loaders;
 
@@ -0,0 +1,686 @@
+//! A scrolling log console with level filters and search.
+
+use std::{collections::VecDeque, ops::Range};
+
+use egui::{
+    Button, Color32, Event, FontId, Id, Modifiers, Rect, Response, RichText, ScrollArea, Sense,
+    TextEdit, TextFormat, TextStyle, Ui, Visuals, text::LayoutJob, vec2,
+};
+
+/// The severity of a [`LogEntry`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    /// All levels, from least to most severe.
+    pub const ALL: [Self; 5] = [Self::Trace, Self::Debug, Self::Info, Self::Warn, Self::Error];
+
+    /// Upper-case name, e.g. `"WARN"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Trace => "TRACE",
+            Self::Debug => "DEBUG",
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+        }
+    }
+
+    /// The color entries of this level are shown with.
+    pub fn color(self, visuals: &Visuals) -> Color32 {
+        match self {
+            Self::Trace => visuals.weak_text_color().gamma_multiply(0.7),
+            Self::Debug => visuals.weak_text_color(),
+            Self::Info => visuals.text_color(),
+            Self::Warn => visuals.warn_fg_color,
+            Self::Error => visuals.error_fg_color,
+        }
+    }
+}
+
+impl From<log::Level> for LogLevel {
+    fn from(level: log::Level) -> Self {
+        match level {
+            log::Level::Trace => Self::Trace,
+            log::Level::Debug => Self::Debug,
+            log::Level::Info => Self::Info,
+            log::Level::Warn => Self::Warn,
+            log::Level::Error => Self::Error,
+        }
+    }
+}
+
+/// One message in a [`LogBuffer`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogEntry {
+    /// Seconds, e.g. since the UNIX epoch. Shown as the time of day (UTC).
+    pub time: Option<f64>,
+
+    pub level: LogLevel,
+
+    /// Where the message came from, e.g. a module path. Not shown if empty.
+    pub target: String,
+
+    pub message: String,
+}
+
+impl LogEntry {
+    pub fn new(level: LogLevel, message: impl Into<String>) -> Self {
+        Self {
+            time: None,
+            level,
+            target: String::new(),
+            message: message.into(),
+        }
+    }
+
+    #[inline]
+    pub fn time(mut self, time: f64) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    #[inline]
+    pub fn target(mut self, target: impl Into<String>) -> Self {
+        self.target = target.into();
+        self
+    }
+
+    /// The entry as one line of plain text, as it is copied to the clipboard.
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        if let Some(time) = self.time {
+            text += &format_time(time);
+            text.push(' ');
+        }
+        text += &format!("{:<5} ", self.level.name());
+        if !self.target.is_empty() {
+            text += &self.target;
+            text += ": ";
+        }
+        text += &self.message;
+        text
+    }
+}
+
+struct StoredEntry {
+    entry: LogEntry,
+
+    /// Increases by one for each entry, so it stays valid when old entries are dropped.
+    seq: u64,
+
+    /// Height when wrapped, for [`LogBuffer::layout_key`].
+    wrapped_height: Option<f32>,
+}
+
+/// The entries shown by a [`LogViewer`].
+///
+/// Only the last [`Self::max_entries`] entries are kept.
+pub struct LogBuffer {
+    entries: VecDeque<StoredEntry>,
+    max_entries: usize,
+    next_seq: u64,
+
+    /// What the cached heights were laid out with.
+    layout_key: Option<Id>,
+}
+
+impl Default for LogBuffer {
+    fn default() -> Self {
+        Self::new(10_000)
+    }
+}
+
+impl LogBuffer {
+    /// Keep at most this many entries, dropping the oldest ones.
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            max_entries: max_entries.max(1),
+            next_seq: 0,
+            layout_key: None,
+        }
+    }
+
+    pub fn max_entries(&self) -> usize {
+        self.max_entries
+    }
+
+    pub fn push(&mut self, entry: LogEntry) {
+        while self.max_entries <= self.entries.len() {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(StoredEntry {
+            entry,
+            seq: self.next_seq,
+            wrapped_height: None,
+        });
+        self.next_seq += 1;
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// All entries, from oldest to newest.
+    pub fn entries(&self) -> impl ExactSizeIterator<Item = &LogEntry> + '_ {
+        self.entries.iter().map(|stored| &stored.entry)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Matches entries against the search field.
+enum Matcher {
+    Everything,
+
+    /// ASCII-lowercase text to look for.
+    Text(String),
+
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+
+    /// An invalid regex; matches nothing.
+    Nothing,
+}
+
+impl Matcher {
+    fn is_match(&self, entry: &LogEntry) -> bool {
+        match self {
+            Self::Everything => true,
+            Self::Text(_) => {
+                !self.find(&entry.message).is_empty() || !self.find(&entry.target).is_empty()
+            }
+            #[cfg(feature = "regex")]
+            Self::Regex(regex) => regex.is_match(&entry.message) || regex.is_match(&entry.target),
+            Self::Nothing => false,
+        }
+    }
+
+    /// Byte ranges of all matches in the text.
+    fn find(&self, text: &str) -> Vec<Range<usize>> {
+        match self {
+            Self::Everything | Self::Nothing => Vec::new(),
+            Self::Text(needle) => {
+                // ASCII-lowercasing keeps the byte offsets intact:
+                let haystack = text.to_ascii_lowercase();
+                haystack
+                    .match_indices(needle.as_str())
+                    .map(|(start, found)| start..start + found.len())
+                    .collect()
+            }
+            #[cfg(feature = "regex")]
+            Self::Regex(regex) => regex
+                .find_iter(text)
+                .filter(|m| !m.is_empty())
+                .map(|m| m.range())
+                .collect(),
+        }
+    }
+}
+
+/// The state of a [`LogViewer`], stored in [`egui::Memory`] between frames.
+#[derive(Clone)]
+struct ViewState {
+    /// Indexed by [`LogLevel`].
+    levels: [bool; 5],
+    search: String,
+    #[cfg(feature = "regex")]
+    use_regex: bool,
+    wrap: bool,
+    follow: bool,
+
+    /// Anchor and end of the selected entries, as [`StoredEntry::seq`].
+    selection: Option<(u64, u64)>,
+
+    #[cfg(feature = "regex")]
+    compiled_regex: Option<(String, Result<regex::Regex, String>)>,
+}
+
+impl Default for ViewState {
+    fn default() -> Self {
+        Self {
+            levels: [true; 5],
+            search: String::new(),
+            #[cfg(feature = "regex")]
+            use_regex: false,
+            wrap: false,
+            follow: true,
+            selection: None,
+            #[cfg(feature = "regex")]
+            compiled_regex: None,
+        }
+    }
+}
+
+impl ViewState {
+    #[cfg(feature = "regex")]
+    fn compile_regex(&mut self) {
+        let is_cached = self
+            .compiled_regex
+            .as_ref()
+            .is_some_and(|(pattern, _)| *pattern == self.search);
+        if self.use_regex && !is_cached {
+            let regex = regex::RegexBuilder::new(&self.search)
+                .case_insensitive(true)
+                .build()
+                .map_err(|err| err.to_string());
+            self.compiled_regex = Some((self.search.clone(), regex));
+        }
+    }
+
+    fn matcher(&self) -> Result<Matcher, String> {
+        if self.search.is_empty() {
+            return Ok(Matcher::Everything);
+        }
+
+        #[cfg(feature = "regex")]
+        if self.use_regex {
+            return match &self.compiled_regex {
+                Some((pattern, Ok(regex))) if *pattern == self.search => {
+                    Ok(Matcher::Regex(regex.clone()))
+                }
+                Some((pattern, Err(err))) if *pattern == self.search => Err(err.clone()),
+                _ => Ok(Matcher::Nothing),
+            };
+        }
+
+        Ok(Matcher::Text(self.search.to_ascii_lowercase()))
+    }
+
+    fn is_selected(&self, seq: u64) -> bool {
+        self.selection
+            .is_some_and(|(a, b)| a.min(b) <= seq && seq <= a.max(b))
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A log console for a [`LogBuffer`].
+///
+/// Has a toolbar for filtering by level, searching (optionally with a regex, with the `regex` feature),
+/// wrapping long lines, and following the newest entries.
+/// Scrolling up pauses following; scrolling back to the bottom resumes it.
+///
+/// Click an entry to select it, and shift-click to select a range.
+/// Copy the selection with the usual shortcut, or with the context menu.
+///
+/// Only the visible entries are laid out and painted, so this stays fast with many entries.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_extras::{LogBuffer, LogEntry, LogLevel, LogViewer};
+///
+/// # let mut logs = LogBuffer::default();
+/// logs.push(LogEntry::new(LogLevel::Info, "Connected").target("net"));
+/// LogViewer::new(&mut logs).show(ui);
+/// # });
+/// ```
+pub struct LogViewer<'a> {
+    buffer: &'a mut LogBuffer,
+    id_salt: Option<Id>,
+    show_toolbar: bool,
+    font_id: Option<FontId>,
+}
+
+impl<'a> LogViewer<'a> {
+    pub fn new(buffer: &'a mut LogBuffer) -> Self {
+        Self {
+            buffer,
+            id_salt: None,
+            show_toolbar: true,
+            font_id: None,
+        }
+    }
+
+    /// Needed if you show several log viewers in the same [`Ui`].
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
+    /// Show the filter and search toolbar?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn show_toolbar(mut self, show_toolbar: bool) -> Self {
+        self.show_toolbar = show_toolbar;
+        self
+    }
+
+    /// The font to use.
+    ///
+    /// Default: [`TextStyle::Monospace`].
+    #[inline]
+    pub fn font(mut self, font_id: FontId) -> Self {
+        self.font_id = Some(font_id);
+        self
+    }
+
+    /// Show the toolbar and the entries. Returns the response of the entries area.
+    pub fn show(self, ui: &mut Ui) -> Response {
+        let Self {
+            buffer,
+            id_salt,
+            show_toolbar,
+            font_id,
+        } = self;
+
+        let id = ui.make_persistent_id(id_salt.unwrap_or_else(|| Id::new("log_viewer")));
+        let font_id = font_id.unwrap_or_else(|| TextStyle::Monospace.resolve(ui.style()));
+        let mut state: ViewState = ui.data_mut(|d| d.get_temp(id)).unwrap_or_default();
+
+        let mut scroll_to_bottom = false;
+        if show_toolbar {
+            #[cfg(feature = "regex")]
+            state.compile_regex();
+            let search_error = state.matcher().err();
+            scroll_to_bottom = toolbar_ui(ui, &mut state, buffer, search_error.as_deref());
+        }
+        #[cfg(feature = "regex")]
+        state.compile_regex();
+        let matcher = state.matcher().unwrap_or(Matcher::Nothing);
+
+        let shown: Vec<usize> = buffer
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(_, stored)| {
+                state.levels[stored.entry.level as usize] && matcher.is_match(&stored.entry)
+            })
+            .map(|(index, _)| index)
+            .collect();
+
+        let row_height = ui.fonts(|f| f.row_height(&font_id));
+        let wrap_width = ui.available_width();
+
+        // Top of each shown entry, and the total height at the end:
+        let mut tops = Vec::with_capacity(shown.len() + 1);
+        if state.wrap {
+            let layout_key = Id::new((
+                &font_id,
+                wrap_width.to_bits(),
+                ui.ctx().pixels_per_point().to_bits(),
+            ));
+            if buffer.layout_key != Some(layout_key) {
+                for stored in &mut buffer.entries {
+                    stored.wrapped_height = None;
+                }
+                buffer.layout_key = Some(layout_key);
+            }
+
+            let mut top = 0.0;
+            for &index in &shown {
+                tops.push(top);
+                let stored = &mut buffer.entries[index];
+                let height = *stored.wrapped_height.get_or_insert_with(|| {
+                    let job = entry_layout_job(
+                        &stored.entry,
+                        &Matcher::Everything,
+                        &font_id,
+                        ui.visuals(),
+                        Some(wrap_width),
+                    );
+                    ui.fonts(|f| f.layout_job(job)).size().y
+                });
+                top += height.max(row_height);
+            }
+            tops.push(top);
+        } else {
+            tops.extend((0..=shown.len()).map(|i| i as f32 * row_height));
+        }
+        #[expect(clippy::unwrap_used)] // Always has the end
+        let total_height = *tops.last().unwrap();
+
+        let mut scroll_area = ScrollArea::vertical()
+            .id_salt(id.with("scroll"))
+            .auto_shrink(false)
+            .stick_to_bottom(state.follow);
+        if scroll_to_bottom {
+            scroll_area = scroll_area.vertical_scroll_offset(total_height);
+        }
+
+        let output = scroll_area.show_viewport(ui, |ui, viewport| {
+            ui.set_height(total_height);
+            let content_rect =
+                Rect::from_min_size(ui.min_rect().min, vec2(ui.available_width(), total_height));
+
+            let visible_rows = {
+                let first = tops.partition_point(|&top| top <= viewport.top()).saturating_sub(1);
+                let end = tops.partition_point(|&top| top < viewport.bottom());
+                first..end.min(shown.len())
+            };
+
+            let rect = Rect::from_x_y_ranges(
+                content_rect.x_range(),
+                content_rect.top() + viewport.top()..=content_rect.top() + viewport.bottom(),
+            );
+            let response = ui.interact(rect, id.with("rows"), Sense::click());
+
+            // Selection:
+            let row_at = |pos: egui::Pos2| {
+                let y = pos.y - content_rect.top();
+                let row = tops.partition_point(|&top| top <= y).checked_sub(1)?;
+                (row < shown.len()).then_some(row)
+            };
+            if response.clicked() {
+                response.request_focus();
+                let clicked_row = response.interact_pointer_pos().and_then(row_at);
+                if let Some(row) = clicked_row {
+                    let seq = buffer.entries[shown[row]].seq;
+                    let shift = ui.input(|i| i.modifiers.contains(Modifiers::SHIFT));
+                    state.selection = match state.selection {
+                        Some((anchor, _)) if shift => Some((anchor, seq)),
+                        _ => Some((seq, seq)),
+                    };
+                } else {
+                    state.selection = None;
+                }
+            }
+
+            let copy_selection = |ui: &Ui| {
+                let text: Vec<String> = shown
+                    .iter()
+                    .map(|&index| &buffer.entries[index])
+                    .filter(|stored| state.is_selected(stored.seq))
+                    .map(|stored| stored.entry.to_text())
+                    .collect();
+                ui.ctx().copy_text(text.join("\n"));
+            };
+            if response.has_focus()
+                && state.selection.is_some()
+                && ui.input(|i| i.events.contains(&Event::Copy))
+            {
+                copy_selection(ui);
+            }
+            response.context_menu(|ui| {
+                if ui
+                    .add_enabled(state.selection.is_some(), Button::new("Copy"))
+                    .clicked()
+                {
+                    copy_selection(ui);
+                    ui.close();
+                }
+            });
+
+            // Paint the visible rows:
+            let painter = ui.painter_at(rect);
+            let selection_bg = ui.visuals().selection.bg_fill;
+            for row in visible_rows {
+                let stored = &buffer.entries[shown[row]];
+                let row_rect = Rect::from_x_y_ranges(
+                    content_rect.x_range(),
+                    content_rect.top() + tops[row]..=content_rect.top() + tops[row + 1],
+                );
+                if state.is_selected(stored.seq) {
+                    painter.rect_filled(row_rect, 0.0, selection_bg);
+                }
+                let job = entry_layout_job(
+                    &stored.entry,
+                    &matcher,
+                    &font_id,
+                    ui.visuals(),
+                    state.wrap.then_some(wrap_width),
+                );
+                let galley = ui.fonts(|f| f.layout_job(job));
+                painter.galley(row_rect.min, galley, ui.visuals().text_color());
+            }
+
+            response
+        });
+
+        // Pause following when the user scrolls up, and resume it when they scroll to the bottom:
+        let max_offset = output.content_size.y - output.inner_rect.height();
+        let at_bottom = max_offset - 1.0 <= output.state.offset.y;
+        if state.follow && !at_bottom && !scroll_to_bottom {
+            state.follow = false;
+        } else if !state.follow && at_bottom && ui.input(|i| i.raw_scroll_delta.y < 0.0) {
+            state.follow = true;
+        }
+
+        ui.data_mut(|d| d.insert_temp(id, state));
+
+        output.inner
+    }
+}
+
+/// Returns `true` if the user asked to scroll to the bottom.
+fn toolbar_ui(
+    ui: &mut Ui,
+    state: &mut ViewState,
+    buffer: &mut LogBuffer,
+    search_error: Option<&str>,
+) -> bool {
+    let mut scroll_to_bottom = false;
+    ui.horizontal(|ui| {
+        for level in LogLevel::ALL {
+            let count = buffer
+                .entries
+                .iter()
+                .filter(|stored| stored.entry.level == level)
+                .count();
+            ui.toggle_value(
+                &mut state.levels[level as usize],
+                RichText::new(level.name()).color(level.color(ui.visuals())),
+            )
+            .on_hover_text(format!("{count} entries"));
+        }
+
+        ui.separator();
+
+        let mut search = TextEdit::singleline(&mut state.search)
+            .hint_text("🔍 Search")
+            .desired_width(160.0);
+        if search_error.is_some() {
+            search = search.text_color(ui.visuals().error_fg_color);
+        }
+        let search_response = ui.add(search);
+        if let Some(err) = search_error {
+            search_response.on_hover_text(err);
+        }
+
+        #[cfg(feature = "regex")]
+        ui.toggle_value(&mut state.use_regex, ".*")
+            .on_hover_text("Search with a regular expression");
+
+        ui.separator();
+
+        ui.toggle_value(&mut state.wrap, "Wrap")
+            .on_hover_text("Wrap long lines");
+        if ui
+            .toggle_value(&mut state.follow, "⏬ Follow")
+            .on_hover_text("Keep scrolling to the newest entries")
+            .clicked()
+            && state.follow
+        {
+            scroll_to_bottom = true;
+        }
+        if ui.button("Clear").clicked() {
+            buffer.clear();
+            state.selection = None;
+        }
+    });
+    scroll_to_bottom
+}
+
+fn entry_layout_job(
+    entry: &LogEntry,
+    matcher: &Matcher,
+    font_id: &FontId,
+    visuals: &Visuals,
+    wrap_width: Option<f32>,
+) -> LayoutJob {
+    let format = |color: Color32| TextFormat {
+        font_id: font_id.clone(),
+        color,
+        ..Default::default()
+    };
+    let weak = visuals.weak_text_color();
+    let level_color = entry.level.color(visuals);
+
+    let mut job = LayoutJob::default();
+    if let Some(time) = entry.time {
+        job.append(&format_time(time), 0.0, format(weak));
+        job.append(" ", 0.0, format(weak));
+    }
+    job.append(&format!("{:<5} ", entry.level.name()), 0.0, format(level_color));
+    if !entry.target.is_empty() {
+        job.append(&entry.target, 0.0, format(weak));
+        job.append(": ", 0.0, format(weak));
+    }
+
+    let message = if wrap_width.is_some() {
+        entry.message.as_str()
+    } else {
+        entry.message.lines().next().unwrap_or_default()
+    };
+    let message_color = match entry.level {
+        LogLevel::Warn | LogLevel::Error => level_color,
+        LogLevel::Trace | LogLevel::Debug | LogLevel::Info => visuals.text_color(),
+    };
+    let mut highlighted = format(message_color);
+    highlighted.background = visuals.selection.bg_fill.gamma_multiply(0.6);
+    let mut pos = 0;
+    for range in matcher.find(message) {
+        job.append(&message[pos..range.start], 0.0, format(message_color));
+        job.append(&message[range.clone()], 0.0, highlighted.clone());
+        pos = range.end;
+    }
+    job.append(&message[pos..], 0.0, format(message_color));
+    if wrap_width.is_none() && message.len() < entry.message.len() {
+        job.append(" …", 0.0, format(weak));
+    }
+
+    job.wrap.max_width = wrap_width.unwrap_or(f32::INFINITY);
+    job
+}
+
+/// Time of day (UTC) as `HH:MM:SS.mmm`.
+fn format_time(seconds: f64) -> String {
+    let ms = (seconds * 1000.0).round() as i64;
+    let ms = ms.rem_euclid(24 * 60 * 60 * 1000);
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        ms / 3_600_000,
+        ms / 60_000 % 60,
+        ms / 1000 % 60,
+        ms % 1000
+    )
+}
+
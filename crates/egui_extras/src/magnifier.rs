@@ -0,0 +1,200 @@
+//! A zoom lens for checking pixel alignment and colors.
+
+use std::sync::Arc;
+
+use egui::{
+    Area, Color32, ColorImage, Context, Frame, Grid, Id, Order, Rect, Sense, Stroke, StrokeKind,
+    TextureHandle, TextureOptions, UserData, Vec2, ViewportCommand,
+    ecolor::{HsvaGamma, Rgba},
+    pos2, vec2,
+};
+
+/// Tags the screenshots taken by [`Magnifier`], so we can tell them apart from those of the app.
+struct MagnifierScreenshot;
+
+/// A debug overlay that shows a zoomed view of the pixels under the mouse cursor,
+/// together with the color of the pixel right under it.
+///
+/// Useful for checking that things are pixel-aligned, and that colors are what you expect.
+///
+/// The magnifier works on the rendered frame, so it needs an integration that supports
+/// [`ViewportCommand::Screenshot`], like `eframe`.
+/// It takes a new screenshot each frame it is shown, so only show it while you need it.
+///
+/// ```
+/// # egui::__run_test_ctx(|ctx| {
+/// # let mut magnifier = egui_extras::Magnifier::default();
+/// if ctx.input(|i| i.modifiers.alt && i.modifiers.shift) {
+///     magnifier.show(ctx);
+/// }
+/// # });
+/// ```
+pub struct Magnifier {
+    /// How many pixels to show on each side of the pixel under the cursor.
+    pub radius: usize,
+
+    /// The size of each magnified pixel, in points.
+    pub zoom: f32,
+
+    /// Draw lines between the magnified pixels?
+    pub show_grid: bool,
+
+    screenshot: Option<(Arc<ColorImage>, TextureHandle)>,
+}
+
+impl Default for Magnifier {
+    fn default() -> Self {
+        Self {
+            radius: 8,
+            zoom: 10.0,
+            show_grid: true,
+            screenshot: None,
+        }
+    }
+}
+
+impl Magnifier {
+    /// Show the lens next to the mouse cursor, and request a screenshot for the next frame.
+    ///
+    /// The first frame only requests the screenshot, so nothing is shown yet.
+    pub fn show(&mut self, ctx: &Context) {
+        ctx.send_viewport_cmd(ViewportCommand::Screenshot(UserData::new(MagnifierScreenshot)));
+
+        let is_ours = |user_data: &UserData| {
+            (user_data.data.as_ref()).is_some_and(|data| data.is::<MagnifierScreenshot>())
+        };
+        let new_screenshot = ctx.input(|i| {
+            i.events.iter().rev().find_map(|event| match event {
+                egui::Event::Screenshot {
+                    user_data, image, ..
+                } if is_ours(user_data) => Some(image.clone()),
+                _ => None,
+            })
+        });
+        if let Some(image) = new_screenshot {
+            match &mut self.screenshot {
+                Some((screenshot, texture)) => {
+                    texture.set(image.clone(), TextureOptions::NEAREST);
+                    *screenshot = image;
+                }
+                None => {
+                    let name = "egui_extras_magnifier";
+                    let texture = ctx.load_texture(name, image.clone(), TextureOptions::NEAREST);
+                    self.screenshot = Some((image, texture));
+                }
+            }
+        }
+
+        let Some((image, texture)) = &self.screenshot else {
+            return;
+        };
+        let Some(pointer_pos) = ctx.input(|i| i.pointer.hover_pos()) else {
+            return;
+        };
+        let [width, height] = image.size;
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        let pixels_per_point = ctx.pixels_per_point();
+        let pixel = [
+            ((pointer_pos.x * pixels_per_point) as usize).min(width - 1),
+            ((pointer_pos.y * pixels_per_point) as usize).min(height - 1),
+        ];
+        let color = image[(pixel[0], pixel[1])];
+
+        let radius = self.radius as f32;
+        let num_pixels = 2.0 * radius + 1.0;
+        let zoom = self.zoom.max(1.0);
+        let uv = Rect::from_min_max(
+            pos2(
+                (pixel[0] as f32 - radius) / width as f32,
+                (pixel[1] as f32 - radius) / height as f32,
+            ),
+            pos2(
+                (pixel[0] as f32 + radius + 1.0) / width as f32,
+                (pixel[1] as f32 + radius + 1.0) / height as f32,
+            ),
+        );
+
+        // Keep the lens away from the pixels it shows, so it doesn't magnify itself:
+        let offset = Vec2::splat(24.0);
+
+        Area::new(Id::new("egui_extras_magnifier"))
+            .order(Order::Tooltip)
+            .fixed_pos(pointer_pos + offset)
+            .interactable(false)
+            .show(ctx, |ui| {
+                Frame::popup(ui.style()).show(ui, |ui| {
+                    let lens_size = Vec2::splat(num_pixels * zoom);
+                    let (rect, _) = ui.allocate_exact_size(lens_size, Sense::hover());
+                    let painter = ui.painter_at(rect);
+                    painter.image(texture.id(), rect, uv, Color32::WHITE);
+
+                    let contrast = if Rgba::from(color).intensity() > 0.5 {
+                        Color32::BLACK
+                    } else {
+                        Color32::WHITE
+                    };
+
+                    if self.show_grid && 4.0 <= zoom {
+                        let stroke = Stroke::new(1.0, Color32::from_black_alpha(40));
+                        for i in 1..(num_pixels as usize) {
+                            let offset = i as f32 * zoom;
+                            painter.vline(rect.left() + offset, rect.y_range(), stroke);
+                            painter.hline(rect.x_range(), rect.top() + offset, stroke);
+                        }
+                    }
+
+                    let center = Rect::from_min_size(
+                        rect.min + Vec2::splat(radius * zoom),
+                        Vec2::splat(zoom),
+                    );
+                    let stroke = Stroke::new(1.0, contrast);
+                    painter.rect_stroke(center, 0.0, stroke, StrokeKind::Outside);
+
+                    ui.add_space(ui.spacing().item_spacing.y);
+                    color_readout(ui, pixel, color);
+                });
+            });
+    }
+}
+
+fn color_readout(ui: &mut egui::Ui, [x, y]: [usize; 2], color: Color32) {
+    Grid::new("egui_extras_magnifier_readout")
+        .num_columns(2)
+        .show(ui, |ui| {
+            ui.label("Pixel");
+            ui.monospace(format!("{x}, {y}"));
+            ui.end_row();
+
+            ui.label("Hex");
+            ui.horizontal(|ui| {
+                let (swatch, _) = ui.allocate_exact_size(vec2(16.0, 16.0), Sense::hover());
+                ui.painter().rect_filled(swatch, 2.0, color);
+                ui.monospace(color.to_hex());
+            });
+            ui.end_row();
+
+            let [r, g, b, a] = color.to_srgba_unmultiplied();
+            ui.label("sRGBA");
+            ui.monospace(format!("{r} {g} {b} {a}"));
+            ui.end_row();
+
+            let linear = Rgba::from(color);
+            ui.label("Linear");
+            ui.monospace(format!(
+                "{:.3} {:.3} {:.3} {:.3}",
+                linear.r(),
+                linear.g(),
+                linear.b(),
+                linear.a()
+            ));
+            ui.end_row();
+
+            let HsvaGamma { h, s, v, .. } = HsvaGamma::from(color);
+            ui.label("HSV");
+            ui.monospace(format!("{:.0}° {:.2} {:.2}", h * 360.0, s, v));
+            ui.end_row();
+        });
+}
@@ -0,0 +1,393 @@
+//! Tiny inline charts, e.g. for table cells.
+
+use std::ops::RangeInclusive;
+
+use egui::{
+    Color32, Pos2, Rect, Response, Sense, Shape, Stroke, Ui, Vec2, Widget, WidgetInfo, WidgetType,
+    emath::remap, pos2, vec2,
+};
+
+/// The range of the values, ignoring non-finite ones.
+fn value_range(values: &[f32]) -> Option<RangeInclusive<f32>> {
+    let (min, max) = values
+        .iter()
+        .filter(|v| v.is_finite())
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), &v| {
+            (min.min(v), max.max(v))
+        });
+    (min <= max).then_some(min..=max)
+}
+
+/// Options shared by [`Sparkline`] and [`MiniBar`].
+#[derive(Clone)]
+struct ChartOptions {
+    desired_size: Option<Vec2>,
+    color: Option<Color32>,
+    range: Option<RangeInclusive<f32>>,
+    hover_readout: bool,
+}
+
+impl Default for ChartOptions {
+    fn default() -> Self {
+        Self {
+            desired_size: None,
+            color: None,
+            range: None,
+            hover_readout: true,
+        }
+    }
+}
+
+impl ChartOptions {
+    /// Allocate space and work out the y range, including `baseline` if given.
+    fn allocate(
+        &self,
+        ui: &mut Ui,
+        values: &[f32],
+        baseline: Option<f32>,
+    ) -> (Rect, Response, RangeInclusive<f32>) {
+        let height = ui.text_style_height(&egui::TextStyle::Body);
+        let desired_size = self.desired_size.unwrap_or(vec2(4.0 * height, height));
+        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::hover());
+
+        let mut range = self
+            .range
+            .clone()
+            .or_else(|| value_range(values))
+            .unwrap_or(0.0..=1.0);
+        if let Some(baseline) = baseline {
+            range = range.start().min(baseline)..=range.end().max(baseline);
+        }
+        if range.start() == range.end() {
+            range = range.start() - 0.5..=range.end() + 0.5;
+        }
+        (rect, response, range)
+    }
+
+    /// Show the hovered value in a tooltip.
+    ///
+    /// Returns the index of the hovered value.
+    fn hover_readout(
+        &self,
+        response: &Response,
+        values: &[f32],
+        x_of: impl Fn(usize) -> f32,
+    ) -> Option<usize> {
+        if !self.hover_readout || values.is_empty() {
+            return None;
+        }
+        let pointer = response.hover_pos()?;
+        let index = (0..values.len()).min_by(|&a, &b| {
+            (x_of(a) - pointer.x)
+                .abs()
+                .total_cmp(&(x_of(b) - pointer.x).abs())
+        })?;
+        response.show_tooltip_text(format!("#{index}: {}", values[index]));
+        Some(index)
+    }
+}
+
+fn widget_info(response: &Response, ui: &Ui, values: &[f32]) {
+    response.widget_info(|| {
+        let mut info = WidgetInfo::new(WidgetType::Other);
+        info.enabled = ui.is_enabled();
+        info.value = values.last().map(|&v| f64::from(v));
+        info
+    });
+}
+
+/// A small line chart without axes, to show a trend inline with text or in a table cell.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let history = [3.0, 4.5, 4.0, 6.0, 5.5, 7.0];
+/// ui.horizontal(|ui| {
+///     ui.label("Requests/s");
+///     ui.add(egui_extras::Sparkline::new(&history).show_min_max(true).show_last(true));
+/// });
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct Sparkline<'a> {
+    values: &'a [f32],
+    options: ChartOptions,
+    fill: bool,
+    show_min_max: bool,
+    show_last: bool,
+}
+
+impl<'a> Sparkline<'a> {
+    pub fn new(values: &'a [f32]) -> Self {
+        Self {
+            values,
+            options: ChartOptions::default(),
+            fill: false,
+            show_min_max: false,
+            show_last: false,
+        }
+    }
+
+    /// Default: four times as wide as a row of text is high.
+    #[inline]
+    pub fn desired_size(mut self, desired_size: Vec2) -> Self {
+        self.options.desired_size = Some(desired_size);
+        self
+    }
+
+    /// Default: the selection stroke color.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.options.color = Some(color.into());
+        self
+    }
+
+    /// The values at the bottom and top of the chart.
+    ///
+    /// Default: the range of the values.
+    #[inline]
+    pub fn range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.options.range = Some(range);
+        self
+    }
+
+    /// Show the value under the mouse in a tooltip.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn hover_readout(mut self, hover_readout: bool) -> Self {
+        self.options.hover_readout = hover_readout;
+        self
+    }
+
+    /// Fill the area under the line.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn fill(mut self, fill: bool) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// Mark the smallest and largest values.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn show_min_max(mut self, show_min_max: bool) -> Self {
+        self.show_min_max = show_min_max;
+        self
+    }
+
+    /// Mark the last value.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn show_last(mut self, show_last: bool) -> Self {
+        self.show_last = show_last;
+        self
+    }
+}
+
+impl Widget for Sparkline<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            values,
+            options,
+            fill,
+            show_min_max,
+            show_last,
+        } = self;
+
+        let (rect, response, range) = options.allocate(ui, values, None);
+        widget_info(&response, ui, values);
+
+        // Leave room for the markers:
+        let marker_radius = 0.08 * rect.height();
+        let plot_rect = rect.shrink(marker_radius);
+        let x_of = |i: usize| {
+            if values.len() <= 1 {
+                plot_rect.center().x
+            } else {
+                remap(
+                    i as f32,
+                    0.0..=(values.len() - 1) as f32,
+                    plot_rect.x_range(),
+                )
+            }
+        };
+        let y_of = |v: f32| remap(v, range.clone(), plot_rect.bottom()..=plot_rect.top());
+        let point = |i: usize| pos2(x_of(i), y_of(values[i]));
+
+        let hovered = options.hover_readout(&response, values, x_of);
+
+        if ui.is_rect_visible(rect) && !values.is_empty() {
+            let visuals = ui.visuals();
+            let color = options.color.unwrap_or(visuals.selection.stroke.color);
+            let painter = ui.painter();
+
+            let points: Vec<Pos2> = (0..values.len())
+                .filter(|&i| values[i].is_finite())
+                .map(point)
+                .collect();
+
+            if fill && points.len() >= 2 {
+                let bottom = plot_rect.bottom();
+                let fill_color = color.gamma_multiply(0.25);
+                let mut mesh = egui::Mesh::default();
+                for pair in points.windows(2) {
+                    let [a, b] = [pair[0], pair[1]];
+                    let idx = mesh.vertices.len() as u32;
+                    mesh.colored_vertex(a, fill_color);
+                    mesh.colored_vertex(b, fill_color);
+                    mesh.colored_vertex(pos2(a.x, bottom), fill_color);
+                    mesh.colored_vertex(pos2(b.x, bottom), fill_color);
+                    mesh.add_triangle(idx, idx + 1, idx + 2);
+                    mesh.add_triangle(idx + 1, idx + 3, idx + 2);
+                }
+                painter.add(Shape::mesh(mesh));
+            }
+
+            painter.add(Shape::line(points, Stroke::new(1.0, color)));
+
+            let finite_indices = || (0..values.len()).filter(|&i| values[i].is_finite());
+            if show_min_max {
+                let min = finite_indices().min_by(|&a, &b| values[a].total_cmp(&values[b]));
+                let max = finite_indices().max_by(|&a, &b| values[a].total_cmp(&values[b]));
+                for (index, marker_color) in
+                    [(min, visuals.error_fg_color), (max, visuals.warn_fg_color)]
+                {
+                    if let Some(index) = index {
+                        painter.circle_filled(point(index), marker_radius, marker_color);
+                    }
+                }
+            }
+            if show_last {
+                if let Some(last) = finite_indices().last() {
+                    painter.circle_filled(point(last), marker_radius, color);
+                }
+            }
+            if let Some(index) = hovered.filter(|&i| values[i].is_finite()) {
+                painter.circle_stroke(
+                    point(index),
+                    1.5 * marker_radius,
+                    Stroke::new(1.0, visuals.strong_text_color()),
+                );
+            }
+        }
+
+        response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A small bar chart without axes, to show a distribution inline with text or in a table cell.
+///
+/// Negative values are drawn downwards from zero.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// ui.add(egui_extras::MiniBar::new(&[1.0, 3.0, -2.0, 4.0]));
+/// # });
+/// ```
+#[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
+pub struct MiniBar<'a> {
+    values: &'a [f32],
+    options: ChartOptions,
+    negative_color: Option<Color32>,
+}
+
+impl<'a> MiniBar<'a> {
+    pub fn new(values: &'a [f32]) -> Self {
+        Self {
+            values,
+            options: ChartOptions::default(),
+            negative_color: None,
+        }
+    }
+
+    /// Default: four times as wide as a row of text is high.
+    #[inline]
+    pub fn desired_size(mut self, desired_size: Vec2) -> Self {
+        self.options.desired_size = Some(desired_size);
+        self
+    }
+
+    /// Default: the selection background color.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.options.color = Some(color.into());
+        self
+    }
+
+    /// The color of bars with negative values.
+    ///
+    /// Default: the error color.
+    #[inline]
+    pub fn negative_color(mut self, color: impl Into<Color32>) -> Self {
+        self.negative_color = Some(color.into());
+        self
+    }
+
+    /// The values at the bottom and top of the chart. Zero is always included.
+    ///
+    /// Default: the range of the values.
+    #[inline]
+    pub fn range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.options.range = Some(range);
+        self
+    }
+
+    /// Show the value under the mouse in a tooltip.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn hover_readout(mut self, hover_readout: bool) -> Self {
+        self.options.hover_readout = hover_readout;
+        self
+    }
+}
+
+impl Widget for MiniBar<'_> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            values,
+            options,
+            negative_color,
+        } = self;
+
+        let (rect, response, range) = options.allocate(ui, values, Some(0.0));
+        widget_info(&response, ui, values);
+
+        let slot_width = rect.width() / values.len().max(1) as f32;
+        let gap = (0.2 * slot_width).min(2.0);
+        let x_of = |i: usize| rect.left() + (i as f32 + 0.5) * slot_width;
+        let y_of = |v: f32| remap(v, range.clone(), rect.bottom()..=rect.top());
+
+        let hovered = options.hover_readout(&response, values, x_of);
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.visuals();
+            let color = options.color.unwrap_or(visuals.selection.bg_fill);
+            let negative_color = negative_color.unwrap_or(visuals.error_fg_color);
+            let zero = y_of(0.0);
+
+            for (i, &value) in values.iter().enumerate() {
+                if !value.is_finite() {
+                    continue;
+                }
+                let x = x_of(i);
+                let bar = Rect::from_x_y_ranges(
+                    x - 0.5 * (slot_width - gap)..=x + 0.5 * (slot_width - gap),
+                    y_of(value).min(zero)..=y_of(value).max(zero).max(y_of(value).min(zero) + 1.0),
+                );
+                let mut bar_color = if value < 0.0 { negative_color } else { color };
+                if hovered == Some(i) {
+                    bar_color = bar_color.lerp_to_gamma(visuals.strong_text_color(), 0.3);
+                }
+                ui.painter().rect_filled(bar, 0.0, bar_color);
+            }
+        }
+
+        response
+    }
+}
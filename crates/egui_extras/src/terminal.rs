@@ -0,0 +1,755 @@
+//! A monospace cell grid for showing terminal output.
+
+use std::{collections::VecDeque, sync::Arc};
+
+use egui::{
+    Color32, Event, FontId, Galley, Id, Rect, Response, ScrollArea, Sense, Stroke, TextFormat,
+    TextStyle, Ui, pos2, text::LayoutJob, vec2,
+};
+
+/// Text attributes of a [`TerminalCell`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CellAttributes {
+    /// Shown with the strong text color, unless the cell has an explicit foreground color.
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub strikethrough: bool,
+
+    /// Swap foreground and background colors.
+    pub inverse: bool,
+
+    /// Shown with a faded foreground color.
+    pub dim: bool,
+}
+
+/// One character cell of a [`TerminalGrid`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TerminalCell {
+    pub ch: char,
+
+    /// `None` means the default text color.
+    pub fg: Option<Color32>,
+
+    /// `None` means the default terminal background.
+    pub bg: Option<Color32>,
+
+    pub attrs: CellAttributes,
+}
+
+impl Default for TerminalCell {
+    fn default() -> Self {
+        Self {
+            ch: ' ',
+            fg: None,
+            bg: None,
+            attrs: CellAttributes::default(),
+        }
+    }
+}
+
+/// A position in a [`TerminalGrid`].
+///
+/// `line` counts from the oldest line in the scrollback, so it includes scrolled-out lines.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GridPos {
+    pub line: usize,
+    pub col: usize,
+}
+
+impl GridPos {
+    pub fn new(line: usize, col: usize) -> Self {
+        Self { line, col }
+    }
+}
+
+#[derive(Clone)]
+struct Line {
+    cells: Vec<TerminalCell>,
+
+    /// Changed since the last call to [`TerminalGrid::take_damage`].
+    damaged: bool,
+
+    /// Cached layout of the text, cleared whenever the line changes.
+    galley: Option<Arc<Galley>>,
+}
+
+impl Line {
+    fn blank(cols: usize) -> Self {
+        Self {
+            cells: vec![TerminalCell::default(); cols],
+            damaged: true,
+            galley: None,
+        }
+    }
+
+    fn damage(&mut self) {
+        self.damaged = true;
+        self.galley = None;
+    }
+
+    fn text(&self) -> String {
+        let text: String = self.cells.iter().map(|cell| cell.ch).collect();
+        text.trim_end().to_owned()
+    }
+}
+
+/// The contents of a terminal: a grid of cells on screen, plus scrollback.
+///
+/// Write to it with [`Self::write_str`] or [`Self::set_cell`], and show it with [`TerminalView`].
+///
+/// Every change marks the affected lines as damaged.
+/// Only damaged lines are laid out again when painting,
+/// and [`Self::take_damage`] reports them, e.g. for mirroring the screen elsewhere.
+#[derive(Clone)]
+pub struct TerminalGrid {
+    cols: usize,
+    rows: usize,
+    scrollback_limit: usize,
+
+    /// Scrollback followed by the `rows` lines on screen.
+    lines: VecDeque<Line>,
+
+    /// Column and row of the cursor, on screen.
+    cursor: [usize; 2],
+
+    /// Show the cursor?
+    pub cursor_visible: bool,
+
+    /// Style used by [`Self::write_str`].
+    pen: TerminalCell,
+
+    /// Start and end (inclusive) of the selection.
+    selection: Option<(GridPos, GridPos)>,
+
+    /// What the cached galleys were laid out with.
+    layout_key: Option<Id>,
+}
+
+impl TerminalGrid {
+    /// A blank grid of `cols` by `rows` cells, with 10 000 lines of scrollback.
+    pub fn new(cols: usize, rows: usize) -> Self {
+        let cols = cols.max(1);
+        let rows = rows.max(1);
+        Self {
+            cols,
+            rows,
+            scrollback_limit: 10_000,
+            lines: (0..rows).map(|_| Line::blank(cols)).collect(),
+            cursor: [0, 0],
+            cursor_visible: true,
+            pen: TerminalCell::default(),
+            selection: None,
+            layout_key: None,
+        }
+    }
+
+    /// How many lines that have scrolled off the screen are kept.
+    #[inline]
+    pub fn with_scrollback_limit(mut self, scrollback_limit: usize) -> Self {
+        self.scrollback_limit = scrollback_limit;
+        self.trim_scrollback();
+        self
+    }
+
+    /// Columns and rows on screen.
+    pub fn size(&self) -> [usize; 2] {
+        [self.cols, self.rows]
+    }
+
+    /// Total number of lines, including scrollback.
+    pub fn num_lines(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// The [`GridPos::line`] of the top row on screen.
+    pub fn first_screen_line(&self) -> usize {
+        self.lines.len() - self.rows
+    }
+
+    /// Change the number of columns and rows on screen.
+    ///
+    /// Lines are truncated or padded. If the screen shrinks, the top lines move into the scrollback.
+    pub fn resize(&mut self, cols: usize, rows: usize) {
+        let cols = cols.max(1);
+        let rows = rows.max(1);
+        if [cols, rows] == [self.cols, self.rows] {
+            return;
+        }
+
+        for line in &mut self.lines {
+            line.cells.resize(cols, TerminalCell::default());
+            line.damage();
+        }
+        while self.lines.len() < rows {
+            self.lines.push_back(Line::blank(cols));
+        }
+
+        // Keep the cursor on the same line, if possible:
+        let cursor_line = self.first_screen_line() + self.cursor[1];
+        self.cols = cols;
+        self.rows = rows;
+        let first = self.first_screen_line();
+        self.cursor = [
+            self.cursor[0].min(cols - 1),
+            cursor_line.saturating_sub(first).min(rows - 1),
+        ];
+        self.trim_scrollback();
+    }
+
+    /// The cell at the given position, if any.
+    pub fn cell(&self, pos: GridPos) -> Option<&TerminalCell> {
+        self.lines.get(pos.line)?.cells.get(pos.col)
+    }
+
+    /// Set a cell on screen. Out-of-bounds positions are ignored.
+    pub fn set_cell(&mut self, col: usize, row: usize, cell: TerminalCell) {
+        let first = self.first_screen_line();
+        if let Some(line) = self.lines.get_mut(first + row) {
+            if let Some(target) = line.cells.get_mut(col) {
+                if *target != cell {
+                    *target = cell;
+                    line.damage();
+                }
+            }
+        }
+    }
+
+    /// The text of a line, without trailing spaces.
+    pub fn line_text(&self, line: usize) -> Option<String> {
+        self.lines.get(line).map(Line::text)
+    }
+
+    /// Column and row of the cursor, on screen.
+    pub fn cursor(&self) -> [usize; 2] {
+        self.cursor
+    }
+
+    /// Move the cursor, clamped to the screen.
+    pub fn set_cursor(&mut self, col: usize, row: usize) {
+        self.cursor = [col.min(self.cols - 1), row.min(self.rows - 1)];
+    }
+
+    /// Set the colors and attributes used by [`Self::write_str`].
+    pub fn set_style(&mut self, fg: Option<Color32>, bg: Option<Color32>, attrs: CellAttributes) {
+        self.pen = TerminalCell {
+            ch: ' ',
+            fg,
+            bg,
+            attrs,
+        };
+    }
+
+    /// Write text at the cursor, using the style set with [`Self::set_style`].
+    ///
+    /// Handles `\n` (new line, also returning to the first column), `\r`, `\t` and backspace.
+    /// Text wraps at the right edge, and the screen scrolls when the cursor moves past the bottom.
+    /// Escape sequences are not interpreted: parse them yourself and use the other methods.
+    pub fn write_str(&mut self, text: &str) {
+        for ch in text.chars() {
+            match ch {
+                '\n' => {
+                    self.cursor[0] = 0;
+                    self.line_feed();
+                }
+                '\r' => self.cursor[0] = 0,
+                '\t' => self.cursor[0] = ((self.cursor[0] / 8 + 1) * 8).min(self.cols - 1),
+                '\x08' => self.cursor[0] = self.cursor[0].saturating_sub(1),
+                ch if ch.is_control() => {}
+                ch => {
+                    if self.cursor[0] >= self.cols {
+                        self.cursor[0] = 0;
+                        self.line_feed();
+                    }
+                    let [col, row] = self.cursor;
+                    self.set_cell(col, row, TerminalCell { ch, ..self.pen });
+                    self.cursor[0] += 1;
+                }
+            }
+        }
+        // The cursor may sit just past the last column until the next character wraps:
+        self.cursor[0] = self.cursor[0].min(self.cols);
+    }
+
+    /// Move the cursor down one row, scrolling if it is at the bottom.
+    pub fn line_feed(&mut self) {
+        if self.cursor[1] + 1 < self.rows {
+            self.cursor[1] += 1;
+        } else {
+            self.scroll_up(1);
+        }
+    }
+
+    /// Scroll the screen up by `n` lines, moving the top lines into the scrollback.
+    pub fn scroll_up(&mut self, n: usize) {
+        for _ in 0..n {
+            self.lines.push_back(Line::blank(self.cols));
+        }
+        self.trim_scrollback();
+    }
+
+    /// Clear all cells on screen and move the cursor to the top left.
+    pub fn clear_screen(&mut self) {
+        let first = self.first_screen_line();
+        for line in self.lines.range_mut(first..) {
+            *line = Line::blank(self.cols);
+        }
+        self.cursor = [0, 0];
+    }
+
+    /// Forget all lines that have scrolled off the screen.
+    pub fn clear_scrollback(&mut self) {
+        let first = self.first_screen_line();
+        self.lines.drain(..first);
+        self.shift_selection(first);
+    }
+
+    /// Lines changed since the last call, as [`GridPos::line`] indices.
+    pub fn take_damage(&mut self) -> Vec<usize> {
+        self.lines
+            .iter_mut()
+            .enumerate()
+            .filter(|(_, line)| std::mem::take(&mut line.damaged))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Find all occurrences of `needle`, as start and end (inclusive) positions.
+    ///
+    /// Matches do not span multiple lines.
+    pub fn find(&self, needle: &str, case_sensitive: bool) -> Vec<(GridPos, GridPos)> {
+        let needle: Vec<char> = if case_sensitive {
+            needle.chars().collect()
+        } else {
+            needle.to_lowercase().chars().collect()
+        };
+        if needle.is_empty() {
+            return Vec::new();
+        }
+
+        let mut matches = Vec::new();
+        for (line_idx, line) in self.lines.iter().enumerate() {
+            let chars: Vec<char> = line
+                .cells
+                .iter()
+                .map(|cell| {
+                    if case_sensitive {
+                        cell.ch
+                    } else {
+                        cell.ch.to_lowercase().next().unwrap_or(cell.ch)
+                    }
+                })
+                .collect();
+            for (col, window) in chars.windows(needle.len()).enumerate() {
+                if window == needle.as_slice() {
+                    matches.push((
+                        GridPos::new(line_idx, col),
+                        GridPos::new(line_idx, col + needle.len() - 1),
+                    ));
+                }
+            }
+        }
+        matches
+    }
+
+    /// The selected cells, as start and end (inclusive), in reading order.
+    pub fn selection(&self) -> Option<(GridPos, GridPos)> {
+        self.selection.map(|(a, b)| (a.min(b), a.max(b)))
+    }
+
+    pub fn set_selection(&mut self, selection: Option<(GridPos, GridPos)>) {
+        self.selection = selection;
+    }
+
+    /// The selected text.
+    ///
+    /// The selection follows the grid: it runs from the start cell to the end of its line,
+    /// through whole lines, to the end cell. Trailing spaces are removed from each line.
+    pub fn selected_text(&self) -> Option<String> {
+        let (start, end) = self.selection()?;
+        let mut lines = Vec::new();
+        for line_idx in start.line..=end.line.min(self.lines.len().saturating_sub(1)) {
+            let cells = &self.lines[line_idx].cells;
+            let from = if line_idx == start.line { start.col } else { 0 };
+            let to = if line_idx == end.line {
+                (end.col + 1).min(cells.len())
+            } else {
+                cells.len()
+            };
+            let text: String = cells
+                .get(from..to)
+                .unwrap_or_default()
+                .iter()
+                .map(|cell| cell.ch)
+                .collect();
+            lines.push(text.trim_end().to_owned());
+        }
+        Some(lines.join("\n"))
+    }
+
+    fn is_selected(&self, pos: GridPos) -> bool {
+        self.selection()
+            .is_some_and(|(start, end)| start <= pos && pos <= end)
+    }
+
+    fn trim_scrollback(&mut self) {
+        let max_lines = self.rows + self.scrollback_limit;
+        if self.lines.len() > max_lines {
+            let excess = self.lines.len() - max_lines;
+            self.lines.drain(..excess);
+            self.shift_selection(excess);
+        }
+    }
+
+    /// Lines were removed from the front.
+    fn shift_selection(&mut self, removed: usize) {
+        if let Some((a, b)) = self.selection {
+            self.selection = (a.line >= removed && b.line >= removed).then(|| {
+                (
+                    GridPos::new(a.line - removed, a.col),
+                    GridPos::new(b.line - removed, b.col),
+                )
+            });
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Shows a [`TerminalGrid`], with scrollback, a blinking cursor, and mouse selection.
+///
+/// Click the view to focus it; copying (e.g. with Ctrl+C) then copies the selected text.
+/// The view does not handle keyboard input for the terminal itself:
+/// read the key events while [`Response::has_focus`] and send them to your process.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let mut grid = egui_extras::TerminalGrid::new(80, 24);
+/// grid.write_str("$ cargo build\n   Compiling egui\n");
+/// egui_extras::TerminalView::new(&mut grid).show(ui);
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct TerminalView<'a> {
+    grid: &'a mut TerminalGrid,
+    id_salt: Option<Id>,
+    font_id: Option<FontId>,
+    cursor_blink: bool,
+    highlights: Vec<(GridPos, GridPos)>,
+}
+
+impl<'a> TerminalView<'a> {
+    pub fn new(grid: &'a mut TerminalGrid) -> Self {
+        Self {
+            grid,
+            id_salt: None,
+            font_id: None,
+            cursor_blink: true,
+            highlights: Vec::new(),
+        }
+    }
+
+    /// Needed if you show several terminals in the same [`Ui`].
+    #[inline]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
+    /// The font to use. Should be monospace.
+    ///
+    /// Default: [`TextStyle::Monospace`].
+    #[inline]
+    pub fn font(mut self, font_id: FontId) -> Self {
+        self.font_id = Some(font_id);
+        self
+    }
+
+    /// Blink the cursor?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn cursor_blink(mut self, cursor_blink: bool) -> Self {
+        self.cursor_blink = cursor_blink;
+        self
+    }
+
+    /// Highlight these ranges, e.g. the results of [`TerminalGrid::find`].
+    #[inline]
+    pub fn highlights(mut self, highlights: Vec<(GridPos, GridPos)>) -> Self {
+        self.highlights = highlights;
+        self
+    }
+
+    /// Show the grid. Scrolls to the bottom when new output arrives, unless the user scrolled up.
+    pub fn show(self, ui: &mut Ui) -> Response {
+        let Self {
+            grid,
+            id_salt,
+            font_id,
+            cursor_blink,
+            highlights,
+        } = self;
+
+        let id = ui.make_persistent_id(id_salt.unwrap_or_else(|| Id::new("terminal")));
+        let font_id = font_id.unwrap_or_else(|| TextStyle::Monospace.resolve(ui.style()));
+        let (cell_width, row_height) =
+            ui.fonts(|f| (f.glyph_width(&font_id, 'M'), f.row_height(&font_id)));
+
+        let visuals = ui.visuals();
+        let default_fg = visuals.text_color();
+        let strong_fg = visuals.strong_text_color();
+        let default_bg = visuals.extreme_bg_color;
+        let selection_bg = visuals.selection.bg_fill;
+        let highlight_bg = visuals.warn_fg_color.gamma_multiply(0.4);
+
+        let layout_key = Id::new((
+            &font_id,
+            default_fg,
+            strong_fg,
+            default_bg,
+            ui.ctx().pixels_per_point().to_bits(),
+        ));
+        if grid.layout_key != Some(layout_key) {
+            for line in &mut grid.lines {
+                line.galley = None;
+            }
+            grid.layout_key = Some(layout_key);
+        }
+
+        let [cols, rows] = grid.size();
+        let num_lines = grid.num_lines();
+
+        ui.scope(|ui| {
+            ui.spacing_mut().item_spacing.y = 0.0;
+            ScrollArea::vertical()
+                .id_salt(id.with("scroll"))
+                .max_height(rows as f32 * row_height)
+                .stick_to_bottom(true)
+                .auto_shrink([false, true])
+                .show_rows(ui, row_height, num_lines, |ui, line_range| {
+                    let size = vec2(
+                        cols as f32 * cell_width,
+                        line_range.len() as f32 * row_height,
+                    );
+                    let (rect, response) = ui.allocate_exact_size(size, Sense::click_and_drag());
+
+                    let grid_pos_at = |pos: egui::Pos2| {
+                        let line = line_range.start
+                            + ((pos.y - rect.top()) / row_height).max(0.0) as usize;
+                        let col = ((pos.x - rect.left()) / cell_width).max(0.0) as usize;
+                        GridPos::new(line.min(num_lines - 1), col.min(cols - 1))
+                    };
+
+                    if response.clicked() {
+                        response.request_focus();
+                        grid.set_selection(None);
+                    }
+                    if response.drag_started() {
+                        response.request_focus();
+                        if let Some(pos) = ui.input(|i| i.pointer.press_origin()) {
+                            let anchor = grid_pos_at(pos);
+                            grid.set_selection(Some((anchor, anchor)));
+                        }
+                    }
+                    if response.dragged() {
+                        if let (Some((anchor, _)), Some(pos)) =
+                            (grid.selection, response.interact_pointer_pos())
+                        {
+                            grid.set_selection(Some((anchor, grid_pos_at(pos))));
+                        }
+                    }
+                    if response.has_focus() && ui.input(|i| i.events.contains(&Event::Copy)) {
+                        if let Some(text) = grid.selected_text() {
+                            ui.ctx().copy_text(text);
+                        }
+                    }
+
+                    let painter = ui.painter_at(rect);
+                    painter.rect_filled(rect, 0.0, default_bg);
+
+                    for (i, line_idx) in line_range.clone().enumerate() {
+                        let top = rect.top() + i as f32 * row_height;
+                        let cell_rect = |col: usize, len: usize| {
+                            Rect::from_min_size(
+                                pos2(rect.left() + col as f32 * cell_width, top),
+                                vec2(len as f32 * cell_width, row_height),
+                            )
+                        };
+
+                        // Backgrounds, in runs of equal color:
+                        let mut col = 0;
+                        while col < cols {
+                            let bg_at = |col: usize| {
+                                let pos = GridPos::new(line_idx, col);
+                                if grid.is_selected(pos) {
+                                    Some(selection_bg)
+                                } else if highlights.iter().any(|(a, b)| *a <= pos && pos <= *b) {
+                                    Some(highlight_bg)
+                                } else {
+                                    let cell = grid.cell(pos).copied().unwrap_or_default();
+                                    if cell.attrs.inverse {
+                                        Some(cell.fg.unwrap_or(default_fg))
+                                    } else {
+                                        cell.bg
+                                    }
+                                }
+                            };
+                            let bg = bg_at(col);
+                            let run = (col + 1..cols).take_while(|&c| bg_at(c) == bg).count() + 1;
+                            if let Some(bg) = bg {
+                                painter.rect_filled(cell_rect(col, run), 0.0, bg);
+                            }
+                            col += run;
+                        }
+
+                        let line = &mut grid.lines[line_idx];
+                        let galley = line
+                            .galley
+                            .get_or_insert_with(|| {
+                                let job = line_layout_job(
+                                    &line.cells,
+                                    &font_id,
+                                    default_fg,
+                                    strong_fg,
+                                    default_bg,
+                                );
+                                ui.fonts(|f| f.layout_job(job))
+                            })
+                            .clone();
+                        painter.galley(pos2(rect.left(), top), galley, default_fg);
+                    }
+
+                    // Cursor:
+                    let [cursor_col, cursor_row] = grid.cursor();
+                    let cursor_line = grid.first_screen_line() + cursor_row;
+                    if grid.cursor_visible && line_range.contains(&cursor_line) {
+                        let time = ui.input(|i| i.time);
+                        let blink_on = !cursor_blink || (time * 2.0) as i64 % 2 == 0;
+                        if cursor_blink {
+                            let until_toggle = 0.5 - time % 0.5;
+                            ui.ctx()
+                                .request_repaint_after(std::time::Duration::from_secs_f64(
+                                    until_toggle,
+                                ));
+                        }
+                        if blink_on {
+                            let cursor_rect = Rect::from_min_size(
+                                pos2(
+                                    rect.left() + cursor_col.min(cols - 1) as f32 * cell_width,
+                                    rect.top()
+                                        + (cursor_line - line_range.start) as f32 * row_height,
+                                ),
+                                vec2(cell_width, row_height),
+                            );
+                            if response.has_focus() {
+                                painter.rect_filled(
+                                    cursor_rect,
+                                    0.0,
+                                    default_fg.gamma_multiply(0.6),
+                                );
+                            } else {
+                                painter.rect_stroke(
+                                    cursor_rect,
+                                    0.0,
+                                    Stroke::new(1.0, default_fg),
+                                    egui::StrokeKind::Inside,
+                                );
+                            }
+                        }
+                    }
+
+                    response
+                })
+                .inner
+        })
+        .inner
+    }
+}
+
+/// Lay out one line, in runs of equal style.
+fn line_layout_job(
+    cells: &[TerminalCell],
+    font_id: &FontId,
+    default_fg: Color32,
+    strong_fg: Color32,
+    default_bg: Color32,
+) -> LayoutJob {
+    let format_of = |cell: &TerminalCell| {
+        let attrs = cell.attrs;
+        let mut color = match cell.fg {
+            Some(fg) => fg,
+            None if attrs.bold => strong_fg,
+            None => default_fg,
+        };
+        if attrs.inverse {
+            color = cell.bg.unwrap_or(default_bg);
+        }
+        if attrs.dim {
+            color = color.gamma_multiply(0.6);
+        }
+        let line_stroke = |enabled: bool| {
+            if enabled {
+                Stroke::new(1.0, color)
+            } else {
+                Stroke::NONE
+            }
+        };
+        TextFormat {
+            font_id: font_id.clone(),
+            color,
+            italics: attrs.italic,
+            underline: line_stroke(attrs.underline),
+            strikethrough: line_stroke(attrs.strikethrough),
+            ..Default::default()
+        }
+    };
+
+    let mut job = LayoutJob::default();
+    let mut run = String::new();
+    let mut run_format: Option<TextFormat> = None;
+    for cell in cells {
+        let format = format_of(cell);
+        if run_format.as_ref().is_some_and(|f| *f != format) {
+            job.append(&run, 0.0, run_format.take().unwrap_or_default());
+            run.clear();
+        }
+        run_format = Some(format);
+        run.push(cell.ch);
+    }
+    if let Some(format) = run_format {
+        job.append(&run, 0.0, format);
+    }
+    job
+}
+
+#[test]
+fn terminal_grid_writing_and_selection() {
+    let mut grid = TerminalGrid::new(5, 2).with_scrollback_limit(1);
+    grid.write_str("hello world");
+    assert_eq!(grid.line_text(0).as_deref(), Some("hello"));
+    assert_eq!(grid.line_text(1).as_deref(), Some(" worl"));
+    assert_eq!(grid.line_text(2).as_deref(), Some("d"));
+    assert_eq!(grid.cursor(), [1, 1]);
+
+    assert_eq!(
+        grid.find("WOR", false),
+        vec![(GridPos::new(1, 1), GridPos::new(1, 3))]
+    );
+
+    grid.set_selection(Some((GridPos::new(2, 0), GridPos::new(0, 3))));
+    assert_eq!(grid.selected_text().as_deref(), Some("lo\n worl\nd"));
+
+    let damage = grid.take_damage();
+    assert!(!damage.is_empty());
+    assert!(grid.take_damage().is_empty());
+
+    grid.write_str("\n");
+    assert_eq!(grid.num_lines(), 3, "Scrollback is limited to one line");
+    assert_eq!(grid.line_text(0).as_deref(), Some(" worl"));
+    assert_eq!(
+        grid.selection(),
+        None,
+        "Selection scrolled out of the scrollback"
+    );
+}
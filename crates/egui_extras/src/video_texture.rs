@@ -0,0 +1,301 @@
+//! Streaming video frames from a decoder thread into an egui texture.
+
+use std::sync::{Arc, Weak};
+
+use egui::{
+    Color32, ColorImage, Context, ImageSource, TextureHandle, TextureOptions, load::SizedTexture,
+    mutex::Mutex,
+};
+
+/// How the YUV values of a [`VideoFrame`] map to RGB.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum YuvMatrix {
+    /// ITU-R BT.601, limited range. Common for SD video.
+    #[default]
+    Bt601,
+
+    /// ITU-R BT.709, limited range. Common for HD video.
+    Bt709,
+}
+
+impl YuvMatrix {
+    fn to_rgb(self, y: u8, u: u8, v: u8) -> Color32 {
+        let c = 1.164 * (f32::from(y) - 16.0);
+        let d = f32::from(u) - 128.0;
+        let e = f32::from(v) - 128.0;
+        let (r, g, b) = match self {
+            Self::Bt601 => (c + 1.596 * e, c - 0.392 * d - 0.813 * e, c + 2.017 * d),
+            Self::Bt709 => (c + 1.793 * e, c - 0.213 * d - 0.533 * e, c + 2.112 * d),
+        };
+        let to_u8 = |x: f32| x.round().clamp(0.0, 255.0) as u8;
+        Color32::from_rgb(to_u8(r), to_u8(g), to_u8(b))
+    }
+}
+
+/// The pixel layout of a [`VideoFrame`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VideoFrameFormat {
+    /// Interleaved, unmultiplied RGBA, 4 bytes per pixel.
+    Rgba,
+
+    /// Interleaved RGB, 3 bytes per pixel.
+    Rgb,
+
+    /// Planar YUV 4:2:0 (I420): the full-size Y plane,
+    /// followed by the U and V planes at half width and half height (rounded up).
+    Yuv420(YuvMatrix),
+
+    /// Semi-planar YUV 4:2:0 (NV12): the full-size Y plane,
+    /// followed by interleaved U/V pairs at half width and half height (rounded up).
+    Nv12(YuvMatrix),
+}
+
+/// A decoded video frame, with tightly packed rows.
+#[derive(Clone, Debug)]
+pub struct VideoFrame {
+    /// Width and height in pixels.
+    pub size: [usize; 2],
+
+    pub format: VideoFrameFormat,
+
+    /// The pixel data, laid out as described by [`Self::format`].
+    pub data: Vec<u8>,
+}
+
+impl VideoFrame {
+    pub fn new(size: [usize; 2], format: VideoFrameFormat, data: Vec<u8>) -> Self {
+        Self { size, format, data }
+    }
+
+    /// The number of bytes [`Self::data`] must have for the size and format.
+    pub fn expected_len(&self) -> usize {
+        let [w, h] = self.size;
+        let chroma = w.div_ceil(2) * h.div_ceil(2);
+        match self.format {
+            VideoFrameFormat::Rgba => 4 * w * h,
+            VideoFrameFormat::Rgb => 3 * w * h,
+            VideoFrameFormat::Yuv420(_) | VideoFrameFormat::Nv12(_) => w * h + 2 * chroma,
+        }
+    }
+
+    /// Convert to an RGBA image.
+    ///
+    /// Returns `None` if [`Self::data`] has the wrong length.
+    pub fn to_color_image(&self) -> Option<ColorImage> {
+        profiling::function_scope!();
+
+        if self.data.len() != self.expected_len() {
+            return None;
+        }
+
+        let [w, h] = self.size;
+        let image = match self.format {
+            VideoFrameFormat::Rgba => ColorImage::from_rgba_unmultiplied(self.size, &self.data),
+            VideoFrameFormat::Rgb => ColorImage::from_rgb(self.size, &self.data),
+            VideoFrameFormat::Yuv420(matrix) | VideoFrameFormat::Nv12(matrix) => {
+                let (luma, chroma) = self.data.split_at(w * h);
+                let chroma_width = w.div_ceil(2);
+                let plane_len = chroma_width * h.div_ceil(2);
+                let chroma_at = |x: usize, y: usize| {
+                    let i = (y / 2) * chroma_width + x / 2;
+                    match self.format {
+                        VideoFrameFormat::Nv12(_) => (chroma[2 * i], chroma[2 * i + 1]),
+                        _ => (chroma[i], chroma[plane_len + i]),
+                    }
+                };
+
+                let mut pixels = Vec::with_capacity(w * h);
+                for y in 0..h {
+                    for x in 0..w {
+                        let (u, v) = chroma_at(x, y);
+                        pixels.push(matrix.to_rgb(luma[y * w + x], u, v));
+                    }
+                }
+                ColorImage::new(self.size, pixels)
+            }
+        };
+        Some(image)
+    }
+}
+
+/// The frame waiting to be uploaded.
+#[derive(Default)]
+struct Mailbox {
+    image: Option<ColorImage>,
+
+    /// Frames that were replaced before they could be shown.
+    dropped: usize,
+}
+
+/// Hands frames from a decoder thread to a [`VideoTexture`].
+///
+/// Can be cloned and sent to other threads.
+#[derive(Clone)]
+pub struct VideoFrameSender {
+    ctx: Context,
+    mailbox: Weak<Mutex<Mailbox>>,
+}
+
+impl VideoFrameSender {
+    /// Convert the frame to RGBA (on the calling thread) and hand it over for upload.
+    ///
+    /// Only the latest frame is kept: if the UI has not picked up the previous frame yet,
+    /// it is dropped. A repaint is requested.
+    ///
+    /// Returns `false` if the [`VideoTexture`] has been dropped, so the decoder can stop,
+    /// or if the frame data has the wrong length.
+    pub fn send(&self, frame: &VideoFrame) -> bool {
+        let Some(image) = frame.to_color_image() else {
+            log::warn!(
+                "Video frame of size {:?} and format {:?} has {} bytes, expected {}",
+                frame.size,
+                frame.format,
+                frame.data.len(),
+                frame.expected_len()
+            );
+            return false;
+        };
+        self.send_image(image)
+    }
+
+    /// Like [`Self::send`], for frames that are already RGBA.
+    pub fn send_image(&self, image: ColorImage) -> bool {
+        let Some(mailbox) = self.mailbox.upgrade() else {
+            return false;
+        };
+        {
+            let mut mailbox = mailbox.lock();
+            if mailbox.image.replace(image).is_some() {
+                mailbox.dropped += 1;
+            }
+        }
+        self.ctx.request_repaint();
+        true
+    }
+}
+
+/// A texture showing a video stream.
+///
+/// Frames are produced on another thread and handed over with a [`VideoFrameSender`].
+/// Each frame, call [`Self::update`] to upload the latest frame (if any),
+/// and paint the texture with [`Self::image_source`]:
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let mut video = egui_extras::VideoTexture::new(ui.ctx(), "video", Default::default());
+/// let sender = video.sender();
+/// std::thread::spawn(move || {
+///     // In a real app, this is your decoder loop:
+///     let frame = egui_extras::VideoFrame::new(
+///         [2, 2],
+///         egui_extras::VideoFrameFormat::Rgb,
+///         vec![255; 12],
+///     );
+///     sender.send(&frame);
+/// });
+///
+/// // Every frame:
+/// video.update();
+/// ui.image(video.image_source());
+/// # });
+/// ```
+///
+/// The previous frame stays in the texture until the next one is uploaded,
+/// so the decoder writes into one buffer while the GPU shows the other.
+/// Color conversion happens on the decoder thread, in [`VideoFrameSender::send`].
+/// To convert on the GPU instead, upload the planes yourself and paint them
+/// with an [`egui::PaintCallback`] for your rendering backend.
+pub struct VideoTexture {
+    ctx: Context,
+    texture: TextureHandle,
+    options: TextureOptions,
+    mailbox: Arc<Mutex<Mailbox>>,
+    frames_shown: usize,
+}
+
+impl VideoTexture {
+    /// Create a texture for the video. It is black until the first frame arrives.
+    pub fn new(ctx: &Context, name: impl Into<String>, options: TextureOptions) -> Self {
+        let texture = ctx.load_texture(name, ColorImage::filled([1, 1], Color32::BLACK), options);
+        Self {
+            ctx: ctx.clone(),
+            texture,
+            options,
+            mailbox: Default::default(),
+            frames_shown: 0,
+        }
+    }
+
+    /// Create a sender for the decoder thread.
+    pub fn sender(&self) -> VideoFrameSender {
+        VideoFrameSender {
+            ctx: self.ctx.clone(),
+            mailbox: Arc::downgrade(&self.mailbox),
+        }
+    }
+
+    /// Upload the latest frame, if a new one has arrived.
+    ///
+    /// Returns `true` if the texture changed.
+    pub fn update(&mut self) -> bool {
+        let Some(image) = self.mailbox.lock().image.take() else {
+            return false;
+        };
+        self.texture.set(image, self.options);
+        self.frames_shown += 1;
+        true
+    }
+
+    /// The texture, for painting with [`egui::Image`] or [`egui::Ui::image`].
+    pub fn image_source(&self) -> ImageSource<'static> {
+        ImageSource::Texture(SizedTexture::from_handle(&self.texture))
+    }
+
+    pub fn texture(&self) -> &TextureHandle {
+        &self.texture
+    }
+
+    /// The size of the current frame, in pixels.
+    pub fn size(&self) -> [usize; 2] {
+        self.texture.size()
+    }
+
+    /// How many frames have been uploaded so far.
+    pub fn frames_shown(&self) -> usize {
+        self.frames_shown
+    }
+
+    /// How many frames were dropped because a newer one arrived before [`Self::update`] was called.
+    pub fn frames_dropped(&self) -> usize {
+        self.mailbox.lock().dropped
+    }
+}
+
+#[test]
+fn yuv_conversion() {
+    // 2x2 gray frame:
+    let gray = VideoFrame::new(
+        [2, 2],
+        VideoFrameFormat::Yuv420(YuvMatrix::Bt601),
+        vec![126, 126, 126, 126, 128, 128],
+    );
+    let image = gray.to_color_image().unwrap();
+    assert_eq!(image.pixels, vec![Color32::from_gray(128); 4]);
+
+    // Odd sizes round the chroma planes up:
+    let nv12 = VideoFrame::new(
+        [3, 1],
+        VideoFrameFormat::Nv12(YuvMatrix::Bt709),
+        vec![235, 235, 235, 128, 128, 128, 128],
+    );
+    assert_eq!(
+        nv12.to_color_image().unwrap().pixels,
+        vec![Color32::WHITE; 3]
+    );
+
+    assert!(
+        VideoFrame::new([2, 2], VideoFrameFormat::Rgb, vec![0; 4])
+            .to_color_image()
+            .is_none()
+    );
+}
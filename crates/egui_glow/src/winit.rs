@@ -75,6 +75,7 @@ impl EguiGlow {
             shapes,
             pixels_per_point,
             viewport_output,
+            stats: _,
         } = self.egui_ctx.run(raw_input, run_ui);
 
         if viewport_output.len() > 1 {
@@ -51,6 +51,29 @@ fn button_node() {
     assert!(!button.is_disabled());
 }
 
+#[test]
+fn button_description_and_keyboard_hint() {
+    let output = accesskit_output_single_egui_frame(|ctx| {
+        CentralPanel::default().show(ctx, |ui| {
+            ui.button("Save")
+                .accessibility_description("Save the document")
+                .keyboard_hint(egui::KeyboardShortcut::new(
+                    egui::Modifiers::CTRL,
+                    egui::Key::S,
+                ));
+        });
+    });
+
+    let (_, button) = output
+        .nodes
+        .iter()
+        .find(|(_, node)| node.role() == Role::Button)
+        .expect("Button should exist in the accesskit output");
+
+    assert_eq!(button.description(), Some("Save the document"));
+    assert!(button.keyboard_shortcut().is_some());
+}
+
 #[test]
 fn disabled_button_node() {
     let button_text = "This is a test button!";
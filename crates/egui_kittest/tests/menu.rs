@@ -184,3 +184,61 @@ fn menu_snapshots() {
     harness.run();
     results.add(harness.try_snapshot("menu/subsubmenu"));
 }
+
+#[test]
+fn show_menus() {
+    use egui::containers::menu::{NativeMenu, NativeMenuButton};
+    use egui::{Event, Id, Key, KeyboardShortcut, Modifiers};
+
+    let menus = vec![
+        NativeMenu::new("File")
+            .button(
+                NativeMenuButton::new(Id::new("open"), "Open")
+                    .shortcut(KeyboardShortcut::new(Modifiers::COMMAND, Key::O)),
+            )
+            .separator()
+            .sub_menu(
+                NativeMenu::new("Recent")
+                    .button(NativeMenuButton::new(Id::new("recent"), "Recent file")),
+            ),
+    ];
+
+    let mut harness = Harness::new_ui_state(
+        |ui, clicked: &mut Option<Id>| {
+            if let Some(id) = MenuBar::new().show_menus(ui, &menus) {
+                *clicked = Some(id);
+            }
+        },
+        None,
+    );
+
+    harness.get_by_label("File").click();
+    harness.run();
+    harness.get_by_label_contains("Open").click();
+    harness.run();
+    assert_eq!(harness.state_mut().take(), Some(Id::new("open")));
+
+    // Shortcuts work with the menu closed:
+    harness.key_press_modifiers(Modifiers::COMMAND, Key::O);
+    harness.run();
+    assert_eq!(harness.state_mut().take(), Some(Id::new("open")));
+
+    // With a native menu bar, the menus go to the integration instead:
+    harness.input_mut().native_menu_bar = true;
+    harness.step();
+    assert_eq!(
+        harness.output().platform_output.native_menu_bar.as_ref(),
+        Some(&menus)
+    );
+    assert!(harness.query_by_label("File").is_none());
+
+    harness.step();
+    assert!(harness.output().platform_output.native_menu_bar.is_none());
+
+    harness
+        .input_mut()
+        .events
+        .push(Event::NativeMenuButton(Id::new("recent")));
+    harness.step();
+    assert_eq!(harness.state_mut().take(), Some(Id::new("recent")));
+}
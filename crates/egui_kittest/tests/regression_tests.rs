@@ -157,3 +157,182 @@ pub fn slider_should_move_with_fixed_decimals() {
     let actual_slider = harness.get_by_role(accesskit::Role::SpinButton);
     assert_eq!(actual_slider.value(), Some("1.00".to_owned()));
 }
+
+#[test]
+pub fn read_only_should_not_change_values() {
+    let mut harness = Harness::new_ui_state(
+        |ui, (checked, text): &mut (bool, String)| {
+            ui.read_only(|ui| {
+                ui.checkbox(checked, "Check me");
+                ui.text_edit_singleline(text);
+            });
+        },
+        (false, "Ferris".to_owned()),
+    );
+
+    harness.get_by_label("Check me").click();
+    harness.run();
+
+    harness.get_by_role(Role::TextInput).focus();
+    harness.run();
+    harness.get_by_role(Role::TextInput).type_text(" the crab");
+    harness.run();
+
+    assert_eq!(harness.state(), &(false, "Ferris".to_owned()));
+}
+
+#[test]
+pub fn selection_should_go_on_from_read_only_text_edit_into_label() {
+    let mut harness = Harness::new_ui(|ui| {
+        let mut text = "Ferris";
+        ui.text_edit_singleline(&mut text);
+        ui.label("the crab");
+    });
+
+    let from = harness.get_by_role(Role::TextInput).rect().left_center() + Vec2::new(1.0, 0.0);
+    let to = harness.get_by_label("the crab").rect().right_center() - Vec2::new(1.0, 0.0);
+    let button = |pos, pressed| egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers: egui::Modifiers::NONE,
+    };
+
+    harness.input_mut().events.push(egui::Event::PointerMoved(from));
+    harness.input_mut().events.push(button(from, true));
+    harness.run();
+    harness.input_mut().events.push(egui::Event::PointerMoved(to));
+    harness.run();
+    harness.input_mut().events.push(button(to, false));
+    harness.run();
+
+    harness.input_mut().events.push(egui::Event::Copy);
+    harness.step();
+
+    let commands = &harness.output().platform_output.commands;
+    let copied = commands.iter().find_map(|command| match command {
+        egui::OutputCommand::CopyHtml { html, text } => Some((html, text)),
+        _ => None,
+    });
+    let Some((html, text)) = copied else {
+        panic!("Expected one copy of both, got {commands:?}");
+    };
+    assert_eq!(text, "Ferris\nthe crab");
+    assert!(html.contains("Ferris</span></span><br><span"), "A line break between them: {html}");
+}
+
+#[test]
+pub fn label_selection_should_be_readable() {
+    let mut harness = Harness::new_ui(|ui| {
+        ui.label("Ferris");
+        ui.label("the crab");
+    });
+    assert_eq!(egui::text_selection::LabelSelectionState::selected_text(&harness.ctx), None);
+
+    let from = harness.get_by_label("Ferris").rect().left_center() + Vec2::new(1.0, 0.0);
+    let to = harness.get_by_label("the crab").rect().right_center() - Vec2::new(1.0, 0.0);
+    let button = |pos, pressed| egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers: egui::Modifiers::NONE,
+    };
+
+    harness.input_mut().events.push(egui::Event::PointerMoved(from));
+    harness.input_mut().events.push(button(from, true));
+    harness.run();
+    harness.input_mut().events.push(egui::Event::PointerMoved(to));
+    harness.run();
+    harness.input_mut().events.push(button(to, false));
+    harness.run();
+
+    assert_eq!(
+        egui::text_selection::LabelSelectionState::selected_text(&harness.ctx),
+        Some("Ferris\nthe crab".to_owned())
+    );
+    let ranges = egui::text_selection::LabelSelectionState::selected_ranges(&harness.ctx);
+    assert_eq!(ranges.iter().map(|(_, r)| r.clone()).collect::<Vec<_>>(), vec![0..6, 0..8]);
+}
+
+#[test]
+pub fn text_edit_should_edit_every_occurrence_selected_with_ctrl_d() {
+    let mut harness = Harness::new_ui_state(
+        |ui, text: &mut String| {
+            ui.text_edit_multiline(text);
+        },
+        "foo bar foo".to_owned(),
+    );
+
+    harness.get_by_role(Role::MultilineTextInput).focus();
+    harness.run();
+
+    // The word at the cursor, which starts at the end:
+    harness.key_press_modifiers(egui::Modifiers::COMMAND, egui::Key::D);
+    harness.run();
+    // Its next occurrence, wrapping around:
+    harness.key_press_modifiers(egui::Modifiers::COMMAND, egui::Key::D);
+    harness.run();
+
+    harness.get_by_role(Role::MultilineTextInput).type_text("baz");
+    harness.run();
+    assert_eq!(harness.state(), "baz bar baz");
+
+    harness.key_press(egui::Key::Backspace);
+    harness.run();
+    assert_eq!(harness.state(), "ba bar ba");
+}
+
+#[test]
+pub fn text_edit_should_drop_filtered_characters() {
+    let mut harness = Harness::new_ui_state(
+        |ui, text: &mut String| {
+            ui.add(
+                egui::TextEdit::singleline(text)
+                    .char_filter(|c| c.is_ascii_digit())
+                    .grapheme_limit(3),
+            );
+        },
+        String::new(),
+    );
+
+    harness.get_by_role(Role::TextInput).focus();
+    harness.run();
+    harness.get_by_role(Role::TextInput).type_text("1a2b34");
+    harness.run();
+    assert_eq!(harness.state(), "123");
+}
+
+#[test]
+pub fn text_edit_should_track_the_ime_composition() {
+    use egui::{Event, ImeEvent};
+
+    let mut harness = Harness::new_ui_state(
+        |ui, (text, composition): &mut (String, Option<std::ops::Range<usize>>)| {
+            let output = egui::TextEdit::singleline(text).show(ui);
+            *composition = output
+                .state
+                .ime_composition()
+                .map(|range| range.as_sorted_char_range());
+        },
+        ("a".to_owned(), None),
+    );
+
+    harness.get_by_role(Role::TextInput).focus();
+    harness.run();
+
+    harness.input_mut().events.extend([
+        Event::Ime(ImeEvent::Enabled),
+        Event::Ime(ImeEvent::Preedit("かな".to_owned())),
+    ]);
+    harness.run();
+    assert_eq!(harness.state().0, "aかな");
+    assert_eq!(harness.state().1, Some(1..3));
+
+    harness
+        .input_mut()
+        .events
+        .push(Event::Ime(ImeEvent::Commit("仮名".to_owned())));
+    harness.run();
+    assert_eq!(harness.state().0, "a仮名");
+    assert_eq!(harness.state().1, None);
+}
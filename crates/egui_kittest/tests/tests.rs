@@ -138,3 +138,32 @@ fn test_scroll_down() {
         "The button was not clicked after scrolling down. (Probably not scrolled enough / at all)"
     );
 }
+
+#[test]
+fn test_inspector_reset_to_default() {
+    use egui::widgets::inspector::Property;
+
+    #[derive(Default)]
+    struct State {
+        speed: f32,
+        changed: Vec<String>,
+    }
+    let mut harness = Harness::new_ui_state(
+        |ui, state| {
+            let response = egui::Inspector::new("inspector").show(ui, |inspector| {
+                inspector.group("Movement", |inspector| {
+                    inspector.add(Property::new("Speed", &mut state.speed).default(1.0));
+                });
+            });
+            state.changed.extend(response.changed_properties);
+        },
+        State::default(),
+    );
+
+    harness.get_by_label("↺").click();
+    harness.run();
+
+    let state = harness.state();
+    assert_eq!(state.speed, 1.0);
+    assert_eq!(state.changed, vec!["Movement/Speed".to_owned()]);
+}
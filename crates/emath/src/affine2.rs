@@ -0,0 +1,205 @@
+use crate::{Pos2, Rot2, TSTransform, Vec2, vec2};
+
+/// A 2D affine transform: any combination of translation, rotation, scaling and shearing.
+///
+/// Positions are transformed as `x_axis * p.x + y_axis * p.y + translation`.
+///
+/// Combine transforms with `*`, which applies the right hand side first:
+///
+/// ```
+/// # use emath::{pos2, vec2, Affine2};
+/// let rotate = Affine2::from_rotation_about(std::f32::consts::FRAC_PI_2, pos2(1.0, 1.0));
+/// let move_right = Affine2::from_translation(vec2(10.0, 0.0));
+/// let p = (move_right * rotate) * pos2(2.0, 1.0);
+/// assert!((p - pos2(11.0, 2.0)).length() < 1e-6);
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+pub struct Affine2 {
+    /// Where the X axis ends up.
+    pub x_axis: Vec2,
+
+    /// Where the Y axis ends up.
+    pub y_axis: Vec2,
+
+    /// Translation, applied last.
+    pub translation: Vec2,
+}
+
+impl Default for Affine2 {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Affine2 {
+    pub const IDENTITY: Self = Self {
+        x_axis: Vec2::X,
+        y_axis: Vec2::Y,
+        translation: Vec2::ZERO,
+    };
+
+    #[inline]
+    pub fn from_translation(translation: Vec2) -> Self {
+        Self {
+            translation,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Scale each axis separately, around `(0, 0)`.
+    #[inline]
+    pub fn from_scale(scale: Vec2) -> Self {
+        Self {
+            x_axis: vec2(scale.x, 0.0),
+            y_axis: vec2(0.0, scale.y),
+            translation: Vec2::ZERO,
+        }
+    }
+
+    /// Rotate clockwise by `angle` radians around `(0, 0)`, like [`Rot2::from_angle`].
+    #[inline]
+    pub fn from_angle(angle: f32) -> Self {
+        let rot = Rot2::from_angle(angle);
+        Self {
+            x_axis: rot * Vec2::X,
+            y_axis: rot * Vec2::Y,
+            translation: Vec2::ZERO,
+        }
+    }
+
+    /// Scale each axis separately, keeping `pivot` where it is.
+    #[inline]
+    pub fn from_scale_about(scale: Vec2, pivot: Pos2) -> Self {
+        Self::from_scale(scale).about(pivot)
+    }
+
+    /// Rotate clockwise by `angle` radians, keeping `pivot` where it is.
+    #[inline]
+    pub fn from_rotation_about(angle: f32, pivot: Pos2) -> Self {
+        Self::from_angle(angle).about(pivot)
+    }
+
+    /// The same transform, but around `pivot` instead of `(0, 0)`.
+    fn about(self, pivot: Pos2) -> Self {
+        let pivot = pivot.to_vec2();
+        Self::from_translation(pivot) * self * Self::from_translation(-pivot)
+    }
+
+    /// How much areas are scaled. Negative if the transform mirrors.
+    #[inline]
+    pub fn determinant(&self) -> f32 {
+        self.x_axis.x * self.y_axis.y - self.y_axis.x * self.x_axis.y
+    }
+
+    /// Is this a valid, invertible transform?
+    pub fn is_valid(&self) -> bool {
+        let det = self.determinant();
+        det.is_finite() && det != 0.0 && self.translation.is_finite()
+    }
+
+    /// Inverts the transform.
+    ///
+    /// The result is garbage if the transform is not [valid](Self::is_valid).
+    ///
+    /// ```
+    /// # use emath::{pos2, vec2, Affine2};
+    /// let t = Affine2::from_rotation_about(1.0, pos2(3.0, 4.0))
+    ///     * Affine2::from_scale(vec2(2.0, 3.0));
+    /// let p = pos2(5.0, -2.0);
+    /// assert!((t.inverse() * (t * p) - p).length() < 1e-5);
+    /// ```
+    pub fn inverse(&self) -> Self {
+        let inv_det = 1.0 / self.determinant();
+        let x_axis = inv_det * vec2(self.y_axis.y, -self.x_axis.y);
+        let y_axis = inv_det * vec2(-self.y_axis.x, self.x_axis.x);
+        let inverse = Self {
+            x_axis,
+            y_axis,
+            translation: Vec2::ZERO,
+        };
+        Self {
+            translation: -inverse.mul_vec(self.translation),
+            ..inverse
+        }
+    }
+
+    /// Transforms the given position.
+    #[inline]
+    pub fn mul_pos(&self, pos: Pos2) -> Pos2 {
+        Pos2::ZERO + self.mul_vec(pos.to_vec2()) + self.translation
+    }
+
+    /// Transforms the given vector, ignoring the translation.
+    #[inline]
+    pub fn mul_vec(&self, vec: Vec2) -> Vec2 {
+        vec.x * self.x_axis + vec.y * self.y_axis
+    }
+}
+
+impl From<TSTransform> for Affine2 {
+    #[inline]
+    fn from(ts: TSTransform) -> Self {
+        Self {
+            x_axis: vec2(ts.scaling, 0.0),
+            y_axis: vec2(0.0, ts.scaling),
+            translation: ts.translation,
+        }
+    }
+}
+
+/// Transforms the position.
+impl std::ops::Mul<Pos2> for Affine2 {
+    type Output = Pos2;
+
+    #[inline]
+    fn mul(self, pos: Pos2) -> Pos2 {
+        self.mul_pos(pos)
+    }
+}
+
+impl std::ops::Mul<Self> for Affine2 {
+    type Output = Self;
+
+    /// Applies the right hand side transform, then the left hand side.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            x_axis: self.mul_vec(rhs.x_axis),
+            y_axis: self.mul_vec(rhs.y_axis),
+            translation: self.mul_vec(rhs.translation) + self.translation,
+        }
+    }
+}
+
+#[test]
+fn test_affine2() {
+    use crate::pos2;
+
+    let p = pos2(3.0, 4.0);
+    assert_eq!(Affine2::IDENTITY * p, p);
+    assert_eq!(Affine2::from_translation(vec2(1.0, 2.0)) * p, pos2(4.0, 6.0));
+    assert_eq!(Affine2::from_scale(vec2(2.0, -1.0)) * p, pos2(6.0, -4.0));
+
+    let pivot = pos2(1.0, 1.0);
+    assert_eq!(Affine2::from_scale_about(vec2(2.0, 3.0), pivot) * pivot, pivot);
+    assert_eq!(
+        Affine2::from_scale_about(vec2(2.0, 3.0), pivot) * pos2(2.0, 2.0),
+        pos2(3.0, 4.0)
+    );
+
+    // A quarter turn clockwise (y down) takes the X axis to the Y axis:
+    let rotated = Affine2::from_rotation_about(std::f32::consts::FRAC_PI_2, pivot) * pos2(2.0, 1.0);
+    assert!((rotated - pos2(1.0, 2.0)).length() < 1e-6, "{rotated:?}");
+
+    let ts = TSTransform::new(vec2(1.0, 2.0), 3.0);
+    assert_eq!(Affine2::from(ts) * p, ts * p);
+
+    let t = Affine2::from_angle(0.5) * Affine2::from_scale(vec2(2.0, 0.5));
+    assert!((t.determinant() - 1.0).abs() < 1e-6);
+    assert!(t.is_valid());
+    assert!(!Affine2::from_scale(vec2(0.0, 1.0)).is_valid());
+}
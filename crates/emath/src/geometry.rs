@@ -0,0 +1,343 @@
+//! Small geometry routines: polygons, convex hulls, intersections, and closest points.
+//!
+//! Polygons are given as a slice of their corners,
+//! and are implicitly closed, i.e. the last point connects back to the first.
+
+use crate::{Pos2, Rect, Vec2};
+
+/// The z component of the 3D cross product.
+///
+/// Positive if `b` is clockwise from `a` on screen (where y is down).
+#[inline]
+fn cross(a: Vec2, b: Vec2) -> f32 {
+    a.x * b.y - a.y * b.x
+}
+
+/// All edges of the implicitly closed polygon.
+fn polygon_edges(points: &[Pos2]) -> impl Iterator<Item = (Pos2, Pos2)> + '_ {
+    points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(&a, &b)| (a, b))
+}
+
+/// The area of the polygon, positive if the points go clockwise on screen
+/// (where y is down), and negative if they go counter-clockwise.
+///
+/// For self-intersecting polygons, the parts going the other way are subtracted.
+pub fn polygon_signed_area(points: &[Pos2]) -> f32 {
+    0.5 * polygon_edges(points)
+        .map(|(a, b)| cross(a.to_vec2(), b.to_vec2()))
+        .sum::<f32>()
+}
+
+/// The area of the polygon, regardless of the direction of its points.
+pub fn polygon_area(points: &[Pos2]) -> f32 {
+    polygon_signed_area(points).abs()
+}
+
+/// Is the point inside the polygon?
+///
+/// Uses the even-odd rule, so for self-intersecting polygons,
+/// areas that are covered twice count as outside.
+pub fn polygon_contains(points: &[Pos2], pos: Pos2) -> bool {
+    let mut inside = false;
+    for (a, b) in polygon_edges(points) {
+        if (a.y > pos.y) != (b.y > pos.y) && pos.x < (b.x - a.x) * (pos.y - a.y) / (b.y - a.y) + a.x
+        {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// The smallest convex polygon containing all the points.
+///
+/// The corners are returned in clockwise order on screen (where y is down),
+/// without any points along the edges.
+/// With fewer than three distinct points, the distinct points are returned.
+pub fn convex_hull(points: &[Pos2]) -> Vec<Pos2> {
+    let mut points: Vec<Pos2> = points.iter().copied().filter(|p| p.is_finite()).collect();
+    points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+
+    // Andrew's monotone chain, first the top half (on screen), then the bottom half:
+    let mut hull: Vec<Pos2> = Vec::with_capacity(points.len() + 1);
+    for &p in &points {
+        push_convex(&mut hull, p, 0);
+    }
+    let first_half_len = hull.len();
+    for &p in points.iter().rev().skip(1) {
+        push_convex(&mut hull, p, first_half_len - 1);
+    }
+    hull.pop(); // The same as the first point
+    hull
+}
+
+/// Add a point to a convex chain, removing the points that would make it concave.
+///
+/// The first `fixed` points are left alone.
+fn push_convex(hull: &mut Vec<Pos2>, p: Pos2, fixed: usize) {
+    while hull.len() >= fixed + 2 {
+        let [o, a] = [hull[hull.len() - 2], hull[hull.len() - 1]];
+        if 0.0 < cross(a - o, p - o) {
+            break;
+        }
+        hull.pop();
+    }
+    hull.push(p);
+}
+
+/// The point on the segment from `a` to `b` closest to `pos`.
+pub fn closest_point_on_segment(a: Pos2, b: Pos2, pos: Pos2) -> Pos2 {
+    let ab = b - a;
+    let length_sq = ab.length_sq();
+    if length_sq <= 0.0 {
+        return a;
+    }
+    let t = ((pos - a).dot(ab) / length_sq).clamp(0.0, 1.0);
+    a + t * ab
+}
+
+/// Where the segment from `a` to `b` crosses the segment from `c` to `d`, if anywhere.
+///
+/// Segments that just touch also intersect.
+/// If the segments overlap along the same line,
+/// the point of the overlap closest to `a` is returned.
+pub fn segment_intersection(a: Pos2, b: Pos2, c: Pos2, d: Pos2) -> Option<Pos2> {
+    let r = b - a;
+    let s = d - c;
+    let ac = c - a;
+    let denominator = cross(r, s);
+
+    if denominator == 0.0 {
+        // Parallel, so they can only intersect if they are on the same line.
+        if cross(ac, r) != 0.0 || cross(ac, s) != 0.0 {
+            return None;
+        }
+        let length_sq = r.length_sq();
+        if length_sq == 0.0 {
+            // `a` and `b` are the same point:
+            return (closest_point_on_segment(c, d, a) == a).then_some(a);
+        }
+        let t_c = ac.dot(r) / length_sq;
+        let t_d = t_c + s.dot(r) / length_sq;
+        let (t_min, t_max) = if t_c <= t_d { (t_c, t_d) } else { (t_d, t_c) };
+        return (t_min <= 1.0 && 0.0 <= t_max).then(|| a + t_min.max(0.0) * r);
+    }
+
+    let t = cross(ac, s) / denominator;
+    let u = cross(ac, r) / denominator;
+    ((0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u)).then(|| a + t * r)
+}
+
+/// Does the segment from `a` to `b` cross or touch the segment from `c` to `d`?
+pub fn segments_intersect(a: Pos2, b: Pos2, c: Pos2, d: Pos2) -> bool {
+    segment_intersection(a, b, c, d).is_some()
+}
+
+/// The part of the segment from `a` to `b` that is inside the rectangle, if any.
+pub fn clip_segment_to_rect(a: Pos2, b: Pos2, rect: Rect) -> Option<(Pos2, Pos2)> {
+    // Liang–Barsky:
+    let delta = b - a;
+    let mut t_min: f32 = 0.0;
+    let mut t_max: f32 = 1.0;
+    for (p, q) in [
+        (-delta.x, a.x - rect.min.x),
+        (delta.x, rect.max.x - a.x),
+        (-delta.y, a.y - rect.min.y),
+        (delta.y, rect.max.y - a.y),
+    ] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None; // Parallel to this edge, and outside it.
+            }
+        } else {
+            let t = q / p;
+            if p < 0.0 {
+                t_min = t_min.max(t);
+            } else {
+                t_max = t_max.min(t);
+            }
+        }
+        if t_max < t_min {
+            return None;
+        }
+    }
+    Some((a + t_min * delta, a + t_max * delta))
+}
+
+/// Does any part of the segment from `a` to `b` lie within the rectangle?
+pub fn segment_intersects_rect(a: Pos2, b: Pos2, rect: Rect) -> bool {
+    clip_segment_to_rect(a, b, rect).is_some()
+}
+
+/// The point along a quadratic Bézier curve at `t` (from `0` to `1`).
+pub fn quadratic_bezier_point([p0, p1, p2]: [Pos2; 3], t: f32) -> Pos2 {
+    let s = 1.0 - t;
+    (s * s * p0.to_vec2() + 2.0 * s * t * p1.to_vec2() + t * t * p2.to_vec2()).to_pos2()
+}
+
+/// The point along a cubic Bézier curve at `t` (from `0` to `1`).
+pub fn cubic_bezier_point([p0, p1, p2, p3]: [Pos2; 4], t: f32) -> Pos2 {
+    let s = 1.0 - t;
+    (s * s * s * p0.to_vec2()
+        + 3.0 * s * s * t * p1.to_vec2()
+        + 3.0 * s * t * t * p2.to_vec2()
+        + t * t * t * p3.to_vec2())
+    .to_pos2()
+}
+
+/// The `t` of the point on the curve closest to `pos`, and that point.
+///
+/// Finds the closest of some evenly spaced samples, then narrows it down around that one.
+fn closest_point_on_curve(curve: impl Fn(f32) -> Pos2, pos: Pos2) -> (f32, Pos2) {
+    const SAMPLES: usize = 32;
+    const REFINEMENTS: usize = 24;
+
+    let distance_sq = |t: f32| curve(t).distance_sq(pos);
+    let best_sample = (0..=SAMPLES)
+        .map(|i| i as f32 / SAMPLES as f32)
+        .min_by(|&a, &b| distance_sq(a).total_cmp(&distance_sq(b)))
+        .unwrap_or(0.0);
+
+    // Ternary search, assuming there is only one minimum this close to the best sample:
+    let mut low = (best_sample - 1.0 / SAMPLES as f32).max(0.0);
+    let mut high = (best_sample + 1.0 / SAMPLES as f32).min(1.0);
+    for _ in 0..REFINEMENTS {
+        let third = (high - low) / 3.0;
+        if distance_sq(low + third) < distance_sq(high - third) {
+            high -= third;
+        } else {
+            low += third;
+        }
+    }
+
+    let t = 0.5 * (low + high);
+    (t, curve(t))
+}
+
+/// The point on a quadratic Bézier curve closest to `pos`, and its `t` along the curve.
+pub fn closest_point_on_quadratic_bezier(points: [Pos2; 3], pos: Pos2) -> (f32, Pos2) {
+    closest_point_on_curve(|t| quadratic_bezier_point(points, t), pos)
+}
+
+/// The point on a cubic Bézier curve closest to `pos`, and its `t` along the curve.
+///
+/// Useful for e.g. hit-testing the connections in a node graph.
+pub fn closest_point_on_cubic_bezier(points: [Pos2; 4], pos: Pos2) -> (f32, Pos2) {
+    closest_point_on_curve(|t| cubic_bezier_point(points, t), pos)
+}
+
+#[test]
+fn test_polygons() {
+    use crate::pos2;
+
+    // Clockwise on screen:
+    let square = [
+        pos2(0.0, 0.0),
+        pos2(10.0, 0.0),
+        pos2(10.0, 10.0),
+        pos2(0.0, 10.0),
+    ];
+    assert_eq!(polygon_signed_area(&square), 100.0);
+    let reversed: Vec<Pos2> = square.iter().rev().copied().collect();
+    assert_eq!(polygon_signed_area(&reversed), -100.0);
+    assert_eq!(polygon_area(&reversed), 100.0);
+
+    assert!(polygon_contains(&square, pos2(5.0, 5.0)));
+    assert!(!polygon_contains(&square, pos2(15.0, 5.0)));
+    assert!(!polygon_contains(&[], pos2(0.0, 0.0)));
+
+    let points = [
+        pos2(0.0, 0.0),
+        pos2(5.0, 5.0), // inside
+        pos2(10.0, 0.0),
+        pos2(10.0, 10.0),
+        pos2(5.0, 0.0), // on an edge
+        pos2(0.0, 10.0),
+        pos2(10.0, 10.0), // duplicate
+    ];
+    let hull = convex_hull(&points);
+    assert_eq!(hull.len(), 4);
+    assert_eq!(polygon_signed_area(&hull), 100.0, "{hull:?}");
+    for corner in square {
+        assert!(hull.contains(&corner));
+    }
+
+    assert_eq!(convex_hull(&[pos2(1.0, 1.0), pos2(1.0, 1.0)]), vec![pos2(1.0, 1.0)]);
+}
+
+#[test]
+fn test_intersections() {
+    use crate::pos2;
+
+    let x = segment_intersection(
+        pos2(0.0, 0.0),
+        pos2(10.0, 10.0),
+        pos2(0.0, 10.0),
+        pos2(10.0, 0.0),
+    );
+    assert_eq!(x, Some(pos2(5.0, 5.0)));
+
+    // Touching at an end:
+    assert!(segments_intersect(
+        pos2(0.0, 0.0),
+        pos2(5.0, 0.0),
+        pos2(5.0, 0.0),
+        pos2(5.0, 5.0)
+    ));
+    // Parallel:
+    assert!(!segments_intersect(
+        pos2(0.0, 0.0),
+        pos2(5.0, 0.0),
+        pos2(0.0, 1.0),
+        pos2(5.0, 1.0)
+    ));
+    // Overlapping on the same line:
+    let overlap = segment_intersection(
+        pos2(0.0, 0.0),
+        pos2(10.0, 0.0),
+        pos2(15.0, 0.0),
+        pos2(5.0, 0.0),
+    );
+    assert_eq!(overlap, Some(pos2(5.0, 0.0)));
+
+    let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(10.0, 10.0));
+    assert_eq!(
+        clip_segment_to_rect(pos2(-5.0, 5.0), pos2(15.0, 5.0), rect),
+        Some((pos2(0.0, 5.0), pos2(10.0, 5.0)))
+    );
+    assert!(segment_intersects_rect(pos2(2.0, 2.0), pos2(3.0, 3.0), rect));
+    assert!(!segment_intersects_rect(pos2(11.0, 0.0), pos2(20.0, 9.0), rect));
+    assert!(segment_intersects_rect(pos2(-1.0, 5.0), pos2(5.0, 11.0), rect));
+    assert!(!segment_intersects_rect(pos2(-1.0, 9.0), pos2(1.0, 12.0), rect));
+}
+
+#[test]
+fn test_closest_point_on_bezier() {
+    use crate::pos2;
+
+    // A straight "curve", so the answer is easy:
+    let line = [
+        pos2(0.0, 0.0),
+        pos2(10.0, 0.0),
+        pos2(20.0, 0.0),
+        pos2(30.0, 0.0),
+    ];
+    let (t, closest) = closest_point_on_cubic_bezier(line, pos2(15.0, 7.0));
+    assert!((t - 0.5).abs() < 1e-3, "{t}");
+    assert!(closest.distance(pos2(15.0, 0.0)) < 1e-2, "{closest:?}");
+
+    let arch = [pos2(0.0, 0.0), pos2(5.0, 10.0), pos2(10.0, 0.0)];
+    let (t, closest) = closest_point_on_quadratic_bezier(arch, pos2(5.0, 20.0));
+    assert!((t - 0.5).abs() < 1e-3, "{t}");
+    assert!(closest.distance(pos2(5.0, 5.0)) < 1e-2, "{closest:?}");
+
+    let (t, closest) = closest_point_on_quadratic_bezier(arch, pos2(-5.0, -5.0));
+    assert!(t < 1e-3, "{t}");
+    assert!(closest.distance(pos2(0.0, 0.0)) < 1e-2, "{closest:?}");
+}
@@ -25,8 +25,10 @@ use std::ops::{Add, Div, Mul, RangeInclusive, Sub};
 
 // ----------------------------------------------------------------------------
 
+mod affine2;
 pub mod align;
 pub mod easing;
+pub mod geometry;
 mod gui_rounding;
 mod history;
 mod numeric;
@@ -35,14 +37,18 @@ mod pos2;
 mod range;
 mod rect;
 mod rect_align;
+mod rect_packer;
 mod rect_transform;
 mod rot2;
+pub mod scale;
 pub mod smart_aim;
+pub mod smoothing;
 mod ts_transform;
 mod vec2;
 mod vec2b;
 
 pub use self::{
+    affine2::Affine2,
     align::{Align, Align2},
     gui_rounding::{GUI_ROUNDING, GuiRounding},
     history::History,
@@ -52,6 +58,7 @@ pub use self::{
     range::Rangef,
     rect::*,
     rect_align::RectAlign,
+    rect_packer::RectPacker,
     rect_transform::*,
     rot2::*,
     ts_transform::*,
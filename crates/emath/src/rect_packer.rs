@@ -0,0 +1,236 @@
+use crate::{Pos2, Rect, Vec2};
+
+/// Packs rectangles into a larger rectangle, e.g. for sprite atlases, thumbnail walls,
+/// or laying out dashboard tiles.
+///
+/// Rectangles can be allocated and freed in any order.
+/// This uses a guillotine packer: each allocation splits the free space it was taken from
+/// in two, and freed space is merged back together with its free neighbors.
+///
+/// Positions are relative to the top left corner of the packer, at `(0, 0)`.
+/// If all sizes are whole numbers, so are all the positions.
+///
+/// ```
+/// # use emath::{RectPacker, vec2};
+/// let mut packer = RectPacker::new(vec2(100.0, 100.0));
+/// let a = packer.allocate(vec2(60.0, 40.0)).unwrap();
+/// let b = packer.allocate(vec2(40.0, 40.0)).unwrap();
+/// assert!(!a.intersects(b.shrink(0.5)));
+/// assert!(packer.allocate(vec2(200.0, 10.0)).is_none());
+///
+/// packer.free(a);
+/// assert!(packer.allocate(vec2(60.0, 40.0)).is_some());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct RectPacker {
+    size: Vec2,
+
+    /// Empty space added to the right and bottom of each allocation.
+    padding: f32,
+
+    /// Non-overlapping free space.
+    free: Vec<Rect>,
+
+    /// The total area of all allocations, including padding.
+    used_area: f32,
+}
+
+impl RectPacker {
+    /// An empty packer of the given size.
+    pub fn new(size: Vec2) -> Self {
+        let mut packer = Self {
+            size: Vec2::ZERO,
+            padding: 0.0,
+            free: vec![],
+            used_area: 0.0,
+        };
+        packer.grow(size);
+        packer
+    }
+
+    /// Add this much empty space to the right and bottom of each allocation,
+    /// e.g. to keep texture sampling from bleeding between the sprites of an atlas.
+    ///
+    /// Set this before allocating anything.
+    #[inline]
+    pub fn with_padding(mut self, padding: f32) -> Self {
+        self.padding = padding.max(0.0);
+        self
+    }
+
+    /// The size of the whole packer.
+    #[inline]
+    pub fn size(&self) -> Vec2 {
+        self.size
+    }
+
+    /// How much of the space is allocated, from `0` to `1`.
+    pub fn fill_ratio(&self) -> f32 {
+        let total_area = self.size.x * self.size.y;
+        if 0.0 < total_area {
+            self.used_area / total_area
+        } else {
+            1.0
+        }
+    }
+
+    /// Free everything.
+    pub fn clear(&mut self) {
+        self.free = vec![Rect::from_min_size(Pos2::ZERO, self.size)];
+        self.free.retain(|rect| rect.is_positive());
+        self.used_area = 0.0;
+    }
+
+    /// Make the packer larger, keeping everything where it is.
+    ///
+    /// For instance, a texture atlas can double its height when it runs out of space.
+    /// The size never shrinks.
+    pub fn grow(&mut self, new_size: Vec2) {
+        let old_size = self.size;
+        let new_size = new_size.max(old_size);
+        self.size = new_size;
+
+        let right = Rect::from_x_y_ranges(old_size.x..=new_size.x, 0.0..=new_size.y);
+        let below = Rect::from_x_y_ranges(0.0..=old_size.x, old_size.y..=new_size.y);
+        for rect in [right, below] {
+            if rect.is_positive() {
+                self.add_free(rect);
+            }
+        }
+    }
+
+    /// Find space for a rectangle of the given size.
+    ///
+    /// Prefers the space closest to the top, so that the used part of the packer stays compact,
+    /// and then the space that fits the rectangle the tightest.
+    ///
+    /// Returns `None` if there is no space left for it.
+    pub fn allocate(&mut self, size: Vec2) -> Option<Rect> {
+        if size.any_nan() || size.min_elem() < 0.0 {
+            return None;
+        }
+        let padded = size + Vec2::splat(self.padding);
+
+        let index = self
+            .free
+            .iter()
+            .enumerate()
+            .filter(|(_, free)| padded.x <= free.width() && padded.y <= free.height())
+            .min_by(|(_, a), (_, b)| {
+                let leftover = |free: &Rect| free.area() - padded.x * padded.y;
+                a.min.y.total_cmp(&b.min.y).then(leftover(a).total_cmp(&leftover(b)))
+            })
+            .map(|(index, _)| index)?;
+
+        let free = self.free.swap_remove(index);
+        let allocated = Rect::from_min_size(free.min, padded);
+
+        // Split the rest in two along the shorter leftover side,
+        // leaving the larger piece as large as possible:
+        let leftover_width = free.width() - padded.x;
+        let leftover_height = free.height() - padded.y;
+        let (right, below) = if leftover_width < leftover_height {
+            (
+                Rect::from_x_y_ranges(allocated.max.x..=free.max.x, free.min.y..=allocated.max.y),
+                Rect::from_x_y_ranges(free.min.x..=free.max.x, allocated.max.y..=free.max.y),
+            )
+        } else {
+            (
+                Rect::from_x_y_ranges(allocated.max.x..=free.max.x, free.min.y..=free.max.y),
+                Rect::from_x_y_ranges(free.min.x..=allocated.max.x, allocated.max.y..=free.max.y),
+            )
+        };
+        self.free.extend([right, below].into_iter().filter(|rect| rect.is_positive()));
+
+        self.used_area += padded.x * padded.y;
+        Some(Rect::from_min_size(allocated.min, size))
+    }
+
+    /// Allocate many rectangles at once, returning them in the same order as the sizes.
+    ///
+    /// The largest rectangles are placed first, which packs a lot better than allocating
+    /// them one by one in a random order.
+    pub fn allocate_many(&mut self, sizes: &[Vec2]) -> Vec<Option<Rect>> {
+        let mut order: Vec<usize> = (0..sizes.len()).collect();
+        order.sort_by(|&a, &b| {
+            let (a, b) = (sizes[a], sizes[b]);
+            b.y.total_cmp(&a.y).then(b.x.total_cmp(&a.x))
+        });
+
+        let mut rects = vec![None; sizes.len()];
+        for index in order {
+            rects[index] = self.allocate(sizes[index]);
+        }
+        rects
+    }
+
+    /// Give back a rectangle returned by [`Self::allocate`], so it can be reused.
+    pub fn free(&mut self, rect: Rect) {
+        let padded = Rect::from_min_size(rect.min, rect.size() + Vec2::splat(self.padding));
+        self.used_area = (self.used_area - padded.area()).max(0.0);
+        self.add_free(padded);
+    }
+
+    /// Add free space, merging it with any free neighbors it shares a whole side with.
+    fn add_free(&mut self, mut rect: Rect) {
+        while let Some(index) = self.free.iter().position(|free| can_merge(*free, rect)) {
+            rect = rect.union(self.free.swap_remove(index));
+        }
+        self.free.push(rect);
+    }
+}
+
+/// Do the rectangles share a whole side, so that together they form a rectangle?
+fn can_merge(a: Rect, b: Rect) -> bool {
+    let same_columns = a.min.x == b.min.x && a.max.x == b.max.x;
+    let same_rows = a.min.y == b.min.y && a.max.y == b.max.y;
+    (same_columns && (a.max.y == b.min.y || b.max.y == a.min.y))
+        || (same_rows && (a.max.x == b.min.x || b.max.x == a.min.x))
+}
+
+#[test]
+fn test_rect_packer() {
+    use crate::vec2;
+
+    let mut packer = RectPacker::new(vec2(100.0, 100.0)).with_padding(2.0);
+    let rects: Vec<Rect> = std::iter::from_fn(|| packer.allocate(vec2(18.0, 8.0))).collect();
+    assert_eq!(rects.len(), 50, "5 columns and 10 rows of 20x10 with the padding");
+    assert_eq!(packer.fill_ratio(), 1.0);
+    for (i, a) in rects.iter().enumerate() {
+        assert!(Rect::from_min_size(Pos2::ZERO, packer.size()).contains_rect(*a));
+        for b in &rects[i + 1..] {
+            assert!(!a.intersects(b.shrink(0.5)), "{a:?} overlaps {b:?}");
+        }
+    }
+
+    // Freeing everything merges the space back together:
+    for rect in rects {
+        packer.free(rect);
+    }
+    assert_eq!(packer.fill_ratio(), 0.0);
+    assert!(packer.allocate(vec2(98.0, 98.0)).is_some());
+
+    let mut packer = RectPacker::new(vec2(100.0, 10.0));
+    assert!(packer.allocate(vec2(100.0, 20.0)).is_none());
+    packer.grow(vec2(100.0, 20.0));
+    assert_eq!(
+        packer.allocate(vec2(100.0, 20.0)),
+        Some(Rect::from_min_size(Pos2::ZERO, vec2(100.0, 20.0)))
+    );
+}
+
+#[test]
+fn test_allocate_many() {
+    use crate::vec2;
+
+    let mut packer = RectPacker::new(vec2(100.0, 100.0));
+    let sizes = [vec2(50.0, 50.0), vec2(100.0, 50.0), vec2(50.0, 50.0), vec2(1.0, 1.0)];
+    let rects = packer.allocate_many(&sizes);
+    assert_eq!(rects[1], Some(Rect::from_min_size(Pos2::ZERO, vec2(100.0, 50.0))));
+    assert!(rects[0].is_some() && rects[2].is_some());
+    assert_eq!(rects[3], None, "Full");
+    for (rect, size) in rects.iter().zip(sizes).take(3) {
+        assert_eq!(rect.unwrap().size(), size);
+    }
+}
@@ -0,0 +1,432 @@
+//! Scales and ticks for charts.
+//!
+//! Use [`AxisTransform`] to map values to screen coordinates along an axis,
+//! and [`nice_ticks`] or [`Scale::ticks`] to find where to put the tick marks and labels.
+
+use std::ops::RangeInclusive;
+
+use crate::{Rangef, lerp};
+
+/// A "nice" distance between ticks: 1, 2, or 5 times a power of ten.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct NiceStep {
+    mantissa: f64,
+    exponent: i32,
+}
+
+impl NiceStep {
+    /// The nice step closest to `step`, which must be positive and finite.
+    fn round(step: f64) -> Self {
+        let exponent = step.log10().floor() as i32;
+        let fraction = step / 10_f64.powi(exponent);
+        match fraction {
+            f if f < 1.5 => Self::new(1.0, exponent),
+            f if f < 3.0 => Self::new(2.0, exponent),
+            f if f < 7.0 => Self::new(5.0, exponent),
+            _ => Self::new(1.0, exponent + 1),
+        }
+    }
+
+    fn new(mantissa: f64, exponent: i32) -> Self {
+        Self { mantissa, exponent }
+    }
+
+    /// The next bigger nice step.
+    fn next(self) -> Self {
+        if self.mantissa < 1.5 {
+            Self::new(2.0, self.exponent)
+        } else if self.mantissa < 3.0 {
+            Self::new(5.0, self.exponent)
+        } else {
+            Self::new(1.0, self.exponent + 1)
+        }
+    }
+
+    fn value(self) -> f64 {
+        self.times(1.0)
+    }
+
+    /// `n` steps from zero.
+    ///
+    /// Dividing by a power of ten, instead of multiplying by its inverse,
+    /// gives us `0.3` instead of `0.30000000000000004`.
+    fn times(self, n: f64) -> f64 {
+        let scaled = n * self.mantissa;
+        let result = if 0 <= self.exponent {
+            scaled * 10_f64.powi(self.exponent)
+        } else {
+            scaled / 10_f64.powi(-self.exponent)
+        };
+        result + 0.0 // Turn -0.0 into 0.0
+    }
+
+    /// The indices of the first and last multiple of this step within the range.
+    fn multiples_in(self, min: f64, max: f64) -> (f64, f64) {
+        // Allow for a little rounding error at the ends:
+        const EPSILON: f64 = 1e-9;
+        let step = self.value();
+        ((min / step - EPSILON).ceil(), (max / step + EPSILON).floor())
+    }
+}
+
+fn ordered(range: &RangeInclusive<f64>) -> (f64, f64) {
+    let (a, b) = (*range.start(), *range.end());
+    if a <= b { (a, b) } else { (b, a) }
+}
+
+/// Round `x` to a "nice" number: 1, 2, or 5 times a power of ten.
+///
+/// So `0.13` becomes `0.1`, `2.8` becomes `2.0`, and `420.0` becomes `500.0`.
+/// Non-positive and non-finite numbers are returned unchanged.
+pub fn nice_number(x: f64) -> f64 {
+    if x.is_finite() && 0.0 < x {
+        NiceStep::round(x).value()
+    } else {
+        x
+    }
+}
+
+/// At most `max_ticks` evenly spaced "nice" values within the range,
+/// e.g. `[0.0, 0.2, 0.4, 0.6, 0.8, 1.0]`.
+///
+/// The distance between the ticks is 1, 2, or 5 times a power of ten,
+/// and as small as `max_ticks` allows.
+pub fn nice_ticks(range: RangeInclusive<f64>, max_ticks: usize) -> Vec<f64> {
+    let (min, max) = ordered(&range);
+    if !min.is_finite() || !max.is_finite() || max_ticks == 0 {
+        return vec![];
+    }
+    if min == max {
+        return vec![min];
+    }
+
+    let mut step = NiceStep::round((max - min) / max_ticks.saturating_sub(1).max(1) as f64);
+    let (mut first, mut last) = step.multiples_in(min, max);
+    while max_ticks as f64 <= last - first {
+        step = step.next();
+        (first, last) = step.multiples_in(min, max);
+    }
+
+    (first as i64..=last as i64)
+        .map(|i| step.times(i as f64))
+        .collect()
+}
+
+/// Expand the range so that it starts and ends on the ticks of [`nice_ticks`],
+/// e.g. `0.13..=0.87` becomes `0.0..=1.0`.
+///
+/// This is often used for the range of the value axis of a chart.
+pub fn nice_range(range: RangeInclusive<f64>, max_ticks: usize) -> RangeInclusive<f64> {
+    let (min, max) = ordered(&range);
+    if !min.is_finite() || !max.is_finite() || min == max {
+        return min..=max;
+    }
+
+    let mut step = NiceStep::round((max - min) / max_ticks.saturating_sub(1).max(1) as f64);
+    loop {
+        let value = step.value();
+        let first = (min / value).floor();
+        let last = (max / value).ceil();
+        if last - first < max_ticks.max(2) as f64 {
+            return step.times(first)..=step.times(last);
+        }
+        step = step.next();
+    }
+}
+
+/// Ticks for a logarithmic axis: powers of ten, with `2` and `5` times them in between
+/// if there is room, or only every few powers of ten if there isn't.
+///
+/// Only positive values are returned.
+pub fn log_ticks(range: RangeInclusive<f64>, max_ticks: usize) -> Vec<f64> {
+    let (min, max) = ordered(&range);
+    if !(0.0 < min && max.is_finite()) || max_ticks == 0 {
+        return vec![];
+    }
+
+    let first_exponent = min.log10().floor() as i32;
+    let last_exponent = max.log10().ceil() as i32;
+    let decades = (last_exponent - first_exponent) as usize + 1;
+
+    let mantissas: &[f64] = if 3 * decades <= max_ticks {
+        &[1.0, 2.0, 5.0]
+    } else {
+        &[1.0]
+    };
+    let every = decades.div_ceil(max_ticks).max(1);
+
+    let in_range = |value: f64| min * (1.0 - 1e-9) <= value && value <= max * (1.0 + 1e-9);
+    (first_exponent..=last_exponent)
+        .filter(|exponent| (exponent - first_exponent) as usize % every == 0)
+        .flat_map(|exponent| {
+            mantissas
+                .iter()
+                .map(move |&mantissa| NiceStep::new(mantissa, exponent).value())
+        })
+        .filter(|&value| in_range(value))
+        .collect()
+}
+
+/// How values are spread out along an axis.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Scale {
+    /// Equal differences take up equal space.
+    #[default]
+    Linear,
+
+    /// Base 10 logarithmic: equal ratios take up equal space.
+    ///
+    /// Only positive values can be shown.
+    Log,
+
+    /// Linear close to zero, and logarithmic further out,
+    /// so that both small and huge values, positive and negative, can be shown together.
+    SymLog {
+        /// Below this magnitude the scale is (nearly) linear.
+        linear_threshold: f64,
+    },
+}
+
+impl Scale {
+    /// Transform a value to where it ends up on a linear axis.
+    pub fn transform(self, value: f64) -> f64 {
+        match self {
+            Self::Linear => value,
+            Self::Log => value.log10(),
+            Self::SymLog { linear_threshold } => {
+                value.signum() * (value.abs() / linear_threshold).ln_1p()
+            }
+        }
+    }
+
+    /// The inverse of [`Self::transform`].
+    pub fn inverse(self, transformed: f64) -> f64 {
+        match self {
+            Self::Linear => transformed,
+            Self::Log => 10_f64.powf(transformed),
+            Self::SymLog { linear_threshold } => {
+                transformed.signum() * linear_threshold * transformed.abs().exp_m1()
+            }
+        }
+    }
+
+    /// At most `max_ticks` nice values within the range, suitable for this scale.
+    pub fn ticks(self, range: RangeInclusive<f64>, max_ticks: usize) -> Vec<f64> {
+        match self {
+            Self::Linear => nice_ticks(range, max_ticks),
+            Self::Log => log_ticks(range, max_ticks),
+            Self::SymLog { linear_threshold } => symlog_ticks(range, linear_threshold, max_ticks),
+        }
+    }
+}
+
+/// Zero, and powers of ten (and their negatives) beyond the linear threshold.
+fn symlog_ticks(range: RangeInclusive<f64>, linear_threshold: f64, max_ticks: usize) -> Vec<f64> {
+    let (min, max) = ordered(&range);
+    if !min.is_finite() || !max.is_finite() || max_ticks == 0 {
+        return vec![];
+    }
+
+    let threshold = nice_number(linear_threshold.abs());
+    let powers = |limit: f64| {
+        if threshold < limit {
+            log_ticks(threshold..=limit, usize::MAX)
+                .into_iter()
+                .filter(|value| NiceStep::round(*value).mantissa == 1.0)
+                .collect()
+        } else {
+            vec![]
+        }
+    };
+
+    let mut ticks: Vec<f64> = powers(-min).into_iter().rev().map(|value| -value).collect();
+    if min <= 0.0 && 0.0 <= max {
+        ticks.push(0.0);
+    }
+    ticks.extend(powers(max));
+
+    // Thin them out, keeping zero if it is there:
+    let every = ticks.len().div_ceil(max_ticks).max(1);
+    let zero_index = ticks.iter().position(|&value| value == 0.0).unwrap_or(0);
+    let mut index = 0;
+    ticks.retain(|_| {
+        let keep = index.abs_diff(zero_index) % every == 0;
+        index += 1;
+        keep
+    });
+    ticks
+}
+
+/// Maps values to screen coordinates along one axis of a chart, and back.
+///
+/// ```
+/// # use emath::{Rangef, scale::{AxisTransform, Scale}};
+/// // A y axis from 1 to 1000, with 100 at the bottom of the screen and 0 at the top:
+/// let y_axis = AxisTransform::new(1.0..=1000.0, Rangef::new(100.0, 0.0))
+///     .with_scale(Scale::Log)
+///     .with_margin(10.0);
+/// assert_eq!(y_axis.value_to_screen(1.0), 90.0);
+/// assert_eq!(y_axis.value_to_screen(1000.0), 10.0);
+/// assert!((y_axis.screen_to_value(50.0) - 31.62).abs() < 0.01);
+/// assert_eq!(y_axis.ticks(5), vec![1.0, 10.0, 100.0, 1000.0]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct AxisTransform {
+    values: RangeInclusive<f64>,
+    screen: Rangef,
+    scale: Scale,
+}
+
+impl AxisTransform {
+    /// Map the `values` onto the `screen` range.
+    ///
+    /// The `screen` range may be flipped, e.g. to have larger values higher up on the screen.
+    pub fn new(values: RangeInclusive<f64>, screen: impl Into<Rangef>) -> Self {
+        Self {
+            values,
+            screen: screen.into(),
+            scale: Scale::Linear,
+        }
+    }
+
+    /// Default: [`Scale::Linear`].
+    #[inline]
+    pub fn with_scale(mut self, scale: Scale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Leave this much space at both ends of the screen range,
+    /// e.g. so that markers at the ends aren't cut off.
+    #[inline]
+    pub fn with_margin(mut self, margin: f32) -> Self {
+        let margin = margin.min(0.5 * self.screen.span().abs());
+        let sign = if self.screen.min <= self.screen.max {
+            1.0
+        } else {
+            -1.0
+        };
+        self.screen = Rangef::new(
+            self.screen.min + sign * margin,
+            self.screen.max - sign * margin,
+        );
+        self
+    }
+
+    #[inline]
+    pub fn values(&self) -> &RangeInclusive<f64> {
+        &self.values
+    }
+
+    /// The screen range, after the margins.
+    #[inline]
+    pub fn screen(&self) -> Rangef {
+        self.screen
+    }
+
+    #[inline]
+    pub fn scale(&self) -> Scale {
+        self.scale
+    }
+
+    /// The values range, transformed by the scale.
+    fn transformed_values(&self) -> Option<(f64, f64)> {
+        let start = self.scale.transform(*self.values.start());
+        let end = self.scale.transform(*self.values.end());
+        (start.is_finite() && end.is_finite() && start != end).then_some((start, end))
+    }
+
+    /// Where on the screen the value ends up.
+    ///
+    /// Values outside the range end up outside the screen range.
+    pub fn value_to_screen(&self, value: f64) -> f32 {
+        let Some((start, end)) = self.transformed_values() else {
+            return self.screen.center();
+        };
+        let t = (self.scale.transform(value) - start) / (end - start);
+        lerp(self.screen.min..=self.screen.max, t as f32)
+    }
+
+    /// Which value is at this screen coordinate.
+    pub fn screen_to_value(&self, screen: f32) -> f64 {
+        let Some((start, end)) = self.transformed_values() else {
+            return *self.values.start();
+        };
+        let t = if self.screen.min == self.screen.max {
+            0.5
+        } else {
+            f64::from((screen - self.screen.min) / (self.screen.max - self.screen.min))
+        };
+        self.scale.inverse(lerp(start..=end, t))
+    }
+
+    /// At most `max_ticks` nice values within the range, suitable for the scale.
+    pub fn ticks(&self, max_ticks: usize) -> Vec<f64> {
+        self.scale.ticks(self.values.clone(), max_ticks)
+    }
+
+    /// The nice values within the range, at least `min_spacing` points apart on screen
+    /// (for linear scales).
+    pub fn ticks_with_spacing(&self, min_spacing: f32) -> Vec<f64> {
+        let max_ticks = (self.screen.span().abs() / min_spacing.max(1.0)) as usize + 1;
+        self.ticks(max_ticks)
+    }
+}
+
+#[test]
+fn test_nice_ticks() {
+    assert_eq!(nice_number(0.13), 0.1);
+    assert_eq!(nice_number(2.8), 2.0);
+    assert_eq!(nice_number(420.0), 500.0);
+    assert_eq!(nice_number(8.0), 10.0);
+
+    assert_eq!(nice_ticks(0.0..=1.0, 6), vec![0.0, 0.2, 0.4, 0.6, 0.8, 1.0]);
+    assert_eq!(nice_ticks(0.0..=1.0, 11)[3], 0.3);
+    assert_eq!(nice_ticks(1.0..=0.0, 3), vec![0.0, 0.5, 1.0]);
+    assert_eq!(nice_ticks(-13.0..=27.0, 5), vec![-10.0, 0.0, 10.0, 20.0]);
+    assert_eq!(nice_ticks(3.0..=3.0, 5), vec![3.0]);
+    assert!(nice_ticks(0.0..=f64::INFINITY, 5).is_empty());
+    for max_ticks in 1..20 {
+        assert!(nice_ticks(-0.37..=123.4, max_ticks).len() <= max_ticks);
+    }
+
+    assert_eq!(nice_range(0.13..=0.87, 6), 0.0..=1.0);
+
+    assert_eq!(log_ticks(1.0..=1000.0, 4), vec![1.0, 10.0, 100.0, 1000.0]);
+    assert_eq!(
+        log_ticks(1.0..=100.0, 10),
+        vec![1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0]
+    );
+    assert_eq!(log_ticks(1.0..=1e6, 4), vec![1.0, 100.0, 1e4, 1e6]);
+    assert!(log_ticks(-1.0..=10.0, 4).is_empty());
+
+    let symlog = Scale::SymLog {
+        linear_threshold: 1.0,
+    };
+    assert_eq!(
+        symlog.ticks(-100.0..=100.0, 10),
+        vec![-100.0, -10.0, -1.0, 0.0, 1.0, 10.0, 100.0]
+    );
+}
+
+#[test]
+fn test_scale_roundtrip() {
+    for scale in [
+        Scale::Linear,
+        Scale::Log,
+        Scale::SymLog {
+            linear_threshold: 2.0,
+        },
+    ] {
+        for value in [0.5, 1.0, 42.0, 1234.5] {
+            let roundtrip = scale.inverse(scale.transform(value));
+            assert!((roundtrip - value).abs() < 1e-9 * value, "{scale:?}: {value}");
+        }
+    }
+
+    let axis = AxisTransform::new(-1.0..=1.0, 0.0..=200.0).with_margin(10.0);
+    assert_eq!(axis.value_to_screen(-1.0), 10.0);
+    assert_eq!(axis.value_to_screen(0.0), 100.0);
+    assert_eq!(axis.screen_to_value(190.0), 1.0);
+}
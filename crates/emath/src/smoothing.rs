@@ -0,0 +1,189 @@
+//! Smoothing and prediction of pointer samples, e.g. for freehand drawing.
+//!
+//! Samples are given as `(time, position)` pairs, with the time in seconds.
+
+use crate::{Pos2, Vec2, lerp};
+
+/// The [1€ filter](https://gery.casiez.net/1euro/) for smoothing a jittery pointer.
+///
+/// It smooths a lot when the pointer moves slowly, where jitter is most visible,
+/// and little when it moves fast, where lag is most visible.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct OneEuroFilter {
+    /// The cutoff frequency when still, in Hz.
+    ///
+    /// Decrease it to reduce jitter.
+    pub min_cutoff: f32,
+
+    /// How fast the cutoff frequency increases with speed, in Hz per point/second.
+    ///
+    /// Increase it to reduce lag.
+    pub beta: f32,
+
+    /// The cutoff frequency used for smoothing the velocity, in Hz.
+    pub derivative_cutoff: f32,
+
+    /// The time, filtered position and filtered velocity of the last sample.
+    last: Option<(f64, Pos2, Vec2)>,
+}
+
+impl Default for OneEuroFilter {
+    fn default() -> Self {
+        Self::new(1.0, 0.05)
+    }
+}
+
+impl OneEuroFilter {
+    pub fn new(min_cutoff: f32, beta: f32) -> Self {
+        Self {
+            min_cutoff,
+            beta,
+            derivative_cutoff: 1.0,
+            last: None,
+        }
+    }
+
+    /// Filter the next sample, returning the smoothed position.
+    ///
+    /// Samples that are not later than the previous one are ignored.
+    pub fn filter(&mut self, time: f64, pos: Pos2) -> Pos2 {
+        let Some((last_time, last_pos, last_velocity)) = self.last else {
+            self.last = Some((time, pos, Vec2::ZERO));
+            return pos;
+        };
+        let dt = (time - last_time) as f32;
+        if dt <= 0.0 {
+            return last_pos;
+        }
+
+        let velocity = (pos - last_pos) / dt;
+        let velocity = lerp(
+            last_velocity..=velocity,
+            smoothing_factor(self.derivative_cutoff, dt),
+        );
+        let cutoff = self.min_cutoff + self.beta * velocity.length();
+        let pos = last_pos.lerp(pos, smoothing_factor(cutoff, dt));
+
+        self.last = Some((time, pos, velocity));
+        pos
+    }
+
+    /// Forget the previous samples, e.g. when a new stroke starts.
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+}
+
+/// How much of a new sample to mix in, for a low-pass filter with the given cutoff frequency.
+fn smoothing_factor(cutoff: f32, dt: f32) -> f32 {
+    let tau = 1.0 / (std::f32::consts::TAU * cutoff);
+    1.0 / (1.0 + tau / dt)
+}
+
+/// A smooth curve through all the `points`, using a uniform Catmull-Rom spline.
+///
+/// Each segment between two points is replaced with `points_per_segment` points,
+/// starting with the first point of the segment.
+/// The last point is kept as is, and fewer than three points are returned as they are.
+pub fn catmull_rom(points: &[Pos2], points_per_segment: usize) -> Vec<Pos2> {
+    if points.len() < 3 || points_per_segment <= 1 {
+        return points.to_vec();
+    }
+
+    let mut curve = Vec::with_capacity((points.len() - 1) * points_per_segment + 1);
+    for i in 0..points.len() - 1 {
+        let p0 = points[i.saturating_sub(1)].to_vec2();
+        let p1 = points[i].to_vec2();
+        let p2 = points[i + 1].to_vec2();
+        let p3 = points[(i + 2).min(points.len() - 1)].to_vec2();
+
+        for step in 0..points_per_segment {
+            let t = step as f32 / points_per_segment as f32;
+            let point = 0.5
+                * (2.0 * p1
+                    + (p2 - p0) * t
+                    + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t * t
+                    + (3.0 * (p1 - p2) + p3 - p0) * t * t * t);
+            curve.push(point.to_pos2());
+        }
+    }
+    curve.extend(points.last());
+    curve
+}
+
+/// Where the pointer will probably be at `time`, continuing with the velocity of the `samples`.
+///
+/// Give it the samples of the last few tens of milliseconds:
+/// older samples make the prediction smoother but slower to follow turns.
+/// Returns `None` if there are no samples.
+pub fn predict_position(samples: &[(f64, Pos2)], time: f64) -> Option<Pos2> {
+    let &(first_time, first_pos) = samples.first()?;
+    let &(last_time, last_pos) = samples.last()?;
+    let duration = (last_time - first_time) as f32;
+    if duration <= 0.0 {
+        return Some(last_pos);
+    }
+    let velocity = (last_pos - first_pos) / duration;
+    Some(last_pos + velocity * (time - last_time) as f32)
+}
+
+#[test]
+fn test_one_euro_filter() {
+    use crate::pos2;
+
+    let mut filter = OneEuroFilter::default();
+    assert_eq!(filter.filter(0.0, pos2(10.0, 10.0)), pos2(10.0, 10.0));
+
+    // Jitter around a still point is smoothed away:
+    let mut max_error: f32 = 0.0;
+    for i in 1..100 {
+        let jitter = if i % 2 == 0 { 1.0 } else { -1.0 };
+        let pos = filter.filter(i as f64 / 100.0, pos2(10.0 + jitter, 10.0));
+        max_error = max_error.max(pos.distance(pos2(10.0, 10.0)));
+    }
+    assert!(max_error < 0.2, "max_error: {max_error}");
+
+    assert_eq!(
+        filter.filter(0.5, pos2(100.0, 100.0)),
+        filter.filter(0.5, pos2(100.0, 100.0)),
+        "Samples from the past are ignored"
+    );
+
+    filter.reset();
+    assert_eq!(filter.filter(2.0, pos2(50.0, 50.0)), pos2(50.0, 50.0));
+}
+
+#[test]
+fn test_catmull_rom() {
+    use crate::pos2;
+
+    let points = [pos2(0.0, 0.0), pos2(10.0, 10.0), pos2(20.0, 0.0)];
+    let curve = catmull_rom(&points, 4);
+    assert_eq!(curve.len(), 9);
+    assert_eq!(curve[0], points[0]);
+    assert_eq!(curve[4], points[1]);
+    assert_eq!(curve[8], points[2]);
+    assert!(
+        curve[2].y > 5.0,
+        "The curve bulges towards the middle point: {:?}",
+        curve[2]
+    );
+
+    assert_eq!(catmull_rom(&points[..2], 4), points[..2]);
+}
+
+#[test]
+fn test_predict_position() {
+    use crate::pos2;
+
+    assert_eq!(predict_position(&[], 1.0), None);
+    assert_eq!(
+        predict_position(&[(1.0, pos2(5.0, 5.0))], 2.0),
+        Some(pos2(5.0, 5.0))
+    );
+    assert_eq!(
+        predict_position(&[(1.0, pos2(0.0, 0.0)), (1.5, pos2(10.0, 0.0))], 2.0),
+        Some(pos2(20.0, 0.0))
+    );
+}
@@ -1,6 +1,9 @@
 use emath::Vec2;
 
-use crate::{Color32, textures::TextureOptions};
+use crate::{
+    Color32,
+    textures::{TextureFilter, TextureOptions},
+};
 use std::sync::Arc;
 
 /// An image stored in RAM.
@@ -298,6 +301,164 @@ impl ColorImage {
         );
         Self::new([w, h], pixels)
     }
+
+    /// Copy `src` into this image, with its top-left corner at `pos`.
+    ///
+    /// The pixels are overwritten, not blended.
+    /// Any part of `src` that falls outside of this image is ignored.
+    pub fn blit(&mut self, [x, y]: [usize; 2], src: &Self) {
+        if x >= self.width() || y >= self.height() {
+            return;
+        }
+        let w = src.width().min(self.width() - x);
+        let h = src.height().min(self.height() - y);
+        for row in 0..h {
+            let dst_offset = (y + row) * self.width() + x;
+            let src_offset = row * src.width();
+            self.pixels[dst_offset..dst_offset + w]
+                .copy_from_slice(&src.pixels[src_offset..src_offset + w]);
+        }
+    }
+
+    /// Create a resized copy of this image.
+    ///
+    /// With [`TextureFilter::Nearest`] each new pixel picks the closest source pixel.
+    /// With [`TextureFilter::Linear`] the image is interpolated bilinearly when enlarging,
+    /// and all covered source pixels are averaged when shrinking, to avoid aliasing.
+    pub fn resized(&self, new_size: [usize; 2], filter: TextureFilter) -> Self {
+        let [w, h] = new_size;
+        if self.pixels.is_empty() || w == 0 || h == 0 {
+            return Self::filled(new_size, Color32::TRANSPARENT);
+        }
+
+        let scale_x = self.width() as f32 / w as f32;
+        let scale_y = self.height() as f32 / h as f32;
+
+        let mut pixels = Vec::with_capacity(w * h);
+        for y in 0..h {
+            for x in 0..w {
+                let color = match filter {
+                    TextureFilter::Nearest => {
+                        let sx = ((x as f32 + 0.5) * scale_x) as usize;
+                        let sy = ((y as f32 + 0.5) * scale_y) as usize;
+                        self[(sx.min(self.width() - 1), sy.min(self.height() - 1))]
+                    }
+                    TextureFilter::Linear if scale_x > 1.0 || scale_y > 1.0 => {
+                        let x_range = (x as f32 * scale_x) as usize
+                            ..(((x + 1) as f32 * scale_x).ceil() as usize).min(self.width());
+                        let y_range = (y as f32 * scale_y) as usize
+                            ..(((y + 1) as f32 * scale_y).ceil() as usize).min(self.height());
+                        self.average(x_range, y_range)
+                    }
+                    TextureFilter::Linear => self.sample_bilinear(
+                        (x as f32 + 0.5) * scale_x - 0.5,
+                        (y as f32 + 0.5) * scale_y - 0.5,
+                    ),
+                };
+                pixels.push(color);
+            }
+        }
+
+        Self {
+            size: new_size,
+            source_size: self.source_size,
+            pixels,
+        }
+    }
+
+    /// Generate a full mipmap chain for this image.
+    ///
+    /// The first element is a copy of this image, and each following level
+    /// is half the size of the previous one (rounded down, but at least 1),
+    /// ending with a 1x1 image.
+    ///
+    /// Backends usually generate mipmaps on the GPU (see [`TextureOptions::mipmap_mode`]),
+    /// so this is mostly useful for custom pre-processing or software rendering.
+    pub fn mipmaps(&self) -> Vec<Self> {
+        let mut levels = vec![self.clone()];
+        while let Some(last) = levels.last() {
+            if last.width() <= 1 && last.height() <= 1 {
+                break;
+            }
+            let size = [(last.width() / 2).max(1), (last.height() / 2).max(1)];
+            let next = last.resized(size, TextureFilter::Linear);
+            levels.push(next);
+        }
+        levels
+    }
+
+    /// Reinterpret the stored pixels as unmultiplied RGBA and convert them to premultiplied alpha.
+    ///
+    /// Useful if the image was filled with unmultiplied data, e.g. via [`Self::as_raw_mut`].
+    pub fn premultiply_alpha(&mut self) {
+        for pixel in &mut self.pixels {
+            let [r, g, b, a] = pixel.to_array();
+            *pixel = Color32::from_rgba_unmultiplied(r, g, b, a);
+        }
+    }
+
+    /// Convert the pixels to unmultiplied RGBA, storing the result in place.
+    ///
+    /// Afterwards the pixels are no longer valid [`Color32`] values,
+    /// but [`Self::as_raw`] can be used to e.g. save the image to a file.
+    /// This is the inverse of [`Self::premultiply_alpha`] (up to rounding).
+    pub fn unpremultiply_alpha(&mut self) {
+        for pixel in &mut self.pixels {
+            let [r, g, b, a] = pixel.to_srgba_unmultiplied();
+            *pixel = Color32::from_rgba_premultiplied(r, g, b, a);
+        }
+    }
+
+    /// The pixels as flat unmultiplied RGBA bytes, e.g. for saving to a file.
+    pub fn to_rgba_unmultiplied(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|pixel| pixel.to_srgba_unmultiplied())
+            .collect()
+    }
+
+    /// Average the (premultiplied) colors in the given pixel ranges.
+    fn average(&self, x_range: std::ops::Range<usize>, y_range: std::ops::Range<usize>) -> Color32 {
+        let mut sum = [0_u32; 4];
+        let mut count = 0;
+        for y in y_range {
+            for x in x_range.clone() {
+                for (sum, channel) in sum.iter_mut().zip(self[(x, y)].to_array()) {
+                    *sum += u32::from(channel);
+                }
+                count += 1;
+            }
+        }
+        if count == 0 {
+            return Color32::TRANSPARENT;
+        }
+        let [r, g, b, a] = sum.map(|sum| ((sum + count / 2) / count) as u8);
+        Color32::from_rgba_premultiplied(r, g, b, a)
+    }
+
+    /// Sample at the given texel coordinates, where integer coordinates are pixel centers.
+    fn sample_bilinear(&self, x: f32, y: f32) -> Color32 {
+        let max_x = self.width() - 1;
+        let max_y = self.height() - 1;
+        let x = x.clamp(0.0, max_x as f32);
+        let y = y.clamp(0.0, max_y as f32);
+        let (x0, y0) = (x as usize, y as usize);
+        let (x1, y1) = ((x0 + 1).min(max_x), (y0 + 1).min(max_y));
+        let (tx, ty) = (x - x0 as f32, y - y0 as f32);
+
+        let top = lerp_color(self[(x0, y0)], self[(x1, y0)], tx);
+        let bottom = lerp_color(self[(x0, y1)], self[(x1, y1)], tx);
+        lerp_color(top, bottom, ty)
+    }
+}
+
+/// Linear interpolation of premultiplied colors, in gamma space.
+fn lerp_color(from: Color32, to: Color32, t: f32) -> Color32 {
+    let (from, to) = (from.to_array(), to.to_array());
+    let [r, g, b, a] = std::array::from_fn(|i| {
+        emath::lerp(f32::from(from[i])..=f32::from(to[i]), t).round() as u8
+    });
+    Color32::from_rgba_premultiplied(r, g, b, a)
 }
 
 impl std::ops::Index<(usize, usize)> for ColorImage {
@@ -447,3 +608,23 @@ impl ImageDelta {
         self.pos.is_none()
     }
 }
+
+#[test]
+fn test_resize_and_mipmaps() {
+    let mut image = ColorImage::filled([4, 2], Color32::BLACK);
+    image.blit([2, 0], &ColorImage::filled([4, 4], Color32::WHITE));
+    assert_eq!(image[(1, 1)], Color32::BLACK);
+    assert_eq!(image[(2, 1)], Color32::WHITE);
+
+    let half = image.resized([2, 1], TextureFilter::Linear);
+    assert_eq!(half.pixels, vec![Color32::BLACK, Color32::WHITE]);
+
+    let double = image.resized([8, 4], TextureFilter::Nearest);
+    assert_eq!(double[(3, 3)], Color32::BLACK);
+    assert_eq!(double[(4, 0)], Color32::WHITE);
+
+    let mipmaps = image.mipmaps();
+    let sizes: Vec<_> = mipmaps.iter().map(|level| level.size).collect();
+    assert_eq!(sizes, vec![[4, 2], [2, 1], [1, 1]]);
+    assert_eq!(mipmaps[2][(0, 0)], Color32::from_gray(128));
+}
@@ -32,6 +32,7 @@ mod margin;
 mod margin_f32;
 mod mesh;
 pub mod mutex;
+pub mod rasterizer;
 mod shadow;
 pub mod shape_transform;
 mod shapes;
@@ -54,6 +55,7 @@ pub use self::{
     margin::Margin,
     margin_f32::*,
     mesh::{Mesh, Mesh16, Vertex},
+    rasterizer::Rasterizer,
     shadow::Shadow,
     shapes::{
         CircleShape, CubicBezierShape, EllipseShape, PaintCallback, PaintCallbackInfo, PathShape,
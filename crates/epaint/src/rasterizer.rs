@@ -0,0 +1,188 @@
+//! Painting meshes into an image on the CPU, without a GPU.
+
+use emath::{Pos2, Rect, Vec2, pos2};
+
+use crate::{
+    ClippedPrimitive, Color32, ColorImage, ImageData, Primitive, TextureId, Vertex,
+    textures::TexturesDelta,
+};
+
+/// Paints tessellated shapes into a [`ColorImage`] on the CPU.
+///
+/// This is much slower than a GPU, but works anywhere,
+/// e.g. for exporting an image of some shapes, or in tests.
+///
+/// Colors are blended in gamma space, like egui does on the GPU.
+/// Meshes with textures that were never set are painted as if the texture was white,
+/// and [`Primitive::Callback`]s are skipped.
+#[derive(Clone, Default)]
+pub struct Rasterizer {
+    textures: ahash::HashMap<TextureId, ColorImage>,
+}
+
+impl Rasterizer {
+    /// Add the new and changed textures, e.g. from egui's `FullOutput::textures_delta`.
+    ///
+    /// Call this before [`Self::paint`], and [`Self::free_textures`] after.
+    pub fn set_textures(&mut self, textures_delta: &TexturesDelta) {
+        for (id, delta) in &textures_delta.set {
+            let ImageData::Color(image) = &delta.image;
+            match delta.pos {
+                None => {
+                    self.textures.insert(*id, (**image).clone());
+                }
+                Some([x, y]) => {
+                    let Some(texture) = self.textures.get_mut(id) else {
+                        continue;
+                    };
+                    let width = image.width();
+                    for (row, pixels) in image.pixels.chunks_exact(width).enumerate() {
+                        let start = (y + row) * texture.width() + x;
+                        texture.pixels[start..start + width].copy_from_slice(pixels);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Remove the textures that are no longer used.
+    pub fn free_textures(&mut self, textures_delta: &TexturesDelta) {
+        for id in &textures_delta.free {
+            self.textures.remove(id);
+        }
+    }
+
+    /// Use this image for a texture, e.g. for a [`TextureId::User`].
+    pub fn set_texture(&mut self, id: TextureId, image: ColorImage) {
+        self.textures.insert(id, image);
+    }
+
+    /// Paint the primitives into a new image of `size` pixels, on top of `clear_color`.
+    pub fn paint(
+        &self,
+        size: [usize; 2],
+        pixels_per_point: f32,
+        clear_color: Color32,
+        primitives: &[ClippedPrimitive],
+    ) -> ColorImage {
+        profiling::function_scope!();
+
+        let mut image = ColorImage::filled(size, clear_color);
+        let image_rect = Rect::from_min_size(Pos2::ZERO, Vec2::new(size[0] as f32, size[1] as f32));
+
+        for ClippedPrimitive {
+            clip_rect,
+            primitive,
+        } in primitives
+        {
+            let Primitive::Mesh(mesh) = primitive else {
+                continue;
+            };
+            let clip_rect = *clip_rect * pixels_per_point;
+            let clip_rect = Rect::from_min_max(clip_rect.min.round(), clip_rect.max.round())
+                .intersect(image_rect);
+            let texture = self.textures.get(&mesh.texture_id);
+            for triangle in mesh.indices.chunks_exact(3) {
+                let vertices = [0, 1, 2].map(|i| &mesh.vertices[triangle[i] as usize]);
+                paint_triangle(&mut image, clip_rect, pixels_per_point, vertices, texture);
+            }
+        }
+
+        image
+    }
+}
+
+fn paint_triangle(
+    image: &mut ColorImage,
+    clip_rect: Rect,
+    pixels_per_point: f32,
+    vertices: [&Vertex; 3],
+    texture: Option<&ColorImage>,
+) {
+    let [a, b, c] = vertices.map(|v| (pixels_per_point * v.pos.to_vec2()).to_pos2());
+    let area = edge(a, b, c);
+    if area == 0.0 || !area.is_finite() {
+        return;
+    }
+
+    let bounds = Rect::from_points(&[a, b, c]).intersect(clip_rect);
+    if !bounds.is_positive() {
+        return;
+    }
+
+    let width = image.width();
+    for y in bounds.min.y.floor() as usize..bounds.max.y.ceil() as usize {
+        for x in bounds.min.x.floor() as usize..bounds.max.x.ceil() as usize {
+            let p = pos2(x as f32 + 0.5, y as f32 + 0.5);
+            if !clip_rect.contains(p) {
+                continue;
+            }
+            let weights = [edge(b, c, p) / area, edge(c, a, p) / area, edge(a, b, p) / area];
+            if weights.iter().any(|&w| w < 0.0) {
+                continue;
+            }
+
+            let mut color = interpolate(weights, vertices.map(|v| v.color));
+            if let Some(texture) = texture {
+                let uv = vertices
+                    .iter()
+                    .zip(weights)
+                    .fold(Vec2::ZERO, |uv, (v, w)| uv + w * v.uv.to_vec2());
+                color = color * sample(texture, uv.to_pos2());
+            }
+
+            let pixel = &mut image.pixels[y * width + x];
+            *pixel = pixel.blend(color);
+        }
+    }
+}
+
+/// Twice the signed area of the triangle `a`, `b`, `p`.
+fn edge(a: Pos2, b: Pos2, p: Pos2) -> f32 {
+    (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x)
+}
+
+fn interpolate(weights: [f32; 3], colors: [Color32; 3]) -> Color32 {
+    let channel = |i: usize| {
+        let value: f32 = (0..3).map(|v| weights[v] * colors[v][i] as f32).sum();
+        value.round().clamp(0.0, 255.0) as u8
+    };
+    Color32::from_rgba_premultiplied(channel(0), channel(1), channel(2), channel(3))
+}
+
+/// Bilinear sampling, clamped to the edges.
+fn sample(texture: &ColorImage, uv: Pos2) -> Color32 {
+    let [width, height] = texture.size;
+    if width == 0 || height == 0 {
+        return Color32::WHITE;
+    }
+    let x = (uv.x * width as f32 - 0.5).clamp(0.0, (width - 1) as f32);
+    let y = (uv.y * height as f32 - 0.5).clamp(0.0, (height - 1) as f32);
+    let (x0, y0) = (x.floor() as usize, y.floor() as usize);
+    let (x1, y1) = ((x0 + 1).min(width - 1), (y0 + 1).min(height - 1));
+    let at = |x: usize, y: usize| texture.pixels[y * width + x];
+
+    let top = at(x0, y0).lerp_to_gamma(at(x1, y0), x - x0 as f32);
+    let bottom = at(x0, y1).lerp_to_gamma(at(x1, y1), x - x0 as f32);
+    top.lerp_to_gamma(bottom, y - y0 as f32)
+}
+
+#[test]
+fn test_rasterizer() {
+    use crate::Mesh;
+
+    let mut mesh = Mesh::default();
+    mesh.add_colored_rect(Rect::from_min_size(pos2(1.0, 1.0), Vec2::splat(2.0)), Color32::RED);
+    let primitive = ClippedPrimitive {
+        clip_rect: Rect::EVERYTHING,
+        primitive: Primitive::Mesh(mesh),
+    };
+
+    // At two pixels per point, the rect covers the pixels 2 to 5 on each axis:
+    let image = Rasterizer::default().paint([8, 8], 2.0, Color32::BLACK, &[primitive]);
+    let at = |x: usize, y: usize| image.pixels[y * 8 + x];
+    assert_eq!(at(2, 2), Color32::RED);
+    assert_eq!(at(5, 5), Color32::RED);
+    assert_eq!(at(1, 3), Color32::BLACK);
+    assert_eq!(at(6, 3), Color32::BLACK);
+}
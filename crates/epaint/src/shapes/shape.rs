@@ -2,7 +2,7 @@
 
 use std::sync::Arc;
 
-use emath::{Align2, Pos2, Rangef, Rect, TSTransform, Vec2, pos2};
+use emath::{Align2, Pos2, Rangef, Rect, TSTransform, Vec2, geometry::polygon_contains, pos2};
 
 use crate::{
     Color32, CornerRadius, Mesh, Stroke, StrokeKind, TextureId,
@@ -481,6 +481,122 @@ impl Shape {
     }
 }
 
+/// ## Hit testing
+impl Shape {
+    /// The distance from `pos` to the painted area of this shape.
+    ///
+    /// Returns `0.0` if `pos` is on the fill or the stroke of the shape,
+    /// and [`f32::INFINITY`] if the shape paints nothing (e.g. [`Self::Noop`]
+    /// or a shape with transparent fill and no stroke).
+    ///
+    /// Text and callbacks are treated as their bounding rectangles.
+    /// Ellipses use an approximation which is exact for circles.
+    pub fn distance_to(&self, pos: Pos2) -> f32 {
+        match self {
+            Self::Noop => f32::INFINITY,
+            Self::Vec(shapes) => shapes
+                .iter()
+                .map(|shape| shape.distance_to(pos))
+                .fold(f32::INFINITY, f32::min),
+            Self::Circle(circle) => {
+                let signed_distance = pos.distance(circle.center) - circle.radius;
+                distance_to_painted(
+                    signed_distance,
+                    circle.fill != Color32::TRANSPARENT,
+                    stroke_band(
+                        circle.stroke.is_empty(),
+                        circle.stroke.width,
+                        StrokeKind::Middle,
+                    ),
+                )
+            }
+            Self::Ellipse(ellipse) => {
+                let radius = ellipse.radius;
+                if radius.x <= 0.0 || radius.y <= 0.0 {
+                    return f32::INFINITY;
+                }
+                let normalized = (pos - ellipse.center) / radius;
+                let signed_distance = (normalized.length() - 1.0) * radius.min_elem();
+                distance_to_painted(
+                    signed_distance,
+                    ellipse.fill != Color32::TRANSPARENT,
+                    stroke_band(
+                        ellipse.stroke.is_empty(),
+                        ellipse.stroke.width,
+                        StrokeKind::Middle,
+                    ),
+                )
+            }
+            Self::LineSegment { points, stroke } => {
+                if stroke.is_empty() {
+                    f32::INFINITY
+                } else {
+                    (distance_to_segment(pos, points[0], points[1]) - 0.5 * stroke.width).max(0.0)
+                }
+            }
+            Self::Path(path) => distance_to_path(
+                pos,
+                &path.points,
+                path.closed,
+                path.fill != Color32::TRANSPARENT,
+                &path.stroke,
+            ),
+            Self::Rect(rect_shape) => {
+                let signed_distance =
+                    signed_distance_to_rounded_rect(pos, rect_shape.rect, rect_shape.corner_radius);
+                distance_to_painted(
+                    signed_distance,
+                    rect_shape.fill != Color32::TRANSPARENT || rect_shape.brush.is_some(),
+                    stroke_band(
+                        rect_shape.stroke.is_empty(),
+                        rect_shape.stroke.width,
+                        rect_shape.stroke_kind,
+                    ),
+                )
+            }
+            Self::Text(text_shape) => {
+                if text_shape.galley.is_empty() {
+                    f32::INFINITY
+                } else {
+                    text_shape.visual_bounding_rect().distance_to_pos(pos)
+                }
+            }
+            Self::Mesh(mesh) => distance_to_mesh(pos, mesh),
+            Self::QuadraticBezier(bezier) => distance_to_path(
+                pos,
+                &bezier.flatten(Some(0.1)),
+                bezier.closed,
+                bezier.fill != Color32::TRANSPARENT,
+                &bezier.stroke,
+            ),
+            Self::CubicBezier(bezier) => distance_to_path(
+                pos,
+                &bezier.flatten(Some(0.1)),
+                bezier.closed,
+                bezier.fill != Color32::TRANSPARENT,
+                &bezier.stroke,
+            ),
+            Self::Callback(callback) => callback.rect.distance_to_pos(pos),
+        }
+    }
+
+    /// Is `pos` on the fill or stroke of this shape?
+    ///
+    /// See [`Self::distance_to`] for details.
+    #[inline]
+    pub fn contains(&self, pos: Pos2) -> bool {
+        self.distance_to(pos) <= 0.0
+    }
+
+    /// Is `pos` within `tolerance` points of the painted area of this shape?
+    ///
+    /// Useful for picking thin lines, where an exact hit is hard to achieve.
+    #[inline]
+    pub fn hit_test(&self, pos: Pos2, tolerance: f32) -> bool {
+        self.distance_to(pos) <= tolerance
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 /// Creates equally spaced filled circles from a line.
@@ -558,3 +674,152 @@ fn dashes_from_line(
         position_on_segment -= segment_length;
     }
 }
+
+/// The range of signed distances (negative inside) covered by a stroke of the given kind.
+fn stroke_band(is_empty: bool, width: f32, kind: StrokeKind) -> Option<(f32, f32)> {
+    if is_empty {
+        return None;
+    }
+    Some(match kind {
+        StrokeKind::Inside => (-width, 0.0),
+        StrokeKind::Middle => (-0.5 * width, 0.5 * width),
+        StrokeKind::Outside => (0.0, width),
+    })
+}
+
+/// Distance to the painted area, given the signed distance to the outline of a closed shape.
+fn distance_to_painted(signed_distance: f32, filled: bool, stroke: Option<(f32, f32)>) -> f32 {
+    let mut distance = f32::INFINITY;
+    if filled {
+        distance = signed_distance.max(0.0);
+    }
+    if let Some((min, max)) = stroke {
+        let stroke_distance = (min - signed_distance).max(signed_distance - max).max(0.0);
+        distance = distance.min(stroke_distance);
+    }
+    distance
+}
+
+fn distance_to_segment(pos: Pos2, a: Pos2, b: Pos2) -> f32 {
+    let ab = b - a;
+    let length_sq = ab.length_sq();
+    if length_sq <= 0.0 {
+        return pos.distance(a);
+    }
+    let t = ((pos - a).dot(ab) / length_sq).clamp(0.0, 1.0);
+    pos.distance(a + t * ab)
+}
+
+fn distance_to_path(
+    pos: Pos2,
+    points: &[Pos2],
+    closed: bool,
+    filled: bool,
+    stroke: &PathStroke,
+) -> f32 {
+    if points.is_empty() {
+        return f32::INFINITY;
+    }
+
+    let mut distance = points
+        .windows(2)
+        .map(|w| distance_to_segment(pos, w[0], w[1]))
+        .fold(pos.distance(points[0]), f32::min);
+
+    if !closed {
+        return if stroke.is_empty() {
+            f32::INFINITY
+        } else {
+            (distance - 0.5 * stroke.width).max(0.0)
+        };
+    }
+
+    if let (Some(&first), Some(&last)) = (points.first(), points.last()) {
+        distance = distance.min(distance_to_segment(pos, last, first));
+    }
+    let signed_distance = if polygon_contains(points, pos) {
+        -distance
+    } else {
+        distance
+    };
+    distance_to_painted(
+        signed_distance,
+        filled,
+        stroke_band(stroke.is_empty(), stroke.width, stroke.kind),
+    )
+}
+
+/// Signed distance to a rectangle with rounded corners, negative inside.
+fn signed_distance_to_rounded_rect(pos: Pos2, rect: Rect, corner_radius: CornerRadius) -> f32 {
+    let center = rect.center();
+    let half_size = 0.5 * rect.size();
+
+    // Pick the radius of the corner in the same quadrant as `pos`:
+    let radius = match (pos.x < center.x, pos.y < center.y) {
+        (true, true) => corner_radius.nw,
+        (false, true) => corner_radius.ne,
+        (true, false) => corner_radius.sw,
+        (false, false) => corner_radius.se,
+    };
+    let radius = f32::from(radius).min(half_size.min_elem());
+
+    let d = (pos - center).abs() - half_size + Vec2::splat(radius);
+    d.max(Vec2::ZERO).length() + d.max_elem().min(0.0) - radius
+}
+
+fn distance_to_mesh(pos: Pos2, mesh: &Mesh) -> f32 {
+    let mut distance = f32::INFINITY;
+    for triangle in mesh.indices.chunks_exact(3) {
+        let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[triangle[i] as usize].pos);
+        if polygon_contains(&[a, b, c], pos) {
+            return 0.0;
+        }
+        distance = distance
+            .min(distance_to_segment(pos, a, b))
+            .min(distance_to_segment(pos, b, c))
+            .min(distance_to_segment(pos, c, a));
+    }
+    distance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hit_test_shapes() {
+        let circle = Shape::circle_filled(pos2(0.0, 0.0), 10.0, Color32::RED);
+        assert!(circle.contains(pos2(5.0, 5.0)));
+        assert_eq!(circle.distance_to(pos2(20.0, 0.0)), 10.0);
+
+        let ring = Shape::circle_stroke(pos2(0.0, 0.0), 10.0, Stroke::new(2.0, Color32::RED));
+        assert!(!ring.contains(pos2(0.0, 0.0)));
+        assert!(ring.contains(pos2(10.5, 0.0)));
+
+        let line = Shape::line_segment(
+            [pos2(0.0, 0.0), pos2(10.0, 0.0)],
+            Stroke::new(2.0, Color32::RED),
+        );
+        assert!(line.contains(pos2(5.0, 1.0)));
+        assert!(!line.contains(pos2(5.0, 3.0)));
+        assert!(line.hit_test(pos2(5.0, 3.0), 2.0));
+
+        let rect = Shape::rect_filled(
+            Rect::from_min_max(pos2(0.0, 0.0), pos2(10.0, 10.0)),
+            5.0,
+            Color32::RED,
+        );
+        assert!(rect.contains(pos2(5.0, 5.0)));
+        assert!(!rect.contains(pos2(0.5, 0.5)), "Outside the rounded corner");
+
+        let triangle = Shape::convex_polygon(
+            vec![pos2(0.0, 0.0), pos2(10.0, 0.0), pos2(0.0, 10.0)],
+            Color32::RED,
+            Stroke::NONE,
+        );
+        assert!(triangle.contains(pos2(2.0, 2.0)));
+        assert!(!triangle.contains(pos2(8.0, 8.0)));
+
+        assert_eq!(Shape::Noop.distance_to(Pos2::ZERO), f32::INFINITY);
+    }
+}
@@ -2208,20 +2208,29 @@ impl Tessellator {
     ///
     /// ## Returns
     /// A list of clip rectangles with matching [`Mesh`].
-    #[allow(unused_mut, clippy::allow_attributes)]
     pub fn tessellate_shapes(&mut self, mut shapes: Vec<ClippedShape>) -> Vec<ClippedPrimitive> {
+        self.tessellate_shapes_drain(&mut shapes)
+    }
+
+    /// Like [`Self::tessellate_shapes`], but takes the shapes out of the given buffer.
+    ///
+    /// The buffer is left empty, but keeps its capacity, so it can be reused next frame.
+    pub fn tessellate_shapes_drain(
+        &mut self,
+        shapes: &mut Vec<ClippedShape>,
+    ) -> Vec<ClippedPrimitive> {
         profiling::function_scope!();
 
         #[cfg(feature = "rayon")]
         if self.options.parallel_tessellation {
-            self.parallel_tessellation_of_large_shapes(&mut shapes);
+            self.parallel_tessellation_of_large_shapes(shapes);
         }
 
         let mut clipped_primitives: Vec<ClippedPrimitive> = Vec::default();
 
         {
             profiling::scope!("tessellate");
-            for clipped_shape in shapes {
+            for clipped_shape in shapes.drain(..) {
                 self.tessellate_clipped_shape(clipped_shape, &mut clipped_primitives);
             }
         }
@@ -80,6 +80,14 @@ pub struct FontImpl {
     pixels_per_point: f32,
     glyph_info_cache: RwLock<ahash::HashMap<char, GlyphInfo>>, // TODO(emilk): standard Mutex
     atlas: Arc<Mutex<TextureAtlas>>,
+
+    /// The font file, for shaping text with.
+    #[cfg(feature = "shaping")]
+    font_data: Option<Arc<crate::text::FontData>>,
+
+    /// The images of shaped clusters, by their glyphs and the positions of those in pixels.
+    #[cfg(feature = "shaping")]
+    cluster_cache: RwLock<ahash::HashMap<Vec<(ab_glyph::GlyphId, i32, i32)>, UvRect>>,
 }
 
 impl FontImpl {
@@ -136,9 +144,22 @@ impl FontImpl {
             pixels_per_point,
             glyph_info_cache: Default::default(),
             atlas,
+            #[cfg(feature = "shaping")]
+            font_data: None,
+            #[cfg(feature = "shaping")]
+            cluster_cache: Default::default(),
         }
     }
 
+    /// Shape text with this font file, which must be the one of the `ab_glyph_font`.
+    ///
+    /// Without it, text is laid out one character at a time.
+    #[cfg(feature = "shaping")]
+    pub(crate) fn with_font_data(mut self, font_data: Option<Arc<crate::text::FontData>>) -> Self {
+        self.font_data = font_data;
+        self
+    }
+
     /// Code points that will always be replaced by the replacement character.
     ///
     /// See also [`invisible_char`].
@@ -269,43 +290,7 @@ impl FontImpl {
             self.scale_in_pixels as f32,
             ab_glyph::Point { x: 0.0, y: 0.0 },
         );
-
-        let uv_rect = self.ab_glyph_font.outline_glyph(glyph).map(|glyph| {
-            let bb = glyph.px_bounds();
-            let glyph_width = bb.width() as usize;
-            let glyph_height = bb.height() as usize;
-            if glyph_width == 0 || glyph_height == 0 {
-                UvRect::default()
-            } else {
-                let glyph_pos = {
-                    let atlas = &mut self.atlas.lock();
-                    let text_alpha_from_coverage = atlas.text_alpha_from_coverage;
-                    let (glyph_pos, image) = atlas.allocate((glyph_width, glyph_height));
-                    glyph.draw(|x, y, v| {
-                        if 0.0 < v {
-                            let px = glyph_pos.0 + x as usize;
-                            let py = glyph_pos.1 + y as usize;
-                            image[(px, py)] = text_alpha_from_coverage.color_from_coverage(v);
-                        }
-                    });
-                    glyph_pos
-                };
-
-                let offset_in_pixels = vec2(bb.min.x, bb.min.y);
-                let offset =
-                    offset_in_pixels / self.pixels_per_point + self.y_offset_in_points * Vec2::Y;
-                UvRect {
-                    offset,
-                    size: vec2(glyph_width as f32, glyph_height as f32) / self.pixels_per_point,
-                    min: [glyph_pos.0 as u16, glyph_pos.1 as u16],
-                    max: [
-                        (glyph_pos.0 + glyph_width) as u16,
-                        (glyph_pos.1 + glyph_height) as u16,
-                    ],
-                }
-            }
-        });
-        let uv_rect = uv_rect.unwrap_or_default();
+        let uv_rect = self.allocate_uv_rect([glyph]);
 
         let advance_width_in_points = self
             .ab_glyph_font
@@ -319,6 +304,143 @@ impl FontImpl {
             uv_rect,
         }
     }
+
+    /// Draw the glyphs into one image in the atlas.
+    fn allocate_uv_rect(&self, glyphs: impl IntoIterator<Item = ab_glyph::Glyph>) -> UvRect {
+        use ab_glyph::Font as _;
+
+        let glyphs: Vec<ab_glyph::OutlinedGlyph> = glyphs
+            .into_iter()
+            .filter_map(|glyph| self.ab_glyph_font.outline_glyph(glyph))
+            .collect();
+        let Some(bb) = glyphs
+            .iter()
+            .map(|glyph| glyph.px_bounds())
+            .reduce(|a, b| ab_glyph::Rect {
+                min: ab_glyph::point(a.min.x.min(b.min.x), a.min.y.min(b.min.y)),
+                max: ab_glyph::point(a.max.x.max(b.max.x), a.max.y.max(b.max.y)),
+            })
+        else {
+            return UvRect::default();
+        };
+        let glyph_width = bb.width() as usize;
+        let glyph_height = bb.height() as usize;
+        if glyph_width == 0 || glyph_height == 0 {
+            return UvRect::default();
+        }
+
+        // Overlapping glyphs, e.g. a letter and its accent, add up:
+        let mut coverage = vec![0.0_f32; glyph_width * glyph_height];
+        for glyph in &glyphs {
+            let min = glyph.px_bounds().min;
+            let (dx, dy) = ((min.x - bb.min.x) as usize, (min.y - bb.min.y) as usize);
+            glyph.draw(|x, y, v| {
+                let (x, y) = (dx + x as usize, dy + y as usize);
+                if x < glyph_width && y < glyph_height {
+                    let c = &mut coverage[y * glyph_width + x];
+                    *c = (*c + v).min(1.0);
+                }
+            });
+        }
+
+        let glyph_pos = {
+            let atlas = &mut self.atlas.lock();
+            let text_alpha_from_coverage = atlas.text_alpha_from_coverage;
+            let (glyph_pos, image) = atlas.allocate((glyph_width, glyph_height));
+            for (i, &v) in coverage.iter().enumerate() {
+                if 0.0 < v {
+                    let px = glyph_pos.0 + i % glyph_width;
+                    let py = glyph_pos.1 + i / glyph_width;
+                    image[(px, py)] = text_alpha_from_coverage.color_from_coverage(v);
+                }
+            }
+            glyph_pos
+        };
+
+        let offset_in_pixels = vec2(bb.min.x, bb.min.y);
+        let offset = offset_in_pixels / self.pixels_per_point + self.y_offset_in_points * Vec2::Y;
+        UvRect {
+            offset,
+            size: vec2(glyph_width as f32, glyph_height as f32) / self.pixels_per_point,
+            min: [glyph_pos.0 as u16, glyph_pos.1 as u16],
+            max: [
+                (glyph_pos.0 + glyph_width) as u16,
+                (glyph_pos.1 + glyph_height) as u16,
+            ],
+        }
+    }
+
+    /// Shape `text`, which should only contain characters of this font.
+    ///
+    /// Returns the clusters of glyphs in logical order,
+    /// each with the byte offset in `text` of its first character.
+    /// A cluster is drawn as one, e.g. a ligature, or a letter with its accents.
+    ///
+    /// `None` if there is no font file to shape with.
+    #[cfg(feature = "shaping")]
+    fn shape(&self, text: &str) -> Option<Vec<(usize, GlyphInfo)>> {
+        use ab_glyph::{Font as _, ScaleFont as _};
+
+        let font_data = self.font_data.as_ref()?;
+        let face = rustybuzz::Face::from_slice(&font_data.font, font_data.index)?;
+        let mut buffer = rustybuzz::UnicodeBuffer::new();
+        buffer.push_str(text);
+        buffer.guess_segment_properties();
+        let shaped = rustybuzz::shape(&face, &[], buffer);
+
+        let scaled = self.ab_glyph_font.as_scaled(self.scale_in_pixels as f32);
+        let (h_scale, v_scale) = (scaled.h_scale_factor(), scaled.v_scale_factor());
+
+        // The glyphs are in visual order, so right-to-left for e.g. Arabic.
+        let infos = shaped.glyph_infos();
+        let mut clusters = vec![];
+        let mut cluster_glyphs = vec![];
+        let mut pen_x = 0.0;
+        for (i, (info, pos)) in infos.iter().zip(shaped.glyph_positions()).enumerate() {
+            let x = pen_x + pos.x_offset as f32 * h_scale;
+            let y = -pos.y_offset as f32 * v_scale; // Font units go up, pixels go down.
+            let glyph_id = ab_glyph::GlyphId(info.glyph_id as u16);
+            cluster_glyphs.push((glyph_id, x.round() as i32, y.round() as i32));
+            pen_x += pos.x_advance as f32 * h_scale;
+
+            if infos.get(i + 1).is_none_or(|next| next.cluster != info.cluster) {
+                let glyph_info = GlyphInfo {
+                    id: glyph_id,
+                    advance_width: pen_x / self.pixels_per_point,
+                    uv_rect: self.cluster_uv_rect(&cluster_glyphs),
+                };
+                clusters.push((info.cluster as usize, glyph_info));
+                cluster_glyphs.clear();
+                pen_x = 0.0;
+            }
+        }
+        clusters.sort_by_key(|(byte_offset, _)| *byte_offset);
+        Some(clusters)
+    }
+
+    /// The image of a shaped cluster, given its glyphs and their positions in pixels.
+    #[cfg(feature = "shaping")]
+    fn cluster_uv_rect(&self, glyphs: &[(ab_glyph::GlyphId, i32, i32)]) -> UvRect {
+        {
+            if let Some(uv_rect) = self.cluster_cache.read().get(glyphs) {
+                return *uv_rect;
+            }
+        }
+
+        let uv_rect = self.allocate_uv_rect(
+            glyphs
+                .iter()
+                .filter(|(glyph_id, _, _)| glyph_id.0 != 0) // Don't draw missing glyphs
+                .map(|&(glyph_id, x, y)| {
+                    glyph_id.with_scale_and_position(
+                        self.scale_in_pixels as f32,
+                        ab_glyph::point(x as f32, y as f32),
+                    )
+                }),
+        );
+        self.cluster_cache.write().insert(glyphs.to_vec(), uv_rect);
+        uv_rect
+    }
 }
 
 type FontIndex = usize;
@@ -467,6 +589,58 @@ impl Font {
         (Some(font_impl), glyph_info)
     }
 
+    /// Shape the runs of characters in `text` that are in the same font, see [`FontImpl::shape`].
+    ///
+    /// Returns the glyphs of the shaped characters by their byte offset in `text`.
+    /// Each cluster is drawn with its first character,
+    /// and its other characters are `None`, i.e. take no space.
+    ///
+    /// Whitespace, soft hyphens and characters missing from all fonts are not shaped.
+    #[cfg(feature = "shaping")]
+    pub(crate) fn shape(&mut self, text: &str) -> ahash::HashMap<usize, Option<GlyphInfo>> {
+        let mut shaped = ahash::HashMap::default();
+        if self.fonts.is_empty() {
+            return shaped;
+        }
+
+        // The font and byte offset of the run of characters so far:
+        let mut run: Option<(FontIndex, usize)> = None;
+        let end = std::iter::once((text.len(), ' '));
+        for (byte_offset, chr) in text.char_indices().chain(end) {
+            let font_index = if chr.is_whitespace() || chr == crate::text::SOFT_HYPHEN {
+                None
+            } else if run.is_some() && invisible_char(chr) {
+                run.map(|(font_index, _)| font_index) // e.g. a zero width joiner
+            } else {
+                let font_index_glyph_info = self.glyph_info(chr);
+                (font_index_glyph_info != self.replacement_glyph).then_some(font_index_glyph_info.0)
+            };
+
+            if run.map(|(font_index, _)| font_index) == font_index {
+                continue;
+            }
+            if let Some((font_index, run_start)) = run {
+                let run_text = &text[run_start..byte_offset];
+                if let Some(clusters) = self.fonts[font_index].shape(run_text) {
+                    for (offset, _) in run_text.char_indices() {
+                        shaped.insert(run_start + offset, None);
+                    }
+                    for (offset, glyph_info) in clusters {
+                        shaped.insert(run_start + offset, Some(glyph_info));
+                    }
+                }
+            }
+            run = font_index.map(|font_index| (font_index, byte_offset));
+        }
+        shaped
+    }
+
+    #[cfg(not(feature = "shaping"))]
+    #[expect(clippy::unused_self)]
+    pub(crate) fn shape(&mut self, _text: &str) -> ahash::HashMap<usize, Option<GlyphInfo>> {
+        Default::default()
+    }
+
     pub(crate) fn ascent(&self) -> f32 {
         if let Some(first) = self.fonts.first() {
             first.ascent()
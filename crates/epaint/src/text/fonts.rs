@@ -472,6 +472,7 @@ impl Fonts {
 
         if needs_recreate {
             let definitions = fonts_and_cache.fonts.definitions.clone();
+            let max_cached_galleys = fonts_and_cache.galley_cache.max_cached_galleys;
 
             *fonts_and_cache = FontsAndCache {
                 fonts: FontsImpl::new(
@@ -480,7 +481,10 @@ impl Fonts {
                     text_alpha_from_coverage,
                     definitions,
                 ),
-                galley_cache: Default::default(),
+                galley_cache: GalleyCache {
+                    max_cached_galleys,
+                    ..Default::default()
+                },
             };
         }
 
@@ -579,6 +583,22 @@ impl Fonts {
         self.lock().galley_cache.num_galleys_in_cache()
     }
 
+    /// How well the [`Galley`] cache worked during the last pass.
+    pub fn galley_cache_stats(&self) -> GalleyCacheStats {
+        self.lock().galley_cache.stats()
+    }
+
+    /// Keep galleys that weren't used this pass around, as long as the cache holds at most this many.
+    ///
+    /// This avoids re-layout of text that comes and goes, e.g. rows of a large table
+    /// that are scrolled in and out of view.
+    /// Galleys used in the current pass are never evicted, even if there are more of them.
+    ///
+    /// The default is `0`, meaning only galleys used in the last pass are kept.
+    pub fn set_max_cached_galleys(&self, max_cached_galleys: usize) {
+        self.lock().galley_cache.max_cached_galleys = max_cached_galleys;
+    }
+
     /// How full is the font atlas?
     ///
     /// This increases as new fonts and/or glyphs are used,
@@ -758,11 +778,38 @@ struct CachedGalley {
     galley: Arc<Galley>,
 }
 
+/// Statistics about the [`Galley`] cache of [`Fonts`].
+///
+/// See [`Fonts::galley_cache_stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GalleyCacheStats {
+    /// Number of galleys in the cache.
+    pub num_galleys: usize,
+
+    /// Number of layouts in the last pass that were found in the cache.
+    pub hits: usize,
+
+    /// Number of layouts in the last pass that had to be computed.
+    pub misses: usize,
+
+    /// Number of galleys removed from the cache at the end of the last pass.
+    pub evictions: usize,
+}
+
 #[derive(Default)]
 struct GalleyCache {
     /// Frame counter used to do garbage collection on the cache
     generation: u32,
     cache: nohash_hasher::IntMap<u64, CachedGalley>,
+
+    /// See [`Fonts::set_max_cached_galleys`].
+    max_cached_galleys: usize,
+
+    /// Counts for the current pass.
+    current_stats: GalleyCacheStats,
+
+    /// Counts for the last completed pass.
+    last_stats: GalleyCacheStats,
 }
 
 impl GalleyCache {
@@ -802,6 +849,7 @@ impl GalleyCache {
         let galley = match self.cache.entry(hash) {
             std::collections::hash_map::Entry::Occupied(entry) => {
                 // The job was found in cache - no need to re-layout.
+                self.current_stats.hits += 1;
                 let cached = entry.into_mut();
                 cached.last_used = self.generation;
 
@@ -822,6 +870,7 @@ impl GalleyCache {
                 galley
             }
             std::collections::hash_map::Entry::Vacant(entry) => {
+                self.current_stats.misses += 1;
                 let job = Arc::new(job);
                 if allow_split_paragraphs && should_cache_each_paragraph_individually(&job) {
                     let (child_galleys, child_hashes) =
@@ -976,12 +1025,45 @@ impl GalleyCache {
         self.cache.len()
     }
 
+    pub fn stats(&self) -> GalleyCacheStats {
+        GalleyCacheStats {
+            num_galleys: self.cache.len(),
+            ..self.last_stats
+        }
+    }
+
     /// Must be called once per frame to clear the [`Galley`] cache.
     pub fn flush_cache(&mut self) {
         let current_generation = self.generation;
-        self.cache.retain(|_key, cached| {
-            cached.last_used == current_generation // only keep those that were used this frame
-        });
+        let num_galleys_before = self.cache.len();
+
+        // Always keep those that were used this frame.
+        // Of the others, keep the most recently used ones that fit within `max_cached_galleys`:
+        let num_used = self
+            .cache
+            .values()
+            .filter(|cached| cached.last_used == current_generation)
+            .count();
+        let max_unused = self.max_cached_galleys.saturating_sub(num_used);
+
+        if max_unused == 0 {
+            self.cache
+                .retain(|_key, cached| cached.last_used == current_generation);
+        } else if max_unused < num_galleys_before - num_used {
+            let mut unused: Vec<(u32, u64)> = self
+                .cache
+                .iter()
+                .filter(|(_, cached)| cached.last_used != current_generation)
+                .map(|(&hash, cached)| (current_generation.wrapping_sub(cached.last_used), hash))
+                .collect();
+            unused.sort_unstable();
+            for (_age, hash) in &unused[max_unused..] {
+                self.cache.remove(hash);
+            }
+        }
+
+        self.current_stats.evictions = num_galleys_before - self.cache.len();
+        self.last_stats = std::mem::take(&mut self.current_stats);
         self.generation = self.generation.wrapping_add(1);
     }
 }
@@ -1003,6 +1085,10 @@ struct FontImplCache {
     pixels_per_point: f32,
     ab_glyph_fonts: BTreeMap<String, (FontTweak, ab_glyph::FontArc)>,
 
+    /// The font files, for shaping text with.
+    #[cfg(feature = "shaping")]
+    font_data: BTreeMap<String, Arc<FontData>>,
+
     /// Map font pixel sizes and names to the cached [`FontImpl`].
     cache: ahash::HashMap<(u32, String), Arc<FontImpl>>,
 }
@@ -1026,6 +1112,8 @@ impl FontImplCache {
             atlas,
             pixels_per_point,
             ab_glyph_fonts,
+            #[cfg(feature = "shaping")]
+            font_data: font_data.clone(),
             cache: Default::default(),
         }
     }
@@ -1054,14 +1142,17 @@ impl FontImplCache {
                 font_name.to_owned(),
             ))
             .or_insert_with(|| {
-                Arc::new(FontImpl::new(
+                let font_impl = FontImpl::new(
                     self.atlas.clone(),
                     self.pixels_per_point,
                     font_name.to_owned(),
                     ab_glyph_font,
                     scale_in_pixels,
                     tweak,
-                ))
+                );
+                #[cfg(feature = "shaping")]
+                let font_impl = font_impl.with_font_data(self.font_data.get(font_name).cloned());
+                Arc::new(font_impl)
             })
             .clone()
     }
@@ -1215,6 +1306,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_galley_cache_limit() {
+        let mut fonts = FontsImpl::new(
+            1.0,
+            4096,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+        let job = |text: &str| {
+            LayoutJob::simple(
+                text.to_owned(),
+                FontId::default(),
+                Color32::WHITE,
+                f32::INFINITY,
+            )
+        };
+
+        let mut cache = GalleyCache {
+            max_cached_galleys: 2,
+            ..Default::default()
+        };
+        for text in ["a", "b", "c"] {
+            cache.layout(&mut fonts, job(text), false);
+        }
+        cache.flush_cache();
+        assert_eq!(cache.stats().misses, 3);
+        assert_eq!(cache.stats().evictions, 0, "Galleys used this pass are kept");
+
+        cache.layout(&mut fonts, job("a"), false);
+        cache.flush_cache();
+        assert_eq!(cache.stats().hits, 1);
+        assert_eq!(cache.stats().evictions, 1);
+        assert_eq!(cache.stats().num_galleys, 2);
+
+        cache.max_cached_galleys = 0;
+        cache.flush_cache();
+        assert_eq!(cache.stats().num_galleys, 0);
+    }
+
     #[test]
     fn test_intrinsic_size() {
         let pixels_per_point = [1.0, 1.3, 2.0, 0.867];
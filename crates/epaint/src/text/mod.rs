@@ -12,7 +12,7 @@ pub const TAB_SIZE: usize = 4;
 pub use {
     fonts::{
         FontData, FontDefinitions, FontFamily, FontId, FontInsert, FontPriority, FontTweak, Fonts,
-        FontsImpl, InsertFontFamily,
+        FontsImpl, GalleyCacheStats, InsertFontFamily,
     },
     text_layout::*,
     text_layout_types::*,
@@ -20,3 +20,9 @@ pub use {
 
 /// Suggested character to use to replace those in password text fields.
 pub const PASSWORD_REPLACEMENT_CHAR: char = '•';
+
+/// An invisible character marking where a word may be broken across rows.
+///
+/// If a row is broken there, it is shown as a `-` at the end of the row.
+/// See [`insert_soft_hyphens`].
+pub const SOFT_HYPHEN: char = '\u{AD}';
@@ -4,7 +4,10 @@ use emath::{Align, GuiRounding as _, NumExt as _, Pos2, Rect, Vec2, pos2, vec2};
 
 use crate::{Color32, Mesh, Stroke, Vertex, stroke::PathStroke, text::font::Font};
 
-use super::{FontsImpl, Galley, Glyph, LayoutJob, LayoutSection, PlacedRow, Row, RowVisuals};
+use super::{
+    FontsImpl, Galley, Glyph, LayoutJob, LayoutSection, PlacedRow, Row, RowVisuals, SOFT_HYPHEN,
+    font::UvRect,
+};
 
 // ----------------------------------------------------------------------------
 
@@ -88,9 +91,18 @@ pub fn layout(fonts: &mut FontsImpl, job: Arc<LayoutJob>) -> Galley {
 
     // For most of this we ignore the y coordinate:
 
+    let bidi_levels = bidi_levels(&job.text);
+
     let mut paragraphs = vec![Paragraph::from_section_index(0)];
     for (section_index, section) in job.sections.iter().enumerate() {
-        layout_section(fonts, &job, section_index as u32, section, &mut paragraphs);
+        layout_section(
+            fonts,
+            &job,
+            &bidi_levels,
+            section_index as u32,
+            section,
+            &mut paragraphs,
+        );
     }
 
     let point_scale = PointScale::new(fonts.pixels_per_point());
@@ -99,6 +111,7 @@ pub fn layout(fonts: &mut FontsImpl, job: Arc<LayoutJob>) -> Galley {
 
     let mut elided = false;
     let mut rows = rows_from_paragraphs(paragraphs, &job, &mut elided);
+    hide_soft_hyphens(&mut rows);
     if elided {
         if let Some(last_placed) = rows.last_mut() {
             let last_row = Arc::make_mut(&mut last_placed.row);
@@ -126,14 +139,37 @@ pub fn layout(fonts: &mut FontsImpl, job: Arc<LayoutJob>) -> Galley {
         }
     }
 
+    if !bidi_levels.is_empty() {
+        for placed_row in &mut rows {
+            reorder_bidi_row(placed_row);
+        }
+    }
+
     // Calculate the Y positions and tessellate the text:
     galley_from_rows(point_scale, job, rows, elided, intrinsic_size)
 }
 
+/// The bidi embedding level of each byte of the text,
+/// or nothing if the text is all left-to-right.
+fn bidi_levels(text: &str) -> Vec<u8> {
+    // All right-to-left characters and bidi control characters are at U+0590 or above:
+    if !text.chars().any(|chr| '\u{0590}' <= chr) {
+        return vec![];
+    }
+
+    profiling::function_scope!();
+    let bidi_info = unicode_bidi::BidiInfo::new(text, None);
+    if !bidi_info.has_rtl() {
+        return vec![];
+    }
+    bidi_info.levels.iter().map(|level| level.number()).collect()
+}
+
 // Ignores the Y coordinate.
 fn layout_section(
     fonts: &mut FontsImpl,
     job: &LayoutJob,
+    bidi_levels: &[u8],
     section_index: u32,
     section: &LayoutSection,
     out_paragraphs: &mut Vec<Paragraph>,
@@ -159,16 +195,32 @@ fn layout_section(
 
     let mut last_glyph_id = None;
 
-    for chr in job.text[byte_range.clone()].chars() {
+    // Empty without the `shaping` feature:
+    let shaped_glyphs = font.shape(&job.text[byte_range.clone()]);
+
+    for (byte_offset, chr) in job.text[byte_range.clone()].char_indices() {
         if job.break_on_newline && chr == '\n' {
             out_paragraphs.push(Paragraph::from_section_index(section_index));
             paragraph = out_paragraphs.last_mut().unwrap();
             paragraph.empty_paragraph_height = line_height; // TODO(emilk): replace this hack with actually including `\n` in the glyphs?
         } else {
-            let (font_impl, glyph_info) = font.font_impl_and_glyph_info(chr);
+            // A soft hyphen is laid out as a `-` that takes no space,
+            // and is hidden later unless the row is broken there:
+            let is_soft_hyphen = chr == SOFT_HYPHEN;
+            let (font_impl, glyph_info) =
+                font.font_impl_and_glyph_info(if is_soft_hyphen { '-' } else { chr });
+
+            // Shaped glyphs are kerned already.
+            // A cluster is drawn with its first character, and the others take no space:
+            let shaped_glyph_info = shaped_glyphs.get(&byte_offset).copied();
+            let is_cluster_start = shaped_glyph_info.is_none_or(|glyph_info| glyph_info.is_some());
+            let glyph_info = shaped_glyph_info.map_or(glyph_info, Option::unwrap_or_default);
+
             if let Some(font_impl) = font_impl {
-                if let Some(last_glyph_id) = last_glyph_id {
-                    paragraph.cursor_x += font_impl.pair_kerning(last_glyph_id, glyph_info.id);
+                if let Some(last_glyph_id) = last_glyph_id.filter(|_| is_cluster_start) {
+                    if shaped_glyph_info.is_none() {
+                        paragraph.cursor_x += font_impl.pair_kerning(last_glyph_id, glyph_info.id);
+                    }
                     paragraph.cursor_x += extra_letter_spacing;
                 }
             }
@@ -183,12 +235,18 @@ fn layout_section(
                 font_height: font.row_height(),
                 font_ascent: font.ascent(),
                 uv_rect: glyph_info.uv_rect,
+                bidi_level: bidi_levels
+                    .get(byte_range.start + byte_offset)
+                    .copied()
+                    .unwrap_or_default(),
                 section_index,
             });
 
-            paragraph.cursor_x += glyph_info.advance_width;
-            paragraph.cursor_x = font.round_to_pixel(paragraph.cursor_x);
-            last_glyph_id = Some(glyph_info.id);
+            if !is_soft_hyphen && is_cluster_start {
+                paragraph.cursor_x += glyph_info.advance_width;
+                paragraph.cursor_x = font.round_to_pixel(paragraph.cursor_x);
+                last_glyph_id = Some(glyph_info.id);
+            }
         }
     }
 }
@@ -393,6 +451,82 @@ fn line_break(
     }
 }
 
+/// Hide the [`SOFT_HYPHEN`]s, except at the end of rows that the paragraph continues after.
+fn hide_soft_hyphens(rows: &mut [PlacedRow]) {
+    let num_rows = rows.len();
+    for (i, placed_row) in rows.iter_mut().enumerate() {
+        if !placed_row.glyphs.iter().any(|glyph| glyph.chr == SOFT_HYPHEN) {
+            continue;
+        }
+        let is_broken_at_end = !placed_row.ends_with_newline && i + 1 < num_rows;
+        let row = Arc::make_mut(&mut placed_row.row);
+        let num_glyphs = row.glyphs.len();
+        for (j, glyph) in row.glyphs.iter_mut().enumerate() {
+            let is_last = j + 1 == num_glyphs;
+            let is_shown = is_last && is_broken_at_end;
+            if glyph.chr == SOFT_HYPHEN && !is_shown {
+                if is_last {
+                    row.size.x -= glyph.advance_width;
+                }
+                glyph.advance_width = 0.0;
+                glyph.uv_rect = UvRect::default();
+            }
+        }
+    }
+}
+
+/// Add [`SOFT_HYPHEN`]s to the text where its words may be hyphenated,
+/// so that wrapped text is less ragged and justified text has smaller gaps between words.
+///
+/// `hyphenate_word` is called for each word, i.e. run of alphabetic characters,
+/// and returns the byte offsets in it where it may be broken,
+/// e.g. from a hyphenation dictionary for the language of the text.
+///
+/// ```
+/// # use epaint::text::{SOFT_HYPHEN, insert_soft_hyphens};
+/// let text = insert_soft_hyphens("Hyphenation works", |word| match word {
+///     "Hyphenation" => vec![2, 6],
+///     _ => vec![],
+/// });
+/// assert_eq!(text, format!("Hy{SOFT_HYPHEN}phen{SOFT_HYPHEN}ation works"));
+/// ```
+pub fn insert_soft_hyphens(
+    text: &str,
+    mut hyphenate_word: impl FnMut(&str) -> Vec<usize>,
+) -> String {
+    let mut hyphenated = String::with_capacity(text.len());
+    let mut word_start = None;
+
+    // A space at the end so that the last word is hyphenated too:
+    for (i, chr) in text.char_indices().chain(std::iter::once((text.len(), ' '))) {
+        if chr.is_alphabetic() {
+            word_start.get_or_insert(i);
+            continue;
+        }
+
+        if let Some(word_start) = word_start.take() {
+            let word = &text[word_start..i];
+            let mut breaks = hyphenate_word(word);
+            breaks.sort_unstable();
+            breaks.dedup();
+            let mut rest_start = 0;
+            for hyphen in breaks {
+                if 0 < hyphen && hyphen < word.len() && word.is_char_boundary(hyphen) {
+                    hyphenated += &word[rest_start..hyphen];
+                    hyphenated.push(SOFT_HYPHEN);
+                    rest_start = hyphen;
+                }
+            }
+            hyphenated += &word[rest_start..];
+        }
+        if i < text.len() {
+            hyphenated.push(chr);
+        }
+    }
+
+    hyphenated
+}
+
 /// Trims the last glyphs in the row and replaces it with an overflow character (e.g. `…`).
 ///
 /// Called before we have any Y coordinates.
@@ -422,6 +556,7 @@ fn replace_last_glyph_with_overflow_character(
 
     // We always try to just append the character first:
     if let Some(last_glyph) = row.glyphs.last() {
+        let bidi_level = last_glyph.bidi_level;
         let section_index = last_glyph.section_index;
         let section = &job.sections[section_index as usize];
         let font = fonts.font(&section.format.font_id);
@@ -451,6 +586,7 @@ fn replace_last_glyph_with_overflow_character(
             font_height: font.row_height(),
             font_ascent: font.ascent(),
             uv_rect: replacement_glyph_info.uv_rect,
+            bidi_level,
             section_index,
         });
     } else {
@@ -473,6 +609,7 @@ fn replace_last_glyph_with_overflow_character(
             font_height: font.row_height(),
             font_ascent: font.ascent(),
             uv_rect: replacement_glyph_info.uv_rect,
+            bidi_level: 0,
             section_index,
         });
     }
@@ -639,6 +776,62 @@ fn halign_and_justify_row(
     row.size.x = target_max_x - target_min_x;
 }
 
+/// Move the glyphs of a row with right-to-left text to their visual order,
+/// following rule L2 of the Unicode bidirectional algorithm.
+///
+/// The glyphs stay in the order of the text, only their x positions change.
+/// Each glyph keeps the space up to the next one, e.g. from kerning and justification.
+fn reorder_bidi_row(placed_row: &mut PlacedRow) {
+    if placed_row.glyphs.iter().all(|glyph| glyph.bidi_level == 0) {
+        return;
+    }
+    let row = Arc::make_mut(&mut placed_row.row);
+    let glyphs = &mut row.glyphs;
+
+    let max_level = glyphs.iter().map(|glyph| glyph.bidi_level).max().unwrap_or(0);
+    let min_level = glyphs.iter().map(|glyph| glyph.bidi_level).min().unwrap_or(0);
+    let lowest_odd_level = min_level | 1;
+
+    // From the highest level down to the lowest odd one, reverse every run at that level
+    // or higher:
+    let mut visual_order: Vec<usize> = (0..glyphs.len()).collect();
+    for level in (lowest_odd_level..=max_level).rev() {
+        let mut run_start = 0;
+        while run_start < visual_order.len() {
+            let run_len = visual_order[run_start..]
+                .iter()
+                .take_while(|&&i| level <= glyphs[i].bidi_level)
+                .count();
+            visual_order[run_start..run_start + run_len].reverse();
+            run_start += run_len + 1;
+        }
+    }
+
+    let advances: Vec<f32> = glyphs
+        .windows(2)
+        .map(|pair| pair[1].pos.x - pair[0].pos.x)
+        .chain(glyphs.last().map(|last| last.advance_width))
+        .collect();
+    let mut x = glyphs[0].pos.x;
+    for i in visual_order {
+        glyphs[i].pos.x = x;
+        x += advances[i];
+    }
+}
+
+/// The glyphs of the row from left to right,
+/// which is not the order of the text if it has right-to-left parts.
+fn glyphs_left_to_right(row: &Row) -> impl Iterator<Item = &Glyph> {
+    let (sorted, in_order) = if row.glyphs.iter().any(|glyph| glyph.bidi_level != 0) {
+        let mut sorted: Vec<&Glyph> = row.glyphs.iter().collect();
+        sorted.sort_by(|a, b| a.pos.x.total_cmp(&b.pos.x));
+        (Some(sorted.into_iter()), None)
+    } else {
+        (None, Some(row.glyphs.iter()))
+    };
+    sorted.into_iter().flatten().chain(in_order.into_iter().flatten())
+}
+
 /// Calculate the Y positions and tessellate the text.
 fn galley_from_rows(
     point_scale: PointScale,
@@ -813,7 +1006,7 @@ fn add_row_backgrounds(point_scale: PointScale, job: &LayoutJob, row: &Row, mesh
     let mut run_start = None;
     let mut last_rect = Rect::NAN;
 
-    for glyph in &row.glyphs {
+    for glyph in glyphs_left_to_right(row) {
         let format = &job.sections[glyph.section_index as usize].format;
         let color = format.background;
         let rect = glyph.logical_rect();
@@ -915,7 +1108,7 @@ fn add_row_hline(
     let mut line_start = None;
     let mut last_right_x = f32::NAN;
 
-    for glyph in &row.glyphs {
+    for glyph in glyphs_left_to_right(row) {
         let (stroke, mut y) = stroke_and_y(glyph);
         stroke.round_center_to_pixel(point_scale.pixels_per_point, &mut y);
 
@@ -954,6 +1147,10 @@ struct RowBreakCandidates {
     /// Breaking anywhere before a CJK character is acceptable too.
     pre_cjk: Option<usize>,
 
+    /// Breaking at a [`SOFT_HYPHEN`] is as good as at a space,
+    /// since that is what it was put there for.
+    hyphen: Option<usize>,
+
     /// Breaking at a dash is a super-
     /// good idea.
     dash: Option<usize>,
@@ -975,6 +1172,8 @@ impl RowBreakCandidates {
             self.space = Some(index);
         } else if is_cjk(chr) && (glyphs.len() == 1 || is_cjk_break_allowed(glyphs[1].chr)) {
             self.cjk = Some(index);
+        } else if chr == SOFT_HYPHEN {
+            self.hyphen = Some(index);
         } else if chr == '-' {
             self.dash = Some(index);
         } else if chr.is_ascii_punctuation() {
@@ -986,7 +1185,7 @@ impl RowBreakCandidates {
     }
 
     fn word_boundary(&self) -> Option<usize> {
-        [self.space, self.cjk, self.pre_cjk]
+        [self.space, self.cjk, self.pre_cjk, self.hyphen]
             .into_iter()
             .max()
             .flatten()
@@ -1016,6 +1215,7 @@ impl RowBreakCandidates {
             space,
             cjk,
             pre_cjk,
+            hyphen,
             dash,
             punctuation,
             any,
@@ -1029,6 +1229,9 @@ impl RowBreakCandidates {
         if pre_cjk.is_some_and(|s| s < index) {
             *pre_cjk = None;
         }
+        if hyphen.is_some_and(|s| s < index) {
+            *hyphen = None;
+        }
         if dash.is_some_and(|s| s < index) {
             *dash = None;
         }
@@ -1180,6 +1383,119 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_bidi() {
+        use crate::text::cursor::CCursor;
+
+        let mut fonts = FontsImpl::new(
+            1.0,
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+        let text_format = TextFormat {
+            font_id: FontId::monospace(12.0),
+            ..Default::default()
+        };
+
+        let job = LayoutJob::single_section("ab אבג cd".into(), text_format.clone());
+        let galley = layout(&mut fonts, job.into());
+        let row = &galley.rows[0];
+        assert_eq!(row.text(), "ab אבג cd", "The glyphs stay in the order of the text");
+        let x = |index: usize| row.glyphs[index].pos.x;
+        assert!(x(0) < x(1) && x(1) < x(2));
+        assert!(x(5) < x(4) && x(4) < x(3), "The Hebrew word is right-to-left");
+        assert!(x(2) < x(5) && x(3) < x(6), "…between the Latin words");
+
+        // A cursor before a right-to-left glyph is on its right side:
+        let alef = row.glyphs[3];
+        assert!(alef.is_rtl());
+        assert_eq!(galley.pos_from_cursor(CCursor::new(3)).min.x, alef.max_x());
+        assert_eq!(galley.pos_from_cursor(CCursor::new(4)).min.x, alef.pos.x);
+        let y = row.rect().center().y;
+        let alef_center = alef.logical_rect().center().x;
+        assert_eq!(galley.cursor_from_pos(vec2(alef_center + 1.0, y)).index, 3);
+        assert_eq!(galley.cursor_from_pos(vec2(alef_center - 1.0, y)).index, 4);
+
+        // A right-to-left paragraph, with Latin text in it:
+        let job = LayoutJob::single_section("אב cd".into(), text_format);
+        let galley = layout(&mut fonts, job.into());
+        let row = &galley.rows[0];
+        let x = |index: usize| row.glyphs[index].pos.x;
+        assert!(x(3) < x(4) && x(4) < x(2) && x(2) < x(1) && x(1) < x(0));
+    }
+
+    #[test]
+    fn test_soft_hyphen() {
+        let mut fonts = FontsImpl::new(
+            1.0,
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+        let text_format = TextFormat {
+            font_id: FontId::monospace(12.0),
+            ..Default::default()
+        };
+        let mut layout_text = |text: &str, max_width: f32| {
+            let mut job = LayoutJob::simple_format(text.into(), text_format.clone());
+            job.wrap.max_width = max_width;
+            layout(&mut fonts, job.into())
+        };
+
+        let hyphenated = "aa bb\u{AD}cc";
+        let unbroken = layout_text(hyphenated, f32::INFINITY);
+        assert_eq!(unbroken.rows.len(), 1);
+        assert_eq!(
+            unbroken.size(),
+            layout_text("aa bbcc", f32::INFINITY).size(),
+            "The soft hyphen is invisible"
+        );
+
+        let max_width = layout_text("aa bb-", f32::INFINITY).size().x;
+        let broken = layout_text(hyphenated, max_width);
+        assert_eq!(
+            broken.rows.iter().map(|row| row.text()).collect::<Vec<_>>(),
+            vec!["aa bb\u{AD}", "cc"],
+            "Broken at the soft hyphen rather than at the space"
+        );
+        let hyphen = broken.rows[0].glyphs.last().unwrap();
+        assert!(!hyphen.uv_rect.is_nothing(), "The hyphen is shown at the end of the row");
+        assert!(broken.rows[0].rect().width() <= max_width);
+    }
+
+    #[cfg(feature = "shaping")]
+    #[test]
+    fn test_shaping() {
+        let mut fonts = FontsImpl::new(
+            1.0,
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+        let text_format = TextFormat {
+            font_id: FontId::proportional(14.0),
+            ..Default::default()
+        };
+        let text = "AVA office, fi\u{AD}nally";
+        let galley = layout(
+            &mut fonts,
+            LayoutJob::simple_format(text.into(), text_format).into(),
+        );
+        let glyphs = &galley.rows[0].glyphs;
+        assert_eq!(
+            glyphs.len(),
+            text.chars().count(),
+            "One glyph per character, so that cursors still work"
+        );
+        assert!(
+            glyphs.windows(2).all(|pair| pair[0].pos.x <= pair[1].pos.x),
+            "Laid out left to right: {:?}",
+            glyphs.iter().map(|glyph| glyph.pos.x).collect::<Vec<_>>()
+        );
+        assert!(glyphs.iter().any(|glyph| !glyph.uv_rect.is_nothing()));
+    }
+
     #[test]
     fn test_truncate_width() {
         let mut fonts = FontsImpl::new(
@@ -203,6 +203,44 @@ impl LayoutJob {
             self.wrap.max_width
         }
     }
+
+    /// The characters in `char_range` as HTML, e.g. for copying them as rich text.
+    ///
+    /// Keeps the color, background, italics, underline, strikethrough and monospace
+    /// of each section.
+    /// [`FontFamily::Name`](crate::FontFamily::Name)s with "bold" in them are made bold.
+    pub fn to_html(&self, char_range: Range<usize>) -> String {
+        let byte_index = |char_index: usize| {
+            self.text
+                .char_indices()
+                .nth(char_index)
+                .map_or(self.text.len(), |(byte_index, _)| byte_index)
+        };
+        let selected = byte_index(char_range.start)..byte_index(char_range.end);
+
+        let mut html = "<span style=\"white-space: pre-wrap\">".to_owned();
+        for section in &self.sections {
+            let start = section.byte_range.start.max(selected.start);
+            let end = section.byte_range.end.min(selected.end);
+            let Some(text) = self.text.get(start..end).filter(|text| !text.is_empty()) else {
+                continue;
+            };
+            html += &format!("<span style=\"{}\">", section.format.css());
+            for c in text.chars() {
+                match c {
+                    '&' => html += "&amp;",
+                    '<' => html += "&lt;",
+                    '>' => html += "&gt;",
+                    '"' => html += "&quot;",
+                    '\n' => html += "<br>",
+                    c => html.push(c),
+                }
+            }
+            html += "</span>";
+        }
+        html += "</span>";
+        html
+    }
 }
 
 impl std::hash::Hash for LayoutJob {
@@ -367,6 +405,36 @@ impl TextFormat {
             ..Default::default()
         }
     }
+
+    /// The CSS style of this format, for [`LayoutJob::to_html`].
+    fn css(&self) -> String {
+        let css_color = |color: Color32| {
+            let [r, g, b, a] = color.to_srgba_unmultiplied();
+            format!("rgba({r}, {g}, {b}, {})", a as f32 / 255.0)
+        };
+
+        let mut css = format!("color: {};", css_color(self.color));
+        if self.background != Color32::TRANSPARENT {
+            css += &format!(" background-color: {};", css_color(self.background));
+        }
+        match &self.font_id.family {
+            crate::FontFamily::Monospace => css += " font-family: monospace;",
+            crate::FontFamily::Name(name) if name.to_lowercase().contains("bold") => {
+                css += " font-weight: bold;";
+            }
+            _ => {}
+        }
+        if self.italics {
+            css += " font-style: italic;";
+        }
+        match (self.underline.is_empty(), self.strikethrough.is_empty()) {
+            (false, false) => css += " text-decoration: underline line-through;",
+            (false, true) => css += " text-decoration: underline;",
+            (true, false) => css += " text-decoration: line-through;",
+            (true, true) => {}
+        }
+        css
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -671,6 +739,9 @@ pub struct Glyph {
 
     /// Baseline position, relative to the row.
     /// Logical position: pos.y is the same for all chars of the same [`TextFormat`].
+    ///
+    /// The glyphs of a [`Row`] are in the order of the text,
+    /// so with right-to-left text the x positions are not in increasing order.
     pub pos: Pos2,
 
     /// Logical width of the glyph.
@@ -697,6 +768,12 @@ pub struct Glyph {
     /// Position and size of the glyph in the font texture, in texels.
     pub uv_rect: UvRect,
 
+    /// The embedding level from the Unicode bidirectional algorithm.
+    ///
+    /// Odd levels are right-to-left. Zero for left-to-right paragraphs without any
+    /// right-to-left text.
+    pub(crate) bidi_level: u8,
+
     /// Index into [`LayoutJob::sections`]. Decides color etc.
     ///
     /// Only used during layout, then set to an invalid value in order to
@@ -721,6 +798,14 @@ impl Glyph {
     pub fn logical_rect(&self) -> Rect {
         Rect::from_min_size(self.pos - vec2(0.0, self.font_ascent), self.size())
     }
+
+    /// Is this glyph part of right-to-left text, e.g. Hebrew or Arabic?
+    ///
+    /// Then the text continues to its left, and a cursor before it is on its right side.
+    #[inline]
+    pub fn is_rtl(&self) -> bool {
+        self.bidi_level % 2 == 1
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -746,17 +831,43 @@ impl Row {
     /// Closest char at the desired x coordinate in row-relative coordinates.
     /// Returns something in the range `[0, char_count_excluding_newline()]`.
     pub fn char_at(&self, desired_x: f32) -> usize {
+        let distance = |glyph: &Glyph| {
+            (glyph.pos.x - desired_x)
+                .max(desired_x - glyph.max_x())
+                .at_least(0.0)
+        };
+
+        // With right-to-left text the glyphs are not sorted by x, so find the closest one:
+        let mut closest: Option<(usize, &Glyph)> = None;
         for (i, glyph) in self.glyphs.iter().enumerate() {
-            if desired_x < glyph.logical_rect().center().x {
-                return i;
+            if closest.is_none_or(|(_, best)| distance(glyph) < distance(best)) {
+                closest = Some((i, glyph));
             }
         }
-        self.char_count_excluding_newline()
+        let Some((i, glyph)) = closest else {
+            return 0;
+        };
+
+        let is_before_center = desired_x < glyph.logical_rect().center().x;
+        if is_before_center != glyph.is_rtl() {
+            i
+        } else {
+            i + 1
+        }
     }
 
+    /// The x coordinate of a cursor before the char at `column`, in row-relative coordinates.
+    ///
+    /// That is the left side of the glyph, or its right side if it is right-to-left.
     pub fn x_offset(&self, column: usize) -> f32 {
         if let Some(glyph) = self.glyphs.get(column) {
-            glyph.pos.x
+            if glyph.is_rtl() {
+                glyph.max_x()
+            } else {
+                glyph.pos.x
+            }
+        } else if let Some(last) = self.glyphs.last().filter(|last| last.is_rtl()) {
+            last.pos.x
         } else {
             self.size.x
         }
@@ -1227,3 +1338,29 @@ impl Galley {
         self.cursor_from_layout(layout_cursor)
     }
 }
+
+#[test]
+fn test_layout_job_to_html() {
+    let mut job = LayoutJob::default();
+    job.append("a < b", 0.0, TextFormat::default());
+    job.append(
+        "\ncode",
+        0.0,
+        TextFormat {
+            font_id: FontId::monospace(12.0),
+            italics: true,
+            ..Default::default()
+        },
+    );
+
+    let gray = "color: rgba(160, 160, 160, 1);";
+    assert_eq!(
+        job.to_html(2..8),
+        format!(
+            "<span style=\"white-space: pre-wrap\">\
+            <span style=\"{gray}\">&lt; b</span>\
+            <span style=\"{gray} font-family: monospace; font-style: italic;\"><br>co</span>\
+            </span>"
+        )
+    );
+}
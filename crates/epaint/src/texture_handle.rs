@@ -86,6 +86,22 @@ impl TextureHandle {
             .set(self.id, ImageDelta::partial(pos, image.into(), options));
     }
 
+    /// Update several subregions of the texture at once, e.g. the dirty rectangles of a frame.
+    ///
+    /// Each region is given as the position of its top-left corner and the new texels.
+    /// Adjacent and overlapping regions are coalesced before upload, see [`TextureManager::set`].
+    #[expect(clippy::needless_pass_by_ref_mut)] // Intentionally hide interiority of mutability
+    pub fn set_partial_regions(
+        &mut self,
+        regions: impl IntoIterator<Item = ([usize; 2], ImageData)>,
+        options: TextureOptions,
+    ) {
+        let mut tex_mngr = self.tex_mngr.write();
+        for (pos, image) in regions {
+            tex_mngr.set(self.id, ImageDelta::partial(pos, image, options));
+        }
+    }
+
     /// width x height
     pub fn size(&self) -> [usize; 2] {
         self.tex_mngr
@@ -1,4 +1,4 @@
-use crate::{ImageData, ImageDelta, TextureId};
+use crate::{ColorImage, ImageData, ImageDelta, TextureId};
 
 // ----------------------------------------------------------------------------
 
@@ -46,6 +46,13 @@ impl TextureManager {
 
     /// Assign a new image to an existing texture,
     /// or update a region of it.
+    ///
+    /// Partial updates are coalesced with updates to the same texture that are
+    /// already enqueued this frame, where possible: regions that are completely overwritten
+    /// are dropped, regions inside an earlier update are copied into it,
+    /// and regions that neighbor an earlier update along a whole edge are joined with it.
+    /// This means many small dirty rectangles can be set each frame without
+    /// resulting in a flood of tiny uploads.
     pub fn set(&mut self, id: TextureId, delta: ImageDelta) {
         if let Some(meta) = self.metas.get_mut(&id) {
             if let Some(pos) = delta.pos {
@@ -54,14 +61,17 @@ impl TextureManager {
                         && pos[1] + delta.image.height() <= meta.size[1],
                     "Partial texture update is outside the bounds of texture {id:?}",
                 );
+                if let Some(delta) = coalesce_partial(&mut self.delta.set, id, delta) {
+                    self.delta.set.push((id, delta));
+                }
             } else {
                 // whole update
                 meta.size = delta.image.size();
                 meta.bytes_per_pixel = delta.image.bytes_per_pixel();
                 // since we update the whole image, we can discard all old enqueued deltas
                 self.delta.set.retain(|(x, _)| x != &id);
+                self.delta.set.push((id, delta));
             }
-            self.delta.set.push((id, delta));
         } else {
             debug_assert!(false, "Tried setting texture {id:?} which is not allocated");
         }
@@ -118,6 +128,117 @@ impl TextureManager {
     }
 }
 
+/// A rectangle of texels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct TexelRect {
+    min: [usize; 2],
+    size: [usize; 2],
+}
+
+impl TexelRect {
+    fn of(delta: &ImageDelta) -> Self {
+        Self {
+            min: delta.pos.unwrap_or([0, 0]),
+            size: delta.image.size(),
+        }
+    }
+
+    fn max(&self) -> [usize; 2] {
+        [self.min[0] + self.size[0], self.min[1] + self.size[1]]
+    }
+
+    fn contains(&self, other: &Self) -> bool {
+        (0..2).all(|d| self.min[d] <= other.min[d] && other.max()[d] <= self.max()[d])
+    }
+
+    fn intersects(&self, other: &Self) -> bool {
+        (0..2).all(|d| self.min[d] < other.max()[d] && other.min[d] < self.max()[d])
+    }
+
+    /// If the two rectangles share a whole edge, return their union.
+    fn join(&self, other: &Self) -> Option<Self> {
+        for (d, e) in [(0, 1), (1, 0)] {
+            let aligned = self.min[e] == other.min[e] && self.size[e] == other.size[e];
+            if aligned && (self.max()[d] == other.min[d] || other.max()[d] == self.min[d]) {
+                let mut joined = *self;
+                joined.min[d] = self.min[d].min(other.min[d]);
+                joined.size[d] = self.size[d] + other.size[d];
+                return Some(joined);
+            }
+        }
+        None
+    }
+}
+
+/// Try to merge the partial update `delta` into the updates of the same texture
+/// already in `queue`, preserving the order in which texels are written.
+///
+/// Returns the delta if it still needs to be enqueued.
+fn coalesce_partial(
+    queue: &mut Vec<(TextureId, ImageDelta)>,
+    id: TextureId,
+    delta: ImageDelta,
+) -> Option<ImageDelta> {
+    let new_rect = TexelRect::of(&delta);
+
+    // Earlier partial updates that are completely overwritten are redundant:
+    queue.retain(|(x, d)| !(*x == id && d.pos.is_some() && new_rect.contains(&TexelRect::of(d))));
+
+    // Is any update after `index` touching `rect`? Then we can't move texels past it.
+    let written_after = |queue: &[(TextureId, ImageDelta)], index: usize, rect: &TexelRect| {
+        queue[index + 1..]
+            .iter()
+            .any(|(x, d)| *x == id && TexelRect::of(d).intersects(rect))
+    };
+
+    for index in (0..queue.len()).rev() {
+        let (x, earlier) = &queue[index];
+        if *x != id || earlier.options != delta.options {
+            continue;
+        }
+        let earlier_rect = TexelRect::of(earlier);
+
+        if earlier_rect.contains(&new_rect) {
+            if written_after(queue, index, &new_rect) {
+                return Some(delta);
+            }
+            // Copy the new texels into the earlier update:
+            let (ImageData::Color(target), ImageData::Color(patch)) =
+                (&mut queue[index].1.image, &delta.image);
+            let offset = [
+                new_rect.min[0] - earlier_rect.min[0],
+                new_rect.min[1] - earlier_rect.min[1],
+            ];
+            std::sync::Arc::make_mut(target).blit(offset, patch);
+            return None;
+        }
+
+        if earlier.pos.is_some() {
+            if let Some(joined) = earlier_rect.join(&new_rect) {
+                if written_after(queue, index, &earlier_rect) {
+                    continue;
+                }
+                // Join the two updates into one, enqueued last:
+                let (_, earlier) = queue.remove(index);
+                let (ImageData::Color(first), ImageData::Color(second)) =
+                    (&earlier.image, &delta.image);
+                let mut image = ColorImage::filled(joined.size, crate::Color32::TRANSPARENT);
+                for (part, rect) in [(first, earlier_rect), (second, new_rect)] {
+                    image.blit(
+                        [rect.min[0] - joined.min[0], rect.min[1] - joined.min[1]],
+                        part,
+                    );
+                }
+                // The joined update may in turn be joined with others, e.g. a row of tiles:
+                let joined = ImageDelta::partial(joined.min, image, delta.options);
+                return coalesce_partial(queue, id, joined);
+            }
+        }
+    }
+
+    Some(delta)
+}
+
 /// Meta-data about an allocated texture.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TextureMeta {
@@ -330,3 +451,49 @@ impl std::fmt::Debug for TexturesDelta {
         debug_struct.finish()
     }
 }
+
+#[test]
+fn coalesce_partial_updates() {
+    use crate::Color32;
+
+    let mut mngr = TextureManager::default();
+    let options = TextureOptions::NEAREST;
+    let id = mngr.alloc(
+        "test".to_owned(),
+        ColorImage::filled([8, 8], Color32::BLACK).into(),
+        options,
+    );
+    mngr.take_delta();
+
+    let tile = |color| ImageData::from(ColorImage::filled([2, 2], color));
+
+    // Two neighbors become one update:
+    mngr.set(id, ImageDelta::partial([0, 0], tile(Color32::RED), options));
+    mngr.set(
+        id,
+        ImageDelta::partial([2, 0], tile(Color32::GREEN), options),
+    );
+    // A patch inside an earlier update is copied into it:
+    mngr.set(
+        id,
+        ImageDelta::partial([1, 0], ColorImage::filled([1, 1], Color32::BLUE), options),
+    );
+
+    let delta = mngr.take_delta();
+    assert_eq!(delta.set.len(), 1);
+    let (_, update) = &delta.set[0];
+    assert_eq!(update.pos, Some([0, 0]));
+    let ImageData::Color(image) = &update.image;
+    assert_eq!(image.size, [4, 2]);
+    assert_eq!(image[(0, 0)], Color32::RED);
+    assert_eq!(image[(1, 0)], Color32::BLUE);
+    assert_eq!(image[(3, 1)], Color32::GREEN);
+
+    // A completely overwritten update is dropped:
+    mngr.set(id, ImageDelta::partial([4, 4], tile(Color32::RED), options));
+    mngr.set(
+        id,
+        ImageDelta::partial([3, 3], ColorImage::filled([4, 4], Color32::WHITE), options),
+    );
+    assert_eq!(mngr.take_delta().set.len(), 1);
+}
@@ -0,0 +1,107 @@
+//! Count heap allocations, to find allocator churn in hot paths.
+//!
+//! Install [`CountingAllocator`] as your global allocator,
+//! then compare [`AllocationStats::now`] before and after the code you are interested in.
+//! `egui` does this for each pass (see `egui::Context::allocations_last_pass`).
+
+#![expect(unsafe_code)]
+
+use std::{
+    alloc::{GlobalAlloc, Layout},
+    sync::atomic::{AtomicUsize, Ordering::Relaxed},
+};
+
+static NUM_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static NUM_DEALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static NUM_BYTES_ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+/// Wraps another allocator and counts all allocations made through it.
+///
+/// ```
+/// #[global_allocator]
+/// static GLOBAL: epaint::util::CountingAllocator<std::alloc::System> =
+///     epaint::util::CountingAllocator::new(std::alloc::System);
+/// # fn main() {}
+/// ```
+pub struct CountingAllocator<A> {
+    allocator: A,
+}
+
+impl<A> CountingAllocator<A> {
+    pub const fn new(allocator: A) -> Self {
+        Self { allocator }
+    }
+}
+
+// SAFETY: we forward everything to the wrapped allocator, and only count on the side.
+unsafe impl<A: GlobalAlloc> GlobalAlloc for CountingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        NUM_ALLOCATIONS.fetch_add(1, Relaxed);
+        NUM_BYTES_ALLOCATED.fetch_add(layout.size(), Relaxed);
+        // SAFETY: same contract as the caller.
+        unsafe { self.allocator.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        NUM_DEALLOCATIONS.fetch_add(1, Relaxed);
+        // SAFETY: same contract as the caller.
+        unsafe { self.allocator.dealloc(ptr, layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        NUM_ALLOCATIONS.fetch_add(1, Relaxed);
+        NUM_BYTES_ALLOCATED.fetch_add(layout.size(), Relaxed);
+        // SAFETY: same contract as the caller.
+        unsafe { self.allocator.alloc_zeroed(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        // A reallocation is a new allocation, as far as churn is concerned:
+        NUM_ALLOCATIONS.fetch_add(1, Relaxed);
+        NUM_DEALLOCATIONS.fetch_add(1, Relaxed);
+        NUM_BYTES_ALLOCATED.fetch_add(new_size, Relaxed);
+        // SAFETY: same contract as the caller.
+        unsafe { self.allocator.realloc(ptr, layout, new_size) }
+    }
+}
+
+/// Allocation counts, either since the start of the program, or between two points in time.
+///
+/// These are all zero unless [`CountingAllocator`] is the global allocator.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocationStats {
+    /// Number of allocations (including reallocations).
+    pub num_allocations: usize,
+
+    /// Number of deallocations (including reallocations).
+    pub num_deallocations: usize,
+
+    /// Total number of bytes allocated. Memory that was freed again is not subtracted.
+    pub num_bytes_allocated: usize,
+}
+
+impl AllocationStats {
+    /// The counts since the start of the program.
+    pub fn now() -> Self {
+        Self {
+            num_allocations: NUM_ALLOCATIONS.load(Relaxed),
+            num_deallocations: NUM_DEALLOCATIONS.load(Relaxed),
+            num_bytes_allocated: NUM_BYTES_ALLOCATED.load(Relaxed),
+        }
+    }
+}
+
+impl std::ops::Sub for AllocationStats {
+    type Output = Self;
+
+    /// The counts between two points in time.
+    fn sub(self, earlier: Self) -> Self {
+        Self {
+            num_allocations: self.num_allocations.wrapping_sub(earlier.num_allocations),
+            num_deallocations: self.num_deallocations.wrapping_sub(earlier.num_deallocations),
+            num_bytes_allocated: self
+                .num_bytes_allocated
+                .wrapping_sub(earlier.num_bytes_allocated),
+        }
+    }
+}
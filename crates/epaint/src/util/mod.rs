@@ -1,3 +1,9 @@
+#[cfg(feature = "count_allocations")]
+mod allocation_counter;
+
+#[cfg(feature = "count_allocations")]
+pub use allocation_counter::{AllocationStats, CountingAllocator};
+
 /// Hash the given value with a predictable hasher.
 #[inline]
 pub fn hash(value: impl std::hash::Hash) -> u64 {
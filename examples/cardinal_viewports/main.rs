@@ -4,9 +4,6 @@
 use eframe::egui;
 use egui::{ViewportBuilder, ViewportId};
 use log::info;
-use once_cell::sync::Lazy;
-use std::sync::Mutex;
-mod monitor_info;
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 enum Direction {
@@ -117,7 +114,7 @@ impl eframe::App for CardinalViewportsApp {
         if let Some(direction) = spawn_direction {
             if let Some(parent_rect) = ctx.input(|i| i.viewport().outer_rect) {
                 let monitor_rects: Vec<egui::Rect> =
-                    unsafe { monitor_info::MONITOR_RECTS.lock().unwrap().clone() };
+                    ctx.input(|i| i.monitors().iter().map(|m| m.rect).collect());
                 let collision_radius = 100.0;
                 let (dx, dy) = direction.vector();
                 let start_pos = egui::pos2(
@@ -149,13 +146,8 @@ impl eframe::App for CardinalViewportsApp {
         }
 
         if let Some(parent_rect) = ctx.input(|i| i.viewport().outer_rect) {
-            let monitor_rects: Vec<egui::Rect> = ctx.input(|i| {
-                i.raw
-                    .viewports
-                    .values()
-                    .filter_map(|v| v.outer_rect)
-                    .collect()
-            });
+            let monitor_rects: Vec<egui::Rect> =
+                ctx.input(|i| i.monitors().iter().map(|m| m.rect).collect());
             let all_monitors_rect = monitor_rects.iter().fold(
                 if let Some(first) = monitor_rects.first() {
                     *first
@@ -222,14 +214,7 @@ impl eframe::App for CardinalViewportsApp {
                     .sqrt();
                     if dist < 100.0 {
                         // Beep and close
-                        #[cfg(target_os = "windows")]
-                        {
-                            unsafe { winapi::um::winuser::MessageBeep(0xFFFFFFFF) };
-                        }
-                        #[cfg(target_os = "linux")]
-                        println!("\x07");
-                        #[cfg(target_os = "macos")]
-                        println!("\x07");
+                        ctx.beep();
                         viewport.open = false;
                     }
                 }
@@ -273,29 +258,12 @@ impl eframe::App for CardinalViewportsApp {
 }
 fn main() {
     let options = eframe::NativeOptions::default();
-    // --- External event loop pattern for monitor snarfing ---
+    // --- External event loop pattern ---
     use eframe::UserEvent;
     use winit::event_loop::EventLoop;
     let event_loop = EventLoop::<UserEvent>::with_user_event()
         .build()
         .expect("Failed to build event loop");
-    monitor_info::fill_monitor_rects();
-    #[cfg(debug_assertions)]
-    {
-        let rects = monitor_info::MONITOR_RECTS.lock().unwrap();
-        println!("[DEBUG] Filled MONITOR_RECTS: {} monitors", rects.len());
-        for (i, r) in rects.iter().enumerate() {
-            println!(
-                "  Monitor {i}: min=({:.1},{:.1}) max=({:.1},{:.1}) size=({:.1},{:.1})",
-                r.min.x,
-                r.min.y,
-                r.max.x,
-                r.max.y,
-                r.width(),
-                r.height()
-            );
-        }
-    }
     let mut app = eframe::create_native(
         "Cardinal Viewports",
         options,
@@ -4,6 +4,7 @@
 (cd crates/ecolor               && cargo publish --quiet)  &&  echo "✅ ecolor"
 (cd crates/epaint_default_fonts && cargo publish --quiet)  &&  echo "✅ epaint_default_fonts"
 (cd crates/epaint               && cargo publish --quiet)  &&  echo "✅ epaint"
+(cd crates/egui_derive          && cargo publish --quiet)  &&  echo "✅ egui_derive"
 (cd crates/egui                 && cargo publish --quiet)  &&  echo "✅ egui"
 (cd crates/egui-winit           && cargo publish --quiet)  &&  echo "✅ egui-winit"
 (cd crates/egui_glow            && cargo publish --quiet)  &&  echo "✅ egui_glow"